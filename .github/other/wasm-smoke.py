@@ -0,0 +1,84 @@
+#!/usr/bin/env python3
+# Copyright (c) godot-rust; Bromeon and contributors.
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+# Runs a web export of itest in headless Chromium and waits for the result line printed by itest/godot/WasmSmoke.gd.
+# Input: directory containing the exported index.html.
+# Requires: playwright (pip), with Chromium installed through `python -m playwright install --with-deps chromium`.
+
+import functools
+import http.server
+import sys
+import threading
+
+from playwright.sync_api import sync_playwright
+
+TIMEOUT_SECONDS = 120
+RESULT_PREFIX = "WASM_SMOKE: "
+
+
+class Handler(http.server.SimpleHTTPRequestHandler):
+    # Cross-origin isolation is needed for SharedArrayBuffer, used by the threads variant.
+    def end_headers(self):
+        self.send_header("Cross-Origin-Opener-Policy", "same-origin")
+        self.send_header("Cross-Origin-Embedder-Policy", "require-corp")
+        super().end_headers()
+
+    def log_message(self, format, *args):
+        pass
+
+
+Handler.extensions_map[".wasm"] = "application/wasm"
+
+
+def main(export_dir):
+    server = http.server.ThreadingHTTPServer(("127.0.0.1", 0), functools.partial(Handler, directory=export_dir))
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    url = f"http://127.0.0.1:{server.server_port}/index.html"
+
+    result = []
+    done = threading.Event()
+
+    def on_console(msg):
+        text = msg.text
+        print(f"[browser] {text}", flush=True)
+        if text.startswith(RESULT_PREFIX):
+            result.append(text[len(RESULT_PREFIX):].strip())
+            done.set()
+
+    def on_error(error):
+        print(f"[browser] uncaught error: {error}", flush=True)
+
+    with sync_playwright() as p:
+        # SwiftShader provides WebGL 2 without a GPU.
+        browser = p.chromium.launch(args=["--use-angle=swiftshader", "--enable-unsafe-swiftshader"])
+        page = browser.new_page()
+        page.on("console", on_console)
+        page.on("pageerror", on_error)
+        page.goto(url)
+
+        # Poll through Playwright, so that console events keep being dispatched while waiting.
+        for _ in range(TIMEOUT_SECONDS):
+            if done.is_set():
+                break
+            page.wait_for_timeout(1000)
+
+        browser.close()
+
+    server.shutdown()
+
+    if not result:
+        print(f"::error::No '{RESULT_PREFIX.strip()}' line within {TIMEOUT_SECONDS}s; extension may have failed to load.")
+        return 1
+    if result[0] != "ok":
+        print(f"::error::Wasm smoke test failed: {result[0]}")
+        return 1
+
+    print("Wasm smoke test passed.")
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main(sys.argv[1]))
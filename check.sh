@@ -186,6 +186,8 @@ function cmd_test() {
 function cmd_itest() {
     findGodot && \
         run cargo build -p itest "${extraCargoArgs[@]}" && \
+        run mkdir -p itest/godot/export && \
+        run "$godotBin" $GODOT_ARGS --path itest/godot --headless --export-pack ExportTest export/itest.pck && \
         run "$godotBin" $GODOT_ARGS --path itest/godot --headless -- "[${extraArgs[@]}]"
 }
 
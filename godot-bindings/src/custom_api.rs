@@ -0,0 +1,242 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! User-supplied API files for `api-custom`, e.g. from a patched engine fork with additional classes.
+
+use crate::GodotVersion;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+/// Path to an `extension_api.json`, used instead of dumping it from the Godot binary.
+pub const JSON_ENV_VAR: &str = "GODOT4_GDEXTENSION_JSON";
+
+/// Path to a `gdextension_interface.h`, used instead of dumping it from the Godot binary.
+pub const HEADER_ENV_VAR: &str = "GODOT4_GDEXTENSION_C_HEADER";
+
+/// Oldest Godot version that godot-rust can generate bindings for; the version declared in the JSON header is checked against it.
+///
+/// The JSON version is the API level of the generated code, so the `compatibility_minimum` in the user's `.gdextension` file should be at
+/// least that version. The build script has no access to that file, so this is not validated.
+const MIN_JSON_VERSION: (u8, u8) = (4, 1);
+
+/// Returns the path stored in `env_var`, if set. Panics if it doesn't refer to a file.
+pub(crate) fn locate_api_file(env_var: &str) -> Option<PathBuf> {
+    println!("cargo:rerun-if-env-changed={env_var}");
+
+    let path = PathBuf::from(std::env::var_os(env_var)?);
+    assert!(
+        path.is_file(),
+        "{env_var} is set to '{}', which is not a file.\n\
+        Provide the absolute path to a file dumped with `godot --dump-extension-api` (JSON) \
+        or `godot --dump-gdextension-interface` (C header).",
+        path.display()
+    );
+
+    println!("cargo:rerun-if-changed={}", path.display());
+    Some(path)
+}
+
+/// Reads the version of a user-supplied `extension_api.json`. Panics with the reason if the file is not usable.
+pub(crate) fn read_json_version(json_path: &Path) -> GodotVersion {
+    let json = read_api_file(json_path);
+
+    parse_json_version(&json).unwrap_or_else(|e| panic!("{}", json_error(json_path, &e)))
+}
+
+pub(crate) fn read_api_file(path: &Path) -> String {
+    std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("failed to read API file '{}': {e}", path.display()))
+}
+
+pub(crate) fn json_error(json_path: &Path, error: &str) -> String {
+    format!(
+        "invalid extension API JSON '{}' (from {JSON_ENV_VAR}): {error}",
+        json_path.display()
+    )
+}
+
+/// Parses the `header` of an `extension_api.json` and checks that godot-rust supports the declared version.
+///
+/// Only the header is inspected here, as this runs in every build script that emits `#[cfg]`s. The rest of the file is validated during
+/// codegen.
+pub(crate) fn parse_json_version(json: &str) -> Result<GodotVersion, String> {
+    const HINT: &str = "expected the output of `godot --dump-extension-api`";
+
+    if !json.trim_start().starts_with('{') {
+        return Err(format!("not a JSON object; {HINT}"));
+    }
+
+    for section in ["builtin_class_sizes", "builtin_classes", "classes"] {
+        if !json.contains(&format!("\"{section}\"")) {
+            return Err(format!("missing `{section}` section; {HINT}"));
+        }
+    }
+
+    let header = Regex::new(r#""header"\s*:\s*\{(?P<body>[^}]*)\}"#)
+        .unwrap()
+        .captures(json)
+        .and_then(|caps| caps.name("body"))
+        .ok_or_else(|| format!("missing `header` object; {HINT}"))?
+        .as_str();
+
+    let field = |key: &str| -> Option<String> {
+        Regex::new(&format!(
+            r#""{key}"\s*:\s*(?:"(?P<str>[^"]*)"|(?P<num>\d+))"#
+        ))
+        .unwrap()
+        .captures(header)
+        .and_then(|caps| caps.name("str").or_else(|| caps.name("num")))
+        .map(|m| m.as_str().to_string())
+    };
+
+    let number = |key: &str| -> Result<u8, String> {
+        let value = field(key).ok_or_else(|| format!("header lacks `{key}`; {HINT}"))?;
+
+        value
+            .parse()
+            .map_err(|_| format!("header has invalid `{key}`: `{value}`"))
+    };
+
+    let major = number("version_major")?;
+    let minor = number("version_minor")?;
+    let patch = number("version_patch")?;
+    let status = field("version_status").unwrap_or_else(|| "stable".to_string());
+    let full_string = field("version_full_name")
+        .unwrap_or_else(|| format!("Godot Engine v{major}.{minor}.{patch}.{status}"));
+
+    if major != 4 {
+        return Err(format!(
+            "declares Godot {major}.{minor}, but godot-rust only supports Godot 4"
+        ));
+    }
+
+    let (min_major, min_minor) = MIN_JSON_VERSION;
+    if (major, minor) < MIN_JSON_VERSION {
+        return Err(format!(
+            "declares Godot {major}.{minor}, but godot-rust requires at least {min_major}.{min_minor}. \
+            Dump the JSON from a Godot 4.1+ engine."
+        ));
+    }
+
+    Ok(GodotVersion {
+        full_string,
+        major,
+        minor,
+        patch,
+        status,
+        custom_rev: None,
+    })
+}
+
+/// Checks that a Godot binary can run an extension generated from the JSON version.
+pub(crate) fn check_binary_version(
+    json_version: &GodotVersion,
+    bin_version: &GodotVersion,
+) -> Result<(), String> {
+    let json = (json_version.major, json_version.minor, json_version.patch);
+    let bin = (bin_version.major, bin_version.minor, bin_version.patch);
+
+    if bin < json {
+        return Err(format!(
+            "the Godot binary ({}) is older than the API JSON ({}); the bindings would call engine APIs that are missing at runtime.\n\
+            Dump the JSON from the same binary, or unset GODOT4_BIN to skip this check.",
+            bin_version.full_string, json_version.full_string
+        ));
+    }
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_json_version_custom_class() {
+    // Fork with one additional class, otherwise minimal.
+    let json = r#"{
+        "header": {
+            "version_major": 4,
+            "version_minor": 3,
+            "version_patch": 1,
+            "version_status": "stable",
+            "version_build": "custom_build",
+            "version_full_name": "Godot Engine v4.3.1.stable.custom_build"
+        },
+        "builtin_class_sizes": [],
+        "builtin_classes": [],
+        "classes": [
+            {
+                "name": "ForkedTerrain",
+                "is_refcounted": false,
+                "is_instantiable": true,
+                "inherits": "Node3D",
+                "api_type": "core"
+            }
+        ]
+    }"#;
+
+    let version = parse_json_version(json).expect("valid JSON");
+    assert_eq!(
+        version,
+        GodotVersion {
+            full_string: "Godot Engine v4.3.1.stable.custom_build".to_string(),
+            major: 4,
+            minor: 3,
+            patch: 1,
+            status: "stable".to_string(),
+            custom_rev: None,
+        }
+    );
+
+    let older_bin = GodotVersion {
+        full_string: "4.3.stable.official".to_string(),
+        major: 4,
+        minor: 3,
+        patch: 0,
+        status: "stable".to_string(),
+        custom_rev: None,
+    };
+    assert!(check_binary_version(&version, &version).is_ok());
+    let err = check_binary_version(&version, &older_bin).unwrap_err();
+    assert!(err.contains("older than the API JSON"), "{err}");
+}
+
+#[test]
+fn test_json_version_errors() {
+    let header = |major: u8, minor: u8| {
+        format!(
+            r#"{{ "header": {{ "version_major": {major}, "version_minor": {minor}, "version_patch": 0 }},
+            "builtin_class_sizes": [], "builtin_classes": [], "classes": [] }}"#
+        )
+    };
+
+    let cases = [
+        ("[]".to_string(), "not a JSON object"),
+        (
+            r#"{ "header": {}, "builtin_classes": [], "classes": [] }"#.to_string(),
+            "missing `builtin_class_sizes` section",
+        ),
+        (
+            r#"{ "builtin_class_sizes": [], "builtin_classes": [], "classes": [] }"#.to_string(),
+            "missing `header` object",
+        ),
+        (
+            r#"{ "header": { "version_major": 4 }, "builtin_class_sizes": [], "builtin_classes": [], "classes": [] }"#
+                .to_string(),
+            "header lacks `version_minor`",
+        ),
+        (header(3, 5), "only supports Godot 4"),
+        (header(4, 0), "requires at least 4.1"),
+    ];
+
+    for (json, expected) in cases {
+        let err = parse_json_version(&json).unwrap_err();
+        assert!(
+            err.contains(expected),
+            "error for {json} should contain `{expected}`, was: {err}"
+        );
+    }
+}
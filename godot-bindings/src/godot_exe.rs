@@ -7,6 +7,7 @@
 
 //! Commands related to Godot executable
 
+use crate::custom_api;
 use crate::godot_version::parse_godot_version;
 use crate::header_gen::generate_rust_binding;
 use crate::watch::StopWatch;
@@ -22,6 +23,10 @@ use std::process::{Command, Output};
 // const GODOT_VERSION_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/gen/godot_version.txt");
 
 pub fn load_gdextension_json(watch: &mut StopWatch) -> String {
+    if let Some(json_path) = custom_api::locate_api_file(custom_api::JSON_ENV_VAR) {
+        return load_custom_json(&json_path, watch);
+    }
+
     let path = format!("{}/extension_api.json", std::env::var("OUT_DIR").unwrap());
     let json_path = Path::new(&path);
 
@@ -55,6 +60,27 @@ pub fn write_gdextension_headers(
     is_h_provided: bool,
     watch: &mut StopWatch,
 ) {
+    let mut is_h_provided = is_h_provided;
+    if !is_h_provided {
+        if let Some(custom_h_path) = custom_api::locate_api_file(custom_api::HEADER_ENV_VAR) {
+            println!(
+                "Use C header from {}: '{}'",
+                custom_api::HEADER_ENV_VAR,
+                custom_h_path.display()
+            );
+            fs::copy(&custom_h_path, inout_h_path).unwrap_or_else(|e| {
+                panic!(
+                    "failed to copy C header '{}' to '{}': {e}",
+                    custom_h_path.display(),
+                    inout_h_path.display()
+                )
+            });
+
+            is_h_provided = true;
+            watch.record("copy_header_h");
+        }
+    }
+
     // None=(unknown, no engine), Some=(version of Godot). Later verified by header itself.
     // Even though we don't support 4.0 anymore, we still detect it, for better error messages.
     if !is_h_provided {
@@ -85,6 +111,30 @@ pub fn write_gdextension_headers(
     watch.record("generate_header_rs");
 }
 
+/// Reads a user-supplied API JSON, e.g. from an engine fork, instead of dumping it from the binary.
+fn load_custom_json(json_path: &Path, watch: &mut StopWatch) -> String {
+    println!(
+        "Use extension API JSON from {}: '{}'",
+        custom_api::JSON_ENV_VAR,
+        json_path.display()
+    );
+
+    let json = custom_api::read_api_file(json_path);
+    let json_version = custom_api::parse_json_version(&json)
+        .unwrap_or_else(|e| panic!("{}", custom_api::json_error(json_path, &e)));
+    watch.record("read_api_json");
+
+    // If a binary is explicitly specified as well, it's likely the one running the extension; make sure it's compatible.
+    if std::env::var_os("GODOT4_BIN").is_some() {
+        let bin_version = read_godot_version(&locate_godot_binary());
+        custom_api::check_binary_version(&json_version, &bin_version)
+            .unwrap_or_else(|e| panic!("{}", custom_api::json_error(json_path, &e)));
+        watch.record("check_godot_version");
+    }
+
+    json
+}
+
 /*
 fn has_version_changed(current_version: &str) -> bool {
     let version_path = Path::new(GODOT_VERSION_PATH);
@@ -249,7 +299,10 @@ pub(crate) fn locate_godot_binary() -> PathBuf {
     } else {
         panic!(
             "gdext with `api-custom` feature requires 'godot4' executable or a GODOT4_BIN \
-                 environment variable (with the path to the executable)."
+                 environment variable (with the path to the executable).\n\
+                 Alternatively, provide previously dumped files via {} and {}.",
+            custom_api::JSON_ENV_VAR,
+            custom_api::HEADER_ENV_VAR
         )
     }
 }
@@ -17,6 +17,7 @@ use std::path::Path;
 pub use watch::StopWatch;
 
 mod import;
+mod wasm_flags;
 
 // This is outside of `godot_version` to allow us to use it even when we don't have the `api-custom`
 // feature enabled.
@@ -41,7 +42,10 @@ pub struct GodotVersion {
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Custom mode: Regenerate all files
 
-// This file is explicitly included in unit tests. Needs regex dependency.
+// These files are explicitly included in unit tests. Need regex dependency.
+#[cfg(all(test, not(feature = "api-custom")))]
+#[allow(dead_code)] // Only the parsing functions are tested.
+mod custom_api;
 #[cfg(test)]
 mod godot_version;
 
@@ -51,6 +55,7 @@ mod depend_on_custom {
     use super::*;
     use std::borrow::Cow;
 
+    pub(crate) mod custom_api;
     pub(crate) mod godot_exe;
     pub(crate) mod godot_version;
     pub(crate) mod header_gen;
@@ -69,7 +74,11 @@ mod depend_on_custom {
     }
 
     pub(crate) fn get_godot_version() -> GodotVersion {
-        godot_exe::read_godot_version(&godot_exe::locate_godot_binary())
+        // A user-supplied JSON determines the API level, even if a Godot binary is available.
+        match custom_api::locate_api_file(custom_api::JSON_ENV_VAR) {
+            Some(json_path) => custom_api::read_json_version(&json_path),
+            None => godot_exe::read_godot_version(&godot_exe::locate_godot_binary()),
+        }
     }
 }
 
@@ -183,16 +192,61 @@ pub fn emit_godot_version_cfg() {
 pub fn emit_wasm_nothreads_cfg() {
     println!(r#"cargo:rustc-check-cfg=cfg(wasm_nothreads, values(none()))"#);
 
+    #[cfg(feature = "experimental-wasm-nothreads")]
+    if is_wasm_target() {
+        println!(r#"cargo:rustc-cfg=wasm_nothreads"#);
+    }
+}
+
+/// When compiling to Wasm, emit build warnings for compiler flags that don't match Godot's web export.
+///
+/// The required flags can't be set from a build script, so the warnings include a `.cargo/config.toml` section to copy.
+pub fn emit_wasm_flag_warnings() {
+    if !is_wasm_target() {
+        return;
+    }
+
+    let env = |name: &str| std::env::var(name).unwrap_or_default();
+    let target_os = env("CARGO_CFG_TARGET_OS");
+    let target_features = env("CARGO_CFG_TARGET_FEATURE");
+    let rustflags = env("CARGO_ENCODED_RUSTFLAGS");
+    let nothreads = cfg!(feature = "experimental-wasm-nothreads");
+
+    let problems = wasm_flags::find_flag_problems(&wasm_flags::WasmTarget {
+        target_os: &target_os,
+        target_features: &target_features,
+        rustflags: &rustflags,
+        nothreads,
+    });
+
+    if problems.is_empty() {
+        return;
+    }
+
+    for problem in problems {
+        println!("cargo:warning=godot-rust Wasm build: {problem}");
+    }
+
+    let suggested = if nothreads {
+        wasm_flags::NOTHREADS_RUSTFLAGS
+    } else {
+        wasm_flags::THREADS_RUSTFLAGS
+    };
+
+    println!("cargo:warning=Suggested `.cargo/config.toml` section:");
+    println!("cargo:warning=  [target.wasm32-unknown-emscripten]");
+    for line in suggested.lines() {
+        println!("cargo:warning=  {line}");
+    }
+}
+
+fn is_wasm_target() -> bool {
     // The environment variable for target family has a list of applicable families separated by commas.
     // For Emscripten in particular, this can be "unix,wasm". Therefore, to check for the Wasm target, we must check each item in the list.
-    #[cfg(feature = "experimental-wasm-nothreads")]
-    if std::env::var("CARGO_CFG_TARGET_FAMILY")
+    std::env::var("CARGO_CFG_TARGET_FAMILY")
         .expect("target family environment variable")
         .split(',')
         .any(|family| family == "wasm")
-    {
-        println!(r#"cargo:rustc-cfg=wasm_nothreads"#);
-    }
 }
 
 // Function for safely removal of build directory. Workaround for errors happening during CI builds:
@@ -0,0 +1,126 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Checks the compiler flags of Wasm builds, which must match how Godot's web export loads GDExtensions.
+
+/// Flags for `[target.wasm32-unknown-emscripten]` in `.cargo/config.toml`, with Wasm threads (Godot's default web export).
+pub(crate) const THREADS_RUSTFLAGS: &str = r#"rustflags = [
+    "-C", "link-args=-sSIDE_MODULE=2",
+    "-C", "link-args=-pthread",
+    "-C", "target-feature=+atomics",
+    "-Zlink-native-libraries=no",
+    "-Cllvm-args=-enable-emscripten-cxx-exceptions=0",
+]"#;
+
+/// Flags for `[target.wasm32-unknown-emscripten]` in `.cargo/config.toml`, without Wasm threads (`experimental-wasm-nothreads`).
+pub(crate) const NOTHREADS_RUSTFLAGS: &str = r#"rustflags = [
+    "-C", "link-args=-sSIDE_MODULE=2",
+    "-Zlink-native-libraries=no",
+    "-Cllvm-args=-enable-emscripten-cxx-exceptions=0",
+]"#;
+
+/// Target configuration of the crate being built, as seen by a build script.
+pub(crate) struct WasmTarget<'a> {
+    pub target_os: &'a str,
+    /// Comma-separated, as in `CARGO_CFG_TARGET_FEATURE`.
+    pub target_features: &'a str,
+    /// Separated by `\x1f`, as in `CARGO_ENCODED_RUSTFLAGS`.
+    pub rustflags: &'a str,
+    pub nothreads: bool,
+}
+
+/// Returns one message per flag that doesn't fit Godot's web export. Empty if the configuration is fine.
+pub(crate) fn find_flag_problems(target: &WasmTarget) -> Vec<String> {
+    if target.target_os != "emscripten" {
+        return vec![format!(
+            "Wasm target OS is `{}`, but Godot loads web GDExtensions as Emscripten side modules; \
+            build with `--target wasm32-unknown-emscripten`",
+            target.target_os
+        )];
+    }
+
+    // Flags can be split across entries ("-C", "link-args=...") or combined ("-Clink-args=..."); substrings catch both.
+    let rustflags = target.rustflags.replace('\x1f', " ");
+    let has_atomics = target
+        .target_features
+        .split(',')
+        .any(|feature| feature == "atomics");
+    let has_pthread = rustflags.contains("-pthread");
+
+    let mut problems = Vec::new();
+    if !rustflags.contains("SIDE_MODULE=2") {
+        problems.push(
+            "missing `-C link-args=-sSIDE_MODULE=2`; Godot cannot load the library otherwise"
+                .to_string(),
+        );
+    }
+
+    if target.nothreads {
+        if has_atomics || has_pthread {
+            problems.push(
+                "`experimental-wasm-nothreads` is enabled, but `-C link-args=-pthread` or `+atomics` is set; \
+                remove them, or disable the feature for web exports with threads"
+                    .to_string(),
+            );
+        }
+    } else if !has_atomics || !has_pthread {
+        problems.push(
+            "Wasm threads need `-C link-args=-pthread` and `-C target-feature=+atomics`; \
+            for web exports without threads, enable the `experimental-wasm-nothreads` feature instead"
+                .to_string(),
+        );
+    }
+
+    problems
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_wasm_flag_problems() {
+    let threads_flags = "-C\x1flink-args=-sSIDE_MODULE=2\x1f-C\x1flink-args=-pthread\x1f-C\x1ftarget-feature=+atomics";
+    let nothreads_flags = "-Clink-args=-sSIDE_MODULE=2\x1f-Zlink-native-libraries=no";
+
+    let check = |target_os, target_features, rustflags, nothreads| {
+        find_flag_problems(&WasmTarget {
+            target_os,
+            target_features,
+            rustflags,
+            nothreads,
+        })
+    };
+
+    assert!(check("emscripten", "atomics,bulk-memory", threads_flags, false).is_empty());
+    assert!(check("emscripten", "bulk-memory", nothreads_flags, true).is_empty());
+
+    let cases = [
+        (
+            check("unknown", "", threads_flags, false),
+            "wasm32-unknown-emscripten",
+        ),
+        (
+            check("emscripten", "atomics", "-Clink-args=-pthread", false),
+            "SIDE_MODULE",
+        ),
+        (
+            check("emscripten", "", nothreads_flags, false),
+            "experimental-wasm-nothreads",
+        ),
+        (
+            check("emscripten", "atomics", threads_flags, true),
+            "remove them",
+        ),
+    ];
+
+    for (problems, expected) in cases {
+        assert_eq!(problems.len(), 1, "{problems:?}");
+        assert!(
+            problems[0].contains(expected),
+            "{problems:?} should contain `{expected}`"
+        );
+    }
+}
@@ -32,6 +32,8 @@ pub struct Context<'a> {
 
 impl<'a> Context<'a> {
     pub fn build_from_api(api: &'a JsonExtensionApi) -> Self {
+        special_cases::init_class_selection(api);
+
         let mut ctx = Self::default();
 
         for class in api.singletons.iter() {
@@ -46,8 +46,7 @@ pub fn generate_builtin_class_files(
         // let godot_class_name = &class.name().godot_ty;
         let module_name = class.mod_name();
 
-        let variant_shout_name = util::ident(variant.godot_shout_name());
-        let generated_class = make_builtin_class(class, &variant_shout_name, ctx);
+        let generated_class = make_builtin_class(class, ctx);
         let file_contents = generated_class.code;
 
         let out_path = gen_path.join(format!("{}.rs", module_name.rust_mod));
@@ -88,11 +87,7 @@ pub fn make_builtin_module_file(classes_and_modules: Vec<GeneratedBuiltinModule>
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Implementation
 
-fn make_builtin_class(
-    class: &BuiltinClass,
-    variant_shout_name: &Ident,
-    ctx: &mut Context,
-) -> GeneratedBuiltin {
+fn make_builtin_class(class: &BuiltinClass, ctx: &mut Context) -> GeneratedBuiltin {
     let godot_name = &class.name().godot_ty;
 
     let RustTy::BuiltinIdent {
@@ -109,7 +104,7 @@ fn make_builtin_class(
     let (
         FnDefinitions { functions: inner_methods, .. },
         FnDefinitions { functions: outer_methods, .. },
-    ) = make_builtin_methods(class, variant_shout_name, &class.methods, ctx);
+    ) = make_builtin_methods(class, &class.methods, ctx);
 
     let imports = util::make_imports();
     let enums = enums::make_enums(&class.enums, &TokenStream::new());
@@ -149,7 +144,6 @@ fn make_builtin_class(
 /// Returns 2 definition packs, one for the `Inner*` methods, and one for those ending up directly in the public-facing (outer) class.
 fn make_builtin_methods(
     builtin_class: &BuiltinClass,
-    variant_shout_name: &Ident,
     methods: &[BuiltinMethod],
     ctx: &mut Context,
 ) -> (FnDefinitions, FnDefinitions) {
@@ -158,17 +152,13 @@ fn make_builtin_methods(
     let inner_defs = methods
         .iter()
         .filter(|&method| !method.is_exposed_in_outer)
-        .map(|method| {
-            make_builtin_method_definition(builtin_class, variant_shout_name, method, ctx)
-        });
+        .map(|method| make_builtin_method_definition(builtin_class, method, ctx));
     let inner_defs = FnDefinitions::expand(inner_defs);
 
     let outer_defs = methods
         .iter()
         .filter(|&method| method.is_exposed_in_outer)
-        .map(|method| {
-            make_builtin_method_definition(builtin_class, variant_shout_name, method, ctx)
-        });
+        .map(|method| make_builtin_method_definition(builtin_class, method, ctx));
     let outer_defs = FnDefinitions::expand(outer_defs);
 
     (inner_defs, outer_defs)
@@ -210,11 +200,10 @@ fn method_safety_doc(class_name: &TyName, method: &BuiltinMethod) -> Option<Toke
 
 fn make_builtin_method_definition(
     builtin_class: &BuiltinClass,
-    variant_shout_name: &Ident,
     method: &BuiltinMethod,
     ctx: &mut Context,
 ) -> FnDefinition {
-    let FnDirection::Outbound { hash } = method.direction() else {
+    let FnDirection::Outbound { .. } = method.direction() else {
         unreachable!("builtin methods are never virtual")
     };
 
@@ -222,23 +211,8 @@ fn make_builtin_method_definition(
     let builtin_name_str = builtin_name.rust_ty.to_string();
     let method_name_str = method.godot_name();
 
-    let fptr_access = if cfg!(feature = "codegen-lazy-fptrs") {
-        let variant_type = quote! { sys::VariantType::#variant_shout_name };
-        let variant_type_str = &builtin_name.godot_ty;
-
-        quote! {
-            fptr_by_key(sys::lazy_keys::BuiltinMethodKey {
-                variant_type: #variant_type,
-                variant_type_str: #variant_type_str,
-                method_name: #method_name_str,
-                hash: #hash,
-            })
-        }
-    } else {
-        let table_index = ctx.get_table_index(&MethodTableKey::from_builtin(builtin_class, method));
-
-        quote! { fptr_by_index(#table_index) }
-    };
+    let table_index = ctx.get_table_index(&MethodTableKey::from_builtin(builtin_class, method));
+    let fptr_access = quote! { fptr_by_index(#table_index) };
 
     let ffi_arg_in = if method.is_exposed_in_outer {
         // TODO create dedicated method (in GodotFfi?) and replace similar occurrences everywhere.
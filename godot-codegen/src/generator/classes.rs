@@ -15,7 +15,7 @@ use crate::models::domain::{
     ModName, TyName,
 };
 use crate::util::{ident, make_string_name};
-use crate::{conv, util, SubmitFn};
+use crate::{conv, special_cases, util, SubmitFn};
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 use std::path::Path;
@@ -171,6 +171,14 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
             Some(instance_id)
         }
 
+        /// New `Gd` pointing to this object, for manual extensions that need to pass `self` on.
+        #[allow(dead_code)]
+        pub(crate) fn __to_gd(&self) -> Gd<Self> {
+            // SAFETY: see __checked_id().
+            let rtti = unsafe { self.rtti.as_ref().unwrap_unchecked() };
+            Gd::from_instance_id(rtti.instance_id())
+        }
+
         #[doc(hidden)]
         pub fn __object_ptr(&self) -> sys::GDExtensionObjectPtr {
             self.object_ptr
@@ -322,8 +330,11 @@ fn make_class_module_file(classes_and_modules: Vec<GeneratedClassModule>) -> Tok
         }
     });
 
+    let excluded_stubs = make_excluded_class_stubs();
+
     quote! {
         #( #class_decls )*
+        #excluded_stubs
 
         /// Notification enums for all classes.
         pub mod notify {
@@ -338,6 +349,45 @@ fn make_class_module_file(classes_and_modules: Vec<GeneratedClassModule>) -> Tok
     }
 }
 
+/// Placeholders for classes excluded by minimal codegen, so that using them is a compile error explaining how to enable them.
+///
+/// Each class becomes a type alias resolving through [`ExcludedClass`][crate::private::ExcludedClass] (in godot-core), which is never
+/// implemented; its `#[diagnostic::on_unimplemented]` message names the class. The alias is only resolved when used, so unused
+/// placeholders cost nothing.
+fn make_excluded_class_stubs() -> TokenStream {
+    let class_names: Vec<TyName> = special_cases::get_excluded_class_names()
+        .iter()
+        .filter(|godot_name| !special_cases::is_godot_type_deleted(godot_name))
+        .map(|godot_name| TyName::from_godot(godot_name))
+        .collect();
+
+    if class_names.is_empty() {
+        return TokenStream::new();
+    }
+
+    let markers = class_names.iter().map(|class_name| {
+        quote! {
+            pub struct #class_name;
+        }
+    });
+
+    let aliases = class_names.iter().map(|class_name| {
+        quote! {
+            #[doc(hidden)]
+            pub type #class_name = <excluded::#class_name as crate::private::ExcludedClass>::Class;
+        }
+    });
+
+    quote! {
+        #[doc(hidden)]
+        pub mod excluded {
+            #( #markers )*
+        }
+
+        #( #aliases )*
+    }
+}
+
 fn make_constructor_and_default(
     class: &Class,
     ctx: &Context,
@@ -477,13 +527,12 @@ fn make_class_method_definition(
     cfg_attributes: &TokenStream,
     ctx: &mut Context,
 ) -> FnDefinition {
-    let FnDirection::Outbound { hash } = method.direction() else {
+    let FnDirection::Outbound { .. } = method.direction() else {
         return FnDefinition::none();
     };
 
     let rust_class_name = class.name().rust_ty.to_string();
     let rust_method_name = method.name();
-    let godot_method_name = method.godot_name();
 
     let receiver = functions_common::make_receiver(method.qualifier(), quote! { self.object_ptr });
 
@@ -495,18 +544,7 @@ fn make_class_method_definition(
         quote! { self.__checked_id() }
     };
 
-    let fptr_access = if cfg!(feature = "codegen-lazy-fptrs") {
-        let godot_class_name = &class.name().godot_ty;
-        quote! {
-            fptr_by_key(sys::lazy_keys::ClassMethodKey {
-                class_name: #godot_class_name,
-                method_name: #godot_method_name,
-                hash: #hash,
-            })
-        }
-    } else {
-        quote! { fptr_by_index(#table_index) }
-    };
+    let fptr_access = quote! { fptr_by_index(#table_index) };
 
     let object_ptr = &receiver.ffi_arg;
     let ptrcall_invocation = quote! {
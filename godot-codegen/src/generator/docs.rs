@@ -100,7 +100,17 @@ pub fn make_virtual_trait_doc(trait_name_str: &str, class_name: &TyName) -> Stri
         \n\n\
         These methods represent constructors (`init`) or callbacks invoked by the engine.\
         \n\n\
-        See also [Godot docs for `{godot_ty}` methods]({online_link}).\n\n{notes}"
+        See also [Godot docs for `{godot_ty}` methods]({online_link}).\
+        \n\n\
+        # Base class behavior\
+        \n\n\
+        Overriding a callback does not replace the engine's own logic for it. For example, a `Node` still emits its `ready` signal \
+        when you implement `ready()`. There is thus no base (\"super\") implementation to call, and GDExtension does not expose one. \
+        Engine methods reachable through `base()` or `base_mut()` are separate functions; some of them, such as `Object::to_string()`, \
+        dispatch back to your override and would recurse.\
+        \n\n\
+        A few callbacks replace an engine fallback that is only used when they are _not_ overridden. \
+        Those methods document how to reproduce the fallback.\n\n{notes}"
     )
 }
 
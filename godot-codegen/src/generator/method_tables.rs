@@ -78,7 +78,7 @@ pub fn make_class_method_table(
             let get_method_bind = crate::interface_fn!(classdb_get_method_bind);
             crate::load_class_method(
                 get_method_bind,
-                &mut inner.string_cache,
+                &mut self.string_cache.lock(),
                 None,
                 key.class_name,
                 key.method_name,
@@ -121,8 +121,8 @@ pub fn make_builtin_method_table(api: &ExtensionApi, ctx: &mut Context) -> Token
             let fetch_fptr = crate::interface_fn!(variant_get_ptr_builtin_method);
             crate::load_builtin_method(
                 fetch_fptr,
-                &mut inner.string_cache,
-                key.variant_type.sys(),
+                &mut self.string_cache.lock(),
+                key.variant_type,
                 key.variant_type_str,
                 key.method_name,
                 key.hash
@@ -191,7 +191,7 @@ struct NamedMethodTable {
     method_count: usize,
 }
 
-#[allow(dead_code)] // Individual fields would need to be cfg'ed with: feature = "codegen-lazy-fptrs".
+#[allow(dead_code)] // Individual fields would need to be cfg'ed with: feature = "codegen-eager-fptrs".
 struct IndexedMethodTable {
     table_name: Ident,
     imports: TokenStream,
@@ -207,9 +207,11 @@ struct IndexedMethodTable {
     method_count: usize,
 }
 
-#[cfg_attr(feature = "codegen-lazy-fptrs", allow(dead_code))]
 struct MethodInit {
+    #[cfg_attr(not(feature = "codegen-eager-fptrs"), allow(dead_code))]
     method_init: TokenStream,
+    #[cfg_attr(feature = "codegen-eager-fptrs", allow(dead_code))]
+    lazy_key: TokenStream,
     index: usize,
 }
 
@@ -219,7 +221,7 @@ impl ToTokens for MethodInit {
     }
 }
 
-#[cfg_attr(feature = "codegen-lazy-fptrs", allow(dead_code))]
+#[cfg_attr(not(feature = "codegen-eager-fptrs"), allow(dead_code))]
 struct MethodInitGroup {
     class_name: Ident,
     class_var_init: Option<TokenStream>,
@@ -247,7 +249,7 @@ impl MethodInitGroup {
         }
     }
 
-    #[cfg(not(feature = "codegen-lazy-fptrs"))]
+    #[cfg(feature = "codegen-eager-fptrs")]
     fn function_name(&self) -> Ident {
         format_ident!("load_{}_methods", self.class_name)
     }
@@ -256,7 +258,6 @@ impl MethodInitGroup {
 struct AccessorMethod {
     name: Ident,
     index: usize,
-    lazy_key: TokenStream,
 }
 
 /// Generate code for a method table based on shared layout.
@@ -302,7 +303,7 @@ fn make_named_method_table(info: NamedMethodTable) -> TokenStream {
     }
 }
 
-#[cfg(not(feature = "codegen-lazy-fptrs"))]
+#[cfg(feature = "codegen-eager-fptrs")]
 fn make_method_table(info: IndexedMethodTable) -> TokenStream {
     let IndexedMethodTable {
         table_name,
@@ -323,26 +324,7 @@ fn make_method_table(info: IndexedMethodTable) -> TokenStream {
     let unused_attr = (method_count == 0).then(|| quote! { #[allow(unused_variables)] });
     let named_method_api = make_named_accessors(&named_accessors, &fptr_type);
 
-    // Make sure methods are complete and in order of index.
-    assert_eq!(
-        method_init_groups
-            .iter()
-            .map(|group| group.method_inits.len())
-            .sum::<usize>(),
-        method_count,
-        "number of methods does not match count"
-    );
-
-    if let Some(last) = method_init_groups.last() {
-        assert_eq!(
-            last.method_inits.last().unwrap().index,
-            method_count - 1,
-            "last method should have highest index (table {})",
-            table_name
-        );
-    } else {
-        assert_eq!(method_count, 0, "empty method table should have count 0");
-    }
+    validate_method_indices(&table_name, &method_init_groups, method_count);
 
     let method_load_inits = method_init_groups.iter().map(|group| {
         let func = group.function_name();
@@ -416,7 +398,7 @@ fn make_method_table(info: IndexedMethodTable) -> TokenStream {
     }
 }
 
-#[cfg(feature = "codegen-lazy-fptrs")]
+#[cfg(not(feature = "codegen-eager-fptrs"))]
 fn make_method_table(info: IndexedMethodTable) -> TokenStream {
     let IndexedMethodTable {
         table_name,
@@ -425,7 +407,7 @@ fn make_method_table(info: IndexedMethodTable) -> TokenStream {
         pre_init_code: _,
         fptr_type,
         fetch_fptr_type: _,
-        method_init_groups: _,
+        method_init_groups,
         lazy_key_type,
         lazy_method_init,
         named_accessors,
@@ -433,28 +415,29 @@ fn make_method_table(info: IndexedMethodTable) -> TokenStream {
         method_count,
     } = info;
 
-    // Editor table can be empty, if the Godot binary is compiled without editor.
-    let unused_attr = (method_count == 0).then(|| quote! { #[allow(unused_variables)] });
     let named_method_api = make_named_accessors(&named_accessors, &fptr_type);
 
-    // Assumes that inits already have a trailing comma.
-    // This is necessary because some generators emit multiple lines (statements) per element.
+    validate_method_indices(&table_name, &method_init_groups, method_count);
+
+    // Validated above: flattening the groups yields the keys in order of index.
+    let lazy_keys = method_init_groups
+        .iter()
+        .flat_map(|group| group.method_inits.iter().map(|init| &init.lazy_key));
+
+    // Assumes that keys already have a trailing comma.
     quote! {
         #imports
-        use crate::StringCache;
-        use std::collections::HashMap;
-        use std::cell::RefCell;
-
-        // Exists to be stored inside RefCell.
-        struct InnerTable {
-            // 'static because at this point, the interface and lifecycle tables are globally available.
-            string_cache: StringCache<'static>,
-            function_pointers: HashMap<#lazy_key_type, #fptr_type>,
-        }
+        use crate::{LazyFnPtr, SharedStringCache};
+
+        // Data rather than code: describes how to resolve each index, only read on the first call of a method.
+        static METHOD_KEYS: [#lazy_key_type; #method_count] = [
+            #( #lazy_keys )*
+        ];
 
         // Note: get_method_bind and other function pointers could potentially be stored as fields in table, to avoid interface_fn!.
         pub struct #table_name {
-            inner: RefCell<InnerTable>,
+            string_cache: SharedStringCache,
+            function_pointers: Box<[LazyFnPtr<#fptr_type>]>,
         }
 
         impl #table_name {
@@ -464,28 +447,25 @@ fn make_method_table(info: IndexedMethodTable) -> TokenStream {
             // TODO: Figure out the right safety preconditions. This currently does not have any because incomplete safety docs
             // can cause issues with people assuming they are sufficient.
             #[allow(clippy::missing_safety_doc)]
-            #unused_attr
             pub unsafe fn load() -> Self {
-                // SAFETY: interface and lifecycle tables are initialized at this point, so we can get 'static references to them.
-                let (interface, lifecycle_table) = unsafe {
-                    (crate::get_interface(), crate::builtin_lifecycle_api())
-                };
-
                 Self {
-                    inner: RefCell::new(InnerTable {
-                        string_cache: StringCache::new(interface, lifecycle_table),
-                        function_pointers: HashMap::new(),
-                    }),
+                    // SAFETY: interface and lifecycle tables are initialized at this point; the table is dropped on deinitialization.
+                    string_cache: unsafe { SharedStringCache::new() },
+                    function_pointers: (0..#method_count).map(|_| LazyFnPtr::new()).collect(),
                 }
             }
 
             #[inline(always)]
-            pub fn fptr_by_key(&self, key: #lazy_key_type) -> #fptr_type {
-                let mut guard = self.inner.borrow_mut();
-                let inner = &mut *guard;
-                *inner.function_pointers.entry(key.clone()).or_insert_with(|| {
-                    #lazy_method_init
-                })
+            pub fn fptr_by_index(&self, index: usize) -> #fptr_type {
+                // SAFETY: indices are statically generated and guaranteed to be in range.
+                let slot = unsafe { self.function_pointers.get_unchecked(index) };
+
+                slot.get_or_init(|| self.load_fptr(index))
+            }
+
+            fn load_fptr(&self, index: usize) -> #fptr_type {
+                let key = &METHOD_KEYS[index];
+                #lazy_method_init
             }
 
             #named_method_api
@@ -493,30 +473,44 @@ fn make_method_table(info: IndexedMethodTable) -> TokenStream {
     }
 }
 
+/// Makes sure methods are complete and in order of index.
+fn validate_method_indices(
+    table_name: &Ident,
+    method_init_groups: &[MethodInitGroup],
+    method_count: usize,
+) {
+    assert_eq!(
+        method_init_groups
+            .iter()
+            .map(|group| group.method_inits.len())
+            .sum::<usize>(),
+        method_count,
+        "number of methods does not match count"
+    );
+
+    if let Some(last) = method_init_groups.last() {
+        assert_eq!(
+            last.method_inits.last().unwrap().index,
+            method_count - 1,
+            "last method should have highest index (table {})",
+            table_name
+        );
+    } else {
+        assert_eq!(method_count, 0, "empty method table should have count 0");
+    }
+}
+
 /// For index-based method tables, have select methods exposed by name for internal use.
 fn make_named_accessors(accessors: &[AccessorMethod], fptr: &TokenStream) -> TokenStream {
     let mut result_api = TokenStream::new();
 
     for accessor in accessors {
-        let AccessorMethod {
-            name,
-            index,
-            lazy_key,
-        } = accessor;
+        let AccessorMethod { name, index } = accessor;
 
-        let code = if cfg!(feature = "codegen-lazy-fptrs") {
-            quote! {
-                #[inline(always)]
-                pub fn #name(&self) -> #fptr {
-                    self.fptr_by_key(#lazy_key)
-                }
-            }
-        } else {
-            quote! {
-                #[inline(always)]
-                pub fn #name(&self) -> #fptr {
-                    self.fptr_by_index(#index)
-                }
+        let code = quote! {
+            #[inline(always)]
+            pub fn #name(&self) -> #fptr {
+                self.fptr_by_index(#index)
             }
         };
 
@@ -543,24 +537,19 @@ fn populate_class_methods(table: &mut IndexedMethodTable, class: &Class, ctx: &m
         let index = ctx.get_table_index(&MethodTableKey::from_class(class, method));
 
         let method_init = make_class_method_init(method, hash, &class_var, class_ty);
-        method_inits.push(MethodInit { method_init, index });
+        let lazy_key = make_class_method_lazy_key(method, hash, class_ty);
+        method_inits.push(MethodInit {
+            method_init,
+            lazy_key,
+            index,
+        });
         table.method_count += 1;
 
         // If requested, add a named accessor for this method.
         if special_cases::is_named_accessor_in_table(class_ty, method.godot_name()) {
-            let class_name_str = class_ty.godot_ty.as_str();
-            let method_name_str = method.name();
-
             table.named_accessors.push(AccessorMethod {
                 name: make_table_accessor_name(class_ty, method),
                 index,
-                lazy_key: quote! {
-                    crate::lazy_keys::ClassMethodKey {
-                        class_name: #class_name_str,
-                        method_name: #method_name_str,
-                        hash: #hash,
-                    }
-                },
             });
         }
     }
@@ -594,27 +583,19 @@ fn populate_builtin_methods(
         let index = ctx.get_table_index(&MethodTableKey::from_builtin(builtin_class, method));
 
         let method_init = make_builtin_method_init(builtin, method, index);
-        method_inits.push(MethodInit { method_init, index });
+        let lazy_key = make_builtin_method_lazy_key(builtin, method);
+        method_inits.push(MethodInit {
+            method_init,
+            lazy_key,
+            index,
+        });
         table.method_count += 1;
 
         // If requested, add a named accessor for this method.
         if special_cases::is_named_accessor_in_table(builtin_ty, method.godot_name()) {
-            let variant_type = builtin.sys_variant_type();
-            let variant_type_str = builtin.godot_original_name();
-            let method_name_str = method.name();
-            let hash = method.hash();
-
             table.named_accessors.push(AccessorMethod {
                 name: make_table_accessor_name(builtin_ty, method),
                 index,
-                lazy_key: quote! {
-                    crate::lazy_keys::BuiltinMethodKey {
-                        variant_type: #variant_type,
-                        variant_type_str: #variant_type_str,
-                        method_name: #method_name_str,
-                        hash: #hash,
-                    }
-                },
             });
         }
     }
@@ -677,6 +658,35 @@ fn make_builtin_method_init(
     }
 }
 
+fn make_class_method_lazy_key(method: &ClassMethod, hash: i64, class_ty: &TyName) -> TokenStream {
+    let class_name_str = class_ty.godot_ty.as_str();
+    let method_name_str = method.godot_name();
+
+    quote! {
+        crate::lazy_keys::ClassMethodKey {
+            class_name: #class_name_str,
+            method_name: #method_name_str,
+            hash: #hash,
+        },
+    }
+}
+
+fn make_builtin_method_lazy_key(builtin: &BuiltinVariant, method: &BuiltinMethod) -> TokenStream {
+    let variant_type = builtin.sys_variant_type();
+    let variant_type_str = builtin.godot_original_name();
+    let method_name_str = method.name();
+    let hash = method.hash();
+
+    quote! {
+        crate::lazy_keys::BuiltinMethodKey {
+            variant_type: crate::#variant_type,
+            variant_type_str: #variant_type_str,
+            method_name: #method_name_str,
+            hash: #hash,
+        },
+    }
+}
+
 /// Lookup key for indexed method tables.
 // Could potentially save a lot of string allocations with lifetimes.
 // See also crate::lazy_keys.
@@ -10,6 +10,7 @@ use crate::generator::{docs, functions_common};
 use crate::models::domain::{
     ApiView, Class, ClassLike, ClassMethod, FnQualifier, Function, TyName,
 };
+use crate::special_cases;
 use crate::util::ident;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
@@ -66,6 +67,10 @@ fn make_special_virtual_methods(notification_enum_name: &Ident) -> TokenStream {
         ///
         /// Override this method to define how the instance is represented as a string.
         /// Used by `impl Display for Gd<T>`, as well as `str()` and `print()` in GDScript.
+        ///
+        /// This replaces Godot's default representation, such as `<Node#12345>`. Do not call `self.base().to_string()` here: it
+        /// dispatches back to this method and recurses endlessly. To extend the default format, build it from `Self::class_name()`
+        /// and `self.base().instance_id()`.
         fn to_string(&self) -> crate::builtin::GString {
             unimplemented!()
         }
@@ -174,7 +179,14 @@ fn make_virtual_method(method: &ClassMethod) -> Option<TokenStream> {
     );
 
     // Virtual methods have no builders.
-    Some(definition.into_functions_only())
+    let functions = definition.into_functions_only();
+
+    let extra_docs =
+        special_cases::get_virtual_method_extra_docs(&method.surrounding_class, method.name());
+    Some(quote! {
+        #( #[doc = #extra_docs] )*
+        #functions
+    })
 }
 
 fn make_all_virtual_methods(
@@ -75,6 +75,12 @@ impl ExtensionApi {
             }
         }
 
+        assert!(
+            !result.is_empty(),
+            "extension_api.json has no builtin sizes for build configurations {:?}",
+            BuildConfiguration::all_applicable()
+        );
+
         result
     }
 }
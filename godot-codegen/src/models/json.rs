@@ -41,6 +41,8 @@ pub struct JsonHeader {
     pub version_status: String,
     pub version_build: String,
     pub version_full_name: String,
+    /// `single` or `double`. Not present in older versions.
+    pub precision: Option<String>,
 }
 
 #[derive(DeJson)]
@@ -265,10 +267,79 @@ pub fn load_extension_api(watch: &mut godot_bindings::StopWatch) -> JsonExtensio
     let json = godot_bindings::load_gdextension_json(watch);
     let json_str: &str = json.as_ref();
 
-    let model: JsonExtensionApi =
-        DeJson::deserialize_json(json_str).expect("failed to deserialize JSON");
+    let model = parse_extension_api(json_str)
+        .and_then(|model| validate_precision(&model.header).map(|_| model))
+        .unwrap_or_else(|e| panic!("{e}"));
     watch.record("deserialize_json");
 
     println!("Parsed extension_api.json for version {:?}", model.header);
     model
 }
+
+/// Deserializes and validates the API JSON, which may come from a patched engine (`api-custom`).
+pub(crate) fn parse_extension_api(json_str: &str) -> Result<JsonExtensionApi, String> {
+    let model: JsonExtensionApi = DeJson::deserialize_json(json_str).map_err(|e| {
+        format!(
+            "failed to deserialize extension_api.json: {e}\n\
+            If the JSON is user-supplied, make sure it is the unmodified output of `godot --dump-extension-api`."
+        )
+    })?;
+
+    let class_names: std::collections::HashSet<&str> =
+        model.classes.iter().map(|c| c.name.as_str()).collect();
+
+    for class in model.classes.iter() {
+        if !matches!(class.api_type.as_str(), "core" | "editor") {
+            return Err(format!(
+                "extension_api.json: class `{}` has API type `{}`, expected `core` or `editor`",
+                class.name, class.api_type
+            ));
+        }
+
+        if let Some(base) = class.inherits.as_deref() {
+            if !class_names.contains(base) {
+                return Err(format!(
+                    "extension_api.json: class `{}` inherits unknown class `{base}`",
+                    class.name
+                ));
+            }
+        }
+    }
+
+    Ok(model)
+}
+
+/// Checks that the JSON was dumped from an engine with the same float precision as selected by the `double-precision` feature.
+pub(crate) fn validate_precision(header: &JsonHeader) -> Result<(), String> {
+    let json_is_double = match header.precision.as_deref() {
+        Some("double") => true,
+        Some("single") => false,
+        Some(other) => {
+            return Err(format!(
+                "extension_api.json: unknown precision `{other}`, expected `single` or `double`"
+            ))
+        }
+        // Older versions don't declare it; mismatches are still detected on initialization.
+        None => return Ok(()),
+    };
+
+    let gdext_is_double = cfg!(feature = "double-precision");
+    if json_is_double == gdext_is_double {
+        return Ok(());
+    }
+
+    let describe = |is_double: bool| {
+        if is_double {
+            "double precision (build configurations double_32/double_64)"
+        } else {
+            "single precision (build configurations float_32/float_64)"
+        }
+    };
+
+    Err(format!(
+        "extension_api.json was dumped from a Godot build with {}, but godot-rust is compiled for {}.\n\
+        Cargo feature `double-precision` must be used if and only if Godot is compiled with `precision=double`.",
+        describe(json_is_double),
+        describe(gdext_is_double),
+    ))
+}
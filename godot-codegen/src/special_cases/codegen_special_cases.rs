@@ -12,6 +12,7 @@
 use crate::context::Context;
 use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonUtilityFunction};
 use crate::special_cases;
+use crate::JsonExtensionApi;
 
 pub(crate) fn is_builtin_method_excluded(method: &JsonBuiltinMethod) -> bool {
     // The `cfg` below becomes `false` for api > 4.1 so clippy would complain it's always false.
@@ -26,7 +27,7 @@ pub(crate) fn is_builtin_method_excluded(method: &JsonBuiltinMethod) -> bool {
 
 #[cfg(not(feature = "codegen-full"))]
 pub(crate) fn is_class_excluded(godot_class_name: &str) -> bool {
-    !SELECTED_CLASSES.contains(&godot_class_name)
+    !class_selection::selected_classes().contains(godot_class_name)
 }
 
 #[cfg(feature = "codegen-full")]
@@ -126,10 +127,178 @@ pub(crate) fn is_utility_function_excluded(
         })
 }
 
+/// Determines the engine classes generated in minimal codegen. Must be called before any exclusion check.
+#[cfg(not(feature = "codegen-full"))]
+pub(crate) fn init_class_selection(api: &JsonExtensionApi) {
+    class_selection::init(api);
+}
+
+#[cfg(feature = "codegen-full")]
+pub(crate) fn init_class_selection(_api: &JsonExtensionApi) {}
+
+/// Engine classes not generated due to minimal codegen, in API order.
+#[cfg(not(feature = "codegen-full"))]
+pub(crate) fn excluded_class_names() -> &'static [String] {
+    class_selection::excluded_classes()
+}
+
+#[cfg(feature = "codegen-full")]
+pub(crate) fn excluded_class_names() -> &'static [String] {
+    &[]
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// User allowlist
+
+#[cfg(not(feature = "codegen-full"))]
+mod class_selection {
+    use crate::JsonExtensionApi;
+    use std::collections::HashSet;
+    use std::sync::OnceLock;
+
+    /// Environment variable with additional engine classes to generate in minimal codegen.
+    ///
+    /// Either a list of class names separated by commas or whitespace, or the path to a file containing such a list (`#` starts
+    /// a comment).
+    const ALLOWLIST_ENV_VAR: &str = "GODOT_CLASS_ALLOWLIST";
+
+    struct ClassSelection {
+        selected: HashSet<String>,
+        excluded: Vec<String>,
+    }
+
+    // Codegen runs once per build script process, always against the same API. Global state avoids threading the selection through
+    // every exclusion check.
+    static SELECTION: OnceLock<ClassSelection> = OnceLock::new();
+
+    pub fn init(api: &JsonExtensionApi) {
+        SELECTION.get_or_init(|| compute_selection(api, &read_allowlist()));
+    }
+
+    pub fn selected_classes() -> &'static HashSet<String> {
+        &get().selected
+    }
+
+    pub fn excluded_classes() -> &'static [String] {
+        &get().excluded
+    }
+
+    fn get() -> &'static ClassSelection {
+        SELECTION
+            .get()
+            .expect("class selection must be initialized before codegen")
+    }
+
+    fn read_allowlist() -> Vec<String> {
+        println!("cargo:rerun-if-env-changed={ALLOWLIST_ENV_VAR}");
+
+        let Ok(value) = std::env::var(ALLOWLIST_ENV_VAR) else {
+            return Vec::new();
+        };
+
+        let path = std::path::Path::new(value.trim());
+        let list = if path.is_file() {
+            println!("cargo:rerun-if-changed={}", path.display());
+
+            std::fs::read_to_string(path).unwrap_or_else(|e| {
+                panic!(
+                    "{ALLOWLIST_ENV_VAR}: failed to read file {}: {e}",
+                    path.display()
+                )
+            })
+        } else {
+            value
+        };
+
+        list.lines()
+            .map(|line| line.split('#').next().unwrap_or_default())
+            .flat_map(|line| line.split(|c: char| c == ',' || c.is_whitespace()))
+            .filter(|name| !name.is_empty())
+            .map(String::from)
+            .collect()
+    }
+
+    fn compute_selection(api: &JsonExtensionApi, allowlist: &[String]) -> ClassSelection {
+        let classes_by_name: std::collections::HashMap<&str, _> = api
+            .classes
+            .iter()
+            .map(|class| (class.name.as_str(), class))
+            .collect();
+
+        let mut selected: HashSet<String> = super::SELECTED_CLASSES
+            .iter()
+            .map(|&name| name.to_string())
+            .collect();
+
+        for name in allowlist {
+            let Some(class) = classes_by_name.get(name.as_str()) else {
+                panic!("{ALLOWLIST_ENV_VAR}: `{name}` is not an engine class of this Godot API version");
+            };
+            selected.insert(name.clone());
+
+            // Classes appearing in signatures: without them, the corresponding methods and signals would be silently dropped.
+            // Deliberately one level deep: following signatures transitively would pull in most of the engine API. Methods of the added
+            // classes that refer to non-selected classes are omitted, like for the minimal core set.
+            let method_types = class.methods.iter().flatten().flat_map(|method| {
+                let ret = method.return_value.iter().map(|ret| ret.type_.as_str());
+                let args = method
+                    .arguments
+                    .iter()
+                    .flatten()
+                    .map(|arg| arg.type_.as_str());
+                ret.chain(args)
+            });
+            let signal_types = class
+                .signals
+                .iter()
+                .flatten()
+                .flat_map(|signal| signal.arguments.iter().flatten())
+                .map(|arg| arg.type_.as_str());
+
+            for ty in method_types.chain(signal_types) {
+                let class_name = referenced_class_name(ty);
+                if classes_by_name.contains_key(class_name) {
+                    selected.insert(class_name.to_string());
+                }
+            }
+        }
+
+        // Base classes are always needed, for Inherits<T> and upcasts.
+        let mut pending: Vec<String> = selected.iter().cloned().collect();
+        while let Some(name) = pending.pop() {
+            let base = classes_by_name
+                .get(name.as_str())
+                .and_then(|class| class.inherits.as_ref());
+
+            if let Some(base) = base {
+                if selected.insert(base.clone()) {
+                    pending.push(base.clone());
+                }
+            }
+        }
+
+        let excluded = api
+            .classes
+            .iter()
+            .map(|class| &class.name)
+            .filter(|name| !selected.contains(name.as_str()))
+            .cloned()
+            .collect();
+
+        ClassSelection { selected, excluded }
+    }
+
+    /// Class part of a JSON type, e.g. `Node` for `typedarray::Node` or `enum::Node.ProcessMode`.
+    fn referenced_class_name(ty: &str) -> &str {
+        let ty = ty.rsplit("::").next().unwrap_or(ty);
+        ty.split('.').next().unwrap_or(ty)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Allowed-classes
 
-// Classes for minimal config
+// Classes for minimal config. Extended by the user through `GODOT_CLASS_ALLOWLIST`.
 #[cfg(not(feature = "codegen-full"))]
 const SELECTED_CLASSES: &[&str] = &[
     // Core class hierarchy
@@ -154,6 +323,7 @@ const SELECTED_CLASSES: &[&str] = &[
     "ResourceLoader",
     "ResourceSaver",
     "FileAccess",
+    "DirAccess",
     //
     // Scene (node_test, rpc_test)
     "MainLoop", // base of SceneTree
@@ -182,6 +352,7 @@ const SELECTED_CLASSES: &[&str] = &[
     "Input",
     "InputEvent",
     "InputEventAction",
+    "InputMap", // tools::InputActions
     //
     // Godot servers (for RID support)
     "RenderingServer",
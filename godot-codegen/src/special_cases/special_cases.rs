@@ -30,7 +30,7 @@ use crate::models::domain::{Enum, RustTy, TyName};
 use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonSignal, JsonUtilityFunction};
 use crate::special_cases::codegen_special_cases;
 use crate::util::option_as_slice;
-use crate::Context;
+use crate::{Context, JsonExtensionApi};
 use proc_macro2::Ident;
 
 #[rustfmt::skip]
@@ -86,6 +86,16 @@ pub fn is_class_method_deleted(class_name: &TyName, method: &JsonClassMethod, ct
     }
 }
 
+/// Determines which engine classes are generated; must be called before any of the other checks.
+pub fn init_class_selection(api: &JsonExtensionApi) {
+    codegen_special_cases::init_class_selection(api)
+}
+
+/// Engine classes which exist in the API, but are not generated due to minimal codegen.
+pub fn get_excluded_class_names() -> &'static [String] {
+    codegen_special_cases::excluded_class_names()
+}
+
 pub fn is_class_deleted(class_name: &TyName) -> bool {
     codegen_special_cases::is_class_excluded(&class_name.godot_ty)
         || is_godot_type_deleted(&class_name.godot_ty)
@@ -245,6 +255,9 @@ pub fn is_method_private(class_or_builtin_ty: &TyName, godot_method_name: &str)
         | ("RefCounted", "unreference")
         | ("Object", "notification")
 
+        // Replaced by manual APIs with different signature
+        | ("Node", "get_groups")
+
         => true, _ => false
     }
 }
@@ -529,6 +542,14 @@ pub fn is_class_method_param_required(
 
         ("EditorPlugin", "handles") => param == "object",
 
+        // The inspected object is always non-null.
+        ("EditorInspectorPlugin", "can_handle") => param == "object",
+        ("EditorInspectorPlugin", "parse_begin") => param == "object",
+        ("EditorInspectorPlugin", "parse_category") => param == "object",
+        ("EditorInspectorPlugin", "parse_group") => param == "object",
+        ("EditorInspectorPlugin", "parse_property") => param == "object",
+        ("EditorInspectorPlugin", "parse_end") => param == "object",
+
         _ => false,
     }
 }
@@ -575,6 +596,9 @@ pub fn maybe_rename_class_method<'m>(class_name: &TyName, godot_method_name: &'m
         // GDScript, GDScriptNativeClass, possibly more in the future
         (_, "new") => "instantiate",
 
+        // Private; the public name is taken by a manual API returning `Vec<StringName>`.
+        ("Node", "get_groups") => "get_groups_array",
+
         _ => godot_method_name,
     }
 }
@@ -614,6 +638,35 @@ pub fn get_interface_extra_docs(trait_name: &str) -> Option<&'static str> {
         "IScriptExtension" => {
             Some("Use this in combination with the [`obj::script` module][crate::obj::script].")
         }
+        "IMainLoop" => Some(
+            "A Rust class inheriting `MainLoop` can replace `SceneTree` as the main loop, e.g. for headless tools. \
+            Select it with the project setting `application/run/main_loop_type` or the `--main-loop <ClassName>` command-line argument.\
+            \n\n\
+            Godot calls `initialize()` once, then `physics_process()` and `process()` every frame until one of them returns `true`, \
+            and finally `finalize()`. If `physics_process()` or `process()` panics, the loop quits as if `true` was returned. Nodes and APIs that rely on `SceneTree`, such as frame callbacks in the `tools` module, \
+            are not available in a custom main loop.",
+        ),
+
+        _ => None,
+    }
+}
+
+/// Documents virtual methods that replace a built-in engine fallback, which is only used when the method is _not_ overridden.
+///
+/// Most virtual methods are pure callbacks: the engine's own logic runs regardless of the override, so there is nothing to "call super" on.
+pub fn get_virtual_method_extra_docs(
+    class_name: &TyName,
+    rust_method_name: &str,
+) -> Option<&'static str> {
+    match (class_name.godot_ty.as_str(), rust_method_name) {
+        ("Control", "has_point") => Some(
+            "When not overridden, Godot checks whether `point` lies within the control's rectangle. To reproduce this as part of \
+            your own logic, use `Rect2::new(Vector2::ZERO, self.base().get_size()).contains_point(point)`.",
+        ),
+        ("Control", "get_tooltip") => Some(
+            "When not overridden, Godot uses the `tooltip_text` property. To reproduce this as part of your own logic, \
+            return `self.base().get_tooltip_text()`.",
+        ),
 
         _ => None,
     }
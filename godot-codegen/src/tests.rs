@@ -11,6 +11,9 @@
 use crate::conv;
 use crate::generator::native_structures::parse_native_structures_format;
 use crate::generator::native_structures::NativeStructuresField;
+use crate::models::domain::ClassCodegenLevel;
+use crate::models::json::{parse_extension_api, validate_precision, JsonHeader};
+use crate::util;
 
 #[test]
 fn test_pascal_conversion() {
@@ -171,3 +174,100 @@ fn test_parse_native_structures_format() {
     ];
     assert_eq!(actual.unwrap(), expected);
 }
+
+#[test]
+fn test_parse_custom_extension_api() {
+    // API of a patched engine, with one class not known to upstream Godot.
+    fn api_json(custom_class: &str) -> String {
+        format!(
+            r#"{{
+            "header": {{
+                "version_major": 4, "version_minor": 3, "version_patch": 0, "version_status": "stable",
+                "version_build": "custom_build", "version_full_name": "Godot Engine v4.3.stable.custom_build"
+            }},
+            "builtin_class_sizes": [],
+            "builtin_classes": [],
+            "classes": [
+                {{ "name": "Object", "is_refcounted": false, "is_instantiable": true, "api_type": "core" }},
+                {{ "name": "Node", "is_refcounted": false, "is_instantiable": true, "inherits": "Object", "api_type": "core" }},
+                {custom_class}
+            ],
+            "global_enums": [],
+            "utility_functions": [],
+            "native_structures": [],
+            "singletons": []
+        }}"#
+        )
+    }
+
+    let api = parse_extension_api(&api_json(
+        r#"{
+            "name": "ForkedTerrain", "is_refcounted": false, "is_instantiable": true, "inherits": "Node", "api_type": "core",
+            "methods": [
+                {
+                    "name": "get_height", "is_const": true, "is_vararg": false, "is_static": false, "is_virtual": false,
+                    "hash": 3954217734, "return_value": { "type": "float", "meta": "float" },
+                    "arguments": [{ "name": "x", "type": "int", "meta": "int32" }]
+                }
+            ]
+        }"#,
+    ))
+    .expect("valid custom API");
+
+    let terrain = api
+        .classes
+        .iter()
+        .find(|class| class.name == "ForkedTerrain")
+        .expect("custom class is parsed");
+
+    assert_eq!(terrain.inherits.as_deref(), Some("Node"));
+    assert_eq!(terrain.methods.as_ref().map(|m| m.len()), Some(1));
+    assert!(util::get_api_level(terrain) == ClassCodegenLevel::Scene);
+
+    // Actionable errors for inconsistent JSON.
+    let err = parse_extension_api(&api_json(
+        r#"{ "name": "ForkedTerrain", "is_refcounted": false, "is_instantiable": true, "inherits": "Terrain", "api_type": "core" }"#,
+    ))
+    .unwrap_err();
+    assert!(
+        err.contains("`ForkedTerrain` inherits unknown class `Terrain`"),
+        "{err}"
+    );
+
+    let err = parse_extension_api(&api_json(
+        r#"{ "name": "ForkedTerrain", "is_refcounted": false, "is_instantiable": true, "api_type": "fork" }"#,
+    ))
+    .unwrap_err();
+    assert!(err.contains("API type `fork`"), "{err}");
+
+    let err = parse_extension_api(r#"{ "header": {} }"#).unwrap_err();
+    assert!(err.contains("--dump-extension-api"), "{err}");
+}
+
+#[test]
+fn test_validate_precision() {
+    let header = |precision: Option<&str>| JsonHeader {
+        version_major: 4,
+        version_minor: 4,
+        version_patch: 0,
+        version_status: "stable".to_string(),
+        version_build: "custom_build".to_string(),
+        version_full_name: "Godot Engine v4.4.stable.custom_build".to_string(),
+        precision: precision.map(String::from),
+    };
+
+    let (matching, mismatching) = if cfg!(feature = "double-precision") {
+        ("double", "single")
+    } else {
+        ("single", "double")
+    };
+
+    assert!(validate_precision(&header(None)).is_ok());
+    assert!(validate_precision(&header(Some(matching))).is_ok());
+
+    let err = validate_precision(&header(Some(mismatching))).unwrap_err();
+    assert!(err.contains("`double-precision`"), "{err}");
+
+    let err = validate_precision(&header(Some("quad"))).unwrap_err();
+    assert!(err.contains("unknown precision `quad`"), "{err}");
+}
@@ -200,10 +200,18 @@ impl Basis {
 
     /// Creates a [`Quaternion`] representing the same rotation as this basis.
     ///
+    /// Scale is ignored. If the basis contains a reflection (negative determinant), the rotation of the negated basis is returned, like in
+    /// Godot.
+    ///
     /// _Godot equivalent: `Basis.get_rotation_quaternion()`_
     #[doc(alias = "get_rotation_quaternion")]
     pub fn get_quaternion(&self) -> Quaternion {
-        RQuat::from_mat3(&self.orthonormalized().to_glam()).to_front()
+        let mut rotation = self.orthonormalized();
+        if rotation.determinant() < 0.0 {
+            rotation = rotation.scaled(Vector3::new(-1.0, -1.0, -1.0));
+        }
+
+        RQuat::from_mat3(&rotation.to_glam()).to_front()
     }
 
     #[deprecated = "Renamed to `get_quaternion()`"]
@@ -267,6 +267,38 @@ impl Callable {
         Self::from_custom_info(info)
     }
 
+    /// Create callable from a Rust function that may be invoked concurrently from multiple threads, e.g. by `WorkerThreadPool`.
+    ///
+    /// Unlike [`from_sync_fn()`][Self::from_sync_fn], the function is only accessed through shared references, so concurrent calls
+    /// don't alias a `&mut`. Not gated behind `experimental-threads`, since the caller controls which Godot APIs are used inside.
+    #[cfg(since_api = "4.2")]
+    pub(crate) fn from_concurrent_fn<F, S>(name: S, rust_function: F) -> Self
+    where
+        F: 'static + Send + Sync + Fn(&[&Variant]) -> Result<Variant, ()>,
+        S: meta::AsArg<GString>,
+    {
+        meta::arg_into_owned!(name);
+
+        let userdata = CallableUserdata {
+            inner: FnWrapper {
+                rust_function,
+                name,
+                thread_id: None,
+            },
+        };
+
+        let info = CallableCustomInfo {
+            callable_userdata: Box::into_raw(Box::new(userdata)) as *mut std::ffi::c_void,
+            call_func: Some(rust_callable_call_concurrent_fn::<F>),
+            free_func: Some(rust_callable_destroy::<FnWrapper<F>>),
+            to_string_func: Some(rust_callable_to_string_named::<F>),
+            is_valid_func: Some(rust_callable_is_valid),
+            ..Self::default_callable_custom_info()
+        };
+
+        Self::from_custom_info(info)
+    }
+
     #[cfg(since_api = "4.2")]
     fn from_custom_info(mut info: CallableCustomInfo) -> Callable {
         // SAFETY: callable_custom_create() is a valid way of creating callables.
@@ -522,6 +554,13 @@ mod custom_callable {
             let ptr = void_ptr as *mut CallableUserdata<T>;
             &mut (*ptr).inner
         }
+
+        /// # Safety
+        /// Returns an unbounded reference. `void_ptr` must be a valid pointer to a `CallableUserdata`.
+        unsafe fn shared_from_raw<'a>(void_ptr: *mut std::ffi::c_void) -> &'a T {
+            let ptr = void_ptr as *const CallableUserdata<T>;
+            &(*ptr).inner
+        }
     }
 
     pub(crate) struct FnWrapper<F> {
@@ -624,6 +663,33 @@ mod custom_callable {
         });
     }
 
+    pub unsafe extern "C" fn rust_callable_call_concurrent_fn<F>(
+        callable_userdata: *mut std::ffi::c_void,
+        p_args: *const sys::GDExtensionConstVariantPtr,
+        p_argument_count: sys::GDExtensionInt,
+        r_return: sys::GDExtensionVariantPtr,
+        r_error: *mut sys::GDExtensionCallError,
+    ) where
+        F: Fn(&[&Variant]) -> Result<Variant, ()> + Sync,
+    {
+        let arg_refs: &[&Variant] = Variant::borrow_ref_slice(p_args, p_argument_count as usize);
+
+        // Only shared access, as this may run on several threads at once.
+        let name = {
+            let w: &FnWrapper<F> = CallableUserdata::shared_from_raw(callable_userdata);
+            w.name.to_string()
+        };
+        let ctx = meta::CallContext::custom_callable(name.as_str());
+
+        crate::private::handle_varcall_panic(&ctx, &mut *r_error, move || {
+            // Get the FnWrapper again inside closure so the Fn doesn't have to be UnwindSafe.
+            let w: &FnWrapper<F> = CallableUserdata::shared_from_raw(callable_userdata);
+            let result = (w.rust_function)(arg_refs);
+            meta::varcall_return_checked(result, r_return, r_error);
+            Ok(())
+        });
+    }
+
     pub unsafe extern "C" fn rust_callable_destroy<T>(callable_userdata: *mut std::ffi::c_void) {
         let rust_ptr = callable_userdata as *mut CallableUserdata<T>;
         let _drop = Box::from_raw(rust_ptr);
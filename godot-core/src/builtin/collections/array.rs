@@ -1076,6 +1076,46 @@ fn correct_variant_t() {
 }
 
 impl VariantArray {
+    /// Converts this untyped array into a typed array, checking that every element is convertible to `T`.
+    ///
+    /// The elements are copied into a new `Array<T>`, which Godot also sees as typed. Returns an error for the first element that cannot
+    /// be converted. Conversions follow [`Variant::try_to()`]; e.g. an `int` element out of the `i32` range is rejected.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let untyped = varray![1, 2, 3];
+    /// let typed: Array<i32> = untyped.try_into_typed().unwrap();
+    /// assert_eq!(typed, array![1, 2, 3]);
+    ///
+    /// let mixed = varray![1, "two"];
+    /// assert!(mixed.try_into_typed::<i32>().is_err());
+    /// ```
+    pub fn try_into_typed<T: ArrayElement>(self) -> Result<Array<T>, ConvertError> {
+        let mut typed = Array::<T>::new();
+        for elem in self.iter_shared() {
+            typed.push(elem.try_to::<T>()?);
+        }
+
+        Ok(typed)
+    }
+
+    /// Reinterprets this untyped array as `Array<T>`, without copying or checking the elements.
+    ///
+    /// The returned reference shares the array with `self`. Godot continues to see the array as untyped, so it does not prevent
+    /// GDScript or the editor from inserting elements of other types.
+    ///
+    /// Use [`try_into_typed()`][Self::try_into_typed] if you are not certain about the element types.
+    ///
+    /// # Safety
+    /// - Every element must be convertible to `T` for as long as the returned reference is used.
+    /// - The returned array must not be passed to Godot APIs that expect a typed array of `T`.
+    ///
+    /// In the current implementation, reading an element that is not convertible panics rather than causing undefined behavior,
+    /// but this should not be relied upon.
+    pub unsafe fn as_typed_unchecked<T: ArrayElement>(&self) -> &Array<T> {
+        self.assume_type_ref::<T>()
+    }
+
     /// # Safety
     /// - Variant must have type `VariantType::ARRAY`.
     /// - Subsequent operations on this array must not rely on the type of the array.
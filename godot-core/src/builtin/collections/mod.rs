@@ -9,12 +9,18 @@ mod array;
 mod dictionary;
 mod extend_buffer;
 mod packed_array;
+#[cfg(feature = "rayon")]
+mod packed_array_par;
+#[cfg(since_api = "4.4")]
+mod typed_dictionary;
 
 // Re-export in godot::builtin.
 pub(crate) mod containers {
     pub use super::array::{Array, VariantArray};
     pub use super::dictionary::Dictionary;
     pub use super::packed_array::*;
+    #[cfg(since_api = "4.4")]
+    pub use super::typed_dictionary::TypedDictionary;
 }
 
 // Re-export in godot::builtin::iter.
@@ -0,0 +1,60 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use rayon::prelude::*;
+use rayon::slice::{Iter, IterMut};
+
+use crate::builtin::{
+    PackedByteArray, PackedFloat32Array, PackedVector3Array, Transform3D, Vector3,
+};
+
+/// Implements `par_iter()` and `par_iter_mut()` on top of the slice views of a packed array.
+macro_rules! impl_packed_par_iter {
+    ($PackedArray:ty, $Element:ty) => {
+        impl $PackedArray {
+            /// Returns a parallel iterator over the elements, see [rayon's `par_iter()`](rayon::iter::IntoParallelRefIterator::par_iter).
+            ///
+            /// Iterates over the [`as_slice()`][Self::as_slice] view; no elements are copied. The closures passed to the iterator
+            /// run on rayon's thread pool, so they must not access Godot objects.
+            pub fn par_iter(&self) -> Iter<'_, $Element> {
+                self.as_slice().par_iter()
+            }
+
+            /// Returns a parallel iterator over exclusive references to the elements, see
+            /// [rayon's `par_iter_mut()`](rayon::iter::IntoParallelRefMutIterator::par_iter_mut).
+            ///
+            /// If the array shares its storage with other copies, copy-on-write happens exactly once, on the calling thread, before
+            /// the work is split. The threads then write into the [`as_mut_slice()`][Self::as_mut_slice] view without further copies.
+            pub fn par_iter_mut(&mut self) -> IterMut<'_, $Element> {
+                self.as_mut_slice().par_iter_mut()
+            }
+        }
+    };
+}
+
+impl_packed_par_iter!(PackedByteArray, u8);
+impl_packed_par_iter!(PackedFloat32Array, f32);
+impl_packed_par_iter!(PackedVector3Array, Vector3);
+
+impl PackedVector3Array {
+    /// Transforms all vectors by `xf` in parallel, i.e. replaces each element `v` with `xf * v`.
+    ///
+    /// The result is bit-for-bit identical to transforming the elements one by one on a single thread.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::prelude::*;
+    ///
+    /// fn move_vertices(vertices: &mut PackedVector3Array) {
+    ///     let xf = Transform3D::IDENTITY.translated(Vector3::UP);
+    ///     vertices.par_transform_in_place(xf);
+    /// }
+    /// ```
+    pub fn par_transform_in_place(&mut self, xf: Transform3D) {
+        self.par_iter_mut().for_each(|v| *v = xf * *v);
+    }
+}
@@ -0,0 +1,358 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::marker::PhantomData;
+
+use godot_ffi as sys;
+use sys::{interface_fn, GodotFfi};
+
+use crate::builtin::{inner, Dictionary, StringName, Variant, VariantType};
+use crate::meta::error::{ConvertError, FromGodotError};
+use crate::meta::{
+    element_godot_type_name, ArrayElement, ArrayTypeInfo, FromGodot, GodotConvert,
+    PropertyHintInfo, ToGodot,
+};
+use crate::registry::property::{Export, Var};
+
+use super::dictionary::{TypedIter, TypedKeys};
+
+/// Godot's typed `Dictionary[K, V]`, available since Godot 4.4.
+///
+/// Like [`Array<T>`][crate::builtin::Array], the key and value types are known both in Rust and in Godot: GDScript sees a
+/// `Dictionary[K, V]` and rejects entries of other types. Either of them can be [`Variant`] to leave it untyped.
+///
+/// Internally, this is a [`Dictionary`] with type metadata, which can be accessed with [`as_untyped()`][Self::as_untyped]. Like
+/// `Dictionary`, this type is reference-counted; `clone()` shares the same storage.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::builtin::TypedDictionary;
+///
+/// let mut scores = TypedDictionary::<GString, i64>::new();
+/// scores.insert("Alice".into(), 30);
+/// scores.insert("Bob".into(), 25);
+///
+/// assert_eq!(scores.get(&"Alice".into()), Some(30));
+/// assert_eq!(scores.len(), 2);
+///
+/// for (name, score) in scores.iter_shared() {
+///     godot_print!("{name}: {score}");
+/// }
+/// ```
+pub struct TypedDictionary<K: ArrayElement, V: ArrayElement> {
+    inner: Dictionary,
+    _phantom: PhantomData<(K, V)>,
+}
+
+impl<K: ArrayElement, V: ArrayElement> TypedDictionary<K, V> {
+    /// Constructs an empty typed dictionary.
+    pub fn new() -> Self {
+        let mut inner = Dictionary::new();
+
+        // SAFETY: `inner` is a newly created, empty and untyped dictionary.
+        unsafe { set_typed::<K, V>(&mut inner) };
+
+        Self::from_checked(inner)
+    }
+
+    /// Converts an untyped dictionary, checking that its Godot-side key and value types are `K` and `V`.
+    ///
+    /// Returns an error if the dictionary is untyped (unless both `K` and `V` are `Variant`) or has different types. To convert
+    /// the entries of an untyped dictionary instead, use `iter_shared().typed()` and collect into a `TypedDictionary`.
+    pub fn try_from_untyped(dict: Dictionary) -> Result<Self, ConvertError> {
+        let expected = (ArrayTypeInfo::of::<K>(), ArrayTypeInfo::of::<V>());
+        let actual = type_info_of(&dict);
+
+        if expected == actual {
+            Ok(Self::from_checked(dict))
+        } else {
+            Err(FromGodotError::BadDictionaryType { expected, actual }.into_error(dict))
+        }
+    }
+
+    fn from_checked(inner: Dictionary) -> Self {
+        Self {
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the value for the given key, or `None` if the key is absent.
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.inner
+            .get(key.to_variant())
+            .map(|value| value.to::<V>())
+    }
+
+    /// ⚠️ Returns the value for the given key, panicking if absent.
+    ///
+    /// # Panics
+    /// If there is no value for the given key.
+    pub fn at(&self, key: &K) -> V {
+        self.get(key)
+            .unwrap_or_else(|| panic!("key {:?} missing in typed dictionary", key.to_variant()))
+    }
+
+    /// Returns `true` if the dictionary contains the given key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.inner.contains_key(key.to_variant())
+    }
+
+    /// Returns the number of entries in the dictionary.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Inserts a value at the given key, returning the previous value for that key (if available).
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.inner
+            .insert(key.to_variant(), value.to_variant())
+            .map(|previous| previous.to::<V>())
+    }
+
+    /// Sets a value at the given key, overwriting any previous value.
+    pub fn set(&mut self, key: K, value: V) {
+        self.inner.set(key.to_variant(), value.to_variant());
+    }
+
+    /// Removes the entry for the given key, returning its value (if available).
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.inner
+            .remove(key.to_variant())
+            .map(|value| value.to::<V>())
+    }
+
+    /// Removes all entries. The key and value types are kept.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Returns a shallow copy, sharing nested references but not the top-level storage. Keeps the key and value types.
+    pub fn duplicate_shallow(&self) -> Self {
+        Self::from_checked(self.inner.duplicate_shallow())
+    }
+
+    /// Returns a deep copy, duplicating nested arrays and dictionaries. Keeps the key and value types.
+    pub fn duplicate_deep(&self) -> Self {
+        Self::from_checked(self.inner.duplicate_deep())
+    }
+
+    /// Iterates over the `(K, V)` entries.
+    ///
+    /// Like [`Dictionary::iter_shared()`], the dictionary may be modified while iterating, but the iteration order is then unspecified.
+    pub fn iter_shared(&self) -> TypedIter<'_, K, V> {
+        self.inner.iter_shared().typed::<K, V>()
+    }
+
+    /// Iterates over the keys.
+    pub fn keys_shared(&self) -> TypedKeys<'_, K> {
+        self.inner.keys_shared().typed::<K>()
+    }
+
+    /// Returns the underlying untyped dictionary, which shares the same storage.
+    ///
+    /// Godot still sees the dictionary as typed, so entries of other types cannot be inserted through it.
+    pub fn as_untyped(&self) -> &Dictionary {
+        &self.inner
+    }
+
+    /// Converts into the underlying untyped dictionary, which keeps its Godot-side type metadata.
+    pub fn into_untyped(self) -> Dictionary {
+        self.inner
+    }
+
+    #[doc(hidden)]
+    pub fn as_inner(&self) -> inner::InnerDictionary {
+        self.inner.as_inner()
+    }
+}
+
+/// Sets the key and value types of an empty, untyped dictionary.
+///
+/// # Safety
+/// `dict` must be empty and untyped, and not shared with other code yet.
+unsafe fn set_typed<K: ArrayElement, V: ArrayElement>(dict: &mut Dictionary) {
+    let key_info = ArrayTypeInfo::of::<K>();
+    let value_info = ArrayTypeInfo::of::<V>();
+    if !key_info.is_typed() && !value_info.is_typed() {
+        return;
+    }
+
+    // Class names must be empty StringNames (not null) for non-object types, and must outlive the FFI call.
+    let empty = StringName::default();
+    let key_class = key_info.class_name().unwrap_or(&empty);
+    let value_class = value_info.class_name().unwrap_or(&empty);
+    let script = Variant::nil();
+
+    interface_fn!(dictionary_set_typed)(
+        dict.sys_mut(),
+        key_info.variant_type().sys(),
+        key_class.string_sys(),
+        script.var_sys(),
+        value_info.variant_type().sys(),
+        value_class.string_sys(),
+        script.var_sys(),
+    );
+}
+
+fn type_info_of(dict: &Dictionary) -> (ArrayTypeInfo, ArrayTypeInfo) {
+    let inner = dict.as_inner();
+
+    let key_type =
+        VariantType::from_sys(inner.get_typed_key_builtin() as sys::GDExtensionVariantType);
+    let value_type =
+        VariantType::from_sys(inner.get_typed_value_builtin() as sys::GDExtensionVariantType);
+
+    let key = ArrayTypeInfo {
+        variant_type: key_type,
+        class_name: (key_type == VariantType::OBJECT).then(|| inner.get_typed_key_class_name()),
+    };
+    let value = ArrayTypeInfo {
+        variant_type: value_type,
+        class_name: (value_type == VariantType::OBJECT).then(|| inner.get_typed_value_class_name()),
+    };
+
+    (key, value)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Traits
+
+impl<K: ArrayElement, V: ArrayElement> Default for TypedDictionary<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Creates a new reference to the data in this dictionary. Changes to the original dictionary will be reflected in the copy and vice versa.
+impl<K: ArrayElement, V: ArrayElement> Clone for TypedDictionary<K, V> {
+    fn clone(&self) -> Self {
+        Self::from_checked(self.inner.clone())
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> PartialEq for TypedDictionary<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> fmt::Debug for TypedDictionary<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> fmt::Display for TypedDictionary<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> Extend<(K, V)> for TypedDictionary<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.set(key, value);
+        }
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> FromIterator<(K, V)> for TypedDictionary<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut dict = Self::new();
+        dict.extend(iter);
+        dict
+    }
+}
+
+impl<K, V, S> From<HashMap<K, V, S>> for TypedDictionary<K, V>
+where
+    K: ArrayElement + Hash,
+    V: ArrayElement,
+{
+    fn from(map: HashMap<K, V, S>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<K, V, S> From<&TypedDictionary<K, V>> for HashMap<K, V, S>
+where
+    K: ArrayElement + Hash + Eq,
+    V: ArrayElement,
+    S: BuildHasher + Default,
+{
+    fn from(dict: &TypedDictionary<K, V>) -> Self {
+        dict.iter_shared().collect()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversion and property traits
+
+impl<K: ArrayElement, V: ArrayElement> GodotConvert for TypedDictionary<K, V> {
+    type Via = Dictionary;
+}
+
+impl<K: ArrayElement, V: ArrayElement> ToGodot for TypedDictionary<K, V> {
+    type ToVia<'v> = Dictionary;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        self.inner.clone()
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> FromGodot for TypedDictionary<K, V> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Self::try_from_untyped(via)
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> Var for TypedDictionary<K, V> {
+    fn get_property(&self) -> Self::Via {
+        self.to_godot()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        *self = FromGodot::from_godot(value)
+    }
+
+    fn var_hint() -> PropertyHintInfo {
+        // Hint string "key_type;value_type", e.g. "String;int". Same format as Godot uses for typed dictionaries in GDScript.
+        PropertyHintInfo {
+            hint: crate::global::PropertyHint::DICTIONARY_TYPE,
+            hint_string: format!(
+                "{};{}",
+                element_godot_type_name::<K>(),
+                element_godot_type_name::<V>()
+            )
+            .into(),
+        }
+    }
+}
+
+impl<K, V> Export for TypedDictionary<K, V>
+where
+    K: ArrayElement + Export,
+    V: ArrayElement + Export,
+{
+    fn export_hint() -> PropertyHintInfo {
+        PropertyHintInfo {
+            hint: crate::global::PropertyHint::TYPE_STRING,
+            hint_string: format!("{};{}", K::element_type_string(), V::element_type_string())
+                .into(),
+        }
+    }
+}
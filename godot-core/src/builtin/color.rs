@@ -22,6 +22,14 @@ use sys::{ffi_methods, GodotFfi};
 ///
 /// To access its [**HSVA**](ColorHsv) representation, use [`Color::to_hsv`].
 ///
+/// # Arithmetic and clamping
+///
+/// Like in Godot, the operators `+`, `-`, `*` and `/` work component-wise on all four channels (including alpha), either with another
+/// `Color` or with an `f32` scalar (`*` and `/` only). Results are **not clamped**, so they may leave the 0 to 1 range. The same holds
+/// for [`lerp()`][Self::lerp], [`darkened()`][Self::darkened], [`lightened()`][Self::lightened] and [`inverted()`][Self::inverted].
+/// Use [`normalized()`][Self::normalized] to clamp all channels to the 0 to 1 range, or [`clamp()`][Self::clamp] for custom bounds.
+/// Apart from these, only the byte conversions such as [`r8()`][Self::r8] clamp.
+///
 /// # Godot docs
 ///
 /// [`Color` (stable)](https://docs.godotengine.org/en/stable/classes/class_color.html)
@@ -218,6 +226,9 @@ impl Color {
     }
 
     /// Blends the given color on top of this color, taking its alpha into account.
+    ///
+    /// This is the "over" operator of alpha compositing: the result's alpha is `over.a + self.a * (1 - over.a)`, and the color channels
+    /// are weighted accordingly. If both alphas are 0, the result is fully transparent black.
     #[must_use]
     pub fn blend(self, over: Color) -> Self {
         self.as_inner().blend(over)
@@ -468,6 +479,23 @@ impl ops::DivAssign<Color> for Color {
     }
 }
 
+impl ops::Div<f32> for Color {
+    type Output = Color;
+    fn div(mut self, rhs: f32) -> Self::Output {
+        self /= rhs;
+        self
+    }
+}
+
+impl ops::DivAssign<f32> for Color {
+    fn div_assign(&mut self, f: f32) {
+        self.r /= f;
+        self.g /= f;
+        self.b /= f;
+        self.a /= f;
+    }
+}
+
 impl ops::Add<Color> for Color {
     type Output = Color;
     fn add(mut self, rhs: Color) -> Self::Output {
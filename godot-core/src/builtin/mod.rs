@@ -14,7 +14,7 @@
 //! [extended documentation page](../__docs/index.html#builtin-api-design).
 
 // Re-export macros.
-pub use crate::{array, dict, real, reals, varray};
+pub use crate::{array, dict, real, reals, variant_match, varray};
 
 // Re-export generated enums.
 pub use crate::gen::central::global_reexported_enums::{Corner, EulerOrder, Side, VariantOperator};
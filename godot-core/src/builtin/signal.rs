@@ -82,6 +82,36 @@ impl Signal {
         Error::from_godot(error as i32)
     }
 
+    /// Connects this signal to the specified callable, returning a guard that disconnects it when dropped.
+    ///
+    /// Use this when the callable is only valid for a limited scope, e.g. a Rust closure capturing data that is dropped later on.
+    /// See [`Connection`] for details on how the guard behaves when the emitting object dies first.
+    ///
+    /// Returns the error of [`connect()`][Self::connect] if the connection could not be established.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::prelude::*;
+    ///
+    /// fn watch_button(button: &Gd<Node>, on_pressed: &Callable) {
+    ///     let signal = Signal::from_object_signal(button, "pressed");
+    ///     let connection = signal.connect_guarded(on_pressed, 0).expect("connect");
+    ///
+    ///     // ...
+    ///     drop(connection); // Disconnects.
+    /// }
+    /// ```
+    pub fn connect_guarded(&self, callable: &Callable, flags: i64) -> Result<Connection, Error> {
+        match self.connect(callable, flags) {
+            Error::OK => Ok(Connection {
+                signal: self.clone(),
+                callable: callable.clone(),
+                active: true,
+            }),
+            err => Err(err),
+        }
+    }
+
     /// Disconnects this signal from the specified [`Callable`].
     ///
     /// If the connection does not exist, generates an error. Use [`Self::is_connected`] to make sure that the connection exists.
@@ -211,3 +241,74 @@ impl fmt::Display for Signal {
         write!(f, "{}", self.to_variant())
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// RAII guard for a signal connection, returned by [`Signal::connect_guarded()`].
+///
+/// When the guard is dropped, the callable is disconnected from the signal. To keep the connection alive beyond the guard's scope, use
+/// [`forget()`][Self::forget].
+///
+/// # Lifetime and validity
+/// The guard keeps the `Signal` and `Callable` values, but not the emitting object alive; `Signal` does not hold a strong reference.
+/// If the object has been freed in the meantime, Godot has already removed all its connections, so dropping the guard does nothing.
+/// Likewise, nothing happens if the connection was removed by other means, e.g. `Signal::disconnect()` or a `ONE_SHOT` connection
+/// that has fired. Dropping a guard thus never causes Godot errors.
+///
+/// For connections made with `ConnectFlags::REFERENCE_COUNTED`, each guard releases one reference.
+#[must_use = "the connection is removed immediately if the guard is dropped"]
+pub struct Connection {
+    signal: Signal,
+    callable: Callable,
+    active: bool,
+}
+
+impl Connection {
+    /// The connected signal.
+    pub fn signal(&self) -> &Signal {
+        &self.signal
+    }
+
+    /// The connected callable.
+    pub fn callable(&self) -> &Callable {
+        &self.callable
+    }
+
+    /// Returns `true` if the connection still exists, i.e. the object is alive and the callable has not been disconnected.
+    pub fn is_connected(&self) -> bool {
+        // Signal::is_connected() prints an error if the object is dead.
+        self.signal.object().is_some() && self.signal.is_connected(&self.callable)
+    }
+
+    /// Disconnects immediately. Equivalent to dropping the guard, but more explicit.
+    pub fn disconnect(mut self) {
+        self.disconnect_if_connected();
+    }
+
+    /// Releases the guard without disconnecting; the connection stays until disconnected manually or the object is freed.
+    pub fn forget(mut self) {
+        self.active = false;
+    }
+
+    fn disconnect_if_connected(&mut self) {
+        if std::mem::take(&mut self.active) && self.is_connected() {
+            self.signal.disconnect(&self.callable);
+        }
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.disconnect_if_connected();
+    }
+}
+
+impl fmt::Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Connection")
+            .field("signal", &self.signal)
+            .field("callable", &self.callable)
+            .field("active", &self.active)
+            .finish()
+    }
+}
@@ -54,6 +54,14 @@ use crate::{impl_shared_string_api, meta};
 /// Note that Godot ignores any bytes after a null-byte. This means that for instance `"hello, world!"` and `"hello, world!\0 ignored by Godot"`
 /// will be treated as the same string if converted to a `GString`.
 ///
+/// # Comparison with Rust strings
+///
+/// `GString` can be compared with `&str` in both directions, e.g. `string == "text"`. This compares the characters directly, without
+/// constructing a Godot string or allocating.
+///
+/// Since `==` accepts several right-hand types, the target of `into()` can no longer be inferred in `string == "text".into()`; this
+/// is a breaking change compared to earlier versions. Remove the `.into()` to compare with the `&str` directly.
+///
 /// # All string types
 ///
 /// | Intended use case | String type                                |
@@ -400,6 +408,18 @@ impl std::str::FromStr for GString {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Comparison with Rust string-types
+
+/// Compares the characters of a `GString` directly with a `&str`, without allocating a temporary Godot string.
+///
+/// Enables `gstring == "literal"` as well as `"literal" == gstring`.
+fn gstring_eq_str(string: &GString, other: &str) -> bool {
+    super::chars_eq_str(string.chars(), other)
+}
+
+impl_str_partial_eq!(GString, gstring_eq_str);
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Conversion from other Godot string-types
 
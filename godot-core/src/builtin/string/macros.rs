@@ -31,3 +31,34 @@ macro_rules! impl_rust_string_conv {
         }
     };
 }
+
+/// Implements `PartialEq` between `$Ty` and Rust string slices, in both directions.
+///
+/// `$eq_fn` has signature `fn(&$Ty, &str) -> bool`.
+macro_rules! impl_str_partial_eq {
+    ($Ty:ty, $eq_fn:path) => {
+        impl PartialEq<str> for $Ty {
+            fn eq(&self, other: &str) -> bool {
+                $eq_fn(self, other)
+            }
+        }
+
+        impl PartialEq<&str> for $Ty {
+            fn eq(&self, other: &&str) -> bool {
+                $eq_fn(self, other)
+            }
+        }
+
+        impl PartialEq<$Ty> for str {
+            fn eq(&self, other: &$Ty) -> bool {
+                $eq_fn(other, self)
+            }
+        }
+
+        impl PartialEq<$Ty> for &str {
+            fn eq(&self, other: &$Ty) -> bool {
+                $eq_fn(other, self)
+            }
+        }
+    };
+}
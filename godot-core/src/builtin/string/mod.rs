@@ -13,7 +13,8 @@ mod node_path;
 mod string_macros;
 mod string_name;
 
-use crate::meta::error::ConvertError;
+use crate::builtin::{Variant, VariantArray};
+use crate::meta::error::{ConvertError, StringError};
 use crate::meta::{FromGodot, GodotConvert, ToGodot};
 use std::ops;
 
@@ -70,6 +71,21 @@ pub enum Encoding {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Evaluates `format % values`, keeping Godot's error message on failure.
+fn sprintf(format: &Variant, values: &VariantArray) -> Result<GString, StringError> {
+    use crate::builtin::VariantOperator;
+
+    match format.evaluate_or_error(&values.to_variant(), VariantOperator::MODULE) {
+        Ok(result) => Ok(result.try_to::<GString>().unwrap_or_default()),
+
+        // On failure, Godot returns the error message as the result.
+        Err(message) => {
+            let message = message.try_to::<GString>().unwrap_or_default();
+            Err(StringError::new(format!("cannot format string: {message}")))
+        }
+    }
+}
+
 /// Returns a tuple of `(from, len)` from a Rust range.
 ///
 /// Unbounded upper bounds are represented by `len = -1`.
@@ -155,3 +171,10 @@ fn found_to_option(index: i64) -> Option<usize> {
         Some(index_usize)
     }
 }
+
+/// Compares Godot's UTF-32 characters with a Rust string, without allocating.
+///
+/// Both sides are sequences of Unicode scalar values, so this is exact code-point equality (no normalization), like Godot's `==`.
+fn chars_eq_str(chars: &[char], s: &str) -> bool {
+    chars.iter().copied().eq(s.chars())
+}
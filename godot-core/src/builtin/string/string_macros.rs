@@ -194,6 +194,51 @@ macro_rules! impl_shared_string_api {
                 self.as_inner().format(array_or_dict, placeholder)
             }
 
+            /// Replaces `{key}` placeholders with the values of `dict`, like GDScript's `String.format(dict)`.
+            ///
+            /// Keys are converted to strings, values are stringified as in GDScript. Placeholders without a matching key are left unchanged,
+            /// e.g. `"{a} {b}"` formatted with `{"a": 1}` results in `"1 {b}"`.
+            ///
+            /// This allows to reuse format strings authored for GDScript. It is unrelated to Rust's `format!` syntax.
+            ///
+            /// # Example
+            /// ```no_run
+            /// # use godot::prelude::*;
+            /// let template = GString::from("{name} has {hp} HP");
+            /// let text = template.format_dict(&dict! { "name": "Goblin", "hp": 12 });
+            /// assert_eq!(text, "Goblin has 12 HP");
+            /// ```
+            pub fn format_dict(&self, dict: &$crate::builtin::Dictionary) -> GString {
+                self.format(&$crate::meta::ToGodot::to_variant(dict))
+            }
+
+            /// Replaces `{0}`, `{1}`, ... placeholders with the elements of `array`, like GDScript's `String.format(array)`.
+            ///
+            /// Elements that are themselves `[key, value]` arrays replace `{key}` instead. Placeholders without a matching index or key are
+            /// left unchanged.
+            pub fn format_array(&self, array: &$crate::builtin::VariantArray) -> GString {
+                self.format(&$crate::meta::ToGodot::to_variant(array))
+            }
+
+            /// Substitutes `%`-placeholders (`%s`, `%d`, `%05.2f`, ...) with the elements of `values`, like GDScript's `string % values`.
+            ///
+            /// Returns an error if the string and the values don't fit together, e.g. if there are more placeholders than values or vice
+            /// versa, or if a value doesn't match its placeholder type. The error contains Godot's message, such as
+            /// `"not enough arguments for format string"`.
+            ///
+            /// # Example
+            /// ```no_run
+            /// # use godot::prelude::*;
+            /// let text = GString::from("%s: %03d").sprintf(&varray!["score", 7]).unwrap();
+            /// assert_eq!(text, "score: 007");
+            /// ```
+            pub fn sprintf(
+                &self,
+                values: &$crate::builtin::VariantArray,
+            ) -> Result<GString, $crate::meta::error::StringError> {
+                super::sprintf(&$crate::meta::ToGodot::to_variant(self), values)
+            }
+
             // left() + right() are not redefined, as their i64 can be negative.
 
             /// Formats the string to be at least `min_length` long, by adding characters to the left of the string, if necessary.
@@ -33,6 +33,15 @@ use crate::{impl_shared_string_api, meta};
 /// Note that Godot ignores any bytes after a null-byte. This means that for instance `"hello, world!"` and  \
 /// `"hello, world!\0 ignored by Godot"` will be treated as the same string if converted to a `StringName`.
 ///
+/// # Comparison with Rust strings
+///
+/// `StringName` can be compared with `&str` in both directions, e.g. `name == "text"`, without interning the `&str` in Godot's global
+/// string table. Godot offers no direct access to a name's characters, so they are read through a `GString` sharing the name's buffer;
+/// no memory is allocated, except for names created from static C strings (e.g. `c"name"`), whose characters Godot copies.
+///
+/// Since `==` accepts several right-hand types, the target of `into()` can no longer be inferred in `name == "text".into()`; this
+/// is a breaking change compared to earlier versions. Remove the `.into()` to compare with the `&str` directly.
+///
 /// # Performance
 ///
 /// The fastest way to create string names is by using null-terminated C-string literals such as `c"MyClass"`. These have `'static` lifetime and
@@ -315,6 +324,17 @@ unsafe impl Sync for StringName {}
 // That is, it's safe to construct a StringName on thread A and destroy it on thread B.
 unsafe impl Send for StringName {}
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Comparison with Rust string-types
+
+/// Compares the content of a `StringName` with a `&str`, see "Comparison with Rust strings" in the type docs.
+fn string_name_eq_str(string: &StringName, other: &str) -> bool {
+    let chars = GString::from(string);
+    super::chars_eq_str(chars.chars(), other)
+}
+
+impl_str_partial_eq!(StringName, string_name_eq_str);
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Conversion from/into other string-types
 
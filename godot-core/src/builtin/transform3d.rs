@@ -135,6 +135,11 @@ impl Transform3D {
 
     /// Returns a transform interpolated between this transform and another by
     /// a given weight (on the range of 0.0 to 1.0).
+    ///
+    /// Rotation, scale and origin are interpolated separately: rotation is spherically interpolated (shortest path), while scale and
+    /// origin are interpolated linearly. Unlike lerping the basis vectors, this keeps intermediate transforms free of skew and shrinking.
+    ///
+    /// _Godot equivalent: `Transform3D.interpolate_with()`_
     #[must_use]
     pub fn interpolate_with(&self, other: &Self, weight: real) -> Self {
         let src_scale = self.basis.get_scale();
@@ -145,8 +150,9 @@ impl Transform3D {
         let dst_rot = other.basis.get_quaternion().normalized();
         let dst_loc = other.origin;
 
+        let rotation = src_rot.slerp(dst_rot, weight).normalized();
         let mut basis = Basis::from_scale(src_scale.lerp(dst_scale, weight));
-        basis = Basis::from_quaternion(src_rot.slerp(dst_rot, weight)) * basis;
+        basis = Basis::from_quaternion(rotation) * basis;
 
         Self {
             basis,
@@ -203,6 +203,15 @@ impl Variant {
     /// Recommended to be used with fully-qualified call syntax.
     /// For example, `Variant::evaluate(&a, &b, VariantOperator::Add)` is equivalent to `a + b` in GDScript.
     pub fn evaluate(&self, rhs: &Variant, op: VariantOperator) -> Option<Variant> {
+        self.evaluate_or_error(rhs, op).ok()
+    }
+
+    /// Like [`evaluate()`][Self::evaluate], but keeps the result on failure, in which Godot may store an error message.
+    pub(crate) fn evaluate_or_error(
+        &self,
+        rhs: &Variant,
+        op: VariantOperator,
+    ) -> Result<Variant, Variant> {
         use crate::obj::EngineEnum;
 
         let op_sys = op.ord() as sys::GDExtensionVariantOperator;
@@ -221,9 +230,9 @@ impl Variant {
         };
 
         if is_valid == 1 {
-            Some(result)
+            Ok(result)
         } else {
-            None
+            Err(result)
         }
     }
 
@@ -246,11 +255,69 @@ impl Variant {
         result
     }
 
-    /// Return Godot's hash value for the variant.
+    /// Returns Godot's 32-bit hash value for the variant.
+    ///
+    /// This is the same value that the engine uses, e.g. as key hash in `Dictionary`, so it can be used to match Godot-side hashing
+    /// exactly. It is unrelated to Rust's [`Hash`](std::hash::Hash) trait: types implementing both may produce different values.
+    ///
+    /// Equal variants have equal hashes, but equal hashes don't imply equal variants, due to collisions.
     ///
     /// _Godot equivalent : `@GlobalScope.hash()`_
-    pub fn hash(&self) -> i64 {
-        unsafe { interface_fn!(variant_hash)(self.var_sys()) }
+    pub fn hash(&self) -> u32 {
+        // The GDExtension interface only deals in `i64`, but the engine's own `hash()` function actually returns `uint32_t`.
+        let hash = unsafe { interface_fn!(variant_hash)(self.var_sys()) };
+        hash.try_into().expect("Godot hashes are uint32_t")
+    }
+
+    /// Compares two variants the way Godot compares `Dictionary` keys.
+    ///
+    /// This differs from `==` ([`PartialEq`]), which uses the `==` operator of GDScript:
+    /// - Values of different types are never equal. `==` considers `1` and `1.0` equal, so they are the same key for `==`, but not in a
+    ///   `Dictionary`.
+    /// - Floats (also within vectors, colors etc.) that are `NaN` are equal to each other, so `NaN` can be used as a key. `==` follows IEEE
+    ///   754 and considers `NaN` unequal to everything, including itself.
+    /// - Containers are compared element-wise with the same rules.
+    ///
+    /// `Dictionary` additionally treats `GString` and `StringName` keys with the same content as equal, which this method does not.
+    ///
+    /// Use this method together with [`hash()`][Self::hash] to match Godot's dictionary semantics exactly: if `hash_compare()` returns
+    /// `true`, the hashes are equal.
+    pub fn hash_compare(&self, other: &Variant) -> bool {
+        let equal = unsafe { interface_fn!(variant_hash_compare)(self.var_sys(), other.var_sys()) };
+        sys::conv::bool_from_sys(equal)
+    }
+
+    /// Returns a shallow copy of a container variant.
+    ///
+    /// For arrays, packed arrays and dictionaries, the container itself is copied, while nested containers are still shared with the
+    /// original. All other types are returned as-is: builtins are copied anyway, and objects keep referring to the same instance.
+    ///
+    /// _Godot equivalent : `Variant.duplicate(false)`_
+    #[doc(alias = "duplicate")]
+    pub fn duplicate_shallow(&self) -> Variant {
+        self.duplicate(false)
+    }
+
+    /// Returns a deep copy of a container variant.
+    ///
+    /// Like [`duplicate_shallow()`][Self::duplicate_shallow], but nested arrays and dictionaries are copied recursively. Objects are
+    /// still not duplicated, not even `Resource`s.
+    ///
+    /// _Godot equivalent : `Variant.duplicate(true)`_
+    pub fn duplicate_deep(&self) -> Variant {
+        self.duplicate(true)
+    }
+
+    fn duplicate(&self, deep: bool) -> Variant {
+        unsafe {
+            Self::new_with_var_uninit(|variant_ptr| {
+                interface_fn!(variant_duplicate)(
+                    self.var_sys(),
+                    variant_ptr,
+                    sys::conv::bool_to_sys(deep),
+                )
+            })
+        }
     }
 
     /// Interpret the `Variant` as `bool`.
@@ -262,12 +329,24 @@ impl Variant {
     /// - empty string
     /// - empty container (array, packed array, dictionary)
     /// - default-constructed other builtins (e.g. zero vector, degenerate plane, zero RID, etc...)
+    /// - null or freed objects
+    ///
+    /// See also [`is_truthy()`][Self::is_truthy], which is the same check.
     pub fn booleanize(&self) -> bool {
         // See Variant::is_zero(), roughly https://github.com/godotengine/godot/blob/master/core/variant/variant.cpp#L859.
 
         unsafe { interface_fn!(variant_booleanize)(self.var_sys()) != 0 }
     }
 
+    /// Checks whether the variant is truthy, exactly like a GDScript condition would.
+    ///
+    /// GDScript uses the same rules for `if value:`, `not value`, `and`/`or` and `while value:`. A variant is falsy if its value is the
+    /// default of its type (`null`, `false`, `0`, empty string or container, zero vector, ...) or a freed object; see
+    /// [`booleanize()`][Self::booleanize] for the full list.
+    pub fn is_truthy(&self) -> bool {
+        self.booleanize()
+    }
+
     /// Assuming that this is of type `OBJECT`, checks whether the object is dead.
     ///
     /// Does not check again that the variant has type `OBJECT`.
@@ -498,6 +577,7 @@ impl Drop for Variant {
 }
 
 // Variant is not Eq because it can contain floats and other types composed of floats.
+// This is GDScript's `==`, not the key equality of `Dictionary`; see `Variant::hash_compare()`.
 impl PartialEq for Variant {
     fn eq(&self, other: &Self) -> bool {
         Self::evaluate(self, other, VariantOperator::EQUAL) //.
@@ -530,3 +610,75 @@ impl fmt::Debug for Variant {
         }
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Dispatches on the dynamic type of a [`Variant`], binding the converted value in each arm.
+///
+/// Each arm of the form `Type(binding) => expr` matches if the variant stores the [`VariantType`] that `Type` is converted from, and
+/// [`Variant::try_to::<Type>()`][Variant::try_to] succeeds. Arms are tried in order; the first match wins. This means that an `i32` arm
+/// does not match integers outside of `i32` range, and a `Gd<Node>` arm does not match other objects.
+///
+/// Types must be simple identifiers, optionally with generic arguments (e.g. `Gd<Node>`, `Array<i64>`). `Variant` itself cannot be
+/// used as a type; use a catch-all instead.
+///
+/// The last arm can optionally be a catch-all:
+/// - `_ => expr` ignores the variant.
+/// - `name => expr` binds `&Variant` to `name`.
+///
+/// Without a catch-all, all arms must evaluate to `()`, and variants matching no arm are silently ignored.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::{variant_match, GString, Variant, Vector2};
+///
+/// fn describe(value: &Variant) -> String {
+///     variant_match!(value, {
+///         i64(n) => format!("integer {n}"),
+///         GString(s) => format!("string \"{s}\""),
+///         Vector2(p) => format!("position {p}"),
+///         other => format!("something else: {other}"),
+///     })
+/// }
+/// ```
+#[macro_export]
+macro_rules! variant_match {
+    ($subject:expr, { $($arms:tt)* }) => {{
+        let __variant_match_subject: &$crate::builtin::Variant = &$subject;
+        let __variant_match_type = __variant_match_subject.get_type();
+        $crate::variant_match!(@arm __variant_match_subject, __variant_match_type; $($arms)*)
+    }};
+
+    // Catch-all, ignoring the variant.
+    (@arm $subject:ident, $vtype:ident; _ => $body:expr $(,)?) => {
+        $body
+    };
+
+    // Typed arm.
+    (@arm $subject:ident, $vtype:ident;
+        $ty:ident $(< $($generic:ty),+ >)? ( $bind:pat ) => $body:expr $(, $($rest:tt)*)?
+    ) => {
+        // Comparing the type first avoids constructing a conversion error for every non-matching arm.
+        let __variant_match_value = if $vtype == $crate::private::variant_type_of::<$ty $(< $($generic),+ >)?>() {
+            $subject.try_to::<$ty $(< $($generic),+ >)?>().ok()
+        } else {
+            None
+        };
+
+        match __variant_match_value {
+            Some($bind) => $body,
+            None => $crate::variant_match!(@arm $subject, $vtype; $($($rest)*)?),
+        }
+    };
+
+    // Catch-all, binding the variant.
+    (@arm $subject:ident, $vtype:ident; $other:ident => $body:expr $(,)?) => {{
+        let $other = $subject;
+        $body
+    }};
+
+    // No catch-all: unmatched variants are ignored.
+    (@arm $subject:ident, $vtype:ident;) => {
+        ()
+    };
+}
@@ -679,9 +679,12 @@ macro_rules! impl_float_vector_fns {
             }
 
             /// Returns `true` if the vector is normalized, i.e. its length is approximately equal to 1.
+            ///
+            /// Uses the same tolerance as Godot: the squared length may differ from 1 by less than `0.001`.
             #[inline]
             pub fn is_normalized(self) -> bool {
-                self.to_glam().is_normalized()
+                // Godot's UNIT_EPSILON, which is more lenient than glam's is_normalized().
+                (self.length_squared() - 1.0).abs() < 0.001
             }
 
             /// Returns `true` if this vector's values are approximately zero.
@@ -1023,39 +1026,60 @@ macro_rules! impl_vector2_vector3_fns {
 
             /// Returns a new vector "bounced off" from a plane defined by the given normal.
             ///
+            /// This is the negation of [`reflect()`][Self::reflect].
+            ///
             /// # Panics
-            /// If `n` is not normalized.
+            /// If `n` is not normalized, see [`is_normalized()`][Self::is_normalized]. Godot prints an error and returns a zero vector
+            /// in this case.
             #[inline]
             pub fn bounce(self, n: Self) -> Self {
                 assert!(n.is_normalized(), "n is not normalized!");
                 -self.reflect(n)
             }
 
-            /// Returns the vector with a maximum length by limiting its length to `length`.
+            /// Returns the vector with a maximum length by limiting its length to `length` (`1.0` if `None`).
+            ///
+            /// Zero vectors are returned unchanged.
             #[inline]
             pub fn limit_length(self, length: Option<real>) -> Self {
                 let length = length.unwrap_or(1.0);
+                let current = self.length();
 
-                Self::from_glam(self.to_glam().clamp_length_max(length))
-
+                if current > 0.0 && length < current {
+                    self / current * length
+                } else {
+                    self
+                }
             }
 
             /// Returns a new vector moved toward `to` by the fixed `delta` amount. Will not go past the final value.
+            ///
+            /// If `to` is closer than `delta` (or approximately equal to `self`), `to` is returned. A negative `delta` moves away from `to`.
             #[inline]
             pub fn move_toward(self, to: Self, delta: real) -> Self {
-                Self::from_glam(self.to_glam().move_towards(to.to_glam(), delta))
+                let difference = to - self;
+                let distance = difference.length();
+
+                if distance <= delta || distance < real::CMP_EPSILON {
+                    to
+                } else {
+                    self + difference / distance * delta
+                }
             }
 
             /// Returns the result of projecting the vector onto the given vector `b`.
+            ///
+            /// `b` does not need to be normalized. If `b` is zero, the result has NaN components.
             #[inline]
             pub fn project(self, b: Self) -> Self {
                 Self::from_glam(self.to_glam().project_onto(b.to_glam()))
             }
 
-            /// Returns the result of reflecting the vector defined by the given direction vector `n`.
+            /// Returns the result of reflecting the vector from a plane defined by the given normal `n`.
             ///
             /// # Panics
-            /// If `n` is not normalized.
+            /// If `n` is not normalized, see [`is_normalized()`][Self::is_normalized]. Godot prints an error and returns a zero vector
+            /// in this case.
             #[inline]
             pub fn reflect(self, n: Self) -> Self {
                 assert!(n.is_normalized(), "n is not normalized!");
@@ -1065,7 +1089,8 @@ macro_rules! impl_vector2_vector3_fns {
             /// Returns a new vector slid along a plane defined by the given normal.
             ///
             /// # Panics
-            /// If `n` is not normalized.
+            /// If `n` is not normalized, see [`is_normalized()`][Self::is_normalized]. Godot prints an error and returns a zero vector
+            /// in this case.
             #[inline]
             pub fn slide(self, n: Self) -> Self {
                 assert!(n.is_normalized(), "n is not normalized!");
@@ -4,10 +4,13 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
-use crate::builtin::NodePath;
-use crate::classes::{Node, PackedScene};
-use crate::meta::{arg_into_ref, AsArg};
-use crate::obj::{Gd, Inherits};
+use crate::builtin::{GString, NodePath, StringName};
+use crate::classes::packed_scene::GenEditState;
+use crate::classes::{ClassDb, Node, Object, PackedScene, SceneTree};
+use crate::meta::error::{GroupMemberError, InstantiateError, PackError, UniqueNodeError};
+use crate::meta::{arg_into_owned, arg_into_ref, AsArg};
+use crate::obj::{bounds, Bounds, Gd, Inherits};
+use crate::tools::{ClassMethodInfo, ClassPropertyInfo, GroupMemberPolicy, SpawnChild};
 
 /// Manual extensions for the `Node` class.
 impl Node {
@@ -43,6 +46,555 @@ impl Node {
         self.get_node_or_null(path)
             .and_then(|node| node.try_cast::<T>().ok())
     }
+
+    /// Returns the parent node, if it has type `T` or inherited.
+    ///
+    /// Returns `None` if the node has no parent (e.g. the tree's root or a detached node), or if the parent is of another type.
+    pub fn get_parent_as<T>(&self) -> Option<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        self.get_parent()
+            .and_then(|parent| parent.try_cast::<T>().ok())
+    }
+
+    /// Returns the owner node, or `None` if there is no owner (e.g. for scene roots or nodes created in code).
+    ///
+    /// Shorthand for `get_owner()`. See [`get_owner_as()`][Self::get_owner_as] to cast the owner to a derived class.
+    pub fn owner(&self) -> Option<Gd<Node>> {
+        self.get_owner()
+    }
+
+    /// Returns the owner node, if it has type `T` or inherited.
+    ///
+    /// The owner is usually the root of the scene which this node was instantiated from, see `set_owner()`. Returns `None` if there is
+    /// no owner (e.g. for scene roots or nodes created in code), or if the owner is of another type.
+    pub fn get_owner_as<T>(&self) -> Option<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        self.get_owner()
+            .and_then(|owner| owner.try_cast::<T>().ok())
+    }
+
+    /// Returns the names of the groups that this node is a member of.
+    ///
+    /// This includes internal groups, whose names start with `_` (e.g. the ones used for viewports and physics).
+    pub fn get_groups(&self) -> Vec<StringName> {
+        self.get_groups_array().iter_shared().collect()
+    }
+
+    /// ⚠️ Retrieves the scene-unique node `%name`, panicking if not found or bad type.
+    ///
+    /// See [`try_get_unique_node_as()`][Self::try_get_unique_node_as] for how the node is looked up.
+    ///
+    /// # Panics
+    /// If the node is not found, or if it does not have type `T` or inherited.
+    pub fn get_unique_node_as<T>(&self, name: impl AsArg<GString>) -> Gd<T>
+    where
+        T: Inherits<Node>,
+    {
+        self.try_get_unique_node_as(name)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Retrieves the scene-unique node `%name` (fallible).
+    ///
+    /// Unique names are marked in the editor with "Access as Unique Name", or in code with `set_unique_name_in_owner(true)`. They are
+    /// scoped to the scene that the node belongs to: the lookup happens among nodes with the same owner as `self`, or among nodes owned
+    /// by `self` if it is the scene root. The `%` prefix is added automatically, but may also be passed explicitly.
+    ///
+    /// Returns [`UniqueNodeError::NotFound`] if there is no such node, and [`UniqueNodeError::WrongType`] if it is not type `T` or inherited.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::prelude::*;
+    ///
+    /// fn health_bar(hud: &Gd<Node>) -> Option<Gd<Control>> {
+    ///     // Same as get_node("%HealthBar"), but typed.
+    ///     hud.try_get_unique_node_as::<Control>("HealthBar").ok()
+    /// }
+    /// ```
+    pub fn try_get_unique_node_as<T>(
+        &self,
+        name: impl AsArg<GString>,
+    ) -> Result<Gd<T>, UniqueNodeError>
+    where
+        T: Inherits<Node>,
+    {
+        arg_into_ref!(name);
+
+        let name = name.to_string();
+        let name = name.strip_prefix('%').unwrap_or(&name);
+        let path = NodePath::from(format!("%{name}").as_str());
+
+        let node = self
+            .get_node_or_null(&path)
+            .ok_or_else(|| UniqueNodeError::NotFound { name: name.into() })?;
+
+        node.try_cast::<T>()
+            .map_err(|node| UniqueNodeError::WrongType {
+                name: name.into(),
+                expected: T::class_name(),
+                actual: node.get_class(),
+            })
+    }
+
+    /// Iterates over the direct children of type `T` or inherited, skipping all others.
+    ///
+    /// If `include_internal` is `true`, internal children (see `add_child()`) are also visited.
+    ///
+    /// The children are fetched one by one while iterating; no intermediate `Array` is allocated.
+    pub fn children_of_type<T>(&self, include_internal: bool) -> impl Iterator<Item = Gd<T>> + '_
+    where
+        T: Inherits<Node>,
+    {
+        let count = self.child_count(include_internal);
+
+        (0..count)
+            .filter_map(move |index| self.child_at(index, include_internal))
+            .filter_map(|child| child.try_cast::<T>().ok())
+    }
+
+    /// Returns the first direct child of type `T` or inherited, or `None` if there is no such child.
+    ///
+    /// If `include_internal` is `true`, internal children are also considered.
+    pub fn first_child_of_type<T>(&self, include_internal: bool) -> Option<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        self.children_of_type::<T>(include_internal).next()
+    }
+
+    /// Iterates over all descendants of type `T` or inherited, in depth-first pre-order.
+    ///
+    /// Nodes of other types are skipped, but their children are still visited. If `include_internal` is `true`, internal children and
+    /// their subtrees are also visited.
+    ///
+    /// The walk keeps only one stack entry per tree level and fetches children one by one, so no `Array` is allocated per level.
+    /// Children removed from the tree during iteration are not visited anymore.
+    pub fn descendants_of_type<T>(&self, include_internal: bool) -> impl Iterator<Item = Gd<T>> + '_
+    where
+        T: Inherits<Node>,
+    {
+        Descendants {
+            root: self,
+            stack: vec![DescendantsFrame {
+                parent: None,
+                next_index: 0,
+            }],
+            include_internal,
+        }
+        .filter_map(|node| node.try_cast::<T>().ok())
+    }
+
+    /// Finds descendants whose name matches `pattern` and which are of type `T` or inherited.
+    ///
+    /// `pattern` supports the wildcards `*` and `?`, as in `find_children()`. If `recursive` is `false`, only direct children are
+    /// checked. If `owned` is `true`, only nodes with an owner are considered, which is the case for nodes instantiated from a scene.
+    ///
+    /// Nodes of other types with a matching name are skipped. If Godot runs with `--verbose`, each of them is logged, which helps to find
+    /// out why an expected node is missing. If nothing matches, the result is empty.
+    pub fn find_children_as<T>(
+        &self,
+        pattern: impl AsArg<GString>,
+        recursive: bool,
+        owned: bool,
+    ) -> Vec<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        let log_mismatches = crate::classes::Os::singleton().is_stdout_verbose();
+
+        // Without logging, Godot filters by class, so nodes of other types never reach Rust.
+        let class_name = if log_mismatches {
+            GString::new()
+        } else {
+            T::class_name().to_gstring()
+        };
+
+        self.find_children_ex(pattern)
+            .type_(&class_name)
+            .recursive(recursive)
+            .owned(owned)
+            .done()
+            .iter_shared()
+            .filter_map(|node| match node.try_cast::<T>() {
+                Ok(node) => Some(node),
+                Err(node) => {
+                    crate::godot_print!(
+                        "find_children_as: skip node `{path}` of class {actual}, expected {expected}",
+                        path = node.get_path(),
+                        actual = node.get_class(),
+                        expected = T::class_name(),
+                    );
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Finds the first owned descendant whose name matches `pattern` and which is of type `T` or inherited.
+    ///
+    /// Unlike `find_child()`, nodes of other types with a matching name are skipped, and the search continues. Descendants are searched
+    /// recursively and must have an owner; see [`find_children_as()`][Self::find_children_as] for control over these options.
+    pub fn find_child_as<T>(&self, pattern: impl AsArg<GString>) -> Option<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        self.find_children_as::<T>(pattern, true, true)
+            .into_iter()
+            .next()
+    }
+
+    /// Adds `child` with a builder that sets name, owner and deferred mode in the correct order.
+    ///
+    /// Returns the typed child from [`done()`][SpawnChild::done], also when adding is deferred. See [`SpawnChild`] for the order of calls.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn fire(parent: Gd<Node>, root: Gd<Node>) {
+    /// let bullet: Gd<Node2D> = parent
+    ///     .spawn(Node2D::new_alloc())
+    ///     .name("Bullet")
+    ///     .owned_by(&root)
+    ///     .deferred()
+    ///     .done();
+    /// # }
+    /// ```
+    pub fn spawn<T>(&self, child: Gd<T>) -> SpawnChild<T>
+    where
+        T: Inherits<Node>,
+    {
+        // The builder keeps its own reference, so that the parent can also be passed to owned_by().
+        let parent = self.__to_gd();
+        SpawnChild::new(parent, child)
+    }
+
+    /// Adds `child` and makes `owner` the owner of the child and its whole subtree, so that it persists in the edited scene.
+    ///
+    /// Meant for `#[class(tool)]` scripts and editor plugins. Nodes added at edit time only appear in the scene dock and are only saved
+    /// with the scene if their owner is the edited scene root, which is typically passed as `owner`:
+    /// - In a tool script running inside the edited scene, that's `get_tree().get_edited_scene_root()`.
+    /// - In an editor plugin, `EditorInterface::get_edited_scene_root()`.
+    ///
+    /// Plain `add_child()` leaves the owner unset, so such nodes silently disappear when the scene is reloaded. Setting the owner of only
+    /// the child is not enough either: its descendants are saved only if they are owned as well.
+    ///
+    /// Descendants that already have an owner keep it. This preserves instantiated sub-scenes: their root is owned by `owner` and
+    /// thus saved as a reference to the sub-scene, while their inner nodes stay owned by the sub-scene root and are not duplicated into
+    /// the edited scene. Internal children are owned just like regular ones.
+    ///
+    /// Outside the editor, this behaves the same, which is useful to prepare a tree for [`PackedScene::pack_node()`]. For more control
+    /// (name, internal mode, deferred adding), use [`spawn()`][Self::spawn] with [`owned_by()`][SpawnChild::owned_by].
+    ///
+    /// # Panics
+    /// If `owner` is neither this node nor one of its ancestors. Godot requires the owner to be an ancestor of the owned nodes.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::prelude::*;
+    ///
+    /// fn add_marker(mut parent: Gd<Node3D>) {
+    ///     let Some(scene_root) = parent.get_tree().and_then(|tree| tree.get_edited_scene_root()) else {
+    ///         return; // Not in the editor.
+    ///     };
+    ///
+    ///     let mut marker = Node3D::new_alloc();
+    ///     marker.add_child(&Node3D::new_alloc());
+    ///
+    ///     // Both the marker and its child are saved with the scene.
+    ///     parent.add_child_editor(&marker, &scene_root);
+    /// }
+    /// ```
+    pub fn add_child_editor<T, O>(&mut self, child: &Gd<T>, owner: &Gd<O>)
+    where
+        T: Inherits<Node>,
+        O: Inherits<Node>,
+    {
+        let owner = owner.clone().upcast::<Node>();
+        let parent = self.__to_gd();
+
+        assert!(
+            owner == parent || owner.is_ancestor_of(&parent),
+            "add_child_editor(): owner `{}` must be the parent `{}` or one of its ancestors",
+            owner.get_name(),
+            parent.get_name(),
+        );
+
+        self.spawn(child.clone()).owned_by(&owner).done();
+    }
+
+    /// Creates a tween bound to this node, with a builder API for its steps.
+    ///
+    /// See [`TweenBuilder`][crate::tools::TweenBuilder] for details; use `TweenBuilder::new()` directly if callbacks should receive a
+    /// specific node type instead of `Gd<Node>`.
+    ///
+    /// # Panics
+    /// If the node is not inside the scene tree.
+    #[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+    pub fn tween(&self) -> crate::tools::TweenBuilder {
+        let node = self.__to_gd();
+        crate::tools::TweenBuilder::new(&node)
+    }
+
+    /// Creates a tween bound to this node, animating its property `path` to `final_value` over `duration` seconds.
+    ///
+    /// The path is given as string, e.g. `"position"` or `"modulate:a"` for a sub-property. Returns the builder, so that easing and transition
+    /// of this step can be configured, and further steps appended:
+    ///
+    /// ```no_run
+    /// use godot::classes::tween::{EaseType, TransitionType};
+    /// use godot::classes::Node2D;
+    /// use godot::prelude::*;
+    ///
+    /// fn move_to(node: &Gd<Node2D>, target: Vector2) {
+    ///     node.tween_property("position", target, 0.5)
+    ///         .ease(EaseType::OUT)
+    ///         .trans(TransitionType::QUAD);
+    /// }
+    /// ```
+    ///
+    /// To check property names and value types at compile time, use [`tween()`][Self::tween] with the `prop!` macro instead.
+    ///
+    /// # Panics
+    /// If the node is not inside the scene tree, or if it has no property `path`.
+    #[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+    pub fn tween_property<V: crate::meta::ToGodot>(
+        &self,
+        path: &str,
+        final_value: V,
+        duration: f64,
+    ) -> crate::tools::TweenBuilder {
+        let path = crate::tools::PropertyPath::<Node, V>::new(path);
+        self.tween().property(path, final_value, duration)
+    }
+
+    /// Sets `owner` as owner of all descendants that don't have an owner yet.
+    ///
+    /// Only owned nodes are saved when a scene is packed, see [`PackedScene::pack_node()`]. Nodes built in Rust have no owner, so this
+    /// needs to be called before packing or saving them; typically with the root of the scene as `owner`. `owner` must be this node or one
+    /// of its ancestors.
+    ///
+    /// Descendants that already have an owner keep it; this preserves the structure of instantiated sub-scenes. Internal children and
+    /// their subtrees are skipped.
+    pub fn set_descendants_owner<O>(&self, owner: &Gd<O>)
+    where
+        O: Inherits<Node>,
+    {
+        let owner = owner.clone().upcast::<Node>();
+
+        // Collect first, since set_owner() must not interfere with the iteration.
+        let unowned: Vec<Gd<Node>> = self
+            .descendants_of_type::<Node>(false)
+            .filter(|node| node.get_owner().is_none())
+            .collect();
+
+        for mut node in unowned {
+            node.set_owner(&owner);
+        }
+    }
+
+    /// Moves this node below `new_parent`, optionally keeping its global transform.
+    ///
+    /// Typed counterpart of `reparent_ex()`. Unlike Godot's `reparent()`, this also accepts nodes without a parent, which are simply added
+    /// as child of `new_parent`. Moving to the current parent does nothing.
+    ///
+    /// With `keep_global_transform`, a 2D or 3D node keeps its position, rotation and scale in the world; its local transform is adjusted
+    /// to the new parent. Without it, the local transform is kept, so the node moves along with the new parent. A node without parent has
+    /// no global transform to keep; it is always added with its local transform, regardless of `keep_global_transform`.
+    ///
+    /// # Ownership
+    /// The node and its children are moved, not re-created, so existing `Gd` pointers remain valid. Nobody needs to free anything.
+    /// Godot keeps the [owner][Self::get_owner] only if it is still an ancestor after the move, otherwise it is cleared; this decides
+    /// whether the node is saved as part of the owner's scene.
+    ///
+    /// # Panics
+    /// If `new_parent` is this node or one of its descendants.
+    pub fn reparent_to<P>(&mut self, new_parent: &Gd<P>, keep_global_transform: bool)
+    where
+        P: Inherits<Node>,
+    {
+        let new_parent = new_parent.clone().upcast::<Node>();
+        let this = self.__to_gd();
+
+        assert!(
+            new_parent != this && !this.is_ancestor_of(&new_parent),
+            "reparent_to(): cannot move node `{}` below itself (new parent `{}`)",
+            this.get_name(),
+            new_parent.get_name(),
+        );
+
+        match self.get_parent() {
+            Some(parent) if parent == new_parent => {}
+            Some(_) => {
+                self.reparent_ex(&new_parent)
+                    .keep_global_transform(keep_global_transform)
+                    .done();
+            }
+            None => {
+                // Orphans have no global transform to keep, so keep_global_transform does not apply.
+                let mut new_parent = new_parent;
+                new_parent.add_child(&this);
+            }
+        }
+    }
+
+    /// Puts `replacement` in the place of this node, and moves all children over to it.
+    ///
+    /// Typed counterpart of `replace_by_ex()`. `replacement` is added to this node's parent, at the same index. With `keep_groups`, it is
+    /// also added to all groups of this node.
+    ///
+    /// # Ownership
+    /// This node is **not** freed. Afterwards, it is an orphan without parent and children, and the caller is responsible for it: either
+    /// free it (exactly once, with [`free()`][crate::obj::Gd::free] or [`queue_free()`][Self::queue_free]), or keep it to insert it again
+    /// later. The moved children are now freed together with `replacement`, so freeing this node does not affect them.
+    ///
+    /// # Panics
+    /// If `replacement` is this node, or already has a parent (e.g. if it is one of this node's children). Remove it from its parent first.
+    pub fn replace_with<T>(&mut self, replacement: &Gd<T>, keep_groups: bool)
+    where
+        T: Inherits<Node>,
+    {
+        let replacement = replacement.clone().upcast::<Node>();
+        let this = self.__to_gd();
+
+        assert!(
+            replacement != this,
+            "replace_with(): node `{}` cannot replace itself",
+            this.get_name(),
+        );
+
+        if let Some(parent) = replacement.get_parent() {
+            panic!(
+                "replace_with(): replacement `{}` already has parent `{}`; remove it before replacing `{}`",
+                replacement.get_name(),
+                parent.get_name(),
+                this.get_name(),
+            );
+        }
+
+        self.replace_by_ex(&replacement)
+            .keep_groups(keep_groups)
+            .done();
+    }
+
+    /// Waits for `count` process frames of the scene tree containing this node.
+    ///
+    /// Resolves to [`AwaitFramesError::NotInTree`][crate::task::AwaitFramesError::NotInTree] if the node is not inside the tree, or if it
+    /// leaves the tree (or is freed) while waiting. With `count == 0`, resolves immediately.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::prelude::*;
+    /// use godot::task;
+    ///
+    /// fn blink(mut node: Gd<Node2D>) {
+    ///     task::spawn(async move {
+    ///         node.hide();
+    ///         if node.await_frames(10).await.is_ok() {
+    ///             node.show();
+    ///         }
+    ///     });
+    /// }
+    /// ```
+    #[cfg(since_api = "4.2")]
+    pub fn await_frames(
+        &self,
+        count: u32,
+    ) -> impl std::future::Future<Output = Result<(), crate::task::AwaitFramesError>> + 'static
+    {
+        self.await_node_frames(crate::task::FrameSignal::Process, count)
+    }
+
+    /// Waits for `count` physics frames of the scene tree containing this node.
+    ///
+    /// See [`await_frames()`][Self::await_frames] for error handling.
+    #[cfg(since_api = "4.2")]
+    pub fn await_physics_frames(
+        &self,
+        count: u32,
+    ) -> impl std::future::Future<Output = Result<(), crate::task::AwaitFramesError>> + 'static
+    {
+        self.await_node_frames(crate::task::FrameSignal::Physics, count)
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn await_node_frames(
+        &self,
+        signal: crate::task::FrameSignal,
+        count: u32,
+    ) -> impl std::future::Future<Output = Result<(), crate::task::AwaitFramesError>> + 'static
+    {
+        // get_tree() prints an error if the node is outside the tree.
+        let tree_id = self
+            .is_inside_tree()
+            .then(|| self.get_tree())
+            .flatten()
+            .map(|tree| tree.instance_id());
+        let node_id = self.__to_gd().instance_id();
+
+        async move {
+            let tree_id = tree_id.ok_or(crate::task::AwaitFramesError::NotInTree)?;
+            crate::task::await_tree_frames(tree_id, signal, count, Some(node_id)).await
+        }
+    }
+
+    fn child_count(&self, include_internal: bool) -> i32 {
+        self.get_child_count_ex()
+            .include_internal(include_internal)
+            .done()
+    }
+
+    fn child_at(&self, index: i32, include_internal: bool) -> Option<Gd<Node>> {
+        self.get_child_ex(index)
+            .include_internal(include_internal)
+            .done()
+    }
+}
+
+/// Depth-first iterator over all descendants of a node, see [`Node::descendants_of_type()`].
+struct Descendants<'a> {
+    root: &'a Node,
+    stack: Vec<DescendantsFrame>,
+    include_internal: bool,
+}
+
+struct DescendantsFrame {
+    /// `None` for the root node, which is only available by reference.
+    parent: Option<Gd<Node>>,
+    next_index: i32,
+}
+
+impl Iterator for Descendants<'_> {
+    type Item = Gd<Node>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(frame) = self.stack.last_mut() {
+            let parent = frame.parent.as_deref().unwrap_or(self.root);
+            let index = frame.next_index;
+
+            // Query the count on every step, so that children removed in between are not accessed out of bounds.
+            if index >= parent.child_count(self.include_internal) {
+                self.stack.pop();
+                continue;
+            }
+
+            frame.next_index += 1;
+            let Some(child) = parent.child_at(index, self.include_internal) else {
+                continue;
+            };
+
+            self.stack.push(DescendantsFrame {
+                parent: Some(child.clone()),
+                next_index: 0,
+            });
+            return Some(child);
+        }
+
+        None
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -63,11 +615,709 @@ impl PackedScene {
 
     /// Instantiates the scene as type `T` (fallible).
     ///
-    /// If the scene is not type `T` or inherited.
+    /// Returns `None` if the scene cannot be instantiated, or if its root is not type `T` or inherited. In the latter case, the
+    /// instantiated nodes are freed. Use [`try_instantiate_with_state()`][Self::try_instantiate_with_state] to tell the two cases apart.
     pub fn try_instantiate_as<T>(&self) -> Option<Gd<T>>
     where
         T: Inherits<Node>,
     {
-        self.instantiate().and_then(|gd| gd.try_cast::<T>().ok())
+        self.try_instantiate_with_state::<T>(GenEditState::DISABLED)
+            .ok()
+    }
+
+    /// Packs the node tree under `root` into a new scene, e.g. to save a tree built in Rust with [`save()`][crate::tools::save].
+    ///
+    /// Godot only packs descendants that are owned by `root` (or by an instantiated sub-scene). This method thus first calls
+    /// [`Node::set_descendants_owner()`] with `root`, so that all unowned descendants are included. `root` itself doesn't need an owner.
+    ///
+    /// The nodes stay in place and are not owned by the returned scene; free them separately if no longer needed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::classes::{Node, Node3D, PackedScene};
+    /// use godot::prelude::*;
+    ///
+    /// let mut root = Node3D::new_alloc();
+    /// root.add_child(&Node3D::new_alloc());
+    ///
+    /// let scene = PackedScene::pack_node(&root).expect("pack scene");
+    /// godot::tools::save(&scene, "user://generated.tscn");
+    /// root.free();
+    /// ```
+    pub fn pack_node<T>(root: &Gd<T>) -> Result<Gd<Self>, PackError>
+    where
+        T: Inherits<Node>,
+    {
+        use crate::obj::NewGd;
+
+        let root = root.clone().upcast::<Node>();
+        root.set_descendants_owner(&root);
+
+        let mut scene = Self::new_gd();
+        match scene.pack(&root) {
+            crate::global::Error::OK => Ok(scene),
+            error => Err(PackError::new(error)),
+        }
+    }
+
+    /// Instantiates the scene as type `T`, with the given edit state (fallible).
+    ///
+    /// The edit state is only relevant for editor plugins; see [`GenEditState`]. At runtime, use `GenEditState::DISABLED`.
+    ///
+    /// Returns [`InstantiateError::Failed`] if Godot cannot instantiate the scene, and [`InstantiateError::WrongType`] if the root is not
+    /// type `T` or inherited. In the latter case, the instantiated nodes are freed.
+    pub fn try_instantiate_with_state<T>(
+        &self,
+        edit_state: GenEditState,
+    ) -> Result<Gd<T>, InstantiateError>
+    where
+        T: Inherits<Node>,
+    {
+        let root = self
+            .instantiate_ex()
+            .edit_state(edit_state)
+            .done()
+            .ok_or(InstantiateError::Failed)?;
+
+        root.try_cast::<T>().map_err(|root| {
+            let actual = root.get_class();
+            root.free();
+
+            InstantiateError::WrongType {
+                expected: T::class_name(),
+                actual,
+            }
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `SceneTree` class.
+impl SceneTree {
+    /// Returns the members of `group` that are of type `T` or inherited, skipping members of other types.
+    ///
+    /// Shorthand for [`nodes_in_group_as()`][Self::nodes_in_group_as] with [`GroupMemberPolicy::Skip`].
+    pub fn get_nodes_in_group_as<T>(&mut self, group: impl AsArg<StringName>) -> Vec<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        self.nodes_in_group_as(group, GroupMemberPolicy::Skip)
+            .expect("GroupMemberPolicy::Skip does not fail")
+    }
+
+    /// Returns the members of `group` that are of type `T` or inherited.
+    ///
+    /// Members of other types are handled according to `policy`: skipped, skipped with a warning, or reported as error.
+    pub fn nodes_in_group_as<T>(
+        &mut self,
+        group: impl AsArg<StringName>,
+        policy: GroupMemberPolicy,
+    ) -> Result<Vec<Gd<T>>, GroupMemberError>
+    where
+        T: Inherits<Node>,
+    {
+        arg_into_owned!(group);
+
+        let mut nodes = Vec::new();
+        for node in self.get_nodes_in_group(&group).iter_shared() {
+            match node.try_cast::<T>() {
+                Ok(node) => nodes.push(node),
+                Err(node) => {
+                    let err = GroupMemberError::wrong_type(
+                        group.clone(),
+                        node.get_path(),
+                        T::class_name(),
+                        node.get_class(),
+                    );
+                    handle_group_member_error(err, policy)?;
+                }
+            }
+        }
+
+        Ok(nodes)
+    }
+
+    /// Calls `f` on the Rust instance of every member of `group` that is of type `T` or inherited.
+    ///
+    /// This is a typed alternative to `call_group()`, which calls methods by name. Members of other types, as well as members whose
+    /// instance is currently bound (which would make `bind_mut()` panic), are handled according to `policy`.
+    ///
+    /// With [`GroupMemberPolicy::Error`], types are checked for all members before `f` is called, so a member of another type fails without
+    /// visiting any member. A bound member is only detected when it is reached, so `f` has already been called on the members before it.
+    ///
+    /// Returns the number of members on which `f` was called.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use godot::tools::GroupMemberPolicy;
+    /// #[derive(GodotClass)]
+    /// #[class(init, base=Node2D)]
+    /// struct Enemy {
+    ///     health: i32,
+    ///     base: Base<Node2D>,
+    /// }
+    ///
+    /// fn damage_all(tree: &mut Gd<SceneTree>) {
+    ///     tree.call_group_typed::<Enemy>("enemies", GroupMemberPolicy::Warn, |enemy| {
+    ///         enemy.health -= 10;
+    ///     })
+    ///     .expect("warnings only");
+    /// }
+    /// ```
+    pub fn call_group_typed<T>(
+        &mut self,
+        group: impl AsArg<StringName>,
+        policy: GroupMemberPolicy,
+        mut f: impl FnMut(&mut T),
+    ) -> Result<usize, GroupMemberError>
+    where
+        T: Inherits<Node> + Bounds<Declarer = bounds::DeclUser>,
+    {
+        arg_into_owned!(group);
+
+        let mut count = 0;
+        for mut node in self.nodes_in_group_as::<T>(&group, policy)? {
+            if node.is_bound() {
+                let path = node.clone().upcast::<Node>().get_path();
+                handle_group_member_error(GroupMemberError::bound(group.clone(), path), policy)?;
+                continue;
+            }
+
+            f(&mut node.bind_mut());
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    /// Waits until the next process (idle) frame.
+    ///
+    /// Equivalent to GDScript's `await get_tree().process_frame`. Resolves to
+    /// [`AwaitFramesError::TreeFreed`][crate::task::AwaitFramesError::TreeFreed] if the tree is freed before.
+    #[cfg(since_api = "4.2")]
+    pub fn await_idle_frame(
+        &self,
+    ) -> impl std::future::Future<Output = Result<(), crate::task::AwaitFramesError>> + 'static
+    {
+        self.await_frames(1)
+    }
+
+    /// Waits until the next physics frame.
+    ///
+    /// Equivalent to GDScript's `await get_tree().physics_frame`.
+    #[cfg(since_api = "4.2")]
+    pub fn await_physics_frame(
+        &self,
+    ) -> impl std::future::Future<Output = Result<(), crate::task::AwaitFramesError>> + 'static
+    {
+        self.await_physics_frames(1)
+    }
+
+    /// Waits for `count` process frames. With `count == 0`, resolves immediately.
+    ///
+    /// To stop waiting when a specific node leaves the tree, use [`Node::await_frames()`] instead.
+    #[cfg(since_api = "4.2")]
+    pub fn await_frames(
+        &self,
+        count: u32,
+    ) -> impl std::future::Future<Output = Result<(), crate::task::AwaitFramesError>> + 'static
+    {
+        let tree_id = self.__to_gd().instance_id();
+        crate::task::await_tree_frames(tree_id, crate::task::FrameSignal::Process, count, None)
+    }
+
+    /// Waits for `count` physics frames. With `count == 0`, resolves immediately.
+    #[cfg(since_api = "4.2")]
+    pub fn await_physics_frames(
+        &self,
+        count: u32,
+    ) -> impl std::future::Future<Output = Result<(), crate::task::AwaitFramesError>> + 'static
+    {
+        let tree_id = self.__to_gd().instance_id();
+        crate::task::await_tree_frames(tree_id, crate::task::FrameSignal::Physics, count, None)
+    }
+}
+
+fn handle_group_member_error(
+    err: GroupMemberError,
+    policy: GroupMemberPolicy,
+) -> Result<(), GroupMemberError> {
+    match policy {
+        GroupMemberPolicy::Skip => Ok(()),
+        GroupMemberPolicy::Warn => {
+            crate::godot_warn!("{err}; skipped");
+            Ok(())
+        }
+        GroupMemberPolicy::Error => Err(err),
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `ClassDB` class.
+///
+/// Typed counterparts of the introspection methods, for classes which are only known by name at runtime. For checking classes,
+/// [`class_exists()`][Self::class_exists] and [`is_parent_class()`][Self::is_parent_class] are already typed.
+impl ClassDb {
+    /// Lists the methods of `class`, including inherited ones unless `no_inheritance` is true.
+    ///
+    /// Returns an empty list if the class does not exist.
+    pub fn class_method_infos(
+        &self,
+        class: impl AsArg<StringName>,
+        no_inheritance: bool,
+    ) -> Vec<ClassMethodInfo> {
+        arg_into_ref!(class);
+
+        if !self.class_exists(class) {
+            return Vec::new();
+        }
+
+        self.class_get_method_list_ex(class)
+            .no_inheritance(no_inheritance)
+            .done()
+            .iter_shared()
+            .map(|dict| ClassMethodInfo::from_dictionary(&dict))
+            .collect()
+    }
+
+    /// Lists the properties of `class`, including inherited ones unless `no_inheritance` is true.
+    ///
+    /// Groups and categories are included as well; they can be recognized by their `usage` flags. Returns an empty list if the class
+    /// does not exist.
+    pub fn class_property_infos(
+        &self,
+        class: impl AsArg<StringName>,
+        no_inheritance: bool,
+    ) -> Vec<ClassPropertyInfo> {
+        arg_into_ref!(class);
+
+        if !self.class_exists(class) {
+            return Vec::new();
+        }
+
+        self.class_get_property_list_ex(class)
+            .no_inheritance(no_inheritance)
+            .done()
+            .iter_shared()
+            .map(|dict| ClassPropertyInfo::from_dictionary(&dict))
+            .collect()
+    }
+
+    /// Creates a new instance of `class`, or `None` if the class does not exist or cannot be instantiated.
+    ///
+    /// Abstract classes and singletons cannot be instantiated. Returned `Node`s and other manually-managed objects must be freed by the
+    /// caller.
+    pub fn instantiate_object(&self, class: impl AsArg<StringName>) -> Option<Gd<Object>> {
+        arg_into_ref!(class);
+
+        // Check first to avoid Godot's error message about non-existent or abstract classes.
+        if !self.class_exists(class) || !self.can_instantiate(class) {
+            return None;
+        }
+
+        self.instantiate(class).try_to::<Gd<Object>>().ok()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Engine` class.
+impl crate::classes::Engine {
+    /// Version of the running engine, as a typed and comparable struct.
+    ///
+    /// Typed counterpart of `get_version_info()`. To only check the API level, prefer
+    /// [`is_godot_version_at_least()`][crate::tools::is_godot_version_at_least].
+    pub fn version(&self) -> crate::tools::GodotVersion {
+        crate::tools::GodotVersion::from_dictionary(&self.get_version_info())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `InputEvent` class.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::InputEvent {
+    /// Casts the event to its concrete class, see [`InputEventView`][crate::tools::InputEventView].
+    pub fn classify(&self) -> crate::tools::InputEventView {
+        crate::tools::InputEventView::classify(self.__to_gd())
+    }
+
+    /// Whether this is a press of `key`, ignoring echo events from holding the key down.
+    pub fn is_key_just_pressed(&self, key: crate::global::Key) -> bool {
+        self.as_key_event(key)
+            .is_some_and(|event| event.is_pressed() && !event.is_echo())
+    }
+
+    /// Whether this is a release of `key`.
+    pub fn is_key_released(&self, key: crate::global::Key) -> bool {
+        self.as_key_event(key)
+            .is_some_and(|event| event.is_released())
+    }
+
+    /// Whether this is a press of the mouse button `button`.
+    pub fn is_mouse_button_pressed(&self, button: crate::global::MouseButton) -> bool {
+        self.__to_gd()
+            .try_cast::<crate::classes::InputEventMouseButton>()
+            .is_ok_and(|event| event.get_button_index() == button && event.is_pressed())
+    }
+
+    fn as_key_event(&self, key: crate::global::Key) -> Option<Gd<crate::classes::InputEventKey>> {
+        self.__to_gd()
+            .try_cast::<crate::classes::InputEventKey>()
+            .ok()
+            .filter(|event| event.get_keycode() == key)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `EditorInterface` class.
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+impl crate::classes::EditorInterface {
+    /// Returns the editor interface singleton, or `None` if not running in the editor.
+    ///
+    /// [`singleton()`][Self::singleton] panics outside the editor (e.g. in exported games or in `tool` classes instantiated at runtime),
+    /// because Godot only registers this singleton while the editor is running.
+    pub fn try_singleton() -> Option<Gd<Self>> {
+        let engine = crate::classes::Engine::singleton();
+
+        // Check first to avoid Godot's error message about non-existent singletons.
+        if engine.has_singleton("EditorInterface") {
+            Some(Self::singleton())
+        } else {
+            None
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `ProjectSettings` class.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::ProjectSettings {
+    /// Reads the setting at `path` and converts it to `T`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::classes::ProjectSettings;
+    ///
+    /// let gravity = ProjectSettings::singleton()
+    ///     .get_as::<f32>("physics/2d/default_gravity")
+    ///     .expect("gravity setting");
+    /// ```
+    pub fn get_as<T: crate::meta::FromGodot>(
+        &self,
+        path: impl AsArg<GString>,
+    ) -> Result<T, crate::meta::error::SettingError> {
+        use crate::meta::error::SettingError;
+
+        arg_into_ref!(path);
+
+        // Check first, since get_setting() returns nil for missing settings, which may convert successfully (e.g. to Option<Gd<T>>).
+        if !self.has_setting(path) {
+            return Err(SettingError::NotFound { path: path.clone() });
+        }
+
+        self.get_setting(path)
+            .try_to::<T>()
+            .map_err(|error| SettingError::WrongType {
+                path: path.clone(),
+                error,
+            })
+    }
+
+    /// Reads the setting at `path` as `T`, or returns `default` if it is missing or has a different type.
+    pub fn get_or<T: crate::meta::FromGodot>(&self, path: impl AsArg<GString>, default: T) -> T {
+        self.get_as(path).unwrap_or(default)
+    }
+
+    /// Registers a custom setting, so that it appears in the editor's project settings and is saved when it deviates from `default`.
+    ///
+    /// If the setting does not exist yet, it is set to `default`. An existing value (e.g. loaded from `project.godot`) is kept.
+    /// The type shown in the editor is derived from `default`; `hint_info` can refine it, e.g. with a range or enum hint.
+    ///
+    /// This does not save the project settings to disk; call [`save()`][Self::save] for that.
+    pub fn register_setting<T: crate::meta::ToGodot>(
+        &mut self,
+        path: impl AsArg<GString>,
+        default: T,
+        hint_info: crate::meta::PropertyHintInfo,
+        visibility: crate::tools::SettingVisibility,
+    ) {
+        use crate::obj::EngineEnum;
+
+        arg_into_ref!(path);
+
+        let default = default.to_variant();
+        if !self.has_setting(path) {
+            self.set_setting(path, &default);
+        }
+
+        self.set_initial_value(path, &default);
+        self.set_as_basic(path, visibility == crate::tools::SettingVisibility::Basic);
+
+        let property_info = crate::builtin::dict! {
+            "name": path.clone(),
+            "type": default.get_type().ord(),
+            "hint": hint_info.hint.ord(),
+            "hint_string": hint_info.hint_string,
+        };
+        self.add_property_info(&property_info);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Image` class.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::Image {
+    /// Creates an image in [`Format::RGBA8`][crate::classes::image::Format::RGBA8] from raw pixel bytes, copying them once.
+    ///
+    /// `data` holds `width * height` pixels of 4 bytes each (`[r, g, b, a]`), row by row, starting at the top-left pixel.
+    ///
+    /// # Panics
+    /// If `data.len()` does not match the size, or if the size is invalid (e.g. negative or larger than Godot's maximum image size).
+    pub fn from_rgba8(width: i32, height: i32, data: &[u8]) -> Gd<Self> {
+        let expected_len = width.max(0) as usize * height.max(0) as usize * 4;
+        assert_eq!(
+            data.len(),
+            expected_len,
+            "Image::from_rgba8(): {width}x{height} image needs {expected_len} bytes, but {} were given",
+            data.len()
+        );
+
+        let data = crate::builtin::PackedByteArray::from(data);
+
+        Self::create_from_data(
+            width,
+            height,
+            false,
+            crate::classes::image::Format::RGBA8,
+            &data,
+        )
+        .unwrap_or_else(|| panic!("Image::from_rgba8(): cannot create {width}x{height} image"))
+    }
+
+    /// Read-only view of the raw pixel data, without copying it.
+    ///
+    /// See [`ImageView`][crate::tools::ImageView] for the data layout and typed per-format accessors.
+    pub fn data_view(&self) -> crate::tools::ImageView {
+        crate::tools::ImageView::new(self)
+    }
+
+    /// Mutable view of the raw pixel data; changes are written back when the returned guard is dropped.
+    ///
+    /// Much faster than `set_pixel()` for bulk updates. See [`ImageViewMut`][crate::tools::ImageViewMut] for details.
+    pub fn data_view_mut(&mut self) -> crate::tools::ImageViewMut<'_> {
+        crate::tools::ImageViewMut::new(self)
+    }
+
+    /// Creates an image from an [`image::DynamicImage`](https://docs.rs/image/0.25/image/enum.DynamicImage.html), copying its pixels.
+    ///
+    /// 8-bit luma/RGB(A) images map to `L8`, `LA8`, `RGB8` and `RGBA8`; 32-bit float images to `RGBF` and `RGBAF`. 16-bit images have no
+    /// Godot counterpart and become `RGBF`/`RGBAF`, which represents them without loss.
+    ///
+    /// An [`image::RgbaImage`](https://docs.rs/image/0.25/image/type.RgbaImage.html) can be passed via `DynamicImage::from(rgba)`.
+    ///
+    /// # Panics
+    /// If the size is larger than Godot's maximum image size.
+    #[cfg(feature = "image-interop")]
+    pub fn from_dynamic_image(source: &::image::DynamicImage) -> Gd<Self> {
+        crate::tools::from_dynamic_image(source)
+    }
+
+    /// Converts the full-size image (without mipmaps) to an [`image::DynamicImage`](https://docs.rs/image/0.25/image/enum.DynamicImage.html).
+    ///
+    /// `L8`, `LA8`, `RGB8`, `RGBA8`, `RGBF` and `RGBAF` are copied as-is. Other uncompressed formats are converted losslessly to the
+    /// closest of those, e.g. half floats to `RGBF`/`RGBAF` and `R8` to `RGB8`. Use `into_rgba8()` and similar on the result to obtain
+    /// a specific buffer type such as `RgbaImage`.
+    ///
+    /// Returns an error for compressed formats and `RGBE9995`; call `decompress()` or `convert()` first.
+    #[cfg(feature = "image-interop")]
+    pub fn to_dynamic_image(
+        &self,
+    ) -> Result<::image::DynamicImage, crate::tools::UnsupportedFormat> {
+        crate::tools::to_dynamic_image(self)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `JSON` class.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::Json {
+    /// Parses JSON `text` and converts the result to `T`.
+    ///
+    /// Typed counterpart of `parse()` + `get_data()`. Objects become [`Dictionary`][crate::builtin::Dictionary] and arrays
+    /// [`VariantArray`][crate::builtin::VariantArray], so these or any [`FromGodot`][crate::meta::FromGodot] types built on them can
+    /// be used as `T`. Note that JSON has no integers: Godot parses all numbers as `f64`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::Dictionary;
+    /// use godot::classes::Json;
+    ///
+    /// let config: Dictionary = Json::parse_to(r#"{ "volume": 0.8, "fullscreen": true }"#)
+    ///     .expect("valid config");
+    ///
+    /// let volume = config.get_or_nil("volume").to::<f64>();
+    /// assert_eq!(volume, 0.8);
+    /// ```
+    pub fn parse_to<T: crate::meta::FromGodot>(
+        text: impl AsArg<GString>,
+    ) -> Result<T, crate::meta::error::JsonError> {
+        use crate::global::Error as GodotError;
+        use crate::meta::error::JsonError;
+        use crate::obj::NewGd;
+
+        arg_into_ref!(text);
+
+        // Instance instead of static parse_string(), which doesn't report errors.
+        let mut json = Self::new_gd();
+        if json.parse(text) != GodotError::OK {
+            return Err(JsonError::Parse {
+                line: json.get_error_line(),
+                message: json.get_error_message(),
+            });
+        }
+
+        json.get_data()
+            .try_to::<T>()
+            .map_err(|error| JsonError::WrongType { error })
+    }
+
+    /// Converts `value` to JSON text, without indentation and with sorted keys.
+    ///
+    /// Typed counterpart of `stringify()`. Values without a JSON equivalent, such as vectors or objects, are converted to strings by
+    /// Godot; use `stringify_ex()` for control over indentation and precision.
+    pub fn stringify_from<T: crate::meta::ToGodot>(value: &T) -> GString {
+        Self::stringify(&value.to_variant())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Mesh` class.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::Mesh {
+    /// Reads surface `surface_idx` into typed vectors. Returns `None` if there is no such surface.
+    ///
+    /// Typed counterpart of `surface_get_arrays()`; see [`MeshArrays`][crate::tools::MeshArrays].
+    pub fn surface_mesh_arrays(&self, surface_idx: i32) -> Option<crate::tools::MeshArrays> {
+        if surface_idx < 0 || surface_idx >= self.get_surface_count() {
+            return None;
+        }
+
+        crate::tools::MeshArrays::from_arrays(&self.surface_get_arrays(surface_idx))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `ShaderMaterial` class.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::ShaderMaterial {
+    /// Sets the shader uniform `name` to `value`.
+    ///
+    /// Typed counterpart of `set_shader_parameter()`. The `StringName` for `name` is created once and then cached.
+    pub fn set_param<T: crate::meta::ToGodot>(&mut self, name: &str, value: T) {
+        let name = crate::tools::cached_param_name(name);
+
+        self.set_shader_parameter(&name, &value.to_variant());
+    }
+
+    /// Reads the shader uniform `name` and converts it to `T`.
+    ///
+    /// Uniforms which have not been set, or which don't exist, are read as nil. Their conversion only succeeds for nil-compatible
+    /// types such as `Option<Gd<Texture2D>>` or `Variant`.
+    pub fn get_param_as<T: crate::meta::FromGodot>(
+        &self,
+        name: &str,
+    ) -> Result<T, crate::meta::error::ConvertError> {
+        let name = crate::tools::cached_param_name(name);
+
+        self.get_shader_parameter(&name).try_to::<T>()
+    }
+
+    /// Checks that the shader declares all uniforms of `P`, with matching types.
+    ///
+    /// Returns all mismatches at once. Intended to be called once at startup, e.g. in `ready()`, to catch shaders that went out of sync
+    /// with the Rust code.
+    ///
+    /// The check only runs in debug builds and in the editor; otherwise, this returns `Ok(())` without querying the shader.
+    pub fn validate_params<P: crate::tools::ShaderParams>(
+        &self,
+    ) -> Result<(), Vec<crate::meta::error::ShaderParamError>> {
+        crate::tools::validate_params::<P>(self)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `TileMapLayer` class.
+#[cfg(all(feature = "codegen-full", since_api = "4.3"))]
+impl crate::classes::TileMapLayer {
+    /// Returns the tile placed at cell `coords`, or `None` if the cell is empty.
+    ///
+    /// Combines `get_cell_source_id()`, `get_cell_atlas_coords()` and `get_cell_alternative_tile()`.
+    pub fn get_tile_cell(
+        &self,
+        coords: crate::builtin::Vector2i,
+    ) -> Option<crate::tools::TileCell> {
+        let source_id = self.get_cell_source_id(coords);
+        if source_id == crate::tools::TileCell::INVALID_SOURCE {
+            return None;
+        }
+
+        Some(crate::tools::TileCell {
+            source_id,
+            atlas_coords: self.get_cell_atlas_coords(coords),
+            alternative_tile: self.get_cell_alternative_tile(coords),
+        })
+    }
+
+    /// Places `tile` at cell `coords`, or erases the cell if `tile` is `None`.
+    pub fn set_tile_cell(
+        &mut self,
+        coords: crate::builtin::Vector2i,
+        tile: Option<crate::tools::TileCell>,
+    ) {
+        match tile {
+            Some(tile) => self
+                .set_cell_ex(coords)
+                .source_id(tile.source_id)
+                .atlas_coords(tile.atlas_coords)
+                .alternative_tile(tile.alternative_tile)
+                .done(),
+            None => self.erase_cell(coords),
+        }
+    }
+
+    /// Returns all non-empty cells together with their tiles.
+    pub fn used_tile_cells(&self) -> Vec<(crate::builtin::Vector2i, crate::tools::TileCell)> {
+        self.get_used_cells()
+            .iter_shared()
+            .filter_map(|coords| Some((coords, self.get_tile_cell(coords)?)))
+            .collect()
+    }
+
+    /// Converts cell coordinates to the global position of the cell's center.
+    ///
+    /// Like `map_to_local()`, followed by `to_global()`. The layer must be inside the scene tree.
+    pub fn map_to_global(&self, coords: crate::builtin::Vector2i) -> crate::builtin::Vector2 {
+        self.to_global(self.map_to_local(coords))
+    }
+
+    /// Converts a global position to the coordinates of the cell containing it.
+    ///
+    /// Like `to_local()`, followed by `local_to_map()`. The layer must be inside the scene tree.
+    pub fn global_to_map(
+        &self,
+        global_position: crate::builtin::Vector2,
+    ) -> crate::builtin::Vector2i {
+        self.local_to_map(self.to_local(global_position))
     }
 }
@@ -0,0 +1,69 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/// Dispatches on the dynamic class of an object, binding the downcast `Gd` in each arm.
+///
+/// Each arm of the form `Class(binding) => expr` attempts [`Gd::try_cast::<Class>()`][crate::obj::Gd::try_cast]. Arms are tried in
+/// order; the first successful cast wins. Since a failed cast hands back the original pointer, no extra reference is created along
+/// the way.
+///
+/// The last arm can optionally be a catch-all:
+/// - `_ => expr` discards the object.
+/// - `name => expr` binds the original, non-downcast `Gd` to `name`.
+///
+/// Without a catch-all, all arms must evaluate to `()`, and objects matching no arm are silently ignored.
+///
+/// Arms must be separated by commas, also when their body is a block. Class names must be simple identifiers (import them first).
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::{match_class, Node, Node2D, Node3D};
+/// use godot::prelude::*;
+///
+/// fn describe(node: Gd<Node>) -> String {
+///     match_class!(node, {
+///         Node2D(node2d) => format!("2D node at {}", node2d.get_position()),
+///         Node3D(node3d) => {
+///             let position = node3d.get_position();
+///             format!("3D node at {position}")
+///         },
+///         other => format!("other node: {other}"),
+///     })
+/// }
+/// ```
+#[macro_export]
+macro_rules! match_class {
+    ($subject:expr, { $($arms:tt)* }) => {{
+        let __match_class_subject = $subject;
+        $crate::match_class!(@arm __match_class_subject; $($arms)*)
+    }};
+
+    // Catch-all, discarding the object.
+    (@arm $subject:ident; _ => $body:expr $(,)?) => {{
+        let _ = $subject;
+        $body
+    }};
+
+    // Downcast arm.
+    (@arm $subject:ident; $class:ident ( $bind:pat ) => $body:expr $(, $($rest:tt)*)?) => {
+        match $subject.try_cast::<$class>() {
+            Ok($bind) => $body,
+            Err($subject) => $crate::match_class!(@arm $subject; $($($rest)*)?),
+        }
+    };
+
+    // Catch-all, binding the original object.
+    (@arm $subject:ident; $other:ident => $body:expr $(,)?) => {{
+        let $other = $subject;
+        $body
+    }};
+
+    // No catch-all: unmatched objects are ignored.
+    (@arm $subject:ident;) => {{
+        let _ = $subject;
+    }};
+}
@@ -18,10 +18,13 @@
 
 mod class_runtime;
 mod manual_extensions;
+mod match_class;
 
 // Re-exports all generated classes, interface traits and sidecar modules.
 pub use crate::gen::classes::*;
 
+pub use crate::match_class;
+
 /// Support for Godot _native structures_.
 ///
 /// Native structures are a niche API in Godot. These are low-level data types that are passed as pointers to/from the engine.
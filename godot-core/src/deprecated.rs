@@ -54,6 +54,10 @@ pub const fn class_hidden() {}
     More information on https://github.com/godot-rust/gdext/pull/959"]
 pub const fn gdextension_entry_point() {}
 
+#[deprecated = "\nThe Cargo feature `lazy-function-tables` has no effect, as engine function pointers are now loaded lazily by default; \
+    remove it. To load them at startup instead, use the feature `eager-function-tables`."]
+pub const fn feature_lazy_function_tables() {}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Godot-side deprecations
 
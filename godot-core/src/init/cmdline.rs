@@ -0,0 +1,125 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use crate::builtin::PackedStringArray;
+use crate::classes::Os;
+
+/// Returns the command-line arguments of the running Godot process, split into engine and user arguments.
+///
+/// User arguments are those after a `--` (or `++`) separator, e.g. `godot --headless -- --mode=export out.pck`. They are not interpreted
+/// by Godot and can be used to configure headless tools such as test runners or exporters.
+///
+/// Available from [`InitLevel::Scene`][super::InitLevel::Scene] onward, e.g. in [`on_level_init()`][super::ExtensionLibrary::on_level_init].
+/// The arguments are read each time this is called, so store the result if needed often.
+///
+/// # Example
+/// ```no_run
+/// use godot::init::cmdline;
+///
+/// // godot --headless -- --mode=export --verbose out.pck
+/// let args = cmdline();
+/// assert_eq!(args.get("mode"), Some("export"));
+/// assert!(args.has("verbose"));
+/// assert_eq!(args.positional(), ["out.pck"]);
+/// ```
+pub fn cmdline() -> CmdlineArgs {
+    let os = Os::singleton();
+
+    CmdlineArgs::from_args(
+        packed_to_strings(os.get_cmdline_args()),
+        packed_to_strings(os.get_cmdline_user_args()),
+    )
+}
+
+fn packed_to_strings(array: PackedStringArray) -> Vec<String> {
+    array.as_slice().iter().map(|arg| arg.to_string()).collect()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Command-line arguments of the Godot process, as returned by [`cmdline()`].
+///
+/// User arguments are parsed as follows:
+/// - `--key=value` is an option with value `value`.
+/// - `--key` is an option with an empty value, i.e. a flag.
+/// - Everything else (including `-k`) is a positional argument.
+///
+/// If an option occurs multiple times, the last value wins. All user arguments remain available in original order through
+/// [`user_args()`][Self::user_args].
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct CmdlineArgs {
+    engine_args: Vec<String>,
+    user_args: Vec<String>,
+    options: HashMap<String, String>,
+    positional: Vec<String>,
+}
+
+impl CmdlineArgs {
+    /// Builds the structured view from already split arguments.
+    ///
+    /// Mostly useful for testing; [`cmdline()`] obtains the arguments from Godot.
+    pub fn from_args<E, U>(engine_args: E, user_args: U) -> Self
+    where
+        E: IntoIterator,
+        E::Item: Into<String>,
+        U: IntoIterator,
+        U::Item: Into<String>,
+    {
+        let user_args: Vec<String> = user_args.into_iter().map(Into::into).collect();
+
+        let mut options = HashMap::new();
+        let mut positional = Vec::new();
+        for arg in &user_args {
+            match arg.strip_prefix("--").filter(|option| !option.is_empty()) {
+                Some(option) => {
+                    let (key, value) = option.split_once('=').unwrap_or((option, ""));
+                    options.insert(key.to_string(), value.to_string());
+                }
+                None => positional.push(arg.clone()),
+            }
+        }
+
+        Self {
+            engine_args: engine_args.into_iter().map(Into::into).collect(),
+            user_args,
+            options,
+            positional,
+        }
+    }
+
+    /// Arguments interpreted by Godot, e.g. `--headless` or `--path <dir>`. Does not include the executable.
+    pub fn engine_args(&self) -> &[String] {
+        &self.engine_args
+    }
+
+    /// Arguments after the `--` separator, unparsed and in original order.
+    pub fn user_args(&self) -> &[String] {
+        &self.user_args
+    }
+
+    /// All `--key=value` options and `--key` flags among the user arguments, keyed without the `--` prefix.
+    pub fn options(&self) -> &HashMap<String, String> {
+        &self.options
+    }
+
+    /// Value of the option `--key=value`, or `Some("")` for a flag `--key`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.options.get(key).map(String::as_str)
+    }
+
+    /// Whether the user arguments contain the option `--key` or `--key=...`.
+    pub fn has(&self, key: &str) -> bool {
+        self.options.contains_key(key)
+    }
+
+    /// User arguments that are not options, in original order.
+    pub fn positional(&self) -> &[String] {
+        &self.positional
+    }
+}
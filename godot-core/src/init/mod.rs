@@ -14,6 +14,9 @@ use sys::GodotFfi;
 use crate::builtin::{GString, StringName};
 use crate::out;
 
+mod cmdline;
+
+pub use cmdline::*;
 pub use sys::GdextBuild;
 
 #[cfg(not(wasm_nothreads))]
@@ -116,6 +119,13 @@ unsafe extern "C" fn ffi_initialize_layer<E: ExtensionLibrary>(
         // SAFETY: Godot will call this from the main thread, after `__gdext_load_library` where the library is initialized,
         // and only once per level.
         unsafe { gdext_on_level_init(level) };
+
+        #[cfg(since_api = "4.2")]
+        if level == InitLevel::Scene {
+            crate::task::install_runtime(E::async_runtime());
+            crate::task::install_main_thread_gate();
+        }
+
         E::on_level_init(level);
     }
 
@@ -180,6 +190,15 @@ unsafe fn gdext_on_level_init(level: InitLevel) {
 
 /// Tasks needed to be done by gdext internally upon unloading an initialization level. Called after user code.
 fn gdext_on_level_deinit(level: InitLevel) {
+    if level == InitLevel::Scene {
+        // Custom loaders/savers hold instances of classes which are about to be unregistered.
+        crate::tools::unregister_resource_formats();
+
+        // Queued closures may capture user types; drop them while their classes are still registered.
+        #[cfg(since_api = "4.2")]
+        crate::task::uninstall_main_thread_gate();
+    }
+
     crate::registry::class::unregister_classes(level);
 
     if level == InitLevel::Core {
@@ -189,6 +208,10 @@ fn gdext_on_level_deinit(level: InitLevel) {
         #[cfg(since_api = "4.2")]
         crate::task::cleanup();
 
+        crate::tools::clear_input_action_names();
+        #[cfg(feature = "codegen-full")]
+        crate::tools::clear_shader_param_names();
+
         // Garbage-collect various statics.
         // SAFETY: this is the last time meta APIs are used.
         unsafe {
@@ -282,6 +305,16 @@ pub unsafe trait ExtensionLibrary {
         // Nothing by default.
     }
 
+    /// Third-party async runtime to integrate with Godot tasks, e.g. `TokioRuntime` with the `tokio` feature. Return `None` (the default)
+    /// to only use the engine-driven executor.
+    ///
+    /// The runtime is created when the `Scene` init level is loaded, and dropped on shutdown. See [`AsyncRuntime`][crate::task::AsyncRuntime]
+    /// for how it interacts with [`task::spawn()`][crate::task::spawn].
+    #[cfg(since_api = "4.2")]
+    fn async_runtime() -> Option<Box<dyn crate::task::AsyncRuntime>> {
+        None
+    }
+
     /// Whether to override the Wasm binary filename used by your GDExtension which the library should expect at runtime. Return `None`
     /// to use the default where gdext expects either `{YourCrate}.wasm` (default binary name emitted by Rust) or
     /// `{YourCrate}.threads.wasm` (for builds producing separate single-threaded and multi-threaded binaries).
@@ -418,6 +451,12 @@ unsafe fn ensure_godot_features_compatible() {
         }
     };
 
+    // Names as in extension_api.json's `builtin_class_sizes`, e.g. "double_64".
+    let build_config = |is_double: bool| -> String {
+        let precision = if is_double { "double" } else { "float" };
+        format!("{precision}_{}", usize::BITS)
+    };
+
     out!(
         "Is double precision: Godot={}, gdext={}",
         s(godot_is_double),
@@ -426,9 +465,10 @@ unsafe fn ensure_godot_features_compatible() {
 
     if godot_is_double != gdext_is_double {
         panic!(
-            "Godot runs with {} precision, but gdext was compiled with {} precision.\n\
+            "Godot runs with {} precision (build configuration `{}`), but gdext was compiled with {} precision (`{}`).\n\
             Cargo feature `double-precision` must be used if and only if Godot is compiled with `precision=double`.\n",
-            s(godot_is_double), s(gdext_is_double),
+            s(godot_is_double), build_config(godot_is_double),
+            s(gdext_is_double), build_config(gdext_is_double),
         );
     }
 }
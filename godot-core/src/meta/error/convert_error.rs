@@ -188,6 +188,13 @@ pub(crate) enum FromGodotError {
     #[cfg(debug_assertions)]
     BadArrayTypeInt { expected: ArrayTypeInfo, value: i64 },
 
+    /// Destination `TypedDictionary<K, V>` has different key or value types than source's runtime types.
+    #[cfg(since_api = "4.4")]
+    BadDictionaryType {
+        expected: (ArrayTypeInfo, ArrayTypeInfo),
+        actual: (ArrayTypeInfo, ArrayTypeInfo),
+    },
+
     /// InvalidEnum is also used by bitfields.
     InvalidEnum,
 
@@ -253,6 +260,14 @@ impl fmt::Display for FromGodotError {
                     "integer value {value} does not fit into Array of type {expected:?}"
                 )
             }
+            #[cfg(since_api = "4.4")]
+            Self::BadDictionaryType { expected, actual } => {
+                write!(
+                    f,
+                    "expected dictionary of type {:?} => {:?}, got dictionary of type {:?} => {:?}",
+                    expected.0, expected.1, actual.0, actual.1
+                )
+            }
             Self::InvalidEnum => write!(f, "invalid engine enum value"),
             Self::ZeroInstanceId => write!(f, "`InstanceId` cannot be 0"),
             Self::UnimplementedDynTrait {
@@ -0,0 +1,102 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::GString;
+use crate::meta::error::ConvertError;
+
+/// Error when parsing an expression.
+///
+/// Returned by [`GExpression::parse()`][crate::tools::GExpression::parse].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ExpressionParseError {
+    source: GString,
+    error_text: GString,
+}
+
+impl ExpressionParseError {
+    pub(crate) fn new(source: GString, error_text: GString) -> Self {
+        Self { source, error_text }
+    }
+
+    /// The expression that failed to parse.
+    pub fn source_text(&self) -> &GString {
+        &self.source
+    }
+
+    /// Error message reported by Godot, see `Expression::get_error_text()`.
+    pub fn error_text(&self) -> &GString {
+        &self.error_text
+    }
+}
+
+impl fmt::Display for ExpressionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to parse expression `{}`: {}",
+            self.source, self.error_text
+        )
+    }
+}
+
+impl Error for ExpressionParseError {}
+
+/// Error when executing a parsed expression.
+///
+/// Returned by [`GExpression::execute()`][crate::tools::GExpression::execute] and
+/// [`GExpression::execute_as()`][crate::tools::GExpression::execute_as].
+#[derive(Debug)]
+pub enum ExpressionEvalError {
+    /// The number of inputs differs from the number of input names passed to `parse()`.
+    WrongInputCount {
+        /// Number of input names.
+        expected: usize,
+
+        /// Number of provided inputs.
+        actual: usize,
+    },
+
+    /// Execution failed at runtime, e.g. due to a division by zero or a call to a non-existent method.
+    Execution {
+        /// Error message reported by Godot, see `Expression::get_error_text()`.
+        error_text: GString,
+    },
+
+    /// The expression was evaluated, but its result could not be converted to the requested type.
+    WrongType {
+        /// Underlying conversion error, which contains the result.
+        error: ConvertError,
+    },
+}
+
+impl fmt::Display for ExpressionEvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongInputCount { expected, actual } => {
+                write!(f, "expression expects {expected} inputs, got {actual}")
+            }
+            Self::Execution { error_text } => {
+                write!(f, "failed to execute expression: {error_text}")
+            }
+            Self::WrongType { error } => {
+                write!(f, "expression result has unexpected type: {error}")
+            }
+        }
+    }
+}
+
+impl Error for ExpressionEvalError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::WrongInputCount { .. } | Self::Execution { .. } => None,
+            Self::WrongType { error } => Some(error),
+        }
+    }
+}
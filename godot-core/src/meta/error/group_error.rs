@@ -0,0 +1,85 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::{GString, NodePath, StringName};
+use crate::meta::ClassName;
+
+/// Error when a member of a scene tree group cannot be used by a typed group operation.
+///
+/// Returned by `SceneTree` methods such as [`nodes_in_group_as()`][crate::classes::SceneTree::nodes_in_group_as], if the policy is
+/// [`GroupMemberPolicy::Error`][crate::tools::GroupMemberPolicy::Error].
+#[derive(Clone, Debug)]
+pub struct GroupMemberError {
+    group: StringName,
+    node_path: NodePath,
+    kind: GroupMemberErrorKind,
+}
+
+#[derive(Clone, Debug)]
+enum GroupMemberErrorKind {
+    WrongType {
+        expected: ClassName,
+        actual: GString,
+    },
+    Bound,
+}
+
+impl GroupMemberError {
+    pub(crate) fn wrong_type(
+        group: StringName,
+        node_path: NodePath,
+        expected: ClassName,
+        actual: GString,
+    ) -> Self {
+        Self {
+            group,
+            node_path,
+            kind: GroupMemberErrorKind::WrongType { expected, actual },
+        }
+    }
+
+    pub(crate) fn bound(group: StringName, node_path: NodePath) -> Self {
+        Self {
+            group,
+            node_path,
+            kind: GroupMemberErrorKind::Bound,
+        }
+    }
+
+    /// Name of the group.
+    pub fn group(&self) -> &StringName {
+        &self.group
+    }
+
+    /// Path of the member node that caused the error.
+    pub fn node_path(&self) -> &NodePath {
+        &self.node_path
+    }
+}
+
+impl fmt::Display for GroupMemberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let group = &self.group;
+        let path = &self.node_path;
+
+        match &self.kind {
+            GroupMemberErrorKind::WrongType { expected, actual } => write!(
+                f,
+                "node '{path}' in group '{group}' has class `{actual}`, which is not `{expected}` or derived"
+            ),
+            GroupMemberErrorKind::Bound => write!(
+                f,
+                "node '{path}' in group '{group}' is currently bound and cannot be borrowed mutably"
+            ),
+        }
+    }
+}
+
+impl Error for GroupMemberError {}
@@ -0,0 +1,57 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::classes::http_request;
+use crate::global::Error as GodotError;
+
+/// Error of an HTTP request made with [`http_get()`][crate::tools::http_get] or [`HttpRequestBuilder`][crate::tools::HttpRequestBuilder].
+///
+/// HTTP status codes such as 404 are not errors; they are reported through [`HttpResponse::status()`][crate::tools::HttpResponse::status].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum HttpError {
+    /// The request could not be started, e.g. because the URL is malformed or there is no scene tree to add the `HTTPRequest` node to.
+    Start(GodotError),
+
+    /// No response was received within the configured timeout.
+    Timeout,
+
+    /// The TLS handshake failed, e.g. due to an invalid certificate.
+    Tls,
+
+    /// The request failed for another reason, such as an unreachable host.
+    Failed(http_request::Result),
+
+    /// The `HTTPRequest` node was freed before the request completed.
+    Cancelled,
+}
+
+impl HttpError {
+    pub(crate) fn from_result(result: http_request::Result) -> Self {
+        match result {
+            http_request::Result::TIMEOUT => Self::Timeout,
+            http_request::Result::TLS_HANDSHAKE_ERROR => Self::Tls,
+            other => Self::Failed(other),
+        }
+    }
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Start(err) => write!(f, "HTTP request could not be started: {err:?}"),
+            Self::Timeout => write!(f, "HTTP request timed out"),
+            Self::Tls => write!(f, "HTTP request failed in TLS handshake"),
+            Self::Failed(result) => write!(f, "HTTP request failed: {result:?}"),
+            Self::Cancelled => write!(f, "HTTP request was cancelled"),
+        }
+    }
+}
+
+impl Error for HttpError {}
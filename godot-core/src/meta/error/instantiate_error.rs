@@ -0,0 +1,46 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::GString;
+use crate::meta::ClassName;
+
+/// Error that can occur when instantiating a `PackedScene` as a specific node type.
+///
+/// Returned by [`PackedScene::try_instantiate_with_state()`][crate::classes::PackedScene::try_instantiate_with_state].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum InstantiateError {
+    /// Godot could not instantiate the scene, e.g. because it has no nodes or its dependencies are missing.
+    Failed,
+
+    /// The scene was instantiated, but its root node does not have the expected type (or a derived one).
+    ///
+    /// The instantiated nodes have been freed.
+    WrongType {
+        /// Class that was requested.
+        expected: ClassName,
+
+        /// Dynamic class of the scene's root node.
+        actual: GString,
+    },
+}
+
+impl fmt::Display for InstantiateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Failed => write!(f, "scene could not be instantiated"),
+            Self::WrongType { expected, actual } => write!(
+                f,
+                "scene root has class `{actual}`, which is not `{expected}` or derived"
+            ),
+        }
+    }
+}
+
+impl Error for InstantiateError {}
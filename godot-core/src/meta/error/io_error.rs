@@ -24,6 +24,10 @@ impl fmt::Display for IoError {
             ErrorData::Load(err) => err.fmt(f),
             ErrorData::Save(err) => err.fmt(f),
             ErrorData::GFile(err) => err.fmt(f),
+            ErrorData::Dir(err) => err.fmt(f),
+            ErrorData::Copy(err) => err.fmt(f),
+            #[cfg(feature = "codegen-full")]
+            ErrorData::Config(err) => err.fmt(f),
         }
     }
 }
@@ -35,6 +39,10 @@ impl Error for IoError {
             ErrorData::Load(err) => Some(err),
             ErrorData::Save(err) => Some(err),
             ErrorData::GFile(err) => Some(err),
+            ErrorData::Dir(err) => Some(err),
+            ErrorData::Copy(err) => Some(err),
+            #[cfg(feature = "codegen-full")]
+            ErrorData::Config(err) => Some(err),
         }
     }
 }
@@ -70,6 +78,37 @@ impl IoError {
         }
     }
 
+    pub(crate) fn dir_access(operation: &'static str, path: String, error: GodotError) -> Self {
+        Self {
+            data: ErrorData::Dir(DirError {
+                operation,
+                path,
+                godot_error: error,
+            }),
+        }
+    }
+
+    pub(crate) fn copying(from: String, to: String, error: GodotError) -> Self {
+        Self {
+            data: ErrorData::Copy(CopyError {
+                from,
+                to,
+                godot_error: error,
+            }),
+        }
+    }
+
+    #[cfg(feature = "codegen-full")]
+    pub(crate) fn config_file(operation: &'static str, path: String, error: GodotError) -> Self {
+        Self {
+            data: ErrorData::Config(ConfigError {
+                operation,
+                path,
+                godot_error: error,
+            }),
+        }
+    }
+
     pub(crate) fn check_unique_open_file_access(
         file_access: Gd<FileAccess>,
     ) -> Result<Gd<FileAccess>, Self> {
@@ -103,6 +142,10 @@ enum ErrorData {
     Load(LoaderError),
     Save(SaverError),
     GFile(GFileError),
+    Dir(DirError),
+    Copy(CopyError),
+    #[cfg(feature = "codegen-full")]
+    Config(ConfigError),
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -192,3 +235,78 @@ impl fmt::Display for GFileError {
         }
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct DirError {
+    operation: &'static str,
+    path: String,
+    godot_error: GodotError,
+}
+
+impl Error for DirError {}
+
+impl fmt::Display for DirError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operation = self.operation;
+        let path = &self.path;
+        let godot_error = &self.godot_error;
+
+        write!(
+            f,
+            "can't {operation} '{path}'; Godot error: {godot_error:?}"
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct CopyError {
+    from: String,
+    to: String,
+    godot_error: GodotError,
+}
+
+impl Error for CopyError {}
+
+impl fmt::Display for CopyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let from = &self.from;
+        let to = &self.to;
+        let godot_error = &self.godot_error;
+
+        write!(
+            f,
+            "can't copy file '{from}' to '{to}'; Godot error: {godot_error:?}"
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "codegen-full")]
+#[derive(Debug)]
+struct ConfigError {
+    operation: &'static str,
+    path: String,
+    godot_error: GodotError,
+}
+
+#[cfg(feature = "codegen-full")]
+impl Error for ConfigError {}
+
+#[cfg(feature = "codegen-full")]
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operation = self.operation;
+        let path = &self.path;
+        let godot_error = &self.godot_error;
+
+        write!(
+            f,
+            "can't {operation} config file '{path}'; Godot error: {godot_error:?}"
+        )
+    }
+}
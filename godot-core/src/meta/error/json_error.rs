@@ -0,0 +1,57 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::GString;
+use crate::meta::error::ConvertError;
+
+/// Error when parsing JSON text into a typed value.
+///
+/// Returned by [`Json::parse_to()`][crate::classes::Json::parse_to].
+#[derive(Debug)]
+pub enum JsonError {
+    /// The text is not valid JSON.
+    Parse {
+        /// Line of the error, as counted by Godot's parser (`JSON::get_error_line()`).
+        ///
+        /// Godot only tracks lines, so no column is available.
+        line: i32,
+
+        /// Error message reported by Godot, see `JSON::get_error_message()`.
+        message: GString,
+    },
+
+    /// The text is valid JSON, but its value could not be converted to the requested type.
+    WrongType {
+        /// Underlying conversion error, which contains the parsed value.
+        error: ConvertError,
+    },
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse { line, message } => {
+                write!(f, "invalid JSON at line {line}: {message}")
+            }
+            Self::WrongType { error } => {
+                write!(f, "JSON value has unexpected type: {error}")
+            }
+        }
+    }
+}
+
+impl Error for JsonError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Parse { .. } => None,
+            Self::WrongType { error } => Some(error),
+        }
+    }
+}
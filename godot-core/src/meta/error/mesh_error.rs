@@ -0,0 +1,65 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::classes::mesh::{ArrayType, PrimitiveType};
+
+/// Error when building a mesh surface with [`MeshBuilder`][crate::tools::MeshBuilder].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum MeshError {
+    /// A channel that every surface needs (currently only `ArrayType::VERTEX`) was not provided or is empty.
+    MissingChannel(ArrayType),
+
+    /// A per-vertex channel does not have exactly one entry per vertex.
+    LengthMismatch {
+        channel: ArrayType,
+        expected: usize,
+        actual: usize,
+    },
+
+    /// An index refers to a vertex that does not exist.
+    IndexOutOfRange { index: i32, vertex_count: usize },
+
+    /// The number of indices (or vertices, if there are no indices) does not fit the primitive, e.g. 4 for `TRIANGLES`.
+    IncompletePrimitive {
+        primitive: PrimitiveType,
+        count: usize,
+    },
+}
+
+impl fmt::Display for MeshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingChannel(channel) => write!(f, "mesh channel {channel:?} is missing"),
+            Self::LengthMismatch {
+                channel,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "mesh channel {channel:?} has {actual} entries, but there are {expected} vertices"
+            ),
+            Self::IndexOutOfRange {
+                index,
+                vertex_count,
+            } => write!(
+                f,
+                "mesh index {index} is out of range for {vertex_count} vertices"
+            ),
+            Self::IncompletePrimitive { primitive, count } => {
+                write!(
+                    f,
+                    "{count} elements do not form complete {primitive:?} primitives"
+                )
+            }
+        }
+    }
+}
+
+impl Error for MeshError {}
@@ -9,10 +9,48 @@
 
 mod call_error;
 mod convert_error;
+#[cfg(feature = "codegen-full")]
+mod expression_error;
+mod group_error;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod http_error;
+mod instantiate_error;
 mod io_error;
+#[cfg(feature = "codegen-full")]
+mod json_error;
+#[cfg(feature = "codegen-full")]
+mod mesh_error;
+mod pack_error;
+mod property_error;
+#[cfg(feature = "codegen-full")]
+mod regex_error;
+#[cfg(feature = "codegen-full")]
+mod setting_error;
+#[cfg(feature = "codegen-full")]
+mod shader_param_error;
 mod string_error;
+mod unique_node_error;
 
 pub use call_error::*;
 pub use convert_error::*;
+#[cfg(feature = "codegen-full")]
+pub use expression_error::*;
+pub use group_error::*;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+pub use http_error::*;
+pub use instantiate_error::*;
 pub use io_error::*;
+#[cfg(feature = "codegen-full")]
+pub use json_error::*;
+#[cfg(feature = "codegen-full")]
+pub use mesh_error::*;
+pub use pack_error::*;
+pub use property_error::*;
+#[cfg(feature = "codegen-full")]
+pub use regex_error::*;
+#[cfg(feature = "codegen-full")]
+pub use setting_error::*;
+#[cfg(feature = "codegen-full")]
+pub use shader_param_error::*;
 pub use string_error::*;
+pub use unique_node_error::*;
@@ -0,0 +1,38 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::global::Error as GodotError;
+
+/// Error when packing a node tree into a `PackedScene`.
+///
+/// Returned by [`PackedScene::pack_node()`][crate::classes::PackedScene::pack_node].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct PackError {
+    godot_error: GodotError,
+}
+
+impl PackError {
+    pub(crate) fn new(godot_error: GodotError) -> Self {
+        Self { godot_error }
+    }
+
+    /// Error code returned by `PackedScene::pack()`.
+    pub fn godot_error(&self) -> GodotError {
+        self.godot_error
+    }
+}
+
+impl fmt::Display for PackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to pack node tree: {:?}", self.godot_error)
+    }
+}
+
+impl Error for PackError {}
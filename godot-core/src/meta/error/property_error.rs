@@ -0,0 +1,60 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::{GString, StringName};
+use crate::meta::error::ConvertError;
+
+/// Error when reading or writing a property by name.
+///
+/// Returned by [`Gd::try_get_property()`][crate::obj::Gd::try_get_property], [`Gd::try_set_property()`][crate::obj::Gd::try_set_property]
+/// and [`Gd::try_set_properties()`][crate::obj::Gd::try_set_properties].
+#[derive(Debug)]
+pub enum PropertyError {
+    /// The object has no property with this name.
+    NotFound {
+        /// Dynamic class of the object.
+        class: GString,
+
+        /// Name of the property.
+        property: StringName,
+    },
+
+    /// The property exists, but its value could not be converted to the requested type, or a value to be written does not match the
+    /// property's type.
+    WrongType {
+        /// Name of the property.
+        property: StringName,
+
+        /// Underlying conversion error, which contains the value.
+        error: ConvertError,
+    },
+}
+
+impl fmt::Display for PropertyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound { class, property } => {
+                write!(f, "class `{class}` has no property `{property}`")
+            }
+            Self::WrongType { property, error } => {
+                write!(f, "property `{property}` has unexpected type: {error}")
+            }
+        }
+    }
+}
+
+impl Error for PropertyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NotFound { .. } => None,
+            Self::WrongType { error, .. } => Some(error),
+        }
+    }
+}
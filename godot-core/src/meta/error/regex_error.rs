@@ -0,0 +1,55 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::GString;
+use crate::global::Error as GodotError;
+
+/// Error when compiling a regular expression.
+///
+/// Returned by [`GRegex::compile()`][crate::tools::GRegex::compile]. Godot prints the detailed reason (e.g. the position of an unbalanced
+/// parenthesis) to the error log, but does not expose it through the API.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct RegexCompileError {
+    pattern: GString,
+    godot_error: GodotError,
+}
+
+impl RegexCompileError {
+    pub(crate) fn new(pattern: GString, godot_error: GodotError) -> Self {
+        Self {
+            pattern,
+            godot_error,
+        }
+    }
+
+    /// The pattern that failed to compile.
+    pub fn pattern(&self) -> &GString {
+        &self.pattern
+    }
+
+    /// Error code returned by `RegEx::compile()`.
+    pub fn godot_error(&self) -> GodotError {
+        self.godot_error
+    }
+}
+
+impl fmt::Display for RegexCompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pattern = &self.pattern;
+        let godot_error = &self.godot_error;
+
+        write!(
+            f,
+            "can't compile regex `{pattern}`; Godot error: {godot_error:?}"
+        )
+    }
+}
+
+impl Error for RegexCompileError {}
@@ -0,0 +1,53 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::GString;
+use crate::meta::error::ConvertError;
+
+/// Error when reading a project setting with a specific type.
+///
+/// Returned by [`ProjectSettings::get_as()`][crate::classes::ProjectSettings::get_as].
+#[derive(Debug)]
+pub enum SettingError {
+    /// No setting with this path exists.
+    NotFound {
+        /// Path of the setting, e.g. `physics/2d/default_gravity`.
+        path: GString,
+    },
+
+    /// The setting exists, but its value could not be converted to the requested type.
+    WrongType {
+        /// Path of the setting.
+        path: GString,
+
+        /// Underlying conversion error, which contains the value.
+        error: ConvertError,
+    },
+}
+
+impl fmt::Display for SettingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound { path } => write!(f, "project setting `{path}` does not exist"),
+            Self::WrongType { path, error } => {
+                write!(f, "project setting `{path}` has unexpected type: {error}")
+            }
+        }
+    }
+}
+
+impl Error for SettingError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NotFound { .. } => None,
+            Self::WrongType { error, .. } => Some(error),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::VariantType;
+
+/// Mismatch between a [`ShaderParams`][crate::tools::ShaderParams] struct and the uniforms of a shader.
+///
+/// Returned by [`ShaderMaterial::validate_params()`][crate::classes::ShaderMaterial::validate_params].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ShaderParamError {
+    /// The material has no shader assigned.
+    NoShader,
+
+    /// The shader does not declare a uniform with this name.
+    MissingUniform {
+        /// Name of the expected uniform.
+        name: String,
+    },
+
+    /// The shader declares the uniform, but with a different type.
+    WrongType {
+        /// Name of the uniform.
+        name: String,
+
+        /// Type declared in the `ShaderParams` struct.
+        expected: VariantType,
+
+        /// Type of the uniform in the shader, e.g. `VECTOR4` for `vec4` or `COLOR` for `vec4 : source_color`.
+        actual: VariantType,
+    },
+}
+
+impl fmt::Display for ShaderParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoShader => write!(f, "shader material has no shader"),
+            Self::MissingUniform { name } => write!(f, "shader has no uniform `{name}`"),
+            Self::WrongType {
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "shader uniform `{name}` has type {actual:?}, expected {expected:?}"
+            ),
+        }
+    }
+}
+
+impl Error for ShaderParamError {}
@@ -0,0 +1,54 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::GString;
+use crate::meta::ClassName;
+
+/// Error when looking up a scene-unique node (`%Name`) with a specific type.
+///
+/// Returned by [`Node::try_get_unique_node_as()`][crate::classes::Node::try_get_unique_node_as].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum UniqueNodeError {
+    /// No node with this unique name exists in the scene of the queried node.
+    NotFound {
+        /// Unique name, without `%` prefix.
+        name: GString,
+    },
+
+    /// A node with this unique name exists, but does not have the expected type (or a derived one).
+    WrongType {
+        /// Unique name, without `%` prefix.
+        name: GString,
+
+        /// Class that was requested.
+        expected: ClassName,
+
+        /// Dynamic class of the found node.
+        actual: GString,
+    },
+}
+
+impl fmt::Display for UniqueNodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound { name } => write!(f, "no scene-unique node `%{name}` found"),
+            Self::WrongType {
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "scene-unique node `%{name}` has class `{actual}`, which is not `{expected}` or derived"
+            ),
+        }
+    }
+}
+
+impl Error for UniqueNodeError {}
@@ -29,6 +29,8 @@ impl Sealed for Vector4Axis {}
 impl Sealed for Quaternion {}
 impl Sealed for Color {}
 impl Sealed for GString {}
+impl Sealed for &GString {}
+impl Sealed for &str {}
 impl Sealed for StringName {}
 impl Sealed for NodePath {}
 impl Sealed for PackedByteArray {}
@@ -60,6 +62,7 @@ impl Sealed for u64 {}
 impl Sealed for u32 {}
 impl Sealed for u16 {}
 impl Sealed for u8 {}
+impl Sealed for usize {}
 impl Sealed for f64 {}
 impl Sealed for f32 {}
 impl Sealed for () {}
@@ -84,3 +87,11 @@ impl<T1, T2, T3, T4, T5, T6> Sealed for (T1, T2, T3, T4, T5, T6) {}
 impl<T1, T2, T3, T4, T5, T6, T7> Sealed for (T1, T2, T3, T4, T5, T6, T7) {}
 impl<T1, T2, T3, T4, T5, T6, T7, T8> Sealed for (T1, T2, T3, T4, T5, T6, T7, T8) {}
 impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Sealed for (T1, T2, T3, T4, T5, T6, T7, T8, T9) {}
+
+// Engine classes implementing tools::AudioPlayer.
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+impl Sealed for crate::classes::AudioStreamPlayer {}
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+impl Sealed for crate::classes::AudioStreamPlayer2D {}
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+impl Sealed for crate::classes::AudioStreamPlayer3D {}
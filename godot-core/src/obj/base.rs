@@ -5,10 +5,19 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::obj::{Gd, GodotClass};
+use crate::obj::{Gd, GdDerefTarget, GodotClass, InstanceId};
 use crate::{classes, sys};
+#[cfg(debug_assertions)]
+use std::cell::RefCell;
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 use std::mem::ManuallyDrop;
+use std::ops::{Deref, DerefMut};
+
+#[cfg(debug_assertions)]
+thread_local! {
+    // Objects whose user init() currently runs on this thread. More than one if an init() creates other objects.
+    static OBJECTS_IN_INIT: RefCell<Vec<InstanceId>> = const { RefCell::new(Vec::new()) };
+}
 
 /// Restricted version of `Gd`, to hold the base instance inside a user's `GodotClass`.
 ///
@@ -88,6 +97,79 @@ impl<T: GodotClass> Base<T> {
         }
     }
 
+    /// Instance ID of the base object.
+    ///
+    /// Can be used at any time, including during `init()`, when the object has not been fully constructed yet.
+    pub fn instance_id(&self) -> InstanceId {
+        self.obj.instance_id()
+    }
+
+    /// Restricted access to the base object while the user `init()` function is running.
+    ///
+    /// During `init()`, the Godot object exists, but the Rust instance has not been attached to it yet. The returned guard dereferences to
+    /// the engine class, so its methods can be used to set up the object itself. This is valid for:
+    /// - Properties declared by the engine class, e.g. `set_name()` on a `Node` or `set_position()` on a `Node2D`.
+    /// - Metadata via `set_meta()`/`get_meta()`.
+    /// - Adding children that were created during `init()`, or connecting signals of the object to other objects.
+    ///
+    /// The following must be avoided until `init()` has returned:
+    /// - Anything that reaches the Rust instance, e.g. calling `#[func]` methods or `#[var]` properties of the class via `call()`, `get()` or
+    ///   `set()`, or emitting signals that are connected to such methods. The Rust instance does not exist yet.
+    /// - Scene tree operations such as `get_tree()`, `get_node()` or `get_parent()`. A new node is not yet inside the tree, and not
+    ///   part of any hierarchy except its own children.
+    ///
+    /// Unlike a `Gd<T>`, the guard does not touch the reference count. Cloning a `Gd` to a `RefCounted` under construction and dropping it
+    /// again would destroy the object, which is why no `Gd` is handed out.
+    ///
+    /// # Panics (Debug)
+    /// If called outside of the `init()` function that received this base, e.g. from a later `ready()` call. Use
+    /// [`WithBaseField::base_mut()`][crate::obj::WithBaseField::base_mut] there instead.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::prelude::*;
+    ///
+    /// #[derive(GodotClass)]
+    /// #[class(base=Node2D)]
+    /// struct Player {
+    ///     base: Base<Node2D>,
+    /// }
+    ///
+    /// #[godot_api]
+    /// impl INode2D for Player {
+    ///     fn init(mut base: Base<Node2D>) -> Self {
+    ///         godot_print!("creating player {}", base.instance_id());
+    ///
+    ///         let mut init = base.init_access();
+    ///         init.set_name("Player");
+    ///         init.set_position(Vector2::new(100.0, 0.0));
+    ///
+    ///         Self { base }
+    ///     }
+    /// }
+    /// ```
+    pub fn init_access(&mut self) -> InitBase<'_, T> {
+        #[cfg(debug_assertions)]
+        {
+            let id = self.instance_id();
+            let in_init = OBJECTS_IN_INIT.with(|objects| objects.borrow().contains(&id));
+            assert!(
+                in_init,
+                "Base::init_access() called on {id} outside of its init() function"
+            );
+        }
+
+        InitBase { base: self }
+    }
+
+    /// Runs the user `init()` function that receives this base, enabling [`init_access()`][Self::init_access] in Debug mode.
+    pub(crate) fn run_init<R>(&self, init: impl FnOnce() -> R) -> R {
+        #[cfg(debug_assertions)]
+        let _scope = InitScope::new(self.instance_id());
+
+        init()
+    }
+
     /// Returns a [`Gd`] referencing the same object as this reference.
     ///
     /// Using this method to call methods on the base field of a Rust object is discouraged, instead use the
@@ -115,3 +197,52 @@ impl<T: GodotClass> Display for Base<T> {
         classes::display_string(&self.obj, f)
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Access to the base object during `init()`, returned by [`Base::init_access()`].
+///
+/// Dereferences to the engine class of the base, e.g. `Node2D`. See `init_access()` for the operations that are valid during `init()`.
+pub struct InitBase<'a, T: GodotClass> {
+    base: &'a mut Base<T>,
+}
+
+impl<T: GodotClass> Deref for InitBase<'_, T> {
+    type Target = GdDerefTarget<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base.obj
+    }
+}
+
+impl<T: GodotClass> DerefMut for InitBase<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base.obj
+    }
+}
+
+/// Marks an object as being in `init()`, until dropped (also on panic).
+#[cfg(debug_assertions)]
+struct InitScope {
+    id: InstanceId,
+}
+
+#[cfg(debug_assertions)]
+impl InitScope {
+    fn new(id: InstanceId) -> Self {
+        OBJECTS_IN_INIT.with(|objects| objects.borrow_mut().push(id));
+        Self { id }
+    }
+}
+
+#[cfg(debug_assertions)]
+impl Drop for InitScope {
+    fn drop(&mut self) {
+        OBJECTS_IN_INIT.with(|objects| {
+            let mut objects = objects.borrow_mut();
+            if let Some(pos) = objects.iter().rposition(|&id| id == self.id) {
+                objects.remove(pos);
+            }
+        });
+    }
+}
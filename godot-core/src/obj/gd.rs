@@ -11,15 +11,15 @@ use std::ops::{Deref, DerefMut};
 use godot_ffi as sys;
 use sys::{static_assert_eq_size_align, SysPtr as _};
 
-use crate::builtin::{Callable, NodePath, StringName, Variant};
-use crate::meta::error::{ConvertError, FromFfiError};
+use crate::builtin::{Callable, GString, NodePath, StringName, Variant, VariantType};
+use crate::meta::error::{ConvertError, FromFfiError, FromVariantError, PropertyError};
 use crate::meta::{
-    ArrayElement, AsArg, CallContext, ClassName, CowArg, FromGodot, GodotConvert, GodotType,
-    ParamType, PropertyHintInfo, RefArg, ToGodot,
+    arg_into_ref, ArrayElement, AsArg, CallContext, ClassName, CowArg, FromGodot, GodotConvert,
+    GodotType, ParamType, PropertyHintInfo, RefArg, ToGodot,
 };
 use crate::obj::{
     bounds, cap, Bounds, DynGd, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits, InstanceId,
-    OnEditor, RawGd, WithSignals,
+    OnEditor, RawGd, WeakGd, WithSignals,
 };
 use crate::private::callbacks;
 use crate::registry::property::{object_export_element_type_string, Export, Var};
@@ -86,6 +86,9 @@ use crate::{classes, out};
 /// on the owning `Gd<T>` is performed. This is important to keep in mind, as you can get into situations that violate dynamic borrow rules; for
 /// example if you are inside a `&mut self` method, make a call to GDScript and indirectly call another method on the same object (re-entrancy).
 ///
+/// To find out which binds lead to such a conflict, Debug builds can log every bind acquire and release, see
+/// [`set_borrow_tracing()`][crate::obj::set_borrow_tracing].
+///
 /// # Conversions
 ///
 /// For type conversions, please read the [`godot::meta` module docs][crate::meta].
@@ -167,6 +170,7 @@ where
     /// * If there is an ongoing function call from GDScript to Rust, which currently holds a `&mut T`
     ///   reference to the user instance. This can happen through re-entrancy (Rust -> GDScript -> Rust call).
     // Note: possible names: write/read, hold/hold_mut, r/w, r/rw, ...
+    #[track_caller]
     pub fn bind(&self) -> GdRef<T> {
         self.raw.bind()
     }
@@ -184,6 +188,7 @@ where
     /// * If another `Gd` smart pointer pointing to the same Rust instance has a live `GdRef` or `GdMut` guard bound.
     /// * If there is an ongoing function call from GDScript to Rust, which currently holds a `&T` or `&mut T`
     ///   reference to the user instance. This can happen through re-entrancy (Rust -> GDScript -> Rust call).
+    #[track_caller]
     pub fn bind_mut(&mut self) -> GdMut<T> {
         if cfg!(feature="print_bind_mut") {
             use crate::godot_print;
@@ -198,6 +203,14 @@ where
 
         self.raw.bind_mut()
     }
+
+    /// Whether a `GdRef` or `GdMut` guard for the user instance is currently alive, so that `bind_mut()` would panic.
+    pub(crate) fn is_bound(&self) -> bool {
+        use crate::storage::Storage as _;
+
+        self.raw.check_rtti("is_bound");
+        self.raw.storage().unwrap().is_bound()
+    }
 }
 
 /// _The methods in this impl block are available for any `T`._ <br><br>
@@ -275,6 +288,16 @@ impl<T: GodotClass> Gd<T> {
         unsafe { instance_id.unwrap_unchecked() }
     }
 
+    /// Returns a weak reference to this object, which does not keep it alive.
+    ///
+    /// See [`WeakGd`] for details, and [`WithBaseField::to_weak_gd()`][crate::obj::WithBaseField::to_weak_gd] for capturing an object in its own callbacks.
+    ///
+    /// # Panics
+    /// If this object is no longer alive.
+    pub fn to_weak_gd(&self) -> WeakGd<T> {
+        WeakGd::from_instance_id(self.instance_id())
+    }
+
     /// Checks if this smart pointer points to a live object (read description!).
     ///
     /// Using this method is often indicative of bad design -- you should dispose of your pointers once an object is
@@ -287,6 +310,33 @@ impl<T: GodotClass> Gd<T> {
         self.raw.is_instance_valid()
     }
 
+    /// Returns the most-derived class name of the object.
+    ///
+    /// For instances of user-defined classes, this is the name under which the Rust class is registered (e.g. `"Player"`), not the
+    /// engine base class. The result differs from [`T::class_name()`][GodotClass::class_name] if the object is of a class derived from `T`.
+    ///
+    /// Equivalent to [`Object::get_class()`][classes::Object::get_class], without the need to upcast.
+    ///
+    /// # Panics
+    /// If the object has been freed.
+    pub fn get_class(&self) -> GString {
+        self.raw.check_rtti("get_class");
+        GString::from(&self.dynamic_class_string())
+    }
+
+    /// Returns `true` if the object's class is `class_name` or inherits from it.
+    ///
+    /// Works with engine and user-defined class names. Unlike [`try_cast()`][Self::try_cast], the class is only known at runtime.
+    ///
+    /// Equivalent to [`Object::is_class()`][classes::Object::is_class], without the need to upcast.
+    ///
+    /// # Panics
+    /// If the object has been freed.
+    pub fn is_class(&self, class_name: impl AsArg<GString>) -> bool {
+        self.raw.check_rtti("is_class");
+        self.raw.as_object_ref().is_class(class_name)
+    }
+
     /// Returns the dynamic class name of the object as `StringName`.
     ///
     /// This method retrieves the class name of the object at runtime, which can be different from [`T::class_name()`] if derived
@@ -499,6 +549,221 @@ impl<T: GodotClass> Gd<T> {
         Callable::from_object_method(self, method_name)
     }
 
+    /// Reads the property `name` and converts it to `V`.
+    ///
+    /// Returns `None` if the object has no such property. Useful when the property name is only known at runtime; otherwise, prefer
+    /// the generated getters.
+    ///
+    /// # Panics
+    /// If the property exists, but its value cannot be converted to `V`. Use [`try_get_property()`][Self::try_get_property] to handle
+    /// this case.
+    pub fn get_property<V: FromGodot>(&self, name: impl AsArg<StringName>) -> Option<V> {
+        match self.try_get_property(name) {
+            Ok(value) => Some(value),
+            Err(PropertyError::NotFound { .. }) => None,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Reads the property `name` and converts it to `V` (fallible).
+    ///
+    /// Goes through [`Object::get()`][classes::Object::get], so properties of scripts and `get_property_list()` overrides are found as well.
+    ///
+    /// Returns [`PropertyError::NotFound`] if the object has no such property, and [`PropertyError::WrongType`] if its value cannot be
+    /// converted to `V`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// fn read_speed(obj: &Gd<Object>) -> f64 {
+    ///     obj.try_get_property::<f64>("speed").unwrap_or(0.0)
+    /// }
+    /// ```
+    pub fn try_get_property<V: FromGodot>(
+        &self,
+        name: impl AsArg<StringName>,
+    ) -> Result<V, PropertyError> {
+        arg_into_ref!(name);
+
+        let value = self.raw.as_object_ref().get(name);
+
+        // Object::get() returns nil for unknown properties, but nil is also a legit value of existing ones.
+        if value.is_nil() && !self.has_property(name) {
+            return Err(self.property_not_found(name));
+        }
+
+        value
+            .try_to::<V>()
+            .map_err(|error| PropertyError::WrongType {
+                property: name.clone(),
+                error,
+            })
+    }
+
+    /// Writes `value` to the property `name`.
+    ///
+    /// # Panics
+    /// If the object has no such property. Use [`try_set_property()`][Self::try_set_property] to handle this case.
+    pub fn set_property<V: ToGodot>(&mut self, name: impl AsArg<StringName>, value: V) {
+        self.try_set_property(name, value)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Writes `value` to the property `name` (fallible).
+    ///
+    /// Goes through [`Object::set()`][classes::Object::set]. Returns [`PropertyError::NotFound`] if the object has no such property;
+    /// in that case, nothing is written.
+    ///
+    /// Godot does not report whether the value was accepted, so a value of the wrong type is not detected here. Godot may print an error
+    /// and ignore the value.
+    pub fn try_set_property<V: ToGodot>(
+        &mut self,
+        name: impl AsArg<StringName>,
+        value: V,
+    ) -> Result<(), PropertyError> {
+        arg_into_ref!(name);
+
+        if !self.has_property(name) {
+            return Err(self.property_not_found(name));
+        }
+
+        self.raw.as_object_mut().set(name, &value.to_variant());
+        Ok(())
+    }
+
+    /// Writes several properties at once, e.g. values loaded from a config file.
+    ///
+    /// # Panics
+    /// If a property does not exist or a value has the wrong type. Use [`try_set_properties()`][Self::try_set_properties] to handle these
+    /// cases.
+    pub fn set_properties(&mut self, properties: &[(&str, Variant)]) {
+        self.try_set_properties(properties)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Writes several properties at once (fallible).
+    ///
+    /// All properties are validated before anything is written, so either all values are applied or none. Returns an error for the first
+    /// property (in order of `properties`) that fails validation:
+    /// - [`PropertyError::NotFound`] if the object has no such property.
+    /// - [`PropertyError::WrongType`] if the value's type doesn't match the declared type of the property. Integers are accepted for float
+    ///   properties, strings for `StringName` and `NodePath` properties (and vice versa), and nil for object properties. Properties declared
+    ///   as `Variant` accept any value.
+    ///
+    /// Unlike [`try_set_property()`][Self::try_set_property], this detects values of the wrong type, which Godot would otherwise reject with
+    /// an error message (or silently). Values are written in order, with [`Object::set()`][classes::Object::set].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// fn apply_config(mut node: Gd<Node2D>) {
+    ///     let result = node.try_set_properties(&[
+    ///         ("position", Vector2::new(10.0, 20.0).to_variant()),
+    ///         ("rotation", 0.5.to_variant()),
+    ///         ("visible", false.to_variant()),
+    ///     ]);
+    ///
+    ///     if let Err(err) = result {
+    ///         godot_error!("invalid config: {err}");
+    ///     }
+    /// }
+    /// ```
+    pub fn try_set_properties(
+        &mut self,
+        properties: &[(&str, Variant)],
+    ) -> Result<(), PropertyError> {
+        let declared_types = self.declared_property_types();
+        let properties: Vec<(StringName, &Variant)> = properties
+            .iter()
+            .map(|(name, value)| (StringName::from(*name), value))
+            .collect();
+
+        for (name, value) in &properties {
+            match declared_types.get(name) {
+                Some(&declared) if !property_accepts(declared, value.get_type()) => {
+                    let kind = FromVariantError::BadType {
+                        expected: declared,
+                        actual: value.get_type(),
+                    };
+
+                    return Err(PropertyError::WrongType {
+                        property: name.clone(),
+                        error: kind.into_error((*value).clone()),
+                    });
+                }
+                Some(_) => {}
+
+                // Not listed, but possibly handled by a `_get()` override.
+                None if self.has_property(name) => {}
+                None => return Err(self.property_not_found(name)),
+            }
+        }
+
+        let object = self.raw.as_object_mut();
+        for (name, value) in &properties {
+            object.set(name, value);
+        }
+
+        Ok(())
+    }
+
+    /// Queues emission of `signal` on the main thread, instead of emitting it immediately.
+    ///
+    /// Untyped counterpart to [`TypedSignal::emit_threadsafe()`][crate::registry::signal::TypedSignal::emit_threadsafe]. Can be called on
+    /// any thread that has access to the object, e.g. from a `WorkerThreadPool` task that created it. Connected handlers always run on the
+    /// main thread, during the next process frame. Signals emitted from the same thread arrive in the order they were emitted.
+    ///
+    /// Arguments are serialized on the calling thread, which is lossy for objects: they are sent by instance ID and may arrive as `null`,
+    /// and objects inside arrays or dictionaries are not supported. See [`ThreadsafeSignal`][crate::task::ThreadsafeSignal] for details.
+    ///
+    /// # Panics
+    /// If the `Scene` init level of the extension is not loaded.
+    #[cfg(since_api = "4.2")]
+    pub fn emit_signal_threadsafe(&self, signal: impl AsArg<StringName>, args: &[Variant]) {
+        crate::meta::arg_into_owned!(signal);
+
+        let gate = crate::task::MainThreadGate::new();
+        crate::task::emit_on_main_thread(&gate, self.instance_id(), signal, args);
+    }
+
+    /// Maps property names to their declared types, from `get_property_list()`.
+    fn declared_property_types(&self) -> std::collections::HashMap<StringName, VariantType> {
+        use crate::obj::EngineEnum;
+
+        self.raw
+            .as_object_ref()
+            .get_property_list()
+            .iter_shared()
+            .filter_map(|prop| {
+                let name = prop.get("name")?.try_to::<GString>().ok()?;
+                let ty = prop.get("type")?.try_to::<i32>().ok()?;
+                Some((StringName::from(&name), VariantType::try_from_ord(ty)?))
+            })
+            .collect()
+    }
+
+    fn has_property(&self, name: &StringName) -> bool {
+        let object = self.raw.as_object_ref();
+
+        // Fast path: a non-nil value implies that the property exists.
+        if !object.get(name).is_nil() {
+            return true;
+        }
+
+        let name = GString::from(name);
+        object.get_property_list().iter_shared().any(|prop| {
+            prop.get("name")
+                .is_some_and(|prop_name| prop_name.try_to::<GString>().as_ref() == Ok(&name))
+        })
+    }
+
+    fn property_not_found(&self, name: &StringName) -> PropertyError {
+        PropertyError::NotFound {
+            class: GString::from(&self.dynamic_class_string()),
+            property: name.clone(),
+        }
+    }
+
     pub(crate) unsafe fn from_obj_sys_or_none(
         ptr: sys::GDExtensionObjectPtr,
     ) -> Result<Self, ConvertError> {
@@ -1017,3 +1282,21 @@ impl<T: GodotClass> std::hash::Hash for Gd<T> {
 // its mutability is anyway present, in the Godot engine.
 impl<T: GodotClass> std::panic::UnwindSafe for Gd<T> {}
 impl<T: GodotClass> std::panic::RefUnwindSafe for Gd<T> {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Whether a property declared with type `declared` accepts values of type `actual`, as Godot would convert them when setting.
+fn property_accepts(declared: VariantType, actual: VariantType) -> bool {
+    let is_string_like = |ty| {
+        matches!(
+            ty,
+            VariantType::STRING | VariantType::STRING_NAME | VariantType::NODE_PATH
+        )
+    };
+
+    declared == actual
+        || declared == VariantType::NIL
+        || (declared == VariantType::FLOAT && actual == VariantType::INT)
+        || (declared == VariantType::OBJECT && actual == VariantType::NIL)
+        || (is_string_like(declared) && is_string_like(actual))
+}
@@ -15,9 +15,11 @@ use godot_ffi::out;
 
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut};
+#[cfg(debug_assertions)]
+use std::panic::Location;
 
 use crate::obj::script::ScriptInstance;
-use crate::obj::{AsDyn, Gd, GodotClass};
+use crate::obj::{AsDyn, Gd, GodotClass, WeakGd};
 
 /// Immutably/shared bound reference guard for a [`Gd`][crate::obj::Gd] smart pointer.
 ///
@@ -25,11 +27,20 @@ use crate::obj::{AsDyn, Gd, GodotClass};
 #[derive(Debug)]
 pub struct GdRef<'a, T: GodotClass> {
     guard: RefGuard<'a, T>,
+
+    // Only used for borrow tracing.
+    #[cfg(debug_assertions)]
+    acquired_at: &'static Location<'static>,
 }
 
 impl<'a, T: GodotClass> GdRef<'a, T> {
+    #[track_caller]
     pub(crate) fn from_guard(guard: RefGuard<'a, T>) -> Self {
-        Self { guard }
+        Self {
+            guard,
+            #[cfg(debug_assertions)]
+            acquired_at: Location::caller(),
+        }
     }
 }
 
@@ -44,6 +55,9 @@ impl<T: GodotClass> Deref for GdRef<'_, T> {
 impl<T: GodotClass> Drop for GdRef<'_, T> {
     fn drop(&mut self) {
         out!("GdRef drop: {:?}", std::any::type_name::<T>());
+
+        #[cfg(debug_assertions)]
+        crate::storage::trace_release::<T>(false, self.acquired_at);
     }
 }
 
@@ -57,11 +71,20 @@ impl<T: GodotClass> Drop for GdRef<'_, T> {
 #[derive(Debug)]
 pub struct GdMut<'a, T: GodotClass> {
     guard: MutGuard<'a, T>,
+
+    // Only used for borrow tracing.
+    #[cfg(debug_assertions)]
+    acquired_at: &'static Location<'static>,
 }
 
 impl<'a, T: GodotClass> GdMut<'a, T> {
+    #[track_caller]
     pub(crate) fn from_guard(guard: MutGuard<'a, T>) -> Self {
-        Self { guard }
+        Self {
+            guard,
+            #[cfg(debug_assertions)]
+            acquired_at: Location::caller(),
+        }
     }
 }
 
@@ -82,6 +105,9 @@ impl<T: GodotClass> DerefMut for GdMut<'_, T> {
 impl<T: GodotClass> Drop for GdMut<'_, T> {
     fn drop(&mut self) {
         out!("GdMut drop: {:?}", std::any::type_name::<T>());
+
+        #[cfg(debug_assertions)]
+        crate::storage::trace_release::<T>(true, self.acquired_at);
     }
 }
 
@@ -275,6 +301,25 @@ make_base_mut!(
     "rust object"
 );
 
+impl<T: GodotClass> BaseRef<'_, T> {
+    /// Returns a weak reference to the Rust object itself, not only its base.
+    ///
+    /// Same as [`WithBaseField::to_weak_gd()`](super::WithBaseField::to_weak_gd), which explains why closures should capture this instead
+    /// of a strong `Gd<T>`. Shadows `Gd::to_weak_gd()` on the dereferenced base, which would return a `WeakGd<T::Base>`.
+    pub fn to_weak_gd(&self) -> WeakGd<T> {
+        WeakGd::from_instance_id(self.gd.instance_id())
+    }
+}
+
+impl<T: GodotClass> BaseMut<'_, T> {
+    /// Returns a weak reference to the Rust object itself, not only its base.
+    ///
+    /// See [`BaseRef::to_weak_gd()`].
+    pub fn to_weak_gd(&self) -> WeakGd<T> {
+        WeakGd::from_instance_id(self.gd.instance_id())
+    }
+}
+
 make_base_ref!(
     ScriptBaseRef,
     ScriptInstance,
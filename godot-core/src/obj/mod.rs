@@ -20,9 +20,11 @@ mod on_editor;
 mod on_ready;
 mod raw_gd;
 mod traits;
+mod weak_gd;
 
 pub(crate) mod rtti;
 
+pub use crate::storage::set_borrow_tracing;
 pub use base::*;
 pub use dyn_gd::DynGd;
 pub use gd::*;
@@ -32,6 +34,7 @@ pub use on_editor::*;
 pub use on_ready::*;
 pub use raw_gd::*;
 pub use traits::*;
+pub use weak_gd::WeakGd;
 
 pub mod bounds;
 pub mod script;
@@ -39,4 +42,8 @@ pub use bounds::private::Bounds;
 
 // Do not re-export rtti here.
 
+// Only exported for itest.
+#[cfg(feature = "trace")]
+pub use crate::storage::capture_borrow_trace;
+
 pub type GdDerefTarget<T> = <<T as Bounds>::Declarer as bounds::Declarer>::DerefTarget<T>;
@@ -314,6 +314,10 @@ where
     fn set_property(&mut self, value: T::Via) {
         OnEditor::<T>::set_property_inner(self, Some(value));
     }
+
+    fn var_hint() -> PropertyHintInfo {
+        T::var_hint()
+    }
 }
 
 impl<T> Export for OnEditor<T>
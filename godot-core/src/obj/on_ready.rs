@@ -7,7 +7,7 @@
 
 use crate::builtin::{GString, NodePath};
 use crate::classes::{Node, Resource};
-use crate::meta::{arg_into_owned, AsArg, GodotConvert};
+use crate::meta::{arg_into_owned, AsArg, GodotConvert, PropertyHintInfo};
 use crate::obj::{Gd, Inherits};
 use crate::registry::property::Var;
 use std::fmt::{self, Debug, Formatter};
@@ -39,7 +39,8 @@ use std::mem;
 /// This container is not designed as a general late-initialization solution, but tailored to the `ready()` semantics of Godot.
 ///
 /// `OnReady<T>` cannot be used with `#[export]` fields, because `ready()` is typically not called in the editor (unless `#[class(tool)]`
-/// is specified). You can however use it with `#[var]` -- just make sure to access the fields in GDScript after `ready()`.
+/// is specified). You can however use it with `#[var]`: the generated getter and setter access the inner value, and panic if the property
+/// is accessed before `ready()`.
 ///
 /// This type is not thread-safe. `ready()` runs on the main thread, and you are expected to access its value on the main thread, as well.
 ///
@@ -103,7 +104,7 @@ use std::mem;
 /// impl INode for MyClass {
 ///     fn ready(&mut self) {
 ///        // self.node is now ready with the node found at path `ChildPath`.
-///        assert_eq!(self.auto.get_name(), "ChildPath".into());
+///        assert_eq!(self.auto.get_name(), "ChildPath");
 ///
 ///        // self.manual needs to be initialized manually.
 ///        self.manual.init(22);
@@ -155,11 +156,18 @@ impl<T: Inherits<Resource>> OnReady<Gd<T>> {
     /// - If the resource does not exist at `path`, cannot be loaded or is not compatible with type `T`.
     ///
     /// Note that the panic will only happen if and when the node enters the SceneTree for the first time
-    /// (i.e. it receives the `READY` notification).
+    /// (i.e. it receives the `READY` notification). The message names both `path` and the expected class `T`.
     pub fn from_loaded(path: impl AsArg<GString>) -> Self {
         arg_into_owned!(path);
 
-        Self::new(move || crate::tools::load(&path))
+        Self::new(move || {
+            crate::tools::try_load::<T>(&path).unwrap_or_else(|err| {
+                panic!(
+                    "OnReady<Gd<{class}>> could not be initialized from '{path}': {err}",
+                    class = T::class_name()
+                )
+            })
+        })
     }
 }
 
@@ -252,6 +260,22 @@ impl<T> OnReady<T> {
             value: initializer(base),
         };
     }
+
+    /// Panics with a message naming `property`, if the value is not yet initialized.
+    ///
+    /// Called by getters and setters generated for `#[var]` fields.
+    #[doc(hidden)]
+    pub fn __ensure_var_initialized(&self, property: &str) {
+        match &self.state {
+            InitState::Initialized { .. } => {}
+            InitState::ManualUninitialized => {
+                panic!("property `{property}` accessed before its OnReady value was initialized; did you call init() in ready()?")
+            }
+            InitState::AutoPrepared { .. } | InitState::AutoInitializing => {
+                panic!("property `{property}` accessed before ready(); OnReady values are only available once the node is ready")
+            }
+        }
+    }
 }
 
 // Panicking Deref is not best practice according to Rust, but constant get() calls are significantly less ergonomic and make it harder to
@@ -285,8 +309,11 @@ impl<T> std::ops::DerefMut for OnReady<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         match &mut self.state {
             InitState::Initialized { value } => value,
-            InitState::ManualUninitialized { .. } | InitState::AutoPrepared { .. } => {
-                panic!("value not yet initialized")
+            InitState::ManualUninitialized => {
+                panic!("OnReady manual value uninitialized, did you call init()?")
+            }
+            InitState::AutoPrepared { .. } => {
+                panic!("OnReady automatic value uninitialized, is only available in ready()")
             }
             InitState::AutoInitializing => unreachable!(),
         }
@@ -307,6 +334,10 @@ impl<T: Var> Var for OnReady<T> {
         let deref: &mut T = self;
         deref.set_property(value);
     }
+
+    fn var_hint() -> PropertyHintInfo {
+        T::var_hint()
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
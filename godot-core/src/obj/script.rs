@@ -265,8 +265,7 @@ pub unsafe fn create_script_instance<T: ScriptInstance>(
         property_can_revert_func: None, // unimplemented until needed.
         property_get_revert_func: None, // unimplemented until needed.
 
-        // ScriptInstance::get_owner() is apparently not called by Godot 4.1 to 4.2 (to verify).
-        get_owner_func: None,
+        get_owner_func: Some(script_instance_info::get_owner_func::<T>),
         get_property_state_func: Some(script_instance_info::get_property_state_func::<T>),
 
         get_method_list_func: Some(script_instance_info::get_method_list_func::<T>),
@@ -863,6 +862,21 @@ mod script_instance_info {
         unsafe { (*r_error).error = error };
     }
 
+    /// Returns the object that the script instance is attached to. Ownership is not transferred to the caller.
+    ///
+    /// # Safety
+    ///
+    /// - `p_instance` must point to a live immutable [`ScriptInstanceData<T>`] for the duration of this function call
+    pub(super) unsafe extern "C" fn get_owner_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+    ) -> sys::GDExtensionObjectPtr {
+        // SAFETY: `p_instance` points to a live immutable `ScriptInstanceData<T>` for the duration of this call.
+        let data = unsafe { ScriptInstanceData::<T>::borrow_script_sys(p_instance) };
+
+        // The script instance is freed before its owner, so the base object is still alive.
+        data.base.obj_sys()
+    }
+
     /// Ownership of the returned object is not transferred to the caller. The caller is therefore responsible for incrementing the reference
     /// count.
     ///
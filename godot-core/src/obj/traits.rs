@@ -9,7 +9,7 @@ use crate::builder::ClassBuilder;
 use crate::builtin::GString;
 use crate::init::InitLevel;
 use crate::meta::ClassName;
-use crate::obj::{bounds, Base, BaseMut, BaseRef, Bounds, Gd};
+use crate::obj::{bounds, Base, BaseMut, BaseRef, Bounds, Gd, WeakGd};
 use crate::storage::Storage;
 use godot_ffi as sys;
 
@@ -271,6 +271,53 @@ pub trait WithBaseField: GodotClass + Bounds<Declarer = bounds::DeclUser> {
     /// calling `to_gd()` is still running; that would lead to a double borrow panic.
     fn to_gd(&self) -> Gd<Self>;
 
+    /// Returns a weak reference to this object, for capturing in closures that this object keeps alive.
+    ///
+    /// Closures connected to signals, or stored in fields, often need to refer back to the object. Capturing `self.to_gd()` stores a strong
+    /// reference inside something the object itself owns:
+    /// - For `RefCounted` classes, this is a reference cycle. The object keeps the closure alive, the closure keeps the object alive, and
+    ///   neither is ever freed.
+    /// - For manually managed classes such as nodes, the closure can outlive the object, and then panics when accessing it.
+    ///
+    /// A [`WeakGd`] avoids both: it does not count as a reference, and [`upgrade()`][WeakGd::upgrade]
+    /// returns `None` once the object is gone. Upgrade inside the closure and drop the resulting `Gd` when the callback returns.
+    ///
+    /// Like `to_gd()`, this can be called during `init()`; upgrading is only possible once the object is fully constructed.
+    ///
+    /// `self.base().to_weak_gd()` and `self.base_mut().to_weak_gd()` return the same `WeakGd<Self>`, for code that already holds a base guard.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::prelude::*;
+    ///
+    /// #[derive(GodotClass)]
+    /// #[class(init, base = RefCounted)]
+    /// struct Counter {
+    ///     count: i32,
+    ///     base: Base<RefCounted>,
+    /// }
+    ///
+    /// #[godot_api]
+    /// impl Counter {
+    ///     #[signal]
+    ///     fn ticked();
+    ///
+    ///     fn listen(&mut self) {
+    ///         let weak = self.to_weak_gd();
+    ///
+    ///         // Capturing self.to_gd() here would keep the counter alive forever.
+    ///         self.signals().ticked().connect(move || {
+    ///             if let Some(mut counter) = weak.upgrade() {
+    ///                 counter.bind_mut().count += 1;
+    ///             }
+    ///         });
+    ///     }
+    /// }
+    /// ```
+    fn to_weak_gd(&self) -> WeakGd<Self> {
+        WeakGd::from_instance_id(self.base_field().instance_id())
+    }
+
     /// Returns a reference to the `Base` stored by this object.
     fn base_field(&self) -> &Base<Self::Base>;
 
@@ -492,6 +539,79 @@ pub trait WithUserSignals: WithSignals + WithBaseField {
     fn signals(&mut self) -> Self::SignalCollection<'_>;
 }
 
+/// Properties that are determined at runtime, rather than declared as struct fields.
+///
+/// Implement this trait for classes declared with `#[class(dynamic_properties)]`, e.g. for data-driven objects whose set of properties
+/// comes from a file. The derive macro wires Godot's `_get`, `_set` and `_get_property_list` callbacks up to the methods of this trait.
+///
+/// # Coexistence with static properties
+/// Fields declared with `#[var]` or `#[export]` keep working alongside dynamic properties:
+/// - Godot calls [`get_property()`][Self::get_property] and [`set_property()`][Self::set_property] first, for _every_ property access,
+///   including those of static properties. Return `None` or `false` for names that are not dynamic, so that Godot falls back to the
+///   static property.
+/// - [`get_property_list()`][Self::get_property_list] only returns the dynamic properties. Godot lists them after the static ones,
+///   e.g. in the editor inspector and in `Object::get_property_list()`.
+///
+/// The same callbacks are used by the `get_property()`, `set_property()` and `get_property_list()` methods of the class's `I*` interface
+/// trait. A class can use either mechanism, but not both.
+///
+/// # Example
+/// ```no_run
+/// use std::collections::HashMap;
+/// use godot::prelude::*;
+/// use godot::meta::PropertyInfo;
+/// use godot::obj::DynamicProperties;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, dynamic_properties)]
+/// struct Creature {
+///     #[export]
+///     level: i64,
+///     stats: HashMap<StringName, i64>,
+/// }
+///
+/// impl DynamicProperties for Creature {
+///     fn get_property_list(&self) -> Vec<PropertyInfo> {
+///         self.stats
+///             .keys()
+///             .map(|name| PropertyInfo::new_export::<i64>(&name.to_string()))
+///             .collect()
+///     }
+///
+///     fn get_property(&self, property: StringName) -> Option<Variant> {
+///         self.stats.get(&property).map(|value| value.to_variant())
+///     }
+///
+///     fn set_property(&mut self, property: StringName, value: Variant) -> bool {
+///         match (self.stats.get_mut(&property), value.try_to::<i64>()) {
+///             (Some(stat), Ok(value)) => {
+///                 *stat = value;
+///                 true
+///             }
+///             _ => false,
+///         }
+///     }
+/// }
+/// ```
+#[cfg(since_api = "4.3")]
+pub trait DynamicProperties: GodotClass {
+    /// Lists the dynamic properties of this object.
+    ///
+    /// Called whenever Godot queries the property list, e.g. when the object is shown in the inspector or saved.
+    fn get_property_list(&self) -> Vec<crate::meta::PropertyInfo>;
+
+    /// Returns the value of the dynamic property `property`, or `None` if there is no such dynamic property.
+    fn get_property(&self, property: crate::builtin::StringName)
+        -> Option<crate::builtin::Variant>;
+
+    /// Sets the dynamic property `property` to `value`. Returns `false` if there is no such dynamic property, or the value is rejected.
+    fn set_property(
+        &mut self,
+        property: crate::builtin::StringName,
+        value: crate::builtin::Variant,
+    ) -> bool;
+}
+
 /// Extension trait for all reference-counted classes.
 pub trait NewGd: GodotClass {
     /// Return a new, ref-counted `Gd` containing a default-constructed instance.
@@ -658,6 +778,12 @@ pub mod cap {
         fn __register_exports();
     }
 
+    /// Auto-implemented for `#[class(eq)]` structs; registers the `equals()` method.
+    pub trait GodotContentEq: GodotClass {
+        #[doc(hidden)]
+        fn __register_equals();
+    }
+
     /// Auto-implemented for `#[godot_api] impl XyVirtual for MyClass` blocks
     pub trait ImplementsGodotVirtual: GodotClass {
         // Cannot use #[cfg(since_api = "4.4")] on the `hash` parameter, because the doc-postprocessing generates #[doc(cfg)],
@@ -0,0 +1,80 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use crate::obj::{Gd, GodotClass, InstanceId};
+
+/// Weak reference to a Godot object, which does not keep the object alive.
+///
+/// Obtained through [`Gd::to_weak_gd()`], or [`WithBaseField::to_weak_gd()`][crate::obj::WithBaseField::to_weak_gd] from within a
+/// user class. To access the object, [`upgrade()`][Self::upgrade] the weak reference to a `Gd<T>`; this fails once the object is destroyed.
+///
+/// The main use case are closures that refer to their own object, see [`WithBaseField::to_weak_gd()`][crate::obj::WithBaseField::to_weak_gd]
+/// for why a strong `Gd<T>` is problematic there.
+///
+/// Internally, only the [`InstanceId`] is stored. Godot never reuses instance IDs, so a weak reference cannot accidentally refer to a
+/// different object allocated later. Like `Gd`, a `WeakGd` can only be used on the thread it was created on.
+pub struct WeakGd<T: GodotClass> {
+    instance_id: InstanceId,
+
+    // Not Send/Sync, same as Gd<T>.
+    _marker: PhantomData<*const T>,
+}
+
+impl<T: GodotClass> WeakGd<T> {
+    pub(crate) fn from_instance_id(instance_id: InstanceId) -> Self {
+        Self {
+            instance_id,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a strong reference to the object, or `None` if it has been destroyed.
+    ///
+    /// For `RefCounted` objects, the returned `Gd` keeps the object alive until it is dropped, like any other `Gd`. Drop it at the end
+    /// of the callback, rather than storing it, to keep the reference weak.
+    pub fn upgrade(&self) -> Option<Gd<T>> {
+        Gd::try_from_instance_id(self.instance_id).ok()
+    }
+
+    /// Instance ID of the referenced object, which may no longer be alive.
+    pub fn instance_id(&self) -> InstanceId {
+        self.instance_id
+    }
+}
+
+// Manual impls to avoid bounds on T.
+impl<T: GodotClass> Clone for WeakGd<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GodotClass> Copy for WeakGd<T> {}
+
+impl<T: GodotClass> PartialEq for WeakGd<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.instance_id == other.instance_id
+    }
+}
+
+impl<T: GodotClass> Eq for WeakGd<T> {}
+
+impl<T: GodotClass> Hash for WeakGd<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.instance_id.hash(state);
+    }
+}
+
+impl<T: GodotClass> Debug for WeakGd<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "WeakGd<{}>({})", T::class_name(), self.instance_id)
+    }
+}
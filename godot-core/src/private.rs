@@ -181,9 +181,156 @@ pub unsafe fn has_virtual_script_method(
     sys::interface_fn!(object_has_script_method)(sys::to_const_ptr(object_ptr), method_sname) != 0
 }
 
+/// Variant type that `T` is converted from, used by `variant_match!`.
+pub fn variant_type_of<T: crate::meta::FromGodot>() -> crate::builtin::VariantType {
+    use crate::meta::GodotType;
+    use sys::GodotFfi;
+
+    <<T::Via as GodotType>::Ffi as GodotFfi>::VARIANT_TYPE
+}
+
+/// Buffer passed by Godot as pointer and element count, used by virtual methods declared with `&mut [T]` (e.g. `IAudioStreamPlayback::mix`).
+///
+/// # Safety
+/// `ptr` must point to `len` initialized elements, which are not otherwise accessed during `'a`. Null or non-positive lengths are allowed.
+pub unsafe fn slice_from_raw_mut<'a, T>(ptr: *mut T, len: i32) -> &'a mut [T] {
+    match usize::try_from(len) {
+        Ok(len) if len > 0 && !ptr.is_null() => std::slice::from_raw_parts_mut(ptr, len),
+        _ => &mut [],
+    }
+}
+
+/// Read-only buffer passed by Godot as pointer and element count, used by virtual methods declared with `&[T]` (e.g. `put_packet`).
+///
+/// # Safety
+/// `ptr` must point to `len` initialized elements, which are not mutated during `'a`. Null or non-positive lengths are allowed.
+pub unsafe fn slice_from_raw<'a, T>(ptr: *const T, len: i32) -> &'a [T] {
+    match usize::try_from(len) {
+        Ok(len) if len > 0 && !ptr.is_null() => std::slice::from_raw_parts(ptr, len),
+        _ => &[],
+    }
+}
+
+/// Writes a packet returned by a `get_packet()` virtual method declared with `Result<&[u8], Error>` to Godot's out-pointers.
+///
+/// # Safety
+/// `r_buffer` and `r_buffer_size` must be valid for writes. The caller must ensure that `packet` outlives Godot's use of the buffer.
+pub unsafe fn write_packet_out(
+    packet: Result<&[u8], crate::global::Error>,
+    r_buffer: *mut *const u8,
+    r_buffer_size: *mut i32,
+) -> crate::global::Error {
+    let packet = match packet {
+        Ok(packet) => packet,
+        Err(err) => return err,
+    };
+
+    let Ok(size) = i32::try_from(packet.len()) else {
+        return crate::global::Error::ERR_OUT_OF_MEMORY;
+    };
+
+    *r_buffer = packet.as_ptr();
+    *r_buffer_size = size;
+
+    crate::global::Error::OK
+}
+
 /// Ensure `T` is an editor plugin.
 pub const fn is_editor_plugin<T: crate::obj::Inherits<crate::classes::EditorPlugin>>() {}
 
+/// Ensures that a `#[class(singleton)]` class is manually managed, as the engine frees singletons explicitly.
+#[diagnostic::on_unimplemented(
+    message = "#[class(singleton)] requires a manually-managed class, but this class inherits `RefCounted`",
+    label = "reference-counted",
+    note = "use a base class not inheriting `RefCounted`, e.g. #[class(base=Object)]"
+)]
+pub trait SingletonMemory {}
+
+impl SingletonMemory for crate::obj::bounds::MemManual {}
+
+/// Resolves the placeholder of an engine class excluded by minimal codegen. Never implemented, so using such a class is a compile error.
+#[diagnostic::on_unimplemented(
+    message = "engine class `{Self}` is not generated, as it is excluded by minimal codegen",
+    label = "excluded engine class",
+    note = "add the class to the `GODOT_CLASS_ALLOWLIST` env var, or enable the `codegen-full` feature"
+)]
+pub trait ExcludedClass {
+    type Class;
+}
+
+/// Returns the engine singleton instance of a `#[class(singleton)]` class.
+pub fn user_singleton<T>() -> crate::obj::Gd<T>
+where
+    T: crate::obj::GodotClass + crate::obj::Inherits<crate::classes::Object>,
+{
+    let class_name = T::class_name();
+
+    crate::classes::Engine::singleton()
+        .get_singleton(&class_name.to_string_name())
+        .unwrap_or_else(|| {
+            panic!("engine singleton `{class_name}` is not registered; it is only available after its init level is loaded")
+        })
+        .cast::<T>()
+}
+
+/// Content comparison of a `#[class(eq)]` instance with a variant, backing the generated `equals()` method.
+///
+/// Objects of other classes (and `null`) are never equal. Comparing an instance with itself does not invoke `PartialEq`.
+pub fn user_class_equals<T>(this: &T, other: &crate::builtin::Variant) -> bool
+where
+    T: PartialEq
+        + crate::obj::GodotClass
+        + crate::obj::Bounds<Declarer = crate::obj::bounds::DeclUser>,
+{
+    let Ok(other) = other.try_to::<crate::obj::Gd<T>>() else {
+        return false;
+    };
+
+    // Shared binds can coexist, so this also works when `other` is the instance on which `equals()` is called.
+    let other = other.bind();
+    std::ptr::eq(this, &*other) || *this == *other
+}
+
+/// Runs the future returned by an `async #[func]` as a task, backing the generated method.
+///
+/// Returns a signal which is emitted once with the converted output of the future, so that GDScript can `await` the method call. If the
+/// task panics or is cancelled, the signal is never emitted.
+#[cfg(since_api = "4.2")]
+pub fn spawn_async_func<F>(future: F) -> crate::builtin::Signal
+where
+    F: std::future::Future + 'static,
+    F::Output: crate::meta::ToGodot,
+{
+    use crate::builtin::{Callable, Signal, Variant};
+    use crate::classes::RefCounted;
+    use crate::meta::ToGodot;
+    use crate::obj::NewGd;
+
+    const SIGNAL: &str = "completed";
+
+    // Only referenced by the task; freed once the signal has been emitted.
+    let mut emitter = RefCounted::new_gd();
+    emitter.add_user_signal(SIGNAL);
+    let signal = Signal::from_object_signal(&emitter, SIGNAL);
+
+    let mut task = Some(async move {
+        let output = future.await.to_variant();
+        emitter.emit_signal(SIGNAL, &[output]);
+    });
+
+    // The caller only connects to the signal after the method has returned. Spawn at the end of the frame, so that a future completing
+    // without suspending does not emit the signal too early.
+    let spawner = Callable::from_local_fn("spawn_async_func", move |_args| {
+        if let Some(task) = task.take() {
+            crate::task::spawn(task);
+        }
+        Ok(Variant::nil())
+    });
+    spawner.call_deferred(&[]);
+
+    signal
+}
+
 // Starting from 4.3, Godot has "runtime classes"; this emulation is no longer needed.
 #[cfg(before_api = "4.3")]
 pub fn is_class_inactive(is_tool: bool) -> bool {
@@ -262,6 +409,9 @@ macro_rules! format_backtrace {
 
         match backtrace.status() {
             BacktraceStatus::Captured => format!("\n[{}]\n{}\n", $prefix, backtrace),
+            BacktraceStatus::Disabled if cfg!(target_family = "wasm") => {
+                "(backtrace not captured on Wasm)".to_string()
+            }
             BacktraceStatus::Disabled => {
                 "(backtrace disabled, run application with `RUST_BACKTRACE=1` environment variable)"
                     .to_string()
@@ -274,10 +424,22 @@ macro_rules! format_backtrace {
     }};
 
     ($prefix:expr) => {
-        $crate::format_backtrace!($prefix, std::backtrace::Backtrace::capture())
+        $crate::format_backtrace!($prefix, $crate::private::capture_backtrace())
     };
 }
 
+/// Captures a backtrace if enabled via `RUST_BACKTRACE`, like [`Backtrace::capture()`][std::backtrace::Backtrace::capture].
+///
+/// On Wasm, stack walking is not reliably supported in Emscripten side modules and may abort at runtime, so nothing is captured there.
+#[cfg(debug_assertions)]
+pub fn capture_backtrace() -> std::backtrace::Backtrace {
+    if cfg!(target_family = "wasm") {
+        std::backtrace::Backtrace::disabled()
+    } else {
+        std::backtrace::Backtrace::capture()
+    }
+}
+
 #[cfg(not(debug_assertions))]
 #[macro_export]
 macro_rules! format_backtrace {
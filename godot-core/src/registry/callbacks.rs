@@ -79,7 +79,7 @@ where
     //out!("create callback: {}", class_name.backing);
 
     let base = unsafe { Base::from_sys(base_ptr) };
-    let user_instance = make_user_instance(unsafe { Base::from_base(&base) });
+    let user_instance = base.run_init(|| make_user_instance(unsafe { Base::from_base(&base) }));
 
     let instance = InstanceStorage::<T>::construct(user_instance, base);
     let instance_ptr = instance.into_raw();
@@ -425,6 +425,10 @@ pub fn register_user_methods_constants<T: cap::ImplementsGodotApi>(_class_builde
     T::__register_constants();
 }
 
+pub fn register_user_equals<T: cap::GodotContentEq>(_class_builder: &mut dyn Any) {
+    T::__register_equals();
+}
+
 pub fn register_user_rpcs<T: cap::ImplementsGodotApi>(object: &mut dyn Any) {
     T::__register_rpcs(object);
 }
@@ -13,7 +13,7 @@ use crate::classes::ClassDb;
 use crate::init::InitLevel;
 use crate::meta::error::{ConvertError, FromGodotError};
 use crate::meta::ClassName;
-use crate::obj::{cap, DynGd, Gd, GodotClass};
+use crate::obj::{cap, DynGd, Gd, GodotClass, InstanceId};
 use crate::private::{ClassPlugin, PluginItem};
 use crate::registry::callbacks;
 use crate::registry::plugin::{DynTraitImpl, ErasedRegisterFn, ITraitImpl, InherentImpl, Struct};
@@ -60,6 +60,8 @@ fn global_dyn_traits_by_typeid() -> GlobalGuard<'static, HashMap<any::TypeId, Ve
 pub struct LoadedClass {
     name: ClassName,
     is_editor_plugin: bool,
+    /// For `#[class(singleton)]`, the instance that this extension registered as engine singleton. `None` if registration failed.
+    singleton_id: Option<InstanceId>,
 }
 
 /// Represents a class which is currently loaded and retained in memory -- including metadata.
@@ -90,6 +92,7 @@ struct ClassRegistrationInfo {
     parent_class_name: Option<ClassName>,
     // Following functions are stored separately, since their order matters.
     register_methods_constants_fn: Option<ErasedRegisterFn>,
+    register_equals_fn: Option<ErasedRegisterFn>,
     register_properties_fn: Option<ErasedRegisterFn>,
     user_register_fn: Option<ErasedRegisterFn>,
     default_virtual_fn: Option<GodotGetVirtual>, // Optional (set if there is at least one OnReady field)
@@ -101,6 +104,7 @@ struct ClassRegistrationInfo {
     #[allow(dead_code)] // Currently unused; may be useful for diagnostics in the future.
     init_level: InitLevel,
     is_editor_plugin: bool,
+    singleton_create_fn: Option<fn() -> Gd<classes::Object>>,
 
     /// One entry for each `dyn Trait` implemented (and registered) for this class.
     dynify_fns_by_trait: HashMap<any::TypeId, DynTraitImpl>,
@@ -170,6 +174,7 @@ pub fn register_class<
         class_name: T::class_name(),
         parent_class_name: Some(T::Base::class_name()),
         register_methods_constants_fn: None,
+        register_equals_fn: None,
         register_properties_fn: None,
         user_register_fn: Some(ErasedRegisterFn {
             raw: callbacks::register_class_by_builder::<T>,
@@ -179,6 +184,7 @@ pub fn register_class<
         godot_params,
         init_level: T::INIT_LEVEL,
         is_editor_plugin: false,
+        singleton_create_fn: None,
         dynify_fns_by_trait: HashMap::new(),
         component_already_filled: Default::default(), // [false; N]
     });
@@ -217,15 +223,35 @@ pub fn auto_register_classes(init_level: InitLevel) {
     register_classes_and_dyn_traits(&mut map, init_level);
 
     // Actually register all the classes.
+    let mut singletons = vec![];
     for info in map.into_values() {
-        #[cfg(feature = "debug-log")]
         let class_name = info.class_name;
+        if let Some(create_fn) = info.singleton_create_fn {
+            singletons.push((class_name, create_fn));
+        }
 
         register_class_raw(info);
 
         out!("Class {class_name} loaded.");
     }
 
+    // Singletons are only instantiated once all classes of this level are registered, since their constructors may depend on each other.
+    // The registry is not locked meanwhile, as constructors may access it.
+    let singleton_ids: HashMap<ClassName, InstanceId> = singletons
+        .into_iter()
+        .filter_map(|(class_name, create_fn)| {
+            register_engine_singleton(class_name, create_fn).map(|id| (class_name, id))
+        })
+        .collect();
+
+    // Remember which singletons are owned by this extension, so that only those are freed on unload.
+    let mut loaded_classes_by_level = global_loaded_classes_by_init_level();
+    for class in loaded_classes_by_level.entry(init_level).or_default() {
+        if let Some(&id) = singleton_ids.get(&class.name) {
+            class.singleton_id = Some(id);
+        }
+    }
+
     out!("All classes for level `{init_level:?}` auto-registered.");
 }
 
@@ -244,6 +270,7 @@ fn register_classes_and_dyn_traits(
         let loaded_class = LoadedClass {
             name: class_name,
             is_editor_plugin: info.is_editor_plugin,
+            singleton_id: None, // Set once the singleton is instantiated.
         };
         let metadata = ClassMetadata {};
 
@@ -276,6 +303,13 @@ pub fn unregister_classes(init_level: InitLevel) {
         .remove(&init_level)
         .unwrap_or_default();
 
+    // Free all singletons first, as their destructors may still use other classes of this level.
+    for class in &loaded_classes_current_level {
+        if let Some(id) = class.singleton_id {
+            unregister_engine_singleton(class.name, id);
+        }
+    }
+
     out!("Unregister classes of level {init_level:?}...");
     for class in loaded_classes_current_level.into_iter().rev() {
         // Remove from other map.
@@ -409,13 +443,19 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             is_editor_plugin,
             is_internal,
             is_instantiable,
+            register_equals_fn,
+            singleton_create_fn,
+            #[cfg(since_api = "4.3")]
+            dynamic_property_fns,
             #[cfg(all(since_api = "4.3", feature = "register-docs"))]
                 docs: _,
         }) => {
             c.parent_class_name = Some(base_class_name);
             c.default_virtual_fn = default_get_virtual_fn;
             c.register_properties_fn = Some(register_properties_fn);
+            c.register_equals_fn = register_equals_fn;
             c.is_editor_plugin = is_editor_plugin;
+            c.singleton_create_fn = singleton_create_fn;
 
             // Classes marked #[class(no_init)] are translated to "abstract" in Godot. This disables their default constructor.
             // "Abstract" is a misnomer -- it's not an abstract base class, but rather a "utility/static class" (although it can have instance
@@ -457,6 +497,25 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
                 c.godot_params.is_runtime =
                     sys::conv::bool_to_sys(crate::private::is_class_runtime(is_tool));
             }
+
+            // rustc already rejects combining #[class(dynamic_properties)] with get_property() etc. in the interface trait, as both
+            // implement the same cap traits.
+            #[cfg(since_api = "4.3")]
+            if let Some(fns) = dynamic_property_fns {
+                let p = &mut c.godot_params;
+                fill_into(&mut p.get_func, Some(fns.get_fn)).expect("duplicate: get_func (def)");
+                fill_into(&mut p.set_func, Some(fns.set_fn)).expect("duplicate: set_func (def)");
+                fill_into(
+                    &mut p.get_property_list_func,
+                    Some(fns.get_property_list_fn),
+                )
+                .expect("duplicate: get_property_list_func (def)");
+                fill_into(
+                    &mut p.free_property_list_func,
+                    Some(fns.free_property_list_fn),
+                )
+                .expect("duplicate: free_property_list_func (def)");
+            }
         }
 
         PluginItem::InherentImpl(InherentImpl {
@@ -503,10 +562,18 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
 
             c.godot_params.to_string_func = user_to_string_fn;
             c.godot_params.notification_func = user_on_notification_fn;
-            c.godot_params.set_func = user_set_fn;
-            c.godot_params.get_func = user_get_fn;
-            c.godot_params.get_property_list_func = user_get_property_list_fn;
-            c.godot_params.free_property_list_func = user_free_property_list_fn;
+            fill_into(&mut c.godot_params.set_func, user_set_fn).expect("duplicate: set_func (i)");
+            fill_into(&mut c.godot_params.get_func, user_get_fn).expect("duplicate: get_func (i)");
+            fill_into(
+                &mut c.godot_params.get_property_list_func,
+                user_get_property_list_fn,
+            )
+            .expect("duplicate: get_property_list_func (i)");
+            fill_into(
+                &mut c.godot_params.free_property_list_func,
+                user_free_property_list_fn,
+            )
+            .expect("duplicate: free_property_list_func (i)");
             c.godot_params.property_can_revert_func = user_property_can_revert_fn;
             c.godot_params.property_get_revert_func = user_property_get_revert_fn;
             c.user_virtual_fn = get_virtual_fn;
@@ -603,13 +670,17 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
     let mut class_builder = 0; // TODO dummy argument; see callbacks
 
     // Order of the following registrations is crucial:
-    // 1. Methods and constants.
+    // 1. Methods and constants (including the generated `equals()` method).
     // 2. Properties (they may depend on get/set methods).
     // 3. User-defined registration function (intuitively, user expects their own code to run after proc-macro generated code).
     if let Some(register_fn) = info.register_methods_constants_fn {
         (register_fn.raw)(&mut class_builder);
     }
 
+    if let Some(register_fn) = info.register_equals_fn {
+        (register_fn.raw)(&mut class_builder);
+    }
+
     if let Some(register_fn) = info.register_properties_fn {
         (register_fn.raw)(&mut class_builder);
     }
@@ -623,6 +694,53 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
     }
 }
 
+/// Instantiates a `#[class(singleton)]` class and registers it with the engine, under the class name.
+///
+/// Returns the ID of the registered instance, or `None` if the name is already taken.
+fn register_engine_singleton(
+    class_name: ClassName,
+    create_fn: fn() -> Gd<classes::Object>,
+) -> Option<InstanceId> {
+    let name = class_name.to_string_name();
+    let mut engine = classes::Engine::singleton();
+
+    // Godot would only print an error and keep the old instance, leaking the new one.
+    if engine.has_singleton(&name) {
+        godot_error!("Cannot register engine singleton `{class_name}`, as a singleton with this name already exists.");
+        return None;
+    }
+
+    let instance = create_fn();
+    engine.register_singleton(&name, &instance);
+
+    out!("> Engine singleton registered");
+    Some(instance.instance_id())
+}
+
+/// Unregisters and frees the engine singleton of a `#[class(singleton)]` class, if it is still the instance with `id`.
+///
+/// Singletons registered under the same name by others (e.g. after this extension's one was unregistered manually) are left untouched.
+fn unregister_engine_singleton(class_name: ClassName, id: InstanceId) {
+    let name = class_name.to_string_name();
+    let mut engine = classes::Engine::singleton();
+
+    let Some(instance) = engine.get_singleton(&name) else {
+        return;
+    };
+
+    if instance.instance_id() != id {
+        godot_warn!(
+            "Engine singleton `{class_name}` was replaced by another object; not freeing it."
+        );
+        return;
+    }
+
+    engine.unregister_singleton(&name);
+    instance.free();
+
+    out!("Engine singleton {class_name} freed");
+}
+
 fn validate_class_constraints(_class: &ClassRegistrationInfo) {
     // TODO: if we add builder API, the proc-macro checks in parse_struct_attributes() etc. should be duplicated here.
 }
@@ -674,6 +792,7 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
         class_name,
         parent_class_name: None,
         register_methods_constants_fn: None,
+        register_equals_fn: None,
         register_properties_fn: None,
         user_register_fn: None,
         default_virtual_fn: None,
@@ -681,6 +800,7 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
         godot_params: default_creation_info(),
         init_level: InitLevel::Scene,
         is_editor_plugin: false,
+        singleton_create_fn: None,
         dynify_fns_by_trait: HashMap::new(),
         component_already_filled: Default::default(), // [false; N]
     }
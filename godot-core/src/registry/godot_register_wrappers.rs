@@ -79,3 +79,16 @@ fn register_var_or_export_inner(
         );
     }
 }
+
+/// Float types, for exports whose property hint only applies to floats.
+#[diagnostic::on_unimplemented(
+    message = "`#[export(exp_easing)]` requires a field of type `f32` or `f64`, found `{Self}`",
+    label = "not a float type"
+)]
+pub trait ExportFloat {}
+
+impl ExportFloat for f32 {}
+impl ExportFloat for f64 {}
+
+/// Statically verifies that an `#[export(exp_easing)]` field has a float type.
+pub fn assert_export_float<T: ExportFloat>() {}
@@ -187,6 +187,18 @@ pub struct Struct {
     /// Whether the class has a default constructor.
     pub(crate) is_instantiable: bool,
 
+    /// Registers the `equals()` method, if `#[class(eq)]` was used.
+    pub(crate) register_equals_fn: Option<ErasedRegisterFn>,
+
+    /// Creates the instance to be registered as engine singleton, if `#[class(singleton)]` was used.
+    pub(crate) singleton_create_fn: Option<fn() -> Gd<classes::Object>>,
+
+    /// Property callbacks, if `#[class(dynamic_properties)]` was used.
+    ///
+    /// This is mutually exclusive with the respective callbacks in [`ITraitImpl`].
+    #[cfg(since_api = "4.3")]
+    pub(crate) dynamic_property_fns: Option<DynamicPropertyFns>,
+
     /// Documentation extracted from the struct's RustDoc.
     #[cfg(all(since_api = "4.3", feature = "register-docs"))]
     pub(crate) docs: StructDocs,
@@ -209,6 +221,10 @@ impl Struct {
             is_editor_plugin: false,
             is_internal: false,
             is_instantiable: false,
+            register_equals_fn: None,
+            singleton_create_fn: None,
+            #[cfg(since_api = "4.3")]
+            dynamic_property_fns: None,
             #[cfg(all(since_api = "4.3", feature = "register-docs"))]
             docs,
         }
@@ -249,6 +265,74 @@ impl Struct {
         self.is_instantiable = true;
         self
     }
+
+    pub fn with_content_eq<T: GodotClass + cap::GodotContentEq>(mut self) -> Self {
+        set(
+            &mut self.register_equals_fn,
+            ErasedRegisterFn {
+                raw: callbacks::register_user_equals::<T>,
+            },
+        );
+        self
+    }
+
+    pub fn with_singleton<T>(mut self) -> Self
+    where
+        T: cap::GodotDefault
+            + Inherits<classes::Object>
+            + Bounds<Memory: crate::private::SingletonMemory>,
+    {
+        set(&mut self.singleton_create_fn, || {
+            T::__godot_default().upcast::<classes::Object>()
+        });
+        self
+    }
+
+    #[cfg(since_api = "4.3")]
+    pub fn with_dynamic_properties<T>(mut self) -> Self
+    where
+        T: GodotClass + cap::GodotGet + cap::GodotSet + cap::GodotGetPropertyList,
+    {
+        set(
+            &mut self.dynamic_property_fns,
+            DynamicPropertyFns {
+                get_fn: callbacks::get_property::<T>,
+                set_fn: callbacks::set_property::<T>,
+                get_property_list_fn: callbacks::get_property_list::<T>,
+                free_property_list_fn: callbacks::free_property_list::<T>,
+            },
+        );
+        self
+    }
+}
+
+/// Low-level callbacks for `#[class(dynamic_properties)]`, dispatching to [`DynamicProperties`][crate::obj::DynamicProperties].
+#[cfg(since_api = "4.3")]
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct DynamicPropertyFns {
+    pub(crate) get_fn: unsafe extern "C" fn(
+        p_instance: sys::GDExtensionClassInstancePtr,
+        p_name: sys::GDExtensionConstStringNamePtr,
+        r_ret: sys::GDExtensionVariantPtr,
+    ) -> sys::GDExtensionBool,
+
+    pub(crate) set_fn: unsafe extern "C" fn(
+        p_instance: sys::GDExtensionClassInstancePtr,
+        p_name: sys::GDExtensionConstStringNamePtr,
+        p_value: sys::GDExtensionConstVariantPtr,
+    ) -> sys::GDExtensionBool,
+
+    pub(crate) get_property_list_fn: unsafe extern "C" fn(
+        p_instance: sys::GDExtensionClassInstancePtr,
+        r_count: *mut u32,
+    )
+        -> *const sys::GDExtensionPropertyInfo,
+
+    pub(crate) free_property_list_fn: unsafe extern "C" fn(
+        p_instance: sys::GDExtensionClassInstancePtr,
+        p_list: *const sys::GDExtensionPropertyInfo,
+        p_count: u32,
+    ),
 }
 
 /// Stores registration functions for methods, constants, and documentation from inherent `#[godot_api]` impl blocks.
@@ -443,6 +443,16 @@ pub mod export_info_functions {
         }
     }
 
+    /// Equivalent to `@export_node_path` in Godot.
+    ///
+    /// Restricts the node picker of an exported `NodePath` to nodes of the given classes (or derived ones).
+    pub fn export_node_path_valid_types(class_names: &[&str]) -> PropertyHintInfo {
+        PropertyHintInfo {
+            hint: PropertyHint::NODE_PATH_VALID_TYPES,
+            hint_string: class_names.join(",").into(),
+        }
+    }
+
     macro_rules! default_export_funcs {
         (
             $( $function_name:ident => $property_hint:ident, )*
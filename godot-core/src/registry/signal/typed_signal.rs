@@ -5,10 +5,11 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::{Callable, Variant};
+use crate::builtin::{Callable, StringName, Variant};
 use crate::classes::object::ConnectFlags;
 use crate::obj::{bounds, Bounds, Gd, GodotClass, WithBaseField, WithSignals, WithUserSignals};
 use crate::registry::signal::{make_callable_name, make_godot_fn, ConnectBuilder, SignalReceiver};
+use crate::task::ThreadsafeSignal;
 use crate::{classes, meta};
 use std::borrow::Cow;
 use std::marker::PhantomData;
@@ -84,6 +85,9 @@ impl<C: GodotClass> SignalObj<C> for Gd<C> {
 ///
 /// For generic use, you can also use [`emit_tuple()`][Self::emit_tuple], which does not provide parameter names.
 ///
+/// To emit from a background thread, use [`to_threadsafe()`][Self::to_threadsafe]. Such emissions are deferred to the main thread, where
+/// connected handlers run.
+///
 /// # More information
 /// See the [Signals](https://godot-rust.github.io/book/register/signals.html) chapter in the book for a detailed introduction and examples.
 pub struct TypedSignal<'c, C: WithSignals, Ps> {
@@ -119,6 +123,28 @@ impl<'c, C: WithSignals, Ps: meta::ParamTuple> TypedSignal<'c, C, Ps> {
         });
     }
 
+    /// Queue emission of the signal on the main thread, instead of emitting it immediately.
+    ///
+    /// Connected handlers run on the main thread during the next process frame. To emit from other threads, obtain a `Send` handle via
+    /// [`to_threadsafe()`][Self::to_threadsafe] and move it to the thread.
+    ///
+    /// Arguments are serialized, which is lossy for objects; see [`ThreadsafeSignal`] for details.
+    pub fn emit_threadsafe(&self, args: Ps) {
+        self.to_threadsafe().emit_threadsafe(args);
+    }
+
+    /// Returns a handle that can be moved to other threads, to emit this signal from there.
+    ///
+    /// See [`ThreadsafeSignal`] for details. Connected handlers always run on the main thread.
+    ///
+    /// # Panics
+    /// If the `Scene` init level of the extension is not loaded.
+    pub fn to_threadsafe(&self) -> ThreadsafeSignal<Ps> {
+        let object_id = self.receiver_object().instance_id();
+
+        ThreadsafeSignal::new(object_id, StringName::from(self.name.as_ref()))
+    }
+
     /// Connect a non-member function (global function, associated function or closure).
     ///
     /// Example usages:
@@ -13,6 +13,7 @@
 
 use crate::builtin::Variant;
 use crate::meta;
+use crate::meta::error::ConvertError;
 
 /// Trait that is implemented for functions that can be connected to signals.
 ///
@@ -35,6 +36,19 @@ pub trait SignalReceiver<I, Ps>: 'static {
 pub trait ParamTuple: 'static {
     fn to_variant_array(&self) -> Vec<Variant>;
     fn from_variant_array(array: &[&Variant]) -> Self;
+
+    /// Like [`from_variant_array()`][Self::from_variant_array], but returns an error if the number or types of arguments do not match.
+    ///
+    /// The default implementation catches a panic from `from_variant_array()` and turns its message into the error. Tuples override this
+    /// to check arguments without panicking.
+    fn try_from_variant_array(array: &[&Variant]) -> Result<Self, ConvertError>
+    where
+        Self: Sized,
+    {
+        let from_array = std::panic::AssertUnwindSafe(|| Self::from_variant_array(array));
+        std::panic::catch_unwind(from_array)
+            .map_err(|err| ConvertError::new(crate::private::extract_panic_message(&*err)))
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -66,6 +80,28 @@ macro_rules! impl_signal_recipient {
                   ),
                )* )
             }
+
+            #[allow(unused_variables, unused_mut, clippy::unused_unit)]
+            fn try_from_variant_array(array: &[&Variant]) -> Result<Self, ConvertError> {
+                let param_names: &[&str] = &[ $( stringify!($args) ),* ];
+                if array.len() != param_names.len() {
+                    return Err(ConvertError::new(format!(
+                        "expected {} arguments, got {}",
+                        param_names.len(),
+                        array.len()
+                    )));
+                }
+
+                let mut iter = array.iter().enumerate();
+                Ok(( $(
+                    {
+                        let (index, variant) = iter.next().expect("length checked above");
+                        <$Ps as meta::FromGodot>::try_from_variant(variant).map_err(|err| {
+                            ConvertError::new(format!("argument #{index}: {err}"))
+                        })?
+                    },
+                )* ))
+            }
         }
 
         // --------------------------------------------------------------------------------------------------------------------------------------
@@ -86,12 +86,14 @@ pub unsafe trait Storage {
     ///
     /// This will ensure Rust's rules surrounding references are upheld. Possibly panicking at runtime if
     /// they are violated.
+    #[track_caller]
     fn get(&self) -> RefGuard<'_, Self::Instance>;
 
     /// Returns a mutable/exclusive reference to this storage's instance.
     ///
     /// This will ensure Rust's rules surrounding references are upheld. Possibly panicking at runtime if
     /// they are violated.
+    #[track_caller]
     fn get_mut(&self) -> MutGuard<'_, Self::Instance>;
 
     /// Returns a guard that allows calling methods on `Gd<Base>` that take `&mut self`.
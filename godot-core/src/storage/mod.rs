@@ -89,16 +89,27 @@ fn log_drop<T: StorageRefCounted>(storage: &T) {
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Tracking borrows in Debug mode
 
+#[cfg(all(debug_assertions, feature = "trace"))]
+pub use borrow_info::capture_borrow_trace;
 #[cfg(debug_assertions)]
 use borrow_info::DebugBorrowTracker;
+#[cfg(debug_assertions)]
+pub use borrow_info::{set_borrow_tracing, trace_release};
 
+#[cfg(all(not(debug_assertions), feature = "trace"))]
+pub use borrow_info_noop::capture_borrow_trace;
 #[cfg(not(debug_assertions))]
 use borrow_info_noop::DebugBorrowTracker;
+#[cfg(not(debug_assertions))]
+pub use borrow_info_noop::{set_borrow_tracing, trace_release};
 
 #[cfg(debug_assertions)]
 mod borrow_info {
+    use std::any::type_name;
     use std::backtrace::Backtrace;
     use std::fmt;
+    use std::panic::Location;
+    use std::sync::atomic::{AtomicU8, Ordering};
     use std::sync::Mutex;
 
     struct TrackedBorrow {
@@ -125,19 +136,23 @@ mod borrow_info {
         // User is expected to set the env var during debug sessions.
 
         #[track_caller]
-        pub fn track_ref_borrow(&self) {
+        pub fn track_ref_borrow<T>(&self) {
+            trace_acquire::<T>(false, Location::caller());
+
             let mut guard = self.last_borrow.lock().unwrap();
             *guard = Some(TrackedBorrow {
-                backtrace: Backtrace::capture(),
+                backtrace: crate::private::capture_backtrace(),
                 is_mut: false,
             });
         }
 
         #[track_caller]
-        pub fn track_mut_borrow(&self) {
+        pub fn track_mut_borrow<T>(&self) {
+            trace_acquire::<T>(true, Location::caller());
+
             let mut guard = self.last_borrow.lock().unwrap();
             *guard = Some(TrackedBorrow {
-                backtrace: Backtrace::capture(),
+                backtrace: crate::private::capture_backtrace(),
                 is_mut: true,
             });
         }
@@ -158,11 +173,108 @@ mod borrow_info {
             }
         }
     }
+
+    // Continuous tracing of all binds. The env var is only read once, on the first borrow.
+
+    const TRACING_UNKNOWN: u8 = 0;
+    const TRACING_OFF: u8 = 1;
+    const TRACING_ON: u8 = 2;
+
+    static TRACING: AtomicU8 = AtomicU8::new(TRACING_UNKNOWN);
+
+    /// Enables or disables logging of every `bind()`/`bind_mut()` acquire and release to the Godot output.
+    ///
+    /// Can also be enabled by setting the environment variable `GODOT_RUST_TRACE_BORROWS=1` before startup. Each line contains the
+    /// borrow kind, the Rust type and the source location of the borrow, e.g.:
+    /// ```text
+    /// [borrow] acquire bind_mut <mygame::Player> at src/player.rs:42:20
+    /// [borrow] release bind_mut <mygame::Player> (acquired at src/player.rs:42:20)
+    /// ```
+    ///
+    /// Borrows taken by Godot when it calls `#[func]` methods or interface callbacks are logged on acquire; they are held for the
+    /// duration of that call. This is useful to find the exact sequence of binds that leads to a "Gd<T>::bind_mut() failed" panic.
+    ///
+    /// Only available in Debug builds; in Release builds, this function does nothing and tracing has no cost.
+    pub fn set_borrow_tracing(enabled: bool) {
+        let state = if enabled { TRACING_ON } else { TRACING_OFF };
+        TRACING.store(state, Ordering::Relaxed);
+    }
+
+    fn is_tracing() -> bool {
+        match TRACING.load(Ordering::Relaxed) {
+            TRACING_ON => true,
+            TRACING_OFF => false,
+            _ => {
+                let enabled = std::env::var_os("GODOT_RUST_TRACE_BORROWS")
+                    .is_some_and(|value| !value.is_empty() && value != "0");
+
+                set_borrow_tracing(enabled);
+                enabled
+            }
+        }
+    }
+
+    fn bind_kind(is_mut: bool) -> &'static str {
+        if is_mut {
+            "bind_mut"
+        } else {
+            "bind"
+        }
+    }
+
+    fn trace_acquire<T>(is_mut: bool, location: &Location<'_>) {
+        if is_tracing() {
+            write_trace(format_args!(
+                "[borrow] acquire {kind} <{ty}> at {location}",
+                kind = bind_kind(is_mut),
+                ty = type_name::<T>(),
+            ));
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn trace_release<T>(is_mut: bool, acquired_at: &Location<'_>) {
+        if is_tracing() {
+            write_trace(format_args!(
+                "[borrow] release {kind} <{ty}> (acquired at {acquired_at})",
+                kind = bind_kind(is_mut),
+                ty = type_name::<T>(),
+            ));
+        }
+    }
+
+    fn write_trace(line: fmt::Arguments) {
+        #[cfg(feature = "trace")]
+        if CAPTURED_TRACE.with_borrow_mut(|captured| {
+            captured
+                .as_mut()
+                .map(|lines| lines.push(line.to_string()))
+                .is_some()
+        }) {
+            return;
+        }
+
+        crate::godot_print!("{line}");
+    }
+
+    #[cfg(feature = "trace")]
+    thread_local! {
+        static CAPTURED_TRACE: std::cell::RefCell<Option<Vec<String>>> = const { std::cell::RefCell::new(None) };
+    }
+
+    /// For itest: runs `f` and returns the trace lines of the borrows on this thread, instead of printing them.
+    #[cfg(feature = "trace")]
+    pub fn capture_borrow_trace(f: impl FnOnce()) -> Vec<String> {
+        CAPTURED_TRACE.set(Some(Vec::new()));
+        f();
+        CAPTURED_TRACE.take().unwrap_or_default()
+    }
 }
 
 #[cfg(not(debug_assertions))]
 mod borrow_info_noop {
     use std::fmt;
+    use std::panic::Location;
 
     pub(super) struct DebugBorrowTracker;
 
@@ -171,9 +283,9 @@ mod borrow_info_noop {
             Self
         }
 
-        pub fn track_ref_borrow(&self) {}
+        pub fn track_ref_borrow<T>(&self) {}
 
-        pub fn track_mut_borrow(&self) {}
+        pub fn track_mut_borrow<T>(&self) {}
     }
 
     impl fmt::Display for DebugBorrowTracker {
@@ -181,4 +293,18 @@ mod borrow_info_noop {
             Ok(())
         }
     }
+
+    /// Enables or disables logging of every `bind()`/`bind_mut()` acquire and release to the Godot output.
+    ///
+    /// Only available in Debug builds; in Release builds, this function does nothing.
+    pub fn set_borrow_tracing(_enabled: bool) {}
+
+    #[cfg(feature = "trace")]
+    pub fn capture_borrow_trace(f: impl FnOnce()) -> Vec<String> {
+        f();
+        Vec::new()
+    }
+
+    #[doc(hidden)]
+    pub fn trace_release<T>(_is_mut: bool, _acquired_at: &Location<'_>) {}
 }
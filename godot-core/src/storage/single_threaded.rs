@@ -63,23 +63,25 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
         &self.base
     }
 
+    #[track_caller]
     fn get(&self) -> RefGuard<'_, T> {
         let guard = self
             .user_instance
             .borrow()
             .unwrap_or_else(|e| super::bind_failed::<T>(e, &self.borrow_tracker));
 
-        self.borrow_tracker.track_ref_borrow();
+        self.borrow_tracker.track_ref_borrow::<T>();
         guard
     }
 
+    #[track_caller]
     fn get_mut(&self) -> MutGuard<'_, T> {
         let guard = self
             .user_instance
             .borrow_mut()
             .unwrap_or_else(|e| super::bind_mut_failed::<T>(e, &self.borrow_tracker));
 
-        self.borrow_tracker.track_mut_borrow();
+        self.borrow_tracker.track_mut_borrow::<T>();
         guard
     }
 
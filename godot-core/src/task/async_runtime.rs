@@ -6,16 +6,18 @@
  */
 
 use std::cell::RefCell;
+use std::fmt;
 use std::future::Future;
-use std::marker::PhantomData;
 use std::panic::AssertUnwindSafe;
 use std::pin::Pin;
+use std::rc::Rc;
 use std::sync::Arc;
 use std::task::{Context, Poll, Wake, Waker};
 use std::thread::{self, LocalKey, ThreadId};
 
 use crate::builtin::{Callable, Variant};
 use crate::private::handle_panic;
+use crate::task::runtime_integration;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Public interface
@@ -23,11 +25,15 @@ use crate::private::handle_panic;
 /// Create a new async background task.
 ///
 /// This function allows creating a new async task in which Godot signals can be awaited, like it is possible in GDScript. The
-/// [`TaskHandle`] that is returned provides synchronous introspection into the current state of the task.
+/// [`TaskHandle`] that is returned provides synchronous introspection into the current state of the task, and gives access to the output
+/// of the future once it has completed.
 ///
 /// Refer to [`Signal::to_future`](crate::builtin::Signal::to_future) and [`Signal::to_fallible_future`](crate::builtin::Signal::to_fallible_future)
 /// for details on how to await a signal.
 ///
+/// If [`ExtensionLibrary::async_runtime()`][crate::init::ExtensionLibrary::async_runtime] provides a third-party runtime, the task is
+/// spawned on it via [`AsyncRuntime::spawn_local()`][crate::task::AsyncRuntime::spawn_local], and first polled during its next update.
+///
 /// # Panics
 /// - If called from any other thread than the main-thread.
 ///
@@ -47,7 +53,7 @@ use crate::private::handle_panic;
 ///     println!("node has changed: {}", node.get_name());
 /// });
 /// ```
-pub fn spawn(future: impl Future<Output = ()> + 'static) -> TaskHandle {
+pub fn spawn<T: 'static>(future: impl Future<Output = T> + 'static) -> TaskHandle<T> {
     // Spawning new tasks is only allowed on the main thread for now.
     // We can not accept Sync + Send futures since all object references (i.e. Gd<T>) are not thread-safe. So a future has to remain on the
     // same thread it was created on. Godots signals on the other hand can be emitted on any thread, so it can't be guaranteed on which thread
@@ -61,43 +67,52 @@ pub fn spawn(future: impl Future<Output = ()> + 'static) -> TaskHandle {
         "godot_task() can only be used on the main thread"
     );
 
-    let (task_handle, godot_waker) = ASYNC_RUNTIME.with_runtime_mut(move |rt| {
-        let task_handle = rt.add_task(Box::pin(future));
-        let godot_waker = Arc::new(GodotWaker::new(
-            task_handle.index,
-            task_handle.id,
-            thread::current().id(),
-        ));
+    let output = Rc::new(RefCell::new(TaskOutput::Pending(Vec::new())));
+    let guard = OutputGuard {
+        output: output.clone(),
+    };
 
-        (task_handle, godot_waker)
-    });
+    // The guard records the output. If the future is dropped before completing, it records cancellation or panic instead.
+    let task = async move {
+        let value = future.await;
+        guard.finish(Ok(value));
+    };
+
+    let (index, id) = ASYNC_RUNTIME.with_runtime_mut(move |rt| rt.add_task(task));
 
-    poll_future(godot_waker);
-    task_handle
+    // A third-party runtime polls the task and receives its wake-ups; otherwise, the engine does.
+    match runtime_integration::installed_runtime() {
+        Some(runtime) => runtime.spawn_local(Box::pin(RoutedTask { index, id })),
+        None => poll_future(Arc::new(GodotWaker::new(index, id, thread::current().id()))),
+    }
+
+    TaskHandle::new(index, id, output)
 }
 
 /// Handle for an active background task.
 ///
-/// This handle provides introspection into the current state of the task, as well as providing a way to cancel it.
+/// This handle provides introspection into the current state of the task, a way to cancel it, and access to the output `T` of its future:
+/// - [`join()`][Self::join] returns a future to await the output in another task.
+/// - [`try_result()`][Self::try_result] retrieves the output from synchronous code, once the task has finished.
+///
+/// A task that panics is dropped, and its result is [`TaskError::Panicked`]. The panic is still printed, but doesn't affect other tasks.
 ///
 /// The associated task will **not** be canceled if this handle is dropped.
-pub struct TaskHandle {
+pub struct TaskHandle<T = ()> {
     index: usize,
     id: u64,
-    _no_send_sync: PhantomData<*const ()>,
+    output: Rc<RefCell<TaskOutput<T>>>,
 }
 
-impl TaskHandle {
-    fn new(index: usize, id: u64) -> Self {
-        Self {
-            index,
-            id,
-            _no_send_sync: PhantomData,
-        }
+impl<T> TaskHandle<T> {
+    fn new(index: usize, id: u64, output: Rc<RefCell<TaskOutput<T>>>) -> Self {
+        Self { index, id, output }
     }
 
     /// Cancels the task if it is still pending and does nothing if it is already completed.
-    pub fn cancel(self) {
+    ///
+    /// The result of a canceled task is [`TaskError::Canceled`].
+    pub fn cancel(&self) {
         ASYNC_RUNTIME.with_runtime_mut(|rt| {
             let Some(task) = rt.tasks.get(self.index) else {
                 // Getting the task from the runtime might return None if the runtime has already been deinitialized. In this case, we just
@@ -140,6 +155,145 @@ impl TaskHandle {
             )
         })
     }
+
+    /// Whether the task has finished, i.e. completed, was canceled or panicked.
+    ///
+    /// Once this returns `true`, [`try_result()`][Self::try_result] returns the result.
+    pub fn is_finished(&self) -> bool {
+        !matches!(*self.output.borrow(), TaskOutput::Pending(_))
+    }
+
+    /// Returns the result of the task if it has finished, or `None` if it is still pending.
+    ///
+    /// The output is moved out of the handle.
+    ///
+    /// # Panics
+    /// If the result has already been retrieved with a previous call.
+    pub fn try_result(&self) -> Option<Result<T, TaskError>> {
+        let mut output = self.output.borrow_mut();
+
+        match std::mem::replace(&mut *output, TaskOutput::Taken) {
+            TaskOutput::Pending(wakers) => {
+                *output = TaskOutput::Pending(wakers);
+                None
+            }
+            TaskOutput::Finished(result) => Some(result),
+            TaskOutput::Taken => panic!("TaskHandle::try_result(): result was already retrieved"),
+        }
+    }
+
+    /// Returns a future that resolves to the result of the task, to be awaited in another task.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::task;
+    ///
+    /// let loading = task::spawn(async {
+    ///     task::next_frame().await.unwrap();
+    ///     42
+    /// });
+    ///
+    /// task::spawn(async move {
+    ///     let value = loading.join().await.expect("loading task failed");
+    ///     assert_eq!(value, 42);
+    /// });
+    /// ```
+    pub fn join(self) -> TaskJoin<T> {
+        TaskJoin {
+            output: self.output,
+        }
+    }
+}
+
+/// Future returned by [`TaskHandle::join()`], resolving to the result of the task.
+#[must_use = "futures do nothing unless awaited"]
+pub struct TaskJoin<T> {
+    output: Rc<RefCell<TaskOutput<T>>>,
+}
+
+impl<T> Future for TaskJoin<T> {
+    type Output = Result<T, TaskError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut output = self.output.borrow_mut();
+
+        match std::mem::replace(&mut *output, TaskOutput::Taken) {
+            TaskOutput::Pending(mut wakers) => {
+                wakers.push(cx.waker().clone());
+                *output = TaskOutput::Pending(wakers);
+                Poll::Pending
+            }
+            TaskOutput::Finished(result) => Poll::Ready(result),
+            TaskOutput::Taken => panic!("TaskJoin polled after the result was retrieved"),
+        }
+    }
+}
+
+/// Reason why a task spawned with [`spawn()`] did not produce an output.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TaskError {
+    /// The task was canceled with [`TaskHandle::cancel()`], or dropped during engine shutdown.
+    Canceled,
+
+    /// The future panicked while being polled.
+    Panicked,
+}
+
+impl fmt::Display for TaskError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Canceled => write!(f, "task was canceled before completing"),
+            Self::Panicked => write!(f, "task panicked"),
+        }
+    }
+}
+
+impl std::error::Error for TaskError {}
+
+enum TaskOutput<T> {
+    /// Task still running; holds the wakers of tasks awaiting it.
+    Pending(Vec<Waker>),
+    Finished(Result<T, TaskError>),
+    Taken,
+}
+
+/// Lives inside the spawned future and records how it ended.
+struct OutputGuard<T> {
+    output: Rc<RefCell<TaskOutput<T>>>,
+}
+
+impl<T> OutputGuard<T> {
+    fn finish(&self, result: Result<T, TaskError>) {
+        let wakers = {
+            let mut output = self.output.borrow_mut();
+            match std::mem::replace(&mut *output, TaskOutput::Finished(result)) {
+                TaskOutput::Pending(wakers) => wakers,
+
+                // Already finished; keep the first result.
+                previous => {
+                    *output = previous;
+                    return;
+                }
+            }
+        };
+
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Drop for OutputGuard<T> {
+    fn drop(&mut self) {
+        // Dropped during unwinding when polling the future panicked; otherwise, the runtime dropped the unfinished future.
+        let error = if thread::panicking() {
+            TaskError::Panicked
+        } else {
+            TaskError::Canceled
+        };
+
+        self.finish(Err(error));
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -159,6 +313,7 @@ thread_local! {
 /// try to access engine resources, which leads to SEGFAULTs.
 pub(crate) fn cleanup() {
     ASYNC_RUNTIME.set(None);
+    runtime_integration::uninstall_runtime();
 }
 
 #[cfg(feature = "trace")]
@@ -278,7 +433,7 @@ impl AsyncRuntime {
     /// free slot, a new slot is added which may grow the underlying [`Vec`].
     ///
     /// The future storage always starts out with a capacity of 10 tasks.
-    fn add_task<F: Future<Output = ()> + 'static>(&mut self, future: F) -> TaskHandle {
+    fn add_task<F: Future<Output = ()> + 'static>(&mut self, future: F) -> (usize, u64) {
         let id = self.next_id();
         let index_slot = self
             .tasks
@@ -300,7 +455,7 @@ impl AsyncRuntime {
             }
         };
 
-        TaskHandle::new(index, id)
+        (index, id)
     }
 
     /// Extract a pending task from the storage.
@@ -382,9 +537,16 @@ fn poll_future(godot_waker: Arc<GodotWaker>) {
     let waker = Waker::from(godot_waker.clone());
     let mut ctx = Context::from_waker(&waker);
 
+    let _ = poll_task(godot_waker.runtime_index, godot_waker.task_id, &mut ctx);
+}
+
+/// Polls the task stored at `index` once, waking `ctx` for the next poll.
+///
+/// Returns `Poll::Ready` if the task is gone afterwards, i.e. it completed, panicked or was canceled.
+fn poll_task(index: usize, task_id: u64, ctx: &mut Context) -> Poll<()> {
     // Move future out of the runtime while we are polling it to avoid holding a mutable reference for the entire runtime.
-    let future = ASYNC_RUNTIME.with_runtime_mut(|rt| {
-        match rt.take_task_for_polling(godot_waker.runtime_index, godot_waker.task_id) {
+    let future =
+        ASYNC_RUNTIME.with_runtime_mut(|rt| match rt.take_task_for_polling(index, task_id) {
             FutureSlotState::Empty => {
                 panic!("Future slot is empty when waking it! This is a bug!");
             }
@@ -396,12 +558,11 @@ fn poll_future(godot_waker: Arc<GodotWaker>) {
             }
 
             FutureSlotState::Pending(future) => Some(future),
-        }
-    });
+        });
 
     let Some(future) = future else {
         // Future has been canceled while the waker was already triggered.
-        return;
+        return Poll::Ready(());
     };
 
     let error_context = || "Godot async task failed".to_string();
@@ -409,30 +570,56 @@ fn poll_future(godot_waker: Arc<GodotWaker>) {
     // If Future::poll() panics, the future is immediately dropped and cannot be accessed again,
     // thus any state that may not have been unwind-safe cannot be observed later.
     let mut future = AssertUnwindSafe(future);
+    let ctx = AssertUnwindSafe(ctx);
 
     let panic_result = handle_panic(error_context, move || {
-        (future.as_mut().poll(&mut ctx), future)
+        // Move the whole wrapper into the closure, not just the (non-unwind-safe) field.
+        let ctx = ctx;
+        (future.as_mut().poll(ctx.0), future)
     });
 
     let Ok((poll_result, future)) = panic_result else {
         // Polling the future caused a panic. The task state has to be cleaned up and we want track the panic if the trace feature is enabled.
         ASYNC_RUNTIME.with_runtime_mut(|rt| {
             #[cfg(feature = "trace")]
-            rt.track_panic(godot_waker.task_id);
-            rt.clear_task(godot_waker.runtime_index);
+            rt.track_panic(task_id);
+            rt.clear_task(index);
         });
 
-        return;
+        return Poll::Ready(());
     };
 
     // Update the state of the Future in the runtime.
     ASYNC_RUNTIME.with_runtime_mut(|rt| match poll_result {
         // Future is still pending, so we park it again.
-        Poll::Pending => rt.park_task(godot_waker.runtime_index, future.0),
+        Poll::Pending => rt.park_task(index, future.0),
 
         // Future has resolved, so we remove it from the runtime.
-        Poll::Ready(()) => rt.clear_task(godot_waker.runtime_index),
+        Poll::Ready(()) => rt.clear_task(index),
     });
+
+    poll_result
+}
+
+/// Task spawned on a third-party [`AsyncRuntime`][crate::task::AsyncRuntime], which polls it with its own wakers.
+///
+/// The future itself stays in the engine's task storage, so that [`TaskHandle`] works the same for both kinds of tasks.
+struct RoutedTask {
+    index: usize,
+    id: u64,
+}
+
+impl Future for RoutedTask {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        // The runtime may outlive the engine's task storage during shutdown; tasks are gone then.
+        if ASYNC_RUNTIME.with_borrow(|rt| rt.is_none()) {
+            return Poll::Ready(());
+        }
+
+        poll_task(self.index, self.id, cx)
+    }
 }
 
 /// Implementation of a [`Waker`] to poll futures with the engine.
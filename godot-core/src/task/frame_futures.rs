@@ -0,0 +1,264 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::future::Future;
+#[cfg(feature = "codegen-full")]
+use std::time::Duration;
+
+use crate::builtin::Signal;
+use crate::classes::{Engine, Node, SceneTree};
+use crate::obj::{Gd, InstanceId};
+use crate::task::{select_signal, FallibleSignalFuture, FallibleSignalFutureError, Selected};
+
+/// Waits until the next process frame, via the `SceneTree::process_frame` signal.
+///
+/// Resolves to an error if the scene tree is freed before, e.g. during engine shutdown.
+///
+/// # Panics
+/// If the main loop is not a `SceneTree`.
+///
+/// # Example
+/// ```no_run
+/// use godot::task;
+///
+/// task::spawn(async {
+///     task::next_frame().await.expect("scene tree freed");
+///     godot::global::godot_print!("one frame later");
+/// });
+/// ```
+pub fn next_frame() -> FallibleSignalFuture<()> {
+    tree_signal("process_frame")
+}
+
+/// Waits until the next physics frame, via the `SceneTree::physics_frame` signal.
+///
+/// Resolves to an error if the scene tree is freed before, e.g. during engine shutdown.
+///
+/// # Panics
+/// If the main loop is not a `SceneTree`.
+pub fn next_physics_frame() -> FallibleSignalFuture<()> {
+    tree_signal("physics_frame")
+}
+
+/// Waits until `condition` returns `true`, checking it immediately and then once per process frame.
+///
+/// Resolves to an error if the scene tree is freed before, e.g. during engine shutdown.
+///
+/// # Panics
+/// If the main loop is not a `SceneTree`.
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::Node;
+/// use godot::prelude::*;
+/// use godot::task;
+///
+/// fn on_ready(node: Gd<Node>) {
+///     task::spawn(async move {
+///         let _ = task::until(move || node.get_child_count() > 0).await;
+///     });
+/// }
+/// ```
+pub fn until<F>(mut condition: F) -> impl Future<Output = Result<(), FallibleSignalFutureError>>
+where
+    F: FnMut() -> bool + 'static,
+{
+    async move {
+        while !condition() {
+            next_frame().await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Waits for `duration`, using a `SceneTreeTimer`.
+///
+/// Equivalent to GDScript's `await get_tree().create_timer(duration).timeout`. The timer keeps running while the scene tree is paused;
+/// use [`sleep_ex()`] to change this.
+///
+/// Resolves to an error if the timer is freed before, e.g. during engine shutdown.
+///
+/// # Panics
+/// If the main loop is not a `SceneTree`.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use godot::task;
+///
+/// task::spawn(async {
+///     task::sleep(Duration::from_secs(1)).await.expect("timer freed");
+///     godot::global::godot_print!("one second later");
+/// });
+/// ```
+#[cfg(feature = "codegen-full")]
+pub fn sleep(duration: Duration) -> FallibleSignalFuture<()> {
+    sleep_ex(duration).done()
+}
+
+/// Like [`sleep()`], with options for pausing, physics and time scale.
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use godot::task;
+///
+/// // Stops counting while the tree is paused.
+/// let future = task::sleep_ex(Duration::from_millis(500))
+///     .process_always(false)
+///     .done();
+/// ```
+#[cfg(feature = "codegen-full")]
+pub fn sleep_ex(duration: Duration) -> ExSleep {
+    ExSleep {
+        duration,
+        process_always: true,
+        process_in_physics: false,
+        ignore_time_scale: false,
+    }
+}
+
+/// Builder returned by [`sleep_ex()`]. The options correspond to the parameters of `SceneTree::create_timer()`.
+#[cfg(feature = "codegen-full")]
+#[must_use]
+pub struct ExSleep {
+    duration: Duration,
+    process_always: bool,
+    process_in_physics: bool,
+    ignore_time_scale: bool,
+}
+
+#[cfg(feature = "codegen-full")]
+impl ExSleep {
+    /// Whether the timer keeps running while the scene tree is paused. Default `true`.
+    pub fn process_always(self, process_always: bool) -> Self {
+        Self {
+            process_always,
+            ..self
+        }
+    }
+
+    /// Whether the timer is updated in physics frames instead of process frames. Default `false`.
+    pub fn process_in_physics(self, process_in_physics: bool) -> Self {
+        Self {
+            process_in_physics,
+            ..self
+        }
+    }
+
+    /// Whether the timer ignores `Engine.time_scale`. Default `false`.
+    pub fn ignore_time_scale(self, ignore_time_scale: bool) -> Self {
+        Self {
+            ignore_time_scale,
+            ..self
+        }
+    }
+
+    /// Starts the timer and returns a future resolving on its timeout.
+    pub fn done(self) -> FallibleSignalFuture<()> {
+        let timer = scene_tree()
+            .create_timer_ex(self.duration.as_secs_f64())
+            .process_always(self.process_always)
+            .process_in_physics(self.process_in_physics)
+            .ignore_time_scale(self.ignore_time_scale)
+            .done()
+            .expect("SceneTree::create_timer() failed");
+
+        // The tree keeps the timer alive until it times out.
+        Signal::from_object_signal(&timer, "timeout").to_fallible_future()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error when awaiting frames with `SceneTree::await_frames()`, `Node::await_frames()` and related methods.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum AwaitFramesError {
+    /// The node is not inside a scene tree, or was removed from it (or freed) while waiting.
+    NotInTree,
+
+    /// The scene tree was freed while waiting, e.g. during engine shutdown.
+    TreeFreed,
+}
+
+impl fmt::Display for AwaitFramesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotInTree => write!(f, "node is not inside the scene tree"),
+            Self::TreeFreed => write!(f, "scene tree was freed before the awaited frame"),
+        }
+    }
+}
+
+impl std::error::Error for AwaitFramesError {}
+
+/// Signal of `SceneTree`, emitted once per frame.
+#[derive(Copy, Clone)]
+pub(crate) enum FrameSignal {
+    Process,
+    Physics,
+}
+
+/// Waits for `count` emissions of the frame signal of the tree with ID `tree_id`.
+///
+/// If `node` is given, fails as soon as that node leaves the tree (or is freed), by racing each frame against its `tree_exiting` signal.
+pub(crate) async fn await_tree_frames(
+    tree_id: InstanceId,
+    signal: FrameSignal,
+    count: u32,
+    node: Option<InstanceId>,
+) -> Result<(), AwaitFramesError> {
+    let signal_name = match signal {
+        FrameSignal::Process => "process_frame",
+        FrameSignal::Physics => "physics_frame",
+    };
+
+    for _ in 0..count {
+        let tree = Gd::<SceneTree>::try_from_instance_id(tree_id)
+            .map_err(|_| AwaitFramesError::TreeFreed)?;
+        let frame = Signal::from_object_signal(&tree, signal_name).to_fallible_future::<()>();
+
+        let Some(node_id) = node else {
+            frame.await.map_err(|_| AwaitFramesError::TreeFreed)?;
+            continue;
+        };
+
+        let node = Gd::<Node>::try_from_instance_id(node_id)
+            .ok()
+            .filter(|node| node.is_inside_tree())
+            .ok_or(AwaitFramesError::NotInTree)?;
+
+        // Emitted when the node is removed from the tree, including when it is freed while inside. If the node is freed otherwise, the
+        // future resolves to an error. Both end the wait. Listed first, so that it wins if the frame passes in the meantime, too.
+        let exiting = Signal::from_object_signal(&node, "tree_exiting").to_fallible_future::<()>();
+
+        match select_signal(exiting, frame).await {
+            Selected::First(_) => return Err(AwaitFramesError::NotInTree),
+            Selected::Second(frame) => frame.map_err(|_| AwaitFramesError::TreeFreed)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn tree_signal(signal: &str) -> FallibleSignalFuture<()> {
+    Signal::from_object_signal(&scene_tree(), signal).to_fallible_future()
+}
+
+fn scene_tree() -> Gd<SceneTree> {
+    Engine::singleton()
+        .get_main_loop()
+        .expect("awaiting frames requires a running main loop")
+        .try_cast::<SceneTree>()
+        .unwrap_or_else(|main_loop| {
+            panic!(
+                "awaiting frames requires the main loop to be a SceneTree, but it is {}",
+                main_loop.get_class()
+            )
+        })
+}
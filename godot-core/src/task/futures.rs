@@ -30,6 +30,7 @@ pub(crate) use crate::impl_dynamic_send;
 /// # Panics
 /// - If the signal object is freed before the signal has been emitted.
 /// - If one of the signal arguments is `!Send`, but the signal was emitted on a different thread.
+/// - If the signal arguments cannot be converted to `R`.
 pub struct SignalFuture<R: ParamTuple + IntoDynamicSend>(FallibleSignalFuture<R>);
 
 impl<R: ParamTuple + IntoDynamicSend> SignalFuture<R> {
@@ -112,7 +113,17 @@ impl<R: ParamTuple + IntoDynamicSend> RustCallable for SignalFutureResolver<R> {
     fn invoke(&mut self, args: &[&Variant]) -> Result<Variant, ()> {
         let waker = {
             let mut data = self.data.lock().unwrap();
-            data.state = SignalFutureState::Ready(R::from_variant_array(args).into_dynamic_send());
+
+            // The future was dropped while the signal was emitted concurrently (before it could disconnect); nobody awaits the value anymore.
+            if matches!(data.state, SignalFutureState::Dropped) {
+                return Ok(Variant::nil());
+            }
+
+            // Conversion errors are reported when the future is polled, where the signal name is known and the panic reaches the awaiting code.
+            data.state = match R::try_from_variant_array(args) {
+                Ok(args) => SignalFutureState::Ready(args.into_dynamic_send()),
+                Err(err) => SignalFutureState::ConversionFailed(err.to_string()),
+            };
 
             // We no longer need the waker after we resolved. If the future is polled again, we'll also get a new waker.
             data.waker.take()
@@ -159,6 +170,7 @@ enum SignalFutureState<T> {
     #[default]
     Pending,
     Ready(T),
+    ConversionFailed(String),
     Dead,
     Dropped,
 }
@@ -167,7 +179,7 @@ impl<T> SignalFutureState<T> {
     fn take(&mut self) -> Self {
         let new_value = match self {
             Self::Pending => Self::Pending,
-            Self::Ready(_) | Self::Dead => Self::Dead,
+            Self::Ready(_) | Self::ConversionFailed(_) | Self::Dead => Self::Dead,
             Self::Dropped => Self::Dropped,
         };
 
@@ -179,8 +191,12 @@ impl<T> SignalFutureState<T> {
 ///
 /// The future might resolve to an error if the signal object is freed before the signal is emitted.
 ///
+/// Dropping the future before the signal is emitted cancels the wait and disconnects it from the signal. To limit the wait, see
+/// `with_timeout()`; to wait for one of two signals, see [`select_signal()`][crate::task::select_signal].
+///
 /// # Panics
 /// - If one of the signal arguments is `!Send`, but the signal was emitted on a different thread.
+/// - If the signal arguments cannot be converted to `R`.
 pub struct FallibleSignalFuture<R: ParamTuple + IntoDynamicSend> {
     data: Arc<Mutex<SignalFutureData<R::Target>>>,
     callable: SignalFutureResolver<R>,
@@ -224,6 +240,13 @@ impl<R: ParamTuple + IntoDynamicSend> FallibleSignalFuture<R> {
             SignalFutureState::Pending => Poll::Pending,
             SignalFutureState::Dropped => unreachable!(),
             SignalFutureState::Dead => Poll::Ready(Err(FallibleSignalFutureError)),
+            SignalFutureState::ConversionFailed(err) => {
+                panic!(
+                    "the awaited signal `{}` was emitted with arguments not convertible to {}: {err}",
+                    self.signal.name(),
+                    std::any::type_name::<R>()
+                );
+            }
             SignalFutureState::Ready(value) => {
                 let Some(value) = DynamicSend::extract_if_safe(value) else {
                     panic!("the awaited signal was not emitted on the main-thread, but contained a non Send argument");
@@ -260,6 +283,7 @@ impl<R: ParamTuple + IntoDynamicSend> Future for FallibleSignalFuture<R> {
     }
 }
 
+// Dropping the future cancels the wait: the internal callable is disconnected, so later emissions of the signal don't reach it.
 impl<R: ParamTuple + IntoDynamicSend> Drop for FallibleSignalFuture<R> {
     fn drop(&mut self) {
         // The callable might alredy be destroyed, this occurs during engine shutdown.
@@ -283,6 +307,32 @@ impl<R: ParamTuple + IntoDynamicSend> Drop for FallibleSignalFuture<R> {
     }
 }
 
+/// Creates a future resolving to the arguments of the next emission of `signal`, converted to the tuple `R`.
+///
+/// Same as [`Signal::to_future()`], but allows to specify the argument types in front. Zero-argument signals resolve to `()`.
+///
+/// # Panics
+/// When polled after the signal is emitted with arguments that cannot be converted to `R` (wrong types or number of arguments); the
+/// panic message names the signal. See also [`SignalFuture`].
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::task;
+///
+/// fn wait_for_result(request: Gd<Object>) {
+///     let signal = Signal::from_object_signal(&request, "completed");
+///
+///     task::spawn(async move {
+///         let (code, message) = task::signal_future::<(i32, GString)>(&signal).await;
+///         godot_print!("completed with {code}: {message}");
+///     });
+/// }
+/// ```
+pub fn signal_future<R: ParamTuple + IntoDynamicSend>(signal: &Signal) -> SignalFuture<R> {
+    signal.to_future()
+}
+
 impl Signal {
     /// Creates a fallible future for this signal.
     ///
@@ -325,6 +375,16 @@ impl<C: WithSignals, R: ParamTuple + IntoDynamicSend> TypedSignal<'_, C, R> {
     }
 }
 
+impl<C: WithSignals, R: ParamTuple + IntoDynamicSend> IntoFuture for TypedSignal<'_, C, R> {
+    type Output = R;
+
+    type IntoFuture = SignalFuture<R>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.to_future()
+    }
+}
+
 impl<C: WithSignals, R: ParamTuple + IntoDynamicSend> IntoFuture for &TypedSignal<'_, C, R> {
     type Output = R;
 
@@ -0,0 +1,385 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::fmt;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::{mpsc, Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::builtin::{Callable, Variant};
+use crate::classes::{Engine, SceneTree};
+use crate::obj::Gd;
+use crate::tools::{register_frame_callback, FrameCallbackHandle, FrameKind};
+
+type Job = Box<dyn FnOnce(&MainThreadContext) + Send>;
+
+/// Sends closures from any thread to the main thread, where they can access Godot objects.
+///
+/// Background threads (e.g. for networking or asset processing) must not touch scene objects directly. Instead, they can pass work to
+/// [`run()`][Self::run], which queues the closure and returns immediately. Queued closures are run on the main thread at the start of the
+/// next process frame, in the order they were queued. The result of a closure is available through the returned [`MainThreadResult`], which
+/// can be awaited in a Godot task or waited on from the background thread.
+///
+/// The gate is `Send` + `Sync` and cheap to clone. Queuing is lock-free. Closures must be `Send`, so Godot objects are best referred to by
+/// [`InstanceId`][crate::obj::InstanceId] and looked up inside the closure.
+///
+/// # Shutdown
+/// When the extension is unloaded, closures still in the queue are dropped without running. Their results resolve to [`GateClosed`], so
+/// threads waiting for them don't block forever. Closures queued after that are dropped immediately.
+///
+/// Closures are only run if the main loop is a `SceneTree`. With a custom main loop, the gate is closed as soon as the main loop starts,
+/// in the same way as on shutdown.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::task::MainThreadGate;
+///
+/// fn load_in_background(label: Gd<Node>) {
+///     let gate = MainThreadGate::new();
+///     let label_id = label.instance_id();
+///
+///     std::thread::spawn(move || {
+///         let text = String::from("expensive result");
+///
+///         gate.run(move |_ctx| {
+///             let mut label = Gd::<Node>::from_instance_id(label_id);
+///             label.set_name(text.as_str());
+///         });
+///     });
+/// }
+/// ```
+#[derive(Clone)]
+pub struct MainThreadGate {
+    sender: mpsc::Sender<Job>,
+}
+
+impl MainThreadGate {
+    /// Creates a gate to the main thread. Can be called from any thread.
+    ///
+    /// # Panics
+    /// If called before the `Scene` init level of the extension is loaded, after it has been unloaded, or if the main loop is not a
+    /// `SceneTree`.
+    pub fn new() -> Self {
+        let sender = GATE_SENDER.lock().unwrap().clone().expect(
+            "MainThreadGate requires the Scene init level to be loaded and a SceneTree as main loop",
+        );
+
+        Self { sender }
+    }
+
+    /// Queues `f` to be run on the main thread during the next process frame.
+    ///
+    /// The returned [`MainThreadResult`] can be awaited, waited on from another thread, or dropped if the result is not needed. The closure
+    /// runs regardless.
+    ///
+    /// If the closure panics, the panic is printed and the result resolves to [`GateClosed`].
+    pub fn run<F, R>(&self, f: F) -> MainThreadResult<R>
+    where
+        F: FnOnce(&MainThreadContext) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let slot = Arc::new(ResultSlot::default());
+        let sender = ResultSender {
+            slot: Some(slot.clone()),
+        };
+
+        let job: Job = Box::new(move |ctx| sender.send(f(ctx)));
+
+        // On failure, the gate is closed; the job (and with it the result sender) is dropped here, which resolves the result.
+        let _ = self.sender.send(job);
+
+        MainThreadResult { slot }
+    }
+}
+
+impl Default for MainThreadGate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for MainThreadGate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MainThreadGate").finish_non_exhaustive()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Passed to closures queued with [`MainThreadGate::run()`]; proves that they run on the main thread.
+pub struct MainThreadContext {
+    tree: Gd<SceneTree>,
+    delta: f64,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl MainThreadContext {
+    /// The scene tree, which is the main loop.
+    pub fn scene_tree(&self) -> Gd<SceneTree> {
+        self.tree.clone()
+    }
+
+    /// Delta time of the current process frame, in seconds.
+    pub fn delta(&self) -> f64 {
+        self.delta
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Result of a closure queued with [`MainThreadGate::run()`].
+///
+/// Can be awaited in a Godot task, or blocked on with [`wait()`][Self::wait] from a background thread. Resolves to [`GateClosed`] if the
+/// closure did not run to completion.
+pub struct MainThreadResult<R> {
+    slot: Arc<ResultSlot<R>>,
+}
+
+impl<R> MainThreadResult<R> {
+    /// Blocks the current thread until the closure has run on the main thread, and returns its result.
+    ///
+    /// Returns [`GateClosed`] if the closure will not run, e.g. because the main loop is not a `SceneTree`.
+    ///
+    /// # Panics
+    /// If called on the main thread, which would never run the closure. With `experimental-wasm-nothreads`, every thread is the main
+    /// thread, so this always panics; await the result instead.
+    pub fn wait(self) -> Result<R, GateClosed> {
+        #[cfg(not(wasm_nothreads))]
+        let is_main_thread = crate::init::is_main_thread();
+        #[cfg(wasm_nothreads)]
+        let is_main_thread = true;
+
+        assert!(
+            !is_main_thread,
+            "MainThreadResult::wait() would deadlock on the main thread; await the result instead"
+        );
+
+        let mut state = self.slot.state.lock().unwrap();
+        loop {
+            match state.take() {
+                ResultState::Pending { waker } => {
+                    *state = ResultState::Pending { waker };
+                    state = self.slot.ready.wait(state).unwrap();
+                }
+                ResultState::Done(value) => return Ok(value),
+                ResultState::Closed | ResultState::Taken => return Err(GateClosed),
+            }
+        }
+    }
+
+    /// Whether the closure has finished (or will never run).
+    pub fn is_finished(&self) -> bool {
+        let state = self.slot.state.lock().unwrap();
+        !matches!(*state, ResultState::Pending { .. })
+    }
+}
+
+impl<R> Future for MainThreadResult<R> {
+    type Output = Result<R, GateClosed>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.slot.state.lock().unwrap();
+
+        match state.take() {
+            ResultState::Pending { .. } => {
+                *state = ResultState::Pending {
+                    waker: Some(cx.waker().clone()),
+                };
+                Poll::Pending
+            }
+            ResultState::Done(value) => Poll::Ready(Ok(value)),
+            ResultState::Closed => Poll::Ready(Err(GateClosed)),
+            ResultState::Taken => panic!("MainThreadResult polled after completion"),
+        }
+    }
+}
+
+/// Error when a closure queued with [`MainThreadGate::run()`] did not complete.
+///
+/// This happens if the extension was unloaded, the main loop is not a `SceneTree`, or the closure panicked.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct GateClosed;
+
+impl fmt::Display for GateClosed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "closure queued on the main thread did not complete (extension unloaded, no SceneTree, or panic)"
+        )
+    }
+}
+
+impl std::error::Error for GateClosed {}
+
+struct ResultSlot<R> {
+    state: Mutex<ResultState<R>>,
+    ready: Condvar,
+}
+
+impl<R> Default for ResultSlot<R> {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(ResultState::Pending { waker: None }),
+            ready: Condvar::new(),
+        }
+    }
+}
+
+enum ResultState<R> {
+    Pending { waker: Option<Waker> },
+    Done(R),
+    Closed,
+    Taken,
+}
+
+impl<R> ResultState<R> {
+    fn take(&mut self) -> Self {
+        std::mem::replace(self, ResultState::Taken)
+    }
+}
+
+/// Completes the slot; if dropped without sending (job dropped or panicked), closes it instead.
+struct ResultSender<R> {
+    slot: Option<Arc<ResultSlot<R>>>,
+}
+
+impl<R> ResultSender<R> {
+    fn send(mut self, value: R) {
+        if let Some(slot) = self.slot.take() {
+            complete(&slot, ResultState::Done(value));
+        }
+    }
+}
+
+impl<R> Drop for ResultSender<R> {
+    fn drop(&mut self) {
+        if let Some(slot) = self.slot.take() {
+            complete(&slot, ResultState::Closed);
+        }
+    }
+}
+
+fn complete<R>(slot: &ResultSlot<R>, result: ResultState<R>) {
+    let waker = {
+        let mut state = slot.state.lock().unwrap();
+        match std::mem::replace(&mut *state, result) {
+            ResultState::Pending { waker } => waker,
+            _ => None,
+        }
+    };
+
+    slot.ready.notify_all();
+
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Internal integration
+
+static GATE_SENDER: Mutex<Option<mpsc::Sender<Job>>> = Mutex::new(None);
+
+struct GateReceiver {
+    receiver: mpsc::Receiver<Job>,
+    frame_callback: Option<FrameCallbackHandle>,
+}
+
+thread_local! {
+    static GATE_RECEIVER: RefCell<Option<GateReceiver>> = const { RefCell::new(None) };
+}
+
+/// Opens the gate. Called on the main thread during `Scene` init level.
+pub(crate) fn install_main_thread_gate() {
+    let (sender, receiver) = mpsc::channel();
+
+    *GATE_SENDER.lock().unwrap() = Some(sender);
+    GATE_RECEIVER.set(Some(GateReceiver {
+        receiver,
+        frame_callback: None,
+    }));
+
+    // The scene tree doesn't exist yet during init; deferred calls are flushed once the main loop runs.
+    Callable::from_local_fn("MainThreadGate::register", |_args| {
+        register_drain_callback();
+        Ok(Variant::nil())
+    })
+    .call_deferred(&[]);
+}
+
+/// Closes the gate and drops all closures that have not run yet.
+pub(crate) fn uninstall_main_thread_gate() {
+    *GATE_SENDER.lock().unwrap() = None;
+
+    // Take out first: dropping the queued jobs resolves their results, which may wake tasks.
+    let gate = GATE_RECEIVER.take();
+    drop(gate);
+}
+
+/// For itest to simulate unloading the extension: closes the gate like during shutdown, then opens a new one.
+#[cfg(feature = "trace")]
+pub fn restart_main_thread_gate() {
+    uninstall_main_thread_gate();
+    install_main_thread_gate();
+}
+
+fn register_drain_callback() {
+    let has_scene_tree = Engine::singleton()
+        .get_main_loop()
+        .is_some_and(|main_loop| main_loop.try_cast::<SceneTree>().is_ok());
+
+    // Without a SceneTree, closures would never run. Close the gate, so that threads waiting for results don't block forever.
+    if !has_scene_tree {
+        uninstall_main_thread_gate();
+        return;
+    }
+
+    let handle = register_frame_callback(FrameKind::Process, drain_jobs);
+
+    GATE_RECEIVER.with_borrow_mut(|gate| {
+        if let Some(gate) = gate {
+            gate.frame_callback = Some(handle);
+        }
+    });
+}
+
+fn drain_jobs(delta: f64) {
+    // Only run jobs queued so far, so that jobs re-queuing themselves don't stall the frame. Collecting also releases the borrow.
+    let jobs: Vec<Job> = GATE_RECEIVER.with_borrow(|gate| {
+        gate.as_ref()
+            .map(|gate| gate.receiver.try_iter().collect())
+            .unwrap_or_default()
+    });
+
+    if jobs.is_empty() {
+        return;
+    }
+
+    let tree = Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+        .expect("frame callback runs with a SceneTree");
+
+    let ctx = MainThreadContext {
+        tree,
+        delta,
+        _not_send: PhantomData,
+    };
+
+    for job in jobs {
+        // A panicking job is reported and resolves its result to GateClosed; the remaining jobs still run.
+        let _ = crate::private::handle_panic(
+            || "MainThreadGate closure panicked".to_string(),
+            AssertUnwindSafe(|| job(&ctx)),
+        );
+    }
+}
@@ -10,20 +10,59 @@
 //! This module contains:
 //! - Implementations of [`Future`](std::future::Future) for [`Signal`](crate::builtin::Signal) and [`TypedSignal`](crate::registry::signal::TypedSignal).
 //! - A way to [`spawn`] new async tasks by using the engine as the async runtime.
+//! - Futures waiting for frames and timers, such as [`next_frame`] and [`until`].
+//! - Combinators for signal futures: timeouts via `with_timeout()` and racing two signals with [`select_signal`].
+//! - Streams of all emissions of a signal, see [`SignalStream`].
+//! - Running Rust closures on Godot's `WorkerThreadPool`, see `spawn_worker()`, and sending closures back to the main thread, see
+//!   [`MainThreadGate`]. Signals can be emitted from other threads through [`ThreadsafeSignal`].
+//! - Integration of third-party async runtimes such as tokio, see [`AsyncRuntime`].
 
 mod async_runtime;
+mod frame_futures;
 mod futures;
+mod main_thread_gate;
+mod runtime_integration;
+mod select;
+mod signal_stream;
+mod threadsafe_signal;
+#[cfg(feature = "codegen-full")]
+mod timeout;
+#[cfg(feature = "tokio")]
+mod tokio_runtime;
+#[cfg(feature = "codegen-full")]
+mod worker_pool;
 
 pub(crate) use async_runtime::cleanup;
+pub(crate) use frame_futures::{await_tree_frames, FrameSignal};
 pub(crate) use futures::{impl_dynamic_send, ThreadConfined};
+pub(crate) use main_thread_gate::{install_main_thread_gate, uninstall_main_thread_gate};
+pub(crate) use runtime_integration::install_runtime;
+pub(crate) use threadsafe_signal::emit_on_main_thread;
 
-pub use async_runtime::{spawn, TaskHandle};
+pub use async_runtime::{spawn, TaskError, TaskHandle, TaskJoin};
+pub use frame_futures::{next_frame, next_physics_frame, until, AwaitFramesError};
+#[cfg(feature = "codegen-full")]
+pub use frame_futures::{sleep, sleep_ex, ExSleep};
 pub use futures::{
-    DynamicSend, FallibleSignalFuture, FallibleSignalFutureError, IntoDynamicSend, SignalFuture,
+    signal_future, DynamicSend, FallibleSignalFuture, FallibleSignalFutureError, IntoDynamicSend,
+    SignalFuture,
 };
+pub use main_thread_gate::{GateClosed, MainThreadContext, MainThreadGate, MainThreadResult};
+pub use runtime_integration::{spawn_on_runtime, AsyncRuntime};
+pub use select::{select_signal, SelectSignal, Selected};
+pub use signal_stream::{ExSignalStream, SignalStream, StreamOverflow, DEFAULT_STREAM_CAPACITY};
+pub use threadsafe_signal::ThreadsafeSignal;
+#[cfg(feature = "codegen-full")]
+pub use timeout::{TimedOut, Timeout};
+#[cfg(feature = "tokio")]
+pub use tokio_runtime::TokioRuntime;
+#[cfg(feature = "codegen-full")]
+pub use worker_pool::{spawn_worker, spawn_worker_group, WorkerGroupHandle, WorkerHandle};
 
 // Only exported for itest.
 #[cfg(feature = "trace")]
 pub use async_runtime::has_godot_task_panicked;
 #[cfg(feature = "trace")]
 pub use futures::{create_test_signal_future_resolver, SignalFutureResolver};
+#[cfg(feature = "trace")]
+pub use main_thread_gate::restart_main_thread_gate;
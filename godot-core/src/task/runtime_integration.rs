@@ -0,0 +1,151 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use crate::builtin::{Callable, Variant};
+use crate::classes::{Engine, SceneTree};
+use crate::tools::{register_frame_callback, FrameCallbackHandle, FrameKind};
+
+/// Third-party async runtime, which drives futures that the engine cannot drive on its own.
+///
+/// Godot tasks ([`spawn()`][super::spawn]) are normally polled by the engine on the main thread. That's enough to await signals, but
+/// libraries built on a specific runtime -- e.g. `reqwest` or `sqlx` on tokio -- need that runtime's timers and IO reactor, and a runtime
+/// context to be entered whenever their futures are created or polled. Implementing this trait and returning the runtime from
+/// [`ExtensionLibrary::async_runtime()`][crate::init::ExtensionLibrary::async_runtime] integrates the two:
+///
+/// - Godot tasks are routed through [`spawn_local()`][Self::spawn_local], so the runtime polls them and handles their wake-ups. The tasks
+///   stay on the main thread, so Godot objects, signals and runtime-specific futures can be used in the same task.
+/// - [`update()`][Self::update] is called once per process frame on the main thread, to let the runtime make progress. Godot tasks are
+///   polled during this update, so they resume at most once per frame.
+/// - [`spawn()`][Self::spawn] backs [`spawn_on_runtime()`][super::spawn_on_runtime], for `Send` futures that don't touch Godot objects.
+///
+/// With the `tokio` Cargo feature, [`TokioRuntime`][super::TokioRuntime] implements this trait.
+pub trait AsyncRuntime: 'static {
+    /// Spawns a future that runs on the runtime, possibly on another thread.
+    fn spawn(&self, future: Pin<Box<dyn Future<Output = ()> + Send + 'static>>);
+
+    /// Spawns a future that must stay on the main thread, such as a Godot task.
+    ///
+    /// The future must only be polled during [`update()`][Self::update], with the runtime's context entered. It must not be polled
+    /// inside this call, which may itself happen while another task is being polled.
+    fn spawn_local(&self, future: Pin<Box<dyn Future<Output = ()> + 'static>>);
+
+    /// Lets the runtime make progress, i.e. fire expired timers, poll IO and run its ready tasks, including those from
+    /// [`spawn_local()`][Self::spawn_local]. Must not block.
+    ///
+    /// Called once per process frame on the main thread.
+    fn update(&self);
+}
+
+/// Spawns a `Send` future on the runtime installed by [`ExtensionLibrary::async_runtime()`][crate::init::ExtensionLibrary::async_runtime].
+///
+/// Unlike [`spawn()`][super::spawn], the future may be polled on any thread, as decided by the runtime. It can thus not hold Godot objects,
+/// but it can be used for runtime-specific work such as network requests, whose results are then passed back to a Godot task, e.g. through
+/// a channel.
+///
+/// # Panics
+/// If no async runtime is installed.
+pub fn spawn_on_runtime(future: impl Future<Output = ()> + Send + 'static) {
+    let runtime = installed_runtime().expect(
+        "spawn_on_runtime() requires ExtensionLibrary::async_runtime() to return a runtime",
+    );
+
+    runtime.spawn(Box::pin(future));
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Internal integration
+
+struct InstalledRuntime {
+    runtime: Rc<dyn AsyncRuntime>,
+
+    /// Calls `update()` every frame; registered deferred, as the scene tree doesn't exist yet when the runtime is installed.
+    frame_callback: Option<FrameCallbackHandle>,
+}
+
+thread_local! {
+    static INSTALLED_RUNTIME: RefCell<Option<InstalledRuntime>> = const { RefCell::new(None) };
+}
+
+/// Installs the runtime returned by `ExtensionLibrary::async_runtime()`. Called on the main thread during `Scene` init level.
+pub(crate) fn install_runtime(runtime: Option<Box<dyn AsyncRuntime>>) {
+    let installed = runtime.map(|runtime| InstalledRuntime {
+        runtime: Rc::from(runtime),
+        frame_callback: None,
+    });
+
+    let is_installed = installed.is_some();
+    INSTALLED_RUNTIME.set(installed);
+
+    if is_installed {
+        // The scene tree doesn't exist yet during init; deferred calls are flushed once the main loop runs.
+        Callable::from_local_fn("AsyncRuntime::register", |_args| {
+            register_frame_updates();
+            Ok(Variant::nil())
+        })
+        .call_deferred(&[]);
+    }
+}
+
+/// Drops the installed runtime, together with all futures it still owns.
+pub(crate) fn uninstall_runtime() {
+    // Take out first: dropping may run arbitrary code (e.g. destructors of futures), which must not observe a borrowed cell.
+    let installed = INSTALLED_RUNTIME.take();
+    drop(installed);
+}
+
+/// The runtime returned by `ExtensionLibrary::async_runtime()`, if any.
+pub(crate) fn installed_runtime() -> Option<Rc<dyn AsyncRuntime>> {
+    INSTALLED_RUNTIME.with_borrow(|installed| {
+        installed
+            .as_ref()
+            .map(|installed| installed.runtime.clone())
+    })
+}
+
+/// Registers the per-frame `update()` callback. Without it, neither Godot tasks nor futures from `spawn_on_runtime()` make progress.
+fn register_frame_updates() {
+    let needs_callback = INSTALLED_RUNTIME.with_borrow(|installed| {
+        installed
+            .as_ref()
+            .is_some_and(|installed| installed.frame_callback.is_none())
+    });
+
+    if !needs_callback {
+        return;
+    }
+
+    // Custom main loops have no frame signals to update the runtime from. Fall back to the engine-driven executor for new tasks.
+    let has_scene_tree = Engine::singleton()
+        .get_main_loop()
+        .is_some_and(|main_loop| main_loop.try_cast::<SceneTree>().is_ok());
+
+    if !has_scene_tree {
+        crate::godot_warn!(
+            "ExtensionLibrary::async_runtime() requires the main loop to be a SceneTree; the runtime is not installed"
+        );
+        uninstall_runtime();
+        return;
+    }
+
+    let handle = register_frame_callback(FrameKind::Process, |_delta| {
+        // Clone the Rc out of the cell, so that tasks polled during the update can access it again.
+        if let Some(runtime) = installed_runtime() {
+            runtime.update();
+        }
+    });
+
+    INSTALLED_RUNTIME.with_borrow_mut(|installed| {
+        if let Some(installed) = installed {
+            installed.frame_callback = Some(handle);
+        }
+    });
+}
@@ -0,0 +1,88 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Waits for whichever of two signals is emitted first.
+///
+/// Accepts signal futures as well as typed signals, which can be awaited directly. Resolves to [`Selected`], telling which one fired
+/// together with its arguments. The other future is dropped at that point, which disconnects it from its signal -- later emissions have
+/// no effect.
+///
+/// If both signals are emitted before the task is polled, `a` wins.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::task::{self, Selected};
+///
+/// fn wait_for_dialog(dialog: Gd<Object>) {
+///     let confirmed = Signal::from_object_signal(&dialog, "confirmed");
+///     let canceled = Signal::from_object_signal(&dialog, "canceled");
+///
+///     task::spawn(async move {
+///         match task::select_signal(confirmed.to_future::<()>(), canceled.to_future::<()>()).await {
+///             Selected::First(()) => godot_print!("confirmed"),
+///             Selected::Second(()) => godot_print!("canceled"),
+///         }
+///     });
+/// }
+/// ```
+pub fn select_signal<A, B>(a: A, b: B) -> SelectSignal<A::IntoFuture, B::IntoFuture>
+where
+    A: IntoFuture,
+    A::IntoFuture: Unpin,
+    B: IntoFuture,
+    B::IntoFuture: Unpin,
+{
+    SelectSignal {
+        futures: Some((a.into_future(), b.into_future())),
+    }
+}
+
+/// Future returned by [`select_signal()`].
+#[must_use = "futures do nothing unless awaited"]
+pub struct SelectSignal<A, B> {
+    futures: Option<(A, B)>,
+}
+
+impl<A: Future + Unpin, B: Future + Unpin> Future for SelectSignal<A, B> {
+    type Output = Selected<A::Output, B::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let (a, b) = this
+            .futures
+            .as_mut()
+            .expect("SelectSignal polled after completion");
+
+        let selected = if let Poll::Ready(value) = Pin::new(a).poll(cx) {
+            Selected::First(value)
+        } else if let Poll::Ready(value) = Pin::new(b).poll(cx) {
+            Selected::Second(value)
+        } else {
+            return Poll::Pending;
+        };
+
+        // Drop both futures right away, so the losing one disconnects from its signal.
+        this.futures = None;
+
+        Poll::Ready(selected)
+    }
+}
+
+/// Which future completed first in [`select_signal()`], with its output.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Selected<A, B> {
+    /// The first future completed.
+    First(A),
+
+    /// The second future completed.
+    Second(B),
+}
@@ -0,0 +1,396 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::builtin::{Callable, RustCallable, Signal, Variant};
+use crate::meta::ParamTuple;
+use crate::obj::WithSignals;
+use crate::registry::signal::TypedSignal;
+use crate::task::{DynamicSend, IntoDynamicSend};
+
+/// Number of emissions buffered by [`Signal::to_stream()`], before the [overflow policy](StreamOverflow) applies.
+pub const DEFAULT_STREAM_CAPACITY: usize = 64;
+
+/// What a [`SignalStream`] does when an emission arrives while its buffer is full.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum StreamOverflow {
+    /// Discard the oldest buffered emission to make room for the new one. Suited for events where only recent ones matter.
+    #[default]
+    DropOldest,
+
+    /// Panic the next time the stream is polled. Suited for events that must not be lost, where an overflow indicates a bug.
+    ///
+    /// The panic happens in the consuming task, not in the code emitting the signal. Emissions after the overflow are discarded.
+    Panic,
+}
+
+/// Stream of all emissions of a signal, created by [`Signal::to_stream()`] or [`TypedSignal::to_stream()`].
+///
+/// While a future like [`SignalFuture`][crate::task::SignalFuture] resolves on the next emission only, the stream stays connected and
+/// buffers every emission until it is consumed. Items are the signal arguments, converted to the tuple `R`. Read them with
+/// [`next()`][Self::next], or -- with the `futures-core` Cargo feature -- through the
+/// [`futures::Stream`](https://docs.rs/futures/latest/futures/stream/trait.Stream.html) trait and its combinators.
+///
+/// The buffer is bounded; if the signal is emitted more often than the stream is consumed, the [`StreamOverflow`] policy decides
+/// what happens. The stream ends (yields `None`) once the signal object is freed and all buffered emissions have been consumed.
+///
+/// Dropping the stream disconnects it from the signal.
+///
+/// # Panics
+/// When polled:
+/// - If the buffer overflowed, with the policy [`StreamOverflow::Panic`].
+/// - If the signal was emitted with arguments not convertible to `R`.
+/// - If one of the signal arguments is `!Send`, but the signal was emitted on a different thread.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::task;
+///
+/// fn log_added_children(parent: &Gd<Node>) {
+///     let signal = Signal::from_object_signal(parent, "child_entered_tree");
+///     let mut children = signal.to_stream::<(Gd<Node>,)>();
+///
+///     task::spawn(async move {
+///         while let Some((child,)) = children.next().await {
+///             godot_print!("added: {}", child.get_name());
+///         }
+///     });
+/// }
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct SignalStream<R: ParamTuple + IntoDynamicSend> {
+    data: Arc<Mutex<SignalStreamData<R::Target>>>,
+    callable: SignalStreamCollector<R>,
+    signal: Signal,
+    capacity: usize,
+}
+
+impl<R: ParamTuple + IntoDynamicSend> SignalStream<R> {
+    fn new(signal: Signal, capacity: usize, overflow: StreamOverflow) -> Self {
+        assert!(capacity > 0, "signal stream capacity must be at least 1");
+        debug_assert!(
+            !signal.is_null(),
+            "Failed to create a stream for an invalid Signal!\nEither the signal object was already freed or the signal was not registered in the object before using it.",
+        );
+
+        let data = Arc::new(Mutex::new(SignalStreamData {
+            buffer: VecDeque::new(),
+            capacity,
+            overflow,
+            state: SignalStreamState::Open,
+            waker: None,
+        }));
+
+        let callable = SignalStreamCollector::new(data.clone());
+        signal.connect(&Callable::from_custom(callable.clone()), 0);
+
+        Self {
+            data,
+            callable,
+            signal,
+            capacity,
+        }
+    }
+
+    /// Waits for the next emission, returning its arguments; or `None` if the signal object was freed and the buffer is empty.
+    ///
+    /// Buffered emissions are returned immediately, in the order they were emitted.
+    pub fn next(&mut self) -> impl Future<Output = Option<R>> + '_ {
+        std::future::poll_fn(move |cx| self.poll_next_item(cx))
+    }
+
+    /// Number of emissions currently buffered and not yet consumed.
+    pub fn buffered_len(&self) -> usize {
+        self.data.lock().unwrap().buffer.len()
+    }
+
+    fn poll_next_item(&mut self, cx: &mut Context<'_>) -> Poll<Option<R>> {
+        let mut data = self.data.lock().unwrap();
+
+        if matches!(data.state, SignalStreamState::Overflowed) {
+            // Drop the lock first, so the mutex is not poisoned by the panic.
+            drop(data);
+            panic!(
+                "the signal stream for `{}` overflowed: more than {} emissions were not consumed in time",
+                self.signal.name(),
+                self.capacity
+            );
+        }
+
+        let Some(item) = data.buffer.pop_front() else {
+            if matches!(data.state, SignalStreamState::Closed) {
+                return Poll::Ready(None);
+            }
+
+            data.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        };
+
+        drop(data);
+
+        match item {
+            Ok(value) => {
+                let Some(value) = DynamicSend::extract_if_safe(value) else {
+                    panic!("the streamed signal was not emitted on the main-thread, but contained a non Send argument");
+                };
+
+                Poll::Ready(Some(value))
+            }
+            Err(err) => panic!(
+                "the streamed signal `{}` was emitted with arguments not convertible to {}: {err}",
+                self.signal.name(),
+                std::any::type_name::<R>()
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "futures-core")]
+impl<R: ParamTuple + IntoDynamicSend> futures_core::Stream for SignalStream<R> {
+    type Item = R;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_next_item(cx)
+    }
+}
+
+// Dropping the stream disconnects it, so that later emissions are no longer buffered.
+impl<R: ParamTuple + IntoDynamicSend> Drop for SignalStream<R> {
+    fn drop(&mut self) {
+        {
+            let mut data = self.data.lock().unwrap();
+            data.state = SignalStreamState::Dropped;
+            data.buffer.clear();
+        }
+
+        // The signal object might already be freed, which also removed the connection.
+        if self.signal.object().is_none() {
+            return;
+        }
+
+        // A new Godot Callable from our RustCallable compares equal to the connected one, see SignalFutureResolver.
+        let gd_callable = Callable::from_custom(self.callable.clone());
+        if self.signal.is_connected(&gd_callable) {
+            self.signal.disconnect(&gd_callable);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Builder returned by [`Signal::to_stream_ex()`] and [`TypedSignal::to_stream_ex()`].
+#[must_use]
+pub struct ExSignalStream<R: ParamTuple + IntoDynamicSend> {
+    signal: Signal,
+    capacity: usize,
+    overflow: StreamOverflow,
+    _output: PhantomData<fn() -> R>,
+}
+
+impl<R: ParamTuple + IntoDynamicSend> ExSignalStream<R> {
+    fn new(signal: Signal) -> Self {
+        Self {
+            signal,
+            capacity: DEFAULT_STREAM_CAPACITY,
+            overflow: StreamOverflow::default(),
+            _output: PhantomData,
+        }
+    }
+
+    /// Maximum number of emissions buffered until the stream is polled. Default [`DEFAULT_STREAM_CAPACITY`].
+    ///
+    /// # Panics
+    /// In [`done()`][Self::done], if `capacity` is 0.
+    pub fn capacity(self, capacity: usize) -> Self {
+        Self { capacity, ..self }
+    }
+
+    /// What happens when an emission arrives while the buffer is full. Default [`StreamOverflow::DropOldest`].
+    pub fn overflow(self, overflow: StreamOverflow) -> Self {
+        Self { overflow, ..self }
+    }
+
+    /// Connects to the signal and returns the stream.
+    pub fn done(self) -> SignalStream<R> {
+        SignalStream::new(self.signal, self.capacity, self.overflow)
+    }
+}
+
+impl Signal {
+    /// Creates a stream of all emissions of this signal.
+    ///
+    /// Buffers up to [`DEFAULT_STREAM_CAPACITY`] emissions and drops the oldest one on overflow; use
+    /// [`to_stream_ex()`][Self::to_stream_ex] to configure this. See [`SignalStream`] for details.
+    ///
+    /// Since the `Signal` type does not contain information on the signal argument types, the item type has to be inferred or specified.
+    pub fn to_stream<R: ParamTuple + IntoDynamicSend>(&self) -> SignalStream<R> {
+        self.to_stream_ex().done()
+    }
+
+    /// Like [`to_stream()`][Self::to_stream], with options for the buffer capacity and overflow policy.
+    ///
+    /// ```no_run
+    /// use godot::prelude::*;
+    /// use godot::task::StreamOverflow;
+    ///
+    /// # fn make(signal: Signal) {
+    /// let stream = signal
+    ///     .to_stream_ex::<(i64,)>()
+    ///     .capacity(1000)
+    ///     .overflow(StreamOverflow::Panic)
+    ///     .done();
+    /// # }
+    /// ```
+    pub fn to_stream_ex<R: ParamTuple + IntoDynamicSend>(&self) -> ExSignalStream<R> {
+        ExSignalStream::new(self.clone())
+    }
+}
+
+impl<C: WithSignals, R: ParamTuple + IntoDynamicSend> TypedSignal<'_, C, R> {
+    /// Creates a stream of all emissions of this signal.
+    ///
+    /// See [`Signal::to_stream()`] and [`SignalStream`] for details.
+    pub fn to_stream(&self) -> SignalStream<R> {
+        self.to_stream_ex().done()
+    }
+
+    /// Like [`to_stream()`][Self::to_stream], with options for the buffer capacity and overflow policy.
+    pub fn to_stream_ex(&self) -> ExSignalStream<R> {
+        ExSignalStream::new(self.to_untyped())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Shared state between stream and connected callable
+
+struct SignalStreamData<T> {
+    /// Converted arguments, or the conversion error which is reported when the item is consumed.
+    buffer: VecDeque<Result<T, String>>,
+    capacity: usize,
+    overflow: StreamOverflow,
+    state: SignalStreamState,
+    waker: Option<Waker>,
+}
+
+enum SignalStreamState {
+    Open,
+
+    /// Buffer was full with [`StreamOverflow::Panic`].
+    Overflowed,
+
+    /// The callable was released by the engine, typically because the signal object was freed.
+    Closed,
+
+    /// The stream was dropped, so emissions are ignored until the callable is disconnected.
+    Dropped,
+}
+
+struct SignalStreamCollector<R: IntoDynamicSend> {
+    data: Arc<Mutex<SignalStreamData<R::Target>>>,
+}
+
+impl<R: IntoDynamicSend> SignalStreamCollector<R> {
+    fn new(data: Arc<Mutex<SignalStreamData<R::Target>>>) -> Self {
+        Self { data }
+    }
+}
+
+impl<R: IntoDynamicSend> Clone for SignalStreamCollector<R> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<R: IntoDynamicSend> std::hash::Hash for SignalStreamCollector<R> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(Arc::as_ptr(&self.data) as usize);
+    }
+}
+
+impl<R: IntoDynamicSend> PartialEq for SignalStreamCollector<R> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.data, &other.data)
+    }
+}
+
+impl<R: ParamTuple + IntoDynamicSend> RustCallable for SignalStreamCollector<R> {
+    fn invoke(&mut self, args: &[&Variant]) -> Result<Variant, ()> {
+        let waker = {
+            let mut data = self.data.lock().unwrap();
+
+            if !matches!(data.state, SignalStreamState::Open) {
+                return Ok(Variant::nil());
+            }
+
+            let is_full = data.buffer.len() >= data.capacity;
+            match data.overflow {
+                StreamOverflow::DropOldest if is_full => {
+                    data.buffer.pop_front();
+                }
+                StreamOverflow::Panic if is_full => {
+                    // The panic is raised in the consuming task, when it polls next.
+                    data.state = SignalStreamState::Overflowed;
+                    data.buffer.clear();
+                }
+                _ => {}
+            }
+
+            if matches!(data.state, SignalStreamState::Open) {
+                let item = R::try_from_variant_array(args)
+                    .map(|args| args.into_dynamic_send())
+                    .map_err(|err| err.to_string());
+
+                data.buffer.push_back(item);
+            }
+
+            data.waker.take()
+        };
+
+        wake(waker);
+        Ok(Variant::nil())
+    }
+}
+
+impl<R: IntoDynamicSend> Display for SignalStreamCollector<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SignalStreamCollector::<{}>", std::any::type_name::<R>())
+    }
+}
+
+// The engine releases the connected callable when the signal object is freed; the stream then ends after its buffer is drained.
+impl<R: IntoDynamicSend> Drop for SignalStreamCollector<R> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut data = self.data.lock().unwrap();
+            if !matches!(data.state, SignalStreamState::Open) {
+                return;
+            }
+
+            data.state = SignalStreamState::Closed;
+            data.waker.take()
+        };
+
+        wake(waker);
+    }
+}
+
+/// Called after releasing the lock, since the runtime may poll the stream right away.
+fn wake(waker: Option<Waker>) {
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
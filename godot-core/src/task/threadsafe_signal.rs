@@ -0,0 +1,177 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::builtin::{PackedByteArray, StringName, Variant, VariantType};
+use crate::classes::Object;
+use crate::global;
+use crate::meta::{ParamTuple, ToGodot};
+use crate::obj::{Gd, InstanceId};
+use crate::task::MainThreadGate;
+
+/// Handle to emit a typed signal from any thread.
+///
+/// Obtained on the main thread via [`TypedSignal::to_threadsafe()`][crate::registry::signal::TypedSignal::to_threadsafe], and then moved
+/// to background threads. [`emit_threadsafe()`][Self::emit_threadsafe] does not emit the signal immediately: the arguments are serialized,
+/// and the actual emission is queued through [`MainThreadGate`] and happens on the main thread during the next process frame.
+///
+/// Connected handlers thus always run on the main thread, where they can freely access Godot objects. Signals emitted through the same
+/// emitter from one thread arrive in the order they were emitted.
+///
+/// # Arguments
+/// Arguments are converted to variants on the emitting thread and serialized with [`var_to_bytes()`][global::var_to_bytes]. This is
+/// lossless for plain data (numbers, strings, vectors, packed arrays, and arrays/dictionaries of those), but **lossy for objects**:
+/// - Objects passed directly as arguments are sent by instance ID, and are not kept alive while the emission is queued. If such an
+///   object is freed before the signal is emitted, `null` is passed instead.
+/// - Objects nested inside arrays or dictionaries, as well as `Callable` and `Signal` values, are not supported. They are not serialized
+///   and do not arrive as the original value.
+///
+/// To pass objects safely, send their [`InstanceId`] and look them up in the handler.
+///
+/// If the emitting object is freed before the signal is emitted on the main thread, the emission is silently skipped.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Downloader {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Downloader {
+///     #[signal]
+///     fn progress(percent: i64);
+///
+///     fn start(&mut self) {
+///         let progress = self.signals().progress().to_threadsafe();
+///
+///         std::thread::spawn(move || {
+///             for percent in 0..=100 {
+///                 progress.emit_threadsafe((percent,));
+///             }
+///         });
+///     }
+/// }
+/// ```
+pub struct ThreadsafeSignal<Ps> {
+    object_id: InstanceId,
+    name: StringName,
+    gate: MainThreadGate,
+    _signature: PhantomData<fn(Ps)>,
+}
+
+impl<Ps: ParamTuple> ThreadsafeSignal<Ps> {
+    pub(crate) fn new(object_id: InstanceId, name: StringName) -> Self {
+        Self {
+            object_id,
+            name,
+            gate: MainThreadGate::new(),
+            _signature: PhantomData,
+        }
+    }
+
+    /// Queues emission of the signal on the main thread, with the given parameters. Can be called from any thread.
+    ///
+    /// Arguments are serialized, which is lossy for objects; see [Arguments](#arguments).
+    pub fn emit_threadsafe(&self, args: Ps) {
+        emit_on_main_thread(
+            &self.gate,
+            self.object_id,
+            self.name.clone(),
+            &args.to_variant_array(),
+        );
+    }
+
+    /// Instance ID of the object that emits the signal.
+    pub fn object_id(&self) -> InstanceId {
+        self.object_id
+    }
+
+    /// Name of the signal.
+    pub fn name(&self) -> &StringName {
+        &self.name
+    }
+}
+
+impl<Ps> Clone for ThreadsafeSignal<Ps> {
+    fn clone(&self) -> Self {
+        Self {
+            object_id: self.object_id,
+            name: self.name.clone(),
+            gate: self.gate.clone(),
+            _signature: PhantomData,
+        }
+    }
+}
+
+impl<Ps> fmt::Debug for ThreadsafeSignal<Ps> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreadsafeSignal")
+            .field("object_id", &self.object_id)
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Internal integration
+
+/// Serializes `args` on the current thread and queues emission of `signal` on the main thread.
+///
+/// Order is preserved, since the gate runs closures in the order they were queued.
+pub(crate) fn emit_on_main_thread(
+    gate: &MainThreadGate,
+    object_id: InstanceId,
+    signal: StringName,
+    args: &[Variant],
+) {
+    let args: Vec<SentArg> = args.iter().map(SentArg::serialize).collect();
+
+    gate.run(move |_ctx| {
+        // Object may have been freed in the meantime.
+        let Ok(mut object) = Gd::<Object>::try_from_instance_id(object_id) else {
+            return;
+        };
+
+        let args: Vec<Variant> = args.into_iter().map(SentArg::deserialize).collect();
+        object.emit_signal(&signal, &args);
+    });
+}
+
+/// Signal argument in a form that can be sent to another thread.
+enum SentArg {
+    /// Objects cannot be serialized; they are looked up again on the main thread.
+    Object(Option<InstanceId>),
+
+    /// Result of `var_to_bytes()`.
+    Bytes(Vec<u8>),
+}
+
+impl SentArg {
+    fn serialize(value: &Variant) -> Self {
+        if value.get_type() == VariantType::OBJECT {
+            let object = value.try_to::<Option<Gd<Object>>>().ok().flatten();
+            return Self::Object(object.map(|object| object.instance_id()));
+        }
+
+        Self::Bytes(global::var_to_bytes(value).to_vec())
+    }
+
+    fn deserialize(self) -> Variant {
+        match self {
+            Self::Object(id) => id
+                .and_then(|id| Gd::<Object>::try_from_instance_id(id).ok())
+                .to_variant(),
+            Self::Bytes(bytes) => global::bytes_to_var(&PackedByteArray::from(bytes.as_slice())),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use crate::meta::ParamTuple;
+use crate::task::{FallibleSignalFuture, IntoDynamicSend, SignalFuture};
+
+/// Future returned by `with_timeout()` on [`SignalFuture`] and [`FallibleSignalFuture`].
+///
+/// Resolves to `Ok` with the output of the wrapped future if it completes first, or to `Err(TimedOut)` once the timer expires. The timer is
+/// a `SceneTreeTimer`, so the timeout has frame granularity. It keeps running while the tree is paused.
+///
+/// Once the `Timeout` is dropped -- after it completes, or when the task is cancelled -- the wrapped future disconnects from its signal.
+#[must_use = "futures do nothing unless awaited"]
+pub struct Timeout<F> {
+    future: F,
+    timer: FallibleSignalFuture<()>,
+}
+
+impl<F: Future + Unpin> Future for Timeout<F> {
+    type Output = Result<F::Output, TimedOut>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        // Poll the future first: if the signal was emitted in the same frame as the timer expired, the value wins.
+        if let Poll::Ready(value) = Pin::new(&mut this.future).poll(cx) {
+            return Poll::Ready(Ok(value));
+        }
+
+        // If the timer is freed early (engine shutdown), the wait is over as well.
+        match Pin::new(&mut this.timer).poll(cx) {
+            Poll::Ready(_) => Poll::Ready(Err(TimedOut)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+fn with_timeout<F: Future + Unpin>(future: F, duration: Duration) -> Timeout<F> {
+    Timeout {
+        future,
+        timer: super::sleep(duration),
+    }
+}
+
+impl<R: ParamTuple + IntoDynamicSend> SignalFuture<R> {
+    /// Waits for the signal at most `duration`, resolving to [`TimedOut`] if it is not emitted in time.
+    ///
+    /// # Panics
+    /// - If the main loop is not a `SceneTree`.
+    /// - When polled, under the same conditions as [`SignalFuture`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use godot::prelude::*;
+    /// use godot::task;
+    ///
+    /// fn wait_for_connection(peer: Gd<Object>) {
+    ///     let signal = Signal::from_object_signal(&peer, "connected");
+    ///
+    ///     task::spawn(async move {
+    ///         match signal.to_future::<()>().with_timeout(Duration::from_secs(5)).await {
+    ///             Ok(()) => godot_print!("connected"),
+    ///             Err(task::TimedOut) => godot_warn!("connection timed out"),
+    ///         }
+    ///     });
+    /// }
+    /// ```
+    pub fn with_timeout(self, duration: Duration) -> Timeout<Self> {
+        with_timeout(self, duration)
+    }
+}
+
+impl<R: ParamTuple + IntoDynamicSend> FallibleSignalFuture<R> {
+    /// Waits for the signal at most `duration`, resolving to [`TimedOut`] if it is not emitted in time.
+    ///
+    /// The inner `Result` reports whether the signal object was freed, as for the future itself.
+    ///
+    /// # Panics
+    /// If the main loop is not a `SceneTree`.
+    pub fn with_timeout(self, duration: Duration) -> Timeout<Self> {
+        with_timeout(self, duration)
+    }
+}
+
+/// Error returned by [`Timeout`] when the awaited future did not complete in time.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct TimedOut;
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "timed out before the awaited signal was emitted")
+    }
+}
+
+impl std::error::Error for TimedOut {}
@@ -0,0 +1,89 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::future::Future;
+use std::pin::Pin;
+
+use tokio::runtime::{Builder, Handle, Runtime};
+use tokio::task::LocalSet;
+
+use crate::task::AsyncRuntime;
+
+/// [`AsyncRuntime`] backed by a current-thread [tokio](https://tokio.rs) runtime, which is driven from the main thread every frame.
+///
+/// Godot tasks ([`spawn()`][crate::task::spawn]) run on a [`LocalSet`] of this runtime, so tokio futures such as `tokio::time::sleep()` or
+/// the requests of tokio-based libraries can be awaited in them, alongside signals. Tasks spawned with `tokio::spawn()` or
+/// [`spawn_on_runtime()`][crate::task::spawn_on_runtime] also run on the main thread, during the per-frame update.
+///
+/// Since the runtime is only driven once per frame, timers, IO and signal wake-ups have frame granularity. Blocking tokio calls such as
+/// `Handle::block_on()` must not be used inside Godot tasks.
+///
+/// # Example
+/// ```no_run
+/// use godot::init::*;
+/// use godot::task::{AsyncRuntime, TokioRuntime};
+///
+/// struct MyExtension;
+///
+/// #[gdextension]
+/// unsafe impl ExtensionLibrary for MyExtension {
+///     fn async_runtime() -> Option<Box<dyn AsyncRuntime>> {
+///         Some(Box::new(TokioRuntime::new()))
+///     }
+/// }
+/// ```
+pub struct TokioRuntime {
+    // Declared first, so that Godot tasks are dropped while the runtime still exists.
+    local_tasks: LocalSet,
+    runtime: Runtime,
+}
+
+impl TokioRuntime {
+    /// Creates a current-thread runtime with all drivers (time, and IO if enabled in tokio) enabled.
+    ///
+    /// # Panics
+    /// If the runtime cannot be created.
+    pub fn new() -> Self {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to create tokio runtime");
+
+        Self {
+            local_tasks: LocalSet::new(),
+            runtime,
+        }
+    }
+
+    /// Handle to the runtime, e.g. to spawn tasks from other threads.
+    pub fn handle(&self) -> &Handle {
+        self.runtime.handle()
+    }
+}
+
+impl Default for TokioRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AsyncRuntime for TokioRuntime {
+    fn spawn(&self, future: Pin<Box<dyn Future<Output = ()> + Send + 'static>>) {
+        self.runtime.spawn(future);
+    }
+
+    fn spawn_local(&self, future: Pin<Box<dyn Future<Output = ()> + 'static>>) {
+        self.local_tasks.spawn_local(future);
+    }
+
+    fn update(&self) {
+        // Yielding once lets the scheduler run ready tasks (local and spawned ones) and poll the time/IO drivers without waiting, which
+        // wakes up pending timers.
+        self.runtime
+            .block_on(self.local_tasks.run_until(tokio::task::yield_now()));
+    }
+}
@@ -0,0 +1,238 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::builtin::{Callable, Variant};
+use crate::classes::WorkerThreadPool;
+use crate::global::Error as GodotError;
+
+type PanicPayload = Box<dyn Any + Send + 'static>;
+
+/// Runs `work` on a thread of Godot's `WorkerThreadPool`.
+///
+/// The closure is wrapped into a `Callable` and submitted via `WorkerThreadPool::add_task()`. Use the returned handle to wait for its
+/// result; a panic inside `work` is caught and returned from [`WorkerHandle::join()`].
+///
+/// Like with `std::thread::spawn()`, the closure runs on another thread. It must not access non-thread-safe Godot objects, such as nodes
+/// inside the scene tree.
+///
+/// # Example
+/// ```no_run
+/// use godot::task;
+///
+/// let handle = task::spawn_worker(|| (1..=1_000_000u64).sum::<u64>());
+///
+/// // ... other work on the main thread ...
+///
+/// let sum = handle.join().expect("worker panicked");
+/// assert_eq!(sum, 500_000_500_000);
+/// ```
+pub fn spawn_worker<F, R>(work: F) -> WorkerHandle<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let result = Arc::new(Mutex::new(None));
+
+    // Godot calls the callable exactly once, but requires a reusable function.
+    let work = Mutex::new(Some(work));
+    let result_slot = result.clone();
+    let callable = Callable::from_concurrent_fn("spawn_worker", move |_args| {
+        let work = lock(&work).take().ok_or(())?;
+        let outcome = panic::catch_unwind(AssertUnwindSafe(work));
+
+        *lock(&result_slot) = Some(outcome);
+        Ok(Variant::nil())
+    });
+
+    let task_id = WorkerThreadPool::singleton().add_task(&callable);
+
+    WorkerHandle {
+        task_id,
+        result,
+        joined: false,
+    }
+}
+
+/// Runs `work` for each index in `0..count`, distributed over the threads of Godot's `WorkerThreadPool`.
+///
+/// The closure is submitted once via `WorkerThreadPool::add_group_task()` and called concurrently for different indices. The results
+/// are collected in index order by [`WorkerGroupHandle::join()`]. If any call panics, the first panic is returned instead.
+///
+/// # Example
+/// ```no_run
+/// use godot::task;
+///
+/// let data: Vec<u64> = (0..1000).collect();
+/// let chunks: Vec<Vec<u64>> = data.chunks(100).map(<[u64]>::to_vec).collect();
+/// let count = chunks.len() as u32;
+///
+/// let partial_sums = task::spawn_worker_group(count, move |i| chunks[i as usize].iter().sum::<u64>())
+///     .join()
+///     .expect("worker panicked");
+///
+/// assert_eq!(partial_sums.iter().sum::<u64>(), 499_500);
+/// ```
+pub fn spawn_worker_group<F, R>(count: u32, work: F) -> WorkerGroupHandle<R>
+where
+    F: Fn(u32) -> R + Send + Sync + 'static,
+    R: Send + 'static,
+{
+    let state = Arc::new(GroupState {
+        results: Mutex::new((0..count).map(|_| None).collect()),
+        panic: Mutex::new(None),
+    });
+
+    let group_state = state.clone();
+    let callable = Callable::from_concurrent_fn("spawn_worker_group", move |args| {
+        let index = args.first().ok_or(())?.try_to::<u32>().map_err(|_| ())?;
+
+        match panic::catch_unwind(AssertUnwindSafe(|| work(index))) {
+            Ok(value) => lock(&group_state.results)[index as usize] = Some(value),
+            Err(payload) => {
+                // Keep the first panic; later ones are dropped.
+                let mut first_panic = lock(&group_state.panic);
+                if first_panic.is_none() {
+                    *first_panic = Some(payload);
+                }
+            }
+        }
+
+        Ok(Variant::nil())
+    });
+
+    let group_id = WorkerThreadPool::singleton().add_group_task(&callable, count as i32);
+
+    WorkerGroupHandle {
+        group_id,
+        state,
+        joined: false,
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Handle to a task submitted with [`spawn_worker()`].
+///
+/// Godot requires every task to be waited for, so that its resources are released. If the handle is dropped without calling
+/// [`join()`][Self::join], the drop blocks until the task has finished.
+pub struct WorkerHandle<R> {
+    task_id: i64,
+    result: Arc<Mutex<Option<thread::Result<R>>>>,
+    joined: bool,
+}
+
+impl<R> WorkerHandle<R> {
+    /// Whether the task has finished. Never blocks.
+    ///
+    /// Can be polled from the main thread (e.g. in `process()`) to call [`join()`][Self::join] only when it returns immediately.
+    pub fn is_finished(&self) -> bool {
+        WorkerThreadPool::singleton().is_task_completed(self.task_id)
+    }
+
+    /// Waits for the task to finish and returns its result, or the panic payload if it panicked.
+    ///
+    /// While waiting, the calling thread is blocked. Godot lets a waiting worker thread process other tasks in the meantime. On the main
+    /// thread, the task must not depend on the main thread making progress, e.g. by waiting for deferred calls.
+    ///
+    /// # Panics
+    /// If Godot refuses to wait because that could deadlock, e.g. when called from another worker task.
+    pub fn join(mut self) -> thread::Result<R> {
+        let error = self.wait();
+        assert_eq!(
+            error,
+            GodotError::OK,
+            "WorkerThreadPool::wait_for_task_completion() failed for task {}",
+            self.task_id
+        );
+
+        lock(&self.result)
+            .take()
+            .expect("worker task finished without result")
+    }
+
+    fn wait(&mut self) -> GodotError {
+        self.joined = true;
+        WorkerThreadPool::singleton().wait_for_task_completion(self.task_id)
+    }
+}
+
+impl<R> Drop for WorkerHandle<R> {
+    fn drop(&mut self) {
+        // Errors are ignored here, as panicking in drop could abort during unwinding.
+        if !self.joined {
+            self.wait();
+        }
+    }
+}
+
+/// Handle to a group task submitted with [`spawn_worker_group()`].
+///
+/// Like [`WorkerHandle`], dropping it without calling [`join()`][Self::join] blocks until all calls have finished.
+pub struct WorkerGroupHandle<R> {
+    group_id: i64,
+    state: Arc<GroupState<R>>,
+    joined: bool,
+}
+
+struct GroupState<R> {
+    results: Mutex<Vec<Option<R>>>,
+    panic: Mutex<Option<PanicPayload>>,
+}
+
+impl<R> WorkerGroupHandle<R> {
+    /// Whether all calls have finished. Never blocks.
+    pub fn is_finished(&self) -> bool {
+        WorkerThreadPool::singleton().is_group_task_completed(self.group_id)
+    }
+
+    /// Number of indices that have been processed so far.
+    pub fn processed_count(&self) -> u32 {
+        WorkerThreadPool::singleton().get_group_processed_element_count(self.group_id)
+    }
+
+    /// Waits for all calls to finish and returns their results in index order, or the first panic payload if any call panicked.
+    ///
+    /// See [`WorkerHandle::join()`] for restrictions while waiting.
+    pub fn join(mut self) -> thread::Result<Vec<R>> {
+        self.wait();
+
+        if let Some(payload) = lock(&self.state.panic).take() {
+            return Err(payload);
+        }
+
+        let results = std::mem::take(&mut *lock(&self.state.results));
+        Ok(results
+            .into_iter()
+            .map(|result| result.expect("worker group finished without result"))
+            .collect())
+    }
+
+    fn wait(&mut self) {
+        self.joined = true;
+        WorkerThreadPool::singleton().wait_for_group_task_completion(self.group_id);
+    }
+}
+
+impl<R> Drop for WorkerGroupHandle<R> {
+    fn drop(&mut self) {
+        if !self.joined {
+            self.wait();
+        }
+    }
+}
+
+/// Locks a mutex, ignoring poisoning (panics are caught before they could poison it).
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
@@ -0,0 +1,83 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::classes::{AudioStream, AudioStreamPlayback};
+use crate::obj::{bounds, Base, Bounds, Gd, GodotClass, Inherits};
+
+/// Playback class of a custom audio stream, which is created by the stream and keeps it.
+///
+/// Godot creates one playback object each time a stream starts playing, via `IAudioStream::instantiate_playback()`. The playback then
+/// generates the audio in `IAudioStreamPlayback::mix()`, usually from parameters of its stream. Implementing this trait on the playback
+/// class connects the two:
+/// - The stream's `instantiate_playback()` becomes a single call to [`instantiate()`][Self::instantiate].
+/// - The playback receives its stream on construction, as `Gd<Self::Stream>`. Parameters changed on the stream while playing are thus
+///   visible to `mix()`.
+///
+/// The stream only hands out playbacks and doesn't keep them, so there are no reference cycles between the two.
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::native::AudioFrame;
+/// use godot::classes::{AudioStream, AudioStreamPlayback, IAudioStream, IAudioStreamPlayback};
+/// use godot::prelude::*;
+/// use godot::tools::StreamPlayback;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=AudioStream)]
+/// struct Constant {
+///     #[init(val = 0.25)]
+///     level: f32,
+///     base: Base<AudioStream>,
+/// }
+///
+/// #[godot_api]
+/// impl IAudioStream for Constant {
+///     fn instantiate_playback(&self) -> Option<Gd<AudioStreamPlayback>> {
+///         ConstantPlayback::instantiate(self.to_gd())
+///     }
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(no_init, base=AudioStreamPlayback)]
+/// struct ConstantPlayback {
+///     stream: Gd<Constant>,
+///     base: Base<AudioStreamPlayback>,
+/// }
+///
+/// impl StreamPlayback for ConstantPlayback {
+///     type Stream = Constant;
+///
+///     fn new_playback(stream: Gd<Constant>, base: Base<AudioStreamPlayback>) -> Self {
+///         Self { stream, base }
+///     }
+/// }
+///
+/// #[godot_api]
+/// impl IAudioStreamPlayback for ConstantPlayback {
+///     fn mix(&mut self, buffer: &mut [AudioFrame], _rate_scale: f32) -> i32 {
+///         let level = self.stream.bind().level;
+///         buffer.fill(AudioFrame { left: level, right: level });
+///         buffer.len() as i32
+///     }
+/// }
+/// ```
+pub trait StreamPlayback:
+    GodotClass + Inherits<AudioStreamPlayback> + Bounds<Declarer = bounds::DeclUser>
+{
+    /// The stream class that creates this playback.
+    type Stream: GodotClass + Inherits<AudioStream>;
+
+    /// Creates the playback state for `stream`. Called once per [`instantiate()`][Self::instantiate].
+    fn new_playback(stream: Gd<Self::Stream>, base: Base<Self::Base>) -> Self;
+
+    /// Creates a new playback for `stream`, to be returned from `IAudioStream::instantiate_playback()`.
+    fn instantiate(stream: Gd<Self::Stream>) -> Option<Gd<AudioStreamPlayback>> {
+        let playback = Gd::<Self>::from_init_fn(|base| Self::new_playback(stream, base));
+
+        Some(playback.upcast())
+    }
+}
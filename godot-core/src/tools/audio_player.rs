@@ -0,0 +1,99 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::Signal;
+use crate::classes::{
+    AudioStream, AudioStreamPlayer, AudioStreamPlayer2D, AudioStreamPlayer3D, Node,
+};
+use crate::meta::sealed::Sealed;
+use crate::obj::{bounds, Bounds, Gd, Inherits};
+use crate::task::SignalFuture;
+
+/// Common playback API of the audio player nodes.
+///
+/// Implemented for [`AudioStreamPlayer`] (non-positional), [`AudioStreamPlayer2D`] and [`AudioStreamPlayer3D`], so that sequencing
+/// code can be written once for all of them. The trait is sealed.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::task;
+/// use godot::tools::AudioPlayer;
+///
+/// // Plays the intro from 2.5 seconds, then starts the loop once it has finished.
+/// fn play_music<P: AudioPlayer>(mut intro: Gd<P>, mut main_loop: Gd<P>) {
+///     task::spawn(async move {
+///         intro.play_to_end(2.5).await;
+///         main_loop.play_from(0.0);
+///     });
+/// }
+/// ```
+pub trait AudioPlayer: Inherits<Node> + Bounds<Declarer = bounds::DeclEngine> + Sealed {
+    /// Starts playback at `position_sec` seconds into the stream. Restarts if already playing.
+    fn play_from(&mut self, position_sec: f32);
+
+    /// Stops playback.
+    fn stop(&mut self);
+
+    /// Whether the stream is currently playing.
+    fn is_playing(&self) -> bool;
+
+    /// Position of the playback in seconds, or 0 if not playing.
+    fn playback_position(&self) -> f32;
+
+    /// The stream being played, if any.
+    fn stream(&self) -> Option<Gd<AudioStream>>;
+
+    /// Future that resolves when the stream reaches its end, via the `finished` signal.
+    ///
+    /// The signal is not emitted when playback is stopped with [`stop()`][Self::stop], or for looping streams. If the player is freed
+    /// before, the future panics when polled; see [`SignalFuture`].
+    fn finished(&self) -> SignalFuture<()>;
+
+    /// Starts playback at `position_sec`, returning a future that resolves once the stream has finished.
+    ///
+    /// Equivalent to [`play_from()`][Self::play_from] followed by [`finished()`][Self::finished].
+    fn play_to_end(&mut self, position_sec: f32) -> SignalFuture<()> {
+        // Create the future first, so that an immediately finishing stream isn't missed.
+        let finished = self.finished();
+        self.play_from(position_sec);
+        finished
+    }
+}
+
+macro_rules! impl_audio_player {
+    ($($Class:ident),* $(,)?) => {$(
+        impl AudioPlayer for $Class {
+            fn play_from(&mut self, position_sec: f32) {
+                self.play_ex().from_position(position_sec).done();
+            }
+
+            fn stop(&mut self) {
+                $Class::stop(self);
+            }
+
+            fn is_playing(&self) -> bool {
+                $Class::is_playing(self)
+            }
+
+            fn playback_position(&self) -> f32 {
+                // Engine method takes &mut self.
+                self.__to_gd().get_playback_position()
+            }
+
+            fn stream(&self) -> Option<Gd<AudioStream>> {
+                self.get_stream()
+            }
+
+            fn finished(&self) -> SignalFuture<()> {
+                Signal::from_object_signal(&self.__to_gd(), "finished").to_future()
+            }
+        }
+    )*};
+}
+
+impl_audio_player!(AudioStreamPlayer, AudioStreamPlayer2D, AudioStreamPlayer3D);
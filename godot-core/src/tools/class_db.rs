@@ -0,0 +1,208 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Array, Dictionary, GString, StringName, Variant, VariantType};
+use crate::classes::ClassDb;
+use crate::global::{MethodFlags, PropertyHint, PropertyUsageFlags};
+use crate::meta::{AsArg, FromGodot};
+use crate::obj::{EngineBitfield, EngineEnum, GodotClass};
+
+/// Whether the running engine has a class named `class`.
+///
+/// Allows to detect at runtime classes that only exist in some Godot versions or builds, e.g. `OpenXRInterface` is missing in builds
+/// compiled without OpenXR. Also works for classes registered by GDExtensions.
+///
+/// # Example
+/// ```no_run
+/// use godot::tools::engine_has_class;
+///
+/// if engine_has_class("OpenXRInterface") {
+///     // Set up XR.
+/// }
+/// ```
+pub fn engine_has_class(class: impl AsArg<StringName>) -> bool {
+    ClassDb::singleton().class_exists(class)
+}
+
+/// Whether class `T` has a method named `method`, including inherited ones.
+///
+/// Useful to call methods added in newer Godot versions only if available, e.g. via [`Object::call()`][crate::classes::Object::call].
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::Node;
+/// use godot::tools::class_has_method;
+///
+/// assert!(class_has_method::<Node>("propagate_call"));
+/// ```
+pub fn class_has_method<T: GodotClass>(method: impl AsArg<StringName>) -> bool {
+    ClassDb::singleton().class_has_method(&T::class_name().to_string_name(), method)
+}
+
+/// Whether class `T` has a signal named `signal`, including inherited ones.
+pub fn class_has_signal<T: GodotClass>(signal: impl AsArg<StringName>) -> bool {
+    ClassDb::singleton().class_has_signal(&T::class_name().to_string_name(), signal)
+}
+
+/// Default value of `property` in class `T`, i.e. its value in a newly constructed instance.
+///
+/// Returns `None` if the property does not exist, or if its default value is `null`. Also returns `None` for abstract classes such as
+/// `CanvasItem`, since Godot reads the default from an instance; query an instantiable subclass (e.g. `Node2D`) instead.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::Color;
+/// use godot::classes::Node2D;
+/// use godot::tools::class_property_default;
+///
+/// let modulate = class_property_default::<Node2D>("modulate");
+/// assert_eq!(modulate.map(|v| v.to::<Color>()), Some(Color::WHITE));
+/// ```
+#[cfg(since_api = "4.3")]
+pub fn class_property_default<T: GodotClass>(property: impl AsArg<StringName>) -> Option<Variant> {
+    let value = ClassDb::singleton()
+        .class_get_property_default_value(&T::class_name().to_string_name(), property);
+
+    if value.is_nil() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+/// Lists the methods of the class named `class`, including inherited ones.
+///
+/// Returns an empty list if the class does not exist. See [`ClassDb::class_method_infos()`] to exclude inherited methods.
+pub fn list_class_methods(class: impl AsArg<StringName>) -> Vec<ClassMethodInfo> {
+    ClassDb::singleton().class_method_infos(class, false)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Property of a class, as reported by `ClassDB`.
+///
+/// Returned by [`ClassDb::class_property_infos()`][crate::classes::ClassDb::class_property_infos]. Also describes arguments and return
+/// values of [`ClassMethodInfo`].
+///
+/// Unlike [`PropertyInfo`][crate::meta::PropertyInfo], which is used to register properties, this describes classes that may be unknown
+/// to Rust, so the class name is stored as a `StringName`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ClassPropertyInfo {
+    /// Name of the property (or argument).
+    pub name: StringName,
+
+    /// Type of the value; [`VariantType::NIL`] for untyped values, or a property which is only a group/category.
+    pub variant_type: VariantType,
+
+    /// For object-typed values, the name of the class. Empty otherwise.
+    pub class_name: StringName,
+
+    /// Editor hint for the value.
+    pub hint: PropertyHint,
+
+    /// Additional information for the hint, whose format depends on `hint`.
+    pub hint_string: GString,
+
+    /// How the property is used (storage, editor, group, ...).
+    pub usage: PropertyUsageFlags,
+}
+
+impl ClassPropertyInfo {
+    /// Parses a property dictionary as returned by `ClassDB.class_get_property_list()`.
+    ///
+    /// Missing or malformed entries fall back to default values.
+    pub fn from_dictionary(dict: &Dictionary) -> Self {
+        let variant_type =
+            VariantType::try_from_ord(get_int(dict, "type") as i32).unwrap_or(VariantType::NIL);
+        let hint =
+            PropertyHint::try_from_ord(get_int(dict, "hint") as i32).unwrap_or(PropertyHint::NONE);
+
+        Self {
+            name: get_name(dict, "name"),
+            variant_type,
+            class_name: get_name(dict, "class_name"),
+            hint,
+            hint_string: get_or_default(dict, "hint_string"),
+            usage: PropertyUsageFlags::from_ord(get_int(dict, "usage") as u64),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Method of a class, as reported by `ClassDB`.
+///
+/// Returned by [`ClassDb::class_method_infos()`][crate::classes::ClassDb::class_method_infos].
+#[derive(Clone, PartialEq, Debug)]
+pub struct ClassMethodInfo {
+    /// Name of the method.
+    pub name: StringName,
+
+    /// Parameters, in declaration order.
+    pub arguments: Vec<ClassPropertyInfo>,
+
+    /// Default values for the last `default_arguments.len()` parameters.
+    pub default_arguments: Vec<Variant>,
+
+    /// Return value; its `variant_type` is [`VariantType::NIL`] for methods returning nothing.
+    pub return_info: ClassPropertyInfo,
+
+    /// Whether the method is const, virtual, static, vararg, ...
+    pub flags: MethodFlags,
+
+    /// Internal ID of the method, as assigned by Godot.
+    pub id: i64,
+}
+
+impl ClassMethodInfo {
+    /// Parses a method dictionary as returned by `ClassDB.class_get_method_list()`.
+    ///
+    /// Missing or malformed entries fall back to default values.
+    pub fn from_dictionary(dict: &Dictionary) -> Self {
+        let arguments = get_or_default::<Array<Dictionary>>(dict, "args")
+            .iter_shared()
+            .map(|arg| ClassPropertyInfo::from_dictionary(&arg))
+            .collect();
+
+        let default_arguments = get_or_default::<Array<Variant>>(dict, "default_args")
+            .iter_shared()
+            .collect();
+
+        Self {
+            name: get_name(dict, "name"),
+            arguments,
+            default_arguments,
+            return_info: ClassPropertyInfo::from_dictionary(&get_or_default(dict, "return")),
+            flags: MethodFlags::from_ord(get_int(dict, "flags") as u64),
+            id: get_int(dict, "id"),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+fn get_or_default<T: FromGodot + Default>(dict: &Dictionary, key: &str) -> T {
+    dict.get(key)
+        .and_then(|value| value.try_to::<T>().ok())
+        .unwrap_or_default()
+}
+
+/// Reads a name, which ClassDB stores as `String` (e.g. `"name"`) or as `StringName` (e.g. `"class_name"`).
+fn get_name(dict: &Dictionary, key: &str) -> StringName {
+    let Some(value) = dict.get(key) else {
+        return StringName::default();
+    };
+
+    match value.try_to::<GString>() {
+        Ok(name) => StringName::from(&name),
+        Err(_) => value.try_to::<StringName>().unwrap_or_default(),
+    }
+}
+
+fn get_int(dict: &Dictionary, key: &str) -> i64 {
+    get_or_default(dict, key)
+}
@@ -0,0 +1,250 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{real, Color, PackedColorArray, PackedFloat32Array, Vector2};
+use crate::classes::gradient::InterpolationMode;
+use crate::classes::{Curve, Gradient};
+use crate::obj::{Gd, NewGd};
+
+/// Builds a [`Curve`] resource from control points.
+///
+/// The resulting curve is sampled with the engine methods `sample()` (exact) and `sample_baked()` (from a precomputed table, faster for
+/// many samples). Godot clamps the sample offset: positions before the first point return the value of the first point, positions after
+/// the last point the value of the last one.
+///
+/// Point positions use `x` as offset, and `y` as value. Godot clamps the value to the [value range](Self::value_range). Before Godot 4.4,
+/// the offset is limited to `0..=1`; since 4.4, it is clamped to the [domain](Self::domain), which defaults to `0..=1`. Points are added
+/// after the ranges have been set, so they are not clamped against the defaults.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::Vector2;
+/// use godot::tools::CurveBuilder;
+///
+/// // Ramps up from 0 to 10, then flattens out.
+/// let curve = CurveBuilder::new()
+///     .value_range(0.0, 10.0)
+///     .point(Vector2::new(0.0, 0.0))
+///     .point_with_tangents(Vector2::new(0.5, 8.0), 10.0, 2.0)
+///     .point(Vector2::new(1.0, 10.0))
+///     .build();
+///
+/// let height = curve.sample(0.25);
+/// ```
+#[must_use]
+#[derive(Clone, Debug)]
+pub struct CurveBuilder {
+    points: Vec<CurvePoint>,
+    min_value: real,
+    max_value: real,
+    #[cfg(since_api = "4.4")]
+    domain: Option<(real, real)>,
+    bake_resolution: Option<i32>,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct CurvePoint {
+    position: Vector2,
+    left_tangent: real,
+    right_tangent: real,
+}
+
+impl CurveBuilder {
+    /// Creates a builder without points, for the value range `0..=1` (Godot's default).
+    pub fn new() -> Self {
+        Self {
+            points: Vec::new(),
+            min_value: 0.0,
+            max_value: 1.0,
+            #[cfg(since_api = "4.4")]
+            domain: None,
+            bake_resolution: None,
+        }
+    }
+
+    /// Adds a control point at `position` (`x` = offset, `y` = value), with flat tangents.
+    pub fn point(self, position: Vector2) -> Self {
+        self.point_with_tangents(position, 0.0, 0.0)
+    }
+
+    /// Adds a control point at `position`, with the slopes `left_tangent` and `right_tangent` on either side.
+    pub fn point_with_tangents(
+        mut self,
+        position: Vector2,
+        left_tangent: real,
+        right_tangent: real,
+    ) -> Self {
+        self.points.push(CurvePoint {
+            position,
+            left_tangent,
+            right_tangent,
+        });
+        self
+    }
+
+    /// Sets the range of values, i.e. the `y` coordinates of points. Default `0..=1`.
+    ///
+    /// # Panics
+    /// If `min` is not less than `max`.
+    pub fn value_range(mut self, min: real, max: real) -> Self {
+        assert!(
+            min < max,
+            "CurveBuilder: value range {min}..={max} is empty"
+        );
+
+        self.min_value = min;
+        self.max_value = max;
+        self
+    }
+
+    /// Sets the range of offsets, i.e. the `x` coordinates of points. Default `0..=1`.
+    ///
+    /// # Panics
+    /// If `min` is not less than `max`.
+    #[cfg(since_api = "4.4")]
+    pub fn domain(mut self, min: real, max: real) -> Self {
+        assert!(min < max, "CurveBuilder: domain {min}..={max} is empty");
+
+        self.domain = Some((min, max));
+        self
+    }
+
+    /// Sets the number of entries in the table used by `sample_baked()`. Godot's default is 100.
+    pub fn bake_resolution(mut self, resolution: i32) -> Self {
+        self.bake_resolution = Some(resolution);
+        self
+    }
+
+    /// Creates the curve.
+    pub fn build(&self) -> Gd<Curve> {
+        let mut curve = Curve::new_gd();
+
+        // Godot keeps min below max when setting either bound, so set them in an order that doesn't cross the current other bound.
+        if self.min_value < curve.get_max_value() {
+            curve.set_min_value(self.min_value);
+            curve.set_max_value(self.max_value);
+        } else {
+            curve.set_max_value(self.max_value);
+            curve.set_min_value(self.min_value);
+        }
+
+        #[cfg(since_api = "4.4")]
+        if let Some((min, max)) = self.domain {
+            // Same ordering concern as for the value range.
+            if min < curve.get_max_domain() {
+                curve.set_min_domain(min);
+                curve.set_max_domain(max);
+            } else {
+                curve.set_max_domain(max);
+                curve.set_min_domain(min);
+            }
+        }
+
+        if let Some(resolution) = self.bake_resolution {
+            curve.set_bake_resolution(resolution);
+        }
+
+        for point in &self.points {
+            curve
+                .add_point_ex(point.position)
+                .left_tangent(point.left_tangent)
+                .right_tangent(point.right_tangent)
+                .done();
+        }
+
+        curve
+    }
+}
+
+impl Default for CurveBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Builds a [`Gradient`] resource from color stops.
+///
+/// Unlike `Gradient::new_gd()`, which starts with a black-to-white gradient, the result only contains the given stops. Stops can be
+/// added in any order; they are sorted by offset.
+///
+/// The resulting gradient is sampled with the engine method `sample()`. Godot clamps the offset: positions before the first stop return
+/// its color, positions after the last stop the color of the last one.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::Color;
+/// use godot::tools::GradientBuilder;
+///
+/// let heat = GradientBuilder::new()
+///     .stop(0.0, Color::BLUE)
+///     .stop(0.5, Color::YELLOW)
+///     .stop(1.0, Color::RED)
+///     .build();
+///
+/// let color = heat.sample(0.75);
+/// ```
+#[must_use]
+#[derive(Clone, Debug)]
+pub struct GradientBuilder {
+    stops: Vec<(f32, Color)>,
+    interpolation: InterpolationMode,
+}
+
+impl GradientBuilder {
+    /// Creates a builder without stops, with linear interpolation.
+    pub fn new() -> Self {
+        Self {
+            stops: Vec::new(),
+            interpolation: InterpolationMode::LINEAR,
+        }
+    }
+
+    /// Adds a color stop at `offset`, usually in `0..=1`.
+    pub fn stop(mut self, offset: f32, color: Color) -> Self {
+        self.stops.push((offset, color));
+        self
+    }
+
+    /// Sets how colors between stops are computed. Default [`InterpolationMode::LINEAR`].
+    pub fn interpolation(mut self, interpolation: InterpolationMode) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Creates the gradient.
+    ///
+    /// # Panics
+    /// If no stop was added.
+    pub fn build(&self) -> Gd<Gradient> {
+        assert!(
+            !self.stops.is_empty(),
+            "GradientBuilder: at least one color stop is required"
+        );
+
+        // Stable sort, so that stops with equal offsets keep their order.
+        let mut stops = self.stops.clone();
+        stops.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        let offsets: PackedFloat32Array = stops.iter().map(|&(offset, _)| offset).collect();
+        let colors: PackedColorArray = stops.iter().map(|&(_, color)| color).collect();
+
+        let mut gradient = Gradient::new_gd();
+        gradient.set_offsets(&offsets);
+        gradient.set_colors(&colors);
+        gradient.set_interpolation_mode(self.interpolation);
+
+        gradient
+    }
+}
+
+impl Default for GradientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,193 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::GString;
+use crate::classes::DirAccess;
+use crate::global::Error;
+use crate::meta::error::IoError;
+use crate::meta::{arg_into_ref, AsArg};
+use crate::obj::Gd;
+
+// Note: all functions accept `res://` and `user://` paths as well as absolute filesystem paths. In exported projects, `res://` is
+// read-only; use `user://` for data written at runtime.
+
+/// Lists the names of all files and subdirectories in the directory at `path`.
+///
+/// Hidden entries as well as `.` and `..` are skipped. Use [`read_dir()`] to include hidden entries or to tell files and directories apart.
+///
+/// # Example
+/// ```no_run
+/// use godot::tools::list_dir;
+///
+/// for name in list_dir("user://saves").unwrap_or_default() {
+///     godot::global::godot_print!("save file: {name}");
+/// }
+/// ```
+pub fn list_dir(path: impl AsArg<GString>) -> Result<Vec<GString>, IoError> {
+    arg_into_ref!(path);
+
+    let entries = read_dir(path, false)?;
+    Ok(entries.map(|entry| entry.name).collect())
+}
+
+/// Iterates over the entries of the directory at `path`.
+///
+/// If `include_hidden` is `false`, hidden entries (e.g. starting with `.` on Unix) are skipped. The navigational entries `.` and `..`
+/// are never returned.
+///
+/// Entries are read one by one from a `DirAccess` stream, which is closed when the iterator is dropped.
+pub fn read_dir(path: impl AsArg<GString>, include_hidden: bool) -> Result<DirEntries, IoError> {
+    arg_into_ref!(path);
+
+    let Some(mut dir) = DirAccess::open(path) else {
+        return Err(IoError::dir_access(
+            "open directory",
+            path.to_string(),
+            DirAccess::get_open_error(),
+        ));
+    };
+
+    dir.set_include_hidden(include_hidden);
+    dir.set_include_navigational(false);
+
+    let err = dir.list_dir_begin();
+    if err != Error::OK {
+        return Err(IoError::dir_access("list directory", path.to_string(), err));
+    }
+
+    Ok(DirEntries {
+        dir,
+        base_path: path.clone(),
+        finished: false,
+    })
+}
+
+/// Returns `true` if a directory exists at `path`.
+pub fn dir_exists(path: impl AsArg<GString>) -> bool {
+    DirAccess::dir_exists_absolute(path)
+}
+
+/// Creates the directory at `path`, including all missing parent directories.
+///
+/// Succeeds if the directory already exists.
+pub fn make_dir_recursive(path: impl AsArg<GString>) -> Result<(), IoError> {
+    arg_into_ref!(path);
+
+    let err = DirAccess::make_dir_recursive_absolute(path);
+    check(err, "create directory", path)
+}
+
+/// Removes the file or **empty** directory at `path`.
+pub fn remove_path(path: impl AsArg<GString>) -> Result<(), IoError> {
+    arg_into_ref!(path);
+
+    let err = DirAccess::remove_absolute(path);
+    check(err, "remove", path)
+}
+
+/// Copies the file at `from` to `to`, overwriting `to` if it exists.
+///
+/// The directory containing `to` must exist. Directories cannot be copied.
+pub fn copy_file(from: impl AsArg<GString>, to: impl AsArg<GString>) -> Result<(), IoError> {
+    arg_into_ref!(from);
+    arg_into_ref!(to);
+
+    let err = DirAccess::copy_absolute(from, to);
+    if err == Error::OK {
+        Ok(())
+    } else {
+        Err(IoError::copying(from.to_string(), to.to_string(), err))
+    }
+}
+
+fn check(err: Error, operation: &'static str, path: &GString) -> Result<(), IoError> {
+    if err == Error::OK {
+        Ok(())
+    } else {
+        Err(IoError::dir_access(operation, path.to_string(), err))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Iterator over the entries of a directory, returned by [`read_dir()`].
+pub struct DirEntries {
+    dir: Gd<DirAccess>,
+    base_path: GString,
+    finished: bool,
+}
+
+impl Iterator for DirEntries {
+    type Item = DirEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let name = self.dir.get_next();
+        if name.is_empty() {
+            self.finished = true;
+            self.dir.list_dir_end();
+            return None;
+        }
+
+        let is_dir = self.dir.current_is_dir();
+        let path = join_path(&self.base_path, &name);
+
+        Some(DirEntry { name, path, is_dir })
+    }
+}
+
+impl Drop for DirEntries {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.dir.list_dir_end();
+        }
+    }
+}
+
+/// Entry of a directory, as returned by [`read_dir()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct DirEntry {
+    name: GString,
+    path: GString,
+    is_dir: bool,
+}
+
+impl DirEntry {
+    /// File or directory name, without parent path.
+    pub fn name(&self) -> &GString {
+        &self.name
+    }
+
+    /// Full path, consisting of the path passed to `read_dir()` and the name.
+    pub fn path(&self) -> &GString {
+        &self.path
+    }
+
+    /// Whether the entry is a directory.
+    pub fn is_dir(&self) -> bool {
+        self.is_dir
+    }
+
+    /// Whether the entry is a file.
+    pub fn is_file(&self) -> bool {
+        !self.is_dir
+    }
+}
+
+fn join_path(base: &GString, name: &GString) -> GString {
+    let base = base.to_string();
+
+    // Keep `res://` and `user://` intact.
+    if base.ends_with('/') {
+        format!("{base}{name}").into()
+    } else {
+        format!("{base}/{name}").into()
+    }
+}
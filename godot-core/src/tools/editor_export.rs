@@ -0,0 +1,91 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{GString, PackedStringArray};
+
+/// Feature tags of an export, as passed to `IEditorExportPlugin::export_begin()` and `export_file()`.
+///
+/// Godot passes the tags of the export preset and platform (e.g. `"mobile"`, `"web"`, `"release"`, or custom ones) as a
+/// `PackedStringArray`. This wrapper provides set-like queries on them.
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::{EditorExportPlugin, IEditorExportPlugin};
+/// use godot::prelude::*;
+/// use godot::tools::ExportFeatures;
+///
+/// #[derive(GodotClass)]
+/// #[class(tool, init, base=EditorExportPlugin)]
+/// struct StripDebugData {
+///     base: Base<EditorExportPlugin>,
+/// }
+///
+/// #[godot_api]
+/// impl IEditorExportPlugin for StripDebugData {
+///     fn export_file(&mut self, path: GString, _type: GString, features: PackedStringArray) {
+///         let features = ExportFeatures::from(features);
+///         if !features.has("debug") && path.to_string().ends_with(".debug.json") {
+///             self.base_mut().skip();
+///         }
+///     }
+///
+///     // Other methods...
+/// #   fn customize_resource(&mut self, _resource: Gd<Resource>, _path: GString) -> Option<Gd<Resource>> { None }
+/// #   fn customize_scene(&mut self, _scene: Gd<Node>, _path: GString) -> Option<Gd<Node>> { None }
+/// #   fn get_customization_configuration_hash(&self) -> u64 { 0 }
+/// #   fn get_name(&self) -> GString { "StripDebugData".into() }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ExportFeatures {
+    features: PackedStringArray,
+}
+
+impl ExportFeatures {
+    /// Returns `true` if the export has the given feature tag.
+    ///
+    /// Compares without allocating Godot strings.
+    pub fn has(&self, feature: &str) -> bool {
+        self.features.as_slice().iter().any(|tag| tag == feature)
+    }
+
+    /// Returns `true` if the export has at least one of the given feature tags.
+    pub fn has_any(&self, features: &[&str]) -> bool {
+        features.iter().any(|feature| self.has(feature))
+    }
+
+    /// Iterates over all feature tags.
+    pub fn iter(&self) -> impl Iterator<Item = &GString> {
+        self.features.as_slice().iter()
+    }
+
+    /// Number of feature tags.
+    pub fn len(&self) -> usize {
+        self.features.len()
+    }
+
+    /// Returns `true` if there are no feature tags.
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+
+    /// Returns the feature tags as a `Vec`.
+    pub fn to_vec(&self) -> Vec<GString> {
+        self.features.to_vec()
+    }
+
+    /// Returns the underlying `PackedStringArray`.
+    pub fn into_inner(self) -> PackedStringArray {
+        self.features
+    }
+}
+
+impl From<PackedStringArray> for ExportFeatures {
+    fn from(features: PackedStringArray) -> Self {
+        Self { features }
+    }
+}
@@ -0,0 +1,116 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Array, Dictionary, GString, Variant};
+use crate::global::{PropertyHint, PropertyUsageFlags};
+use crate::meta::{arg_into_owned, AsArg, ToGodot};
+use crate::obj::{EngineBitfield, EngineEnum};
+
+/// Option of a custom asset importer, as shown in Godot's _Import_ dock.
+///
+/// Godot's `EditorImportPlugin::_get_import_options()` returns the options as an array of dictionaries with well-known keys. This type
+/// describes one such option; convert a list of them with [`ImportOption::to_array()`].
+///
+/// The values chosen by the user are passed to `IEditorImportPlugin::import()` as a `Dictionary`, keyed by [`name`][Self::name].
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::{EditorImportPlugin, IEditorImportPlugin};
+/// use godot::global::PropertyHint;
+/// use godot::prelude::*;
+/// use godot::tools::ImportOption;
+///
+/// #[derive(GodotClass)]
+/// #[class(tool, init, base=EditorImportPlugin)]
+/// struct AsepriteImporter {
+///     base: Base<EditorImportPlugin>,
+/// }
+///
+/// #[godot_api]
+/// impl IEditorImportPlugin for AsepriteImporter {
+///     fn get_import_options(&self, _path: GString, _preset_index: i32) -> Array<Dictionary> {
+///         ImportOption::to_array([
+///             ImportOption::new("split_layers", false),
+///             ImportOption::new("frame_duration", 0.1)
+///                 .with_hint(PropertyHint::RANGE, "0.01,1.0,0.01"),
+///         ])
+///     }
+///
+///     // Other methods...
+/// #   fn get_importer_name(&self) -> GString { todo!() }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ImportOption {
+    /// Key under which the value is passed to `import()`.
+    pub name: GString,
+
+    /// Initial value; also determines the type of the option in the _Import_ dock.
+    pub default_value: Variant,
+
+    /// Editor hint, e.g. [`PropertyHint::RANGE`] or [`PropertyHint::ENUM`].
+    pub property_hint: PropertyHint,
+
+    /// Hint string, whose format depends on [`property_hint`][Self::property_hint].
+    pub hint_string: GString,
+
+    /// Usage flags; [`PropertyUsageFlags::DEFAULT`] unless specified otherwise.
+    pub usage: PropertyUsageFlags,
+}
+
+impl ImportOption {
+    /// Creates an option without editor hint.
+    pub fn new(name: impl AsArg<GString>, default_value: impl ToGodot) -> Self {
+        arg_into_owned!(name);
+
+        Self {
+            name,
+            default_value: default_value.to_variant(),
+            property_hint: PropertyHint::NONE,
+            hint_string: GString::new(),
+            usage: PropertyUsageFlags::DEFAULT,
+        }
+    }
+
+    /// Sets the editor hint and hint string.
+    pub fn with_hint(
+        mut self,
+        property_hint: PropertyHint,
+        hint_string: impl AsArg<GString>,
+    ) -> Self {
+        arg_into_owned!(hint_string);
+
+        self.property_hint = property_hint;
+        self.hint_string = hint_string;
+        self
+    }
+
+    /// Sets the usage flags.
+    pub fn with_usage(mut self, usage: PropertyUsageFlags) -> Self {
+        self.usage = usage;
+        self
+    }
+
+    /// Converts this option to the dictionary format expected by Godot.
+    pub fn to_dictionary(&self) -> Dictionary {
+        let mut dict = Dictionary::new();
+        dict.set("name", self.name.clone());
+        dict.set("default_value", self.default_value.clone());
+        dict.set("property_hint", self.property_hint.ord());
+        dict.set("hint_string", self.hint_string.clone());
+        dict.set("usage", self.usage.ord());
+        dict
+    }
+
+    /// Converts a list of options to the return value of `IEditorImportPlugin::get_import_options()`.
+    pub fn to_array(options: impl IntoIterator<Item = ImportOption>) -> Array<Dictionary> {
+        options
+            .into_iter()
+            .map(|option| option.to_dictionary())
+            .collect()
+    }
+}
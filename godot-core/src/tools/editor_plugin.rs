@@ -0,0 +1,341 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::builtin::{Callable, GString, Variant};
+use crate::classes::editor_plugin::DockSlot;
+use crate::classes::object::ConnectFlags;
+use crate::classes::{
+    Button, Control, EditorExportPlugin, EditorImportPlugin, EditorInspectorPlugin, EditorPlugin,
+    Engine, Script, Texture2D,
+};
+use crate::meta::{arg_into_owned, AsArg};
+use crate::obj::{EngineEnum, Gd, Inherits};
+use crate::tools::try_load;
+
+/// Editor UI and registrations added by an [`EditorPlugin`], which are removed again when the plugin leaves the scene tree.
+///
+/// Godot expects every `add_*` call of an editor plugin to be undone in `_exit_tree()`; forgetting one leaves dangling docks or inspector
+/// plugins behind when the plugin is disabled or the extension is hot-reloaded. This type keeps track of the additions and removes them
+/// automatically as soon as the plugin emits `tree_exiting`. You can also remove everything earlier via [`clear()`][Self::clear].
+///
+/// Controls added as docks or bottom panels are owned by the editor while added. On removal, they are freed with `queue_free()`.
+///
+/// Outside the editor, e.g. when a plugin class is instantiated in a running game, there is no editor UI to add to. In that case, additions are
+/// only tracked, so that controls are still freed on removal.
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::editor_plugin::DockSlot;
+/// use godot::classes::{EditorPlugin, IEditorPlugin, Label};
+/// use godot::prelude::*;
+/// use godot::tools::EditorAdditions;
+///
+/// #[derive(GodotClass)]
+/// #[class(tool, init, base=EditorPlugin)]
+/// struct MyPlugin {
+///     additions: EditorAdditions,
+///     base: Base<EditorPlugin>,
+/// }
+///
+/// #[godot_api]
+/// impl IEditorPlugin for MyPlugin {
+///     fn enter_tree(&mut self) {
+///         let plugin = self.to_gd();
+///         let dock = Label::new_alloc();
+///         self.additions.add_dock(&plugin, DockSlot::LEFT_UL, &dock);
+///     }
+///
+///     // No exit_tree() needed: the dock is removed and freed automatically.
+/// }
+/// ```
+#[derive(Default)]
+pub struct EditorAdditions {
+    state: Rc<RefCell<AdditionsState>>,
+}
+
+impl EditorAdditions {
+    /// Creates an empty set of additions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `control` as a dock in the given `slot`, see [`EditorPlugin::add_control_to_dock()`].
+    pub fn add_dock<P, C>(&mut self, plugin: &Gd<P>, slot: DockSlot, control: &Gd<C>)
+    where
+        P: Inherits<EditorPlugin>,
+        C: Inherits<Control>,
+    {
+        let mut plugin = self.hook_plugin(plugin);
+        let control = control.clone().upcast::<Control>();
+
+        if is_editor() {
+            plugin.add_control_to_dock(slot, &control);
+        }
+
+        self.push(Addition::Dock(control));
+    }
+
+    /// Adds `control` to the bottom panel with the given `title`, see [`EditorPlugin::add_control_to_bottom_panel()`].
+    ///
+    /// Returns the button that toggles the panel, or `None` outside the editor.
+    pub fn add_bottom_panel<P, C>(
+        &mut self,
+        plugin: &Gd<P>,
+        control: &Gd<C>,
+        title: impl AsArg<GString>,
+    ) -> Option<Gd<Button>>
+    where
+        P: Inherits<EditorPlugin>,
+        C: Inherits<Control>,
+    {
+        let mut plugin = self.hook_plugin(plugin);
+        let control = control.clone().upcast::<Control>();
+
+        let button = if is_editor() {
+            plugin.add_control_to_bottom_panel(&control, title)
+        } else {
+            None
+        };
+
+        self.push(Addition::BottomPanel(control));
+        button
+    }
+
+    /// Registers an inspector plugin, see [`EditorPlugin::add_inspector_plugin()`].
+    pub fn add_inspector_plugin<P, I>(&mut self, plugin: &Gd<P>, inspector_plugin: &Gd<I>)
+    where
+        P: Inherits<EditorPlugin>,
+        I: Inherits<EditorInspectorPlugin>,
+    {
+        let mut plugin = self.hook_plugin(plugin);
+        let inspector_plugin = inspector_plugin.clone().upcast::<EditorInspectorPlugin>();
+
+        if is_editor() {
+            plugin.add_inspector_plugin(&inspector_plugin);
+        }
+
+        self.push(Addition::InspectorPlugin(inspector_plugin));
+    }
+
+    /// Registers an importer for custom asset formats, see [`EditorPlugin::add_import_plugin()`].
+    ///
+    /// Editor plugins enter the tree once the editor is initialized, so calling this in `IEditorPlugin::enter_tree()` registers the importer
+    /// at the right time. Use [`ImportOption`][crate::tools::ImportOption] to declare its options.
+    pub fn add_import_plugin<P, I>(&mut self, plugin: &Gd<P>, importer: &Gd<I>)
+    where
+        P: Inherits<EditorPlugin>,
+        I: Inherits<EditorImportPlugin>,
+    {
+        let mut plugin = self.hook_plugin(plugin);
+        let importer = importer.clone().upcast::<EditorImportPlugin>();
+
+        if is_editor() {
+            plugin.add_import_plugin(&importer);
+        }
+
+        self.push(Addition::ImportPlugin(importer));
+    }
+
+    /// Registers a plugin that hooks into the export process, see [`EditorPlugin::add_export_plugin()`].
+    ///
+    /// Use [`ExportFeatures`][crate::tools::ExportFeatures] to query the feature tags passed to its callbacks.
+    pub fn add_export_plugin<P, E>(&mut self, plugin: &Gd<P>, exporter: &Gd<E>)
+    where
+        P: Inherits<EditorPlugin>,
+        E: Inherits<EditorExportPlugin>,
+    {
+        let mut plugin = self.hook_plugin(plugin);
+        let exporter = exporter.clone().upcast::<EditorExportPlugin>();
+
+        if is_editor() {
+            plugin.add_export_plugin(&exporter);
+        }
+
+        self.push(Addition::ExportPlugin(exporter));
+    }
+
+    /// Registers a custom type backed by `script`, see [`EditorPlugin::add_custom_type()`].
+    ///
+    /// The icon is loaded from `icon_path`. If the path is empty, the type has no icon; if loading fails, a warning is printed and the type
+    /// is registered without icon.
+    pub fn add_custom_type<P>(
+        &mut self,
+        plugin: &Gd<P>,
+        type_name: impl AsArg<GString>,
+        base_name: impl AsArg<GString>,
+        script: &Gd<Script>,
+        icon_path: impl AsArg<GString>,
+    ) where
+        P: Inherits<EditorPlugin>,
+    {
+        arg_into_owned!(type_name);
+        arg_into_owned!(icon_path);
+
+        let mut plugin = self.hook_plugin(plugin);
+
+        if is_editor() {
+            let icon = if icon_path.is_empty() {
+                None
+            } else {
+                try_load::<Texture2D>(&icon_path)
+                    .inspect_err(|err| {
+                        crate::godot_warn!("icon for custom type `{type_name}` not loaded: {err}")
+                    })
+                    .ok()
+            };
+
+            plugin.add_custom_type(&type_name, base_name, script, icon.as_ref());
+        }
+
+        self.push(Addition::CustomType(type_name));
+    }
+
+    /// Removes all additions from the editor, in reverse order of adding them.
+    ///
+    /// Called automatically when the plugin leaves the scene tree.
+    pub fn clear(&mut self) {
+        AdditionsState::clear(&self.state);
+    }
+
+    /// Number of additions currently in effect.
+    pub fn len(&self) -> usize {
+        self.state.borrow().additions.len()
+    }
+
+    /// Whether there are no additions in effect.
+    pub fn is_empty(&self) -> bool {
+        self.state.borrow().additions.is_empty()
+    }
+
+    /// Remembers the plugin and makes sure that additions are removed once it leaves the tree.
+    fn hook_plugin<P>(&mut self, plugin: &Gd<P>) -> Gd<EditorPlugin>
+    where
+        P: Inherits<EditorPlugin>,
+    {
+        let mut plugin = plugin.clone().upcast::<EditorPlugin>();
+        let mut state = self.state.borrow_mut();
+
+        let existing = state.plugin.get_or_insert_with(|| plugin.clone());
+        assert_eq!(
+            existing.instance_id(),
+            plugin.instance_id(),
+            "EditorAdditions must only be used with a single plugin"
+        );
+
+        if !state.exit_hooked {
+            let weak_state = Rc::downgrade(&self.state);
+            let on_exit = Callable::from_local_fn("EditorAdditions::clear", move |_args| {
+                if let Some(state) = weak_state.upgrade() {
+                    state.borrow_mut().exit_hooked = false;
+                    AdditionsState::clear(&state);
+                }
+                Ok(Variant::nil())
+            });
+
+            plugin
+                .connect_ex("tree_exiting", &on_exit)
+                .flags(ConnectFlags::ONE_SHOT.ord() as u32)
+                .done();
+
+            state.exit_hooked = true;
+        }
+
+        plugin
+    }
+
+    fn push(&mut self, addition: Addition) {
+        self.state.borrow_mut().additions.push(addition);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+#[derive(Default)]
+struct AdditionsState {
+    plugin: Option<Gd<EditorPlugin>>,
+    additions: Vec<Addition>,
+    exit_hooked: bool,
+}
+
+impl AdditionsState {
+    fn clear(this: &RefCell<Self>) {
+        // Release the borrow before calling into the engine, which may emit signals.
+        let (plugin, additions) = {
+            let mut state = this.borrow_mut();
+            (state.plugin.clone(), std::mem::take(&mut state.additions))
+        };
+
+        let Some(mut plugin) = plugin.filter(|p| p.is_instance_valid()) else {
+            return;
+        };
+
+        let is_editor = is_editor();
+        for addition in additions.into_iter().rev() {
+            addition.remove(&mut plugin, is_editor);
+        }
+    }
+}
+
+enum Addition {
+    Dock(Gd<Control>),
+    BottomPanel(Gd<Control>),
+    InspectorPlugin(Gd<EditorInspectorPlugin>),
+    ImportPlugin(Gd<EditorImportPlugin>),
+    ExportPlugin(Gd<EditorExportPlugin>),
+    CustomType(GString),
+}
+
+impl Addition {
+    fn remove(self, plugin: &mut Gd<EditorPlugin>, is_editor: bool) {
+        match self {
+            Addition::Dock(mut control) => {
+                if control.is_instance_valid() {
+                    if is_editor {
+                        plugin.remove_control_from_docks(&control);
+                    }
+                    control.queue_free();
+                }
+            }
+            Addition::BottomPanel(mut control) => {
+                if control.is_instance_valid() {
+                    if is_editor {
+                        plugin.remove_control_from_bottom_panel(&control);
+                    }
+                    control.queue_free();
+                }
+            }
+            Addition::InspectorPlugin(inspector_plugin) => {
+                if is_editor {
+                    plugin.remove_inspector_plugin(&inspector_plugin);
+                }
+            }
+            Addition::ImportPlugin(importer) => {
+                if is_editor {
+                    plugin.remove_import_plugin(&importer);
+                }
+            }
+            Addition::ExportPlugin(exporter) => {
+                if is_editor {
+                    plugin.remove_export_plugin(&exporter);
+                }
+            }
+            Addition::CustomType(type_name) => {
+                if is_editor {
+                    plugin.remove_custom_type(&type_name);
+                }
+            }
+        }
+    }
+}
+
+fn is_editor() -> bool {
+    Engine::singleton().is_editor_hint()
+}
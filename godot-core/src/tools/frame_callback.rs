@@ -0,0 +1,121 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Callable, Variant};
+use crate::classes::{Engine, SceneTree};
+use crate::obj::{Gd, InstanceId};
+
+/// Kind of frame that a callback registered with [`register_frame_callback()`] runs on.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum FrameKind {
+    /// Every process (idle) frame, with the delta of `Node::get_process_delta_time()`.
+    Process,
+
+    /// Every physics frame, with the delta of `Node::get_physics_process_delta_time()`.
+    Physics,
+}
+
+impl FrameKind {
+    fn signal_name(self) -> &'static str {
+        match self {
+            FrameKind::Process => "process_frame",
+            FrameKind::Physics => "physics_frame",
+        }
+    }
+}
+
+/// Runs `callback` on every frame of the given kind, without needing a node.
+///
+/// The callback receives the frame's delta time in seconds. It keeps running until the returned handle is dropped, so store the handle
+/// for as long as the callback should stay active.
+///
+/// # Ordering
+/// The callback is connected to the `SceneTree` signal `process_frame` or `physics_frame`. Godot emits these signals at the start of each
+/// frame, **before** `_process()` or `_physics_process()` is called on any node. The order among several frame callbacks is unspecified.
+///
+/// # Panics
+/// If the main loop is not a `SceneTree`, e.g. when a custom `MainLoop` is used, or before the main loop has been created.
+///
+/// # Example
+/// ```no_run
+/// use godot::tools::{register_frame_callback, FrameKind, FrameCallbackHandle};
+///
+/// fn start_clock() -> FrameCallbackHandle {
+///     let mut elapsed = 0.0;
+///     register_frame_callback(FrameKind::Process, move |delta| {
+///         elapsed += delta;
+///     })
+/// }
+/// ```
+pub fn register_frame_callback(
+    kind: FrameKind,
+    mut callback: impl FnMut(f64) + 'static,
+) -> FrameCallbackHandle {
+    let mut tree = scene_tree();
+
+    let delta_tree = tree.clone();
+    let callable = Callable::from_local_fn("frame_callback", move |_args| {
+        if let Some(root) = delta_tree.get_root() {
+            let delta = match kind {
+                FrameKind::Process => root.get_process_delta_time(),
+                FrameKind::Physics => root.get_physics_process_delta_time(),
+            };
+            callback(delta);
+        }
+        Ok(Variant::nil())
+    });
+
+    tree.connect(kind.signal_name(), &callable);
+
+    FrameCallbackHandle {
+        tree_id: tree.instance_id(),
+        kind,
+        callable,
+    }
+}
+
+/// Keeps a callback registered with [`register_frame_callback()`] active; unregisters it when dropped.
+#[must_use = "the frame callback is unregistered as soon as the handle is dropped"]
+pub struct FrameCallbackHandle {
+    tree_id: InstanceId,
+    kind: FrameKind,
+    callable: Callable,
+}
+
+impl FrameCallbackHandle {
+    /// Kind of frame that the callback runs on.
+    pub fn kind(&self) -> FrameKind {
+        self.kind
+    }
+}
+
+impl Drop for FrameCallbackHandle {
+    fn drop(&mut self) {
+        // The scene tree is already gone during engine shutdown.
+        let Ok(mut tree) = Gd::<SceneTree>::try_from_instance_id(self.tree_id) else {
+            return;
+        };
+
+        let signal = self.kind.signal_name();
+        if tree.is_connected(signal, &self.callable) {
+            tree.disconnect(signal, &self.callable);
+        }
+    }
+}
+
+fn scene_tree() -> Gd<SceneTree> {
+    Engine::singleton()
+        .get_main_loop()
+        .expect("register_frame_callback() requires a running main loop")
+        .try_cast::<SceneTree>()
+        .unwrap_or_else(|main_loop| {
+            panic!(
+                "register_frame_callback() requires the main loop to be a SceneTree, but it is {}",
+                main_loop.get_class()
+            )
+        })
+}
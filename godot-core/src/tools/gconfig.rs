@@ -0,0 +1,267 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Dictionary, GString, PackedByteArray};
+use crate::classes::ConfigFile;
+use crate::global::Error as GodotError;
+use crate::meta::error::{ConvertError, IoError};
+use crate::meta::{arg_into_ref, AsArg, FromGodot, GodotConvert, ToGodot};
+use crate::obj::{Gd, NewGd};
+
+/// Typed access to INI-style configuration files.
+///
+/// Wraps Godot's [`ConfigFile`], converting values from and to Rust types instead of exposing `Variant`s, and returning `Result`s from
+/// loading and saving.
+///
+/// Files are organized in sections, each holding key-value pairs:
+/// ```ini
+/// [audio]
+/// master_volume=0.8
+/// muted=false
+/// ```
+///
+/// # Example
+/// ```no_run
+/// use godot::tools::GConfig;
+///
+/// let mut config = GConfig::load("user://settings.cfg").unwrap_or_default();
+///
+/// let volume = config.get::<f32>("audio", "master_volume")
+///     .expect("master_volume is a number")
+///     .unwrap_or(1.0);
+///
+/// config.set("audio", "master_volume", volume * 0.5);
+/// config.save("user://settings.cfg").expect("settings saved");
+/// ```
+#[derive(Debug)]
+pub struct GConfig {
+    config: Gd<ConfigFile>,
+}
+
+impl GConfig {
+    /// Creates an empty configuration.
+    pub fn new() -> Self {
+        Self {
+            config: ConfigFile::new_gd(),
+        }
+    }
+
+    /// Loads the configuration file at `path`.
+    pub fn load(path: impl AsArg<GString>) -> Result<Self, IoError> {
+        arg_into_ref!(path);
+
+        let mut config = ConfigFile::new_gd();
+        let err = config.load(path);
+        Self::from_result(config, "load", path, err)
+    }
+
+    /// Loads a configuration file at `path` that was encrypted with `key`, see [`save_encrypted()`][Self::save_encrypted].
+    pub fn load_encrypted(path: impl AsArg<GString>, key: &[u8]) -> Result<Self, IoError> {
+        arg_into_ref!(path);
+
+        let mut config = ConfigFile::new_gd();
+        let err = config.load_encrypted(path, &PackedByteArray::from(key));
+        Self::from_result(config, "load encrypted", path, err)
+    }
+
+    /// Loads a configuration file at `path` that was encrypted with `password`, see
+    /// [`save_encrypted_pass()`][Self::save_encrypted_pass].
+    pub fn load_encrypted_pass(
+        path: impl AsArg<GString>,
+        password: impl AsArg<GString>,
+    ) -> Result<Self, IoError> {
+        arg_into_ref!(path);
+
+        let mut config = ConfigFile::new_gd();
+        let err = config.load_encrypted_pass(path, password);
+        Self::from_result(config, "load encrypted", path, err)
+    }
+
+    /// Saves the configuration to `path`, overwriting any existing file.
+    pub fn save(&self, path: impl AsArg<GString>) -> Result<(), IoError> {
+        arg_into_ref!(path);
+
+        let err = self.config.clone().save(path);
+        Self::check("save", path, err)
+    }
+
+    /// Saves the configuration to `path`, encrypted with `key` (32 bytes for AES-256).
+    pub fn save_encrypted(&self, path: impl AsArg<GString>, key: &[u8]) -> Result<(), IoError> {
+        arg_into_ref!(path);
+
+        let err = self
+            .config
+            .clone()
+            .save_encrypted(path, &PackedByteArray::from(key));
+        Self::check("save encrypted", path, err)
+    }
+
+    /// Saves the configuration to `path`, encrypted with `password`.
+    pub fn save_encrypted_pass(
+        &self,
+        path: impl AsArg<GString>,
+        password: impl AsArg<GString>,
+    ) -> Result<(), IoError> {
+        arg_into_ref!(path);
+
+        let err = self.config.clone().save_encrypted_pass(path, password);
+        Self::check("save encrypted", path, err)
+    }
+
+    /// Returns the value of `key` in `section`, converted to `T`.
+    ///
+    /// Returns `Ok(None)` if the section or key does not exist, and `Err` if the value has a different type.
+    pub fn get<T: FromGodot>(
+        &self,
+        section: impl AsArg<GString>,
+        key: impl AsArg<GString>,
+    ) -> Result<Option<T>, ConvertError> {
+        arg_into_ref!(section);
+        arg_into_ref!(key);
+
+        // get_value() prints an error for missing keys without default.
+        if !self.config.has_section_key(section, key) {
+            return Ok(None);
+        }
+
+        self.config.get_value(section, key).try_to::<T>().map(Some)
+    }
+
+    /// Sets `key` in `section` to `value`, creating the section if needed.
+    ///
+    /// As in Godot, setting a value that converts to `null` (e.g. `None`) removes the key, and the section if it becomes empty.
+    pub fn set(
+        &mut self,
+        section: impl AsArg<GString>,
+        key: impl AsArg<GString>,
+        value: impl ToGodot,
+    ) {
+        self.config.set_value(section, key, &value.to_variant());
+    }
+
+    /// Whether `section` exists.
+    pub fn has_section(&self, section: impl AsArg<GString>) -> bool {
+        self.config.has_section(section)
+    }
+
+    /// Whether `key` exists in `section`.
+    pub fn has_key(&self, section: impl AsArg<GString>, key: impl AsArg<GString>) -> bool {
+        self.config.has_section_key(section, key)
+    }
+
+    /// Removes `section` with all its keys. Does nothing if the section does not exist.
+    pub fn erase_section(&mut self, section: impl AsArg<GString>) {
+        arg_into_ref!(section);
+
+        // erase_section() prints an error for missing sections.
+        if self.config.has_section(section) {
+            self.config.erase_section(section);
+        }
+    }
+
+    /// Names of all sections, in file order.
+    pub fn sections(&self) -> impl Iterator<Item = GString> {
+        self.config.get_sections().to_vec().into_iter()
+    }
+
+    /// Names of all keys in `section`, in file order. Empty if the section does not exist.
+    pub fn keys(&self, section: impl AsArg<GString>) -> std::vec::IntoIter<GString> {
+        arg_into_ref!(section);
+
+        // get_section_keys() prints an error for missing sections.
+        let keys = if self.config.has_section(section) {
+            self.config.get_section_keys(section).to_vec()
+        } else {
+            Vec::new()
+        };
+
+        keys.into_iter()
+    }
+
+    /// Reads all keys of `section` into a struct `S`, which converts from a `Dictionary`.
+    ///
+    /// The dictionary maps each key (as `GString`) to its value. Returns `Ok(None)` if the section does not exist.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::prelude::*;
+    /// use godot::meta::error::ConvertError;
+    /// use godot::tools::GConfig;
+    ///
+    /// struct AudioSettings {
+    ///     master_volume: f32,
+    ///     muted: bool,
+    /// }
+    ///
+    /// impl GodotConvert for AudioSettings {
+    ///     type Via = Dictionary;
+    /// }
+    ///
+    /// impl FromGodot for AudioSettings {
+    ///     fn try_from_godot(dict: Dictionary) -> Result<Self, ConvertError> {
+    ///         let get = |key: &str| dict.get(key).unwrap_or_default();
+    ///         Ok(Self {
+    ///             master_volume: get("master_volume").try_to()?,
+    ///             muted: get("muted").try_to()?,
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// fn audio_settings(config: &GConfig) -> Option<AudioSettings> {
+    ///     config.read_struct::<AudioSettings>("audio").ok().flatten()
+    /// }
+    /// ```
+    pub fn read_struct<S>(&self, section: impl AsArg<GString>) -> Result<Option<S>, ConvertError>
+    where
+        S: FromGodot + GodotConvert<Via = Dictionary>,
+    {
+        arg_into_ref!(section);
+
+        if !self.config.has_section(section) {
+            return Ok(None);
+        }
+
+        let dict: Dictionary = self
+            .keys(section)
+            .map(|key| {
+                let value = self.config.get_value(section, &key);
+                (key, value)
+            })
+            .collect();
+
+        S::try_from_godot(dict).map(Some)
+    }
+
+    /// Returns the underlying `ConfigFile`, e.g. to pass it to Godot APIs.
+    pub fn config_file(&self) -> Gd<ConfigFile> {
+        self.config.clone()
+    }
+
+    fn from_result(
+        config: Gd<ConfigFile>,
+        operation: &'static str,
+        path: &GString,
+        err: GodotError,
+    ) -> Result<Self, IoError> {
+        Self::check(operation, path, err)?;
+        Ok(Self { config })
+    }
+
+    fn check(operation: &'static str, path: &GString, err: GodotError) -> Result<(), IoError> {
+        if err == GodotError::OK {
+            Ok(())
+        } else {
+            Err(IoError::config_file(operation, path.to_string(), err))
+        }
+    }
+}
+
+impl Default for GConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
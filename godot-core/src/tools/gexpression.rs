@@ -0,0 +1,147 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::{GString, PackedStringArray, Variant, VariantArray};
+use crate::classes::{Expression, Object};
+use crate::global::Error as GodotError;
+use crate::meta::error::{ExpressionEvalError, ExpressionParseError};
+use crate::meta::{arg_into_owned, AsArg, FromGodot};
+use crate::obj::{Gd, NewGd};
+
+/// Parsed expression, ready to be executed multiple times.
+///
+/// Wraps Godot's [`Expression`] class, which evaluates GDScript-like expressions at runtime, e.g. for consoles or modding. Instead of
+/// checking error codes and `has_execute_failed()`, parsing and execution return `Result`s carrying Godot's error text.
+///
+/// Parsing is the expensive step, so keep a `GExpression` around and call [`execute()`][Self::execute] with different inputs, rather
+/// than parsing the same source again.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::GExpression;
+///
+/// let expr = GExpression::parse("base_damage * (1.0 + bonus)", &["base_damage", "bonus"])
+///     .expect("valid expression");
+///
+/// let damage: f64 = expr
+///     .execute_as(&[10.to_variant(), 0.5.to_variant()], None)
+///     .expect("evaluates to float");
+/// assert_eq!(damage, 15.0);
+///
+/// // Reuse with other inputs.
+/// let damage: f64 = expr.execute_as(&[4.to_variant(), 0.25.to_variant()], None).unwrap();
+/// assert_eq!(damage, 5.0);
+/// ```
+pub struct GExpression {
+    expression: Gd<Expression>,
+    source: GString,
+    input_names: PackedStringArray,
+}
+
+impl GExpression {
+    /// Parses `source`, which can refer to the variables `input_names`.
+    ///
+    /// Values for the inputs are passed to [`execute()`][Self::execute], in the same order.
+    pub fn parse(
+        source: impl AsArg<GString>,
+        input_names: &[&str],
+    ) -> Result<Self, ExpressionParseError> {
+        arg_into_owned!(source);
+
+        let input_names: PackedStringArray = input_names
+            .iter()
+            .map(|name| GString::from(*name))
+            .collect();
+
+        let mut expression = Expression::new_gd();
+        let err = expression
+            .parse_ex(&source)
+            .input_names(&input_names)
+            .done();
+
+        if err != GodotError::OK {
+            let error_text = expression.get_error_text();
+            return Err(ExpressionParseError::new(source, error_text));
+        }
+
+        Ok(Self {
+            expression,
+            source,
+            input_names,
+        })
+    }
+
+    /// Executes the expression with the given input values, returning the result as `Variant`.
+    ///
+    /// `inputs` correspond to the input names passed to [`parse()`][Self::parse]. If `base` is provided, the expression can call its
+    /// methods and access its properties, like `self` in GDScript.
+    pub fn execute(
+        &self,
+        inputs: &[Variant],
+        base: Option<&Gd<Object>>,
+    ) -> Result<Variant, ExpressionEvalError> {
+        let expected = self.input_names.len();
+        if inputs.len() != expected {
+            return Err(ExpressionEvalError::WrongInputCount {
+                expected,
+                actual: inputs.len(),
+            });
+        }
+
+        let inputs = VariantArray::from(inputs);
+
+        // Errors are reported via the Result, so Godot doesn't need to print them.
+        let mut expression = self.expression.clone();
+        let result = expression
+            .execute_ex()
+            .inputs(&inputs)
+            .base_instance(base)
+            .show_error(false)
+            .done();
+
+        if expression.has_execute_failed() {
+            return Err(ExpressionEvalError::Execution {
+                error_text: expression.get_error_text(),
+            });
+        }
+
+        Ok(result)
+    }
+
+    /// Executes the expression like [`execute()`][Self::execute], converting the result to `T`.
+    pub fn execute_as<T: FromGodot>(
+        &self,
+        inputs: &[Variant],
+        base: Option<&Gd<Object>>,
+    ) -> Result<T, ExpressionEvalError> {
+        self.execute(inputs, base)?
+            .try_to::<T>()
+            .map_err(|error| ExpressionEvalError::WrongType { error })
+    }
+
+    /// The source text passed to [`parse()`][Self::parse].
+    pub fn source_text(&self) -> &GString {
+        &self.source
+    }
+
+    /// Names of the inputs, in the order expected by [`execute()`][Self::execute].
+    pub fn input_names(&self) -> &PackedStringArray {
+        &self.input_names
+    }
+}
+
+impl fmt::Debug for GExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GExpression")
+            .field("source", &self.source)
+            .field("input_names", &self.input_names)
+            .finish()
+    }
+}
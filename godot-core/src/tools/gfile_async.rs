@@ -0,0 +1,439 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{ready, Context, Poll, Waker};
+use std::{cmp, fmt};
+
+use futures_io::{AsyncRead, AsyncSeek, AsyncWrite};
+
+#[cfg(not(wasm_nothreads))]
+use crate::task::spawn_worker;
+use crate::task::{MainThreadGate, WorkerHandle};
+use crate::tools::GFile;
+
+/// Asynchronous access to a [`GFile`], with file operations running on Godot's `WorkerThreadPool`.
+///
+/// Implements [`AsyncRead`], [`AsyncWrite`] and [`AsyncSeek`] from the [`futures-io`](https://docs.rs/futures-io) crate, so that big files
+/// can be streamed in a [Godot task][crate::task::spawn] while the game keeps rendering. Combinators like `read_to_end()` or `copy()` are
+/// available through the extension traits of the `futures` crate.
+///
+/// Each read or write is performed on a worker thread, in chunks of at most [`chunk_size()`][Self::chunk_size] bytes. When a chunk is done,
+/// the waiting task is woken up on the main thread. Only one operation is in flight at a time:
+/// - Reads fetch a whole chunk and serve smaller reads from it.
+/// - Writes copy up to one chunk and return immediately. The next write waits until the previous chunk has been written, which limits
+///   memory usage when the producer is faster than the disk. Errors of a write are returned by the next operation; call `flush()` or
+///   `close()` at the end to observe them.
+///
+/// Seeking while a read is in flight (i.e. a read returned `Pending` and was not completed) fails with an error, since the position of the
+/// pending read would be ambiguous. Pending writes are awaited before seeking.
+///
+/// Wake-ups are delivered on the main thread, so the file is meant to be used in Godot tasks. Requires the `futures-io` Cargo feature.
+///
+/// With `experimental-wasm-nothreads`, there are no worker threads; each operation then runs synchronously when it is started, and the
+/// API behaves the same otherwise.
+///
+/// # Example
+/// ```no_run
+/// use futures::AsyncReadExt;
+/// use godot::classes::file_access::ModeFlags;
+/// use godot::task;
+/// use godot::tools::GFile;
+///
+/// task::spawn(async {
+///     let file = GFile::open("user://world.sav", ModeFlags::READ).unwrap();
+///     let mut file = file.into_async();
+///
+///     let mut contents = Vec::new();
+///     file.read_to_end(&mut contents).await.unwrap();
+/// });
+/// ```
+pub struct AsyncGFile {
+    /// `None` while an operation is in flight (then owned by the worker), or after an operation panicked.
+    file: Option<SendFile>,
+    in_flight: Option<InFlight>,
+    read_buffer: Vec<u8>,
+    read_pos: usize,
+    chunk_size: usize,
+    gate: MainThreadGate,
+}
+
+impl AsyncGFile {
+    /// Default size of chunks that are read or written at once: 64 KiB.
+    pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+    /// Wraps a file for asynchronous access. See also [`GFile::into_async()`].
+    ///
+    /// # Panics
+    /// If the `Scene` init level of the extension is not loaded.
+    pub fn new(file: GFile) -> Self {
+        Self {
+            file: Some(SendFile(file)),
+            in_flight: None,
+            read_buffer: Vec::new(),
+            read_pos: 0,
+            chunk_size: Self::DEFAULT_CHUNK_SIZE,
+            gate: MainThreadGate::new(),
+        }
+    }
+
+    /// Sets the maximum number of bytes read or written by a single operation on the worker thread.
+    ///
+    /// Smaller chunks let other tasks make progress more often; larger chunks reduce the per-chunk overhead.
+    ///
+    /// # Panics
+    /// If `chunk_size` is 0.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk size must be positive");
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Maximum number of bytes read or written by a single operation.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Whether an operation is currently running on a worker thread.
+    pub fn is_busy(&self) -> bool {
+        self.in_flight.is_some()
+    }
+
+    /// Converts back into a synchronous [`GFile`], positioned after the bytes that have been read or written so far.
+    ///
+    /// If an operation is in flight, blocks until it has finished. Returns its error if it failed, or if an earlier operation panicked.
+    pub fn into_sync(mut self) -> io::Result<GFile> {
+        if let Some(in_flight) = self.in_flight.take() {
+            let outcome = in_flight.join();
+            self.complete(outcome)?;
+        }
+
+        self.discard_read_buffer()?;
+        let SendFile(file) = self.file.take().ok_or_else(file_lost)?;
+        Ok(file)
+    }
+
+    // ------------------------------------------------------------------------------------------------------------------------------------------
+    // Private methods.
+
+    /// Moves the file to a worker thread and runs `op` on it.
+    fn start<F>(&mut self, kind: OpKind, op: F) -> io::Result<()>
+    where
+        F: FnOnce(&mut GFile) -> io::Result<Vec<u8>> + Send + 'static,
+    {
+        let mut file = self.file.take().ok_or_else(file_lost)?;
+        let shared = Arc::new(OpShared::default());
+
+        let worker_shared = shared.clone();
+        let gate = self.gate.clone();
+        let work = move || {
+            // Declared first, so it is dropped last: wakes the task after the outcome is stored, or if `op` panicked.
+            let _wake = WakeOnDrop {
+                shared: worker_shared.clone(),
+                gate,
+            };
+
+            let result = op(&mut file.0);
+            *lock(&worker_shared.outcome) = Some(Outcome::Done(file, result));
+        };
+
+        #[cfg(not(wasm_nothreads))]
+        let handle = Some(spawn_worker(work));
+
+        // Without threads, the operation runs right away; the next poll then completes it. A panic is recorded by `WakeOnDrop`.
+        #[cfg(wasm_nothreads)]
+        let handle = {
+            let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(work));
+            None
+        };
+
+        self.in_flight = Some(InFlight {
+            kind,
+            handle,
+            shared,
+        });
+
+        Ok(())
+    }
+
+    /// Polls the in-flight operation. Returns `None` if there is none, otherwise the kind of the completed operation.
+    ///
+    /// On completion, the file is moved back, and read data is stored in the read buffer.
+    fn poll_in_flight(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Option<OpKind>>> {
+        let Some(in_flight) = &self.in_flight else {
+            return Poll::Ready(Ok(None));
+        };
+
+        // Register waker before checking the outcome, so that a completion in between is not missed.
+        *lock(&in_flight.shared.waker) = Some(cx.waker().clone());
+        if lock(&in_flight.shared.outcome).is_none() {
+            return Poll::Pending;
+        }
+
+        let in_flight = self.in_flight.take().expect("checked above");
+        let kind = in_flight.kind;
+        let outcome = in_flight.join();
+
+        Poll::Ready(self.complete(outcome).map(|()| Some(kind)))
+    }
+
+    fn complete(&mut self, outcome: Outcome) -> io::Result<()> {
+        let (file, result) = match outcome {
+            Outcome::Done(file, result) => (file, result),
+            Outcome::Panicked => {
+                return Err(io::Error::other(
+                    "file operation panicked on worker thread; file is closed",
+                ))
+            }
+        };
+
+        self.file = Some(file);
+
+        // Only reads return data; their chunk replaces the (fully consumed) read buffer.
+        let data = result?;
+        if !data.is_empty() {
+            self.read_buffer = data;
+            self.read_pos = 0;
+        }
+
+        Ok(())
+    }
+
+    /// Moves the cursor back to the logical position, if read data has been buffered but not consumed.
+    fn discard_read_buffer(&mut self) -> io::Result<()> {
+        let remaining = self.buffered_len();
+        self.read_buffer.clear();
+        self.read_pos = 0;
+
+        if remaining > 0 {
+            let file = self.file.as_mut().ok_or_else(file_lost)?;
+            file.0.seek(SeekFrom::Current(-(remaining as i64)))?;
+        }
+
+        Ok(())
+    }
+
+    fn buffered_len(&self) -> usize {
+        self.read_buffer.len() - self.read_pos
+    }
+}
+
+impl GFile {
+    /// Converts this file into an [`AsyncGFile`], which implements the `futures-io` traits.
+    ///
+    /// # Panics
+    /// If the `Scene` init level of the extension is not loaded.
+    pub fn into_async(self) -> AsyncGFile {
+        AsyncGFile::new(self)
+    }
+}
+
+impl AsyncRead for AsyncGFile {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        loop {
+            if this.buffered_len() > 0 {
+                let len = cmp::min(buf.len(), this.buffered_len());
+                let start = this.read_pos;
+                buf[..len].copy_from_slice(&this.read_buffer[start..start + len]);
+                this.read_pos += len;
+
+                return Poll::Ready(Ok(len));
+            }
+
+            match ready!(this.poll_in_flight(cx))? {
+                // Read chunk is now buffered, unless the end of the file was reached.
+                Some(OpKind::Read) if this.buffered_len() == 0 => return Poll::Ready(Ok(0)),
+                Some(_) => continue,
+                None if buf.is_empty() => return Poll::Ready(Ok(0)),
+                None => {
+                    let chunk_size = this.chunk_size;
+                    this.start(OpKind::Read, move |file| {
+                        let mut data = vec![0; chunk_size];
+                        let len = file.read(&mut data)?;
+                        data.truncate(len);
+                        Ok(data)
+                    })?;
+                }
+            }
+        }
+    }
+}
+
+impl AsyncWrite for AsyncGFile {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        // Wait for the previous operation first; this provides backpressure.
+        while ready!(this.poll_in_flight(cx))?.is_some() {}
+
+        this.discard_read_buffer()?;
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        let len = cmp::min(buf.len(), this.chunk_size);
+        let data = buf[..len].to_vec();
+        this.start(OpKind::Write, move |file| {
+            file.write_all(&data)?;
+            Ok(Vec::new())
+        })?;
+
+        Poll::Ready(Ok(len))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        loop {
+            match ready!(this.poll_in_flight(cx))? {
+                Some(OpKind::Flush) => return Poll::Ready(Ok(())),
+                Some(_) => continue,
+                None => this.start(OpKind::Flush, |file| {
+                    file.flush()?;
+                    Ok(Vec::new())
+                })?,
+            }
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        // The file itself is closed when dropped.
+        self.poll_flush(cx)
+    }
+}
+
+impl AsyncSeek for AsyncGFile {
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<io::Result<u64>> {
+        let this = self.get_mut();
+
+        if this
+            .in_flight
+            .as_ref()
+            .is_some_and(|in_flight| in_flight.kind == OpKind::Read)
+        {
+            return Poll::Ready(Err(io::Error::other(
+                "cannot seek while a read is in flight",
+            )));
+        }
+
+        while ready!(this.poll_in_flight(cx))?.is_some() {}
+
+        // The cursor is ahead of the logical position by the number of buffered bytes.
+        let pos = match pos {
+            SeekFrom::Current(offset) => SeekFrom::Current(offset - this.buffered_len() as i64),
+            pos => pos,
+        };
+
+        this.read_buffer.clear();
+        this.read_pos = 0;
+
+        let file = this.file.as_mut().ok_or_else(file_lost)?;
+        Poll::Ready(file.0.seek(pos))
+    }
+}
+
+impl fmt::Debug for AsyncGFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncGFile")
+            .field("chunk_size", &self.chunk_size)
+            .field("busy", &self.is_busy())
+            .field("buffered", &self.buffered_len())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Worker integration
+
+/// Lets a `GFile` move to a worker thread for the duration of one operation.
+struct SendFile(GFile);
+
+// SAFETY: `GFile` holds the only reference to its `FileAccess` (see `GFile::try_from_unique()`), and its buffers are not shared. The file is
+// only accessed by one thread at a time: it is moved to the worker for an operation and moved back once that has finished.
+unsafe impl Send for SendFile {}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum OpKind {
+    Read,
+    Write,
+    Flush,
+}
+
+struct InFlight {
+    kind: OpKind,
+    /// `None` if the operation already ran on the calling thread (single-threaded Wasm).
+    handle: Option<WorkerHandle<()>>,
+    shared: Arc<OpShared>,
+}
+
+impl InFlight {
+    /// Waits for the worker task and returns its outcome. Only blocks briefly if the outcome is already stored.
+    fn join(self) -> Outcome {
+        // Panics are recorded as outcome by `WakeOnDrop`, so the payload can be ignored.
+        if let Some(handle) = self.handle {
+            let _ = handle.join();
+        }
+
+        lock(&self.shared.outcome)
+            .take()
+            .unwrap_or(Outcome::Panicked)
+    }
+}
+
+#[derive(Default)]
+struct OpShared {
+    outcome: Mutex<Option<Outcome>>,
+    waker: Mutex<Option<Waker>>,
+}
+
+enum Outcome {
+    Done(SendFile, io::Result<Vec<u8>>),
+    Panicked,
+}
+
+/// Wakes the waiting task on the main thread when the worker operation ends, whether by completion or panic.
+struct WakeOnDrop {
+    shared: Arc<OpShared>,
+    gate: MainThreadGate,
+}
+
+impl Drop for WakeOnDrop {
+    fn drop(&mut self) {
+        lock(&self.shared.outcome).get_or_insert(Outcome::Panicked);
+
+        if let Some(waker) = lock(&self.shared.waker).take() {
+            self.gate.run(move |_ctx| waker.wake());
+        }
+    }
+}
+
+fn file_lost() -> io::Error {
+    io::Error::other("file was closed after an operation panicked")
+}
+
+/// Locks a mutex, ignoring poisoning (the guarded data stays consistent when a worker panics).
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
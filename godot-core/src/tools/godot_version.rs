@@ -0,0 +1,143 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::builtin::{Dictionary, GString};
+use crate::meta::FromGodot;
+use crate::sys;
+
+/// Version of the running Godot engine, as returned by [`Engine::version()`][crate::classes::Engine::version].
+///
+/// Versions are ordered by `major`, `minor` and `patch`. Versions with equal numbers but different builds (e.g. `dev` and `stable`) are
+/// not equal; to keep the order total, they are ordered by comparing the remaining fields as strings. This tie-break carries no meaning
+/// about which release is newer, so compare [`triple()`][Self::triple] when only the version numbers matter.
+///
+/// To branch on the API level, [`is_godot_version_at_least()`] is the runtime counterpart of `#[cfg(since_api = "4.x")]`.
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::Engine;
+///
+/// let version = Engine::singleton().version();
+/// if version.is_at_least(4, 3) {
+///     godot::global::godot_print!("Running on {version} ({})", version.hash);
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct GodotVersion {
+    pub major: u8,
+    pub minor: u8,
+    /// 0 if the version has no patch component, e.g. `4.3`.
+    pub patch: u8,
+
+    /// Release status, e.g. `"stable"`, `"rc2"` or `"dev"`.
+    pub status: String,
+
+    /// Build name, e.g. `"official"` or `"custom_build"`.
+    pub build: String,
+
+    /// Full Git commit hash of the engine source, or empty if unknown.
+    pub hash: String,
+
+    /// Human-readable version, e.g. `"4.3-stable (official)"`.
+    pub string: String,
+}
+
+impl GodotVersion {
+    /// Parses the dictionary returned by `Engine.get_version_info()`.
+    ///
+    /// Missing or malformed entries fall back to 0 or empty strings.
+    pub fn from_dictionary(dict: &Dictionary) -> Self {
+        let number = |key: &str| get_or_default::<i64>(dict, key) as u8;
+        let string = |key: &str| get_or_default::<GString>(dict, key).to_string();
+
+        Self {
+            major: number("major"),
+            minor: number("minor"),
+            patch: number("patch"),
+            status: string("status"),
+            build: string("build"),
+            hash: string("hash"),
+            string: string("string"),
+        }
+    }
+
+    /// Returns `(major, minor, patch)`.
+    pub fn triple(&self) -> (u8, u8, u8) {
+        (self.major, self.minor, self.patch)
+    }
+
+    /// Returns `true` if this version is `major.minor` or later, regardless of patch level and status.
+    pub fn is_at_least(&self, major: u8, minor: u8) -> bool {
+        (self.major, self.minor) >= (major, minor)
+    }
+
+    /// Returns the version encoded as `0xMMmmpp` (major, minor, patch), like Godot's `hex` entry.
+    pub fn hex(&self) -> u32 {
+        ((self.major as u32) << 16) | ((self.minor as u32) << 8) | self.patch as u32
+    }
+}
+
+impl PartialOrd for GodotVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for GodotVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Remaining fields only break ties, consistent with Eq.
+        self.triple()
+            .cmp(&other.triple())
+            .then_with(|| self.status.cmp(&other.status))
+            .then_with(|| self.build.cmp(&other.build))
+            .then_with(|| self.hash.cmp(&other.hash))
+            .then_with(|| self.string.cmp(&other.string))
+    }
+}
+
+impl fmt::Display for GodotVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)?;
+        if self.patch != 0 {
+            write!(f, ".{}", self.patch)?;
+        }
+        write!(f, "-{}", self.status)
+    }
+}
+
+/// Returns `true` if the running Godot engine has version `major.minor` or later.
+///
+/// Runtime counterpart of `#[cfg(since_api = "major.minor")]`, for when the extension is loaded by a newer Godot binary than the API
+/// level it was compiled against. Typed shorthand for [`GdextBuild::since_api()`][crate::init::GdextBuild::since_api], and cheaper than
+/// [`Engine::version()`][crate::classes::Engine::version], since it reads the version reported at initialization instead of querying the
+/// engine.
+///
+/// # Example
+/// ```no_run
+/// use godot::tools::is_godot_version_at_least;
+///
+/// if is_godot_version_at_least(4, 3) {
+///     // Call a method that only exists in Godot 4.3+, e.g. dynamically via `Object::call()`.
+/// }
+/// ```
+pub fn is_godot_version_at_least(major: u8, minor: u8) -> bool {
+    // GdextBuild::since_api() only accepts Godot 4 versions.
+    match major.cmp(&4) {
+        Ordering::Less => true,
+        Ordering::Equal => sys::GdextBuild::since_api(&format!("4.{minor}")),
+        Ordering::Greater => false,
+    }
+}
+
+fn get_or_default<T: FromGodot + Default>(dict: &Dictionary, key: &str) -> T {
+    dict.get(key)
+        .and_then(|value| value.try_to::<T>().ok())
+        .unwrap_or_default()
+}
@@ -0,0 +1,240 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::{GString, Variant};
+use crate::classes::{RegEx, RegExMatch};
+use crate::global::Error as GodotError;
+use crate::meta::error::RegexCompileError;
+use crate::meta::sealed::Sealed;
+use crate::meta::{arg_into_owned, AsArg, ToGodot};
+use crate::obj::{Gd, NewGd};
+
+/// Compiled regular expression.
+///
+/// Wraps Godot's [`RegEx`] class, which uses the [PCRE2](https://www.pcre.org/) syntax. Compilation returns a `Result` instead of an error
+/// code, and matches are returned as [`RegexMatch`], whose accessors use `Option` for groups that did not participate in the match.
+///
+/// Compiling is the expensive step, so keep a `GRegex` around instead of compiling the same pattern for every search.
+///
+/// Positions are indices of characters (Unicode code points) in the subject, as in GDScript; they are not byte offsets into a Rust `&str`.
+///
+/// # Example
+/// ```no_run
+/// use godot::tools::GRegex;
+///
+/// let regex = GRegex::compile(r"(?<key>\w+)=(?<value>\d+)").expect("valid pattern");
+///
+/// let m = regex.search("volume=80").expect("pattern matches");
+/// assert_eq!(m.get_string("key").unwrap(), "volume");
+/// assert_eq!(m.get_string(2).unwrap(), "80");
+/// assert_eq!(m.get_start(0), Some(0));
+///
+/// let keys: Vec<String> = regex
+///     .search_all("a=1, b=2, c=3")
+///     .map(|m| m.get_string("key").unwrap().to_string())
+///     .collect();
+/// assert_eq!(keys, ["a", "b", "c"]);
+/// ```
+pub struct GRegex {
+    regex: Gd<RegEx>,
+}
+
+impl GRegex {
+    /// Compiles `pattern`.
+    ///
+    /// On failure, Godot prints the reason (e.g. the position of the syntax error) to the error log.
+    pub fn compile(pattern: impl AsArg<GString>) -> Result<Self, RegexCompileError> {
+        arg_into_owned!(pattern);
+
+        let mut regex = RegEx::new_gd();
+        let err = regex.compile(&pattern);
+
+        if err != GodotError::OK {
+            return Err(RegexCompileError::new(pattern, err));
+        }
+
+        Ok(Self { regex })
+    }
+
+    /// Returns the first match in `subject`, or `None` if the pattern does not match.
+    pub fn search(&self, subject: impl AsArg<GString>) -> Option<RegexMatch> {
+        self.regex.search(subject).map(RegexMatch::from_match)
+    }
+
+    /// Returns the first match in `subject` that starts at character index `offset` or later.
+    pub fn search_from(&self, subject: impl AsArg<GString>, offset: usize) -> Option<RegexMatch> {
+        self.regex
+            .search_ex(subject)
+            .offset(to_godot_index(offset))
+            .done()
+            .map(RegexMatch::from_match)
+    }
+
+    /// Iterates over all non-overlapping matches in `subject`, in order.
+    pub fn search_all(&self, subject: impl AsArg<GString>) -> std::vec::IntoIter<RegexMatch> {
+        let matches: Vec<RegexMatch> = self
+            .regex
+            .search_all(subject)
+            .iter_shared()
+            .map(RegexMatch::from_match)
+            .collect();
+
+        matches.into_iter()
+    }
+
+    /// Whether the pattern matches anywhere in `subject`.
+    pub fn is_match(&self, subject: impl AsArg<GString>) -> bool {
+        self.regex.search(subject).is_some()
+    }
+
+    /// Replaces all matches in `subject` with `replacement`, which can refer to groups as `$1` or `$name`.
+    pub fn replace_all(
+        &self,
+        subject: impl AsArg<GString>,
+        replacement: impl AsArg<GString>,
+    ) -> GString {
+        self.regex.sub_ex(subject, replacement).all(true).done()
+    }
+
+    /// The pattern passed to [`compile()`][Self::compile].
+    pub fn pattern(&self) -> GString {
+        self.regex.get_pattern()
+    }
+
+    /// Number of capturing groups, not counting the implicit group 0 for the whole match.
+    pub fn group_count(&self) -> usize {
+        self.regex.get_group_count() as usize
+    }
+
+    /// Returns the underlying `RegEx`, e.g. to pass it to Godot APIs.
+    pub fn regex(&self) -> Gd<RegEx> {
+        self.regex.clone()
+    }
+}
+
+impl fmt::Debug for GRegex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GRegex")
+            .field("pattern", &self.pattern())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Result of a successful [`GRegex`] search.
+///
+/// Groups are selected by index (`0` is the whole match) or by name, see [`RegexGroup`]. Accessors return `None` for groups that do not
+/// exist in the pattern or did not participate in the match, e.g. the unmatched branch of `(a)|(b)`.
+pub struct RegexMatch {
+    inner: Gd<RegExMatch>,
+}
+
+impl RegexMatch {
+    fn from_match(inner: Gd<RegExMatch>) -> Self {
+        Self { inner }
+    }
+
+    /// Text matched by `group`.
+    pub fn get_string(&self, group: impl RegexGroup) -> Option<GString> {
+        let group = group.to_group_variant();
+
+        // get_string() returns an empty string for non-participating groups, which is indistinguishable from an empty match.
+        self.start_of(&group)?;
+        Some(self.inner.get_string_ex().name(&group).done())
+    }
+
+    /// Character index in the subject at which `group` starts.
+    pub fn get_start(&self, group: impl RegexGroup) -> Option<usize> {
+        self.start_of(&group.to_group_variant())
+    }
+
+    /// Character index in the subject right after the end of `group`.
+    pub fn get_end(&self, group: impl RegexGroup) -> Option<usize> {
+        let end = self
+            .inner
+            .get_end_ex()
+            .name(&group.to_group_variant())
+            .done();
+
+        usize::try_from(end).ok()
+    }
+
+    /// Text of the whole match, i.e. group 0.
+    pub fn as_gstring(&self) -> GString {
+        self.inner.get_string()
+    }
+
+    /// Number of capturing groups in the pattern, not counting group 0.
+    pub fn group_count(&self) -> usize {
+        self.inner.get_group_count() as usize
+    }
+
+    /// The string that was searched.
+    pub fn subject(&self) -> GString {
+        self.inner.get_subject()
+    }
+
+    /// Returns the underlying `RegExMatch`, e.g. to pass it to Godot APIs.
+    pub fn regex_match(&self) -> Gd<RegExMatch> {
+        self.inner.clone()
+    }
+
+    fn start_of(&self, group: &Variant) -> Option<usize> {
+        let start = self.inner.get_start_ex().name(group).done();
+        usize::try_from(start).ok()
+    }
+}
+
+impl fmt::Debug for RegexMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegexMatch")
+            .field("string", &self.as_gstring())
+            .field("start", &self.get_start(0))
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Selects a capturing group in a [`RegexMatch`]: by index (`usize`, `i32`) or by name (`&str`, `&GString`).
+///
+/// This trait is sealed and cannot be implemented outside the library.
+pub trait RegexGroup: Sealed {
+    #[doc(hidden)]
+    fn to_group_variant(&self) -> Variant;
+}
+
+impl RegexGroup for usize {
+    fn to_group_variant(&self) -> Variant {
+        to_godot_index(*self).to_variant()
+    }
+}
+
+impl RegexGroup for i32 {
+    fn to_group_variant(&self) -> Variant {
+        self.to_variant()
+    }
+}
+
+impl RegexGroup for &str {
+    fn to_group_variant(&self) -> Variant {
+        GString::from(*self).to_variant()
+    }
+}
+
+impl RegexGroup for &GString {
+    fn to_group_variant(&self) -> Variant {
+        (*self).to_variant()
+    }
+}
+
+fn to_godot_index(index: usize) -> i32 {
+    i32::try_from(index).expect("regex index exceeds i32::MAX")
+}
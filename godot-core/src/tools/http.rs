@@ -0,0 +1,270 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::Duration;
+
+use crate::builtin::{GString, PackedByteArray, PackedStringArray, Signal};
+use crate::classes::http_client::Method;
+use crate::classes::{http_request, Engine, HttpRequest, Node, SceneTree};
+use crate::global::Error as GodotError;
+use crate::meta::error::HttpError;
+use crate::obj::{EngineEnum, Gd, Inherits, NewAlloc};
+
+/// Sends a `GET` request to `url` and returns the response once it arrives.
+///
+/// Shorthand for [`HttpRequestBuilder::new(url).send()`][HttpRequestBuilder::send]; see there for details.
+///
+/// # Example
+/// ```no_run
+/// use godot::task;
+/// use godot::tools::http_get;
+///
+/// task::spawn(async {
+///     match http_get("https://example.com/status.json").await {
+///         Ok(response) => println!("{}: {}", response.status(), response.text()),
+///         Err(err) => eprintln!("{err}"),
+///     }
+/// });
+/// ```
+pub fn http_get(url: impl Into<GString>) -> impl Future<Output = Result<HttpResponse, HttpError>> {
+    HttpRequestBuilder::new(url).send()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Configures and sends an HTTP request using an `HTTPRequest` node.
+///
+/// The node is added to the given parent (by default the scene tree's root) when the request is sent, and freed once the response has
+/// arrived or the future is dropped.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use godot::classes::http_client::Method;
+/// use godot::tools::HttpRequestBuilder;
+///
+/// # async fn post() {
+/// let response = HttpRequestBuilder::new("https://example.com/scores")
+///     .method(Method::POST)
+///     .header("Content-Type", "application/json")
+///     .body_text(r#"{"score": 42}"#)
+///     .timeout(Duration::from_secs(5))
+///     .send()
+///     .await;
+/// # }
+/// ```
+#[must_use]
+pub struct HttpRequestBuilder {
+    url: GString,
+    method: Method,
+    headers: Vec<String>,
+    body: PackedByteArray,
+    timeout: Option<Duration>,
+    parent: Option<Gd<Node>>,
+}
+
+impl HttpRequestBuilder {
+    /// Creates a `GET` request to `url`, without headers, body or timeout.
+    pub fn new(url: impl Into<GString>) -> Self {
+        Self {
+            url: url.into(),
+            method: Method::GET,
+            headers: Vec::new(),
+            body: PackedByteArray::new(),
+            timeout: None,
+            parent: None,
+        }
+    }
+
+    /// Sets the HTTP method, e.g. `POST`.
+    pub fn method(mut self, method: Method) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Adds a header line `name: value`.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push(format!("{name}: {value}"));
+        self
+    }
+
+    /// Sets the request body as raw bytes.
+    pub fn body(mut self, body: impl Into<PackedByteArray>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Sets the request body as UTF-8 text.
+    pub fn body_text(self, body: &str) -> Self {
+        self.body(body.as_bytes())
+    }
+
+    /// Fails the request with [`HttpError::Timeout`] if it takes longer than `timeout`. By default, there is no timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Adds the `HTTPRequest` node below `parent` instead of the scene tree's root.
+    ///
+    /// The parent must be inside the scene tree.
+    pub fn parent<N: Inherits<Node>>(mut self, parent: &Gd<N>) -> Self {
+        self.parent = Some(parent.clone().upcast());
+        self
+    }
+
+    /// Starts the request and returns a future resolving to the response.
+    ///
+    /// The request is started immediately, not only when the future is first polled. Must be called on the main thread, and the future
+    /// must be awaited in a task spawned with [`task::spawn()`][crate::task::spawn], since completion is detected through a signal.
+    pub fn send(self) -> impl Future<Output = Result<HttpResponse, HttpError>> {
+        let started = self.start();
+
+        async move {
+            let (node, future) = started?;
+
+            let (result, status, headers, body) = future.await.map_err(|_| HttpError::Cancelled)?;
+            drop(node);
+
+            let result = http_request::Result::try_from_ord(result as i32)
+                .unwrap_or(http_request::Result::REQUEST_FAILED);
+
+            if result != http_request::Result::SUCCESS {
+                return Err(HttpError::from_result(result));
+            }
+
+            Ok(HttpResponse::new(status, headers, body))
+        }
+    }
+
+    fn start(self) -> Result<(RequestNode, CompletedFuture), HttpError> {
+        let mut parent = match self.parent {
+            Some(parent) => parent,
+            None => scene_root().ok_or(HttpError::Start(GodotError::ERR_UNCONFIGURED))?,
+        };
+
+        let mut node = HttpRequest::new_alloc();
+        if let Some(timeout) = self.timeout {
+            node.set_timeout(timeout.as_secs_f64());
+        }
+        parent.add_child(&node);
+
+        // Guard frees the node on all paths, including when the future is dropped before completion.
+        let node = RequestNode(node);
+
+        // Connect before starting, so the completion signal cannot be missed.
+        let future = Signal::from_object_signal(&node.0, "request_completed").to_fallible_future();
+
+        let err = node
+            .0
+            .clone()
+            .request_raw_ex(&self.url)
+            .custom_headers(&PackedStringArray::from_iter(
+                self.headers.into_iter().map(GString::from),
+            ))
+            .method(self.method)
+            .request_data_raw(&self.body)
+            .done();
+
+        if err != GodotError::OK {
+            return Err(HttpError::Start(err));
+        }
+
+        Ok((node, future))
+    }
+}
+
+type CompletedFuture =
+    crate::task::FallibleSignalFuture<(i64, i64, PackedStringArray, PackedByteArray)>;
+
+fn scene_root() -> Option<Gd<Node>> {
+    let tree = Engine::singleton()
+        .get_main_loop()?
+        .try_cast::<SceneTree>()
+        .ok()?;
+
+    tree.get_root().map(Gd::upcast)
+}
+
+/// Frees the `HTTPRequest` node when the request is done or abandoned.
+struct RequestNode(Gd<HttpRequest>);
+
+impl Drop for RequestNode {
+    fn drop(&mut self) {
+        if self.0.is_instance_valid() {
+            self.0.cancel_request();
+            self.0.queue_free();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Response to an HTTP request, see [`http_get()`] and [`HttpRequestBuilder`].
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    status: i64,
+    headers: HashMap<String, String>,
+    body: PackedByteArray,
+}
+
+impl HttpResponse {
+    fn new(status: i64, header_lines: PackedStringArray, body: PackedByteArray) -> Self {
+        let headers = header_lines
+            .as_slice()
+            .iter()
+            .filter_map(|line| {
+                let line = line.to_string();
+                let (name, value) = line.split_once(':')?;
+
+                Some((name.trim().to_ascii_lowercase(), value.trim().to_string()))
+            })
+            .collect();
+
+        Self {
+            status,
+            headers,
+            body,
+        }
+    }
+
+    /// HTTP status code, e.g. `200` or `404`.
+    pub fn status(&self) -> i64 {
+        self.status
+    }
+
+    /// Whether the status code is in the `2xx` range.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// All response headers, with names in lowercase.
+    ///
+    /// If a header occurs multiple times, the last value is kept.
+    pub fn headers(&self) -> &HashMap<String, String> {
+        &self.headers
+    }
+
+    /// Value of the header `name`, which is matched case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /// Raw response body.
+    pub fn body(&self) -> &PackedByteArray {
+        &self.body
+    }
+
+    /// Response body decoded as UTF-8, with invalid sequences replaced by `�`.
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(self.body.as_slice()).into_owned()
+    }
+}
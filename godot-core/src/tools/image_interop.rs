@@ -0,0 +1,156 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use ::image::{DynamicImage, ImageBuffer};
+
+use crate::builtin::PackedByteArray;
+use crate::classes::image::Format;
+use crate::classes::Image;
+use crate::obj::Gd;
+
+/// Error returned by [`Image::to_dynamic_image()`] when the image's format has no counterpart in the `image` crate.
+///
+/// This is the case for compressed formats (S3TC, ETC, ASTC, ...) and for `RGBE9995`. Decompress or convert the image first.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct UnsupportedFormat {
+    format: Format,
+}
+
+impl UnsupportedFormat {
+    /// The format of the image that could not be converted.
+    pub fn format(&self) -> Format {
+        self.format
+    }
+}
+
+impl fmt::Display for UnsupportedFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "image format {:?} cannot be converted to an `image::DynamicImage`",
+            self.format
+        )
+    }
+}
+
+impl Error for UnsupportedFormat {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Format with a direct `DynamicImage` counterpart, into which `format` is converted losslessly.
+fn interop_format(format: Format) -> Option<Format> {
+    let target = match format {
+        Format::L8 | Format::LA8 | Format::RGB8 | Format::RGBA8 | Format::RGBF | Format::RGBAF => {
+            format
+        }
+        Format::R8 | Format::RG8 | Format::RGB565 => Format::RGB8,
+        Format::RGBA4444 => Format::RGBA8,
+        Format::RF | Format::RGF | Format::RH | Format::RGH | Format::RGBH => Format::RGBF,
+        Format::RGBAH => Format::RGBAF,
+        _ => return None,
+    };
+
+    Some(target)
+}
+
+pub(crate) fn from_dynamic_image(source: &DynamicImage) -> Gd<Image> {
+    let (format, data) = match source {
+        DynamicImage::ImageLuma8(buffer) => (
+            Format::L8,
+            PackedByteArray::from(buffer.as_raw().as_slice()),
+        ),
+        DynamicImage::ImageLumaA8(buffer) => (
+            Format::LA8,
+            PackedByteArray::from(buffer.as_raw().as_slice()),
+        ),
+        DynamicImage::ImageRgb8(buffer) => (
+            Format::RGB8,
+            PackedByteArray::from(buffer.as_raw().as_slice()),
+        ),
+        DynamicImage::ImageRgba8(buffer) => (
+            Format::RGBA8,
+            PackedByteArray::from(buffer.as_raw().as_slice()),
+        ),
+        DynamicImage::ImageRgb32F(buffer) => (Format::RGBF, float_bytes(buffer.as_raw())),
+        DynamicImage::ImageRgba32F(buffer) => (Format::RGBAF, float_bytes(buffer.as_raw())),
+
+        // 16-bit formats have no Godot counterpart; f32 represents them without loss.
+        other if other.color().has_alpha() => {
+            (Format::RGBAF, float_bytes(other.to_rgba32f().as_raw()))
+        }
+        other => (Format::RGBF, float_bytes(other.to_rgb32f().as_raw())),
+    };
+
+    let width = dimension(source.width());
+    let height = dimension(source.height());
+
+    Image::create_from_data(width, height, false, format, &data).unwrap_or_else(|| {
+        panic!("Image::from_dynamic_image(): cannot create {width}x{height} image")
+    })
+}
+
+pub(crate) fn to_dynamic_image(image: &Image) -> Result<DynamicImage, UnsupportedFormat> {
+    let format = image.get_format();
+    let target = interop_format(format).ok_or(UnsupportedFormat { format })?;
+
+    let width = image.get_width();
+    let height = image.get_height();
+    let view = image.data_view();
+    let level = view.level(0).expect("image has at least one level");
+
+    // Only the full-size image is converted, mipmaps are dropped.
+    let data = if target == format {
+        level.bytes().to_vec()
+    } else {
+        let bytes = PackedByteArray::from(level.bytes());
+        let mut copy = Image::create_from_data(width, height, false, format, &bytes)
+            .expect("copy of valid image");
+
+        copy.convert(target);
+        copy.get_data().to_vec()
+    };
+
+    let (width, height) = (width as u32, height as u32);
+    let dynamic = match target {
+        Format::L8 => ImageBuffer::from_raw(width, height, data).map(DynamicImage::ImageLuma8),
+        Format::LA8 => ImageBuffer::from_raw(width, height, data).map(DynamicImage::ImageLumaA8),
+        Format::RGB8 => ImageBuffer::from_raw(width, height, data).map(DynamicImage::ImageRgb8),
+        Format::RGBA8 => ImageBuffer::from_raw(width, height, data).map(DynamicImage::ImageRgba8),
+        Format::RGBF => {
+            ImageBuffer::from_raw(width, height, floats(&data)).map(DynamicImage::ImageRgb32F)
+        }
+        Format::RGBAF => {
+            ImageBuffer::from_raw(width, height, floats(&data)).map(DynamicImage::ImageRgba32F)
+        }
+        _ => unreachable!("interop_format() returned {target:?}"),
+    };
+
+    Ok(dynamic.expect("image data matches its size and format"))
+}
+
+fn dimension(size: u32) -> i32 {
+    i32::try_from(size).unwrap_or_else(|_| panic!("image dimension {size} exceeds i32 range"))
+}
+
+/// Godot stores float channels in native byte order.
+fn float_bytes(values: &[f32]) -> PackedByteArray {
+    values
+        .iter()
+        .flat_map(|value| value.to_ne_bytes())
+        .collect()
+}
+
+fn floats(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_ne_bytes(chunk.try_into().unwrap()))
+        .collect()
+}
@@ -0,0 +1,367 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::ops::Range;
+
+use crate::builtin::PackedByteArray;
+use crate::classes::image::Format;
+use crate::classes::Image;
+
+/// Read-only view of an image's raw pixel data, see [`Image::data_view()`].
+///
+/// The view shares the image's buffer (Godot's `PackedByteArray` is copy-on-write), so creating it does not copy any pixels. It is a
+/// snapshot: later changes to the image are not reflected.
+///
+/// Data is laid out row by row, starting at the top-left pixel. If the image has mipmaps, all levels are stored consecutively, starting
+/// with the full-size image; use [`level()`][Self::level] to access an individual one.
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::Image;
+///
+/// let image = Image::from_rgba8(2, 1, &[255, 0, 0, 255, 0, 0, 255, 255]);
+///
+/// let view = image.data_view();
+/// let pixels = view.level(0).unwrap().rgba8().expect("RGBA8 format");
+/// assert_eq!(pixels[1], [0, 0, 255, 255]);
+/// ```
+pub struct ImageView {
+    data: PackedByteArray,
+    layout: ImageLayout,
+}
+
+impl ImageView {
+    pub(crate) fn new(image: &Image) -> Self {
+        let data = image.get_data();
+        let layout = ImageLayout::new(image, data.len());
+
+        Self { data, layout }
+    }
+
+    /// Width of the full-size image (mipmap level 0), in pixels.
+    pub fn width(&self) -> i32 {
+        self.layout.levels[0].width
+    }
+
+    /// Height of the full-size image (mipmap level 0), in pixels.
+    pub fn height(&self) -> i32 {
+        self.layout.levels[0].height
+    }
+
+    /// Pixel format of the image.
+    pub fn format(&self) -> Format {
+        self.layout.format
+    }
+
+    /// Number of mipmap levels, including the full-size image. Always at least 1.
+    pub fn level_count(&self) -> usize {
+        self.layout.levels.len()
+    }
+
+    /// All bytes of the image, including all mipmap levels.
+    pub fn bytes(&self) -> &[u8] {
+        self.data.as_slice()
+    }
+
+    /// Mipmap level `index`, where level 0 is the full-size image. Returns `None` if there is no such level.
+    pub fn level(&self, index: usize) -> Option<ImageLevel<'_>> {
+        let info = self.layout.levels.get(index)?;
+
+        Some(ImageLevel {
+            width: info.width,
+            height: info.height,
+            format: self.layout.format,
+            bytes: &self.data.as_slice()[info.range.clone()],
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Mutable view of an image's raw pixel data, see [`Image::data_view_mut()`].
+///
+/// The first write access copies the pixel data once (copy-on-write); all changes are stored back into the image when the guard is
+/// dropped. This makes bulk pixel manipulation much faster than calling `set_pixel()` per pixel.
+///
+/// The layout is the same as for [`ImageView`]. Size and format of the image cannot be changed through the view.
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::image::Format;
+/// use godot::classes::Image;
+///
+/// let mut image = Image::create_empty(256, 1, false, Format::RGBA8).unwrap();
+///
+/// let mut view = image.data_view_mut();
+/// let mut level = view.level_mut(0).unwrap();
+/// for (x, pixel) in level.rgba8_mut().unwrap().iter_mut().enumerate() {
+///     *pixel = [x as u8, 0, 0, 255];
+/// }
+/// ```
+pub struct ImageViewMut<'a> {
+    image: &'a mut Image,
+    data: PackedByteArray,
+    layout: ImageLayout,
+}
+
+impl<'a> ImageViewMut<'a> {
+    pub(crate) fn new(image: &'a mut Image) -> Self {
+        let data = image.get_data();
+        let layout = ImageLayout::new(image, data.len());
+
+        Self {
+            image,
+            data,
+            layout,
+        }
+    }
+
+    /// Width of the full-size image (mipmap level 0), in pixels.
+    pub fn width(&self) -> i32 {
+        self.layout.levels[0].width
+    }
+
+    /// Height of the full-size image (mipmap level 0), in pixels.
+    pub fn height(&self) -> i32 {
+        self.layout.levels[0].height
+    }
+
+    /// Pixel format of the image.
+    pub fn format(&self) -> Format {
+        self.layout.format
+    }
+
+    /// Number of mipmap levels, including the full-size image.
+    pub fn level_count(&self) -> usize {
+        self.layout.levels.len()
+    }
+
+    /// All bytes of the image, including all mipmap levels.
+    pub fn bytes(&self) -> &[u8] {
+        self.data.as_slice()
+    }
+
+    /// All bytes of the image, including all mipmap levels (mutable).
+    pub fn bytes_mut(&mut self) -> &mut [u8] {
+        self.data.as_mut_slice()
+    }
+
+    /// Mipmap level `index`, where level 0 is the full-size image. Returns `None` if there is no such level.
+    pub fn level(&self, index: usize) -> Option<ImageLevel<'_>> {
+        let info = self.layout.levels.get(index)?;
+
+        Some(ImageLevel {
+            width: info.width,
+            height: info.height,
+            format: self.layout.format,
+            bytes: &self.data.as_slice()[info.range.clone()],
+        })
+    }
+
+    /// Mipmap level `index` (mutable). Returns `None` if there is no such level.
+    pub fn level_mut(&mut self, index: usize) -> Option<ImageLevelMut<'_>> {
+        let info = self.layout.levels.get(index)?;
+
+        Some(ImageLevelMut {
+            width: info.width,
+            height: info.height,
+            format: self.layout.format,
+            bytes: &mut self.data.as_mut_slice()[info.range.clone()],
+        })
+    }
+}
+
+impl Drop for ImageViewMut<'_> {
+    fn drop(&mut self) {
+        // Hands the buffer back without copying, thanks to copy-on-write.
+        let has_mipmaps = self.layout.levels.len() > 1;
+        let (width, height) = (self.width(), self.height());
+
+        self.image
+            .set_data(width, height, has_mipmaps, self.layout.format, &self.data);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Pixel data of one mipmap level, obtained from [`ImageView::level()`] or [`ImageViewMut::level()`].
+#[derive(Copy, Clone, Debug)]
+pub struct ImageLevel<'a> {
+    width: i32,
+    height: i32,
+    format: Format,
+    bytes: &'a [u8],
+}
+
+impl<'a> ImageLevel<'a> {
+    /// Width of this level, in pixels.
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// Height of this level, in pixels.
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Raw bytes of this level.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Index of the pixel at `(x, y)` in the typed pixel slices, i.e. `y * width + x`.
+    pub fn pixel_index(&self, x: i32, y: i32) -> usize {
+        (y * self.width + x) as usize
+    }
+
+    /// Pixels as `[r, g, b, a]` bytes, if the format is [`Format::RGBA8`].
+    pub fn rgba8(&self) -> Option<&'a [[u8; 4]]> {
+        cast_pixels(self.format, Format::RGBA8, self.bytes)
+    }
+
+    /// Pixels as single `f32` red channel, if the format is [`Format::RF`].
+    pub fn rf(&self) -> Option<&'a [f32]> {
+        cast_pixels(self.format, Format::RF, self.bytes)
+    }
+
+    /// Pixels as `[r, g, b, a]` floats, if the format is [`Format::RGBAF`].
+    pub fn rgbaf(&self) -> Option<&'a [[f32; 4]]> {
+        cast_pixels(self.format, Format::RGBAF, self.bytes)
+    }
+}
+
+/// Mutable pixel data of one mipmap level, obtained from [`ImageViewMut::level_mut()`].
+#[derive(Debug)]
+pub struct ImageLevelMut<'a> {
+    width: i32,
+    height: i32,
+    format: Format,
+    bytes: &'a mut [u8],
+}
+
+impl ImageLevelMut<'_> {
+    /// Width of this level, in pixels.
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// Height of this level, in pixels.
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Raw bytes of this level (mutable).
+    pub fn bytes_mut(&mut self) -> &mut [u8] {
+        self.bytes
+    }
+
+    /// Index of the pixel at `(x, y)` in the typed pixel slices, i.e. `y * width + x`.
+    pub fn pixel_index(&self, x: i32, y: i32) -> usize {
+        (y * self.width + x) as usize
+    }
+
+    /// Pixels as `[r, g, b, a]` bytes, if the format is [`Format::RGBA8`].
+    pub fn rgba8_mut(&mut self) -> Option<&mut [[u8; 4]]> {
+        cast_pixels_mut(self.format, Format::RGBA8, self.bytes)
+    }
+
+    /// Pixels as single `f32` red channel, if the format is [`Format::RF`].
+    pub fn rf_mut(&mut self) -> Option<&mut [f32]> {
+        cast_pixels_mut(self.format, Format::RF, self.bytes)
+    }
+
+    /// Pixels as `[r, g, b, a]` floats, if the format is [`Format::RGBAF`].
+    pub fn rgbaf_mut(&mut self) -> Option<&mut [[f32; 4]]> {
+        cast_pixels_mut(self.format, Format::RGBAF, self.bytes)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Types which can be reinterpreted from pixel bytes.
+///
+/// # Safety
+/// Every bit pattern must be a valid value, and the type must not contain padding.
+unsafe trait Pixel: Copy {}
+
+unsafe impl Pixel for [u8; 4] {}
+unsafe impl Pixel for f32 {}
+unsafe impl Pixel for [f32; 4] {}
+
+fn cast_pixels<T: Pixel>(format: Format, expected: Format, bytes: &[u8]) -> Option<&[T]> {
+    if format != expected {
+        return None;
+    }
+
+    // SAFETY: T is valid for all bit patterns (see Pixel); alignment is checked below.
+    let (prefix, pixels, suffix) = unsafe { bytes.align_to::<T>() };
+    (prefix.is_empty() && suffix.is_empty()).then_some(pixels)
+}
+
+fn cast_pixels_mut<T: Pixel>(
+    format: Format,
+    expected: Format,
+    bytes: &mut [u8],
+) -> Option<&mut [T]> {
+    if format != expected {
+        return None;
+    }
+
+    // SAFETY: T is valid for all bit patterns, and so are bytes written through it (see Pixel); alignment is checked below.
+    let (prefix, pixels, suffix) = unsafe { bytes.align_to_mut::<T>() };
+    (prefix.is_empty() && suffix.is_empty()).then_some(pixels)
+}
+
+struct LevelInfo {
+    width: i32,
+    height: i32,
+    range: Range<usize>,
+}
+
+struct ImageLayout {
+    format: Format,
+    levels: Vec<LevelInfo>,
+}
+
+impl ImageLayout {
+    fn new(image: &Image, total_len: usize) -> Self {
+        let count = image.get_mipmap_count() + 1;
+
+        let offsets: Vec<usize> = (0..count)
+            .map(|level| image.get_mipmap_offset(level) as usize)
+            .chain(std::iter::once(total_len))
+            .collect();
+
+        let (width, height) = (image.get_width(), image.get_height());
+        let levels = offsets
+            .windows(2)
+            .enumerate()
+            .map(|(level, window)| {
+                // Each mipmap level halves the size, down to 1x1.
+                let shrink = |size: i32| {
+                    if level == 0 {
+                        size
+                    } else {
+                        (size >> level).max(1)
+                    }
+                };
+
+                LevelInfo {
+                    width: shrink(width),
+                    height: shrink(height),
+                    range: window[0]..window[1],
+                }
+            })
+            .collect();
+
+        Self {
+            format: image.get_format(),
+            levels,
+        }
+    }
+}
@@ -0,0 +1,167 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use crate::builtin::{StringName, Vector2};
+use crate::classes::{Input, InputMap};
+use crate::sys::Global;
+
+// Action names are created once per enum type, on first use. StringNames must be released before the engine shuts down.
+static ACTION_NAMES: Global<HashMap<TypeId, Vec<StringName>>> = Global::default();
+
+/// Input actions of the project's `InputMap`, declared as a Rust enum.
+///
+/// This trait should not be implemented manually; use `#[derive(InputActions)]` on a fieldless enum instead. Every enumerator maps to one
+/// action. By default, the action name is the enumerator name in `snake_case`; use `#[action(name = "...")]` to choose a different name,
+/// e.g. for the built-in `ui_*` actions.
+///
+/// The `StringName` of each action is created once and then cached, so queries like [`pressed()`][Self::pressed] don't allocate.
+///
+/// # Validation
+/// In debug builds, the first access to any action of an enum checks whether all its actions exist in the `InputMap`, and prints a
+/// warning listing the missing ones. Use [`missing_actions()`][Self::missing_actions] to perform this check explicitly.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::register::InputActions;
+/// use godot::tools::InputActions;
+///
+/// #[derive(InputActions, Copy, Clone)]
+/// enum Action {
+///     Jump,     // "jump"
+///     MoveLeft, // "move_left"
+///     MoveRight,
+///     #[action(name = "ui_accept")]
+///     Confirm,
+/// }
+///
+/// fn update_player(player: &mut Gd<Node2D>, delta: f32) {
+///     let direction = Action::get_axis(Action::MoveLeft, Action::MoveRight);
+///     player.translate(Vector2::new(direction * 200.0 * delta, 0.0));
+///
+///     if Action::Jump.just_pressed() {
+///         godot_print!("jump!");
+///     }
+/// }
+/// ```
+pub trait InputActions: Copy + 'static {
+    /// All actions, in declaration order.
+    const ALL: &'static [Self];
+
+    /// Name of this action in the `InputMap`.
+    fn action_name(self) -> &'static str;
+
+    #[doc(hidden)]
+    fn __action_index(self) -> usize;
+
+    /// Name of this action as cached `StringName`.
+    fn string_name(self) -> StringName {
+        cached_action_name(self)
+    }
+
+    /// Whether the action is currently pressed, see `Input::is_action_pressed()`.
+    fn pressed(self) -> bool {
+        Input::singleton().is_action_pressed(&self.string_name())
+    }
+
+    /// Whether the action started being pressed in the current frame, see `Input::is_action_just_pressed()`.
+    fn just_pressed(self) -> bool {
+        Input::singleton().is_action_just_pressed(&self.string_name())
+    }
+
+    /// Whether the action stopped being pressed in the current frame, see `Input::is_action_just_released()`.
+    fn just_released(self) -> bool {
+        Input::singleton().is_action_just_released(&self.string_name())
+    }
+
+    /// Strength between 0 and 1, for analog input such as joysticks, see `Input::get_action_strength()`.
+    fn strength(self) -> f32 {
+        Input::singleton().get_action_strength(&self.string_name())
+    }
+
+    /// Axis value between -1 and 1 from two opposite actions, see `Input::get_axis()`.
+    fn get_axis(negative: Self, positive: Self) -> f32 {
+        Input::singleton().get_axis(&negative.string_name(), &positive.string_name())
+    }
+
+    /// Direction vector from four actions, with default deadzone, see `Input::get_vector()`.
+    ///
+    /// The result has a length of at most 1.
+    fn get_vector(
+        negative_x: Self,
+        positive_x: Self,
+        negative_y: Self,
+        positive_y: Self,
+    ) -> Vector2 {
+        Input::singleton().get_vector(
+            &negative_x.string_name(),
+            &positive_x.string_name(),
+            &negative_y.string_name(),
+            &positive_y.string_name(),
+        )
+    }
+
+    /// Returns all actions that do not exist in the `InputMap`.
+    fn missing_actions() -> Vec<Self> {
+        let input_map = InputMap::singleton();
+
+        Self::ALL
+            .iter()
+            .copied()
+            .filter(|action| !input_map.has_action(&action.string_name()))
+            .collect()
+    }
+}
+
+fn cached_action_name<A: InputActions>(action: A) -> StringName {
+    let index = action.__action_index();
+
+    let mut names = ACTION_NAMES.lock();
+    if let Some(cached) = names.get(&TypeId::of::<A>()) {
+        return cached[index].clone();
+    }
+
+    let created: Vec<StringName> = A::ALL
+        .iter()
+        .map(|action| StringName::from(action.action_name()))
+        .collect();
+    let name = created[index].clone();
+    names.insert(TypeId::of::<A>(), created);
+
+    // Validation looks up the names again; release the lock first.
+    drop(names);
+    if cfg!(debug_assertions) {
+        warn_missing_actions::<A>();
+    }
+
+    name
+}
+
+fn warn_missing_actions<A: InputActions>() {
+    let missing = A::missing_actions();
+    if missing.is_empty() {
+        return;
+    }
+
+    let names = missing
+        .iter()
+        .map(|action| action.action_name())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    crate::godot_warn!(
+        "{} declares input actions that are missing in the InputMap: {names}",
+        std::any::type_name::<A>()
+    );
+}
+
+pub(crate) fn clear_input_action_names() {
+    ACTION_NAMES.lock().clear();
+}
@@ -0,0 +1,104 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::classes::{
+    InputEvent, InputEventAction, InputEventJoypadButton, InputEventJoypadMotion, InputEventKey,
+    InputEventMouseButton, InputEventMouseMotion, InputEventScreenTouch,
+};
+use crate::match_class;
+use crate::obj::Gd;
+
+/// Input event downcast to its concrete class, for handling all kinds of events in a single `match`.
+///
+/// Obtained through [`InputEvent::classify()`] or [`InputEventView::classify()`]. The cast is performed once, when classifying.
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::{INode, InputEvent, Node};
+/// use godot::prelude::*;
+/// use godot::tools::InputEventView;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Player {
+///     fn input(&mut self, event: Gd<InputEvent>) {
+///         match event.classify() {
+///             InputEventView::Key(key) => godot_print!("key {:?}", key.get_keycode()),
+///             InputEventView::MouseButton(button) => godot_print!("click at {}", button.get_position()),
+///             InputEventView::MouseMotion(motion) => godot_print!("moved by {}", motion.get_relative()),
+///             _ => {}
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub enum InputEventView {
+    /// Keyboard key press or release.
+    Key(Gd<InputEventKey>),
+
+    /// Mouse button press or release, including the scroll wheel.
+    MouseButton(Gd<InputEventMouseButton>),
+
+    /// Mouse or pen movement.
+    MouseMotion(Gd<InputEventMouseMotion>),
+
+    /// Gamepad button press or release.
+    JoypadButton(Gd<InputEventJoypadButton>),
+
+    /// Gamepad stick or trigger movement.
+    JoypadMotion(Gd<InputEventJoypadMotion>),
+
+    /// Touch screen press or release.
+    ScreenTouch(Gd<InputEventScreenTouch>),
+
+    /// Action from the `InputMap`, usually emitted via `Input::parse_input_event()`.
+    Action(Gd<InputEventAction>),
+
+    /// Any other event, e.g. gestures, MIDI or screen drag.
+    Other(Gd<InputEvent>),
+}
+
+impl InputEventView {
+    /// Casts `event` to its concrete class.
+    pub fn classify(event: Gd<InputEvent>) -> Self {
+        match_class!(event, {
+            InputEventKey(key) => Self::Key(key),
+            InputEventMouseButton(button) => Self::MouseButton(button),
+            InputEventMouseMotion(motion) => Self::MouseMotion(motion),
+            InputEventJoypadButton(button) => Self::JoypadButton(button),
+            InputEventJoypadMotion(motion) => Self::JoypadMotion(motion),
+            InputEventScreenTouch(touch) => Self::ScreenTouch(touch),
+            InputEventAction(action) => Self::Action(action),
+            other => Self::Other(other),
+        })
+    }
+
+    /// Returns the event, upcast to `InputEvent`.
+    pub fn into_event(self) -> Gd<InputEvent> {
+        match self {
+            Self::Key(event) => event.upcast(),
+            Self::MouseButton(event) => event.upcast(),
+            Self::MouseMotion(event) => event.upcast(),
+            Self::JoypadButton(event) => event.upcast(),
+            Self::JoypadMotion(event) => event.upcast(),
+            Self::ScreenTouch(event) => event.upcast(),
+            Self::Action(event) => event.upcast(),
+            Self::Other(event) => event,
+        }
+    }
+}
+
+impl From<Gd<InputEvent>> for InputEventView {
+    fn from(event: Gd<InputEvent>) -> Self {
+        Self::classify(event)
+    }
+}
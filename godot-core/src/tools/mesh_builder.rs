@@ -0,0 +1,305 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{
+    Color, PackedColorArray, PackedFloat32Array, PackedInt32Array, PackedVector2Array,
+    PackedVector3Array, Variant, VariantArray, Vector2, Vector3, Vector4,
+};
+use crate::classes::mesh::{ArrayFormat, ArrayType, PrimitiveType};
+use crate::classes::ArrayMesh;
+use crate::meta::error::MeshError;
+use crate::meta::{FromGodot, ToGodot};
+use crate::obj::{EngineBitfield, Gd, IndexEnum, NewGd};
+
+/// Builds a mesh surface from Rust slices, without assembling the surface array by hand.
+///
+/// Godot's `add_surface_from_arrays()` expects a `VariantArray` with one packed array per [`ArrayType`], at the index of that type.
+/// This builder fills it from typed slices and validates them first: every channel must have one entry per vertex, and indices must
+/// be in range and form complete primitives.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::{Vector2, Vector3};
+/// use godot::tools::MeshBuilder;
+///
+/// let vertices = [Vector3::ZERO, Vector3::RIGHT, Vector3::UP];
+/// let uvs = [Vector2::ZERO, Vector2::RIGHT, Vector2::DOWN];
+///
+/// let mesh = MeshBuilder::new(&vertices)
+///     .uvs(&uvs)
+///     .indices(&[0, 2, 1])
+///     .build()
+///     .expect("valid mesh");
+/// ```
+#[must_use]
+#[derive(Clone, Debug)]
+pub struct MeshBuilder<'a> {
+    primitive: PrimitiveType,
+    vertices: &'a [Vector3],
+    normals: Option<&'a [Vector3]>,
+    tangents: Option<&'a [Vector4]>,
+    colors: Option<&'a [Color]>,
+    uvs: Option<&'a [Vector2]>,
+    uv2s: Option<&'a [Vector2]>,
+    indices: Option<&'a [i32]>,
+    flags: ArrayFormat,
+}
+
+impl<'a> MeshBuilder<'a> {
+    /// Creates a builder for a [`PrimitiveType::TRIANGLES`] surface with the given vertex positions.
+    pub fn new(vertices: &'a [Vector3]) -> Self {
+        Self {
+            primitive: PrimitiveType::TRIANGLES,
+            vertices,
+            normals: None,
+            tangents: None,
+            colors: None,
+            uvs: None,
+            uv2s: None,
+            indices: None,
+            flags: ArrayFormat::from_ord(0),
+        }
+    }
+
+    /// Sets the primitive type; the default is `TRIANGLES`.
+    pub fn primitive(mut self, primitive: PrimitiveType) -> Self {
+        self.primitive = primitive;
+        self
+    }
+
+    /// Sets one normal per vertex.
+    pub fn normals(mut self, normals: &'a [Vector3]) -> Self {
+        self.normals = Some(normals);
+        self
+    }
+
+    /// Sets one tangent per vertex: `x`, `y`, `z` hold the tangent, `w` the binormal direction (`1.0` or `-1.0`).
+    pub fn tangents(mut self, tangents: &'a [Vector4]) -> Self {
+        self.tangents = Some(tangents);
+        self
+    }
+
+    /// Sets one color per vertex.
+    pub fn colors(mut self, colors: &'a [Color]) -> Self {
+        self.colors = Some(colors);
+        self
+    }
+
+    /// Sets one UV coordinate per vertex.
+    pub fn uvs(mut self, uvs: &'a [Vector2]) -> Self {
+        self.uvs = Some(uvs);
+        self
+    }
+
+    /// Sets one secondary UV coordinate per vertex, e.g. for lightmaps.
+    pub fn uv2s(mut self, uv2s: &'a [Vector2]) -> Self {
+        self.uv2s = Some(uv2s);
+        self
+    }
+
+    /// Sets the indices; without them, every consecutive group of vertices forms a primitive.
+    pub fn indices(mut self, indices: &'a [i32]) -> Self {
+        self.indices = Some(indices);
+        self
+    }
+
+    /// Sets format flags passed to `add_surface_from_arrays()`, e.g. compression flags. The channel flags are derived automatically.
+    pub fn flags(mut self, flags: ArrayFormat) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Creates a new `ArrayMesh` with a single surface.
+    pub fn build(&self) -> Result<Gd<ArrayMesh>, MeshError> {
+        let mut mesh = ArrayMesh::new_gd();
+        self.add_to(&mut mesh)?;
+
+        Ok(mesh)
+    }
+
+    /// Adds a surface to an existing mesh and returns its index.
+    pub fn add_to(&self, mesh: &mut Gd<ArrayMesh>) -> Result<i32, MeshError> {
+        let arrays = self.to_arrays()?;
+
+        mesh.add_surface_from_arrays_ex(self.primitive, &arrays)
+            .flags(self.flags)
+            .done();
+
+        Ok(mesh.get_surface_count() - 1)
+    }
+
+    /// Validates the channels and returns the surface array, as expected by `add_surface_from_arrays()` and similar methods.
+    pub fn to_arrays(&self) -> Result<VariantArray, MeshError> {
+        self.validate()?;
+
+        let mut arrays = VariantArray::new();
+        arrays.resize(ArrayType::ENUMERATOR_COUNT, &Variant::nil());
+
+        // Godot stores tangents as 4 floats per vertex, independent of the `real` precision.
+        #[allow(clippy::unnecessary_cast)]
+        let tangents = self.tangents.map(|tangents| {
+            tangents
+                .iter()
+                .flat_map(|t| [t.x as f32, t.y as f32, t.z as f32, t.w as f32])
+                .collect::<PackedFloat32Array>()
+        });
+
+        let channels = [
+            (
+                ArrayType::VERTEX,
+                Some(PackedVector3Array::from(self.vertices).to_variant()),
+            ),
+            (
+                ArrayType::NORMAL,
+                self.normals
+                    .map(|n| PackedVector3Array::from(n).to_variant()),
+            ),
+            (ArrayType::TANGENT, tangents.map(|t| t.to_variant())),
+            (
+                ArrayType::COLOR,
+                self.colors.map(|c| PackedColorArray::from(c).to_variant()),
+            ),
+            (
+                ArrayType::TEX_UV,
+                self.uvs.map(|uv| PackedVector2Array::from(uv).to_variant()),
+            ),
+            (
+                ArrayType::TEX_UV2,
+                self.uv2s
+                    .map(|uv| PackedVector2Array::from(uv).to_variant()),
+            ),
+            (
+                ArrayType::INDEX,
+                self.indices.map(|i| PackedInt32Array::from(i).to_variant()),
+            ),
+        ];
+
+        for (channel, value) in channels {
+            if let Some(value) = value {
+                arrays.set(channel.to_index(), &value);
+            }
+        }
+
+        Ok(arrays)
+    }
+
+    fn validate(&self) -> Result<(), MeshError> {
+        let vertex_count = self.vertices.len();
+        if vertex_count == 0 {
+            return Err(MeshError::MissingChannel(ArrayType::VERTEX));
+        }
+
+        let channel_lengths = [
+            (ArrayType::NORMAL, self.normals.map(<[_]>::len)),
+            (ArrayType::TANGENT, self.tangents.map(<[_]>::len)),
+            (ArrayType::COLOR, self.colors.map(<[_]>::len)),
+            (ArrayType::TEX_UV, self.uvs.map(<[_]>::len)),
+            (ArrayType::TEX_UV2, self.uv2s.map(<[_]>::len)),
+        ];
+
+        for (channel, len) in channel_lengths {
+            if let Some(actual) = len.filter(|&len| len != vertex_count) {
+                return Err(MeshError::LengthMismatch {
+                    channel,
+                    expected: vertex_count,
+                    actual,
+                });
+            }
+        }
+
+        if let Some(indices) = self.indices {
+            if let Some(&index) = indices
+                .iter()
+                .find(|&&index| index < 0 || index as usize >= vertex_count)
+            {
+                return Err(MeshError::IndexOutOfRange {
+                    index,
+                    vertex_count,
+                });
+            }
+        }
+
+        let count = self.indices.map_or(vertex_count, <[_]>::len);
+        let complete = match self.primitive {
+            PrimitiveType::LINES => count % 2 == 0,
+            PrimitiveType::TRIANGLES => count % 3 == 0,
+            PrimitiveType::LINE_STRIP => count >= 2,
+            PrimitiveType::TRIANGLE_STRIP => count >= 3,
+            _ => true,
+        };
+
+        if !complete {
+            return Err(MeshError::IncompletePrimitive {
+                primitive: self.primitive,
+                count,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Typed channels of a mesh surface, read back with [`Mesh::surface_mesh_arrays()`][crate::classes::Mesh::surface_mesh_arrays].
+///
+/// Channels that the surface does not have are `None`. Use [`builder()`][Self::builder] to add the data to another mesh.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MeshArrays {
+    pub vertices: Vec<Vector3>,
+    pub normals: Option<Vec<Vector3>>,
+    pub tangents: Option<Vec<Vector4>>,
+    pub colors: Option<Vec<Color>>,
+    pub uvs: Option<Vec<Vector2>>,
+    pub uv2s: Option<Vec<Vector2>>,
+    pub indices: Option<Vec<i32>>,
+}
+
+impl MeshArrays {
+    /// Converts a surface array as returned by `surface_get_arrays()`.
+    ///
+    /// Returns `None` if the array has no 3D vertex channel (e.g. a 2D mesh).
+    pub fn from_arrays(arrays: &VariantArray) -> Option<Self> {
+        let vertices = channel::<PackedVector3Array>(arrays, ArrayType::VERTEX)?;
+
+        let tangents = channel::<PackedFloat32Array>(arrays, ArrayType::TANGENT).map(|floats| {
+            floats
+                .as_slice()
+                .chunks_exact(4)
+                .map(|t| Vector4::new(t[0] as _, t[1] as _, t[2] as _, t[3] as _))
+                .collect()
+        });
+
+        Some(Self {
+            vertices: vertices.to_vec(),
+            normals: channel::<PackedVector3Array>(arrays, ArrayType::NORMAL).map(|a| a.to_vec()),
+            tangents,
+            colors: channel::<PackedColorArray>(arrays, ArrayType::COLOR).map(|a| a.to_vec()),
+            uvs: channel::<PackedVector2Array>(arrays, ArrayType::TEX_UV).map(|a| a.to_vec()),
+            uv2s: channel::<PackedVector2Array>(arrays, ArrayType::TEX_UV2).map(|a| a.to_vec()),
+            indices: channel::<PackedInt32Array>(arrays, ArrayType::INDEX).map(|a| a.to_vec()),
+        })
+    }
+
+    /// Builder for a surface with the same channels, borrowing from `self`.
+    pub fn builder(&self) -> MeshBuilder<'_> {
+        MeshBuilder {
+            normals: self.normals.as_deref(),
+            tangents: self.tangents.as_deref(),
+            colors: self.colors.as_deref(),
+            uvs: self.uvs.as_deref(),
+            uv2s: self.uv2s.as_deref(),
+            indices: self.indices.as_deref(),
+            ..MeshBuilder::new(&self.vertices)
+        }
+    }
+}
+
+/// Packed array stored for `channel`, or `None` if the channel is absent.
+fn channel<A: FromGodot>(arrays: &VariantArray, channel: ArrayType) -> Option<A> {
+    arrays.get(channel.to_index())?.try_to::<A>().ok()
+}
@@ -10,10 +10,118 @@
 //! Contains functionality that extends existing Godot classes and functions, to make them more versatile
 //! or better integrated with Rust.
 
+#[cfg(feature = "codegen-full")]
+mod audio_playback;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod audio_player;
+mod class_db;
+#[cfg(feature = "codegen-full")]
+mod curve_builder;
+mod dir;
+mod editor_export;
+#[cfg(feature = "codegen-full")]
+mod editor_import;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod editor_plugin;
+#[cfg(since_api = "4.2")]
+mod frame_callback;
+#[cfg(feature = "codegen-full")]
+mod gconfig;
+#[cfg(feature = "codegen-full")]
+mod gexpression;
 mod gfile;
+#[cfg(all(feature = "codegen-full", feature = "futures-io", since_api = "4.2"))]
+mod gfile_async;
+mod godot_version;
+#[cfg(feature = "codegen-full")]
+mod gregex;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod http;
+#[cfg(feature = "image-interop")] // Implies codegen-full.
+mod image_interop;
+#[cfg(feature = "codegen-full")]
+mod image_view;
+mod input_actions;
+#[cfg(feature = "codegen-full")]
+mod input_event;
+#[cfg(feature = "codegen-full")]
+mod mesh_builder;
+#[cfg(feature = "codegen-full")]
+mod net_io;
+mod node_process;
+#[cfg(feature = "codegen-full")]
+mod physics_extension;
+#[cfg(feature = "codegen-full")]
+mod physics_query;
+#[cfg(feature = "codegen-full")]
+mod project_settings;
+mod rendering_rid;
+mod resource_format;
 mod save_load;
+mod scene_group;
+#[cfg(feature = "codegen-full")]
+mod shader_params;
+mod spawn;
+#[cfg(all(feature = "codegen-full", since_api = "4.3"))]
+mod tile_cell;
 mod translate;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod tween_builder;
 
+#[cfg(feature = "codegen-full")]
+pub use audio_playback::*;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+pub use audio_player::*;
+pub use class_db::*;
+#[cfg(feature = "codegen-full")]
+pub use curve_builder::*;
+pub use dir::*;
+pub use editor_export::*;
+#[cfg(feature = "codegen-full")]
+pub use editor_import::*;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+pub use editor_plugin::*;
+#[cfg(since_api = "4.2")]
+pub use frame_callback::*;
+#[cfg(feature = "codegen-full")]
+pub use gconfig::*;
+#[cfg(feature = "codegen-full")]
+pub use gexpression::*;
 pub use gfile::*;
+#[cfg(all(feature = "codegen-full", feature = "futures-io", since_api = "4.2"))]
+pub use gfile_async::*;
+pub use godot_version::*;
+#[cfg(feature = "codegen-full")]
+pub use gregex::*;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+pub use http::*;
+#[cfg(feature = "image-interop")] // Implies codegen-full.
+pub use image_interop::*;
+#[cfg(feature = "codegen-full")]
+pub use image_view::*;
+pub use input_actions::*;
+#[cfg(feature = "codegen-full")]
+pub use input_event::*;
+#[cfg(feature = "codegen-full")]
+pub use mesh_builder::*;
+#[cfg(feature = "codegen-full")]
+pub use net_io::*;
+pub use node_process::*;
+#[cfg(feature = "codegen-full")]
+pub use physics_extension::*;
+#[cfg(feature = "codegen-full")]
+pub use physics_query::*;
+#[cfg(feature = "codegen-full")]
+pub use project_settings::*;
+pub use rendering_rid::*;
+pub use resource_format::*;
 pub use save_load::*;
+pub use scene_group::*;
+#[cfg(feature = "codegen-full")]
+pub use shader_params::*;
+pub use spawn::*;
+#[cfg(all(feature = "codegen-full", since_api = "4.3"))]
+pub use tile_cell::*;
 pub use translate::*;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+pub use tween_builder::*;
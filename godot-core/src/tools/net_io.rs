@@ -0,0 +1,324 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::io::{ErrorKind, Read, Write};
+
+use crate::builtin::{GString, PackedByteArray, VariantArray};
+use crate::classes::{
+    stream_peer_tcp, stream_peer_tls, PacketPeer, PacketPeerUdp, StreamPeer, StreamPeerTcp,
+    StreamPeerTls,
+};
+use crate::global::Error;
+use crate::meta::{arg_into_ref, AsArg, FromGodot};
+use crate::obj::{Gd, Inherits, NewGd};
+
+/// Byte stream over a Godot [`StreamPeer`], implementing [`Read`] and [`Write`].
+///
+/// Works with any stream peer, most notably [`StreamPeerTcp`] and [`StreamPeerTls`]. Those are polled automatically before each read,
+/// so their connection status stays up to date.
+///
+/// Network peers are non-blocking: if no data is available yet, reads fail with [`ErrorKind::WouldBlock`] instead of waiting. The same
+/// happens for writes if the peer cannot accept data right now, e.g. because a TCP connection is still being established. Once the
+/// connection is closed by the other side and all data has been read, reads return `Ok(0)`.
+///
+/// Engine errors are mapped to the closest [`ErrorKind`], for example `ERR_CONNECTION_ERROR` to [`ErrorKind::ConnectionReset`].
+///
+/// ## Example
+/// ```no_run
+/// use std::io::{ErrorKind, Read, Write};
+/// use godot::tools::GStream;
+///
+/// let mut stream = GStream::connect_tcp("127.0.0.1", 4242).unwrap();
+/// stream.write_all(b"ping").unwrap();
+///
+/// // Typically done each frame, e.g. in process().
+/// let mut buf = [0; 256];
+/// match stream.read(&mut buf) {
+///     Ok(0) => println!("connection closed"),
+///     Ok(n) => println!("received {:?}", &buf[..n]),
+///     Err(e) if e.kind() == ErrorKind::WouldBlock => {} // Nothing yet.
+///     Err(e) => eprintln!("error: {e}"),
+/// }
+/// ```
+///
+/// ## See also
+///
+/// - [`GPacketPeer`] for datagram-based protocols.
+/// - [`GFile`][crate::tools::GFile], the equivalent for `FileAccess`.
+pub struct GStream {
+    peer: Gd<StreamPeer>,
+}
+
+impl GStream {
+    /// Wraps an existing stream peer, e.g. one returned by `TCPServer::take_connection()`.
+    pub fn new<T: Inherits<StreamPeer>>(peer: Gd<T>) -> Self {
+        Self {
+            peer: peer.upcast(),
+        }
+    }
+
+    /// Starts a TCP connection to `host` and `port`.
+    ///
+    /// Connecting is non-blocking: the stream can be used right away, but reads and writes fail with [`ErrorKind::WouldBlock`] until the
+    /// connection is established.
+    pub fn connect_tcp(host: impl AsArg<GString>, port: u16) -> std::io::Result<Self> {
+        arg_into_ref!(host);
+
+        let mut peer = StreamPeerTcp::new_gd();
+        check_error(peer.connect_to_host(host, port as i32))?;
+
+        Ok(Self::new(peer))
+    }
+
+    /// The wrapped stream peer.
+    pub fn peer(&self) -> &Gd<StreamPeer> {
+        &self.peer
+    }
+
+    /// Returns the wrapped stream peer, consuming the `GStream`.
+    pub fn into_inner(self) -> Gd<StreamPeer> {
+        self.peer
+    }
+
+    /// Updates the connection state of TCP and TLS peers, returning an error if the connection failed.
+    ///
+    /// Returns `Ok(false)` if the peer is disconnected (or was never connected), and `Ok(true)` otherwise.
+    fn poll(&mut self) -> std::io::Result<bool> {
+        if let Ok(mut tcp) = self.peer.clone().try_cast::<StreamPeerTcp>() {
+            check_error(tcp.poll())?;
+
+            return match tcp.get_status() {
+                stream_peer_tcp::Status::ERROR => Err(ErrorKind::ConnectionReset.into()),
+                stream_peer_tcp::Status::NONE => Ok(false),
+                _ => Ok(true),
+            };
+        }
+
+        if let Ok(mut tls) = self.peer.clone().try_cast::<StreamPeerTls>() {
+            tls.poll();
+
+            return match tls.get_status() {
+                stream_peer_tls::Status::DISCONNECTED => Ok(false),
+                stream_peer_tls::Status::ERROR
+                | stream_peer_tls::Status::ERROR_HOSTNAME_MISMATCH => {
+                    Err(ErrorKind::ConnectionReset.into())
+                }
+                _ => Ok(true),
+            };
+        }
+
+        // Other peers, e.g. StreamPeerBuffer, have no connection.
+        Ok(true)
+    }
+
+    fn is_connecting(&self) -> bool {
+        if let Ok(tcp) = self.peer.clone().try_cast::<StreamPeerTcp>() {
+            return tcp.get_status() == stream_peer_tcp::Status::CONNECTING;
+        }
+
+        if let Ok(tls) = self.peer.clone().try_cast::<StreamPeerTls>() {
+            return tls.get_status() == stream_peer_tls::Status::HANDSHAKING;
+        }
+
+        false
+    }
+
+    fn is_network_peer(&self) -> bool {
+        self.peer.is_class("StreamPeerTCP") || self.peer.is_class("StreamPeerTLS")
+    }
+}
+
+impl Read for GStream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let connected = self.poll()?;
+
+        let available = self.peer.get_available_bytes().max(0) as usize;
+        if available == 0 {
+            return if connected && self.is_network_peer() {
+                Err(ErrorKind::WouldBlock.into())
+            } else {
+                Ok(0)
+            };
+        }
+
+        let requested = buf.len().min(available);
+        let result = self.peer.get_partial_data(requested as i32);
+        let (error, data) = unpack_result::<PackedByteArray>(&result);
+        check_error(error)?;
+
+        let data = data.as_slice();
+        buf[..data.len()].copy_from_slice(data);
+
+        Ok(data.len())
+    }
+}
+
+impl Write for GStream {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        self.poll()?;
+
+        // Godot reports writes on not-yet-connected peers as errors, but they are only temporarily unavailable.
+        if self.is_connecting() {
+            return Err(ErrorKind::WouldBlock.into());
+        }
+
+        let result = self.peer.put_partial_data(&PackedByteArray::from(buf));
+        let (error, sent) = unpack_result::<i64>(&result);
+        check_error(error)?;
+
+        match sent {
+            0 => Err(ErrorKind::WouldBlock.into()),
+            sent => Ok(sent as usize),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        // Data is handed to the OS immediately; there is no buffer to flush.
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Datagram-based communication over a Godot [`PacketPeer`], e.g. [`PacketPeerUdp`].
+///
+/// Each [`send()`][Self::send] transmits one packet, and each [`recv()`][Self::recv] returns at most one whole packet. Receiving never
+/// blocks.
+///
+/// ## Example
+/// ```no_run
+/// use godot::tools::GPacketPeer;
+///
+/// let mut server = GPacketPeer::bind_udp(4242).unwrap();
+/// let mut client = GPacketPeer::connect_udp("127.0.0.1", 4242).unwrap();
+///
+/// client.send(b"hello").unwrap();
+///
+/// // Later, e.g. in the next frame:
+/// if let Some(packet) = server.recv() {
+///     println!("received {packet:?}");
+/// }
+/// ```
+pub struct GPacketPeer {
+    peer: Gd<PacketPeer>,
+}
+
+impl GPacketPeer {
+    /// Wraps an existing packet peer.
+    pub fn new<T: Inherits<PacketPeer>>(peer: Gd<T>) -> Self {
+        Self {
+            peer: peer.upcast(),
+        }
+    }
+
+    /// Creates a UDP peer listening on `port` of all local addresses.
+    pub fn bind_udp(port: u16) -> std::io::Result<Self> {
+        let mut peer = PacketPeerUdp::new_gd();
+        check_error(peer.bind(port as i32))?;
+
+        Ok(Self::new(peer))
+    }
+
+    /// Creates a UDP peer which sends packets to `host` and `port`.
+    ///
+    /// `host` must be an IP address; use `IP.resolve_hostname()` to resolve host names first.
+    pub fn connect_udp(host: impl AsArg<GString>, port: u16) -> std::io::Result<Self> {
+        arg_into_ref!(host);
+
+        let mut peer = PacketPeerUdp::new_gd();
+        check_error(peer.set_dest_address(host, port as i32))?;
+
+        Ok(Self::new(peer))
+    }
+
+    /// The wrapped packet peer.
+    pub fn peer(&self) -> &Gd<PacketPeer> {
+        &self.peer
+    }
+
+    /// Returns the wrapped packet peer, consuming the `GPacketPeer`.
+    pub fn into_inner(self) -> Gd<PacketPeer> {
+        self.peer
+    }
+
+    /// Sends `data` as a single packet.
+    pub fn send(&mut self, data: &[u8]) -> std::io::Result<()> {
+        check_error(self.peer.put_packet(&PackedByteArray::from(data)))
+    }
+
+    /// Returns the next received packet, or `Ok(None)` if there is none.
+    pub fn try_recv(&mut self) -> std::io::Result<Option<Vec<u8>>> {
+        if self.peer.get_available_packet_count() <= 0 {
+            return Ok(None);
+        }
+
+        let packet = self.peer.get_packet();
+        check_error(self.peer.get_packet_error())?;
+
+        Ok(Some(packet.to_vec()))
+    }
+
+    /// Returns the next received packet, or `None` if there is none.
+    ///
+    /// Also returns `None` if receiving the next packet fails, even if further packets are available. Use [`try_recv()`][Self::try_recv]
+    /// to tell errors apart from an empty queue.
+    pub fn recv(&mut self) -> Option<Vec<u8>> {
+        self.try_recv().ok().flatten()
+    }
+
+    /// For UDP peers, the address and port from which the last received packet was sent.
+    pub fn last_sender(&self) -> Option<(GString, u16)> {
+        let udp = self.peer.clone().try_cast::<PacketPeerUdp>().ok()?;
+
+        Some((udp.get_packet_ip(), udp.get_packet_port() as u16))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Maps an engine error to a `std::io::Error`, keeping the Godot error in the message.
+fn check_error(error: Error) -> std::io::Result<()> {
+    let kind = match error {
+        Error::OK => return Ok(()),
+        Error::ERR_BUSY => ErrorKind::WouldBlock,
+        Error::ERR_CANT_CONNECT => ErrorKind::ConnectionRefused,
+        Error::ERR_CONNECTION_ERROR => ErrorKind::ConnectionReset,
+        Error::ERR_TIMEOUT => ErrorKind::TimedOut,
+        Error::ERR_ALREADY_IN_USE => ErrorKind::AddrInUse,
+        Error::ERR_CANT_RESOLVE => ErrorKind::AddrNotAvailable,
+        Error::ERR_INVALID_PARAMETER => ErrorKind::InvalidInput,
+        Error::ERR_OUT_OF_MEMORY => ErrorKind::OutOfMemory,
+        Error::ERR_FILE_EOF => ErrorKind::UnexpectedEof,
+        Error::ERR_UNCONFIGURED => ErrorKind::NotConnected,
+        _ => ErrorKind::Other,
+    };
+
+    Err(std::io::Error::new(kind, format!("GodotError: {error:?}")))
+}
+
+/// Splits the `[Error, value]` arrays returned by `StreamPeer::get_partial_data()` and `put_partial_data()`.
+fn unpack_result<T: FromGodot + Default>(result: &VariantArray) -> (Error, T) {
+    let error = result
+        .get(0)
+        .and_then(|error| error.try_to::<Error>().ok())
+        .unwrap_or(Error::FAILED);
+
+    let value = result
+        .get(1)
+        .and_then(|value| value.try_to::<T>().ok())
+        .unwrap_or_default();
+
+    (error, value)
+}
@@ -0,0 +1,93 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::classes::Node;
+use crate::obj::{Inherits, WithBaseField};
+
+/// Processing toggles of [`Node`], callable directly on user classes.
+///
+/// Implemented for every user class with a `Base<T>` field, where `T` is `Node` or derived from it. This allows writing
+/// `self.set_process(false)` instead of `self.base_mut().set_process(false)`. The trait is part of the prelude.
+///
+/// # Borrowing
+/// Each method goes through [`base_mut()`][WithBaseField::base_mut] (setters) or [`base()`][WithBaseField::base] (getters) for the
+/// duration of the engine call. Neither of them binds the object again, so they are safe to call from any `&mut self` method, including
+/// `ready()` or a `#[func]` invoked by Godot. No guard is held afterwards, either.
+///
+/// From outside the object, use the methods of [`Gd<Node>`][crate::obj::Gd] directly, e.g. `gd.set_process(false)`. No `bind_mut()` is
+/// necessary there; binding would only lock the Rust instance, which these engine methods don't access.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base = Node)]
+/// struct Turret {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Turret {
+///     fn ready(&mut self) {
+///         // Only processes once activated.
+///         self.set_process(false);
+///         self.set_physics_process(false);
+///     }
+/// }
+/// ```
+pub trait NodeProcessing: WithBaseField
+where
+    Self::Base: Inherits<Node>,
+{
+    /// Enables or disables `process()` and the corresponding notification.
+    ///
+    /// See [`Node::set_process()`].
+    fn set_process(&mut self, enable: bool) {
+        self.base_mut().upcast_mut::<Node>().set_process(enable);
+    }
+
+    /// Enables or disables `physics_process()` and the corresponding notification.
+    ///
+    /// See [`Node::set_physics_process()`].
+    fn set_physics_process(&mut self, enable: bool) {
+        self.base_mut()
+            .upcast_mut::<Node>()
+            .set_physics_process(enable);
+    }
+
+    /// Enables or disables `input()`.
+    ///
+    /// See [`Node::set_process_input()`].
+    fn set_process_input(&mut self, enable: bool) {
+        self.base_mut()
+            .upcast_mut::<Node>()
+            .set_process_input(enable);
+    }
+
+    /// Whether `process()` is called every frame.
+    fn is_processing(&self) -> bool {
+        self.base().upcast_ref::<Node>().is_processing()
+    }
+
+    /// Whether `physics_process()` is called every physics frame.
+    fn is_physics_processing(&self) -> bool {
+        self.base().upcast_ref::<Node>().is_physics_processing()
+    }
+
+    /// Whether `input()` is called for input events.
+    fn is_processing_input(&self) -> bool {
+        self.base().upcast_ref::<Node>().is_processing_input()
+    }
+}
+
+impl<T> NodeProcessing for T
+where
+    T: WithBaseField,
+    T::Base: Inherits<Node>,
+{
+}
@@ -0,0 +1,281 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Support for implementing custom 3D physics backends, via `IPhysicsServer3DExtension` and `IPhysicsDirectSpaceState3DExtension`.
+//!
+//! The query virtuals of `IPhysicsDirectSpaceState3DExtension` report results through raw pointers to native structures. The result types
+//! of the [typed queries][crate::tools::RayQuery3D] can be written to those pointers, so the same types are used on both sides.
+
+use std::ptr;
+
+#[cfg(since_api = "4.2")]
+use crate::builtin::{Callable, Variant};
+use crate::builtin::{Rid, Vector3};
+use crate::classes::native::{
+    ObjectId, PhysicsServer3DExtensionRayResult, PhysicsServer3DExtensionShapeRestInfo,
+    PhysicsServer3DExtensionShapeResult,
+};
+#[cfg(since_api = "4.2")]
+use crate::classes::{PhysicsServer3DExtension, PhysicsServer3DManager};
+#[cfg(since_api = "4.2")]
+use crate::meta::ToGodot;
+use crate::obj::InstanceId;
+#[cfg(since_api = "4.2")]
+use crate::obj::{Inherits, NewAlloc};
+use crate::tools::{ColliderHit, RayHit3D, ShapeCast};
+
+impl RayHit3D {
+    /// Writes this hit to the `result` out-parameter of `IPhysicsDirectSpaceState3DExtension::intersect_ray()`.
+    ///
+    /// A `face_index` of `None` is reported as `-1`.
+    ///
+    /// # Safety
+    /// `out` must be valid for writes, as is the case for the pointer passed by Godot.
+    ///
+    /// # Panics
+    /// If `collider` is no longer alive.
+    pub unsafe fn write_to(&self, out: *mut PhysicsServer3DExtensionRayResult) {
+        let mut result = PhysicsServer3DExtensionRayResult {
+            position: self.position,
+            normal: self.normal,
+            rid: self.rid,
+            collider_id: ObjectId { id: 0 },
+            raw_collider_ptr: ptr::null_mut(),
+            shape: self.shape_idx,
+            #[cfg(since_api = "4.1")]
+            face_index: self.face_index.unwrap_or(-1),
+        };
+        result.set_collider(self.collider.clone());
+
+        out.write(result);
+    }
+}
+
+impl ColliderHit {
+    /// Writes this hit to a single element of the `results` out-parameter of `IPhysicsDirectSpaceState3DExtension::intersect_point()`
+    /// or `intersect_shape()`.
+    ///
+    /// Use [`ShapeResultWriter`] to fill the whole array.
+    ///
+    /// # Safety
+    /// `out` must be valid for writes.
+    ///
+    /// # Panics
+    /// If `collider` is no longer alive.
+    pub unsafe fn write_to(&self, out: *mut PhysicsServer3DExtensionShapeResult) {
+        let mut result = PhysicsServer3DExtensionShapeResult {
+            rid: self.rid,
+            collider_id: ObjectId { id: 0 },
+            raw_collider_ptr: ptr::null_mut(),
+            shape: self.shape_idx,
+        };
+        result.set_collider(self.collider.clone());
+
+        out.write(result);
+    }
+}
+
+impl ShapeCast {
+    /// Writes the fractions to the `closest_safe` and `closest_unsafe` out-parameters of
+    /// `IPhysicsDirectSpaceState3DExtension::cast_motion()`.
+    ///
+    /// Null pointers are skipped.
+    ///
+    /// # Safety
+    /// Each pointer must be null or valid for writes.
+    pub unsafe fn write_to(&self, closest_safe: *mut f32, closest_unsafe: *mut f32) {
+        if let Some(safe) = closest_safe.as_mut() {
+            *safe = self.safe_fraction;
+        }
+        if let Some(unsafe_) = closest_unsafe.as_mut() {
+            *unsafe_ = self.unsafe_fraction;
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Contact information of a shape at rest, reported by `IPhysicsDirectSpaceState3DExtension::rest_info()` and `cast_motion()`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ShapeRestInfo3D {
+    /// Contact point, in global coordinates.
+    pub point: Vector3,
+    /// Surface normal of the other object at the contact point.
+    pub normal: Vector3,
+    /// RID of the other collision object.
+    pub rid: Rid,
+    /// Instance ID of the object that the other collision object is attached to, if any.
+    pub collider_id: Option<InstanceId>,
+    /// Index of the shape within the other collision object.
+    pub shape_idx: i32,
+    /// Velocity of the other object at the contact point.
+    pub linear_velocity: Vector3,
+}
+
+impl ShapeRestInfo3D {
+    /// Writes the rest info to the out-parameter of `IPhysicsDirectSpaceState3DExtension::rest_info()` or `cast_motion()`.
+    ///
+    /// Does nothing if `out` is null, which Godot passes to `cast_motion()` when the info is not needed.
+    ///
+    /// # Safety
+    /// `out` must be null or valid for writes.
+    pub unsafe fn write_to(&self, out: *mut PhysicsServer3DExtensionShapeRestInfo) {
+        if out.is_null() {
+            return;
+        }
+
+        out.write(PhysicsServer3DExtensionShapeRestInfo {
+            point: self.point,
+            normal: self.normal,
+            rid: self.rid,
+            collider_id: ObjectId {
+                id: self.collider_id.map_or(0, InstanceId::to_u64),
+            },
+            shape: self.shape_idx,
+            linear_velocity: self.linear_velocity,
+        });
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Fills the `results` array of `IPhysicsDirectSpaceState3DExtension::intersect_point()` and `intersect_shape()`.
+///
+/// Godot passes a pointer to an array with room for `max_results` entries, and expects the number of written entries as return value.
+///
+/// # Example
+/// ```no_run
+/// # use godot::classes::native::PhysicsServer3DExtensionShapeResult;
+/// use godot::tools::{ColliderHit, ShapeResultWriter};
+///
+/// # fn find_hits() -> Vec<ColliderHit> { unimplemented!() }
+/// // Inside `unsafe fn intersect_point(..., results: *mut PhysicsServer3DExtensionShapeResult, max_results: i32) -> i32`:
+/// # unsafe fn f(results: *mut PhysicsServer3DExtensionShapeResult, max_results: i32) -> i32 {
+/// let mut writer = ShapeResultWriter::new(results, max_results);
+/// for hit in find_hits() {
+///     if !writer.push(&hit) {
+///         break;
+///     }
+/// }
+/// writer.count()
+/// # }
+/// ```
+pub struct ShapeResultWriter {
+    results: *mut PhysicsServer3DExtensionShapeResult,
+    capacity: usize,
+    len: usize,
+}
+
+impl ShapeResultWriter {
+    /// Starts writing to `results`, which has room for `max_results` entries.
+    ///
+    /// # Safety
+    /// `results` must be valid for writes of `max_results` consecutive entries, as long as the writer is in use.
+    pub unsafe fn new(results: *mut PhysicsServer3DExtensionShapeResult, max_results: i32) -> Self {
+        let capacity = if results.is_null() {
+            0
+        } else {
+            usize::try_from(max_results).unwrap_or(0)
+        };
+
+        Self {
+            results,
+            capacity,
+            len: 0,
+        }
+    }
+
+    /// Writes the next entry. Returns `false` if the array is full, in which case nothing is written.
+    ///
+    /// # Panics
+    /// If `hit.collider` is no longer alive.
+    pub fn push(&mut self, hit: &ColliderHit) -> bool {
+        if self.is_full() {
+            return false;
+        }
+
+        // SAFETY: len < capacity, and new() requires that capacity entries are writable.
+        unsafe { hit.write_to(self.results.add(self.len)) };
+        self.len += 1;
+        true
+    }
+
+    /// Returns `true` if no more entries can be written.
+    pub fn is_full(&self) -> bool {
+        self.len >= self.capacity
+    }
+
+    /// Number of written entries, to be returned from the virtual method.
+    pub fn count(&self) -> i32 {
+        self.len as i32
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Registers the Rust class `T` as a 3D physics engine named `name`.
+///
+/// The engine can then be selected with the project setting `physics/3d/physics_engine` or the `--physics-engine` command-line argument.
+/// Godot creates the server on startup, so this must be called during [`InitLevel::Servers`][crate::init::InitLevel::Servers], e.g. in
+/// [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init].
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::PhysicsServer3DExtension;
+/// use godot::prelude::*;
+/// use godot::tools::register_physics_server_3d;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base = PhysicsServer3DExtension)]
+/// struct MyPhysicsServer {
+///     base: Base<PhysicsServer3DExtension>,
+/// }
+///
+/// // #[godot_api] impl IPhysicsServer3DExtension for MyPhysicsServer { ... }
+///
+/// struct MyExtension;
+///
+/// #[gdextension]
+/// unsafe impl ExtensionLibrary for MyExtension {
+///     fn on_level_init(level: InitLevel) {
+///         if level == InitLevel::Servers {
+///             register_physics_server_3d::<MyPhysicsServer>("MyPhysics");
+///         }
+///     }
+/// }
+/// ```
+#[cfg(since_api = "4.2")]
+pub fn register_physics_server_3d<T>(name: &str)
+where
+    T: Inherits<PhysicsServer3DExtension> + NewAlloc,
+{
+    let factory = make_physics_server_3d_factory::<T>(name);
+
+    PhysicsServer3DManager::singleton().register_server(name, &factory);
+}
+
+/// Creates the callable which Godot invokes to instantiate the server, returning a `Gd<PhysicsServer3DExtension>`.
+#[cfg(since_api = "4.2")]
+fn make_physics_server_3d_factory<T>(name: &str) -> Callable
+where
+    T: Inherits<PhysicsServer3DExtension> + NewAlloc,
+{
+    Callable::from_local_fn(&format!("create {name}"), |_args: &[&Variant]| {
+        Ok(T::new_alloc()
+            .upcast::<PhysicsServer3DExtension>()
+            .to_variant())
+    })
+}
+
+/// For itest: the factory that [`register_physics_server_3d()`] passes to Godot, which only calls it on startup.
+#[cfg(all(since_api = "4.2", feature = "trace"))]
+pub fn create_test_physics_server_3d_factory<T>(name: &str) -> Callable
+where
+    T: Inherits<PhysicsServer3DExtension> + NewAlloc,
+{
+    make_physics_server_3d_factory::<T>(name)
+}
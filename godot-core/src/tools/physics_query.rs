@@ -0,0 +1,508 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Typed wrappers around the queries of `PhysicsDirectSpaceState2D` and `PhysicsDirectSpaceState3D`.
+//!
+//! Godot returns query results as `Dictionary` objects. Here, they are converted to structs; keys that are missing (e.g. `face_index` in
+//! older Godot versions) fall back to default values.
+
+use crate::builtin::{Array, Dictionary, Rid, Transform2D, Transform3D, Vector2, Vector3};
+use crate::classes::{
+    Object, PhysicsDirectSpaceState2D, PhysicsDirectSpaceState3D, PhysicsPointQueryParameters2D,
+    PhysicsPointQueryParameters3D, PhysicsRayQueryParameters2D, PhysicsRayQueryParameters3D,
+    PhysicsShapeQueryParameters2D, PhysicsShapeQueryParameters3D,
+};
+use crate::meta::FromGodot;
+use crate::obj::{Gd, InstanceId, NewGd};
+
+/// Ray from `from` to `to`, for [`PhysicsDirectSpaceState3D::cast_ray()`].
+///
+/// Construct with struct update syntax, e.g. `RayQuery3D { from, to, ..Default::default() }`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RayQuery3D {
+    pub from: Vector3,
+    pub to: Vector3,
+    /// Physics layers that the ray detects. Default: all layers.
+    pub collision_mask: u32,
+    /// Objects to ignore, e.g. `vec![player.get_rid()]` for a `CollisionObject3D`.
+    pub exclude: Vec<Rid>,
+    /// Default: `true`.
+    pub collide_with_bodies: bool,
+    /// Default: `false`.
+    pub collide_with_areas: bool,
+    /// Whether a ray starting inside a shape reports a hit, with zero normal. Default: `false`.
+    pub hit_from_inside: bool,
+    /// Whether back faces of concave shapes are hit. Default: `true`.
+    pub hit_back_faces: bool,
+}
+
+impl Default for RayQuery3D {
+    fn default() -> Self {
+        Self {
+            from: Vector3::ZERO,
+            to: Vector3::ZERO,
+            collision_mask: u32::MAX,
+            exclude: Vec::new(),
+            collide_with_bodies: true,
+            collide_with_areas: false,
+            hit_from_inside: false,
+            hit_back_faces: true,
+        }
+    }
+}
+
+/// Ray from `from` to `to`, for [`PhysicsDirectSpaceState2D::cast_ray()`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct RayQuery2D {
+    pub from: Vector2,
+    pub to: Vector2,
+    /// Physics layers that the ray detects. Default: all layers.
+    pub collision_mask: u32,
+    /// Objects to ignore, e.g. `vec![player.get_rid()]` for a `CollisionObject2D`.
+    pub exclude: Vec<Rid>,
+    /// Default: `true`.
+    pub collide_with_bodies: bool,
+    /// Default: `false`.
+    pub collide_with_areas: bool,
+    /// Whether a ray starting inside a shape reports a hit, with zero normal. Default: `false`.
+    pub hit_from_inside: bool,
+}
+
+impl Default for RayQuery2D {
+    fn default() -> Self {
+        Self {
+            from: Vector2::ZERO,
+            to: Vector2::ZERO,
+            collision_mask: u32::MAX,
+            exclude: Vec::new(),
+            collide_with_bodies: true,
+            collide_with_areas: false,
+            hit_from_inside: false,
+        }
+    }
+}
+
+/// Shape placed at `transform` and optionally moved by `motion`, for shape queries on [`PhysicsDirectSpaceState3D`].
+///
+/// `shape_rid` is obtained from a shape resource via `Shape3D::get_rid()`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ShapeQuery3D {
+    pub shape_rid: Rid,
+    pub transform: Transform3D,
+    /// Motion for [`cast_shape()`][PhysicsDirectSpaceState3D::cast_shape]. Ignored by other queries.
+    pub motion: Vector3,
+    pub margin: f32,
+    /// Physics layers that the shape detects. Default: all layers.
+    pub collision_mask: u32,
+    pub exclude: Vec<Rid>,
+    /// Default: `true`.
+    pub collide_with_bodies: bool,
+    /// Default: `false`.
+    pub collide_with_areas: bool,
+}
+
+impl Default for ShapeQuery3D {
+    fn default() -> Self {
+        Self {
+            shape_rid: Rid::Invalid,
+            transform: Transform3D::IDENTITY,
+            motion: Vector3::ZERO,
+            margin: 0.0,
+            collision_mask: u32::MAX,
+            exclude: Vec::new(),
+            collide_with_bodies: true,
+            collide_with_areas: false,
+        }
+    }
+}
+
+/// Shape placed at `transform` and optionally moved by `motion`, for shape queries on [`PhysicsDirectSpaceState2D`].
+///
+/// `shape_rid` is obtained from a shape resource via `Shape2D::get_rid()`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ShapeQuery2D {
+    pub shape_rid: Rid,
+    pub transform: Transform2D,
+    /// Motion for [`cast_shape()`][PhysicsDirectSpaceState2D::cast_shape]. Ignored by other queries.
+    pub motion: Vector2,
+    pub margin: f32,
+    /// Physics layers that the shape detects. Default: all layers.
+    pub collision_mask: u32,
+    pub exclude: Vec<Rid>,
+    /// Default: `true`.
+    pub collide_with_bodies: bool,
+    /// Default: `false`.
+    pub collide_with_areas: bool,
+}
+
+impl Default for ShapeQuery2D {
+    fn default() -> Self {
+        Self {
+            shape_rid: Rid::Invalid,
+            transform: Transform2D::IDENTITY,
+            motion: Vector2::ZERO,
+            margin: 0.0,
+            collision_mask: u32::MAX,
+            exclude: Vec::new(),
+            collide_with_bodies: true,
+            collide_with_areas: false,
+        }
+    }
+}
+
+/// Point in global coordinates, for [`PhysicsDirectSpaceState3D::intersect_point()`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct PointQuery3D {
+    pub position: Vector3,
+    /// Physics layers that the point detects. Default: all layers.
+    pub collision_mask: u32,
+    pub exclude: Vec<Rid>,
+    /// Default: `true`.
+    pub collide_with_bodies: bool,
+    /// Default: `false`.
+    pub collide_with_areas: bool,
+}
+
+impl Default for PointQuery3D {
+    fn default() -> Self {
+        Self {
+            position: Vector3::ZERO,
+            collision_mask: u32::MAX,
+            exclude: Vec::new(),
+            collide_with_bodies: true,
+            collide_with_areas: false,
+        }
+    }
+}
+
+/// Point in global coordinates, for [`PhysicsDirectSpaceState2D::intersect_point()`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct PointQuery2D {
+    pub position: Vector2,
+    /// Physics layers that the point detects. Default: all layers.
+    pub collision_mask: u32,
+    pub exclude: Vec<Rid>,
+    /// Default: `true`.
+    pub collide_with_bodies: bool,
+    /// Default: `false`.
+    pub collide_with_areas: bool,
+}
+
+impl Default for PointQuery2D {
+    fn default() -> Self {
+        Self {
+            position: Vector2::ZERO,
+            collision_mask: u32::MAX,
+            exclude: Vec::new(),
+            collide_with_bodies: true,
+            collide_with_areas: false,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Results
+
+/// Result of [`PhysicsDirectSpaceState3D::cast_ray()`].
+#[derive(Clone, Debug)]
+pub struct RayHit3D {
+    /// Intersection point, in global coordinates.
+    pub position: Vector3,
+    /// Surface normal at the intersection point; zero if the ray started inside the shape.
+    pub normal: Vector3,
+    pub collider: Gd<Object>,
+    pub collider_id: InstanceId,
+    /// RID of the intersected collision object.
+    pub rid: Rid,
+    /// Index of the intersected shape within the collision object.
+    pub shape_idx: i32,
+    /// Face index for `ConcavePolygonShape3D`, otherwise `None`. Also `None` if Godot does not report it.
+    pub face_index: Option<i32>,
+}
+
+/// Result of [`PhysicsDirectSpaceState2D::cast_ray()`].
+#[derive(Clone, Debug)]
+pub struct RayHit2D {
+    /// Intersection point, in global coordinates.
+    pub position: Vector2,
+    /// Surface normal at the intersection point; zero if the ray started inside the shape.
+    pub normal: Vector2,
+    pub collider: Gd<Object>,
+    pub collider_id: InstanceId,
+    /// RID of the intersected collision object.
+    pub rid: Rid,
+    /// Index of the intersected shape within the collision object.
+    pub shape_idx: i32,
+}
+
+/// Collision object found by `intersect_shape()` or `intersect_point()`, in 2D and 3D.
+#[derive(Clone, Debug)]
+pub struct ColliderHit {
+    pub collider: Gd<Object>,
+    pub collider_id: InstanceId,
+    /// RID of the collision object.
+    pub rid: Rid,
+    /// Index of the shape within the collision object.
+    pub shape_idx: i32,
+}
+
+/// Result of `cast_shape()`, in 2D and 3D: how far along its motion a shape can move.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ShapeCast {
+    /// Fraction of the motion (between 0 and 1) that can be travelled without collision.
+    pub safe_fraction: f32,
+    /// Fraction of the motion at which a collision first occurs.
+    pub unsafe_fraction: f32,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Manual extensions for the space state classes
+
+impl PhysicsDirectSpaceState3D {
+    /// Casts a ray and returns the closest hit, if any. Typed version of `intersect_ray()`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::prelude::*;
+    /// use godot::tools::RayQuery3D;
+    ///
+    /// fn ground_below(node: &Gd<Node3D>) -> Option<Vector3> {
+    ///     let mut space = node.get_world_3d()?.get_direct_space_state()?;
+    ///     let from = node.get_global_position();
+    ///
+    ///     let hit = space.cast_ray(&RayQuery3D {
+    ///         from,
+    ///         to: from + Vector3::DOWN * 100.0,
+    ///         ..Default::default()
+    ///     })?;
+    ///     Some(hit.position)
+    /// }
+    /// ```
+    pub fn cast_ray(&mut self, query: &RayQuery3D) -> Option<RayHit3D> {
+        let mut params = PhysicsRayQueryParameters3D::new_gd();
+        params.set_from(query.from);
+        params.set_to(query.to);
+        params.set_collision_mask(query.collision_mask);
+        params.set_exclude(&rid_array(&query.exclude));
+        params.set_collide_with_bodies(query.collide_with_bodies);
+        params.set_collide_with_areas(query.collide_with_areas);
+        params.set_hit_from_inside(query.hit_from_inside);
+        params.set_hit_back_faces(query.hit_back_faces);
+
+        let result = self.intersect_ray(&params);
+        let collider = parse_collider(&result)?;
+
+        Some(RayHit3D {
+            position: get_or_default(&result, "position"),
+            normal: get_or_default(&result, "normal"),
+            collider: collider.collider,
+            collider_id: collider.collider_id,
+            rid: collider.rid,
+            shape_idx: collider.shape_idx,
+            face_index: get(&result, "face_index").filter(|&index: &i32| index >= 0),
+        })
+    }
+
+    /// Moves the shape along `query.motion` and returns where it first collides, or `None` if the whole motion is free.
+    ///
+    /// Typed version of `cast_motion()`.
+    pub fn cast_shape(&mut self, query: &ShapeQuery3D) -> Option<ShapeCast> {
+        let params = shape_params_3d(query);
+        parse_shape_cast(self.cast_motion(&params).as_slice())
+    }
+
+    /// Returns up to `max_results` objects that the shape intersects. Typed version of `intersect_shape()`.
+    pub fn intersect_shape_typed(
+        &mut self,
+        query: &ShapeQuery3D,
+        max_results: usize,
+    ) -> Vec<ColliderHit> {
+        let params = shape_params_3d(query);
+        let results = self
+            .intersect_shape_ex(&params)
+            .max_results(to_i32(max_results))
+            .done();
+
+        parse_collider_array(&results)
+    }
+
+    /// Returns up to `max_results` objects that contain the point. Typed version of `intersect_point()`.
+    pub fn intersect_point_typed(
+        &mut self,
+        query: &PointQuery3D,
+        max_results: usize,
+    ) -> Vec<ColliderHit> {
+        let mut params = PhysicsPointQueryParameters3D::new_gd();
+        params.set_position(query.position);
+        params.set_collision_mask(query.collision_mask);
+        params.set_exclude(&rid_array(&query.exclude));
+        params.set_collide_with_bodies(query.collide_with_bodies);
+        params.set_collide_with_areas(query.collide_with_areas);
+
+        let results = self
+            .intersect_point_ex(&params)
+            .max_results(to_i32(max_results))
+            .done();
+
+        parse_collider_array(&results)
+    }
+}
+
+impl PhysicsDirectSpaceState2D {
+    /// Casts a ray and returns the closest hit, if any. Typed version of `intersect_ray()`.
+    pub fn cast_ray(&mut self, query: &RayQuery2D) -> Option<RayHit2D> {
+        let mut params = PhysicsRayQueryParameters2D::new_gd();
+        params.set_from(query.from);
+        params.set_to(query.to);
+        params.set_collision_mask(query.collision_mask);
+        params.set_exclude(&rid_array(&query.exclude));
+        params.set_collide_with_bodies(query.collide_with_bodies);
+        params.set_collide_with_areas(query.collide_with_areas);
+        params.set_hit_from_inside(query.hit_from_inside);
+
+        let result = self.intersect_ray(&params);
+        let collider = parse_collider(&result)?;
+
+        Some(RayHit2D {
+            position: get_or_default(&result, "position"),
+            normal: get_or_default(&result, "normal"),
+            collider: collider.collider,
+            collider_id: collider.collider_id,
+            rid: collider.rid,
+            shape_idx: collider.shape_idx,
+        })
+    }
+
+    /// Moves the shape along `query.motion` and returns where it first collides, or `None` if the whole motion is free.
+    ///
+    /// Typed version of `cast_motion()`.
+    pub fn cast_shape(&mut self, query: &ShapeQuery2D) -> Option<ShapeCast> {
+        let params = shape_params_2d(query);
+        parse_shape_cast(self.cast_motion(&params).as_slice())
+    }
+
+    /// Returns up to `max_results` objects that the shape intersects. Typed version of `intersect_shape()`.
+    pub fn intersect_shape_typed(
+        &mut self,
+        query: &ShapeQuery2D,
+        max_results: usize,
+    ) -> Vec<ColliderHit> {
+        let params = shape_params_2d(query);
+        let results = self
+            .intersect_shape_ex(&params)
+            .max_results(to_i32(max_results))
+            .done();
+
+        parse_collider_array(&results)
+    }
+
+    /// Returns up to `max_results` objects that contain the point. Typed version of `intersect_point()`.
+    pub fn intersect_point_typed(
+        &mut self,
+        query: &PointQuery2D,
+        max_results: usize,
+    ) -> Vec<ColliderHit> {
+        let mut params = PhysicsPointQueryParameters2D::new_gd();
+        params.set_position(query.position);
+        params.set_collision_mask(query.collision_mask);
+        params.set_exclude(&rid_array(&query.exclude));
+        params.set_collide_with_bodies(query.collide_with_bodies);
+        params.set_collide_with_areas(query.collide_with_areas);
+
+        let results = self
+            .intersect_point_ex(&params)
+            .max_results(to_i32(max_results))
+            .done();
+
+        parse_collider_array(&results)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+fn shape_params_3d(query: &ShapeQuery3D) -> Gd<PhysicsShapeQueryParameters3D> {
+    let mut params = PhysicsShapeQueryParameters3D::new_gd();
+    params.set_shape_rid(query.shape_rid);
+    params.set_transform(query.transform);
+    params.set_motion(query.motion);
+    params.set_margin(query.margin);
+    params.set_collision_mask(query.collision_mask);
+    params.set_exclude(&rid_array(&query.exclude));
+    params.set_collide_with_bodies(query.collide_with_bodies);
+    params.set_collide_with_areas(query.collide_with_areas);
+    params
+}
+
+fn shape_params_2d(query: &ShapeQuery2D) -> Gd<PhysicsShapeQueryParameters2D> {
+    let mut params = PhysicsShapeQueryParameters2D::new_gd();
+    params.set_shape_rid(query.shape_rid);
+    params.set_transform(query.transform);
+    params.set_motion(query.motion);
+    params.set_margin(query.margin);
+    params.set_collision_mask(query.collision_mask);
+    params.set_exclude(&rid_array(&query.exclude));
+    params.set_collide_with_bodies(query.collide_with_bodies);
+    params.set_collide_with_areas(query.collide_with_areas);
+    params
+}
+
+fn rid_array(rids: &[Rid]) -> Array<Rid> {
+    rids.iter().copied().collect()
+}
+
+fn to_i32(max_results: usize) -> i32 {
+    i32::try_from(max_results).unwrap_or(i32::MAX)
+}
+
+/// Reads the keys shared by all query results. Returns `None` for empty results, or if the collider cannot be determined.
+fn parse_collider(result: &Dictionary) -> Option<ColliderHit> {
+    if result.is_empty() {
+        return None;
+    }
+
+    let collider_id = get::<i64>(result, "collider_id").and_then(InstanceId::try_from_i64);
+
+    // The collider is null if the object has been freed in the meantime; fall back to the ID in case only one key is present.
+    let collider = get::<Gd<Object>>(result, "collider")
+        .or_else(|| collider_id.and_then(|id| Gd::try_from_instance_id(id).ok()))?;
+
+    Some(ColliderHit {
+        collider_id: collider_id.unwrap_or_else(|| collider.instance_id()),
+        collider,
+        rid: get(result, "rid").unwrap_or(Rid::Invalid),
+        shape_idx: get_or_default(result, "shape"),
+    })
+}
+
+fn parse_collider_array(results: &Array<Dictionary>) -> Vec<ColliderHit> {
+    results
+        .iter_shared()
+        .filter_map(|result| parse_collider(&result))
+        .collect()
+}
+
+fn parse_shape_cast(fractions: &[f32]) -> Option<ShapeCast> {
+    // Godot returns [1, 1] if there is no collision, and an empty array on invalid parameters.
+    match *fractions {
+        [safe_fraction, unsafe_fraction] if safe_fraction < 1.0 || unsafe_fraction < 1.0 => {
+            Some(ShapeCast {
+                safe_fraction,
+                unsafe_fraction,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn get<T: FromGodot>(dict: &Dictionary, key: &str) -> Option<T> {
+    dict.get(key).and_then(|value| value.try_to::<T>().ok())
+}
+
+fn get_or_default<T: FromGodot + Default>(dict: &Dictionary, key: &str) -> T {
+    get(dict, key).unwrap_or_default()
+}
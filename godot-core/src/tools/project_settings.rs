@@ -0,0 +1,19 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/// Where a custom project setting shows up in the editor's project settings dialog.
+///
+/// Used by [`ProjectSettings::register_setting()`][crate::classes::ProjectSettings::register_setting].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum SettingVisibility {
+    /// Always visible.
+    Basic,
+
+    /// Only visible when "Advanced Settings" is enabled in the dialog.
+    #[default]
+    Advanced,
+}
@@ -0,0 +1,261 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use crate::builtin::Rid;
+use crate::classes::RenderingServer;
+use crate::meta::sealed::Sealed;
+use crate::obj::Gd;
+
+/// Types that refer to a server object through a [`Rid`].
+///
+/// Implemented for `Rid` itself, [`TypedRid`] and [`OwnedRid`]. Raw server methods still take `Rid`, which can be obtained with
+/// `as_rid()`:
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::RenderingServer;
+/// use godot::tools::{AsRid, MeshRid};
+///
+/// let mesh = MeshRid::create();
+/// RenderingServer::singleton().mesh_clear(mesh.as_rid());
+/// ```
+pub trait AsRid {
+    /// Returns the untyped RID.
+    fn as_rid(&self) -> Rid;
+}
+
+impl AsRid for Rid {
+    fn as_rid(&self) -> Rid {
+        *self
+    }
+}
+
+/// Kind of `RenderingServer` object that a [`TypedRid`] refers to.
+///
+/// This trait is sealed; the kinds are [`MeshKind`], [`InstanceKind`], [`ScenarioKind`], [`CanvasItemKind`] and [`MaterialKind`].
+pub trait RenderingRidKind: Sealed + 'static {
+    /// Name used in `Debug` output, e.g. `"MeshRid"`.
+    const NAME: &'static str;
+
+    #[doc(hidden)]
+    fn create(server: &mut Gd<RenderingServer>) -> Rid;
+}
+
+macro_rules! impl_rid_kinds {
+    ($(
+        $( #[$attr:meta] )*
+        $Kind:ident => $Alias:ident, $create:ident;
+    )*) => {$(
+        #[doc = concat!("Marker for `RenderingServer` objects created with `", stringify!($create), "()`.")]
+        #[derive(Debug)]
+        pub enum $Kind {}
+
+        impl Sealed for $Kind {}
+
+        impl RenderingRidKind for $Kind {
+            const NAME: &'static str = stringify!($Alias);
+
+            fn create(server: &mut Gd<RenderingServer>) -> Rid {
+                server.$create()
+            }
+        }
+
+        $( #[$attr] )*
+        pub type $Alias = TypedRid<$Kind>;
+    )*};
+}
+
+impl_rid_kinds! {
+    /// RID of a mesh, created by `RenderingServer::mesh_create()`.
+    MeshKind => MeshRid, mesh_create;
+
+    /// RID of a 3D visual instance, created by `RenderingServer::instance_create()`.
+    InstanceKind => InstanceRid, instance_create;
+
+    /// RID of a 3D scenario (the rendering counterpart of a `World3D`), created by `RenderingServer::scenario_create()`.
+    ScenarioKind => ScenarioRid, scenario_create;
+
+    /// RID of a 2D canvas item, created by `RenderingServer::canvas_item_create()`.
+    CanvasItemKind => CanvasItemRid, canvas_item_create;
+
+    /// RID of a material, created by `RenderingServer::material_create()`.
+    MaterialKind => MaterialRid, material_create;
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// [`Rid`] of a `RenderingServer` object with known kind `K`.
+///
+/// Like `Rid`, this is a plain `Copy` handle; the server object is not freed automatically. Use [`free()`][Self::free], or
+/// [`OwnedRid`] for RAII management. Usually referred to by its aliases such as [`MeshRid`] or [`InstanceRid`].
+pub struct TypedRid<K: RenderingRidKind> {
+    rid: Rid,
+    _kind: PhantomData<K>,
+}
+
+impl<K: RenderingRidKind> TypedRid<K> {
+    /// Creates a new server object of kind `K`, e.g. with `RenderingServer::mesh_create()` for [`MeshRid`].
+    pub fn create() -> Self {
+        let rid = K::create(&mut RenderingServer::singleton());
+        Self::from_rid_unchecked(rid)
+    }
+
+    /// Wraps a RID that was obtained from a raw server method.
+    ///
+    /// The kind is not verified; passing a RID of another kind causes Godot errors in later server calls, but no UB.
+    pub fn from_rid_unchecked(rid: Rid) -> Self {
+        Self {
+            rid,
+            _kind: PhantomData,
+        }
+    }
+
+    /// Frees the server object, with `RenderingServer::free_rid()`.
+    ///
+    /// Copies of this handle become dangling; using them causes Godot errors, but no UB.
+    pub fn free(self) {
+        RenderingServer::singleton().free_rid(self.rid);
+    }
+
+    /// Returns the untyped RID.
+    pub fn rid(self) -> Rid {
+        self.rid
+    }
+}
+
+impl InstanceRid {
+    /// Creates an instance that renders `base` (e.g. a mesh) inside `scenario`, with `RenderingServer::instance_create2()`.
+    pub fn create_with(base: impl AsRid, scenario: ScenarioRid) -> Self {
+        let rid = RenderingServer::singleton().instance_create2(base.as_rid(), scenario.rid);
+        Self::from_rid_unchecked(rid)
+    }
+
+    /// Sets the object rendered by this instance, e.g. a [`MeshRid`].
+    pub fn set_base(self, base: impl AsRid) {
+        RenderingServer::singleton().instance_set_base(self.rid, base.as_rid());
+    }
+
+    /// Moves this instance into the given scenario.
+    pub fn set_scenario(self, scenario: ScenarioRid) {
+        RenderingServer::singleton().instance_set_scenario(self.rid, scenario.rid);
+    }
+}
+
+impl<K: RenderingRidKind> AsRid for TypedRid<K> {
+    fn as_rid(&self) -> Rid {
+        self.rid
+    }
+}
+
+impl<K: RenderingRidKind> From<TypedRid<K>> for Rid {
+    fn from(typed: TypedRid<K>) -> Self {
+        typed.rid
+    }
+}
+
+// Manual impls, as derives would require bounds on K.
+impl<K: RenderingRidKind> Clone for TypedRid<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: RenderingRidKind> Copy for TypedRid<K> {}
+
+impl<K: RenderingRidKind> PartialEq for TypedRid<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rid == other.rid
+    }
+}
+
+impl<K: RenderingRidKind> Eq for TypedRid<K> {}
+
+impl<K: RenderingRidKind> Hash for TypedRid<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rid.hash(state);
+    }
+}
+
+impl<K: RenderingRidKind> fmt::Debug for TypedRid<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({})", K::NAME, self.rid.to_u64())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Owning guard for a `RenderingServer` object, which is freed on drop.
+///
+/// Dereferences to the [`TypedRid`] handle, so typed helpers and [`AsRid`] are available directly.
+///
+/// # Example
+/// ```no_run
+/// use godot::tools::{InstanceRid, MeshKind, OwnedRid, ScenarioKind};
+///
+/// let scenario = OwnedRid::<ScenarioKind>::create();
+/// let mesh = OwnedRid::<MeshKind>::create();
+/// let instance = OwnedRid::new(InstanceRid::create_with(*mesh, *scenario));
+///
+/// // Freed in reverse order of declaration: instance, mesh, scenario.
+/// ```
+pub struct OwnedRid<K: RenderingRidKind> {
+    handle: TypedRid<K>,
+}
+
+impl<K: RenderingRidKind> OwnedRid<K> {
+    /// Creates a new server object of kind `K` and takes ownership of it.
+    pub fn create() -> Self {
+        Self::new(TypedRid::create())
+    }
+
+    /// Takes ownership of an existing server object.
+    pub fn new(handle: TypedRid<K>) -> Self {
+        Self { handle }
+    }
+
+    /// Returns the handle, without transferring ownership.
+    pub fn handle(&self) -> TypedRid<K> {
+        self.handle
+    }
+
+    /// Releases ownership; the server object is no longer freed on drop.
+    pub fn into_inner(self) -> TypedRid<K> {
+        let handle = self.handle;
+        std::mem::forget(self);
+        handle
+    }
+}
+
+impl<K: RenderingRidKind> Deref for OwnedRid<K> {
+    type Target = TypedRid<K>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+impl<K: RenderingRidKind> AsRid for OwnedRid<K> {
+    fn as_rid(&self) -> Rid {
+        self.handle.rid
+    }
+}
+
+impl<K: RenderingRidKind> Drop for OwnedRid<K> {
+    fn drop(&mut self) {
+        self.handle.free();
+    }
+}
+
+impl<K: RenderingRidKind> fmt::Debug for OwnedRid<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OwnedRid").field(&self.handle).finish()
+    }
+}
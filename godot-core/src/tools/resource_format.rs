@@ -0,0 +1,196 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::Variant;
+use crate::classes::{Resource, ResourceFormatLoader, ResourceLoader};
+use crate::global::Error as GodotError;
+use crate::meta::ToGodot;
+use crate::obj::{Gd, Inherits, InstanceId};
+use crate::sys::Global;
+
+#[cfg(feature = "codegen-full")]
+use crate::classes::{ResourceFormatSaver, ResourceSaver};
+
+/// Custom loaders and savers registered through this module, which are removed from the engine on `Scene` deinit.
+///
+/// Instance IDs instead of `Gd` pointers, as the latter are not `Send`. The engine keeps the objects alive while they are registered.
+#[derive(Default)]
+struct RegisteredFormats {
+    loaders: Vec<InstanceId>,
+    #[cfg(feature = "codegen-full")]
+    savers: Vec<InstanceId>,
+}
+
+static REGISTERED_FORMATS: Global<RegisteredFormats> = Global::default();
+
+/// Registers a custom [`ResourceFormatLoader`] with the engine.
+///
+/// After this call, [`load()`][crate::tools::load] and GDScript's `load()`/`preload()` can load files with the extensions recognized by
+/// `loader`. The loader is added at the front, i.e. it takes precedence over built-in loaders for the same extensions.
+///
+/// The loader is automatically unregistered when the `Scene` init level is unloaded. This is necessary for hot reloading: the engine must
+/// not keep objects of classes which are about to be unregistered. Call this function in
+/// [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init] at level [`InitLevel::Scene`][crate::init::InitLevel::Scene].
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::{IResourceFormatLoader, Resource, ResourceFormatLoader};
+/// use godot::global::Error;
+/// use godot::prelude::*;
+/// use godot::tools::{register_resource_format_loader, resource_load_result};
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=ResourceFormatLoader)]
+/// struct AtlasLoader {
+///     base: Base<ResourceFormatLoader>,
+/// }
+///
+/// #[godot_api]
+/// impl IResourceFormatLoader for AtlasLoader {
+///     fn get_recognized_extensions(&self) -> PackedStringArray {
+///         PackedStringArray::from(&[GString::from("atlas")])
+///     }
+///
+///     fn handles_type(&self, type_: StringName) -> bool {
+///         type_ == StringName::from("Resource")
+///     }
+///
+///     fn get_resource_type(&self, _path: GString) -> GString {
+///         GString::from("Resource")
+///     }
+///
+///     fn load(&self, path: GString, _original_path: GString, _use_sub_threads: bool, _cache_mode: i32) -> Variant {
+///         let result = match GFile::open(&path, godot::classes::file_access::ModeFlags::READ) {
+///             Ok(_file) => Ok(Resource::new_gd()), // Parse file contents here.
+///             Err(_) => Err(Error::ERR_FILE_CANT_OPEN),
+///         };
+///
+///         resource_load_result(result)
+///     }
+/// }
+///
+/// struct MyExtension;
+///
+/// #[gdextension]
+/// unsafe impl ExtensionLibrary for MyExtension {
+///     fn on_level_init(level: InitLevel) {
+///         if level == InitLevel::Scene {
+///             register_resource_format_loader(&AtlasLoader::new_gd());
+///         }
+///     }
+/// }
+/// ```
+pub fn register_resource_format_loader<T>(loader: &Gd<T>)
+where
+    T: Inherits<ResourceFormatLoader>,
+{
+    let loader = loader.clone().upcast::<ResourceFormatLoader>();
+
+    ResourceLoader::singleton()
+        .add_resource_format_loader_ex(&loader)
+        .at_front(true)
+        .done();
+
+    REGISTERED_FORMATS.lock().loaders.push(loader.instance_id());
+}
+
+/// Unregisters a loader previously registered with [`register_resource_format_loader()`].
+///
+/// Only needed if the loader should be removed before the `Scene` init level is unloaded.
+pub fn unregister_resource_format_loader<T>(loader: &Gd<T>)
+where
+    T: Inherits<ResourceFormatLoader>,
+{
+    let loader = loader.clone().upcast::<ResourceFormatLoader>();
+    let id = loader.instance_id();
+
+    REGISTERED_FORMATS.lock().loaders.retain(|e| *e != id);
+    ResourceLoader::singleton().remove_resource_format_loader(&loader);
+}
+
+/// Registers a custom [`ResourceFormatSaver`] with the engine.
+///
+/// After this call, [`save()`][crate::tools::save] and [`ResourceSaver`] can store resources in the format of `saver`.
+/// The saver is added at the front, i.e. it takes precedence over built-in savers.
+///
+/// Like loaders, savers are automatically unregistered when the `Scene` init level is unloaded.
+/// See [`register_resource_format_loader()`] for details.
+#[cfg(feature = "codegen-full")]
+pub fn register_resource_format_saver<T>(saver: &Gd<T>)
+where
+    T: Inherits<ResourceFormatSaver>,
+{
+    let saver = saver.clone().upcast::<ResourceFormatSaver>();
+
+    ResourceSaver::singleton()
+        .add_resource_format_saver_ex(&saver)
+        .at_front(true)
+        .done();
+
+    REGISTERED_FORMATS.lock().savers.push(saver.instance_id());
+}
+
+/// Unregisters a saver previously registered with [`register_resource_format_saver()`].
+///
+/// Only needed if the saver should be removed before the `Scene` init level is unloaded.
+#[cfg(feature = "codegen-full")]
+pub fn unregister_resource_format_saver<T>(saver: &Gd<T>)
+where
+    T: Inherits<ResourceFormatSaver>,
+{
+    let saver = saver.clone().upcast::<ResourceFormatSaver>();
+    let id = saver.instance_id();
+
+    REGISTERED_FORMATS.lock().savers.retain(|e| *e != id);
+    ResourceSaver::singleton().remove_resource_format_saver(&saver);
+}
+
+/// Converts the outcome of a custom load operation to the return value expected by `IResourceFormatLoader::load()`.
+///
+/// Godot's `_load()` virtual returns a `Variant`, which must hold either the loaded resource or an [`Error`][GodotError] code. This function
+/// maps `Ok(resource)` to the former and `Err(error)` to the latter, so that loader implementations can use `Result` and `?` internally.
+///
+/// Returning `Err(Error::OK)` is not meaningful; the engine would treat it as a failed load without error description.
+pub fn resource_load_result<T>(result: Result<Gd<T>, GodotError>) -> Variant
+where
+    T: Inherits<Resource>,
+{
+    match result {
+        Ok(resource) => resource.upcast::<Resource>().to_variant(),
+        Err(error) => error.to_variant(),
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+/// Removes all loaders and savers from the engine that were registered through this module.
+///
+/// Called when the `Scene` level is unloaded, after user deinit code.
+pub(crate) fn unregister_resource_formats() {
+    let formats = std::mem::take(&mut *REGISTERED_FORMATS.lock());
+
+    if !formats.loaders.is_empty() {
+        let mut resource_loader = ResourceLoader::singleton();
+        for id in formats.loaders {
+            // Skip loaders which have already been destroyed by someone else.
+            if let Ok(loader) = Gd::<ResourceFormatLoader>::try_from_instance_id(id) {
+                resource_loader.remove_resource_format_loader(&loader);
+            }
+        }
+    }
+
+    #[cfg(feature = "codegen-full")]
+    if !formats.savers.is_empty() {
+        let mut resource_saver = ResourceSaver::singleton();
+        for id in formats.savers {
+            if let Ok(saver) = Gd::<ResourceFormatSaver>::try_from_instance_id(id) {
+                resource_saver.remove_resource_format_saver(&saver);
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/// How typed group operations on `SceneTree` treat members that cannot be used.
+///
+/// A member cannot be used if its type does not match, or, for [`call_group_typed()`][crate::classes::SceneTree::call_group_typed], if
+/// its Rust instance is currently bound (e.g. because a method of it is running further up the call stack).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum GroupMemberPolicy {
+    /// Skip such members silently.
+    #[default]
+    Skip,
+
+    /// Skip such members and print a warning.
+    Warn,
+
+    /// Stop at the first such member and return a [`GroupMemberError`][crate::meta::error::GroupMemberError].
+    Error,
+}
@@ -0,0 +1,134 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use crate::builtin::{Dictionary, GString, StringName, VariantType};
+use crate::classes::{Engine, Shader, ShaderMaterial};
+use crate::meta::error::ShaderParamError;
+use crate::obj::EngineEnum;
+use crate::sys::Global;
+
+// Parameter names are created once per name, on first use. StringNames must be released before the engine shuts down.
+static PARAM_NAMES: Global<HashMap<String, StringName>> = Global::default();
+
+/// Expected uniform of a shader, as declared by [`ShaderParams`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ShaderUniform {
+    /// Name of the uniform in the shader code.
+    pub name: &'static str,
+
+    /// Type of the uniform, as reported by `Shader.get_shader_uniform_list()`.
+    pub variant_type: VariantType,
+}
+
+/// Typed set of shader parameters, declared as a Rust struct.
+///
+/// This trait should not be implemented manually; use `#[derive(ShaderParams)]` on a struct with named fields instead. Every field maps
+/// to one uniform. By default, the uniform name is the field name; use `#[param(name = "...")]` to choose a different name.
+///
+/// Field types determine the expected uniform types: `f32` for `float`, `i32` for `int`, `Vector3` for `vec3`, `Color` for
+/// `vec4 : source_color`, `Vector4` for a plain `vec4`, `Option<Gd<Texture2D>>` for `sampler2D`, etc.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::ShaderMaterial;
+/// use godot::register::ShaderParams;
+///
+/// // shader_type canvas_item;
+/// // uniform vec4 tint : source_color;
+/// // uniform float flash_strength;
+/// #[derive(ShaderParams)]
+/// struct FlashParams {
+///     tint: Color,
+///     #[param(name = "flash_strength")]
+///     strength: f32,
+/// }
+///
+/// fn flash(material: &mut Gd<ShaderMaterial>) {
+///     if let Err(errors) = material.validate_params::<FlashParams>() {
+///         godot_error!("flash shader does not match: {errors:?}");
+///     }
+///
+///     let params = FlashParams { tint: Color::WHITE, strength: 0.8 };
+///     params.apply_to(material);
+/// }
+/// ```
+pub trait ShaderParams {
+    /// All uniforms, in declaration order.
+    fn uniforms() -> Vec<ShaderUniform>;
+
+    /// Sets all parameters on `material`, see [`ShaderMaterial::set_param()`].
+    fn apply_to(&self, material: &mut ShaderMaterial);
+}
+
+/// Name of a shader parameter as cached `StringName`.
+pub(crate) fn cached_param_name(name: &str) -> StringName {
+    let mut names = PARAM_NAMES.lock();
+    if let Some(cached) = names.get(name) {
+        return cached.clone();
+    }
+
+    let created = StringName::from(name);
+    names.insert(name.to_string(), created.clone());
+    created
+}
+
+pub(crate) fn clear_shader_param_names() {
+    PARAM_NAMES.lock().clear();
+}
+
+pub(crate) fn validate_params<P: ShaderParams>(
+    material: &ShaderMaterial,
+) -> Result<(), Vec<ShaderParamError>> {
+    // Only meaningful during development; release games skip the uniform list query.
+    if !cfg!(debug_assertions) && !Engine::singleton().is_editor_hint() {
+        return Ok(());
+    }
+
+    let Some(shader) = material.get_shader() else {
+        return Err(vec![ShaderParamError::NoShader]);
+    };
+
+    let declared = declared_uniforms(&shader);
+    let errors: Vec<ShaderParamError> = P::uniforms()
+        .into_iter()
+        .filter_map(|uniform| match declared.get(uniform.name) {
+            None => Some(ShaderParamError::MissingUniform {
+                name: uniform.name.to_string(),
+            }),
+            Some(&actual) if actual != uniform.variant_type => Some(ShaderParamError::WrongType {
+                name: uniform.name.to_string(),
+                expected: uniform.variant_type,
+                actual,
+            }),
+            Some(_) => None,
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Maps uniform names to their types.
+fn declared_uniforms(shader: &Shader) -> HashMap<String, VariantType> {
+    shader
+        .get_shader_uniform_list()
+        .iter_shared()
+        .filter_map(|entry| entry.try_to::<Dictionary>().ok())
+        .filter_map(|dict| {
+            let name = dict.get("name")?.try_to::<GString>().ok()?;
+            let ord = dict.get("type")?.try_to::<i32>().ok()?;
+
+            Some((name.to_string(), VariantType::try_from_ord(ord)?))
+        })
+        .collect()
+}
@@ -0,0 +1,157 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::StringName;
+use crate::classes::node::InternalMode;
+use crate::classes::Node;
+use crate::meta::{arg_into_owned, AsArg, ToGodot};
+use crate::obj::{Gd, Inherits};
+
+/// Builder that adds a child node, returned by [`Node::spawn()`].
+///
+/// Calls are performed in the following order when [`done()`][Self::done] is invoked:
+/// 1. `set_name()`, so that the node enters the tree under the requested name and no auto-generated name is used.
+/// 2. `add_child()`, with the configured `force_readable_name` and `internal` arguments.
+/// 3. `set_owner()` on the child, and on all its descendants that do not have an owner yet.
+///
+/// The owner must be an ancestor of the child once it is added, which is why it can only be set after step 2.
+#[must_use = "the child is only added once done() is called"]
+pub struct SpawnChild<T: Inherits<Node>> {
+    parent: Gd<Node>,
+    child: Gd<T>,
+    name: Option<StringName>,
+    owner: Option<Gd<Node>>,
+    force_readable_name: bool,
+    internal: InternalMode,
+    deferred: bool,
+}
+
+impl<T: Inherits<Node>> SpawnChild<T> {
+    pub(crate) fn new(parent: Gd<Node>, child: Gd<T>) -> Self {
+        Self {
+            parent,
+            child,
+            name: None,
+            owner: None,
+            force_readable_name: false,
+            internal: InternalMode::DISABLED,
+            deferred: false,
+        }
+    }
+
+    /// Name of the child in the tree.
+    ///
+    /// If another sibling already has this name, Godot renames the child; see `force_readable_name()`.
+    pub fn name(mut self, name: impl AsArg<StringName>) -> Self {
+        arg_into_owned!(name);
+        self.name = Some(name);
+        self
+    }
+
+    /// Generate human-readable names on collision (e.g. `Bullet2` instead of `@Bullet@123`). Slower, defaults to `false`.
+    pub fn force_readable_name(mut self, force_readable_name: bool) -> Self {
+        self.force_readable_name = force_readable_name;
+        self
+    }
+
+    /// Adds the child as internal node, see `Node::add_child()`. Defaults to `InternalMode::DISABLED`.
+    pub fn internal(mut self, internal: InternalMode) -> Self {
+        self.internal = internal;
+        self
+    }
+
+    /// Sets the owner of the child and of its unowned descendants.
+    ///
+    /// Only owned nodes are saved when a scene is packed. In editor tools, pass the edited scene root
+    /// (`EditorInterface::get_edited_scene_root()`) so that the spawned nodes appear in the scene dock and persist when the scene is saved.
+    ///
+    /// Descendants that already have an owner keep it; this preserves the structure of instantiated sub-scenes.
+    pub fn owned_by<O>(mut self, owner: &Gd<O>) -> Self
+    where
+        O: Inherits<Node>,
+    {
+        self.owner = Some(owner.clone().upcast());
+        self
+    }
+
+    /// Adds the child at the end of the current frame, via `call_deferred()`.
+    ///
+    /// Required when the parent is busy setting up its children, e.g. inside `ready()` or a signal emitted during tree changes.
+    /// The name is set immediately, while adding and setting the owner are deferred in the documented order.
+    pub fn deferred(mut self) -> Self {
+        self.deferred = true;
+        self
+    }
+
+    /// Performs the configured calls and returns the child.
+    ///
+    /// With [`deferred()`][Self::deferred], this returns immediately; the child is not yet inside the tree.
+    pub fn done(self) -> Gd<T> {
+        let Self {
+            mut parent,
+            child,
+            name,
+            owner,
+            force_readable_name,
+            internal,
+            deferred,
+        } = self;
+
+        let mut node = child.clone().upcast::<Node>();
+        if let Some(name) = name {
+            node.set_name(&name);
+        }
+
+        // Descendants are collected before adding, so that nodes added later by the child's own ready() are not affected.
+        let unowned = if owner.is_some() {
+            unowned_descendants(&node)
+        } else {
+            Vec::new()
+        };
+
+        if deferred {
+            // Deferred calls are flushed in FIFO order, so set_owner() runs after add_child().
+            parent.call_deferred(
+                "add_child",
+                &[
+                    node.to_variant(),
+                    force_readable_name.to_variant(),
+                    internal.to_variant(),
+                ],
+            );
+
+            if let Some(owner) = owner {
+                let args = [owner.to_variant()];
+                node.call_deferred("set_owner", &args);
+                for mut descendant in unowned {
+                    descendant.call_deferred("set_owner", &args);
+                }
+            }
+        } else {
+            parent
+                .add_child_ex(&node)
+                .force_readable_name(force_readable_name)
+                .internal(internal)
+                .done();
+
+            if let Some(owner) = owner {
+                node.set_owner(&owner);
+                for mut descendant in unowned {
+                    descendant.set_owner(&owner);
+                }
+            }
+        }
+
+        child
+    }
+}
+
+fn unowned_descendants(node: &Gd<Node>) -> Vec<Gd<Node>> {
+    node.descendants_of_type::<Node>(true)
+        .filter(|descendant| descendant.get_owner().is_none())
+        .collect()
+}
@@ -0,0 +1,62 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::Vector2i;
+
+/// Tile placed in a cell of a `TileMapLayer`, identifying the tile within the layer's `TileSet`.
+///
+/// Returned by [`TileMapLayer::get_tile_cell()`][crate::classes::TileMapLayer::get_tile_cell] and accepted by
+/// [`TileMapLayer::set_tile_cell()`][crate::classes::TileMapLayer::set_tile_cell]. Empty cells are represented as `None` instead of
+/// Godot's sentinel values (source ID `-1`, atlas coordinates `(-1, -1)`, alternative tile `-1`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TileCell {
+    /// ID of the `TileSetSource` within the tile set.
+    pub source_id: i32,
+
+    /// Coordinates of the tile within a `TileSetAtlasSource`. For scene collections, this is `(0, 0)`.
+    pub atlas_coords: Vector2i,
+
+    /// Alternative tile ID; `0` is the tile itself. For scene collections, this identifies the scene.
+    pub alternative_tile: i32,
+}
+
+impl TileCell {
+    /// Source ID that Godot reports for empty cells.
+    pub const INVALID_SOURCE: i32 = -1;
+
+    /// Atlas coordinates that Godot reports for empty cells.
+    pub const INVALID_ATLAS_COORDS: Vector2i = Vector2i::new(-1, -1);
+
+    /// Alternative tile that Godot reports for empty cells.
+    pub const INVALID_ALTERNATIVE: i32 = -1;
+
+    /// Tile at `atlas_coords` of the atlas source `source_id`, without alternative.
+    pub const fn atlas(source_id: i32, atlas_coords: Vector2i) -> Self {
+        Self {
+            source_id,
+            atlas_coords,
+            alternative_tile: 0,
+        }
+    }
+
+    /// Scene with ID `scene_id` of the scene collection source `source_id`.
+    pub const fn scene(source_id: i32, scene_id: i32) -> Self {
+        Self {
+            source_id,
+            atlas_coords: Vector2i::ZERO,
+            alternative_tile: scene_id,
+        }
+    }
+
+    /// Returns a copy using the given alternative tile.
+    pub const fn with_alternative(self, alternative_tile: i32) -> Self {
+        Self {
+            alternative_tile,
+            ..self
+        }
+    }
+}
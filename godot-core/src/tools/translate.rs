@@ -7,6 +7,11 @@
 
 //! Macros for translation.
 
+use crate::builtin::{Dictionary, GString};
+use crate::classes::Object;
+use crate::meta::ToGodot;
+use crate::obj::{Gd, Inherits, WithBaseField};
+
 pub use crate::{tr, tr_n};
 
 /// A convenience macro for using the [`Object::tr()`](crate::classes::Object::tr()) and [`Object::tr_ex()`](crate::classes::Object::tr_ex())
@@ -34,6 +39,35 @@ pub use crate::{tr, tr_n};
 /// ```
 /// The methods are called from the [`Engine`](crate::classes::Engine) singleton.
 ///
+/// # Placeholders in translations
+/// The forms above format the message _before_ translating it, so the translation must contain the formatted text. To look up the
+/// message with its placeholders instead, pass an object (a `Gd<T>`, or `self` in a class with a `Base<T>` field) as first argument,
+/// followed by named arguments. The message is translated with [`Object::tr()`](crate::classes::Object::tr()) on that object, and the
+/// placeholders are then substituted with [`GString::format()`](crate::builtin::GString::format()):
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::tools::tr;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Wallet {
+///     coins: i32,
+///     base: Base<Node>,
+/// }
+///
+/// impl Wallet {
+///     fn status(&self) -> GString {
+///         // Looks up "Picked up {count} coins", e.g. translated to "{count} Münzen aufgehoben".
+///         tr!(self, "Picked up {count} coins", count = self.coins)
+///     }
+///
+///     fn menu_entry(&self) -> GString {
+///         tr!(self, "menu"; "Coins: {count}", count = self.coins)
+///     }
+/// }
+/// ```
+/// If no translation is loaded for the current locale, the untranslated message is formatted.
+///
 /// See also: [Translation contexts](https://docs.godotengine.org/en/stable/tutorials/i18n/internationalizing_games.html#translation-contexts)
 /// in Godot.
 #[macro_export]
@@ -53,6 +87,21 @@ macro_rules! tr {
             .context(&context)
             .done()
     }};
+
+    ($object:expr, $fmt:literal $(, $key:ident = $value:expr)* $(,)?) => {{
+        use $crate::tools::TrObject as _;
+        let msg = $object.__tr_object().tr($fmt);
+
+        $crate::tools::__tr_format(msg, $crate::builtin::dict! { $((stringify!($key)): $value),* })
+    }};
+
+    ($object:expr, $context:expr; $fmt:literal $(, $key:ident = $value:expr)* $(,)?) => {{
+        use $crate::tools::TrObject as _;
+        let context = format!("{}", $context);
+        let msg = $object.__tr_object().tr_ex($fmt).context(&context).done();
+
+        $crate::tools::__tr_format(msg, $crate::builtin::dict! { $((stringify!($key)): $value),* })
+    }};
 }
 
 /// A convenience macro for using the [`Object::tr_n()`](crate::classes::Object::tr_n()) and
@@ -82,6 +131,20 @@ macro_rules! tr {
 /// ```
 /// The methods are called from the [`Engine`](crate::classes::Engine) singleton.
 ///
+/// # Placeholders in translations
+/// Like [`tr!`](crate::tools::tr), the message can be translated with its placeholders by passing an object first, followed by `n`
+/// and named arguments. Placeholders are substituted with [`GString::format()`](crate::builtin::GString::format()) after calling
+/// [`Object::tr_n()`](crate::classes::Object::tr_n()):
+/// ```no_run
+/// # use godot::prelude::*;
+/// # let node = Node::new_alloc();
+/// # let n = 3;
+/// use godot::tools::tr_n;
+///
+/// let text = tr_n!(node, n, "Picked up {count} coin", "Picked up {count} coins", count = n);
+/// let text = tr_n!(node, n, "shop"; "{count} item", "{count} items", count = n); // with context
+/// ```
+///
 /// See also: [Translation contexts](https://docs.godotengine.org/en/stable/tutorials/i18n/internationalizing_games.html#translation-contexts)
 /// in Godot.
 #[macro_export]
@@ -105,4 +168,53 @@ macro_rules! tr_n {
             .context(&format!("{}", $context))
             .done()
     };
+
+    ($object:expr, $n:expr, $singular:literal, $plural:literal $(, $key:ident = $value:expr)* $(,)?) => {{
+        use $crate::tools::TrObject as _;
+        let msg = $object.__tr_object().tr_n($singular, $plural, $n);
+
+        $crate::tools::__tr_format(msg, $crate::builtin::dict! { $((stringify!($key)): $value),* })
+    }};
+
+    ($object:expr, $n:expr, $context:expr; $singular:literal, $plural:literal $(, $key:ident = $value:expr)* $(,)?) => {{
+        use $crate::tools::TrObject as _;
+        let context = format!("{}", $context);
+        let msg = $object
+            .__tr_object()
+            .tr_n_ex($singular, $plural, $n)
+            .context(&context)
+            .done();
+
+        $crate::tools::__tr_format(msg, $crate::builtin::dict! { $((stringify!($key)): $value),* })
+    }};
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation details of the macros.
+
+/// Object on which `tr!` and `tr_n!` look up translations: either `Gd<T>`, or a user class with a `Base<T>` field.
+#[doc(hidden)]
+pub trait TrObject {
+    fn __tr_object(&self) -> Gd<Object>;
+}
+
+impl<T: Inherits<Object>> TrObject for Gd<T> {
+    fn __tr_object(&self) -> Gd<Object> {
+        self.clone().upcast()
+    }
+}
+
+impl<T: WithBaseField + Inherits<Object>> TrObject for T {
+    fn __tr_object(&self) -> Gd<Object> {
+        self.to_gd().upcast()
+    }
+}
+
+#[doc(hidden)]
+pub fn __tr_format(msg: GString, args: Dictionary) -> GString {
+    if args.is_empty() {
+        return msg;
+    }
+
+    msg.format(&args.to_variant())
 }
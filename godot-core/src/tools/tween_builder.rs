@@ -0,0 +1,353 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::marker::PhantomData;
+
+use crate::builtin::{real, Callable, Color, NodePath, Signal, Variant, Vector2, Vector3};
+use crate::classes::tween::{EaseType, TransitionType};
+use crate::classes::{CallbackTweener, MethodTweener, Node, Object, PropertyTweener, Tween};
+use crate::meta::{FromGodot, ToGodot};
+use crate::obj::{Gd, GodotClass, Inherits};
+use crate::task::SignalFuture;
+
+/// Property path of class `C`, whose value has type `V`.
+///
+/// Usually created with the [`prop!`](../macro.prop.html) macro, which checks at compile time that `C` has a getter for the property and
+/// derives `V` from its return type. Sub-properties of vectors and colors can be selected with methods like [`x()`][Self::x], resulting
+/// in paths like `"position:x"`.
+///
+/// ```no_run
+/// use godot::builtin::NodePath;
+/// use godot::classes::Node2D;
+/// use godot::prop;
+///
+/// let position_x = prop!(Node2D::position).x();
+/// assert_eq!(position_x.path(), NodePath::from("position:x"));
+/// ```
+pub struct PropertyPath<C, V> {
+    path: String,
+    _marker: PhantomData<fn(&C) -> V>,
+}
+
+impl<C: GodotClass, V> PropertyPath<C, V> {
+    /// Creates a path from a string, e.g. `"position:x"`, without checking that the property exists or has type `V`.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            _marker: PhantomData,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn __from_getter(property: &str, _getter: impl FnOnce(&C) -> V) -> Self {
+        Self::new(property)
+    }
+
+    /// The path as `NodePath`, as expected by Godot APIs such as `Tween::tween_property()`.
+    pub fn path(&self) -> NodePath {
+        NodePath::from(self.path.as_str())
+    }
+
+    fn sub<W>(self, component: &str) -> PropertyPath<C, W> {
+        PropertyPath {
+            path: format!("{}:{component}", self.path),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C: GodotClass> PropertyPath<C, Vector2> {
+    /// Path to the `x` component.
+    pub fn x(self) -> PropertyPath<C, real> {
+        self.sub("x")
+    }
+
+    /// Path to the `y` component.
+    pub fn y(self) -> PropertyPath<C, real> {
+        self.sub("y")
+    }
+}
+
+impl<C: GodotClass> PropertyPath<C, Vector3> {
+    /// Path to the `x` component.
+    pub fn x(self) -> PropertyPath<C, real> {
+        self.sub("x")
+    }
+
+    /// Path to the `y` component.
+    pub fn y(self) -> PropertyPath<C, real> {
+        self.sub("y")
+    }
+
+    /// Path to the `z` component.
+    pub fn z(self) -> PropertyPath<C, real> {
+        self.sub("z")
+    }
+}
+
+impl<C: GodotClass> PropertyPath<C, Color> {
+    /// Path to the red component.
+    pub fn r(self) -> PropertyPath<C, f32> {
+        self.sub("r")
+    }
+
+    /// Path to the green component.
+    pub fn g(self) -> PropertyPath<C, f32> {
+        self.sub("g")
+    }
+
+    /// Path to the blue component.
+    pub fn b(self) -> PropertyPath<C, f32> {
+        self.sub("b")
+    }
+
+    /// Path to the alpha component.
+    pub fn a(self) -> PropertyPath<C, f32> {
+        self.sub("a")
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Tweener that was added last, which `ease()`, `trans()` and `delay()` apply to.
+enum LastTweener {
+    None,
+    Property(Gd<PropertyTweener>),
+    Method(Gd<MethodTweener>),
+    Callback(Gd<CallbackTweener>),
+}
+
+/// Builder for a [`Tween`] animating a node, returned by [`Node::tween()`].
+///
+/// Steps run one after another, unless [`parallel()`][Self::parallel] is used. Like any tween, it starts automatically on the next
+/// process frame, and is stopped when the node leaves the tree or is freed.
+///
+/// Rust closures passed to [`method()`][Self::method] and [`then_call()`][Self::then_call] are wrapped into `Callable`s. They
+/// receive the animated node as `Gd<T>`; use [`TweenBuilder::new()`] instead of `Node::tween()` to obtain a specific `T`.
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::tween::{EaseType, TransitionType};
+/// use godot::classes::Node2D;
+/// use godot::prelude::*;
+/// use godot::prop;
+/// use godot::tools::TweenBuilder;
+///
+/// fn slide_out(node: &Gd<Node2D>) {
+///     TweenBuilder::new(node)
+///         .property(prop!(Node2D::position).x(), 500.0, 1.0)
+///         .ease(EaseType::OUT)
+///         .trans(TransitionType::CUBIC)
+///         .property(prop!(Node2D::modulate).a(), 0.0, 0.25)
+///         .then_call(|mut node| node.queue_free());
+/// }
+/// ```
+pub struct TweenBuilder<T: Inherits<Node> = Node> {
+    target: Gd<T>,
+    tween: Gd<Tween>,
+    last: LastTweener,
+}
+
+impl<T: Inherits<Node>> TweenBuilder<T> {
+    /// Creates a tween bound to `target`, see `Node::create_tween()`.
+    ///
+    /// # Panics
+    /// If `target` is not inside the scene tree.
+    pub fn new(target: &Gd<T>) -> Self {
+        let tween = target
+            .clone()
+            .upcast::<Node>()
+            .create_tween()
+            .unwrap_or_else(|| {
+                panic!("TweenBuilder::new(): node {target} must be inside the scene tree")
+            });
+
+        Self {
+            target: target.clone(),
+            tween,
+            last: LastTweener::None,
+        }
+    }
+
+    /// Animates the property `path` of the target node to `final_value`, over `duration` seconds.
+    ///
+    /// # Panics
+    /// If the target node is not an instance of `C`.
+    pub fn property<C, V>(self, path: PropertyPath<C, V>, final_value: V, duration: f64) -> Self
+    where
+        C: Inherits<Object>,
+        V: ToGodot,
+    {
+        let target = self.target.clone().upcast::<Node>().upcast::<Object>();
+        self.property_of(&target, path, final_value, duration)
+    }
+
+    /// Animates the property `path` of another object to `final_value`, over `duration` seconds.
+    ///
+    /// # Panics
+    /// If `object` is not an instance of `C`.
+    pub fn property_of<O, C, V>(
+        mut self,
+        object: &Gd<O>,
+        path: PropertyPath<C, V>,
+        final_value: V,
+        duration: f64,
+    ) -> Self
+    where
+        O: Inherits<Object>,
+        C: Inherits<Object>,
+        V: ToGodot,
+    {
+        let object = object.clone().upcast::<Object>();
+        let class_name = C::class_name();
+        assert!(
+            object.is_class(&class_name.to_gstring()),
+            "TweenBuilder: {object} is not an instance of {class_name}, cannot tween property `{}`",
+            path.path
+        );
+
+        let tweener = self
+            .tween
+            .tween_property(&object, &path.path(), &final_value.to_variant(), duration)
+            .expect("Tween::tween_property() failed");
+
+        self.last = LastTweener::Property(tweener);
+        self
+    }
+
+    /// Interpolates from `from` to `to` over `duration` seconds, calling `method` with the target node and each intermediate value.
+    ///
+    /// Useful to animate values which are not properties, e.g. arguments of a shader or a Rust field.
+    pub fn method<V, F>(mut self, from: V, to: V, duration: f64, mut method: F) -> Self
+    where
+        V: ToGodot + FromGodot,
+        F: FnMut(Gd<T>, V) + 'static,
+    {
+        let target = self.target.clone();
+        let callable = Callable::from_local_fn("TweenBuilder::method", move |args| {
+            let value = args.first().ok_or(())?.try_to::<V>().map_err(|_| ())?;
+            method(target.clone(), value);
+            Ok(Variant::nil())
+        });
+
+        let tweener = self
+            .tween
+            .tween_method(&callable, &from.to_variant(), &to.to_variant(), duration)
+            .expect("Tween::tween_method() failed");
+
+        self.last = LastTweener::Method(tweener);
+        self
+    }
+
+    /// Calls `callback` with the target node, once the previous steps have finished.
+    pub fn then_call<F>(mut self, mut callback: F) -> Self
+    where
+        F: FnMut(Gd<T>) + 'static,
+    {
+        let target = self.target.clone();
+        let callable = Callable::from_local_fn("TweenBuilder::then_call", move |_args| {
+            callback(target.clone());
+            Ok(Variant::nil())
+        });
+
+        let tweener = self
+            .tween
+            .tween_callback(&callable)
+            .expect("Tween::tween_callback() failed");
+
+        self.last = LastTweener::Callback(tweener);
+        self
+    }
+
+    /// Waits for `duration` seconds before the next step.
+    pub fn interval(mut self, duration: f64) -> Self {
+        self.tween.tween_interval(duration);
+        self.last = LastTweener::None;
+        self
+    }
+
+    /// Easing of the last property or method step.
+    ///
+    /// If there is no such step yet (or the last step was a callback or interval), sets the default for all following steps instead.
+    pub fn ease(mut self, ease: EaseType) -> Self {
+        match &mut self.last {
+            LastTweener::Property(tweener) => {
+                tweener.set_ease(ease);
+            }
+            LastTweener::Method(tweener) => {
+                tweener.set_ease(ease);
+            }
+            LastTweener::None | LastTweener::Callback(_) => {
+                self.tween.set_ease(ease);
+            }
+        }
+        self
+    }
+
+    /// Transition curve of the last property or method step.
+    ///
+    /// If there is no such step yet (or the last step was a callback or interval), sets the default for all following steps instead.
+    pub fn trans(mut self, trans: TransitionType) -> Self {
+        match &mut self.last {
+            LastTweener::Property(tweener) => {
+                tweener.set_trans(trans);
+            }
+            LastTweener::Method(tweener) => {
+                tweener.set_trans(trans);
+            }
+            LastTweener::None | LastTweener::Callback(_) => {
+                self.tween.set_trans(trans);
+            }
+        }
+        self
+    }
+
+    /// Delays the start of the last step by `delay` seconds.
+    ///
+    /// # Panics
+    /// If no property, method or callback step has been added directly before.
+    pub fn delay(mut self, delay: f64) -> Self {
+        match &mut self.last {
+            LastTweener::Property(tweener) => {
+                tweener.set_delay(delay);
+            }
+            LastTweener::Method(tweener) => {
+                tweener.set_delay(delay);
+            }
+            LastTweener::Callback(tweener) => {
+                tweener.set_delay(delay);
+            }
+            LastTweener::None => panic!("TweenBuilder::delay(): no step to delay"),
+        }
+        self
+    }
+
+    /// Runs all following steps at the same time, see `Tween::set_parallel()`.
+    pub fn parallel(mut self) -> Self {
+        self.tween.set_parallel();
+        self
+    }
+
+    /// Repeats the tween `loops` times; 0 repeats it forever. See `Tween::set_loops()`.
+    pub fn loops(mut self, loops: i32) -> Self {
+        self.tween.set_loops_ex().loops(loops).done();
+        self
+    }
+
+    /// Returns the underlying tween, e.g. to pause or kill it.
+    pub fn tween(&self) -> Gd<Tween> {
+        self.tween.clone()
+    }
+
+    /// Future that resolves when the tween has finished, via its `finished` signal.
+    ///
+    /// Never resolves for infinite loops. If the tween is killed or its node is freed before, the future panics when polled; see
+    /// [`SignalFuture`].
+    pub fn finished(self) -> SignalFuture<()> {
+        Signal::from_object_signal(&self.tween, "finished").to_future()
+    }
+}
@@ -0,0 +1,151 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Building blocks for method tables that resolve their function pointers on first call.
+//!
+//! Each table index owns one [`LazyFnPtr`] slot. Slots live inside the table instance, which is dropped on deinitialization, so a
+//! hot-reloaded library starts out with empty slots and resolves all pointers again against the new engine state.
+
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use crate as sys;
+
+/// Function pointer which is resolved on first use, then cached.
+///
+/// Lock-free: the fast path is a single atomic load. Resolving is idempotent, so if multiple threads race on the first call, each of
+/// them looks up the same pointer and stores it.
+pub struct LazyFnPtr<T> {
+    ptr: AtomicPtr<c_void>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: RawFnPtr> LazyFnPtr<T> {
+    pub const fn new() -> Self {
+        Self {
+            ptr: AtomicPtr::new(ptr::null_mut()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the cached pointer, or resolves it with `init` on first call.
+    #[inline(always)]
+    pub fn get_or_init(&self, init: impl FnOnce() -> T) -> T {
+        let ptr = self.ptr.load(Ordering::Acquire);
+        if ptr.is_null() {
+            return self.init_cold(init);
+        }
+
+        // SAFETY: only non-null pointers obtained from `T::into_raw()` are ever stored.
+        unsafe { T::from_raw(ptr) }
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn init_cold(&self, init: impl FnOnce() -> T) -> T {
+        let value = init();
+        self.ptr.store(value.into_raw(), Ordering::Release);
+
+        value
+    }
+}
+
+impl<T: RawFnPtr> Default for LazyFnPtr<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Function pointer types that can be stored in a [`LazyFnPtr`].
+pub trait RawFnPtr: Copy {
+    fn into_raw(self) -> *mut c_void;
+
+    /// # Safety
+    /// `ptr` must be non-null and obtained from [`into_raw()`][Self::into_raw].
+    unsafe fn from_raw(ptr: *mut c_void) -> Self;
+}
+
+impl RawFnPtr for sys::ClassMethodBind {
+    fn into_raw(self) -> *mut c_void {
+        self.0 as *mut c_void
+    }
+
+    unsafe fn from_raw(ptr: *mut c_void) -> Self {
+        sys::ClassMethodBind(ptr as sys::GDExtensionMethodBindPtr)
+    }
+}
+
+impl RawFnPtr for sys::BuiltinMethodBind {
+    fn into_raw(self) -> *mut c_void {
+        self as *mut c_void
+    }
+
+    unsafe fn from_raw(ptr: *mut c_void) -> Self {
+        // SAFETY: `ptr` originates from a function pointer of this type; data and function pointers have the same size on all
+        // platforms supported by Godot.
+        unsafe { std::mem::transmute::<*mut c_void, Self>(ptr) }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// String name cache shared by all lazy slots of one table.
+///
+/// Only locked while resolving a pointer for the first time.
+pub struct SharedStringCache {
+    // 'static because lazy tables are only created once the interface and lifecycle tables are globally available.
+    inner: Mutex<sys::StringCache<'static>>,
+}
+
+impl SharedStringCache {
+    /// # Safety
+    /// The binding must be initialized, and this cache must be dropped before it is deinitialized.
+    pub unsafe fn new() -> Self {
+        // SAFETY: binding is initialized, see precondition.
+        let (interface, lifecycle_table) =
+            unsafe { (sys::get_interface(), sys::builtin_lifecycle_api()) };
+
+        Self {
+            inner: Mutex::new(sys::StringCache::new(interface, lifecycle_table)),
+        }
+    }
+
+    pub fn lock(&self) -> MutexGuard<'_, sys::StringCache<'static>> {
+        // Loading a method panics only after the string names are cached, so the cache remains consistent after poisoning.
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+// SAFETY: the cached `StringName`s are only accessed behind the mutex, and only passed to Godot's method lookup functions, which are
+// thread-safe. They are destroyed together with the table, on deinitialization.
+unsafe impl Send for SharedStringCache {}
+// SAFETY: see above.
+unsafe impl Sync for SharedStringCache {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Key types, describing how to resolve the method at a given table index.
+// Could reuse them in normal load functions, but less code when passing separate parameters -> faster parsing.
+pub mod lazy_keys {
+    pub struct ClassMethodKey {
+        pub class_name: &'static str,
+        pub method_name: &'static str,
+        pub hash: i64,
+    }
+
+    pub struct BuiltinMethodKey {
+        pub variant_type: crate::GDExtensionVariantType,
+        pub variant_type_str: &'static str,
+        pub method_name: &'static str,
+        pub hash: i64,
+    }
+}
@@ -21,12 +21,6 @@
 
 // More validations in godot crate. #[cfg]s are checked in godot-core.
 
-#[cfg(all(feature = "codegen-lazy-fptrs", feature = "experimental-threads"))]
-compile_error!(
-    "Cannot combine `lazy-function-tables` and `experimental-threads` features;\n\
-    thread safety for lazy-loaded function pointers is not yet implemented."
-);
-
 #[cfg(all(
     feature = "experimental-wasm-nothreads",
     feature = "experimental-threads"
@@ -54,6 +48,8 @@ mod extras;
 mod global;
 mod godot_ffi;
 mod interface_init;
+#[cfg(not(feature = "codegen-eager-fptrs"))]
+mod lazy_table;
 #[cfg(target_os = "linux")]
 pub mod linux_reload_workaround;
 mod opaque;
@@ -70,7 +66,7 @@ pub use godot_macros::wasm_declare_init_fn;
 #[cfg(not(target_family = "wasm"))]
 #[macro_export]
 macro_rules! wasm_declare_init_fn {
-    () => {};
+    ($($body:tt)*) => {};
 }
 
 pub use crate::godot_ffi::{GodotFfi, GodotNullableFfi, PrimitiveConversionError, PtrcallType};
@@ -91,6 +87,8 @@ pub use gen::central::*;
 pub use gen::gdextension_interface::*;
 pub use gen::interface::*;
 pub use global::*;
+#[cfg(not(feature = "codegen-eager-fptrs"))]
+pub use lazy_table::*;
 pub use string_cache::StringCache;
 pub use toolbox::*;
 
@@ -109,6 +107,9 @@ use binding::{
 #[cfg(not(wasm_nothreads))]
 static MAIN_THREAD_ID: ManualInitCell<std::thread::ThreadId> = ManualInitCell::new();
 
+/// Time spent in loading engine method tables during initialization, in nanoseconds. See [`method_table_load_time()`].
+static METHOD_TABLE_LOAD_NANOS: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
 /// Stage of the Godot initialization process.
 ///
 /// Godot's initialization and deinitialization processes are split into multiple stages, like a stack. At each level,
@@ -225,15 +226,21 @@ pub unsafe fn initialize(
     // SAFETY: We do not touch `version` again after passing it to `new` here.
     let runtime_metadata = unsafe { GdextRuntimeMetadata::new(version) };
 
+    // Restart measurement, in case of hot reload.
+    METHOD_TABLE_LOAD_NANOS.store(0, std::sync::atomic::Ordering::Relaxed);
+
     let builtin_method_table = {
-        #[cfg(feature = "codegen-lazy-fptrs")]
+        #[cfg(not(feature = "codegen-eager-fptrs"))]
         {
             None // loaded later
         }
-        #[cfg(not(feature = "codegen-lazy-fptrs"))]
+        #[cfg(feature = "codegen-eager-fptrs")]
         {
+            let begin = std::time::Instant::now();
+
             // SAFETY: The interface was successfully loaded from Godot, so we should be able to load the builtin function table.
             let table = unsafe { BuiltinMethodTable::load(&interface, &mut string_names) };
+            record_method_table_load(begin);
             out!("Loaded builtin method table.");
             Some(table)
         }
@@ -261,10 +268,13 @@ pub unsafe fn initialize(
     out!("Assigned binding.");
 
     // Lazy case: load afterward because table's internal StringCache stores &'static references to the interface.
-    #[cfg(feature = "codegen-lazy-fptrs")]
+    #[cfg(not(feature = "codegen-eager-fptrs"))]
     {
+        let begin = std::time::Instant::now();
+
         // SAFETY: The interface was successfully loaded from Godot, so we should be able to load the builtin function table.
         let table = unsafe { BuiltinMethodTable::load() };
+        record_method_table_load(begin);
 
         unsafe { initialize_builtin_method_table(table) }
 
@@ -303,11 +313,11 @@ pub unsafe fn load_class_method_table(api_level: InitLevel) {
     out!("Load class method table for level '{:?}'...", api_level);
     let begin = std::time::Instant::now();
 
-    #[cfg(not(feature = "codegen-lazy-fptrs"))]
+    #[cfg(feature = "codegen-eager-fptrs")]
     // SAFETY: The interface has been initialized.
     let interface = unsafe { get_interface() };
 
-    #[cfg(not(feature = "codegen-lazy-fptrs"))]
+    #[cfg(feature = "codegen-eager-fptrs")]
     // SAFETY: The interface has been initialized.
     let mut string_names = StringCache::new(interface, unsafe { builtin_lifecycle_api() });
 
@@ -321,9 +331,9 @@ pub unsafe fn load_class_method_table(api_level: InitLevel) {
         InitLevel::Servers => {
             // SAFETY: The interface has been initialized and this function hasn't been called before.
             unsafe {
-                #[cfg(feature = "codegen-lazy-fptrs")]
+                #[cfg(not(feature = "codegen-eager-fptrs"))]
                 initialize_class_server_method_table(ClassServersMethodTable::load());
-                #[cfg(not(feature = "codegen-lazy-fptrs"))]
+                #[cfg(feature = "codegen-eager-fptrs")]
                 initialize_class_server_method_table(ClassServersMethodTable::load(
                     interface,
                     &mut string_names,
@@ -335,9 +345,9 @@ pub unsafe fn load_class_method_table(api_level: InitLevel) {
         InitLevel::Scene => {
             // SAFETY: The interface has been initialized and this function hasn't been called before.
             unsafe {
-                #[cfg(feature = "codegen-lazy-fptrs")]
+                #[cfg(not(feature = "codegen-eager-fptrs"))]
                 initialize_class_scene_method_table(ClassSceneMethodTable::load());
-                #[cfg(not(feature = "codegen-lazy-fptrs"))]
+                #[cfg(feature = "codegen-eager-fptrs")]
                 initialize_class_scene_method_table(ClassSceneMethodTable::load(
                     interface,
                     &mut string_names,
@@ -349,9 +359,9 @@ pub unsafe fn load_class_method_table(api_level: InitLevel) {
         InitLevel::Editor => {
             // SAFETY: The interface has been initialized and this function hasn't been called before.
             unsafe {
-                #[cfg(feature = "codegen-lazy-fptrs")]
+                #[cfg(not(feature = "codegen-eager-fptrs"))]
                 initialize_class_editor_method_table(ClassEditorMethodTable::load());
-                #[cfg(not(feature = "codegen-lazy-fptrs"))]
+                #[cfg(feature = "codegen-eager-fptrs")]
                 initialize_class_editor_method_table(ClassEditorMethodTable::load(
                     interface,
                     &mut string_names,
@@ -362,7 +372,7 @@ pub unsafe fn load_class_method_table(api_level: InitLevel) {
         }
     }
 
-    let _elapsed = std::time::Instant::now() - begin;
+    let _elapsed = record_method_table_load(begin);
     out!(
         "{:?} level: loaded {} classes and {} methods in {}s.",
         api_level,
@@ -372,6 +382,50 @@ pub unsafe fn load_class_method_table(api_level: InitLevel) {
     );
 }
 
+/// Total time that loading the builtin and class method tables took during initialization.
+///
+/// This is the startup cost of method tables, which is much lower with lazy tables (the default) than with `eager-function-tables`.
+/// Utility function and lifecycle tables are not included, as they are always loaded eagerly.
+#[doc(hidden)]
+pub fn method_table_load_time() -> std::time::Duration {
+    let nanos = METHOD_TABLE_LOAD_NANOS.load(std::sync::atomic::Ordering::Relaxed);
+    std::time::Duration::from_nanos(nanos)
+}
+
+fn record_method_table_load(begin: std::time::Instant) -> std::time::Duration {
+    let elapsed = begin.elapsed();
+    let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+    METHOD_TABLE_LOAD_NANOS.fetch_add(nanos, std::sync::atomic::Ordering::Relaxed);
+
+    elapsed
+}
+
+/// Loads a separate scene-level class method table, without registering it. Used to benchmark the startup cost of method tables.
+///
+/// # Safety
+///
+/// - Must be called from the main thread.
+/// - The interface must have been initialized.
+/// - The returned table must be dropped before the library is deinitialized.
+#[doc(hidden)]
+pub unsafe fn load_standalone_scene_method_table() -> ClassSceneMethodTable {
+    #[cfg(not(feature = "codegen-eager-fptrs"))]
+    {
+        // SAFETY: The interface has been initialized.
+        unsafe { ClassSceneMethodTable::load() }
+    }
+
+    #[cfg(feature = "codegen-eager-fptrs")]
+    {
+        // SAFETY: The interface has been initialized.
+        let interface = unsafe { get_interface() };
+        let mut string_names = StringCache::new(interface, unsafe { builtin_lifecycle_api() });
+
+        // SAFETY: The interface has been initialized.
+        unsafe { ClassSceneMethodTable::load(interface, &mut string_names) }
+    }
+}
+
 /// # Safety
 ///
 /// - Must be accessed from the main thread.
@@ -54,7 +54,7 @@ macro_rules! plugin_execute_pre_main {
                 __inner_init
             };
 
-            $crate::wasm_declare_init_fn!();
+            $crate::wasm_declare_init_fn!($body);
         };
     };
 }
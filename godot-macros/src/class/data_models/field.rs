@@ -39,6 +39,23 @@ impl Field {
         }
     }
 
+    /// Statement to run at the start of generated `#[var]` getters and setters.
+    ///
+    /// `OnReady<T>` fields panic with the property name if accessed before initialization. Other fields need no check; `OnEditor<T>`
+    /// in particular must stay readable while uninitialized, since the editor displays its (null or sentinel) value.
+    pub fn make_var_init_check(&self, class_name: &Ident) -> TokenStream {
+        if !self.is_onready {
+            return TokenStream::new();
+        }
+
+        let field_name = &self.name;
+        let property = format!("{class_name}.{field_name}");
+
+        quote! {
+            self.#field_name.__ensure_var_initialized(#property);
+        }
+    }
+
     /// For a previously performed check, either pastes the generated code, or a syntactically valid fallback.
     ///
     /// In case of incorrect proc-macro usage, it's nice if the resulting generated code is still syntactically valid, to not trip over
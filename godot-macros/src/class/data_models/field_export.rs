@@ -9,7 +9,7 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use std::collections::{HashMap, HashSet};
 
-use crate::util::{KvParser, ListParser};
+use crate::util::{bail, is_identifier, KvParser, KvValue, ListParser};
 use crate::ParseResult;
 
 pub struct FieldExport {
@@ -27,6 +27,16 @@ impl FieldExport {
     pub fn to_export_hint(&self) -> Option<TokenStream> {
         self.export_type.to_export_hint()
     }
+
+    /// Compile-time check that the field type is compatible with the export kind, if it is restricted.
+    pub fn to_type_check(&self, field_type: &venial::TypeExpr) -> Option<TokenStream> {
+        match self.export_type {
+            ExportType::ExpEasing { .. } => Some(quote! {
+                ::godot::register::private::assert_export_float::<#field_type>();
+            }),
+            _ => None,
+        }
+    }
 }
 
 /// Store info from `#[export]` attribute.
@@ -130,6 +140,13 @@ pub enum ExportType {
     /// - `PLACEHOLDER_TEXT`
     PlaceholderText { placeholder: TokenStream },
 
+    /// ### GDScript annotations
+    /// - `@export_node_path`
+    ///
+    /// ### Property hints
+    /// - `NODE_PATH_VALID_TYPES`
+    NodePathValidTypes { class_names: Vec<String> },
+
     /// ### GDScript annotations
     /// - `@export_color_no_alpha`
     ///
@@ -270,9 +287,18 @@ impl ExportType {
             return Ok(Self::ColorNoAlpha);
         }
 
+        if let Some((key, value)) = parser.handle_any_entry("node_path_valid_types") {
+            return Self::new_node_path_valid_types(key, value);
+        }
+
         Ok(Self::Default)
     }
 
+    /// Whether this export is only valid on `NodePath` fields.
+    pub fn requires_node_path(&self) -> bool {
+        matches!(self, Self::NodePathValidTypes { .. })
+    }
+
     fn new_range_list(mut parser: ListParser) -> ParseResult<Self> {
         const FLAG_OPTIONS: [&str; 7] = [
             "or_greater",
@@ -354,7 +380,12 @@ impl ExportType {
         let mut options = HashSet::new();
 
         while let Some(option) = parser.next_allowed_ident(&ALLOWED_OPTIONS[..])? {
-            options.insert(option.to_string());
+            if !options.insert(option.to_string()) {
+                return bail!(
+                    option,
+                    "`{option}` specified more than once in `exp_easing`"
+                );
+            }
         }
 
         parser.finish()?;
@@ -381,6 +412,41 @@ impl ExportType {
 
         Ok(Self::Flags { bits })
     }
+
+    fn new_node_path_valid_types(key: Ident, value: Option<KvValue>) -> ParseResult<Self> {
+        let Some(value) = value else {
+            return bail!(
+                key,
+                "expected `{key}` to be followed by `= \"Class1,Class2\"`"
+            );
+        };
+
+        let literal = value.as_literal()?;
+        let literal_str = literal.to_string();
+        let Some(list) = literal_str
+            .strip_prefix('"')
+            .and_then(|s| s.strip_suffix('"'))
+        else {
+            return bail!(
+                literal,
+                "`{key}` expects a string literal with comma-separated class names"
+            );
+        };
+
+        let mut class_names = Vec::new();
+        for class_name in list.split(',').map(str::trim) {
+            if !is_identifier(class_name) {
+                return bail!(
+                    literal,
+                    "`{key}` contains invalid class name `{class_name}`; expected comma-separated identifiers"
+                );
+            }
+
+            class_names.push(class_name.to_string());
+        }
+
+        Ok(Self::NodePathValidTypes { class_names })
+    }
 }
 
 macro_rules! quote_export_func {
@@ -510,6 +576,9 @@ impl ExportType {
             Self::PlaceholderText { placeholder } => quote_export_func! {
                 export_placeholder(#placeholder)
             },
+            Self::NodePathValidTypes { class_names } => quote_export_func! {
+                export_node_path_valid_types(&[#(#class_names),*])
+            },
             Self::ColorNoAlpha => quote_export_func! { export_color_no_alpha() },
         }
     }
@@ -179,6 +179,7 @@ impl GetterSetterImpl {
         } = field;
 
         let function_name = format_ident!("{}{field_name}", kind.prefix());
+        let init_check = field.make_var_init_check(class_name);
 
         let signature;
         let function_body;
@@ -189,6 +190,7 @@ impl GetterSetterImpl {
                     fn #function_name(&self) -> <#field_type as ::godot::meta::GodotConvert>::Via
                 };
                 function_body = quote! {
+                    #init_check
                     <#field_type as ::godot::register::property::Var>::get_property(&self.#field_name)
                 };
             }
@@ -197,6 +199,7 @@ impl GetterSetterImpl {
                     fn #function_name(&mut self, #field_name: <#field_type as ::godot::meta::GodotConvert>::Via)
                 };
                 function_body = quote! {
+                    #init_check
                     <#field_type as ::godot::register::property::Var>::set_property(&mut self.#field_name, #field_name);
                 };
             }
@@ -225,6 +228,7 @@ impl GetterSetterImpl {
                 registered_name: None,
                 is_script_virtual: false,
                 rpc_info: None,
+                param_renames: Vec::new(),
             },
             None,
         );
@@ -29,6 +29,11 @@ pub struct FuncDefinition {
 
     /// Information about the RPC configuration, if provided.
     pub rpc_info: Option<RpcAttr>,
+
+    /// Names of the parameters as registered in Godot, from `#[arg(rename = "...")]`. `None` entries use the Rust identifier.
+    ///
+    /// Can be shorter than the parameter list (e.g. empty for generated functions).
+    pub param_renames: Vec<Option<String>>,
 }
 
 impl FuncDefinition {
@@ -43,9 +48,24 @@ impl FuncDefinition {
             self.rust_ident().to_string()
         }
     }
+
+    /// Parameter names as seen by Godot, e.g. in GDScript autocompletion and docs.
+    pub fn godot_param_names(&self) -> Vec<String> {
+        self.signature_info
+            .param_idents
+            .iter()
+            .enumerate()
+            .map(|(index, ident)| match self.param_renames.get(index) {
+                Some(Some(rename)) => rename.clone(),
+                _ => ident.to_string(),
+            })
+            .collect()
+    }
 }
 
 /// Returns a C function which acts as the callback when a virtual method of this instance is invoked.
+///
+/// If `bool_ret_on_panic` is set, the method returns `bool` and this value is reported to Godot if the user function panics.
 //
 // Virtual methods are non-static by their nature; so there's no support for static ones.
 pub fn make_virtual_callback(
@@ -53,6 +73,7 @@ pub fn make_virtual_callback(
     signature_info: &SignatureInfo,
     before_kind: BeforeKind,
     interface_trait: Option<&venial::TypeExpr>,
+    bool_ret_on_panic: Option<bool>,
 ) -> TokenStream {
     let method_name = &signature_info.method_name;
 
@@ -66,6 +87,14 @@ pub fn make_virtual_callback(
     );
     let invocation = make_ptrcall_invocation(&wrapped_method, true);
 
+    // Godot does not initialize the return value of virtual calls, and a panicking call doesn't write it.
+    let init_ret = bool_ret_on_panic.map(|value| {
+        quote! {
+            // SAFETY: Godot passes a pointer to the `bool` return value, which is overwritten if the call succeeds.
+            unsafe { ret.cast::<sys::GDExtensionBool>().write(sys::conv::bool_to_sys(#value)) };
+        }
+    });
+
     quote! {
         {
             use ::godot::sys;
@@ -77,6 +106,7 @@ pub fn make_virtual_callback(
                 ret: sys::GDExtensionTypePtr,
             ) {
                 let call_ctx = #call_ctx;
+                #init_ret
                 let _success = ::godot::private::handle_ptrcall_panic(
                     &call_ctx,
                     || #invocation
@@ -118,10 +148,7 @@ pub fn make_method_registration(
     let ptrcall_fn_decl = make_ptrcall_fn(&call_ctx, &forwarding_closure);
 
     // String literals II
-    let param_ident_strs = signature_info
-        .param_idents
-        .iter()
-        .map(|ident| ident.to_string());
+    let param_ident_strs = func_definition.godot_param_names();
 
     // Transport #[cfg] attrs to the FFI glue to ensure functions which were conditionally
     // removed from compilation don't cause errors.
@@ -188,13 +215,23 @@ pub struct SignatureInfo {
     pub receiver_type: ReceiverType,
     pub param_idents: Vec<Ident>,
     /// Parameter types *without* receiver.
+    ///
+    /// Parameters declared as `&Gd<T>` appear here as `Gd<T>`, which is the type used for marshalling.
     pub param_types: Vec<venial::TypeExpr>,
+    /// For each parameter, whether it is declared as `&Gd<T>` and must be passed to the user function by reference.
+    pub param_by_ref: Vec<bool>,
     pub return_type: TokenStream,
 
     /// `(original index, new type)` only for changed parameters; empty if no changes.
     ///
     /// Index points into original venial tokens (i.e. takes into account potential receiver params).
     pub modified_param_types: Vec<(usize, venial::TypeExpr)>,
+
+    /// Whether the user function returns a `Display` type, which is converted to `GString` (`#[func(as_string)]`).
+    pub return_as_string: bool,
+
+    /// Whether the user function is an `async fn`, whose future is spawned as a task. Godot sees a `Signal` return type.
+    pub is_async: bool,
 }
 
 impl SignatureInfo {
@@ -204,11 +241,26 @@ impl SignatureInfo {
             receiver_type: ReceiverType::Mut,
             param_idents: vec![],
             param_types: vec![],
+            param_by_ref: vec![],
             return_type: quote! { () },
             modified_param_types: vec![],
+            return_as_string: false,
+            is_async: false,
         }
     }
 
+    /// Registers the return type as `GString`, converting the value returned by the user function via `Display`.
+    pub fn convert_return_to_string(&mut self) {
+        self.return_type = quote! { ::godot::builtin::GString };
+        self.return_as_string = true;
+    }
+
+    /// Registers the return type as `Signal`, which is emitted with the output of the `async fn` once its future completes.
+    pub fn convert_return_to_signal(&mut self) {
+        self.return_type = quote! { ::godot::builtin::Signal };
+        self.is_async = true;
+    }
+
     // The below functions share quite a bit of tokenization. If ever we run into codegen slowness, we could cache/reuse identical
     // sub-expressions.
 
@@ -216,6 +268,21 @@ impl SignatureInfo {
         // Note: for GdSelf receivers, first parameter is not even part of SignatureInfo anymore.
         util::make_signature_tuple_type(&self.return_type, &self.param_types)
     }
+
+    /// Expressions to pass the (marshalled) parameters to the user function, borrowing those declared as `&Gd<T>`.
+    pub fn param_forward_exprs(&self) -> Vec<TokenStream> {
+        self.param_idents
+            .iter()
+            .zip(&self.param_by_ref)
+            .map(|(ident, by_ref)| {
+                if *by_ref {
+                    quote! { &#ident }
+                } else {
+                    quote! { #ident }
+                }
+            })
+            .collect()
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -239,6 +306,7 @@ fn make_forwarding_closure(
 ) -> TokenStream {
     let method_name = &signature_info.method_name;
     let params = &signature_info.param_idents;
+    let args = signature_info.param_forward_exprs();
 
     let instance_decl = match &signature_info.receiver_type {
         ReceiverType::Ref => quote! {
@@ -274,13 +342,14 @@ fn make_forwarding_closure(
                             _ => unreachable!("unexpected receiver type"), // checked above.
                         };
 
-                        quote! { <#class_name as #interface_trait>::#method_name( #instance_ref, #(#params),* ) }
+                        quote! { <#class_name as #interface_trait>::#method_name( #instance_ref, #(#args),* ) }
                     }
 
                     // impl Class {...}
-                    None => quote! { instance.#method_name( #(#params),* ) },
+                    None => quote! { instance.#method_name( #(#args),* ) },
                 }
             };
+            let method_call = maybe_convert_return(signature_info, method_call);
 
             quote! {
                 |instance_ptr, params| {
@@ -298,6 +367,11 @@ fn make_forwarding_closure(
         ReceiverType::GdSelf => {
             // Method call is always present, since GdSelf implies that the user declares the method.
             // (Absent method is only used in the case of a generated default virtual method, e.g. for ready()).
+            let method_call = maybe_convert_return(
+                signature_info,
+                quote! { #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#args),*) },
+            );
+
             quote! {
                 |instance_ptr, params| {
                     let ( #(#params,)* ) = params;
@@ -306,22 +380,44 @@ fn make_forwarding_closure(
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
 
                     #before_method_call
-                    #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*)
+                    #method_call
                 }
             }
         }
         ReceiverType::Static => {
             // No before-call needed, since static methods are not virtual.
+            let method_call = maybe_convert_return(
+                signature_info,
+                quote! { #class_name::#method_name(#(#args),*) },
+            );
+
             quote! {
                 |_, params| {
                     let ( #(#params,)* ) = params;
-                    #class_name::#method_name(#(#params),*)
+                    #method_call
                 }
             }
         }
     }
 }
 
+/// For `#[func(as_string)]`, wraps the call to the user function in a `Display` -> `GString` conversion.
+///
+/// For `async fn`, spawns the returned future instead, returning the signal that GDScript can `await`.
+fn maybe_convert_return(signature_info: &SignatureInfo, method_call: TokenStream) -> TokenStream {
+    if signature_info.is_async {
+        quote! {
+            ::godot::private::spawn_async_func(#method_call)
+        }
+    } else if signature_info.return_as_string {
+        quote! {
+            ::godot::builtin::GString::from(::std::string::ToString::to_string(&#method_call))
+        }
+    } else {
+        method_call
+    }
+}
+
 /// Maps each usage of `Self` to the struct it's referencing,
 /// since `Self` can't be used inside nested functions.
 fn map_self_to_class_name<In, Out>(tokens: In, class_name: &Ident) -> Out
@@ -360,6 +456,7 @@ pub(crate) fn into_signature_info(
     let num_params = signature.params.inner.len();
     let mut param_idents = Vec::with_capacity(num_params);
     let mut param_types = Vec::with_capacity(num_params);
+    let mut param_by_ref = Vec::with_capacity(num_params);
     let ret_type = match signature.return_ty {
         None => quote! { () },
         Some(ty) => map_self_to_class_name(ty.tokens, class_name),
@@ -385,7 +482,12 @@ pub(crate) fn into_signature_info(
             }
             venial::FnParam::Typed(arg) => {
                 let ident = maybe_rename_parameter(arg.name, &mut next_unnamed_index);
-                let ty = match maybe_change_parameter_type(arg.ty, &method_name, index) {
+                let (arg_ty, by_ref) = match strip_gd_param_reference(&arg.ty) {
+                    Some(ty) => (ty, true),
+                    None => (arg.ty, false),
+                };
+
+                let ty = match maybe_change_parameter_type(arg_ty, &method_name, index) {
                     // Parameter type was modified.
                     Ok(ty) => {
                         modified_param_types.push((index, ty.clone()));
@@ -399,6 +501,7 @@ pub(crate) fn into_signature_info(
                 };
 
                 param_types.push(ty);
+                param_by_ref.push(by_ref);
                 param_idents.push(ident);
             }
         }
@@ -409,8 +512,48 @@ pub(crate) fn into_signature_info(
         receiver_type,
         param_idents,
         param_types,
+        param_by_ref,
         return_type: ret_type,
         modified_param_types,
+        return_as_string: false,
+        is_async: false,
+    }
+}
+
+/// If a parameter is declared as `&Gd<T>` (optionally with lifetime), returns `Gd<T>`.
+///
+/// The engine passes objects by pointer, so the marshalled `Gd<T>` lives on the stack of the FFI glue for the duration of the call, and the
+/// user function receives a reference to it.
+fn strip_gd_param_reference(param_ty: &venial::TypeExpr) -> Option<venial::TypeExpr> {
+    let mut tokens = param_ty.tokens.as_slice();
+
+    match tokens.first() {
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '&' => tokens = &tokens[1..],
+        _ => return None,
+    }
+
+    // Lifetime `'a` is tokenized as punct `'` followed by ident `a`.
+    if let Some(TokenTree::Punct(punct)) = tokens.first() {
+        if punct.as_char() == '\'' {
+            tokens = tokens.get(2..)?;
+        }
+    }
+
+    // `&mut Gd<T>` is not supported; the object is owned by the FFI glue.
+    if matches!(tokens.first(), Some(TokenTree::Ident(ident)) if ident == "mut") {
+        return None;
+    }
+
+    // Type path like `Gd<T>` or `godot::obj::Gd<T>`: the segment before the first `<` must be `Gd`.
+    let generic_start = tokens
+        .iter()
+        .position(|tt| matches!(tt, TokenTree::Punct(punct) if punct.as_char() == '<'))?;
+
+    match generic_start.checked_sub(1).map(|i| &tokens[i]) {
+        Some(TokenTree::Ident(ident)) if ident == "Gd" => Some(venial::TypeExpr {
+            tokens: tokens.to_vec(),
+        }),
+        _ => None,
     }
 }
 
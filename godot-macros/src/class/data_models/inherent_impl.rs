@@ -7,8 +7,8 @@
 
 use crate::class::{
     into_signature_info, make_constant_registration, make_method_registration,
-    make_signal_registrations, ConstDefinition, FuncDefinition, RpcAttr, RpcMode, SignalDefinition,
-    SignatureInfo, TransferMode,
+    make_signal_registrations, ConstDefinition, FuncDefinition, ReceiverType, RpcAttr, RpcMode,
+    SignalDefinition, SignatureInfo, TransferMode,
 };
 use crate::util::{
     bail, c_str, format_funcs_collection_struct, ident, make_funcs_collection_constants,
@@ -16,7 +16,7 @@ use crate::util::{
 };
 use crate::{handle_mutually_exclusive_keys, util, ParseResult};
 
-use proc_macro2::{Delimiter, Group, Ident, TokenStream};
+use proc_macro2::{Delimiter, Group, Ident, Span, TokenStream};
 use quote::spanned::Spanned;
 use quote::{format_ident, quote};
 
@@ -64,6 +64,7 @@ struct FuncAttr {
     pub rename: Option<String>,
     pub is_virtual: bool,
     pub has_gd_self: bool,
+    pub as_string: Option<Span>,
 }
 
 #[derive(Default)]
@@ -225,9 +226,18 @@ fn process_godot_fns(
             continue;
         };
 
+        // `async` is only supported for #[func], checked below.
+        let is_async = function.qualifiers.tk_async.is_some();
+        if is_async && !matches!(attr.ty, ItemAttrType::Func(..)) {
+            return attr.bail("`async` is only supported for #[func]", function);
+        }
+        if is_async {
+            // Tasks are only available from Godot 4.2 on.
+            require_api_version!("4.2", &function.name, "async #[func]")?;
+        }
+
         if function.qualifiers.tk_default.is_some()
             || function.qualifiers.tk_const.is_some()
-            || function.qualifiers.tk_async.is_some()
             || function.qualifiers.tk_unsafe.is_some()
             || function.qualifiers.tk_extern.is_some()
             || function.qualifiers.extern_abi.is_some()
@@ -242,6 +252,7 @@ fn process_godot_fns(
         match attr.ty {
             ItemAttrType::Func(func, rpc_info) => {
                 let external_attributes = function.attributes.clone();
+                let mut param_renames = extract_param_renames(function)?;
 
                 // Signatures are the same thing without body.
                 let mut signature = util::reduce_to_signature(function);
@@ -263,6 +274,13 @@ fn process_godot_fns(
                             );
                         };
 
+                        if param_renames.remove(0).is_some() {
+                            return bail!(
+                                &param.name,
+                                "#[arg(rename)] is not supported for the `gd_self` parameter"
+                            );
+                        }
+
                         // Note: parameter is explicitly NOT renamed (maybe_rename_parameter).
                         Some(param.name)
                     }
@@ -271,9 +289,48 @@ fn process_godot_fns(
                 };
 
                 // Clone might not strictly be necessary, but the 2 other callers of into_signature_info() are better off with pass-by-value.
-                let signature_info =
+                let mut signature_info =
                     into_signature_info(signature.clone(), class_name, gd_self_parameter.is_some());
 
+                if let Some(span) = func.as_string {
+                    if func.is_virtual {
+                        return bail!(span, "#[func(as_string)] cannot be combined with `virtual`");
+                    }
+                    if function.return_ty.is_none() {
+                        return bail!(
+                            span,
+                            "#[func(as_string)] requires a return type implementing `Display`"
+                        );
+                    }
+
+                    signature_info.convert_return_to_string();
+                }
+
+                if is_async {
+                    if func.is_virtual {
+                        return attr
+                            .bail("async #[func] cannot be combined with `virtual`", function);
+                    }
+                    if func.as_string.is_some() {
+                        return attr.bail(
+                            "async #[func] cannot be combined with `as_string`",
+                            function,
+                        );
+                    }
+                    if matches!(
+                        signature_info.receiver_type,
+                        ReceiverType::Ref | ReceiverType::Mut
+                    ) {
+                        // The future outlives the call, so it cannot hold a bind guard.
+                        return attr.bail(
+                            "async #[func] cannot take `&self` or `&mut self`; use #[func(gd_self)] with a `Gd<Self>` parameter instead",
+                            function,
+                        );
+                    }
+
+                    signature_info.convert_return_to_signal();
+                }
+
                 // For virtual methods, rename/mangle existing user method and create a new method with the original name,
                 // which performs a dynamic dispatch.
                 let registered_name = if func.is_virtual {
@@ -297,6 +354,7 @@ fn process_godot_fns(
                     registered_name,
                     is_script_virtual: func.is_virtual,
                     rpc_info,
+                    param_renames,
                 });
             }
 
@@ -392,6 +450,51 @@ fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<ConstDefi
     Ok(constant_signatures)
 }
 
+/// Parses and removes `#[arg(rename = "name")]` attributes on the parameters of a `#[func]`.
+///
+/// Returns one entry per typed (non-receiver) parameter: the name registered in Godot, or `None` to keep the Rust identifier.
+fn extract_param_renames(function: &mut venial::Function) -> ParseResult<Vec<Option<String>>> {
+    let mut renames = vec![];
+
+    for (param, _punct) in function.params.iter_mut() {
+        let venial::FnParam::Typed(param) = param else {
+            continue;
+        };
+
+        let rename = if let Some(mut parser) = KvParser::parse(&param.attributes, "arg")? {
+            // #[arg(rename = "name")]
+            let value = parser.handle_expr_required("rename")?;
+            parser.finish()?;
+
+            let value_str = value.to_string();
+            let Some(rename) = value_str
+                .strip_prefix('"')
+                .and_then(|s| s.strip_suffix('"'))
+                .filter(|s| util::is_identifier(s))
+            else {
+                return bail!(
+                    value,
+                    "#[arg(rename)] expects a string literal with a valid identifier, e.g. `rename = \"target\"`"
+                );
+            };
+
+            Some(rename.to_string())
+        } else {
+            None
+        };
+
+        param.attributes.retain(|attr| {
+            !attr
+                .get_single_path_segment()
+                .is_some_and(|name| name == "arg")
+        });
+
+        renames.push(rename);
+    }
+
+    Ok(renames)
+}
+
 fn add_virtual_script_call(
     virtual_functions: &mut Vec<venial::Function>,
     function: &mut venial::Function,
@@ -425,6 +528,18 @@ fn add_virtual_script_call(
     let sig_tuple = signature_info.tuple_type();
     let arg_names = &signature_info.param_idents;
 
+    // Script dispatch needs owned arguments; `&Gd<T>` parameters are cloned.
+    let script_args = arg_names
+        .iter()
+        .zip(&signature_info.param_by_ref)
+        .map(|(name, by_ref)| {
+            if *by_ref {
+                quote! { #name.clone() }
+            } else {
+                quote! { #name }
+            }
+        });
+
     let (object_ptr, receiver);
     if let Some(gd_self_parameter) = gd_self_parameter {
         object_ptr = quote! { #gd_self_parameter.obj_sys() };
@@ -443,7 +558,7 @@ fn add_virtual_script_call(
         if has_virtual_override {
             // Dynamic dispatch.
             type CallSig = #sig_tuple;
-            let args = (#( #arg_names, )*);
+            let args = (#( #script_args, )*);
             unsafe {
                 <CallSig as ::godot::meta::VarcallSignatureTuple>::out_script_virtual_call(
                     #class_name_str,
@@ -510,12 +625,18 @@ where
                 // #[func(gd_self)]
                 let has_gd_self = parser.handle_alone("gd_self")?;
 
+                // #[func(as_string)]
+                let as_string = parser
+                    .handle_alone_with_span("as_string")?
+                    .map(|key| key.span());
+
                 parser.finish()?;
 
                 AttrParseResult::Func(FuncAttr {
                     rename,
                     is_virtual,
                     has_gd_self,
+                    as_string,
                 })
             }
 
@@ -6,11 +6,11 @@
  */
 
 use crate::class::{into_signature_info, make_virtual_callback, BeforeKind, SignatureInfo};
-use crate::util::ident;
+use crate::util::{bail, ident};
 use crate::{util, ParseResult};
 
-use proc_macro2::{Delimiter, Group, Ident, TokenStream};
-use quote::{quote, ToTokens};
+use proc_macro2::{Delimiter, Group, Ident, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
 
 /// Codegen for `#[godot_api] impl ISomething for MyType`.
 pub fn transform_trait_impl(mut original_impl: venial::Impl) -> ParseResult<TokenStream> {
@@ -25,6 +25,7 @@ pub fn transform_trait_impl(mut original_impl: venial::Impl) -> ParseResult<Toke
     let docs = quote! {};
 
     let mut decls = IDecls::default();
+    let mut extra_items = Vec::new();
 
     for item in original_impl.body_items.iter_mut() {
         let method = if let venial::ImplMember::AssocFunction(f) = item {
@@ -33,6 +34,15 @@ pub fn transform_trait_impl(mut original_impl: venial::Impl) -> ParseResult<Toke
             continue;
         };
 
+        // Audio mix and packet methods may be declared with safe slices; turn them into the trait's raw signature first.
+        if let Some(helper_impl) = rewrite_slice_buffer_fn(method, &class_name, &trait_base_class)?
+        {
+            extra_items.push(helper_impl);
+        }
+        if let Some(helper_impl) = rewrite_packet_out_fn(method, &class_name, &trait_base_class)? {
+            extra_items.push(helper_impl);
+        }
+
         // Transport #[cfg] attributes to the virtual method's FFI glue, to ensure it won't be
         // registered in Godot if conditionally removed from compilation.
         let cfg_attrs = util::extract_cfg_attrs(&method.attributes)
@@ -78,6 +88,7 @@ pub fn transform_trait_impl(mut original_impl: venial::Impl) -> ParseResult<Toke
                 let changed_function = handle_regular_virtual_fn(
                     &class_name,
                     &trait_path,
+                    &trait_base_class,
                     method,
                     regular_virtual_fn,
                     cfg_attrs,
@@ -113,6 +124,7 @@ pub fn transform_trait_impl(mut original_impl: venial::Impl) -> ParseResult<Toke
             signature_info: SignatureInfo::fn_ready(),
             before_kind: BeforeKind::OnlyBefore,
             interface_trait: None,
+            bool_ret_on_panic: None,
         };
 
         decls.overridden_virtuals.push(match_arm);
@@ -157,6 +169,7 @@ pub fn transform_trait_impl(mut original_impl: venial::Impl) -> ParseResult<Toke
     let mut result = quote! {
         // #original_impl inserted below.
         #decls
+        #( #extra_items )*
 
         impl ::godot::private::You_forgot_the_attribute__godot_api for #class_name {}
 
@@ -454,6 +467,7 @@ fn handle_property_get_revert<'a>(
 fn handle_regular_virtual_fn<'a>(
     class_name: &Ident,
     trait_path: &venial::TypeExpr,
+    trait_base_class: &Ident,
     original_method: &venial::Function,
     method_name: &str,
     cfg_attrs: Vec<&'a venial::Attribute>,
@@ -512,6 +526,11 @@ fn handle_regular_virtual_fn<'a>(
         BeforeKind::Without
     };
 
+    // Custom main loops quit if process() or physics_process() panics. Otherwise, the loop would keep running with a panicking callback.
+    let bool_ret_on_panic = (trait_base_class == "MainLoop"
+        && matches!(method_name, "process" | "physics_process"))
+    .then_some(true);
+
     // Note that, if the same method is implemented multiple times (with different cfg attr combinations),
     // then there will be multiple match arms annotated with the same cfg attr combinations, thus they will
     // be reduced to just one arm (at most, if the implementations aren't all removed from compilation) for
@@ -527,6 +546,7 @@ fn handle_regular_virtual_fn<'a>(
         signature_info,
         before_kind,
         interface_trait: Some(trait_path.clone()),
+        bool_ret_on_panic,
     });
 
     updated_function
@@ -535,6 +555,232 @@ fn handle_regular_virtual_fn<'a>(
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Rest of implementation
 
+/// Allows buffer-passing virtual methods to be declared safely, with `&mut [T]` or `&[T]` instead of a raw pointer and element count.
+///
+/// Applies to `IAudioStreamPlayback::mix()` and `IAudioStreamPlaybackResampled::mix_resampled()`, whose engine signatures end in the
+/// frame count. For example, `fn mix(&mut self, buffer: &mut [AudioFrame], rate_scale: f32) -> i32` is rewritten to the trait's
+/// `unsafe fn mix(&mut self, buffer: *mut AudioFrame, rate_scale: f32, frames: i32) -> i32`, which converts the buffer to a slice.
+///
+/// Also applies to `put_packet()` of the packet peer extension classes (e.g. `IMultiplayerPeerExtension`), which is declared as
+/// `fn put_packet(&mut self, buffer: &[u8]) -> Error`.
+///
+/// The user's body is moved into a hidden, safe inherent method taking the slice, which is returned as an additional item. This way, unsafe
+/// operations in the body still need an `unsafe` block.
+///
+/// Only methods of these traits are rewritten; methods with the same name in other traits are left untouched, as are methods already
+/// declared `unsafe` (with the raw signature).
+fn rewrite_slice_buffer_fn(
+    method: &mut venial::Function,
+    class_name: &Ident,
+    trait_base_class: &Ident,
+) -> ParseResult<Option<TokenStream>> {
+    let base_class = trait_base_class.to_string();
+    let (param_count, is_mut) = match method.name.to_string().as_str() {
+        "mix" if is_audio_playback_class(&base_class) => (2, true), // buffer, rate_scale
+        "mix_resampled" if base_class == "AudioStreamPlaybackResampled" => (1, true), // buffer
+        "put_packet" if is_packet_peer_extension_class(&base_class) => (1, false), // buffer
+        _ => return Ok(None),
+    };
+
+    if method.qualifiers.tk_unsafe.is_some() {
+        return Ok(None);
+    }
+
+    let typed_params: Vec<&venial::FnTypedParam> = method
+        .params
+        .iter()
+        .filter_map(|(param, _)| match param {
+            venial::FnParam::Typed(typed) => Some(typed),
+            venial::FnParam::Receiver(_) => None,
+        })
+        .collect();
+
+    let Some(buffer) = typed_params.first() else {
+        return Ok(None);
+    };
+    let Some(element_ty) = slice_element(&buffer.ty, is_mut) else {
+        return Ok(None);
+    };
+
+    if typed_params.len() != param_count {
+        return bail!(
+            &method.name,
+            "`{}` with a slice buffer takes {param_count} parameter(s) besides `&mut self`; the element count is the slice length",
+            method.name
+        );
+    }
+
+    let buffer_name = &buffer.name;
+    let rest_params = &typed_params[1..];
+    let rest_names = rest_params.iter().map(|param| &param.name);
+    let raw_rest_params = rest_params.iter().map(|param| {
+        let name = &param.name;
+        let ty = &param.ty;
+        quote! { #name: #ty, }
+    });
+    let helper_params = typed_params.iter().map(|param| {
+        let tk_mut = &param.tk_mut;
+        let name = &param.name;
+        let ty = &param.ty;
+        quote! { #tk_mut #name: #ty, }
+    });
+
+    let (ptr_ty, slice_ty, to_slice) = if is_mut {
+        (
+            quote! { *mut #element_ty },
+            quote! { &mut [#element_ty] },
+            quote! { slice_from_raw_mut },
+        )
+    } else {
+        (
+            quote! { *const #element_ty },
+            quote! { &[#element_ty] },
+            quote! { slice_from_raw },
+        )
+    };
+
+    let attributes = &method.attributes;
+    let cfg_attrs = util::extract_cfg_attrs(attributes);
+    let name = &method.name;
+    let return_ty = method.return_ty.as_ref().map(|ty| quote! { -> #ty });
+    let body = &method.body;
+    let helper = format_ident!("__godot_{}", name);
+
+    let helper_impl = quote! {
+        impl #class_name {
+            #( #cfg_attrs )*
+            #[doc(hidden)]
+            fn #helper(&mut self, #( #helper_params )*) #return_ty #body
+        }
+    };
+
+    let rewritten = quote! {
+        #( #attributes )*
+        unsafe fn #name(&mut self, #buffer_name: #ptr_ty, #( #raw_rest_params )* __element_count: i32) #return_ty {
+            // SAFETY: Godot passes a buffer with `element_count` elements, which is exclusively available to this call.
+            let #buffer_name: #slice_ty = unsafe {
+                ::godot::private::#to_slice(#buffer_name, __element_count)
+            };
+
+            Self::#helper(self, #buffer_name, #( #rest_names ),*)
+        }
+    };
+
+    let item = venial::parse_item(rewritten)?;
+    *method = item
+        .as_function()
+        .expect("rewritten method is a function")
+        .clone();
+
+    Ok(Some(helper_impl))
+}
+
+/// Allows `get_packet()` of the packet peer extension classes to be declared safely, returning the packet as a slice.
+///
+/// The engine signature passes the packet through out-pointers: `unsafe fn get_packet(&mut self, r_buffer: *mut *const u8,
+/// r_buffer_size: *mut i32) -> Error`. Instead, the method can be declared as `fn get_packet(&mut self) -> Result<&[u8], Error>`. Since the
+/// engine reads the packet after the method returns, the slice must borrow from `self` (typically a field holding the current packet).
+///
+/// The user's body is moved into a hidden inherent method, which is returned as an additional item.
+fn rewrite_packet_out_fn(
+    method: &mut venial::Function,
+    class_name: &Ident,
+    trait_base_class: &Ident,
+) -> ParseResult<Option<TokenStream>> {
+    if method.name != "get_packet"
+        || method.qualifiers.tk_unsafe.is_some()
+        || !is_packet_peer_extension_class(&trait_base_class.to_string())
+    {
+        return Ok(None);
+    }
+
+    let has_typed_params = method
+        .params
+        .iter()
+        .any(|(param, _)| matches!(param, venial::FnParam::Typed(_)));
+
+    if has_typed_params {
+        return bail!(
+            &method.name,
+            "`get_packet` takes no parameters besides `&mut self`; return the packet as `Result<&[u8], Error>`"
+        );
+    }
+
+    let Some(return_ty) = &method.return_ty else {
+        return bail!(
+            &method.name,
+            "`get_packet` must return the packet as `Result<&[u8], Error>`"
+        );
+    };
+
+    let attributes = &method.attributes;
+    let cfg_attrs = util::extract_cfg_attrs(attributes);
+    let body = &method.body;
+    let helper = ident("__godot_get_packet");
+
+    let helper_impl = quote! {
+        impl #class_name {
+            #( #cfg_attrs )*
+            #[doc(hidden)]
+            fn #helper(&mut self) -> #return_ty #body
+        }
+    };
+
+    let rewritten = quote! {
+        #( #attributes )*
+        unsafe fn get_packet(&mut self, r_buffer: *mut *const u8, r_buffer_size: *mut i32) -> ::godot::global::Error {
+            let packet = Self::#helper(self);
+
+            // SAFETY: Godot passes valid out-pointers. The packet borrows from `self` and thus outlives this call.
+            unsafe { ::godot::private::write_packet_out(packet, r_buffer, r_buffer_size) }
+        }
+    };
+
+    let item = venial::parse_item(rewritten)?;
+    *method = item
+        .as_function()
+        .expect("rewritten method is a function")
+        .clone();
+
+    Ok(Some(helper_impl))
+}
+
+/// Extensible classes with the `mix()` virtual method, i.e. `AudioStreamPlayback` and its subclass with resampling.
+fn is_audio_playback_class(base_class: &str) -> bool {
+    matches!(
+        base_class,
+        "AudioStreamPlayback" | "AudioStreamPlaybackResampled"
+    )
+}
+
+/// Extensible classes with the `get_packet()` and `put_packet()` virtual methods of `PacketPeer`.
+fn is_packet_peer_extension_class(base_class: &str) -> bool {
+    matches!(
+        base_class,
+        "PacketPeerExtension" | "MultiplayerPeerExtension" | "WebRTCDataChannelExtension"
+    )
+}
+
+/// If `ty` is `&mut [T]` (or `&[T]` if `is_mut` is false), without lifetime, returns `T`.
+fn slice_element(ty: &venial::TypeExpr, is_mut: bool) -> Option<TokenStream> {
+    match ty.tokens.as_slice() {
+        [TokenTree::Punct(amp), TokenTree::Ident(mut_kw), TokenTree::Group(group)]
+            if is_mut
+                && amp.as_char() == '&'
+                && mut_kw == "mut"
+                && group.delimiter() == Delimiter::Bracket =>
+        {
+            Some(group.stream())
+        }
+        [TokenTree::Punct(amp), TokenTree::Group(group)]
+            if !is_mut && amp.as_char() == '&' && group.delimiter() == Delimiter::Bracket =>
+        {
+            Some(group.stream())
+        }
+        _ => None,
+    }
+}
+
 /// Returns `false` if the given class does definitely not inherit `Node`, `true` otherwise.
 ///
 /// `#[godot_api]` has currently no way of checking base class at macro-resolve time, so the `_ready` branch is unconditionally
@@ -579,6 +825,7 @@ struct OverriddenVirtualFn<'a> {
     signature_info: SignatureInfo,
     before_kind: BeforeKind,
     interface_trait: Option<venial::TypeExpr>,
+    bool_ret_on_panic: Option<bool>,
 }
 
 impl OverriddenVirtualFn<'_> {
@@ -601,6 +848,7 @@ impl OverriddenVirtualFn<'_> {
             &self.signature_info,
             self.before_kind,
             self.interface_trait.as_ref(),
+            self.bool_ret_on_panic,
         );
 
         quote! {
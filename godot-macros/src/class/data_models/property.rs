@@ -85,6 +85,10 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
 
             export_hint = export.to_export_hint();
             registration_fn = quote! { register_export };
+
+            if let Some(type_check) = export.to_type_check(field_type) {
+                export_tokens.push(type_check);
+            }
         } else {
             export_hint = None;
             registration_fn = quote! { register_var };
@@ -288,6 +288,20 @@ fn make_signal_individual_struct(details: &SignalDetails) -> TokenStream {
                 &mut self.typed
             }
         }
+
+        // Allows `self.signals().my_signal().await`. The bound involving 'c avoids errors for parameters that cannot be awaited.
+        #(#signal_cfg_attrs)*
+        impl<'c> std::future::IntoFuture for #individual_struct_name<'c>
+        where
+            ::godot::register::TypedSignal<'c, #class_name, #param_tuple>: std::future::IntoFuture,
+        {
+            type Output = <::godot::register::TypedSignal<'c, #class_name, #param_tuple> as std::future::IntoFuture>::Output;
+            type IntoFuture = <::godot::register::TypedSignal<'c, #class_name, #param_tuple> as std::future::IntoFuture>::IntoFuture;
+
+            fn into_future(self) -> Self::IntoFuture {
+                std::future::IntoFuture::into_future(self.typed)
+            }
+        }
     }
 }
 
@@ -5,16 +5,17 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, Punct, TokenStream};
+use proc_macro2::{Ident, Punct, Span, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
 
 use crate::class::{
-    make_property_impl, make_virtual_callback, BeforeKind, Field, FieldCond, FieldDefault,
-    FieldExport, FieldVar, Fields, SignatureInfo,
+    into_signature_info, make_method_registration, make_property_impl, make_virtual_callback,
+    BeforeKind, Field, FieldCond, FieldDefault, FieldExport, FieldVar, Fields, FuncDefinition,
+    SignatureInfo,
 };
 use crate::util::{
-    bail, error, format_funcs_collection_struct, ident, path_ends_with_complex,
-    require_api_version, KvParser,
+    bail, error, format_funcs_collection_struct, ident, make_funcs_collection_constant,
+    path_ends_with_complex, require_api_version, KvParser,
 };
 use crate::{handle_mutually_exclusive_keys, util, ParseResult};
 
@@ -73,6 +74,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let inherits_macro = format_ident!("unsafe_inherits_transitive_{}", base_ty);
 
     let prv = quote! { ::godot::private };
+
     let godot_exports_impl = make_property_impl(class_name, &fields);
 
     let godot_withbase_impl = if let Some(Field { name, ty, .. }) = &fields.base_field {
@@ -126,6 +128,52 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         modifiers.push(quote! { with_instantiable });
     }
 
+    let builder_impl = match struct_cfg.builder_span {
+        Some(span) if !is_instantiable => {
+            return bail!(
+                span,
+                "#[class(builder)] requires a constructor; it cannot be combined with #[class(no_init)]"
+            );
+        }
+        Some(_) => make_builder_impl(class, &fields.all_fields),
+        None => TokenStream::new(),
+    };
+
+    let singleton_impl = match struct_cfg.singleton_span {
+        Some(span) if !is_instantiable => {
+            return bail!(
+                span,
+                "#[class(singleton)] requires a constructor; it cannot be combined with #[class(no_init)]"
+            );
+        }
+        // Deeper RefCounted hierarchies are caught by the `with_singleton` bounds.
+        Some(span) if struct_cfg.base_ty == ident("RefCounted") => {
+            return bail!(
+                span,
+                "#[class(singleton)] requires a manually-managed class; specify a base not inheriting RefCounted, e.g. #[class(base=Object)]"
+            );
+        }
+        Some(_) => {
+            modifiers.push(quote! { with_singleton::<#class_name> });
+            make_singleton_impl(class)
+        }
+        None => TokenStream::new(),
+    };
+
+    let eq_impl = if struct_cfg.eq_span.is_some() {
+        modifiers.push(quote! { with_content_eq::<#class_name> });
+        make_eq_impl(class_name)
+    } else {
+        TokenStream::new()
+    };
+
+    let dynamic_properties_impl = if struct_cfg.dynamic_properties {
+        modifiers.push(quote! { with_dynamic_properties::<#class_name> });
+        make_dynamic_properties_impl(class_name)
+    } else {
+        TokenStream::new()
+    };
+
     if has_default_virtual {
         modifiers.push(quote! { with_default_get_virtual_fn::<#class_name> });
     }
@@ -171,6 +219,10 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         #godot_withbase_impl
         #godot_exports_impl
         #user_class_impl
+        #builder_impl
+        #singleton_impl
+        #dynamic_properties_impl
+        #eq_impl
         #init_expecter
         #( #deprecations )*
         #( #errors )*
@@ -209,7 +261,11 @@ struct ClassAttributes {
     init_strategy: InitStrategy,
     is_tool: bool,
     is_internal: bool,
+    dynamic_properties: bool,
     rename: Option<Ident>,
+    builder_span: Option<Span>,
+    singleton_span: Option<Span>,
+    eq_span: Option<Span>,
     deprecations: Vec<TokenStream>,
 }
 
@@ -250,6 +306,201 @@ fn make_godot_init_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
     }
 }
 
+/// Generates the typed accessor `MyClass::singleton()`.
+fn make_singleton_impl(class: &venial::Struct) -> TokenStream {
+    let class_name = &class.name;
+    let vis = &class.vis_marker;
+
+    quote! {
+        impl #class_name {
+            /// Returns the engine singleton instance of this class.
+            ///
+            /// # Panics
+            /// If called before the class's init level is loaded, or after it is unloaded.
+            #vis fn singleton() -> ::godot::obj::Gd<Self> {
+                ::godot::private::user_singleton::<Self>()
+            }
+        }
+    }
+}
+
+/// Dispatches Godot's `_get`, `_set` and `_get_property_list` to the user's `DynamicProperties` impl, for `#[class(dynamic_properties)]`.
+///
+/// Implements the same cap traits as `get_property()` etc. in `#[godot_api] impl IClass`, so combining both is rejected by rustc.
+fn make_dynamic_properties_impl(class_name: &Ident) -> TokenStream {
+    quote! {
+        impl ::godot::obj::cap::GodotGet for #class_name {
+            fn __godot_get_property(&self, property: ::godot::builtin::StringName) -> Option<::godot::builtin::Variant> {
+                <Self as ::godot::obj::DynamicProperties>::get_property(self, property)
+            }
+        }
+
+        impl ::godot::obj::cap::GodotSet for #class_name {
+            fn __godot_set_property(&mut self, property: ::godot::builtin::StringName, value: ::godot::builtin::Variant) -> bool {
+                <Self as ::godot::obj::DynamicProperties>::set_property(self, property, value)
+            }
+        }
+
+        impl ::godot::obj::cap::GodotGetPropertyList for #class_name {
+            fn __godot_get_property_list(&mut self) -> Vec<::godot::meta::PropertyInfo> {
+                <Self as ::godot::obj::DynamicProperties>::get_property_list(self)
+            }
+        }
+    }
+}
+
+/// Generates content comparison for `#[class(eq)]`, based on the user's `PartialEq` impl.
+///
+/// Godot has no hook to overload `==` for objects; GDScript always compares identity there. Instead, a method `equals(other)` is
+/// registered alongside the class's `#[func]`s, plus `PartialEq` impls between `Gd<Self>` and `Self` on the Rust side.
+///
+/// The `equals` constant in the funcs collection clashes with the one of a user-defined `#[func] fn equals`, rejecting the duplicate at
+/// compile time.
+fn make_eq_impl(class_name: &Ident) -> TokenStream {
+    let signature = quote! {
+        fn __godot_equals(&self, other: ::godot::builtin::Variant) -> bool
+    };
+
+    let registration = make_method_registration(
+        class_name,
+        FuncDefinition {
+            signature_info: into_signature_info(
+                util::parse_signature(signature.clone()),
+                class_name,
+                false,
+            ),
+            external_attributes: Vec::new(),
+            registered_name: Some("equals".to_string()),
+            is_script_virtual: false,
+            rpc_info: None,
+            param_renames: Vec::new(),
+        },
+        None,
+    )
+    .expect("equals() registration should not fail");
+
+    let funcs_collection = format_funcs_collection_struct(class_name);
+    let funcs_collection_constant =
+        make_funcs_collection_constant(class_name, &ident("equals"), None, &[]);
+
+    quote! {
+        impl #class_name {
+            #[doc(hidden)]
+            pub #signature {
+                ::godot::private::user_class_equals(self, &other)
+            }
+        }
+
+        impl #funcs_collection {
+            #funcs_collection_constant
+        }
+
+        impl ::godot::obj::cap::GodotContentEq for #class_name {
+            fn __register_equals() {
+                #registration
+            }
+        }
+
+        impl ::std::cmp::PartialEq<#class_name> for ::godot::obj::Gd<#class_name> {
+            fn eq(&self, other: &#class_name) -> bool {
+                *self.bind() == *other
+            }
+        }
+
+        impl ::std::cmp::PartialEq<::godot::obj::Gd<#class_name>> for #class_name {
+            fn eq(&self, other: &::godot::obj::Gd<#class_name>) -> bool {
+                *self == *other.bind()
+            }
+        }
+    }
+}
+
+/// Generates `MyClass::builder()` and `MyClassBuilder`, with one setter per `#[var]` or `#[export]` field.
+///
+/// The object is created through the regular constructor (generated or user-defined `init`), so unset fields keep their initial values.
+fn make_builder_impl(class: &venial::Struct, all_fields: &[Field]) -> TokenStream {
+    let class_name = &class.name;
+    let vis = &class.vis_marker;
+    let builder_name = format_ident!("{}Builder", class_name);
+
+    // OnReady fields are only initialized in ready(), which would overwrite any value set here.
+    let fields: Vec<&Field> = all_fields
+        .iter()
+        .filter(|field| (field.var.is_some() || field.export.is_some()) && !field.is_onready)
+        .collect();
+
+    let names: Vec<&Ident> = fields.iter().map(|field| &field.name).collect();
+    let types = fields.iter().map(|field| &field.ty);
+
+    let setters = fields.iter().map(|field| {
+        let name = &field.name;
+        let ty = &field.ty;
+        let doc = format!("Sets the `{name}` field; otherwise, the value from `init` is kept.");
+
+        quote_spanned! { field.span=>
+            #[doc = #doc]
+            pub fn #name(mut self, value: #ty) -> Self {
+                self.#name = Some(value);
+                self
+            }
+        }
+    });
+
+    let assignments = if names.is_empty() {
+        TokenStream::new()
+    } else {
+        quote! {
+            {
+                let mut this = gd.bind_mut();
+                #(
+                    if let Some(value) = self.#names {
+                        this.#names = value;
+                    }
+                )*
+            }
+        }
+    };
+
+    let builder_doc =
+        format!("Builder for [`{class_name}`], created with [`{class_name}::builder()`].");
+    let builder_fn_doc = format!(
+        "Returns a builder, which constructs a `Gd<{class_name}>` via `init` and then assigns the fields set on the builder."
+    );
+
+    quote! {
+        #[doc = #builder_doc]
+        #[must_use]
+        #vis struct #builder_name {
+            #( #names: Option<#types>, )*
+        }
+
+        impl #class_name {
+            #[doc = #builder_fn_doc]
+            #vis fn builder() -> #builder_name {
+                #builder_name {
+                    #( #names: None, )*
+                }
+            }
+        }
+
+        impl #builder_name {
+            #( #setters )*
+
+            /// Constructs the object, then assigns all fields that were set on this builder.
+            ///
+            /// For manually managed classes, the result must be freed or attached to the scene tree.
+            pub fn build(self) -> ::godot::obj::Gd<#class_name> {
+                use ::godot::obj::bounds::Declarer as _;
+
+                #[allow(unused_mut)]
+                let mut gd = ::godot::obj::bounds::DeclUser::create_gd::<#class_name>();
+                #assignments
+                gd
+            }
+        }
+    }
+}
+
 fn make_onready_init(all_fields: &[Field]) -> TokenStream {
     let onready_fields = all_fields
         .iter()
@@ -343,8 +594,13 @@ fn make_user_class_impl(
         let tool_check = util::make_virtual_tool_check();
         let signature_info = SignatureInfo::fn_ready();
 
-        let callback =
-            make_virtual_callback(class_name, &signature_info, BeforeKind::OnlyBefore, None);
+        let callback = make_virtual_callback(
+            class_name,
+            &signature_info,
+            BeforeKind::OnlyBefore,
+            None,
+            None,
+        );
 
         // See also __virtual_call() codegen.
         // This doesn't explicitly check if the base class inherits from Node (and thus has `_ready`), but the derive-macro already does
@@ -407,7 +663,11 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
     let mut init_strategy = InitStrategy::UserDefined;
     let mut is_tool = false;
     let mut is_internal = false;
+    let mut dynamic_properties = false;
     let mut rename: Option<Ident> = None;
+    let mut builder_span = None;
+    let mut singleton_span = None;
+    let mut eq_span = None;
     let mut deprecations = vec![];
 
     // #[class] attribute on struct
@@ -439,6 +699,21 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
         // #[class(rename = NewName)]
         rename = parser.handle_ident("rename")?;
 
+        // #[class(builder)]
+        if let Some(key) = parser.handle_alone_with_span("builder")? {
+            builder_span = Some(key.span());
+        }
+
+        // #[class(singleton)]
+        if let Some(key) = parser.handle_alone_with_span("singleton")? {
+            singleton_span = Some(key.span());
+        }
+
+        // #[class(eq)]
+        if let Some(key) = parser.handle_alone_with_span("eq")? {
+            eq_span = Some(key.span());
+        }
+
         // #[class(internal)]
         // Named "internal" following Godot terminology: https://github.com/godotengine/godot-cpp/blob/master/include/godot_cpp/core/class_db.hpp#L327
         if let Some(span) = parser.handle_alone_with_span("internal")? {
@@ -446,6 +721,12 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             is_internal = true;
         }
 
+        // #[class(dynamic_properties)]
+        if let Some(span) = parser.handle_alone_with_span("dynamic_properties")? {
+            require_api_version!("4.3", span, "#[class(dynamic_properties)]")?;
+            dynamic_properties = true;
+        }
+
         // Deprecated #[class(hidden)]
         if let Some(ident) = parser.handle_alone_with_span("hidden")? {
             require_api_version!("4.2", &ident, "#[class(hidden)]")?;
@@ -466,7 +747,11 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
         init_strategy,
         is_tool,
         is_internal,
+        dynamic_properties,
         rename,
+        builder_span,
+        singleton_span,
+        eq_span,
         deprecations,
     })
 }
@@ -591,6 +876,14 @@ fn parse_fields(
         // #[export]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export")? {
             let export = FieldExport::new_from_kv(&mut parser)?;
+            if export.export_type.requires_node_path()
+                && !path_ends_with_complex(&field.ty, "NodePath")
+            {
+                return bail!(
+                    parser.span(),
+                    "#[export(node_path_valid_types)] is only valid on `NodePath` fields"
+                );
+            }
             field.export = Some(export);
             parser.finish()?;
         }
@@ -656,6 +949,16 @@ fn parse_fields(
                 ));
             }
         } else {
+            if field.is_onready {
+                if let Some(export) = field.export.as_ref() {
+                    errors.push(error!(
+                        export.span,
+                        "#[export] is not supported on `OnReady<T>` fields, as ready() does not run in the editor; \
+                        use #[var] instead, or `OnEditor<T>` for values set in the editor"
+                    ));
+                }
+            }
+
             all_fields.push(field);
         }
     }
@@ -0,0 +1,106 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+/// Derives `InputActions` for a fieldless enum.
+pub fn derive_input_actions(item: venial::Item) -> ParseResult<TokenStream> {
+    let enum_ = match item {
+        venial::Item::Enum(enum_) => enum_,
+        other => return bail!(other, "#[derive(InputActions)] only supports enums"),
+    };
+
+    if let Some(generic_params) = &enum_.generic_params {
+        return bail!(
+            generic_params,
+            "#[derive(InputActions)] does not support lifetimes or generic parameters"
+        );
+    }
+
+    let mut variant_names = Vec::new();
+    let mut action_names = Vec::new();
+
+    for variant in enum_.variants.items() {
+        if !matches!(variant.fields, venial::Fields::Unit) {
+            return bail!(
+                &variant.fields,
+                "#[derive(InputActions)] only supports enumerators without fields"
+            );
+        }
+
+        let action_name = match KvParser::parse(&variant.attributes, "action")? {
+            Some(mut parser) => {
+                let name = parser.handle_expr_required("name")?;
+                parser.finish()?;
+                name
+            }
+            None => {
+                let snake = to_snake_case(&variant.name.to_string());
+                quote! { #snake }
+            }
+        };
+
+        variant_names.push(variant.name.clone());
+        action_names.push(action_name);
+    }
+
+    if variant_names.is_empty() {
+        return bail!(
+            &enum_.name,
+            "#[derive(InputActions)] requires at least one enumerator"
+        );
+    }
+
+    let name = &enum_.name;
+    let indices = 0..variant_names.len();
+
+    Ok(quote! {
+        impl ::godot::tools::InputActions for #name {
+            const ALL: &'static [Self] = &[ #( Self::#variant_names ),* ];
+
+            fn action_name(self) -> &'static str {
+                match self {
+                    #( Self::#variant_names => #action_names, )*
+                }
+            }
+
+            fn __action_index(self) -> usize {
+                match self {
+                    #( Self::#variant_names => #indices, )*
+                }
+            }
+        }
+    })
+}
+
+/// Converts `PascalCase` to `snake_case`, keeping acronyms together: `UIAccept` -> `ui_accept`, `MoveLeft2` -> `move_left2`.
+fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut result = String::with_capacity(name.len() + 4);
+
+    for (i, &ch) in chars.iter().enumerate() {
+        if ch.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                result.push('_');
+            }
+        }
+
+        result.extend(ch.to_lowercase());
+    }
+
+    result
+}
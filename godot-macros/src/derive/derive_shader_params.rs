@@ -0,0 +1,82 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+/// Derives `ShaderParams` for a struct with named fields.
+pub fn derive_shader_params(item: venial::Item) -> ParseResult<TokenStream> {
+    let struct_ = match item {
+        venial::Item::Struct(struct_) => struct_,
+        other => return bail!(other, "#[derive(ShaderParams)] only supports structs"),
+    };
+
+    if let Some(generic_params) = &struct_.generic_params {
+        return bail!(
+            generic_params,
+            "#[derive(ShaderParams)] does not support lifetimes or generic parameters"
+        );
+    }
+
+    let fields = match &struct_.fields {
+        venial::Fields::Named(fields) => fields,
+        other => {
+            return bail!(
+                other,
+                "#[derive(ShaderParams)] only supports structs with named fields"
+            )
+        }
+    };
+
+    let mut field_names = Vec::new();
+    let mut field_types = Vec::new();
+    let mut param_names = Vec::new();
+
+    for (field, _) in fields.fields.iter() {
+        let param_name = match KvParser::parse(&field.attributes, "param")? {
+            Some(mut parser) => {
+                let name = parser.handle_expr_required("name")?;
+                parser.finish()?;
+                name
+            }
+            None => {
+                let name = field.name.to_string();
+                quote! { #name }
+            }
+        };
+
+        field_names.push(field.name.clone());
+        field_types.push(field.ty.clone());
+        param_names.push(param_name);
+    }
+
+    let name = &struct_.name;
+
+    Ok(quote! {
+        impl ::godot::tools::ShaderParams for #name {
+            fn uniforms() -> ::std::vec::Vec<::godot::tools::ShaderUniform> {
+                ::std::vec![
+                    #(
+                        ::godot::tools::ShaderUniform {
+                            name: #param_names,
+                            variant_type: ::godot::private::variant_type_of::<#field_types>(),
+                        },
+                    )*
+                ]
+            }
+
+            fn apply_to(&self, material: &mut ::godot::classes::ShaderMaterial) {
+                #(
+                    material.set_param(#param_names, ::godot::meta::ToGodot::to_variant(&self.#field_names));
+                )*
+            }
+        }
+    })
+}
@@ -11,11 +11,15 @@ mod data_models;
 mod derive_export;
 mod derive_from_godot;
 mod derive_godot_convert;
+mod derive_input_actions;
+mod derive_shader_params;
 mod derive_to_godot;
 mod derive_var;
 
 pub(crate) use derive_export::*;
 pub(crate) use derive_from_godot::*;
 pub(crate) use derive_godot_convert::*;
+pub(crate) use derive_input_actions::*;
+pub(crate) use derive_shader_params::*;
 pub(crate) use derive_to_godot::*;
 pub(crate) use derive_var::*;
@@ -8,7 +8,7 @@
 mod markdown_converter;
 
 use crate::class::{ConstDefinition, Field, FuncDefinition, SignalDefinition};
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
 /// Returns code containing the doc information of a `#[derive(GodotClass)] struct MyClass` declaration iff class or any of its members is documented.
@@ -147,7 +147,7 @@ fn attribute_docs_to_bbcode(doc: &[venial::Attribute]) -> Option<String> {
 fn format_venial_params_xml(params: &venial::Punctuated<venial::FnParam>) -> String {
     let non_receiver_params = params.iter().filter_map(|(param, _punct)| match param {
         venial::FnParam::Receiver(_) => None,
-        venial::FnParam::Typed(p) => Some((&p.name, &p.ty)),
+        venial::FnParam::Typed(p) => Some((p.name.to_string(), &p.ty)),
     });
 
     format_params_xml(non_receiver_params)
@@ -199,16 +199,14 @@ pub fn format_member_xml(member: &Field) -> Option<String> {
 
     Some(format!(
         r#"<member name="{name}" type="{ty}" default="{default}">{docs}</member>"#,
-        name = xml_escape(name.to_string()),
+        name = xml_escape(name),
         ty = xml_escape(ty),
         default = xml_escape(default),
         docs = xml_escape(docs),
     ))
 }
 
-fn format_params_xml<'a, 'b>(
-    params: impl Iterator<Item = (&'a Ident, &'b venial::TypeExpr)>,
-) -> String {
+fn format_params_xml<'a>(params: impl Iterator<Item = (String, &'a venial::TypeExpr)>) -> String {
     use std::fmt::Write;
 
     let mut output = String::new();
@@ -216,7 +214,7 @@ fn format_params_xml<'a, 'b>(
         write!(
             output,
             r#"<param index="{index}" name="{name}" type="{ty}" />"#,
-            name = xml_escape(name.to_string()),
+            name = xml_escape(name),
             ty = xml_escape(ty.to_token_stream().to_string()),
         )
         .expect("write to string failed");
@@ -267,7 +265,10 @@ fn format_method_xml(method: &FuncDefinition) -> Option<String> {
     let return_ty = signature.return_type.to_token_stream().to_string();
     let return_ty = xml_escape(return_ty);
 
-    let param_names_and_types = signature.param_idents.iter().zip(&signature.param_types);
+    let param_names_and_types = method
+        .godot_param_names()
+        .into_iter()
+        .zip(&signature.param_types);
     let params = format_params_xml(param_names_and_types);
 
     Some(format!(
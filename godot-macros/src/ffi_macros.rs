@@ -9,26 +9,18 @@
 
 #![cfg(feature = "experimental-wasm")]
 
-use crate::util::bail;
 use crate::ParseResult;
-use proc_macro2::TokenStream;
+use proc_macro2::{TokenStream, TokenTree};
 use quote::{format_ident, quote};
 
 pub(super) fn wasm_declare_init_fn(input: TokenStream) -> ParseResult<TokenStream> {
-    if !input.is_empty() {
-        return bail!(input, "macro expects no arguments");
-    }
-
-    // Create sufficiently unique identifier without entire `uuid` (let alone `rand`) crate dependency.
-    let a = unsafe { libc::rand() };
-    let b = unsafe { libc::rand() };
-
     // Rust presently requires that statics with a custom `#[link_section]` must be a simple
     // list of bytes on the Wasm target (with no extra levels of indirection such as references).
     //
-    // As such, instead we export a function with a random name of known prefix to be used by the embedder.
+    // As such, instead we export a function with a unique name of known prefix to be used by the embedder.
     // This prefix is queried at load time, see godot-macros/src/gdextension.rs.
-    let function_name = format_ident!("__godot_rust_registrant_{a}_{b}");
+    let suffix = unique_registrant_suffix(&input);
+    let function_name = format_ident!("__godot_rust_registrant_{suffix}");
 
     let code = quote! {
         #[cfg(target_family = "wasm")] // Strictly speaking not necessary, as this macro is only invoked for Wasm.
@@ -40,3 +32,57 @@ pub(super) fn wasm_declare_init_fn(input: TokenStream) -> ParseResult<TokenStrea
 
     Ok(code)
 }
+
+/// Returns a suffix that is unique across all crates linked into the same Wasm binary.
+///
+/// All registrants end up in one module, so names must not collide between crates (e.g. a library registering classes, and the extension
+/// using it). Crate name and version identify the crate; within it, `input` (the registration code) is hashed together with the source
+/// positions of its tokens. The latter tell apart identical code in different places, e.g. same-named classes or tests in two modules.
+///
+/// The name depends only on the crate's source, not on the order in which macros are expanded, so builds stay reproducible.
+fn unique_registrant_suffix(input: &TokenStream) -> String {
+    let crate_name = std::env::var("CARGO_CRATE_NAME").unwrap_or_default();
+    let version = std::env::var("CARGO_PKG_VERSION").unwrap_or_default();
+
+    let mut hash = Fnv1a::new();
+    hash.write(input.to_string().as_bytes());
+    hash_token_positions(input.clone(), &mut hash);
+
+    let suffix = format!("{crate_name}_{version}_{:016x}", hash.0);
+    suffix
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// Feeds the source position of each token (recursively) into `hash`.
+///
+/// Stable Rust offers no direct access to span locations in proc-macros before 1.88. The `Debug` representation has the form
+/// `#<ctx> bytes(<lo>..<hi>)`, where only the byte range is kept: the syntax context is numbered in expansion order.
+fn hash_token_positions(tokens: TokenStream, hash: &mut Fnv1a) {
+    for token in tokens {
+        let span = format!("{:?}", token.span());
+        let position = span.find("bytes(").map_or(span.as_str(), |i| &span[i..]);
+        hash.write(position.as_bytes());
+
+        if let TokenTree::Group(group) = token {
+            hash_token_positions(group.stream(), hash);
+        }
+    }
+}
+
+/// 64-bit FNV-1a hash; deterministic across compiler runs, unlike `std`'s `DefaultHasher`.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
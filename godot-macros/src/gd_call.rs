@@ -0,0 +1,117 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Delimiter, Ident, Spacing, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned};
+
+use crate::util::{bail, safe_ident};
+use crate::ParseResult;
+
+/// Parses `receiver.method(positional...; name = value, ...)` and expands it to the generated typed call.
+pub(super) fn gd_call(input: TokenStream) -> ParseResult<TokenStream> {
+    let mut tokens: Vec<TokenTree> = input.into_iter().collect();
+
+    // Expected tail: `.` `method` `( ... )`.
+    let Some(TokenTree::Group(args)) = tokens.pop() else {
+        return bail!(
+            TokenStream::new(),
+            "gd_call! expects a method call, e.g. `gd_call!(node.add_child(&child))`"
+        );
+    };
+    if args.delimiter() != Delimiter::Parenthesis {
+        return bail!(args, "expected parenthesized arguments");
+    }
+
+    let Some(TokenTree::Ident(method)) = tokens.pop() else {
+        return bail!(args, "expected method name before arguments");
+    };
+
+    match tokens.pop() {
+        Some(TokenTree::Punct(dot)) if dot.as_char() == '.' => {}
+        _ => return bail!(method, "expected `receiver.method(...)`"),
+    }
+
+    if tokens.is_empty() {
+        return bail!(method, "missing receiver before `.{method}`");
+    }
+
+    let receiver: TokenStream = tokens.into_iter().collect();
+    let (positional, defaults) = split_args(args.stream())?;
+
+    if defaults.is_empty() {
+        return Ok(quote! { #receiver . #method ( #positional ) });
+    }
+
+    // Keep span of the user's method, so that unknown methods are reported at the right place.
+    let method_ex = format_ident!("{}_ex", method, span = method.span());
+    let setters = defaults.into_iter().map(|(name, value)| {
+        let span = name.span();
+        let mut setter = safe_ident(name.to_string().trim_start_matches("r#"));
+        setter.set_span(span);
+
+        quote_spanned! { span=> . #setter ( #value ) }
+    });
+
+    Ok(quote! {
+        #receiver . #method_ex ( #positional ) #( #setters )* .done()
+    })
+}
+
+/// Splits arguments at the top-level `;` into positional ones and `name = value` pairs.
+fn split_args(args: TokenStream) -> ParseResult<(TokenStream, Vec<(Ident, TokenStream)>)> {
+    let mut positional = TokenStream::new();
+    let mut iter = args.into_iter().peekable();
+
+    for tt in iter.by_ref() {
+        if is_punct(&tt, ';') {
+            break;
+        }
+        positional.extend([tt]);
+    }
+
+    let mut defaults = Vec::new();
+    while iter.peek().is_some() {
+        let name = match iter.next() {
+            Some(TokenTree::Ident(name)) => name,
+            Some(other) => return bail!(other, "expected `name = value` for default parameter"),
+            None => unreachable!(),
+        };
+
+        match iter.next() {
+            Some(TokenTree::Punct(eq)) if eq.as_char() == '=' && eq.spacing() == Spacing::Alone => {
+            }
+            _ => return bail!(name, "expected `=` after default parameter `{name}`"),
+        }
+
+        let mut value = TokenStream::new();
+        for tt in iter.by_ref() {
+            if is_punct(&tt, ',') {
+                break;
+            }
+            value.extend([tt]);
+        }
+
+        if value.is_empty() {
+            return bail!(name, "missing value for default parameter `{name}`");
+        }
+
+        if defaults
+            .iter()
+            .any(|(existing, _): &(Ident, _)| *existing == name)
+        {
+            return bail!(name, "default parameter `{name}` specified more than once");
+        }
+
+        defaults.push((name, value));
+    }
+
+    Ok((positional, defaults))
+}
+
+fn is_punct(tt: &TokenTree, ch: char) -> bool {
+    matches!(tt, TokenTree::Punct(punct) if punct.as_char() == ch)
+}
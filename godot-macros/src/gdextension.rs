@@ -52,6 +52,7 @@ pub fn attribute_gdextension(item: venial::Item) -> ParseResult<TokenStream> {
 
     Ok(quote! {
         #deprecation
+        ::godot::__emit_feature_deprecations!();
         #impl_decl
 
         // This cfg cannot be checked from the outer proc-macro since its 'target' is the build
@@ -16,8 +16,10 @@ mod derive;
 #[cfg(all(feature = "register-docs", since_api = "4.3"))]
 mod docs;
 mod ffi_macros;
+mod gd_call;
 mod gdextension;
 mod itest;
+mod prop;
 mod util;
 
 use proc_macro::TokenStream;
@@ -108,6 +110,114 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// ## Builder
+///
+/// For classes with many properties, `#[class(builder)]` additionally generates `MyStruct::builder()`, which returns a `MyStructBuilder`
+/// with one setter per `#[var]` or `#[export]` field. `build()` returns a `Gd<MyStruct>`:
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, builder, base=Node2D)]
+/// struct Enemy {
+///     #[export]
+///     #[init(val = 100)]
+///     health: i32,
+///
+///     #[var]
+///     speed: f32,
+///
+///     base: Base<Node2D>,
+/// }
+///
+/// fn spawn_fast_enemy() -> Gd<Enemy> {
+///     Enemy::builder().speed(5.0).build()
+///     // health is 100, as set by `init`.
+/// }
+/// ```
+///
+/// The object is constructed through its regular `init` function -- generated by `#[class(init)]` or user-defined -- and the base is
+/// wired as usual. Afterwards, the fields set on the builder are assigned directly, without calling custom `#[var(set)]` setters.
+/// Fields that are not set keep their `init` values, e.g. from `#[init(val = ...)]`. `OnReady<T>` fields have no setter, since they are
+/// initialized later in `ready()`. The builder cannot be combined with `#[class(no_init)]`.
+///
+/// ## Engine singletons
+///
+/// With `#[class(singleton)]`, a single instance of the class is registered as an engine singleton under the class name, like `Input` or
+/// `Engine`. GDScript can then call its `#[func]` methods directly, e.g. `Scoreboard.add_points(10)`. From Rust, the instance is
+/// available through the generated `MyStruct::singleton()` accessor:
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, singleton, base=Object)]
+/// struct Scoreboard {
+///     points: i64,
+/// }
+///
+/// #[godot_api]
+/// impl Scoreboard {
+///     #[func]
+///     fn add_points(&mut self, points: i64) {
+///         self.points += points;
+///     }
+/// }
+///
+/// fn reward_player() {
+///     Scoreboard::singleton().bind_mut().add_points(10);
+/// }
+/// ```
+///
+/// The instance is constructed with the class's `init` function, after all classes of the same init level have been registered.
+/// When that level is unloaded -- on shutdown or hot reload -- the singleton is unregistered and freed before the classes are. This
+/// requires a manually-managed class (not inheriting `RefCounted`), and cannot be combined with `#[class(no_init)]`.
+///
+/// ## Content equality
+///
+/// `Gd<T>` compares object identity, and so does GDScript's `==` operator. For value-like classes, `#[class(eq)]` additionally exposes
+/// the class's own `PartialEq` impl, which must be provided by the user:
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass, PartialEq)]
+/// #[class(init, eq)]
+/// struct Money {
+///     cents: i64,
+///     currency: GString,
+/// }
+///
+/// fn same_price(a: &Gd<Money>, b: &Gd<Money>) -> bool {
+///     *a == *b.bind() // Gd<Money> == Money
+/// }
+/// ```
+///
+/// This generates:
+/// - `impl PartialEq<Money> for Gd<Money>` and `impl PartialEq<Gd<Money>> for Money`, which bind the instance(s) and delegate to `PartialEq`.
+/// - A method `equals(other)` registered with Godot, so GDScript can write `a.equals(b)`. It returns `false` for `null` and objects of
+///   other classes, and `true` if `other` is the same instance (without calling `PartialEq`).
+///
+/// Godot offers no hook for extension classes to overload `==` on objects, so `a == b` in GDScript keeps comparing identity.
+/// Both operands are bound with shared access, so comparisons fail while one of them is mutably bound.
+///
+/// Since `equals` is generated, the class cannot declare a `#[func]` with that name itself:
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass, PartialEq)]
+/// #[class(init, eq)]
+/// struct Money {
+///     cents: i64,
+/// }
+///
+/// #[godot_api]
+/// impl Money {
+///     #[func]
+///     fn equals(&self, other: Gd<Money>) -> bool { // duplicate `equals`
+///         self.cents == other.bind().cents
+///     }
+/// }
+/// ```
+///
 /// # Inheritance
 ///
 /// Unlike C++, Rust doesn't really have inheritance, but the GDExtension API lets us "inherit"
@@ -276,6 +386,11 @@ use crate::util::{bail, ident, KvParser};
 ///     #[export(exp_easing)]
 ///     ease: f64,
 ///
+///     // @export_exp_easing("attenuation", "positive_only")
+///     // Only allowed on f32 and f64 fields.
+///     #[export(exp_easing = (attenuation, positive_only))]
+///     fade: f32,
+///
 ///     // @export_enum("One", "Two", "Ten:10", "Twelve:12", "Thirteen")
 ///     #[export(enum = (One, Two, Ten = 10, Twelve = 12, Thirteen))]
 ///     exported_enum: i64,
@@ -283,6 +398,11 @@ use crate::util::{bail, ident, KvParser};
 ///     // @export_flags("A:1", "B:2", "AB:3")
 ///     #[export(flags = (A = 1, B = 2, AB = 3))]
 ///     flags: u32,
+///
+///     // @export_node_path("Node2D", "Control")
+///     // Only valid on `NodePath` fields.
+///     #[export(node_path_valid_types = "Node2D,Control")]
+///     target: NodePath,
 /// }
 ///
 /// ```
@@ -327,6 +447,13 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// ## Dynamic properties
+///
+/// Properties that are only known at runtime (e.g. for data-driven objects) can be exposed with `#[class(dynamic_properties)]`. The class
+/// must then implement the [`DynamicProperties`](../obj/trait.DynamicProperties.html) trait, which maps to Godot's `_get`, `_set` and
+/// `_get_property_list`. Dynamic properties coexist with `#[var]` and `#[export]` fields; see the trait for how both are combined.
+/// Requires Godot 4.3 or later.
+///
 /// # Further class customization
 ///
 /// ## Running code in the editor
@@ -586,6 +713,78 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Audio buffers
+///
+/// Virtual methods that fill an audio buffer, `IAudioStreamPlayback::mix()` and `IAudioStreamPlaybackResampled::mix_resampled()`, receive a
+/// raw `*mut AudioFrame` pointer and frame count, and are thus `unsafe`. Instead, they can be declared safely with a `&mut [AudioFrame]`
+/// parameter in place of pointer and count; `#[godot_api]` converts between the two.
+/// Audio classes require the `codegen-full` feature.
+///
+/// ```ignore
+/// # use godot::prelude::*;
+/// use godot::classes::native::AudioFrame;
+/// use godot::classes::{AudioStreamPlayback, IAudioStreamPlayback};
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=AudioStreamPlayback)]
+/// struct Silence {
+///     base: Base<AudioStreamPlayback>,
+/// }
+///
+/// #[godot_api]
+/// impl IAudioStreamPlayback for Silence {
+///     fn mix(&mut self, buffer: &mut [AudioFrame], _rate_scale: f32) -> i32 {
+///         buffer.fill(AudioFrame { left: 0.0, right: 0.0 });
+///         buffer.len() as i32
+///     }
+/// }
+/// ```
+///
+/// ## Packet buffers
+///
+/// Similarly, the packet peer extension classes (`IMultiplayerPeerExtension`, `IPacketPeerExtension`, `IWebRtcDataChannelExtension`)
+/// exchange packets through raw pointers. Their packet methods can be declared safely as follows:
+/// - `fn put_packet(&mut self, buffer: &[u8]) -> Error` receives the packet to send.
+/// - `fn get_packet(&mut self) -> Result<&[u8], Error>` returns the next received packet. Godot reads the packet only after the method
+///   has returned, so the slice must borrow from `self` -- typically a field holding the current packet, which stays untouched until the
+///   next `get_packet()` call.
+///
+/// ```ignore
+/// # use godot::prelude::*;
+/// use std::collections::VecDeque;
+/// use godot::classes::{IPacketPeerExtension, PacketPeerExtension};
+/// use godot::global::Error;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=PacketPeerExtension)]
+/// struct Echo {
+///     queue: VecDeque<Vec<u8>>,
+///     current: Vec<u8>,
+///     base: Base<PacketPeerExtension>,
+/// }
+///
+/// #[godot_api]
+/// impl IPacketPeerExtension for Echo {
+///     fn put_packet(&mut self, buffer: &[u8]) -> Error {
+///         self.queue.push_back(buffer.to_vec());
+///         Error::OK
+///     }
+///
+///     fn get_packet(&mut self) -> Result<&[u8], Error> {
+///         self.current = self.queue.pop_front().ok_or(Error::ERR_UNAVAILABLE)?;
+///         Ok(&self.current)
+///     }
+///
+///     fn get_available_packet_count(&self) -> i32 {
+///         self.queue.len() as i32
+///     }
+///
+///     fn get_max_packet_size(&self) -> i32 {
+///         1 << 16
+///     }
+/// }
+/// ```
+///
 /// # User-defined functions
 ///
 /// You can use the `#[func]` attribute to declare your own functions. These are exposed to Godot and callable from GDScript.
@@ -631,6 +830,152 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Object parameters by reference
+///
+/// Object parameters can be declared as `&Gd<T>` instead of `Gd<T>`. From Godot's side, the signature is identical. On the Rust side,
+/// the `Gd<T>` is constructed from the engine's argument by the generated glue code, which keeps ownership and only lends it to your function.
+/// Rust code calling the method directly can then pass `&obj`.
+///
+/// This is a convenience for the function signature, not an optimization: calls from Godot convert the argument exactly like for by-value
+/// parameters. Each call constructs a new `Gd<T>`, which increments the reference count of `RefCounted` objects for the duration of the
+/// call (other objects are not reference-counted). Calls from scripts overriding a `#[func(virtual)]` method clone the `Gd<T>` as well.
+///
+/// The reference is only valid for the duration of the call. To store the object, clone the `Gd`.
+///
+/// `&mut Gd<T>` is not supported.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {
+/// #     base: Base<RefCounted>,
+/// # }
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func]
+///     fn is_player(&self, node: &Gd<Node>) -> bool {
+///         node.is_in_group("players")
+///     }
+/// }
+/// ```
+///
+/// ## Parameter names
+///
+/// Parameters are registered under their Rust names, which GDScript shows in autocompletion and the editor docs. To register a different
+/// name, annotate the parameter with `#[arg(rename = "...")]`. This only affects introspection: arguments are always passed by position.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {
+/// #     base: Base<RefCounted>,
+/// # }
+/// #[godot_api]
+/// impl MyStruct {
+///     // Shown in GDScript as `move_by(offset: Vector2)`.
+///     #[func]
+///     fn move_by(&mut self, #[arg(rename = "offset")] offset_in_pixels: Vector2) {
+///         // ...
+///     }
+/// }
+/// ```
+///
+/// ## String returns via `Display`
+///
+/// Return values are normally converted through `ToGodot`. With `#[func(as_string)]`, the return type only needs to implement `Display`
+/// instead: the value is formatted with `to_string()` and passed to Godot as `GString`. This also allows `-> impl Display`.
+///
+/// Only the return value is affected. From Godot's side, the method simply returns `String`; Rust callers of the function still receive
+/// the original type. `as_string` cannot be combined with `virtual`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use std::fmt;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {
+/// #     base: Base<RefCounted>,
+/// # }
+/// struct Version {
+///     major: u32,
+///     minor: u32,
+/// }
+///
+/// impl fmt::Display for Version {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "v{}.{}", self.major, self.minor)
+///     }
+/// }
+///
+/// #[godot_api]
+/// impl MyStruct {
+///     // Called from GDScript as `obj.get_version()`, returns "v1.2".
+///     #[func(as_string)]
+///     fn get_version(&self) -> Version {
+///         Version { major: 1, minor: 2 }
+///     }
+/// }
+/// ```
+///
+/// ## Async functions
+///
+/// `#[func]` can be declared as `async fn`. When called, the returned future is spawned as a task on the [`godot::task`](../task/index.html) executor, running
+/// on the main thread like other tasks. To Godot, the method returns a `Signal`, which is emitted with the output of the future once it
+/// completes. GDScript can thus `await` the call directly: `var data = await obj.fetch_data()`. Requires Godot 4.2 or later.
+///
+/// The future cannot borrow the object, as it outlives the call. Async methods thus take an explicit `Gd<Self>` via `#[func(gd_self)]`
+/// and bind it only where needed, not across `.await` points; `&self` and `&mut self` receivers are a compile error. While the method is
+/// suspended, other code can access the object as usual. Static async functions are also supported. Parameters must be owned,
+/// i.e. not `&Gd<T>`. Async functions cannot be combined with `virtual` or `as_string`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::task;
+///
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Downloader {
+///     url: GString,
+///     last_result: GString,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Downloader {
+///     // GDScript: `var data: String = await downloader.fetch_data()`.
+///     #[func(gd_self)]
+///     async fn fetch_data(mut this: Gd<Self>) -> GString {
+///         let url = this.bind().url.clone();
+///         task::next_frame().await.expect("scene tree freed");
+///         let data = GString::from(format!("payload from {url}"));
+///
+///         // Bind only between `.await` points, never across them.
+///         this.bind_mut().last_result = data.clone();
+///         data
+///     }
+/// }
+/// ```
+///
+/// Taking `&self` or `&mut self` in an async function does not compile:
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Downloader {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Downloader {
+///     #[func]
+///     async fn fetch_data(&self) -> GString {
+///         GString::new()
+///     }
+/// }
+/// ```
+///
 /// ## Virtual methods
 ///
 /// Functions with the `#[func(virtual)]` attribute are virtual functions, meaning attached scripts can override them.
@@ -1019,6 +1364,125 @@ pub fn derive_export(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_export)
 }
 
+/// Derive macro for [`InputActions`](../tools/trait.InputActions.html) on fieldless enums.
+///
+/// Each enumerator is mapped to an input action of the project's `InputMap`. The action name is the enumerator name converted to
+/// `snake_case`, unless specified with `#[action(name = "...")]`:
+///
+/// ```no_run
+/// use godot::register::InputActions;
+///
+/// #[derive(InputActions, Copy, Clone)]
+/// enum Action {
+///     Jump,     // "jump"
+///     MoveLeft, // "move_left"
+///     #[action(name = "ui_cancel")]
+///     Pause,
+/// }
+/// ```
+#[proc_macro_derive(InputActions, attributes(action))]
+pub fn derive_input_actions(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_input_actions)
+}
+
+/// Derive macro for [`ShaderParams`](../tools/trait.ShaderParams.html) on structs with named fields.
+///
+/// Each field is mapped to a uniform of a shader, with the field type determining the expected uniform type. The uniform name is the
+/// field name, unless specified with `#[param(name = "...")]`:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::register::ShaderParams;
+///
+/// #[derive(ShaderParams)]
+/// struct OutlineParams {
+///     width: f32,                 // uniform float width;
+///     #[param(name = "outline_color")]
+///     color: Color,               // uniform vec4 outline_color : source_color;
+/// }
+/// ```
+///
+/// Requires the `codegen-full` feature.
+#[proc_macro_derive(ShaderParams, attributes(param))]
+pub fn derive_shader_params(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_shader_params)
+}
+
+/// Calls a method of an engine class, with optional named default parameters.
+///
+/// The call is resolved to the typed method generated for the engine class, so method names, argument types and names of default
+/// parameters are checked at compile time. This is a checked alternative to the stringly-typed `Object::call()`.
+///
+/// Positional arguments come first. Default parameters can follow after a `;`, with their Godot name; they are forwarded to the
+/// `*_ex()` builder of the method:
+///
+/// | Macro invocation                                                | Expands to                                                    |
+/// |-----------------------------------------------------------------|---------------------------------------------------------------|
+/// | `gd_call!(node.add_child(&child))`                              | `node.add_child(&child)`                                      |
+/// | `gd_call!(node.add_child(&child; force_readable_name = true))`  | `node.add_child_ex(&child).force_readable_name(true).done()`  |
+///
+/// Parameter names that are Rust keywords are mapped like in the generated API, e.g. `type = ..` calls `type_(..)`.
+/// Values containing a top-level `,` (e.g. generic arguments) must be wrapped in parentheses.
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::Node;
+/// use godot::gd_call;
+/// use godot::prelude::*;
+///
+/// fn add_enemies(mut level: Gd<Node>, enemies: &[Gd<Node>]) {
+///     for enemy in enemies {
+///         gd_call!(level.add_child(enemy; force_readable_name = true));
+///     }
+///
+///     let found = gd_call!(level.find_children("Enemy*"; type = "Node2D", owned = false));
+///     godot_print!("found {} enemies", found.len());
+/// }
+/// ```
+///
+/// Unknown methods or default parameters are compile errors:
+/// ```compile_fail
+/// # use godot::classes::Node;
+/// # use godot::gd_call;
+/// # use godot::prelude::*;
+/// # fn f(mut level: Gd<Node>) {
+/// gd_call!(level.add_child_typo(&Node::new_alloc()));
+/// # }
+/// ```
+#[proc_macro]
+pub fn gd_call(input: TokenStream) -> TokenStream {
+    translate_functional(input, gd_call::gd_call)
+}
+
+/// Property path with compile-time checked name and value type, e.g. for tweens.
+///
+/// `prop!(Class::property)` creates a [`PropertyPath<Class, V>`](../tools/struct.PropertyPath.html). The property must have a getter
+/// `Class::get_property()`, whose return type becomes `V`. This is the case for most engine properties, as well as for `#[var]` fields of
+/// user classes. Properties with other getters (e.g. `is_visible()`) can be created with `PropertyPath::new()`.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::Vector2;
+/// use godot::classes::Node2D;
+/// use godot::prop;
+/// use godot::tools::PropertyPath;
+///
+/// let position: PropertyPath<Node2D, Vector2> = prop!(Node2D::position);
+/// let rotation = prop!(Node2D::rotation); // PropertyPath<Node2D, real>
+/// let alpha = prop!(Node2D::modulate).a(); // "modulate:a"
+/// ```
+///
+/// Unknown properties are compile errors:
+/// ```compile_fail
+/// # use godot::classes::Node2D;
+/// # use godot::prop;
+/// let path = prop!(Node2D::positon);
+/// ```
+#[proc_macro]
+pub fn prop(input: TokenStream) -> TokenStream {
+    translate_functional(input, prop::prop)
+}
+
 /// Similar to `#[test]`, but runs an integration test with Godot.
 ///
 /// Transforms the `fn` into one returning `bool` (success of the test), which must be called explicitly.
@@ -1099,7 +1563,6 @@ where
 }
 
 /// For `#[proc_macro]` function-style macros.
-#[cfg(feature = "experimental-wasm")]
 fn translate_functional<F>(input: TokenStream, transform: F) -> TokenStream
 where
     F: FnOnce(TokenStream2) -> ParseResult<TokenStream2>,
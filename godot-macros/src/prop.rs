@@ -0,0 +1,46 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned};
+
+use crate::util::bail;
+use crate::ParseResult;
+
+/// Parses `Class::property` and expands it to a `PropertyPath`, type-checked against the getter `Class::get_property()`.
+pub(super) fn prop(input: TokenStream) -> ParseResult<TokenStream> {
+    let mut tokens: Vec<TokenTree> = input.into_iter().collect();
+
+    let Some(TokenTree::Ident(property)) = tokens.pop() else {
+        return bail!(
+            TokenStream::new(),
+            "prop! expects `Class::property`, e.g. `prop!(Node2D::position)`"
+        );
+    };
+
+    // Expected before the property: `:` `:` (joint punctuation).
+    let is_colon =
+        |tt: Option<TokenTree>| matches!(tt, Some(TokenTree::Punct(p)) if p.as_char() == ':');
+    if !is_colon(tokens.pop()) || !is_colon(tokens.pop()) {
+        return bail!(property, "expected `Class::{property}`");
+    }
+
+    if tokens.is_empty() {
+        return bail!(property, "missing class before `::{property}`");
+    }
+
+    let class: TokenStream = tokens.into_iter().collect();
+    let property_name = property.to_string();
+
+    // Keep span of the user's property, so that unknown properties are reported at the right place.
+    let getter = format_ident!("get_{}", property, span = property.span());
+    let call = quote_spanned! { property.span()=> obj.#getter() };
+
+    Ok(quote! {
+        ::godot::tools::PropertyPath::<#class, _>::__from_getter(#property_name, |obj: &#class| #call)
+    })
+}
@@ -16,7 +16,7 @@ use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 mod kv_parser;
 mod list_parser;
 
-pub(crate) use kv_parser::KvParser;
+pub(crate) use kv_parser::{KvParser, KvValue};
 pub(crate) use list_parser::ListParser;
 
 pub fn ident(s: &str) -> Ident {
@@ -300,6 +300,16 @@ pub fn safe_ident(s: &str) -> Ident {
     }
 }
 
+/// Whether `name` is a valid identifier in GDScript (ASCII only).
+pub fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Parses a `meta` TokenStream, that is, the tokens in parameter position of a proc-macro (between the braces).
@@ -72,12 +72,25 @@
 //!
 //!   Sets the [**API level**](https://godot-rust.github.io/book/toolchain/godot-version.html) to the specified Godot version,
 //!   or a custom-built local binary.
-//!   You can use at most one `api-*` feature. If absent, the current Godot minor version is used, with patch level 0.<br><br>
+//!   You can use at most one `api-*` feature. If absent, the current Godot minor version is used, with patch level 0.
+//!
+//!   With `api-custom`, all bindings are regenerated from the API of the engine you run, including classes that only exist in a patched
+//!   fork. By default, `extension_api.json` and `gdextension_interface.h` are dumped from the Godot binary in the `GODOT4_BIN` environment
+//!   variable (or `godot4` in `PATH`). Alternatively, previously dumped files can be passed as absolute paths in `GODOT4_GDEXTENSION_JSON`
+//!   and `GODOT4_GDEXTENSION_C_HEADER`; no binary is needed then. The JSON's version becomes the API level; the build checks that it is
+//!   at least 4.1, and if `GODOT4_BIN` is set as well, that the binary is not older than the JSON. Your `.gdextension` file is not read,
+//!   so make sure yourself that its `compatibility_minimum` is no less than the JSON's version.<br><br>
 //!
 //! * **`double-precision`**
 //!
 //!   Use `f64` instead of `f32` for the floating-point type [`real`][type@builtin::real]. Requires Godot to be compiled with the
-//!   scons flag `precision=double`.<br><br>
+//!   scons flag `precision=double`.
+//!
+//!   This changes the layout of all vector, matrix and transform types (`Vector3`, `Transform3D`, ...), as well as the size of `Variant`.
+//!   Bindings must therefore be generated from the API of the double-precision engine, which requires `api-custom` (either a Godot
+//!   binary, or a previously dumped `extension_api.json` and header). If the JSON declares a different precision, the build fails;
+//!   if the engine loading the extension uses a different precision, initialization fails with both build configurations
+//!   (e.g. `double_64` vs. `float_64`).<br><br>
 //!
 //! * **`experimental-godot-api`**
 //!
@@ -86,12 +99,15 @@
 //!
 //! _Rust functionality toggles:_
 //!
-//! * **`lazy-function-tables`**
+//! * **`eager-function-tables`**
 //!
-//!   Instead of loading all engine function pointers at startup, load them lazily on first use. This reduces startup time and RAM usage, but
-//!   incurs additional overhead in each FFI call. Also, you lose the guarantee that once the library has booted, all function pointers are
-//!   truly available. Function calls may thus panic only at runtime, possibly in deeply nested code paths.
-//!   This feature is not yet thread-safe and can thus not be combined with `experimental-threads`.<br><br>
+//!   By default, engine method pointers are resolved lazily, on the first call of each method. This keeps startup fast, which matters
+//!   in particular for editors loading many extensions. Every call then checks whether the pointer is already resolved (one atomic load).
+//!   Resolved pointers are stored atomically, so lazy tables are thread-safe and can be combined with `experimental-threads`.
+//!   With this feature, all function pointers are instead loaded at startup. This adds startup time, but guarantees that once the library
+//!   has booted, all pointers are truly available; without it, a missing method only panics when it is first called.
+//!   The former `lazy-function-tables` feature has no effect anymore, as lazy loading is the default; it only emits a deprecation
+//!   warning.<br><br>
 //!
 //! * **`experimental-threads`**
 //!
@@ -106,17 +122,28 @@
 //!
 //!   Please read [Export to Web](https://godot-rust.github.io/book/toolchain/export-web.html) in the book.
 //!
-//!   By default, Wasm threads are enabled and require the flag `"-C", "link-args=-pthread"` in the `wasm32-unknown-unknown` target.
+//!   Build with a nightly toolchain and `-Zbuild-std` for the `wasm32-unknown-emscripten` target, which produces a side module that Godot's
+//!   web export can load. By default, Wasm threads are enabled and require the flags `"-C", "link-args=-pthread"` and
+//!   `"-C", "target-feature=+atomics"` in the `[target.wasm32-unknown-emscripten]` section of `.cargo/config.toml`.
 //!   This must be kept in sync with Godot's Web export settings (threading support enabled). To disable it, use **additionally* the feature
-//!   `experimental-wasm-nothreads`.<br><br>
+//!   `experimental-wasm-nothreads`.
+//!
+//!   These flags cannot be set by godot-rust itself. Instead, the build emits warnings if a flag is missing or contradicts the enabled
+//!   features, together with the complete `rustflags` section to copy into `.cargo/config.toml`.
+//!
+//!   On Wasm, no backtraces are captured (e.g. for panics or failed `bind()`/`bind_mut()` calls), as Emscripten side modules don't
+//!   reliably support stack walking.<br><br>
 //!
-//!   It is recommended to use this feature in combination with `lazy-function-tables` to reduce the size of the generated Wasm binary.
+//!   Lazy function tables (the default) also keep the generated Wasm binary small; avoid `eager-function-tables` here.
 //!
 //! * **`experimental-wasm-nothreads`**
 //!
 //!   Requires the `experimental-wasm` feature. Disables threading support for WebAssembly exports. This needs to be kept in sync with
-//!   Godot's Web export setting (threading support disabled), and must _not_ use the `"-C", "link-args=-pthread"` flag in the
-//!   `wasm32-unknown-unknown` target.<br><br>
+//!   Godot's Web export setting (threading support disabled), and must _not_ use the `"-C", "link-args=-pthread"` flag or the `atomics`
+//!   target feature.
+//!
+//!   Everything then runs on the main thread. [Godot tasks](task) work as usual. File operations of `AsyncGFile` run synchronously when
+//!   they are started, and blocking on a `MainThreadResult` panics instead of deadlocking; await it instead.<br><br>
 //!
 //! * **`codegen-rustfmt`**
 //!
@@ -137,6 +164,54 @@
 //!   Implement the [serde](https://serde.rs/) traits `Serialize` and `Deserialize` traits for certain built-in types.
 //!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
 //!
+//! * **`image-interop`**
+//!
+//!   Conversions between [`Image`][classes::Image] and the [image](https://docs.rs/image) crate's `DynamicImage`, see
+//!   `Image::from_dynamic_image()` and `Image::to_dynamic_image()`. Uses `image` 0.25 without its default (codec) features.
+//!   Implies full codegen, since `Image` is not part of the minimal set of classes.<br><br>
+//!
+//! * **`tokio`**
+//!
+//!   Integrates the [tokio](https://tokio.rs) runtime with Godot tasks via `task::TokioRuntime`, so that tokio-based futures can be awaited
+//!   in [`task::spawn()`]. Requires Godot 4.2 or later.<br><br>
+//!
+//! * **`futures-io`**
+//!
+//!   Implements the [futures-io](https://docs.rs/futures-io) traits `AsyncRead`, `AsyncWrite` and `AsyncSeek` for `tools::AsyncGFile`, which
+//!   streams files in chunks on Godot's `WorkerThreadPool`. Requires Godot 4.2 or later.<br><br>
+//!
+//! * **`futures-core`**
+//!
+//!   Implements the [futures-core](https://docs.rs/futures-core) `Stream` trait for `task::SignalStream`, so that signal emissions can be
+//!   consumed with stream combinators. Requires Godot 4.2 or later.<br><br>
+//!
+//! * **`rayon`**
+//!
+//!   Parallel iteration with [rayon](https://docs.rs/rayon) over the slices of `PackedByteArray`, `PackedFloat32Array` and
+//!   `PackedVector3Array`, via `par_iter()`, `par_iter_mut()` and `PackedVector3Array::par_transform_in_place()`.
+//!
+//! ## Generating only selected engine classes
+//!
+//! By default, bindings for all engine classes are generated, which dominates the compile time of this crate. Projects using only a
+//! small part of the engine can disable the default features (`default-features = false`) to generate a minimal set of core classes
+//! (such as `Object`, `Node`, `Resource` and `SceneTree`) instead.
+//!
+//! Additional classes are selected with the `GODOT_CLASS_ALLOWLIST` environment variable, for example in `.cargo/config.toml`:
+//!
+//! ```toml
+//! [env]
+//! GODOT_CLASS_ALLOWLIST = "Timer, Tween, AnimationPlayer"
+//! ```
+//!
+//! Class names are separated by commas or whitespace. The value can also be the path to a file with one or more class names per line,
+//! where `#` starts a comment. Besides the listed classes, all classes appearing in their method and signal signatures are generated,
+//! as well as the base classes of all of these. This is not transitive: classes appearing only in the signatures of such added classes
+//! are not generated, unless listed themselves. Unknown class names abort the build.
+//!
+//! Using a class that is not generated (e.g. `Gd<Timer>` or `Timer::new_alloc()`) fails to compile, with an error that names the class
+//! and says how to enable it. Methods of generated classes whose signatures involve non-generated classes are omitted. Some higher-level APIs that build on
+//! specific engine classes (e.g. `tools::CurveBuilder` or `RpcConfig`) are only available with the full set of classes.
+//!
 
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/godot-rust/assets/master/gdext/ferris.svg"
@@ -159,7 +234,27 @@ compile_error!(
 // See also https://github.com/godotengine/godot/issues/86346.
 // Could technically be moved to godot-codegen to reduce time-to-failure slightly, but would scatter validations even more.
 #[cfg(all(feature = "double-precision", not(feature = "api-custom")))]
-compile_error!("The feature `double-precision` currently requires `api-custom` due to incompatibilities in the GDExtension API JSON.");
+compile_error!(
+    "The feature `double-precision` currently requires `api-custom` due to incompatibilities in the GDExtension API JSON.\n\
+    Use a double-precision Godot binary (GODOT4_BIN) or the API files dumped from it (GODOT4_GDEXTENSION_JSON, GODOT4_GDEXTENSION_C_HEADER)."
+);
+
+// Warnings in dependencies are not shown to users, so feature deprecations are emitted by #[gdextension] in the user's crate.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "lazy-function-tables")]
+macro_rules! __emit_feature_deprecations {
+    () => {
+        $crate::__deprecated::emit_deprecated_warning!(feature_lazy_function_tables);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "lazy-function-tables"))]
+macro_rules! __emit_feature_deprecations {
+    () => {};
+}
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Modules
@@ -167,6 +262,12 @@ compile_error!("The feature `double-precision` currently requires `api-custom` d
 #[doc(inline)]
 pub use godot_core::{builtin, classes, global, meta, obj, task, tools};
 
+/// Compile-time checked calls to engine class methods, with named default parameters.
+pub use godot_macros::gd_call;
+
+/// Property paths with compile-time checked name and value type.
+pub use godot_macros::prop;
+
 #[doc(hidden)]
 pub use godot_core::possibly_docs as docs;
 
@@ -185,10 +286,14 @@ pub mod init {
 pub mod register {
     pub use godot_core::registry::property;
     pub use godot_core::registry::signal::*;
-    pub use godot_macros::{godot_api, godot_dyn, Export, GodotClass, GodotConvert, Var};
+    pub use godot_macros::{
+        godot_api, godot_dyn, Export, GodotClass, GodotConvert, InputActions, Var,
+    };
 
     #[cfg(feature = "__codegen-full")]
     pub use godot_core::registry::RpcConfig;
+    #[cfg(feature = "__codegen-full")]
+    pub use godot_macros::ShaderParams;
 
     /// Re-exports used by proc-macro API.
     #[doc(hidden)]
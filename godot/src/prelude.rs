@@ -27,7 +27,7 @@ pub use super::tools::{load, save, try_load, try_save, GFile};
 pub use super::init::{gdextension, ExtensionLibrary, InitLevel};
 pub use super::obj::{
     AsDyn, Base, DynGd, DynGdMut, DynGdRef, Gd, GdMut, GdRef, GodotClass, Inherits, InstanceId,
-    OnEditor, OnReady,
+    OnEditor, OnReady, WeakGd,
 };
 
 // Make trait methods available.
@@ -38,3 +38,4 @@ pub use super::obj::NewGd as _;
 pub use super::obj::WithBaseField as _; // base(), base_mut(), to_gd()
 pub use super::obj::WithSignals as _; // Gd::signals()
 pub use super::obj::WithUserSignals as _; // self.signals()
+pub use super::tools::NodeProcessing as _; // self.set_process()
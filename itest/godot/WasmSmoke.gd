@@ -0,0 +1,19 @@
+# Copyright (c) godot-rust; Bromeon and contributors.
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+# Main scene of web exports (see project.godot), run by CI in a headless browser: .github/other/wasm-smoke.py.
+# Only checks that the extension initializes and that a #[func] can be called; the actual test suite is not run on the web.
+
+extends Node
+
+func _ready():
+	var ok := false
+	if ClassDB.class_exists("FuncObj"):
+		var obj = ClassDB.instantiate("FuncObj")
+		ok = obj.call("give_one") == 1
+	else:
+		push_error("FuncObj is not registered; the extension was not initialized.")
+
+	print("WASM_SMOKE: ", "ok" if ok else "failed")
@@ -28,6 +28,12 @@ func _ready() -> void:
 	var planet = retained_obj.favorite_planet
 
 	print("[GD Editor] Sanity check: initial number is ", num, "; planet is ", planet)
+
+	if not _check_engine_methods():
+		return
+
+	if not _check_singleton():
+		return
 	
 	var extensions = GDExtensionManager.get_loaded_extensions()
 	if extensions.size() == 1:
@@ -80,6 +86,14 @@ func _process(delta: float) -> void:
 	var planet = retained_obj.favorite_planet
 	retained_obj.free()
 
+	# Check that engine method pointers are resolved again, rather than reused from before the reload.
+	if not _check_engine_methods():
+		return
+
+	# Check that the engine singleton has been registered again.
+	if not _check_singleton():
+		return
+
 	if num == 777 and planet == "Mars":
 		print("[GD Editor] Successful hot-reload! Exit...")
 		get_tree().quit(0)
@@ -100,6 +114,32 @@ func _hot_reload():
 	return true
 
 
+func _check_engine_methods() -> bool:
+	var r = Reloadable.new()
+	var result = r.call_engine_methods()
+	r.free()
+
+	if result != "engine:6":
+		fail(str("Engine method calls returned wrong result (", result, ")"))
+		return false
+
+	return true
+
+
+func _check_singleton() -> bool:
+	# Accessed dynamically, as the singleton is briefly unregistered during reload.
+	if not Engine.has_singleton("ReloadService"):
+		fail("Singleton ReloadService is not registered")
+		return false
+
+	var answer = Engine.get_singleton("ReloadService").get_answer()
+	if answer != 42:
+		fail(str("Singleton ReloadService returned wrong answer (", answer, ")"))
+		return false
+
+	return true
+
+
 func fail(s: String) -> void:
 	print("::error::[GD Editor] ", s) # GitHub Action syntax
 	get_tree().quit(1)
@@ -43,6 +43,35 @@ impl Reloadable {
             favorite_planet: Planet::from_godot(s),
         })
     }
+
+    /// Calls engine class and builtin methods, whose function pointers must be resolved again after reload.
+    #[func]
+    fn call_engine_methods(&self) -> GString {
+        let mut node = Node::new_alloc();
+        node.set_name("engine");
+        let name = GString::from(&node.get_name());
+        node.free();
+
+        format!("{name}:{}", name.len()).into()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Engine singleton, which must be unregistered before and registered again after the reload.
+#[derive(GodotClass)]
+#[class(init, singleton, tool, base=Object)]
+struct ReloadService {
+    #[init(val = 42)]
+    answer: i64,
+}
+
+#[godot_api]
+impl ReloadService {
+    #[func]
+    fn get_answer(&self) -> i64 {
+        self.answer
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
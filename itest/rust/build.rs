@@ -493,10 +493,16 @@ fn generate_property_template(inputs: &[Input]) -> PropertyTests {
             //  #[export(range = (0, 100, 1, "or_greater", "or_less"))] export_range_int_0_100_1_or_greater_or_less: int,
             #[export(exp_easing)]
             export_exp_easing: f64,
+            #[export(exp_easing = (attenuation))]
+            export_exp_easing_attenuation: f32,
+            #[export(exp_easing = (positive_only))]
+            export_exp_easing_positive_only: f64,
+            #[export(exp_easing = (attenuation, positive_only))]
+            export_exp_easing_attenuation_positive_only: f64,
             #[export(color_no_alpha)]
             export_color_no_alpha: Color,
-            // Not implemented
-            //  #[export(node_path = ("Button", "TouchScreenButton"))] export_node_path_button_touch_screen_button: NodePath,
+            #[export(node_path_valid_types = "Button,TouchScreenButton")]
+            export_node_path_button_touch_screen_button: NodePath,
             #[export(flags = (Fire, Water, Earth, Wind))]
             export_flags_fire_water_earth_wind: i64,
             #[export(flags = (Self = 4, Allies = 8, Foes = 16))]
@@ -536,6 +542,9 @@ fn generate_property_template(inputs: &[Input]) -> PropertyTests {
 @export_range(-10, 20, 0.2) var export_range_float_neg10_20_02: float
 @export_range(0, 100, 1, "or_greater", "or_less") var export_range_int_0_100_1_or_greater_or_less: int
 @export_exp_easing var export_exp_easing: float
+@export_exp_easing("attenuation") var export_exp_easing_attenuation: float
+@export_exp_easing("positive_only") var export_exp_easing_positive_only: float
+@export_exp_easing("attenuation", "positive_only") var export_exp_easing_attenuation_positive_only: float
 @export_color_no_alpha var export_color_no_alpha: Color
 @export_node_path("Button", "TouchScreenButton") var export_node_path_button_touch_screen_button: NodePath
 @export_flags("Fire", "Water", "Earth", "Wind") var export_flags_fire_water_earth_wind: int
@@ -0,0 +1,61 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Compares ways of generating a 64x64 RGBA8 gradient.
+
+use godot::builtin::Color;
+use godot::classes::image::Format;
+use godot::classes::Image;
+use godot::obj::Gd;
+
+use crate::framework::bench;
+
+const SIZE: i32 = 64;
+
+fn gradient_pixel(x: i32, y: i32) -> [u8; 4] {
+    [(x * 4) as u8, (y * 4) as u8, 0, 255]
+}
+
+#[bench(repeat = 5)]
+fn image_gradient_set_pixel() -> Gd<Image> {
+    let mut image = Image::create_empty(SIZE, SIZE, false, Format::RGBA8).unwrap();
+
+    for y in 0..SIZE {
+        for x in 0..SIZE {
+            let [r, g, b, a] = gradient_pixel(x, y);
+            image.set_pixel(x, y, Color::from_rgba8(r, g, b, a));
+        }
+    }
+
+    image
+}
+
+#[bench(repeat = 5)]
+fn image_gradient_data_view_mut() -> Gd<Image> {
+    let mut image = Image::create_empty(SIZE, SIZE, false, Format::RGBA8).unwrap();
+
+    {
+        let mut view = image.data_view_mut();
+        let mut level = view.level_mut(0).unwrap();
+        let pixels = level.rgba8_mut().unwrap();
+
+        for (i, pixel) in pixels.iter_mut().enumerate() {
+            *pixel = gradient_pixel(i as i32 % SIZE, i as i32 / SIZE);
+        }
+    }
+
+    image
+}
+
+#[bench(repeat = 5)]
+fn image_gradient_from_rgba8() -> Gd<Image> {
+    let bytes: Vec<u8> = (0..SIZE)
+        .flat_map(|y| (0..SIZE).flat_map(move |x| gradient_pixel(x, y)))
+        .collect();
+
+    Image::from_rgba8(SIZE, SIZE, &bytes)
+}
@@ -0,0 +1,33 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Startup cost of engine method tables, using the largest one (Scene level).
+// With lazy tables (default), `method_table_load` is what extensions pay at startup, and `method_table_load_resolve_all` the upper bound
+// spread over the first calls. With `eager-function-tables`, both pay the full resolution cost up front.
+
+use godot::sys;
+
+use crate::framework::bench;
+
+#[bench(repeat = 3)]
+fn method_table_load() -> usize {
+    // SAFETY: benchmarks run on the main thread after initialization; the table is dropped before returning.
+    let table = unsafe { sys::load_standalone_scene_method_table() };
+    drop(table);
+
+    sys::ClassSceneMethodTable::METHOD_COUNT
+}
+
+#[bench(repeat = 3)]
+fn method_table_load_resolve_all() -> usize {
+    // SAFETY: see above.
+    let table = unsafe { sys::load_standalone_scene_method_table() };
+
+    (0..sys::ClassSceneMethodTable::METHOD_COUNT)
+        .map(|index| table.fptr_by_index(index).0 as usize)
+        .fold(0, usize::wrapping_add)
+}
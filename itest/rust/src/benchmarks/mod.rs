@@ -10,14 +10,20 @@
 use std::hint::black_box;
 
 use godot::builtin::inner::InnerRect2i;
-use godot::builtin::{GString, PackedInt32Array, Rect2i, StringName, Vector2i};
+use godot::builtin::{GString, PackedInt32Array, Rect2i, StringName, Variant, Vector2i};
 use godot::classes::{Node3D, Os, RefCounted};
+use godot::meta::ToGodot;
 use godot::obj::{Gd, InstanceId, NewAlloc, NewGd};
-use godot::register::GodotClass;
+use godot::register::{godot_api, GodotClass};
 
 use crate::framework::bench;
 
 mod color;
+#[cfg(feature = "codegen-full")]
+mod image;
+mod method_tables;
+#[cfg(feature = "rayon")]
+mod packed_par;
 
 #[bench]
 fn builtin_string_ctor() -> GString {
@@ -93,6 +99,24 @@ fn utilities_ffi_call() -> f64 {
     godot::global::pow(base, exponent)
 }
 
+// The following two compare Godot calling a #[func] with an object parameter declared `Gd<T>` vs. `&Gd<T>`. They perform the same
+// conversion, so they are expected to be equally fast; see "Object parameters by reference" in the #[godot_api] docs.
+#[bench(repeat = 25)]
+fn func_object_param_by_value() -> Variant {
+    let mut bench = MyBenchType::new_gd();
+    let arg = RefCounted::new_gd().to_variant();
+
+    bench.call("take_by_value", &[arg])
+}
+
+#[bench(repeat = 25)]
+fn func_object_param_by_ref() -> Variant {
+    let mut bench = MyBenchType::new_gd();
+    let arg = RefCounted::new_gd().to_variant();
+
+    bench.call("take_by_ref", &[arg])
+}
+
 #[bench(repeat = 25)]
 fn packed_array_from_iter_known_size() -> PackedInt32Array {
     // Create an iterator whose `size_hint()` returns `(len, Some(len))`.
@@ -119,3 +143,16 @@ fn packed_array_from_iter_unknown_size() -> PackedInt32Array {
 #[derive(GodotClass)]
 #[class(init)]
 struct MyBenchType {}
+
+#[godot_api]
+impl MyBenchType {
+    #[func]
+    fn take_by_value(&self, object: Gd<RefCounted>) -> InstanceId {
+        object.instance_id()
+    }
+
+    #[func]
+    fn take_by_ref(&self, object: &Gd<RefCounted>) -> InstanceId {
+        object.instance_id()
+    }
+}
@@ -0,0 +1,86 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Compares serial and rayon-based processing of large packed arrays.
+// Both variants include copying the input into a new packed array.
+
+use std::hint::black_box;
+use std::sync::OnceLock;
+
+use godot::builtin::{real, Basis, PackedFloat32Array, PackedVector3Array, Transform3D, Vector3};
+
+use rayon::iter::ParallelIterator;
+
+use crate::framework::bench;
+
+const LEN: usize = 1_000_000;
+
+fn vertices() -> PackedVector3Array {
+    static VERTICES: OnceLock<Vec<Vector3>> = OnceLock::new();
+
+    let vertices = VERTICES.get_or_init(|| {
+        (0..LEN)
+            .map(|i| Vector3::new(i as real, (i % 100) as real, -(i as real)))
+            .collect()
+    });
+    PackedVector3Array::from(vertices.as_slice())
+}
+
+fn samples() -> PackedFloat32Array {
+    static SAMPLES: OnceLock<Vec<f32>> = OnceLock::new();
+
+    let samples = SAMPLES.get_or_init(|| (0..LEN).map(|i| i as f32 * 0.001).collect());
+    PackedFloat32Array::from(samples.as_slice())
+}
+
+fn transform() -> Transform3D {
+    Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, black_box(0.5)),
+        Vector3::new(1.0, 2.0, 3.0),
+    )
+}
+
+#[bench(repeat = 5)]
+fn packed_vector3_transform_serial() -> PackedVector3Array {
+    let mut vertices = vertices();
+    let xf = transform();
+
+    for v in vertices.as_mut_slice() {
+        *v = xf * *v;
+    }
+
+    vertices
+}
+
+#[bench(repeat = 5)]
+fn packed_vector3_transform_par() -> PackedVector3Array {
+    let mut vertices = vertices();
+    vertices.par_transform_in_place(transform());
+
+    vertices
+}
+
+#[bench(repeat = 5)]
+fn packed_float32_map_serial() -> PackedFloat32Array {
+    let mut samples = samples();
+
+    for x in samples.as_mut_slice() {
+        *x = (x.sin() * 0.5 + 0.5).powf(2.2);
+    }
+
+    samples
+}
+
+#[bench(repeat = 5)]
+fn packed_float32_map_par() -> PackedFloat32Array {
+    let mut samples = samples();
+    samples
+        .par_iter_mut()
+        .for_each(|x| *x = (x.sin() * 0.5 + 0.5).powf(2.2));
+
+    samples
+}
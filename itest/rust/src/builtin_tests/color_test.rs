@@ -97,6 +97,59 @@ fn color_blend() {
     );
 }
 
+#[itest]
+fn color_blend_alpha() {
+    let base = Color::from_rgba(1.0, 0.0, 0.0, 1.0);
+    let over = Color::from_rgba(0.0, 0.0, 1.0, 0.5);
+
+    assert_eq_approx!(base.blend(over), Color::from_rgba(0.5, 0.0, 0.5, 1.0));
+    assert_eq!(
+        Color::TRANSPARENT_BLACK.blend(Color::TRANSPARENT_WHITE),
+        Color::TRANSPARENT_BLACK
+    );
+}
+
+#[itest]
+fn color_arithmetic() {
+    let a = Color::from_rgba(0.25, 0.5, 0.75, 1.0);
+    let b = Color::from_rgba(0.5, 0.5, 0.5, 0.5);
+
+    assert_eq!(a + b, Color::from_rgba(0.75, 1.0, 1.25, 1.5));
+    assert_eq!(a - b, Color::from_rgba(-0.25, 0.0, 0.25, 0.5));
+    assert_eq!(a * b, Color::from_rgba(0.125, 0.25, 0.375, 0.5));
+    assert_eq!(a / b, Color::from_rgba(0.5, 1.0, 1.5, 2.0));
+    assert_eq!(a * 2.0, Color::from_rgba(0.5, 1.0, 1.5, 2.0));
+    assert_eq!(2.0 * a, a * 2.0);
+    assert_eq!(a / 2.0, Color::from_rgba(0.125, 0.25, 0.375, 0.5));
+
+    let mut c = a;
+    c += b;
+    c -= b;
+    c *= 4.0;
+    c /= 4.0;
+    assert_eq!(c, a);
+}
+
+#[itest]
+fn color_lerp_and_shading() {
+    let from = Color::from_rgba(0.0, 0.2, 0.4, 1.0);
+    let to = Color::from_rgba(1.0, 0.6, 0.4, 0.0);
+
+    assert_eq_approx!(from.lerp(to, 0.5), Color::from_rgba(0.5, 0.4, 0.4, 0.5));
+    assert_eq!(from.lerp(to, 0.0), from);
+
+    // Not clamped, like in Godot.
+    assert_eq_approx!(from.lerp(to, 2.0), Color::from_rgba(2.0, 1.0, 0.4, -1.0));
+
+    let grey = Color::from_rgba(0.5, 0.5, 0.5, 0.8);
+    assert_eq_approx!(grey.darkened(0.5), Color::from_rgba(0.25, 0.25, 0.25, 0.8));
+    assert_eq_approx!(grey.lightened(0.5), Color::from_rgba(0.75, 0.75, 0.75, 0.8));
+    assert_eq_approx!(
+        Color::from_rgba(0.25, 0.5, 1.0, 0.3).inverted(),
+        Color::from_rgba(0.75, 0.5, 0.0, 0.3)
+    );
+}
+
 #[itest]
 fn color_to_u32() {
     let c = Color::from_html("#01020304").unwrap();
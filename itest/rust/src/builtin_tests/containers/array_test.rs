@@ -581,3 +581,42 @@ impl ArrayTest {
         (1..(n + 1)).collect()
     }
 }
+
+#[itest]
+fn untyped_array_try_into_typed() {
+    let untyped = varray![1, 2, 3];
+    let typed = untyped
+        .try_into_typed::<i32>()
+        .expect("all elements are i32");
+    assert_eq!(typed, array![1, 2, 3]);
+    assert_eq!(typed.to_variant().try_to::<Array<i32>>().unwrap(), typed);
+
+    let empty = VariantArray::new().try_into_typed::<GString>().unwrap();
+    assert!(empty.is_empty());
+
+    let mixed = varray![1, "two", 3];
+    mixed
+        .try_into_typed::<i32>()
+        .expect_err("string element should not convert to i32");
+
+    let out_of_range = varray![1, i64::MAX];
+    out_of_range
+        .try_into_typed::<i32>()
+        .expect_err("i64::MAX should not convert to i32");
+}
+
+#[itest]
+fn untyped_array_as_typed_unchecked() {
+    let mut untyped = varray![10, 20];
+
+    // SAFETY: all elements are integers in i32 range.
+    let typed = unsafe { untyped.as_typed_unchecked::<i32>() };
+    assert_eq!(typed.at(1), 20);
+    assert_eq!(typed.iter_shared().sum::<i32>(), 30);
+
+    // The typed view shares the array.
+    untyped.push(&30.to_variant());
+    let typed = unsafe { untyped.as_typed_unchecked::<i32>() };
+    assert_eq!(typed.len(), 3);
+    assert_eq!(typed.back(), Some(30));
+}
@@ -0,0 +1,107 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{
+    real, Basis, PackedByteArray, PackedFloat32Array, PackedVector3Array, Transform3D, Vector3,
+};
+use rayon::prelude::*;
+
+use crate::framework::itest;
+
+// Large enough that rayon splits the work across threads.
+const LEN: usize = 100_000;
+
+#[itest]
+fn packed_array_par_iter_matches_serial() {
+    let array: PackedFloat32Array = (0..LEN).map(|i| i as f32 * 0.37).collect();
+
+    let parallel: Vec<u32> = array.par_iter().map(|x| x.sqrt().to_bits()).collect();
+    let serial: Vec<u32> = array
+        .as_slice()
+        .iter()
+        .map(|x| x.sqrt().to_bits())
+        .collect();
+
+    assert_eq!(parallel, serial);
+}
+
+#[itest]
+fn packed_array_par_iter_mut_matches_serial() {
+    let mut parallel: PackedByteArray = (0..LEN).map(|i| i as u8).collect();
+    let mut serial = parallel.subarray(0, LEN);
+
+    parallel
+        .par_iter_mut()
+        .for_each(|byte| *byte = byte.wrapping_mul(31) ^ 0x5a);
+    serial
+        .as_mut_slice()
+        .iter_mut()
+        .for_each(|byte| *byte = byte.wrapping_mul(31) ^ 0x5a);
+
+    assert_eq!(parallel, serial);
+}
+
+#[itest]
+fn packed_array_par_iter_mut_copy_on_write() {
+    let original: PackedFloat32Array = (0..LEN).map(|i| i as f32).collect();
+    let original_ptr = original.as_slice().as_ptr();
+
+    let mut copy = original.clone();
+    assert_eq!(copy.as_slice().as_ptr(), original_ptr, "storage is shared");
+
+    copy.par_iter_mut().for_each(|x| *x = -*x);
+
+    // The copy was detached once, up front; all threads wrote into the same new storage.
+    let copy_ptr = copy.as_slice().as_ptr();
+    assert_ne!(copy_ptr, original_ptr);
+    assert!(copy
+        .as_slice()
+        .iter()
+        .enumerate()
+        .all(|(i, &x)| x == -(i as f32)));
+    assert!(original
+        .as_slice()
+        .iter()
+        .enumerate()
+        .all(|(i, &x)| x == i as f32));
+
+    // Now unique, so no further copy happens.
+    copy.par_iter_mut().for_each(|x| *x = -*x);
+    assert_eq!(copy.as_slice().as_ptr(), copy_ptr);
+}
+
+#[itest]
+fn packed_vector3_array_par_transform_matches_serial() {
+    let vertices: PackedVector3Array = (0..LEN)
+        .map(|i| {
+            let i = i as real;
+            Vector3::new(i.sin() * 10.0, i * 0.01, i.cos() * -3.0)
+        })
+        .collect();
+
+    let xf = Transform3D::new(
+        Basis::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalized(), 0.7)
+            .scaled(Vector3::new(1.5, 0.5, 2.0)),
+        Vector3::new(-4.0, 8.25, 0.1),
+    );
+
+    let mut parallel = vertices.clone();
+    parallel.par_transform_in_place(xf);
+
+    let serial: Vec<Vector3> = vertices.as_slice().iter().map(|&v| xf * v).collect();
+
+    assert_eq!(parallel.len(), serial.len());
+    for (p, s) in parallel.as_slice().iter().zip(serial.iter()) {
+        assert_eq!(
+            [p.x.to_bits(), p.y.to_bits(), p.z.to_bits()],
+            [s.x.to_bits(), s.y.to_bits(), s.z.to_bits()]
+        );
+    }
+
+    // The source array is not affected.
+    assert_ne!(vertices, parallel);
+}
@@ -210,11 +210,11 @@ fn packed_array_index() {
     array.push("first");
     array.push(&GString::from("second"));
 
-    assert_eq!(array[0], "first".into());
-    assert_eq!(array[1], "second".into());
+    assert_eq!(array[0], "first");
+    assert_eq!(array[1], "second");
 
     array[0] = GString::from("begin");
-    assert_eq!(array[0], "begin".into());
+    assert_eq!(array[0], "begin");
 }
 
 #[itest]
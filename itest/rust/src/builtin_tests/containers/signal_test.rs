@@ -5,9 +5,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::framework::itest;
+use crate::framework::{itest, suppress_godot_print};
 use godot::builtin::{GString, Signal, StringName};
 use godot::classes::{Node, Object, RefCounted};
+use godot::global::Error;
 use godot::meta::ToGodot;
 use godot::obj::{Base, Gd, InstanceId, NewAlloc, NewGd};
 use godot::register::{godot_api, GodotClass};
@@ -289,6 +290,95 @@ fn signal_construction_and_id() {
     assert_eq!(signal.object(), None);
 }
 
+#[itest]
+fn signal_connect_guarded() {
+    let mut emitter = Emitter::new_alloc();
+    let receiver = Receiver::new_alloc();
+
+    let signal = Signal::from_object_signal(&emitter, "signal_int");
+    let callable = receiver.callable("receive_int");
+
+    let connection = signal.connect_guarded(&callable, 0).expect("connect");
+    assert!(connection.is_connected());
+    assert!(signal.is_connected(&callable));
+    assert_eq!(connection.signal(), &signal);
+    assert_eq!(connection.callable(), &callable);
+
+    emitter.emit_signal("signal_int", &[5.to_variant()]);
+    assert_eq!(receiver.bind().last_received(), LastReceived::Int(5));
+
+    drop(connection);
+    assert!(!signal.is_connected(&callable));
+
+    // Explicit disconnect.
+    let connection = signal.connect_guarded(&callable, 0).expect("connect");
+    connection.disconnect();
+    assert!(!signal.is_connected(&callable));
+
+    receiver.free();
+    emitter.free();
+}
+
+#[itest]
+fn signal_connect_guarded_forget() {
+    let emitter = Emitter::new_alloc();
+    let receiver = Receiver::new_alloc();
+
+    let signal = Signal::from_object_signal(&emitter, "signal_int");
+    let callable = receiver.callable("receive_int");
+
+    signal
+        .connect_guarded(&callable, 0)
+        .expect("connect")
+        .forget();
+    assert!(signal.is_connected(&callable));
+
+    signal.disconnect(&callable);
+    receiver.free();
+    emitter.free();
+}
+
+#[itest]
+fn signal_connect_guarded_outlives_object() {
+    let emitter = Emitter::new_alloc();
+    let receiver = Receiver::new_alloc();
+
+    let signal = Signal::from_object_signal(&emitter, "signal_int");
+    let connection = signal
+        .connect_guarded(&receiver.callable("receive_int"), 0)
+        .expect("connect");
+
+    // Freeing the emitter removes its connections; dropping the guard afterwards must not disconnect again.
+    emitter.free();
+    assert!(!connection.is_connected());
+    drop(connection);
+
+    receiver.free();
+}
+
+#[itest]
+fn signal_connect_guarded_already_connected() {
+    let emitter = Emitter::new_alloc();
+    let receiver = Receiver::new_alloc();
+
+    let signal = Signal::from_object_signal(&emitter, "signal_int");
+    let callable = receiver.callable("receive_int");
+    let connection = signal.connect_guarded(&callable, 0).expect("connect");
+
+    let mut result = None;
+    suppress_godot_print(|| result = Some(signal.connect_guarded(&callable, 0)));
+
+    let err = result.unwrap().expect_err("connected twice");
+    assert_eq!(err, Error::ERR_INVALID_PARAMETER);
+
+    // The failed attempt must not have disconnected the existing connection.
+    assert!(connection.is_connected());
+    drop(connection);
+
+    receiver.free();
+    emitter.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helper types
 
@@ -0,0 +1,152 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(since_api = "4.4")]
+
+use std::collections::HashMap;
+
+use godot::builtin::{dict, Dictionary, GString, TypedDictionary, Variant, VariantType};
+use godot::classes::{Node, Object};
+use godot::global::PropertyHint;
+use godot::meta::{FromGodot, ToGodot};
+use godot::obj::{Gd, NewAlloc};
+use godot::register::GodotClass;
+
+use crate::framework::itest;
+
+#[itest]
+fn typed_dictionary_godot_metadata() {
+    let dict = TypedDictionary::<GString, i64>::new();
+    let inner = dict.as_inner();
+
+    assert!(inner.is_typed());
+    assert_eq!(
+        inner.get_typed_key_builtin(),
+        VariantType::STRING.ord() as i64
+    );
+    assert_eq!(
+        inner.get_typed_value_builtin(),
+        VariantType::INT.ord() as i64
+    );
+
+    let objects = TypedDictionary::<i64, Gd<Node>>::new();
+    let inner = objects.as_inner();
+    assert_eq!(
+        inner.get_typed_value_builtin(),
+        VariantType::OBJECT.ord() as i64
+    );
+    assert_eq!(inner.get_typed_value_class_name(), "Node");
+
+    // Variant keys and values remain untyped.
+    let untyped = TypedDictionary::<Variant, Variant>::new();
+    assert!(!untyped.as_inner().is_typed());
+
+    let half = TypedDictionary::<GString, Variant>::new();
+    assert!(half.as_inner().is_typed_key());
+    assert!(!half.as_inner().is_typed_value());
+}
+
+#[itest]
+fn typed_dictionary_insert_get_remove() {
+    let mut dict = TypedDictionary::<GString, i64>::new();
+    assert!(dict.is_empty());
+
+    assert_eq!(dict.insert("a".into(), 1), None);
+    assert_eq!(dict.insert("a".into(), 2), Some(1));
+    dict.set("b".into(), 3);
+
+    assert_eq!(dict.len(), 2);
+    assert_eq!(dict.get(&"a".into()), Some(2));
+    assert_eq!(dict.at(&"b".into()), 3);
+    assert_eq!(dict.get(&"c".into()), None);
+    assert!(dict.contains_key(&"b".into()));
+
+    assert_eq!(dict.remove(&"a".into()), Some(2));
+    assert_eq!(dict.remove(&"a".into()), None);
+
+    let keys: Vec<GString> = dict.keys_shared().collect();
+    assert_eq!(keys, vec![GString::from("b")]);
+
+    dict.clear();
+    assert!(dict.is_empty());
+    assert!(dict.as_inner().is_typed(), "clear() keeps types");
+}
+
+#[itest]
+fn typed_dictionary_hash_map() {
+    let map = HashMap::from([(1_i64, GString::from("one")), (2, GString::from("two"))]);
+
+    let dict = TypedDictionary::from(map.clone());
+    assert_eq!(dict.len(), 2);
+    assert_eq!(dict.get(&2), Some("two".into()));
+    assert!(dict.as_inner().is_typed());
+
+    let back: HashMap<i64, GString> = HashMap::from(&dict);
+    assert_eq!(back, map);
+
+    let collected: TypedDictionary<i64, GString> = map.into_iter().collect();
+    assert_eq!(collected.len(), 2);
+}
+
+#[itest]
+fn typed_dictionary_conversion() {
+    let mut dict = TypedDictionary::<GString, i64>::new();
+    dict.set("x".into(), 10);
+
+    // Roundtrip through Variant keeps the typed metadata.
+    let variant = dict.to_variant();
+    let back = variant.to::<TypedDictionary<GString, i64>>();
+    assert_eq!(back, dict);
+
+    // Different types are rejected.
+    let err = variant.try_to::<TypedDictionary<GString, f64>>();
+    assert!(err.is_err());
+
+    // Untyped dictionaries are rejected, even if their entries would fit.
+    let untyped: Dictionary = dict! { "x": 10 };
+    assert!(TypedDictionary::<GString, i64>::try_from_untyped(untyped.clone()).is_err());
+    assert!(TypedDictionary::<Variant, Variant>::try_from_untyped(untyped).is_ok());
+
+    let shared = Dictionary::from_godot(dict.to_godot());
+    assert_eq!(shared.get("x"), Some(10.to_variant()));
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct TypedDictionaryHolder {
+    #[var]
+    scores: TypedDictionary<GString, i64>,
+}
+
+#[itest]
+fn typed_dictionary_property() {
+    let mut holder = TypedDictionaryHolder::new_alloc();
+
+    let property = holder
+        .get_property_list()
+        .iter_shared()
+        .find(|prop| prop.get_or_nil("name") == "scores".to_variant())
+        .expect("property `scores`");
+    assert_eq!(
+        property.get_or_nil("hint"),
+        PropertyHint::DICTIONARY_TYPE.ord().to_variant()
+    );
+    assert_eq!(
+        property.get_or_nil("hint_string"),
+        "String;int".to_variant()
+    );
+
+    let mut scores = TypedDictionary::<GString, i64>::new();
+    scores.set("Alice".into(), 30);
+    holder.set("scores", &scores.to_variant());
+    assert_eq!(holder.bind().scores.get(&"Alice".into()), Some(30));
+
+    let value = holder.get("scores");
+    assert_eq!(value.to::<TypedDictionary<GString, i64>>(), scores);
+
+    holder.free();
+}
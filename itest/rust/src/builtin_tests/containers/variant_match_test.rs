@@ -0,0 +1,105 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{
+    array, variant_match, varray, Array, GString, StringName, Variant, VariantArray, Vector2,
+};
+use godot::classes::{Node, Node2D, RefCounted};
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewAlloc, NewGd};
+
+use crate::framework::itest;
+
+fn describe(value: &Variant) -> String {
+    variant_match!(value, {
+        i64(n) => format!("int {n}"),
+        f64(x) => format!("float {x}"),
+        GString(s) => format!("string {s}"),
+        StringName(s) => format!("string_name {s}"),
+        Vector2(p) => format!("vector2 {}", p.x + p.y),
+        Array<i64>(arr) => format!("ints {}", arr.len()),
+        VariantArray(arr) => format!("array {}", arr.len()),
+        Gd<Node2D>(_node) => "node2d".to_string(),
+        other => format!("other {:?}", other.get_type()),
+    })
+}
+
+#[itest]
+fn variant_match_basic_types() {
+    assert_eq!(describe(&42.to_variant()), "int 42");
+    assert_eq!(describe(&2.5.to_variant()), "float 2.5");
+    assert_eq!(describe(&"hello".to_variant()), "string hello");
+    assert_eq!(
+        describe(&StringName::from("sn").to_variant()),
+        "string_name sn"
+    );
+    assert_eq!(describe(&Vector2::new(1.0, 2.0).to_variant()), "vector2 3");
+
+    // Catch-all binds the variant.
+    let nil = Variant::nil();
+    assert_eq!(describe(&nil), format!("other {:?}", nil.get_type()));
+}
+
+#[itest]
+fn variant_match_generic_types() {
+    assert_eq!(describe(&array![1_i64, 2, 3].to_variant()), "ints 3");
+    assert_eq!(describe(&varray![1, "two"].to_variant()), "array 2");
+
+    let node2d = Node2D::new_alloc();
+    assert_eq!(describe(&node2d.to_variant()), "node2d");
+
+    // Other object types are not converted to Gd<Node2D>.
+    let node = Node::new_alloc();
+    assert!(describe(&node.to_variant()).starts_with("other"));
+
+    node2d.free();
+    node.free();
+}
+
+#[itest]
+fn variant_match_first_matching_arm() {
+    let value = 300.to_variant();
+
+    // i8 does not fit, so the next arm is tried.
+    let result = variant_match!(value, {
+        i8(_small) => "i8",
+        i64(_large) => "i64",
+        _ => "other",
+    });
+    assert_eq!(result, "i64");
+
+    let result = variant_match!(value, {
+        i32(_n) => "i32",
+        i64(_n) => "i64",
+        _ => "other",
+    });
+    assert_eq!(result, "i32");
+}
+
+#[itest]
+fn variant_match_without_catch_all() {
+    let mut seen = Vec::new();
+
+    for value in [
+        1.to_variant(),
+        "skip".to_variant(),
+        RefCounted::new_gd().to_variant(),
+        2.to_variant(),
+    ] {
+        variant_match!(value, {
+            i64(n) => seen.push(n),
+            Gd<RefCounted>(_obj) => seen.push(-1),
+        });
+    }
+
+    assert_eq!(seen, vec![1, -1, 2]);
+
+    let unused: Array<GString> = Array::new();
+    variant_match!(unused.to_variant(), {
+        Array<GString>(arr) => assert!(arr.is_empty()),
+    });
+}
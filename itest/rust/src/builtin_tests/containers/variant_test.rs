@@ -9,12 +9,15 @@ use std::cmp::Ordering;
 use std::fmt::Display;
 
 use godot::builtin::{
-    array, dict, varray, Array, GString, NodePath, Signal, StringName, Variant, Vector2, Vector3,
+    array, dict, varray, Aabb, Array, Callable, Color, GString, NodePath, PackedByteArray,
+    PackedColorArray, PackedFloat64Array, PackedInt32Array, PackedStringArray, PackedVector2Array,
+    PackedVector3Array, Plane, Projection, Quaternion, Rect2, Rect2i, Rid, Signal, StringName,
+    Transform2D, Transform3D, Variant, Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i,
 };
 use godot::builtin::{Basis, Dictionary, VariantArray, VariantOperator, VariantType};
-use godot::classes::{Node, Node2D};
+use godot::classes::{GDScript, Node, Node2D, RefCounted};
 use godot::meta::{FromGodot, ToGodot};
-use godot::obj::{Gd, InstanceId, NewAlloc};
+use godot::obj::{Gd, InstanceId, NewAlloc, NewGd};
 use godot::sys::GodotFfi;
 
 use crate::common::roundtrip;
@@ -519,6 +522,113 @@ fn variant_booleanize() {
     assert!(!gstr("").to_variant().booleanize());
 }
 
+#[itest]
+fn variant_is_truthy_matches_gdscript() {
+    let code = r#"
+extends RefCounted
+
+func truthy(value) -> bool:
+    if value:
+        return true
+    return false
+"#;
+
+    let mut script = GDScript::new_gd();
+    script.set_source_code(code);
+    script.reload();
+
+    let mut evaluator = RefCounted::new_gd();
+    evaluator.set_script(&script.to_variant());
+
+    let node = Node::new_alloc();
+    let object_variant = node.to_variant();
+
+    // Default value and non-default value of every type.
+    let values = [
+        Variant::nil(),
+        false.to_variant(),
+        true.to_variant(),
+        0.to_variant(),
+        (-1).to_variant(),
+        0.0.to_variant(),
+        0.5.to_variant(),
+        gstr("").to_variant(),
+        gstr("false").to_variant(),
+        Vector2::ZERO.to_variant(),
+        Vector2::new(0.0, 1.0).to_variant(),
+        Vector2i::ZERO.to_variant(),
+        Vector2i::new(1, 0).to_variant(),
+        Rect2::default().to_variant(),
+        Rect2::new(Vector2::ZERO, Vector2::ONE).to_variant(),
+        Rect2i::default().to_variant(),
+        Rect2i::new(Vector2i::ONE, Vector2i::ZERO).to_variant(),
+        Vector3::ZERO.to_variant(),
+        Vector3::UP.to_variant(),
+        Vector3i::ZERO.to_variant(),
+        Vector3i::new(0, 0, 3).to_variant(),
+        Transform2D::IDENTITY.to_variant(),
+        Transform2D::from_angle_origin(0.0, Vector2::ONE).to_variant(),
+        Vector4::ZERO.to_variant(),
+        Vector4::new(0.0, 0.0, 0.0, 1.0).to_variant(),
+        Vector4i::ZERO.to_variant(),
+        Vector4i::new(0, 0, 0, -1).to_variant(),
+        Plane::new(Vector3::UP, 0.0).to_variant(),
+        Quaternion::IDENTITY.to_variant(),
+        Quaternion::from_axis_angle(Vector3::UP, 1.0).to_variant(),
+        Aabb::default().to_variant(),
+        Aabb::new(Vector3::ZERO, Vector3::ONE).to_variant(),
+        Basis::IDENTITY.to_variant(),
+        TEST_BASIS.to_variant(),
+        Transform3D::IDENTITY.to_variant(),
+        Transform3D::new(Basis::IDENTITY, Vector3::ONE).to_variant(),
+        Projection::IDENTITY.to_variant(),
+        Projection::ZERO.to_variant(),
+        Color::from_rgba(0.0, 0.0, 0.0, 1.0).to_variant(),
+        Color::from_rgba(0.0, 0.0, 0.0, 0.0).to_variant(),
+        Color::WHITE.to_variant(),
+        StringName::default().to_variant(),
+        StringName::from("name").to_variant(),
+        NodePath::default().to_variant(),
+        NodePath::from("a/b").to_variant(),
+        Rid::Invalid.to_variant(),
+        Rid::new(1).to_variant(),
+        object_variant.clone(),
+        Callable::invalid().to_variant(),
+        Callable::from_object_method(&node, "get_name").to_variant(),
+        Signal::invalid().to_variant(),
+        Signal::from_object_signal(&node, "ready").to_variant(),
+        Dictionary::new().to_variant(),
+        dict! { "key": 0 }.to_variant(),
+        VariantArray::new().to_variant(),
+        varray![Variant::nil()].to_variant(),
+        PackedByteArray::new().to_variant(),
+        PackedByteArray::from(&[0u8][..]).to_variant(),
+        PackedInt32Array::new().to_variant(),
+        PackedInt32Array::from(&[0][..]).to_variant(),
+        PackedFloat64Array::new().to_variant(),
+        PackedStringArray::from(&[gstr("")][..]).to_variant(),
+        PackedVector2Array::new().to_variant(),
+        PackedVector3Array::from(&[Vector3::ZERO][..]).to_variant(),
+        PackedColorArray::new().to_variant(),
+    ];
+
+    for value in values {
+        let expected = evaluator.call("truthy", &[value.clone()]).to::<bool>();
+        assert_eq!(
+            value.is_truthy(),
+            expected,
+            "is_truthy() differs from GDScript for {value:?} ({:?})",
+            value.get_type()
+        );
+        assert_eq!(value.booleanize(), expected);
+    }
+
+    // Freed objects are falsy (not passed to GDScript, which would report an error on the dead instance).
+    assert!(object_variant.is_truthy());
+    node.free();
+    assert!(!object_variant.is_truthy());
+}
+
 #[itest]
 fn variant_hash() {
     let hash_is_not_0 = [
@@ -542,11 +652,93 @@ fn variant_hash() {
 
     assert_eq!(Variant::nil().hash(), 0);
 
+    // Same hash as the contained builtin values.
+    assert_eq!(gstr("string").to_variant().hash(), gstr("string").hash());
+    assert_eq!(
+        varray![1, "two"].to_variant().hash(),
+        varray![1, "two"].hash()
+    );
+
     // It's not guaranteed that different object will have different hash, but it is
     // extremely unlikely for a collision to happen.
     assert_ne!(dict! { 0: dict! { 0: 0 } }, dict! { 0: dict! { 0: 1 } });
 }
 
+#[itest]
+fn variant_hash_compare() {
+    let nan = f64::NAN.to_variant();
+    let one_int = 1.to_variant();
+    let one_float = 1.0.to_variant();
+
+    // NaN: unequal with `==`, but equal as dictionary key.
+    assert_ne!(nan, f64::NAN.to_variant());
+    assert!(nan.hash_compare(&nan));
+    assert!(varray![f64::NAN]
+        .to_variant()
+        .hash_compare(&varray![f64::NAN].to_variant()));
+    assert!(Vector2::new(f32::NAN as _, 1.0)
+        .to_variant()
+        .hash_compare(&Vector2::new(f32::NAN as _, 1.0).to_variant()));
+
+    // Different types: equal with `==`, but different dictionary keys.
+    assert_eq!(one_int, one_float);
+    assert!(!one_int.hash_compare(&one_float));
+
+    // Regular values agree.
+    assert!(one_int.hash_compare(&1.to_variant()));
+    assert!(!one_int.hash_compare(&2.to_variant()));
+    assert!(dict! { "a": varray![1, 2] }
+        .to_variant()
+        .hash_compare(&dict! { "a": varray![1, 2] }.to_variant()));
+
+    // Matches the behavior of Dictionary itself.
+    let mut dict = Dictionary::new();
+    dict.set(nan.clone(), "nan");
+    dict.set(one_int.clone(), "int");
+    assert_eq!(dict.get(nan), Some("nan".to_variant()));
+    assert_eq!(dict.get(one_float), None);
+}
+
+#[itest]
+fn variant_duplicate() {
+    let inner = varray![1, 2];
+    let original = varray![inner.clone(), "text"].to_variant();
+
+    let shallow = original.duplicate_shallow();
+    let deep = original.duplicate_deep();
+    assert_eq!(shallow, original);
+    assert_eq!(deep, original);
+
+    // Top-level container is copied in both cases.
+    let mut shallow_array = shallow.to::<VariantArray>();
+    shallow_array.push(&3.to_variant());
+    assert_eq!(original.to::<VariantArray>().len(), 2);
+
+    // Nested containers are only copied by the deep variant.
+    let mut inner = inner;
+    inner.push(&3.to_variant());
+    assert_eq!(
+        shallow.to::<VariantArray>().at(0),
+        varray![1, 2, 3].to_variant()
+    );
+    assert_eq!(deep.to::<VariantArray>().at(0), varray![1, 2].to_variant());
+
+    let dict = dict! { "key": inner.clone() }.to_variant();
+    let deep = dict.duplicate_deep();
+    inner.push(&4.to_variant());
+    assert_eq!(deep, dict! { "key": varray![1, 2, 3] }.to_variant());
+
+    // Non-containers are returned as-is; objects are not duplicated.
+    assert_eq!(5.to_variant().duplicate_deep(), 5.to_variant());
+    let node = Node::new_alloc();
+    let node_variant = node.to_variant();
+    assert_eq!(
+        node_variant.duplicate_deep().object_id(),
+        Some(node.instance_id())
+    );
+    node.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 fn truncate_bad<T>(original_value: i64)
@@ -57,6 +57,37 @@ fn transform2d_equiv() {
     );
 }
 
+#[itest]
+fn transform2d_interpolate_with_rotation() {
+    // Rotation wraps around +/-PI; the shortest path goes through PI, not through 0.
+    let from = Transform2D::from_angle_scale_skew_origin(
+        3.0,
+        Vector2::new(1.0, 2.0),
+        0.2,
+        Vector2::new(1.0, -2.0),
+    );
+    let to = Transform2D::from_angle_scale_skew_origin(
+        -2.9,
+        Vector2::new(0.5, 3.0),
+        -0.1,
+        Vector2::new(-4.0, 5.0),
+    );
+
+    for (a, b) in [(from, to), (to, from), (TEST_TRANSFORM, to)] {
+        let inner = InnerTransform2D::from_outer(&a);
+
+        for weight in [0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0] {
+            let expected = inner.interpolate_with(b, weight);
+            let actual = a.interpolate_with(&b, weight as real);
+
+            assert_eq_approx!(actual, expected, "weight {weight}, from {a:?} to {b:?}");
+        }
+    }
+
+    let half = from.interpolate_with(&to, 0.5);
+    assert!(half.rotation().abs() > 3.0, "{}", half.rotation());
+}
+
 #[itest]
 fn transform2d_determinant() {
     let inner = InnerTransform2D::from_outer(&TEST_TRANSFORM);
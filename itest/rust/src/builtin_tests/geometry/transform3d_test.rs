@@ -8,7 +8,7 @@
 use crate::framework::itest;
 
 use godot::builtin::inner::InnerTransform3D;
-use godot::builtin::{Aabb, Basis, Plane, Transform3D, VariantOperator, Vector3};
+use godot::builtin::{real, Aabb, Basis, EulerOrder, Plane, Transform3D, VariantOperator, Vector3};
 use godot::meta::ToGodot;
 use godot::private::class_macros::assert_eq_approx;
 
@@ -44,6 +44,41 @@ fn transform3d_equiv() {
     }
 }
 
+#[itest]
+fn transform3d_interpolate_with_rotation() {
+    // Rotations of more than 180 degrees apart on several axes, with non-uniform scale.
+    let from = Transform3D::new(
+        Basis::from_euler(EulerOrder::YXZ, Vector3::new(0.3, 2.9, -1.2))
+            * Basis::from_diagonal(1.0, 2.0, 0.5),
+        Vector3::new(1.0, -2.0, 3.0),
+    );
+    let to = Transform3D::new(
+        Basis::from_euler(EulerOrder::YXZ, Vector3::new(-1.4, -2.8, 2.5))
+            * Basis::from_diagonal(3.0, 1.0, 1.5),
+        Vector3::new(-4.0, 5.0, 0.0),
+    );
+    // Reflection (negative determinant).
+    let mirrored = Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, 2.0) * Basis::from_diagonal(-1.0, 1.0, 1.0),
+        Vector3::ZERO,
+    );
+
+    for (a, b) in [(from, to), (to, from), (from, mirrored)] {
+        let inner = InnerTransform3D::from_outer(&a);
+
+        for weight in [0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0] {
+            let expected = inner.interpolate_with(b, weight);
+            let actual = a.interpolate_with(&b, weight as real);
+
+            assert_eq_approx!(actual, expected, "weight {weight}, from {a:?} to {b:?}");
+        }
+    }
+
+    // Intermediate transforms keep their scale, unlike lerping basis vectors.
+    let half = from.interpolate_with(&to, 0.5);
+    assert_eq_approx!(half.basis.get_scale(), Vector3::new(2.0, 1.5, 1.0));
+}
+
 #[itest]
 fn transform3d_xform_equiv() {
     let vec = Vector3::new(1.0, 2.0, 3.0);
@@ -245,6 +245,11 @@ fn is_normalized() {
 
     assert_eq!(a.is_normalized(), a.as_inner().is_normalized());
     assert_eq!(b.is_normalized(), b.as_inner().is_normalized());
+
+    // Within Godot's tolerance, but not glam's.
+    let c = Vector2::new(1.00025, 0.0);
+    assert!(c.is_normalized());
+    assert_eq!(c.is_normalized(), c.as_inner().is_normalized());
 }
 
 #[itest]
@@ -289,6 +294,18 @@ fn limit_length() {
         a.limit_length(Some(b as real)),
         a.as_inner().limit_length(b)
     );
+
+    // Shorter vectors, zero vectors and negative limits.
+    assert_eq!(
+        a.limit_length(Some(100.0)),
+        a.as_inner().limit_length(100.0)
+    );
+    assert_eq!(a.limit_length(None), a.as_inner().limit_length(1.0));
+    assert_eq!(
+        Vector2::ZERO.limit_length(Some(1.0)),
+        Vector2::ZERO.as_inner().limit_length(1.0)
+    );
+    assert_eq_approx!(a.limit_length(Some(-2.0)), a.as_inner().limit_length(-2.0));
 }
 
 #[itest]
@@ -345,6 +362,17 @@ fn move_toward() {
     let c = 5.0;
 
     assert_eq!(a.move_toward(b, c as real), a.as_inner().move_toward(b, c));
+
+    // Overshooting stops at the target; negative deltas move away.
+    assert_eq!(a.move_toward(b, 100.0), a.as_inner().move_toward(b, 100.0));
+    assert_eq_approx!(a.move_toward(b, -2.0), a.as_inner().move_toward(b, -2.0));
+
+    // Very close targets are only snapped to below Godot's CMP_EPSILON.
+    let close = a + Vector2::new(0.00005, 0.0);
+    assert_eq_approx!(
+        a.move_toward(close, 0.00001),
+        a.as_inner().move_toward(close, 0.00001)
+    );
 }
 
 #[itest]
@@ -28,9 +28,13 @@ mod containers {
     mod array_test;
     mod callable_test;
     mod dictionary_test;
+    #[cfg(feature = "rayon")]
+    mod packed_array_par_test;
     mod packed_array_test;
     mod rid_test;
     mod signal_test;
+    mod typed_dictionary_test;
+    mod variant_match_test;
     mod variant_test;
 }
 
@@ -42,6 +46,7 @@ mod string {
 }
 
 mod script {
+    mod kv_script_tests;
     mod script_instance_tests;
 }
 
@@ -49,5 +54,7 @@ mod color_test;
 
 mod convert_test;
 
+mod real_test;
+
 #[cfg(feature = "serde")]
 mod serde_test;
@@ -0,0 +1,70 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Runs against both single- and double-precision engines (CI jobs with `godot/double-precision`). In either case, values must pass
+// through the engine without losing any bits of `real`.
+
+use godot::builtin::{real, RealConv, Transform3D, VariantOperator, Vector2, Vector3};
+use godot::classes::{Node3D, Os};
+use godot::meta::ToGodot;
+use godot::obj::NewAlloc;
+
+use crate::framework::itest;
+
+/// Value using all mantissa bits of `real`, so any narrowing conversion changes it.
+fn third() -> real {
+    1.0 / 3.0
+}
+
+#[itest]
+fn real_precision_matches_engine() {
+    let engine_is_double = Os::singleton().has_feature("double");
+    let gdext_is_double = size_of::<real>() == size_of::<f64>();
+
+    assert_eq!(engine_is_double, gdext_is_double);
+}
+
+#[itest]
+fn real_roundtrip_variant() {
+    let vector = Vector3::new(third(), -2.0 * third(), 1000.0 + third());
+    let variant = vector.to_variant();
+
+    assert_eq!(variant.to::<Vector3>(), vector);
+
+    // Engine method returning `real_t` as `float`.
+    let x = variant.call("dot", &[Vector3::RIGHT.to_variant()]);
+    assert_eq!(x.to::<f64>(), third().as_f64());
+}
+
+#[itest]
+fn real_roundtrip_engine() {
+    let mut node = Node3D::new_alloc();
+
+    let position = Vector3::new(third(), 2.0 * third(), 1e6 + third());
+    node.set_position(position);
+    assert_eq!(node.get_position(), position);
+
+    let transform = Transform3D::IDENTITY.translated(position);
+    node.set_transform(transform);
+    assert_eq!(node.get_transform(), transform);
+
+    node.free();
+}
+
+#[itest]
+fn real_arithmetic_matches_engine() {
+    let a = Vector2::new(third(), 1e4 + third());
+    let b = Vector2::new(third() / 7.0, -third());
+
+    let engine_sum = a
+        .to_variant()
+        .evaluate(&b.to_variant(), VariantOperator::ADD)
+        .expect("Vector2 + Vector2 is valid");
+
+    // Bit-identical only if both sides compute with the same precision.
+    assert_eq!(engine_sum.to::<Vector2>(), a + b);
+}
@@ -0,0 +1,293 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Toy script language whose scripts only store key/value pairs. Exercises the ScriptInstance glue end-to-end through the Object API.
+
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use godot::builtin::{
+    Array, Dictionary, GString, PackedStringArray, StringName, Variant, VariantType,
+};
+use godot::classes::{IScriptExtension, Object, Script, ScriptExtension, ScriptLanguage};
+use godot::global::Error;
+use godot::meta::{MethodInfo, PropertyInfo, ToGodot};
+use godot::obj::script::{create_script_instance, ScriptInstance, SiMut};
+use godot::obj::{Base, Gd, NewAlloc, WithBaseField};
+use godot::register::{godot_api, GodotClass};
+use godot::sys;
+
+use super::script_instance_tests::TestScriptLanguage;
+use crate::framework::itest;
+
+/// Number of live `KvScriptInstance`s, to verify that instances are dropped together with their owner.
+static LIVE_INSTANCES: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(GodotClass)]
+#[class(base = ScriptExtension, no_init, tool)]
+struct KvScript {
+    language: Gd<TestScriptLanguage>,
+    base: Base<ScriptExtension>,
+}
+
+impl KvScript {
+    fn new(language: Gd<TestScriptLanguage>) -> Gd<Self> {
+        Gd::from_init_fn(|base| Self { language, base })
+    }
+}
+
+#[rustfmt::skip]
+#[godot_api]
+impl IScriptExtension for KvScript {
+    fn can_instantiate(&self) -> bool {
+        true
+    }
+
+    unsafe fn instance_create(&self, for_object: Gd<Object>) -> *mut c_void {
+        let instance = KvScriptInstance::new(self.to_gd().upcast(), self.language.clone().upcast());
+        create_script_instance(instance, for_object)
+    }
+
+    fn get_language(&self) -> Option<Gd<ScriptLanguage>> {
+        Some(self.language.clone().upcast())
+    }
+
+    fn editor_can_reload_from_file(&mut self) -> bool { unreachable!() }
+    fn get_base_script(&self) -> Option<Gd<Script>> { unreachable!() }
+    fn get_global_name(&self) -> StringName { unreachable!() }
+    fn inherits_script(&self, _script: Gd<Script>) -> bool { unreachable!() }
+    fn get_instance_base_type(&self) -> StringName { unreachable!() }
+    unsafe fn placeholder_instance_create(&self, _for_object: Gd<Object>) -> *mut c_void { unreachable!() }
+    fn instance_has(&self, _object: Gd<Object>) -> bool { unreachable!() }
+    fn has_source_code(&self) -> bool { unreachable!() }
+    fn get_source_code(&self) -> GString { unreachable!() }
+    fn set_source_code(&mut self, _code: GString) { unreachable!() }
+    fn reload(&mut self, _keep_state: bool) -> Error { unreachable!() }
+    fn get_documentation(&self) -> Array<Dictionary> { unreachable!() }
+    fn has_method(&self, _method: StringName) -> bool { unreachable!() }
+    #[cfg(since_api = "4.2")]
+    fn has_static_method(&self, _method: StringName) -> bool { unreachable!() }
+    fn get_method_info(&self, _method: StringName) -> Dictionary { unreachable!() }
+    fn is_tool(&self) -> bool { unreachable!() }
+    fn is_valid(&self) -> bool { unreachable!() }
+    fn has_script_signal(&self, _signal: StringName) -> bool { unreachable!() }
+    fn get_script_signal_list(&self) -> Array<Dictionary> { unreachable!() }
+    fn has_property_default_value(&self, _property: StringName) -> bool { unreachable!() }
+    fn get_property_default_value(&self, _property: StringName) -> Variant { unreachable!() }
+    fn update_exports(&mut self) { unreachable!() }
+    fn get_script_method_list(&self) -> Array<Dictionary> { unreachable!() }
+    fn get_script_property_list(&self) -> Array<Dictionary> { unreachable!() }
+    fn get_member_line(&self, _member: StringName) -> i32 { unreachable!() }
+    fn get_constants(&self) -> Dictionary { unreachable!() }
+    fn get_members(&self) -> Array<StringName> { unreachable!() }
+    fn is_placeholder_fallback_enabled(&self) -> bool { unreachable!() }
+    fn get_rpc_config(&self) -> Variant { unreachable!() }
+
+    #[cfg(since_api = "4.4")]
+    fn get_doc_class_name(&self) -> StringName { unreachable!() }
+}
+
+/// Stores every assigned property; `keys()` returns the stored keys, `owner_id()` the instance ID of the owning object.
+struct KvScriptInstance {
+    entries: Vec<(StringName, Variant)>,
+    script: Gd<Script>,
+    language: Gd<ScriptLanguage>,
+}
+
+impl KvScriptInstance {
+    fn new(script: Gd<Script>, language: Gd<ScriptLanguage>) -> Self {
+        LIVE_INSTANCES.fetch_add(1, Ordering::SeqCst);
+
+        Self {
+            entries: Vec::new(),
+            script,
+            language,
+        }
+    }
+
+    fn lookup(&self, name: &StringName) -> Option<&Variant> {
+        self.entries
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value)
+    }
+}
+
+impl Drop for KvScriptInstance {
+    fn drop(&mut self) {
+        LIVE_INSTANCES.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl ScriptInstance for KvScriptInstance {
+    type Base = Object;
+
+    fn class_name(&self) -> GString {
+        GString::from("KvScript")
+    }
+
+    fn set_property(mut this: SiMut<Self>, name: StringName, value: &Variant) -> bool {
+        // Let the engine handle built-in properties such as `script`.
+        if name == StringName::from("script") {
+            return false;
+        }
+
+        match this.entries.iter_mut().find(|(key, _)| *key == name) {
+            Some((_, existing)) => *existing = value.clone(),
+            None => this.entries.push((name, value.clone())),
+        }
+        true
+    }
+
+    fn get_property(&self, name: StringName) -> Option<Variant> {
+        self.lookup(&name).cloned()
+    }
+
+    fn get_property_list(&self) -> Vec<PropertyInfo> {
+        self.entries
+            .iter()
+            .map(|(key, _)| PropertyInfo::new_var::<Variant>(&key.to_string()))
+            .collect()
+    }
+
+    fn get_method_list(&self) -> Vec<MethodInfo> {
+        Vec::new()
+    }
+
+    fn call(
+        this: SiMut<Self>,
+        method: StringName,
+        _args: &[&Variant],
+    ) -> Result<Variant, sys::GDExtensionCallErrorType> {
+        match method.to_string().as_str() {
+            "keys" => {
+                let keys: PackedStringArray = this
+                    .entries
+                    .iter()
+                    .map(|(key, _)| GString::from(key))
+                    .collect();
+
+                Ok(keys.to_variant())
+            }
+            "owner_id" => Ok(this.base().instance_id().to_variant()),
+            _ => Err(sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD),
+        }
+    }
+
+    fn is_placeholder(&self) -> bool {
+        false
+    }
+
+    fn has_method(&self, method: StringName) -> bool {
+        matches!(method.to_string().as_str(), "keys" | "owner_id")
+    }
+
+    fn get_script(&self) -> &Gd<Script> {
+        &self.script
+    }
+
+    fn get_property_type(&self, name: StringName) -> VariantType {
+        self.lookup(&name)
+            .map_or(VariantType::NIL, |value| value.get_type())
+    }
+
+    fn to_string(&self) -> GString {
+        GString::from(format!("KvScript({} entries)", self.entries.len()))
+    }
+
+    fn get_property_state(&self) -> Vec<(StringName, Variant)> {
+        self.entries.clone()
+    }
+
+    fn get_language(&self) -> Gd<ScriptLanguage> {
+        self.language.clone()
+    }
+
+    fn on_refcount_decremented(&self) -> bool {
+        true
+    }
+
+    fn on_refcount_incremented(&self) {}
+
+    fn property_get_fallback(&self, _name: StringName) -> Option<Variant> {
+        None
+    }
+
+    fn property_set_fallback(_this: SiMut<Self>, _name: StringName, _value: &Variant) -> bool {
+        false
+    }
+
+    #[cfg(since_api = "4.3")]
+    fn get_method_argument_count(&self, method: StringName) -> Option<u32> {
+        self.has_method(method).then_some(0)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Test Cases
+
+#[itest]
+fn kv_script_get_set_call() {
+    let language = TestScriptLanguage::new_alloc();
+    let script = KvScript::new(language.clone());
+    let mut object = Object::new_alloc();
+    object.set_script(&script.to_variant());
+
+    object.set("health", &100.to_variant());
+    object.set("name", &"Slime".to_variant());
+    object.set("health", &75.to_variant());
+
+    assert_eq!(object.get("health"), 75.to_variant());
+    assert_eq!(object.get("name"), "Slime".to_variant());
+    assert_eq!(object.get("missing"), Variant::nil());
+
+    let keys = object.call("keys", &[]).to::<PackedStringArray>();
+    assert_eq!(
+        keys.as_slice(),
+        &[GString::from("health"), GString::from("name")]
+    );
+
+    let owner_id = object.call("owner_id", &[]);
+    assert_eq!(owner_id, object.instance_id().to_variant());
+
+    let listed = object
+        .get_property_list()
+        .iter_shared()
+        .filter_map(|prop| prop.get("name"))
+        .filter(|name| *name == "health".to_variant() || *name == "name".to_variant())
+        .count();
+    assert_eq!(listed, 2);
+
+    object.free();
+    language.free();
+}
+
+#[itest]
+fn kv_script_instance_freed_with_owner() {
+    let language = TestScriptLanguage::new_alloc();
+    let script = KvScript::new(language.clone());
+    let before = LIVE_INSTANCES.load(Ordering::SeqCst);
+
+    let mut object = Object::new_alloc();
+    object.set_script(&script.to_variant());
+    object.set("key", &1.to_variant());
+    assert_eq!(LIVE_INSTANCES.load(Ordering::SeqCst), before + 1);
+
+    object.free();
+    assert_eq!(LIVE_INSTANCES.load(Ordering::SeqCst), before);
+
+    // Replacing the script also frees the instance.
+    let mut object = Object::new_alloc();
+    object.set_script(&script.to_variant());
+    assert_eq!(LIVE_INSTANCES.load(Ordering::SeqCst), before + 1);
+
+    object.set_script(&Variant::nil());
+    assert_eq!(LIVE_INSTANCES.load(Ordering::SeqCst), before);
+
+    object.free();
+    language.free();
+}
@@ -247,7 +247,7 @@ impl ScriptInstance for TestScriptInstance {
 
 #[derive(GodotClass)]
 #[class(base = ScriptLanguageExtension, tool, init)]
-struct TestScriptLanguage {
+pub(super) struct TestScriptLanguage {
     base: Base<ScriptLanguageExtension>,
 }
 
@@ -8,7 +8,7 @@
 use std::collections::HashSet;
 
 use crate::framework::{expect_debug_panic_or_release_ok, itest};
-use godot::builtin::{Encoding, GString, PackedStringArray};
+use godot::builtin::{dict, varray, Encoding, GString, PackedStringArray};
 
 // TODO use tests from godot-rust/gdnative
 
@@ -44,6 +44,23 @@ fn string_equality() {
     assert_ne!(string, different);
 }
 
+#[itest]
+fn string_eq_str() {
+    let string = GString::from("some string");
+
+    assert_eq!(string, "some string");
+    assert_eq!("some string", string);
+    assert_ne!(string, "some");
+    assert_ne!(string, "some string ");
+    assert!(string == *"some string");
+
+    let unicode = GString::from("Grüße 🦀");
+    assert_eq!(unicode, "Grüße 🦀");
+    assert_ne!(unicode, "Gruße 🦀");
+
+    assert_eq!(GString::new(), "");
+}
+
 #[itest]
 fn string_ordering() {
     let low = GString::from("Alpha");
@@ -141,12 +158,12 @@ fn string_with_null() {
 #[itest]
 fn string_substr() {
     let string = GString::from("stable");
-    assert_eq!(string.substr(..), "stable".into());
-    assert_eq!(string.substr(1..), "table".into());
-    assert_eq!(string.substr(..4), "stab".into());
-    assert_eq!(string.substr(..=3), "stab".into());
-    assert_eq!(string.substr(2..5), "abl".into());
-    assert_eq!(string.substr(2..=4), "abl".into());
+    assert_eq!(string.substr(..), "stable");
+    assert_eq!(string.substr(1..), "table");
+    assert_eq!(string.substr(..4), "stab");
+    assert_eq!(string.substr(..=3), "stab");
+    assert_eq!(string.substr(2..5), "abl");
+    assert_eq!(string.substr(2..=4), "abl");
 }
 
 #[itest]
@@ -228,42 +245,92 @@ fn gstring_erase() {
     let s = GString::from("Hello World");
     assert_eq!(s.erase(..), GString::new());
     assert_eq!(s.erase(4..4), s);
-    assert_eq!(s.erase(2..=2), "Helo World".into());
-    assert_eq!(s.erase(1..=3), "Ho World".into());
-    assert_eq!(s.erase(1..4), "Ho World".into());
-    assert_eq!(s.erase(..6), "World".into());
-    assert_eq!(s.erase(5..), "Hello".into());
+    assert_eq!(s.erase(2..=2), "Helo World");
+    assert_eq!(s.erase(1..=3), "Ho World");
+    assert_eq!(s.erase(1..4), "Ho World");
+    assert_eq!(s.erase(..6), "World");
+    assert_eq!(s.erase(5..), "Hello");
 }
 
 #[itest]
 fn gstring_insert() {
     let s = GString::from("H World");
-    assert_eq!(s.insert(1, "i"), "Hi World".into());
-    assert_eq!(s.insert(1, "ello"), "Hello World".into());
-    assert_eq!(s.insert(7, "."), "H World.".into());
-    assert_eq!(s.insert(0, "¿"), "¿H World".into());
+    assert_eq!(s.insert(1, "i"), "Hi World");
+    assert_eq!(s.insert(1, "ello"), "Hello World");
+    assert_eq!(s.insert(7, "."), "H World.");
+    assert_eq!(s.insert(0, "¿"), "¿H World");
 
     // Special behavior in Godot, but maybe the idea is to allow large constants to mean "end".
-    assert_eq!(s.insert(123, "!"), "H World!".into());
+    assert_eq!(s.insert(123, "!"), "H World!");
 }
 
 #[itest]
 fn gstring_pad() {
     let s = GString::from("123");
-    assert_eq!(s.lpad(5, '0'), "00123".into());
-    assert_eq!(s.lpad(2, ' '), "123".into());
-    assert_eq!(s.lpad(4, ' '), " 123".into());
+    assert_eq!(s.lpad(5, '0'), "00123");
+    assert_eq!(s.lpad(2, ' '), "123");
+    assert_eq!(s.lpad(4, ' '), " 123");
 
-    assert_eq!(s.rpad(5, '+'), "123++".into());
-    assert_eq!(s.rpad(2, ' '), "123".into());
-    assert_eq!(s.rpad(4, ' '), "123 ".into());
+    assert_eq!(s.rpad(5, '+'), "123++");
+    assert_eq!(s.rpad(2, ' '), "123");
+    assert_eq!(s.rpad(4, ' '), "123 ");
 
     let s = GString::from("123.456");
-    assert_eq!(s.pad_decimals(5), "123.45600".into());
-    assert_eq!(s.pad_decimals(2), "123.45".into()); // note: Godot rounds down
+    assert_eq!(s.pad_decimals(5), "123.45600");
+    assert_eq!(s.pad_decimals(2), "123.45"); // note: Godot rounds down
+
+    assert_eq!(s.pad_zeros(5), "00123.456");
+    assert_eq!(s.pad_zeros(2), "123.456");
+}
+
+#[itest]
+fn gstring_format_dict() {
+    let template = GString::from("{name} has {hp} HP");
+    assert_eq!(
+        template.format_dict(&dict! { "name": "Goblin", "hp": 12 }),
+        "Goblin has 12 HP"
+    );
+
+    // Missing keys leave the placeholder untouched; unused keys are ignored.
+    assert_eq!(
+        template.format_dict(&dict! { "name": "Goblin", "mana": 3 }),
+        "Goblin has {hp} HP"
+    );
+}
+
+#[itest]
+fn gstring_format_array() {
+    let template = GString::from("{0} + {1} = {2}");
+    assert_eq!(template.format_array(&varray![1, 2, 3]), "1 + 2 = 3");
+    assert_eq!(template.format_array(&varray![1]), "1 + {1} = {2}");
+
+    // Key-value pairs.
+    let template = GString::from("{x}/{y}");
+    assert_eq!(
+        template.format_array(&varray![varray!["x", 4], varray!["y", 5]]),
+        "4/5"
+    );
+}
 
-    assert_eq!(s.pad_zeros(5), "00123.456".into());
-    assert_eq!(s.pad_zeros(2), "123.456".into());
+#[itest]
+fn gstring_sprintf() {
+    let template = GString::from("%s: %03d (%.1f%%)");
+    assert_eq!(
+        template.sprintf(&varray!["score", 7, 99.5]).unwrap(),
+        "score: 007 (99.5%)"
+    );
+
+    let err = template.sprintf(&varray!["score"]).unwrap_err();
+    assert!(
+        err.to_string().contains("not enough arguments"),
+        "unexpected error: {err}"
+    );
+
+    let err = GString::from("%d").sprintf(&varray![1, 2]).unwrap_err();
+    assert!(
+        err.to_string().contains("not all arguments converted"),
+        "unexpected error: {err}"
+    );
 }
 
 // Byte and C-string conversions.
@@ -8,7 +8,7 @@
 use std::collections::HashSet;
 
 use crate::framework::{assert_eq_self, itest};
-use godot::builtin::{Encoding, GString, NodePath, StringName};
+use godot::builtin::{dict, varray, Encoding, GString, NodePath, StringName};
 
 #[itest]
 fn string_name_default() {
@@ -58,6 +58,23 @@ fn string_name_equality() {
     assert_ne!(string, different);
 }
 
+#[itest]
+fn string_name_eq_str() {
+    let name = StringName::from("some string");
+
+    assert_eq!(name, "some string");
+    assert_eq!("some string", name);
+    assert_ne!(name, "some");
+    assert_ne!(name, "some string ");
+    assert!(name == *"some string");
+
+    let unicode = StringName::from("Grüße 🦀");
+    assert_eq!(unicode, "Grüße 🦀");
+    assert_ne!(unicode, "Gruße 🦀");
+
+    assert_eq!(StringName::default(), "");
+}
+
 #[itest]
 #[allow(clippy::eq_op)]
 fn string_name_transient_ord() {
@@ -117,6 +134,16 @@ fn string_name_length() {
     assert_eq!(empty.len(), 0);
 }
 
+#[itest]
+fn string_name_format() {
+    let name = StringName::from("{0}_{kind}");
+    assert_eq!(name.format_dict(&dict! { "kind": "enemy" }), "{0}_enemy");
+    assert_eq!(name.format_array(&varray![3]), "3_{kind}");
+
+    let name = StringName::from("slot_%d");
+    assert_eq!(name.sprintf(&varray![2]).unwrap(), "slot_2");
+}
+
 #[itest]
 fn string_name_is_empty() {
     let name = StringName::from("hello!");
@@ -7,14 +7,16 @@
 
 use std::ops::Deref;
 
-use godot::builtin::{Callable, Signal, Variant};
-use godot::classes::{Object, RefCounted};
+use godot::builtin::{Callable, GString, Signal, Variant};
+use godot::classes::{GDScript, Object, RefCounted};
 use godot::meta::ToGodot;
 use godot::obj::{Base, Gd, NewAlloc, NewGd};
 use godot::prelude::{godot_api, GodotClass};
-use godot::task::{self, create_test_signal_future_resolver, SignalFuture, TaskHandle};
+use godot::task::{
+    self, create_test_signal_future_resolver, Selected, SignalFuture, TaskError, TaskHandle,
+};
 
-use crate::framework::{expect_async_panic, itest, TestContext};
+use crate::framework::{expect_async_panic, expect_panic, itest, suppress_panic_log, TestContext};
 
 #[derive(GodotClass)]
 #[class(init)]
@@ -26,6 +28,12 @@ struct AsyncRefCounted {
 impl AsyncRefCounted {
     #[signal]
     fn custom_signal(value: u32);
+
+    #[signal]
+    fn finished(code: i32, message: GString);
+
+    #[signal]
+    fn done();
 }
 
 #[itest(async)]
@@ -70,6 +78,73 @@ fn cancel_async_task(ctx: &TestContext) {
     handle.cancel();
 }
 
+#[itest(async)]
+fn task_handle_join() -> TaskHandle {
+    let producer = task::spawn(async {
+        task::next_frame().await.unwrap();
+        GString::from("produced")
+    });
+
+    assert!(!producer.is_finished());
+    assert_eq!(producer.try_result(), None);
+
+    task::spawn(async move {
+        let result = producer.join().await;
+        assert_eq!(result, Ok(GString::from("produced")));
+    })
+}
+
+#[itest]
+fn task_handle_try_result_immediate() {
+    // Completes during the first poll in spawn().
+    let handle = task::spawn(async { 42 });
+
+    assert!(handle.is_finished());
+    assert!(!handle.is_pending());
+    assert_eq!(handle.try_result(), Some(Ok(42)));
+
+    expect_panic("result retrieved twice", || {
+        let _ = handle.try_result();
+    });
+}
+
+#[itest(async)]
+fn task_handle_canceled() -> TaskHandle {
+    let never = task::spawn(async {
+        task::until(|| false).await.unwrap();
+        1
+    });
+
+    assert!(!never.is_finished());
+    never.cancel();
+    assert!(never.is_finished());
+    assert_eq!(never.try_result(), Some(Err(TaskError::Canceled)));
+
+    let canceled = task::spawn(async {
+        task::until(|| false).await.unwrap();
+    });
+    canceled.cancel();
+
+    task::spawn(async move {
+        assert_eq!(canceled.join().await, Err(TaskError::Canceled));
+    })
+}
+
+#[itest(async)]
+fn task_handle_panicked() -> TaskHandle {
+    fn failing_computation() -> i32 {
+        panic!("task failure")
+    }
+
+    // Panics during the first poll, inside spawn().
+    let panicking = suppress_panic_log(|| task::spawn(async { failing_computation() }));
+    assert!(panicking.is_finished());
+
+    task::spawn(async move {
+        assert_eq!(panicking.join().await, Err(TaskError::Panicked));
+    })
+}
+
 #[itest(async)]
 fn async_task_fallible_signal_future() -> TaskHandle {
     let mut obj = Object::new_alloc();
@@ -190,3 +265,213 @@ fn async_typed_signal() -> TaskHandle {
 
     task_handle
 }
+
+#[itest(async)]
+fn async_typed_signal_multiple_args() -> TaskHandle {
+    let object = AsyncRefCounted::new_gd();
+    let mut copy = object.clone();
+
+    let task_handle = task::spawn(async move {
+        // Awaited directly, without deref().
+        let (code, message) = copy.signals().finished().await;
+
+        assert_eq!(code, 404);
+        assert_eq!(message, "not found");
+    });
+
+    gdscript_emitter().call(
+        "emit_finished",
+        &[
+            object.to_variant(),
+            404.to_variant(),
+            "not found".to_variant(),
+        ],
+    );
+
+    task_handle
+}
+
+#[itest(async)]
+fn async_typed_signal_no_args() -> TaskHandle {
+    let mut object = AsyncRefCounted::new_gd();
+    let mut copy = object.clone();
+
+    let task_handle = task::spawn(async move {
+        let () = copy.signals().done().await;
+    });
+
+    object.signals().done().emit();
+
+    task_handle
+}
+
+#[itest(async)]
+fn async_signal_future_typed() -> TaskHandle {
+    let object = AsyncRefCounted::new_gd();
+    let signal = Signal::from_object_signal(&object, "finished");
+
+    let task_handle = task::spawn(async move {
+        let (code, message) = task::signal_future::<(i32, GString)>(&signal).await;
+
+        assert_eq!(code, 200);
+        assert_eq!(message, "ok");
+    });
+
+    gdscript_emitter().call(
+        "emit_finished",
+        &[object.to_variant(), 200.to_variant(), "ok".to_variant()],
+    );
+
+    task_handle
+}
+
+#[itest(async)]
+fn async_signal_future_wrong_types_panic() -> TaskHandle {
+    let object = AsyncRefCounted::new_gd();
+    let signal = Signal::from_object_signal(&object, "finished");
+
+    let task_handle = task::spawn(expect_async_panic(
+        "future should panic when the signal arguments have the wrong type",
+        async move {
+            task::signal_future::<(i32, GString)>(&signal).await;
+        },
+    ));
+
+    // Arguments swapped.
+    gdscript_emitter().call(
+        "emit_finished",
+        &[object.to_variant(), "ok".to_variant(), 200.to_variant()],
+    );
+
+    task_handle
+}
+
+#[itest]
+fn signal_future_drop_disconnects() {
+    let mut object = AsyncRefCounted::new_gd();
+    let signal = Signal::from_object_signal(&object, "custom_signal");
+
+    let future = signal.to_future::<(u32,)>();
+    assert_eq!(object.get_signal_connection_list("custom_signal").len(), 1);
+
+    drop(future);
+    assert!(object
+        .get_signal_connection_list("custom_signal")
+        .is_empty());
+
+    // Must not reach the dropped future.
+    object.signals().custom_signal().emit(1);
+}
+
+#[itest(async)]
+fn select_signal_first() -> TaskHandle {
+    let mut object = AsyncRefCounted::new_gd();
+    let custom = Signal::from_object_signal(&object, "custom_signal");
+    let done = Signal::from_object_signal(&object, "done");
+
+    let copy = object.clone();
+    let task_handle = task::spawn(async move {
+        let selected =
+            task::select_signal(custom.to_future::<(u32,)>(), done.to_future::<()>()).await;
+        assert_eq!(selected, Selected::First((5,)));
+
+        // The losing future is disconnected right away.
+        assert!(copy.get_signal_connection_list("done").is_empty());
+    });
+
+    object.signals().custom_signal().emit(5);
+
+    task_handle
+}
+
+#[itest(async)]
+fn select_signal_second() -> TaskHandle {
+    let mut object = AsyncRefCounted::new_gd();
+    let mut copy = object.clone();
+
+    let task_handle = task::spawn(async move {
+        let custom = copy.signals().custom_signal().to_future();
+        let done = copy.signals().done().to_future();
+
+        let selected = task::select_signal(custom, done).await;
+        assert_eq!(selected, Selected::Second(()));
+        assert!(copy.get_signal_connection_list("custom_signal").is_empty());
+
+        // Later emissions of the losing signal have no effect.
+        copy.signals().custom_signal().emit(7);
+    });
+
+    object.signals().done().emit();
+
+    task_handle
+}
+
+#[cfg(feature = "codegen-full")]
+mod timeout {
+    use std::time::Duration;
+
+    use godot::builtin::Signal;
+    use godot::obj::NewGd;
+    use godot::task::{self, TaskHandle, TimedOut};
+
+    use super::AsyncRefCounted;
+    use crate::framework::itest;
+
+    #[itest(async)]
+    fn signal_future_timeout_expires() -> TaskHandle {
+        let mut object = AsyncRefCounted::new_gd();
+        let signal = Signal::from_object_signal(&object, "custom_signal");
+
+        task::spawn(async move {
+            let result = signal
+                .to_future::<(u32,)>()
+                .with_timeout(Duration::from_millis(20))
+                .await;
+
+            assert_eq!(result, Err(TimedOut));
+            assert!(object
+                .get_signal_connection_list("custom_signal")
+                .is_empty());
+
+            // Emitting after the timeout must not reach the dropped future.
+            object.signals().custom_signal().emit(3);
+        })
+    }
+
+    #[itest(async)]
+    fn signal_future_timeout_early_emission() -> TaskHandle {
+        let mut object = AsyncRefCounted::new_gd();
+        let signal = Signal::from_object_signal(&object, "custom_signal");
+
+        let task_handle = task::spawn(async move {
+            let result = signal
+                .to_fallible_future::<(u32,)>()
+                .with_timeout(Duration::from_secs(10))
+                .await;
+
+            assert!(matches!(result, Ok(Ok((9,)))), "emitted before timeout");
+        });
+
+        object.signals().custom_signal().emit(9);
+
+        task_handle
+    }
+}
+
+/// Object with a GDScript method emitting `finished` on another object, bypassing the typed Rust API.
+fn gdscript_emitter() -> Gd<RefCounted> {
+    let code = r#"
+extends RefCounted
+
+func emit_finished(object, code, message):
+    object.emit_signal("finished", code, message)
+"#;
+
+    let mut script = GDScript::new_gd();
+    script.set_source_code(code);
+    script.reload();
+
+    let mut emitter = RefCounted::new_gd();
+    emitter.set_script(&script.to_variant());
+    emitter
+}
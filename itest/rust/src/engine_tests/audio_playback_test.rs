@@ -0,0 +1,142 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// mix() is called on the audio thread, so this requires experimental-threads (see also native_st_niche_audio_test.rs).
+#![cfg(all(feature = "codegen-full", feature = "experimental-threads"))]
+
+use std::f32::consts::TAU;
+use std::thread;
+use std::time::Duration;
+
+use godot::classes::native::AudioFrame;
+use godot::classes::{
+    AudioEffectCapture, AudioServer, AudioStream, AudioStreamPlayback, AudioStreamPlayer,
+    IAudioStream, IAudioStreamPlayback,
+};
+use godot::obj::{Base, Gd, NewAlloc, NewGd, WithBaseField};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::StreamPlayback;
+
+use crate::framework::{itest, TestContext};
+
+const MIX_RATE: f32 = 44100.0;
+
+#[derive(GodotClass)]
+#[class(init, base=AudioStream)]
+struct SineStream {
+    #[init(val = 440.0)]
+    frequency: f32,
+    base: Base<AudioStream>,
+}
+
+#[godot_api]
+impl IAudioStream for SineStream {
+    fn instantiate_playback(&self) -> Option<Gd<AudioStreamPlayback>> {
+        SinePlayback::instantiate(self.to_gd())
+    }
+}
+
+#[derive(GodotClass)]
+#[class(no_init, base=AudioStreamPlayback)]
+struct SinePlayback {
+    stream: Gd<SineStream>,
+    phase: f32,
+    playing: bool,
+    base: Base<AudioStreamPlayback>,
+}
+
+// The playback keeps its stream, so that parameters can be changed while playing.
+impl StreamPlayback for SinePlayback {
+    type Stream = SineStream;
+
+    fn new_playback(stream: Gd<SineStream>, base: Base<AudioStreamPlayback>) -> Self {
+        Self {
+            stream,
+            phase: 0.0,
+            playing: false,
+            base,
+        }
+    }
+}
+
+#[godot_api]
+impl IAudioStreamPlayback for SinePlayback {
+    fn start(&mut self, _from_pos: f64) {
+        self.playing = true;
+    }
+
+    fn stop(&mut self) {
+        self.playing = false;
+    }
+
+    fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    // Declared with a slice instead of `*mut AudioFrame` + frame count.
+    fn mix(&mut self, buffer: &mut [AudioFrame], rate_scale: f32) -> i32 {
+        let step = self.stream.bind().frequency * rate_scale / MIX_RATE;
+
+        for frame in buffer.iter_mut() {
+            let sample = (self.phase * TAU).sin() * 0.5;
+            *frame = AudioFrame {
+                left: sample,
+                right: sample,
+            };
+            self.phase = (self.phase + step).fract();
+        }
+
+        buffer.len() as i32
+    }
+}
+
+#[itest]
+fn audio_playback_keeps_stream() {
+    let stream = SineStream::new_gd();
+    let playback = stream
+        .clone()
+        .upcast::<AudioStream>()
+        .instantiate_playback()
+        .expect("stream creates a playback");
+
+    let playback = playback.cast::<SinePlayback>();
+    assert_eq!(playback.bind().stream, stream);
+}
+
+#[itest]
+fn audio_playback_mix_slice(ctx: &TestContext) {
+    let mut server = AudioServer::singleton();
+
+    // Separate bus with a capture effect, to record what the player outputs.
+    let bus_index = server.get_bus_count();
+    server.add_bus();
+    server.set_bus_name(bus_index, "ItestCapture");
+
+    let capture = AudioEffectCapture::new_gd();
+    server.add_bus_effect(bus_index, &capture);
+
+    let mut player = AudioStreamPlayer::new_alloc();
+    player.set_stream(&SineStream::new_gd());
+    player.set_bus("ItestCapture");
+    ctx.scene_tree.clone().add_child(&player);
+    player.play();
+
+    thread::sleep(Duration::from_secs(1));
+
+    let frames = capture.get_frames_available();
+    assert!(frames > 0, "no audio was captured");
+
+    let buffer = capture.get_buffer(frames);
+    let peak = buffer.as_slice().iter().fold(0.0_f32, |peak, frame| {
+        peak.max(frame.x.abs()).max(frame.y.abs())
+    });
+    assert!(peak > 0.1, "captured audio is silent (peak {peak})");
+
+    player.stop();
+    player.free();
+    server.remove_bus(bus_index);
+}
@@ -0,0 +1,78 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use godot::builtin::PackedByteArray;
+use godot::classes::audio_stream_wav::Format;
+use godot::classes::{
+    AudioStreamPlayer, AudioStreamPlayer2D, AudioStreamPlayer3D, AudioStreamWav, Node,
+};
+use godot::obj::{Gd, NewAlloc, NewGd};
+use godot::task::{self, TaskHandle};
+use godot::tools::AudioPlayer;
+
+use crate::framework::{itest, TestContext};
+
+const MIX_RATE: i32 = 44100;
+
+/// Short 8-bit mono stream of silence.
+fn make_stream(duration_sec: f32) -> Gd<AudioStreamWav> {
+    let sample_count = (duration_sec * MIX_RATE as f32) as usize;
+
+    let mut stream = AudioStreamWav::new_gd();
+    stream.set_format(Format::FORMAT_8_BITS);
+    stream.set_mix_rate(MIX_RATE);
+    stream.set_stereo(false);
+    stream.set_data(&PackedByteArray::from(vec![0u8; sample_count]));
+    stream
+}
+
+fn check_playback<P: AudioPlayer>(ctx: &TestContext, mut player: Gd<P>) {
+    ctx.scene_tree.clone().add_child(&player);
+
+    assert!(player.stream().is_some());
+    assert!(!player.is_playing());
+
+    player.play_from(0.5);
+    assert!(player.is_playing());
+
+    player.stop();
+    assert!(!player.is_playing());
+    assert_eq!(player.playback_position(), 0.0);
+
+    player.upcast::<Node>().free();
+}
+
+#[itest]
+fn audio_player_playback(ctx: &TestContext) {
+    let mut player = AudioStreamPlayer::new_alloc();
+    player.set_stream(&make_stream(1.0));
+    check_playback(ctx, player);
+
+    let mut player = AudioStreamPlayer2D::new_alloc();
+    player.set_stream(&make_stream(1.0));
+    check_playback(ctx, player);
+
+    let mut player = AudioStreamPlayer3D::new_alloc();
+    player.set_stream(&make_stream(1.0));
+    check_playback(ctx, player);
+}
+
+#[itest(async)]
+fn audio_player_play_to_end(ctx: &TestContext) -> TaskHandle {
+    let mut player = AudioStreamPlayer::new_alloc();
+    player.set_stream(&make_stream(0.05));
+    ctx.scene_tree.clone().add_child(&player);
+
+    task::spawn(async move {
+        player.play_to_end(0.0).await;
+        assert!(!player.is_playing());
+
+        player.free();
+    })
+}
@@ -0,0 +1,184 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{StringName, VariantType};
+use godot::classes::{ClassDb, Node, RefCounted};
+use godot::global::{PropertyHint, PropertyUsageFlags};
+use godot::obj::{EngineBitfield, Gd};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::{self, ClassMethodInfo};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base = RefCounted)]
+struct ClassDbProbe {
+    #[var]
+    level: i32,
+}
+
+#[godot_api]
+impl ClassDbProbe {
+    #[func]
+    fn scaled(&self, factor: f64) -> f64 {
+        self.level as f64 * factor
+    }
+}
+
+fn find_method<'a>(methods: &'a [ClassMethodInfo], name: &str) -> Option<&'a ClassMethodInfo> {
+    methods
+        .iter()
+        .find(|method| method.name == StringName::from(name))
+}
+
+#[itest]
+fn class_db_exists_and_parent() {
+    let class_db = ClassDb::singleton();
+
+    assert!(class_db.class_exists("Node"));
+    assert!(class_db.class_exists("ClassDbProbe"));
+    assert!(!class_db.class_exists("NoSuchClass"));
+
+    assert!(class_db.is_parent_class("Node", "Object"));
+    assert!(class_db.is_parent_class("ClassDbProbe", "RefCounted"));
+    assert!(!class_db.is_parent_class("Object", "Node"));
+}
+
+#[itest]
+fn class_db_method_infos() {
+    let class_db = ClassDb::singleton();
+    let methods = class_db.class_method_infos("Node", false);
+
+    let add_child = find_method(&methods, "add_child").expect("Node::add_child exists");
+    assert_eq!(add_child.arguments.len(), 3);
+    assert_eq!(add_child.default_arguments.len(), 2);
+    assert_eq!(add_child.return_info.variant_type, VariantType::NIL);
+
+    let node_arg = &add_child.arguments[0];
+    assert_eq!(node_arg.name, StringName::from("node"));
+    assert_eq!(node_arg.variant_type, VariantType::OBJECT);
+    assert_eq!(node_arg.class_name, StringName::from("Node"));
+
+    // Inherited from Object.
+    assert!(find_method(&methods, "get_instance_id").is_some());
+
+    let own_methods = class_db.class_method_infos("Node", true);
+    assert!(find_method(&own_methods, "add_child").is_some());
+    assert!(find_method(&own_methods, "get_instance_id").is_none());
+
+    assert!(class_db.class_method_infos("NoSuchClass", false).is_empty());
+}
+
+#[itest]
+fn class_db_method_infos_user_class() {
+    let methods = ClassDb::singleton().class_method_infos("ClassDbProbe", true);
+
+    let scaled = find_method(&methods, "scaled").expect("#[func] is registered");
+    assert_eq!(scaled.arguments.len(), 1);
+    assert_eq!(scaled.arguments[0].name, StringName::from("factor"));
+    assert_eq!(scaled.arguments[0].variant_type, VariantType::FLOAT);
+    assert_eq!(scaled.return_info.variant_type, VariantType::FLOAT);
+    assert!(scaled.default_arguments.is_empty());
+
+    // Generated accessors of #[var].
+    assert!(find_method(&methods, "get_level").is_some());
+    assert!(find_method(&methods, "set_level").is_some());
+}
+
+#[itest]
+fn class_db_property_infos() {
+    let class_db = ClassDb::singleton();
+    let properties = class_db.class_property_infos("Node", true);
+
+    let process_mode = properties
+        .iter()
+        .find(|property| property.name == StringName::from("process_mode"))
+        .expect("Node.process_mode exists");
+
+    assert_eq!(process_mode.variant_type, VariantType::INT);
+    assert_eq!(process_mode.hint, PropertyHint::ENUM);
+    assert!(!process_mode.hint_string.is_empty());
+    assert!(process_mode.usage.is_set(PropertyUsageFlags::STORAGE));
+
+    let probe_properties = class_db.class_property_infos("ClassDbProbe", true);
+    let level = probe_properties
+        .iter()
+        .find(|property| property.name == StringName::from("level"))
+        .expect("#[var] is registered");
+    assert_eq!(level.variant_type, VariantType::INT);
+
+    assert!(class_db
+        .class_property_infos("NoSuchClass", false)
+        .is_empty());
+}
+
+#[itest]
+fn class_db_instantiate_object() {
+    let class_db = ClassDb::singleton();
+
+    let object = class_db
+        .instantiate_object("RefCounted")
+        .expect("RefCounted can be instantiated");
+    assert!(object.try_cast::<RefCounted>().is_ok());
+
+    let node = class_db
+        .instantiate_object("Node")
+        .expect("Node can be instantiated");
+    let node: Gd<Node> = node.cast();
+    node.free();
+
+    let probe = class_db
+        .instantiate_object("ClassDbProbe")
+        .expect("user class can be instantiated");
+    let probe: Gd<ClassDbProbe> = probe.cast();
+    assert_eq!(probe.bind().scaled(2.0), 0.0);
+
+    assert!(class_db.instantiate_object("NoSuchClass").is_none());
+}
+
+#[itest]
+fn class_db_feature_queries() {
+    assert!(tools::engine_has_class("Node"));
+    assert!(tools::engine_has_class("ClassDbProbe"));
+    assert!(!tools::engine_has_class("NoSuchClass"));
+
+    assert!(tools::class_has_method::<Node>("propagate_call"));
+    assert!(tools::class_has_method::<Node>("get_instance_id")); // Inherited.
+    assert!(!tools::class_has_method::<Node>("no_such_method"));
+    assert!(tools::class_has_method::<ClassDbProbe>("scaled"));
+
+    assert!(tools::class_has_signal::<Node>("ready"));
+    assert!(tools::class_has_signal::<Node>("script_changed")); // Inherited.
+    assert!(!tools::class_has_signal::<Node>("no_such_signal"));
+
+    let methods = tools::list_class_methods("Node");
+    assert!(find_method(&methods, "propagate_call").is_some());
+    assert!(find_method(&methods, "get_instance_id").is_some());
+    assert!(tools::list_class_methods("NoSuchClass").is_empty());
+}
+
+#[cfg(since_api = "4.3")]
+#[itest]
+fn class_db_property_default() {
+    use godot::builtin::Color;
+    use godot::classes::{CanvasItem, Node2D};
+    use godot::meta::ToGodot;
+
+    let modulate = tools::class_property_default::<Node2D>("modulate");
+    assert_eq!(modulate, Some(Color::WHITE.to_variant()));
+
+    // Abstract classes have no instance to read the default from.
+    assert_eq!(
+        tools::class_property_default::<CanvasItem>("modulate"),
+        None
+    );
+
+    assert_eq!(
+        tools::class_property_default::<Node>("no_such_property"),
+        None
+    );
+}
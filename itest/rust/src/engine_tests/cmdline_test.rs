@@ -0,0 +1,109 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::PackedStringArray;
+use godot::classes::Os;
+use godot::init::{cmdline, CmdlineArgs};
+
+use crate::framework::itest;
+
+fn to_strings(array: PackedStringArray) -> Vec<String> {
+    array.as_slice().iter().map(|arg| arg.to_string()).collect()
+}
+
+#[itest]
+fn cmdline_parse_user_args() {
+    let args = CmdlineArgs::from_args(
+        ["--headless", "--path", "project"],
+        [
+            "--mode=export",
+            "--verbose",
+            "out.pck",
+            "-x",
+            "--level=1=2",
+            "--mode=test",
+        ],
+    );
+
+    assert_eq!(args.engine_args(), ["--headless", "--path", "project"]);
+    assert_eq!(args.user_args().len(), 6);
+
+    assert_eq!(args.get("mode"), Some("test")); // Last one wins.
+    assert_eq!(args.get("verbose"), Some(""));
+    assert_eq!(args.get("level"), Some("1=2"));
+    assert_eq!(args.get("missing"), None);
+    assert!(args.has("verbose"));
+    assert!(!args.has("x"));
+    assert_eq!(args.options().len(), 3);
+
+    assert_eq!(args.positional(), ["out.pck", "-x"]);
+}
+
+#[itest]
+fn cmdline_parse_empty() {
+    let args = CmdlineArgs::from_args(Vec::<String>::new(), ["--", "plain"]);
+
+    assert!(args.engine_args().is_empty());
+    assert!(args.options().is_empty());
+    assert_eq!(args.positional(), ["--", "plain"]);
+}
+
+#[itest]
+fn cmdline_matches_os() {
+    let os = Os::singleton();
+    let args = cmdline();
+
+    assert_eq!(args.engine_args(), to_strings(os.get_cmdline_args()));
+    assert_eq!(args.user_args(), to_strings(os.get_cmdline_user_args()));
+}
+
+// The test runner rejects unknown user arguments, so a second Godot process is launched with extra arguments.
+#[cfg(feature = "codegen-full")]
+mod child_process {
+    use godot::classes::{IMainLoop, MainLoop};
+    use godot::global::godot_print;
+    use godot::init::cmdline;
+    use godot::register::{godot_api, GodotClass};
+
+    use crate::framework::{itest, run_headless_main_loop};
+
+    /// Prints the parsed user arguments and quits immediately.
+    #[derive(GodotClass)]
+    #[class(init, base = MainLoop)]
+    struct CmdlineMainLoop;
+
+    #[godot_api]
+    impl IMainLoop for CmdlineMainLoop {
+        fn process(&mut self, _delta: f32) -> bool {
+            let args = cmdline();
+            godot_print!(
+                "[CmdlineMainLoop] mode={:?} verbose={} positional={:?}",
+                args.get("mode"),
+                args.has("verbose"),
+                args.positional(),
+            );
+            true
+        }
+    }
+
+    #[itest]
+    fn cmdline_user_args_in_child_process() {
+        let (exit_code, output) = run_headless_main_loop(
+            "CmdlineMainLoop",
+            &["--mode=export", "--verbose", "out.pck"],
+        );
+
+        assert_eq!(exit_code, 0, "child process failed:\n{output}");
+
+        let expected =
+            r#"[CmdlineMainLoop] mode=Some("export") verbose=true positional=["out.pck"]"#;
+        assert!(
+            output.contains(expected),
+            "unexpected parsed arguments:\n{output}"
+        );
+    }
+}
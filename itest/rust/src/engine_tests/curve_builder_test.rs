@@ -0,0 +1,169 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::{Color, Vector2};
+use godot::classes::gradient::InterpolationMode;
+use godot::tools::{CurveBuilder, GradientBuilder};
+
+use crate::framework::{expect_panic, itest};
+
+#[itest]
+fn curve_builder_points() {
+    let curve = CurveBuilder::new()
+        .point(Vector2::new(0.0, 0.0))
+        .point(Vector2::new(0.5, 1.0))
+        .point(Vector2::new(1.0, 0.5))
+        .build();
+
+    assert_eq!(curve.get_point_count(), 3);
+    assert_eq!(curve.get_point_position(1), Vector2::new(0.5, 1.0));
+
+    assert_eq_approx!(curve.sample(0.0), 0.0);
+    assert_eq_approx!(curve.sample(0.5), 1.0);
+    assert_eq_approx!(curve.sample(1.0), 0.5);
+}
+
+#[itest]
+fn curve_builder_value_range() {
+    // Range entirely above Godot's default 0..=1, so the bounds must be set in the right order.
+    let curve = CurveBuilder::new()
+        .value_range(2.0, 5.0)
+        .point(Vector2::new(0.0, 2.0))
+        .point(Vector2::new(1.0, 5.0))
+        .build();
+
+    assert_eq!(curve.get_min_value(), 2.0);
+    assert_eq!(curve.get_max_value(), 5.0);
+
+    // Values are not clamped to 0..=1.
+    assert_eq_approx!(curve.sample(1.0), 5.0);
+
+    let negative = CurveBuilder::new().value_range(-5.0, -2.0).build();
+    assert_eq!(negative.get_min_value(), -5.0);
+    assert_eq!(negative.get_max_value(), -2.0);
+}
+
+#[cfg(since_api = "4.4")]
+#[itest]
+fn curve_builder_domain() {
+    // Offsets outside the default 0..=1 are kept once the domain covers them.
+    let curve = CurveBuilder::new()
+        .domain(2.0, 4.0)
+        .point(Vector2::new(2.0, 0.0))
+        .point(Vector2::new(4.0, 1.0))
+        .build();
+
+    assert_eq!(curve.get_min_domain(), 2.0);
+    assert_eq!(curve.get_max_domain(), 4.0);
+    assert_eq!(curve.get_point_position(1), Vector2::new(4.0, 1.0));
+    assert_eq_approx!(curve.sample(3.0), 0.5);
+}
+
+#[itest]
+fn curve_builder_tangents_and_bake() {
+    let curve = CurveBuilder::new()
+        .point_with_tangents(Vector2::new(0.0, 0.0), 0.0, 1.0)
+        .point_with_tangents(Vector2::new(1.0, 1.0), 1.0, 0.0)
+        .bake_resolution(50)
+        .build();
+
+    assert_eq!(curve.get_point_right_tangent(0), 1.0);
+    assert_eq!(curve.get_point_left_tangent(1), 1.0);
+    assert_eq!(curve.get_bake_resolution(), 50);
+
+    // Tangents matching the slope of the line yield a straight line.
+    assert_eq_approx!(curve.sample(0.25), 0.25);
+    assert_eq_approx!(curve.sample_baked(0.25), 0.25);
+}
+
+#[itest]
+fn curve_sample_out_of_range() {
+    let curve = CurveBuilder::new()
+        .point(Vector2::new(0.25, 0.2))
+        .point(Vector2::new(0.75, 0.8))
+        .build();
+
+    // Before the first and after the last point, the values of those points are returned.
+    assert_eq_approx!(curve.sample(-1.0), 0.2);
+    assert_eq_approx!(curve.sample(0.0), 0.2);
+    assert_eq_approx!(curve.sample(1.0), 0.8);
+    assert_eq_approx!(curve.sample(3.0), 0.8);
+    assert_eq_approx!(curve.sample_baked(-1.0), 0.2);
+    assert_eq_approx!(curve.sample_baked(3.0), 0.8);
+}
+
+#[itest]
+fn curve_builder_empty_range_panics() {
+    expect_panic("empty value range", || {
+        let _ = CurveBuilder::new().value_range(1.0, 1.0);
+    });
+}
+
+#[cfg(since_api = "4.4")]
+#[itest]
+fn curve_builder_empty_domain_panics() {
+    expect_panic("empty domain", || {
+        let _ = CurveBuilder::new().domain(1.0, 0.0);
+    });
+}
+
+#[itest]
+fn gradient_builder_stops() {
+    // Added out of order.
+    let gradient = GradientBuilder::new()
+        .stop(1.0, Color::RED)
+        .stop(0.0, Color::BLUE)
+        .build();
+
+    // Only the given stops, without Godot's default black and white.
+    assert_eq!(gradient.get_point_count(), 2);
+    assert_eq!(gradient.get_offset(0), 0.0);
+    assert_eq!(gradient.get_color(0), Color::BLUE);
+
+    assert_eq_approx!(gradient.sample(0.0), Color::BLUE);
+    assert_eq_approx!(gradient.sample(0.5), Color::from_rgb(0.5, 0.0, 0.5));
+    assert_eq_approx!(gradient.sample(1.0), Color::RED);
+}
+
+#[itest]
+fn gradient_sample_out_of_range() {
+    let gradient = GradientBuilder::new()
+        .stop(0.2, Color::BLUE)
+        .stop(0.8, Color::RED)
+        .build();
+
+    assert_eq_approx!(gradient.sample(-1.0), Color::BLUE);
+    assert_eq_approx!(gradient.sample(0.1), Color::BLUE);
+    assert_eq_approx!(gradient.sample(0.9), Color::RED);
+    assert_eq_approx!(gradient.sample(2.0), Color::RED);
+}
+
+#[itest]
+fn gradient_builder_interpolation() {
+    let gradient = GradientBuilder::new()
+        .stop(0.0, Color::BLUE)
+        .stop(0.5, Color::RED)
+        .interpolation(InterpolationMode::CONSTANT)
+        .build();
+
+    assert_eq!(
+        gradient.get_interpolation_mode(),
+        InterpolationMode::CONSTANT
+    );
+    assert_eq_approx!(gradient.sample(0.49), Color::BLUE);
+    assert_eq_approx!(gradient.sample(0.5), Color::RED);
+}
+
+#[itest]
+fn gradient_builder_empty_panics() {
+    expect_panic("gradient without stops", || {
+        GradientBuilder::new().build();
+    });
+}
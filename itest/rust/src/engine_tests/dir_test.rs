@@ -0,0 +1,100 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::file_access::ModeFlags;
+use godot::tools::{
+    copy_file, dir_exists, list_dir, make_dir_recursive, read_dir, remove_path, GFile,
+};
+
+use crate::framework::itest;
+
+const TEST_DIR: &str = "user://itest_dir";
+
+#[itest]
+fn dir_create_list_remove() {
+    make_dir_recursive(&format!("{TEST_DIR}/sub/deeper")).unwrap();
+    assert!(dir_exists(TEST_DIR));
+    assert!(dir_exists(&format!("{TEST_DIR}/sub/deeper")));
+
+    // Creating an existing directory succeeds.
+    make_dir_recursive(TEST_DIR).unwrap();
+
+    write_file(&format!("{TEST_DIR}/a.txt"));
+    write_file(&format!("{TEST_DIR}/.hidden"));
+    copy_file(
+        &format!("{TEST_DIR}/a.txt"),
+        &format!("{TEST_DIR}/sub/b.txt"),
+    )
+    .unwrap();
+
+    assert_eq!(sorted_names(TEST_DIR, false), ["a.txt", "sub"]);
+    assert_eq!(sorted_names(TEST_DIR, true), [".hidden", "a.txt", "sub"]);
+    assert_eq!(
+        sorted_names(&format!("{TEST_DIR}/sub"), false),
+        ["b.txt", "deeper"]
+    );
+
+    let listed: Vec<String> = list_dir(TEST_DIR)
+        .unwrap()
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+    assert_eq!(listed.len(), 2);
+    assert!(listed.contains(&"a.txt".to_string()));
+
+    let entry = read_dir(TEST_DIR, false)
+        .unwrap()
+        .find(|entry| entry.name() == "sub")
+        .expect("subdirectory is listed");
+    assert!(entry.is_dir());
+    assert!(!entry.is_file());
+    assert_eq!(entry.path(), "user://itest_dir/sub");
+
+    // Non-empty directories are not removed.
+    remove_path(&format!("{TEST_DIR}/sub")).expect_err("directory is not empty");
+
+    for path in ["sub/deeper", "sub/b.txt", "sub", "a.txt", ".hidden"] {
+        remove_path(&format!("{TEST_DIR}/{path}")).unwrap();
+    }
+    remove_path(TEST_DIR).unwrap();
+    assert!(!dir_exists(TEST_DIR));
+}
+
+#[itest]
+fn dir_errors() {
+    let missing = "user://itest_dir_missing";
+    assert!(!dir_exists(missing));
+
+    let err = list_dir(missing).expect_err("directory does not exist");
+    assert!(err.to_string().contains(missing), "{err}");
+
+    assert!(read_dir(missing, true).is_err());
+
+    let err = copy_file(&format!("{missing}/a.txt"), &format!("{missing}/b.txt"))
+        .expect_err("source file does not exist");
+    assert!(
+        err.to_string()
+            .contains(&format!("'{missing}/a.txt' to '{missing}/b.txt'")),
+        "{err}"
+    );
+
+    assert!(remove_path(missing).is_err());
+}
+
+fn write_file(path: &str) {
+    let mut file = GFile::open(path, ModeFlags::WRITE).unwrap();
+    file.write_gstring_line("dir test").unwrap();
+}
+
+fn sorted_names(path: &str, include_hidden: bool) -> Vec<String> {
+    let mut names: Vec<String> = read_dir(path, include_hidden)
+        .unwrap()
+        .map(|entry| entry.name().to_string())
+        .collect();
+    names.sort();
+    names
+}
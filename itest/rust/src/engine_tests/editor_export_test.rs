@@ -0,0 +1,203 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+// Integration tests don't run in the editor, so most tests invoke the plugin's virtuals directly, in the order an export would call them.
+// Before the tests, check.sh and CI additionally export the itest project with `--export-pack`, where ExportTestPlugin registers the
+// exporter; export_pack_contains_manifest() then checks the result.
+
+use godot::builtin::{GString, PackedByteArray, PackedStringArray};
+use godot::classes::{
+    EditorExportPlugin, EditorPlugin, FileAccess, IEditorExportPlugin, IEditorPlugin, Node,
+    ProjectSettings, Resource,
+};
+use godot::obj::{Base, Gd, NewGd, WithBaseField};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::ExportFeatures;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(tool, init, base=EditorExportPlugin)]
+struct StripDebugExport {
+    features: ExportFeatures,
+    is_debug: bool,
+    skipped: Vec<GString>,
+    finished: bool,
+    base: Base<EditorExportPlugin>,
+}
+
+#[godot_api]
+impl IEditorExportPlugin for StripDebugExport {
+    fn export_begin(
+        &mut self,
+        features: PackedStringArray,
+        is_debug: bool,
+        _path: GString,
+        _flags: u32,
+    ) {
+        self.features = ExportFeatures::from(features);
+        self.is_debug = is_debug;
+
+        let manifest = PackedByteArray::from(EXPORT_MANIFEST.as_bytes());
+        self.base_mut()
+            .add_file(EXPORT_MANIFEST_PATH, &manifest, false);
+    }
+
+    fn export_file(&mut self, path: GString, _type: GString, _features: PackedStringArray) {
+        if !self.features.has("debug") && path.to_string().ends_with(".debug.json") {
+            self.skipped.push(path);
+            self.base_mut().skip();
+        }
+    }
+
+    fn export_end(&mut self) {
+        self.finished = true;
+    }
+
+    fn customize_resource(
+        &mut self,
+        _resource: Gd<Resource>,
+        _path: GString,
+    ) -> Option<Gd<Resource>> {
+        None
+    }
+
+    fn customize_scene(&mut self, _scene: Gd<Node>, _path: GString) -> Option<Gd<Node>> {
+        None
+    }
+
+    fn get_customization_configuration_hash(&self) -> u64 {
+        0
+    }
+
+    fn get_name(&self) -> GString {
+        "StripDebugExport".into()
+    }
+}
+
+/// Registers the exporter in the editor, which runs for `--export-pack`.
+#[derive(GodotClass)]
+#[class(tool, init, base=EditorPlugin)]
+struct ExportTestPlugin {
+    exporter: Option<Gd<StripDebugExport>>,
+    base: Base<EditorPlugin>,
+}
+
+#[godot_api]
+impl IEditorPlugin for ExportTestPlugin {
+    fn enter_tree(&mut self) {
+        let exporter = StripDebugExport::new_gd();
+        self.base_mut().add_export_plugin(&exporter);
+        self.exporter = Some(exporter);
+    }
+
+    fn exit_tree(&mut self) {
+        if let Some(exporter) = self.exporter.take() {
+            self.base_mut().remove_export_plugin(&exporter);
+        }
+    }
+}
+
+/// Output of the export run, relative to the itest project; keep in sync with check.sh and the CI action.
+const EXPORT_PACK_PATH: &str = "res://export/itest.pck";
+const EXPORT_MANIFEST_PATH: &str = "res://export_manifest.txt";
+const EXPORT_MANIFEST: &str = "exported by StripDebugExport";
+
+fn features(tags: &[&str]) -> ExportFeatures {
+    let tags: Vec<GString> = tags.iter().map(|&tag| tag.into()).collect();
+    ExportFeatures::from(PackedStringArray::from(tags.as_slice()))
+}
+
+#[itest]
+fn export_features_queries() {
+    let features = features(&["mobile", "android", "release"]);
+
+    assert!(features.has("mobile"));
+    assert!(!features.has("web"));
+    assert!(!features.has("mob"));
+    assert!(features.has_any(&["web", "android"]));
+    assert!(!features.has_any(&["web", "windows"]));
+    assert!(!features.has_any(&[]));
+
+    assert_eq!(features.len(), 3);
+    assert!(!features.is_empty());
+    assert_eq!(
+        features.to_vec(),
+        vec![
+            GString::from("mobile"),
+            GString::from("android"),
+            GString::from("release")
+        ]
+    );
+    assert_eq!(features.iter().count(), 3);
+    assert_eq!(features.into_inner().len(), 3);
+
+    let empty = ExportFeatures::default();
+    assert!(empty.is_empty());
+    assert!(!empty.has(""));
+}
+
+#[itest]
+fn export_plugin_skips_files() {
+    let mut exporter = StripDebugExport::new_gd();
+    let release = features(&["release", "pc"]).into_inner();
+
+    {
+        let mut exporter = exporter.bind_mut();
+        exporter.export_begin(release.clone(), false, "build/game.pck".into(), 0);
+        assert!(exporter.features.has("pc"));
+        assert!(!exporter.is_debug);
+
+        let res_type = GString::from("Resource");
+        exporter.export_file("res://data.json".into(), res_type.clone(), release.clone());
+        exporter.export_file("res://data.debug.json".into(), res_type, release);
+        exporter.export_end();
+    }
+
+    let exporter = exporter.bind();
+    assert_eq!(
+        exporter.skipped,
+        vec![GString::from("res://data.debug.json")]
+    );
+    assert!(exporter.finished);
+}
+
+#[itest]
+fn export_plugin_keeps_debug_files() {
+    let mut exporter = StripDebugExport::new_gd();
+    let debug = features(&["debug", "pc"]).into_inner();
+
+    let mut guard = exporter.bind_mut();
+    guard.export_begin(debug.clone(), true, "build/game.pck".into(), 0);
+    guard.export_file("res://data.debug.json".into(), "Resource".into(), debug);
+
+    assert!(guard.is_debug);
+    assert!(guard.skipped.is_empty());
+}
+
+#[itest]
+fn export_pack_contains_manifest() {
+    // Only check.sh and CI export the project; don't fail when Godot is run on the itest project directly.
+    if !FileAccess::file_exists(EXPORT_PACK_PATH) {
+        println!("  {EXPORT_PACK_PATH} missing, skip check; export the itest project first (see `./check.sh itest`).");
+        return;
+    }
+
+    // The manifest only exists inside the pack, as it is added by the exporter.
+    assert!(!FileAccess::file_exists(EXPORT_MANIFEST_PATH));
+    // Don't replace the running project's files with their exported copies.
+    let loaded = ProjectSettings::singleton()
+        .load_resource_pack_ex(EXPORT_PACK_PATH)
+        .replace_files(false)
+        .done();
+    assert!(loaded);
+
+    let manifest = FileAccess::get_file_as_string(EXPORT_MANIFEST_PATH);
+    assert_eq!(manifest, GString::from(EXPORT_MANIFEST));
+}
@@ -0,0 +1,183 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+// The editor is not running during integration tests, so the importer's virtuals are invoked directly, the way the editor's import
+// pipeline would call them.
+
+use godot::builtin::{Array, Dictionary, GString, PackedStringArray};
+use godot::classes::file_access::ModeFlags;
+use godot::classes::{EditorImportPlugin, IEditorImportPlugin, Resource};
+use godot::global::{Error, PropertyHint, PropertyUsageFlags};
+use godot::meta::ToGodot;
+use godot::obj::{Base, EngineBitfield, EngineEnum, NewGd};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::{try_load, try_save, GFile, ImportOption};
+
+use crate::framework::itest;
+
+const SOURCE_FILE: &str = "import_test.toy";
+const IMPORTED_FILE: &str = "import_test.toy.imported.tres";
+
+#[derive(GodotClass)]
+#[class(tool, init, base=EditorImportPlugin)]
+struct ToyImporter {
+    base: Base<EditorImportPlugin>,
+}
+
+#[godot_api]
+impl IEditorImportPlugin for ToyImporter {
+    fn get_importer_name(&self) -> GString {
+        "itest.toy".into()
+    }
+
+    fn get_visible_name(&self) -> GString {
+        "Toy".into()
+    }
+
+    fn get_recognized_extensions(&self) -> PackedStringArray {
+        PackedStringArray::from(&[GString::from("toy")])
+    }
+
+    fn get_save_extension(&self) -> GString {
+        "tres".into()
+    }
+
+    fn get_resource_type(&self) -> GString {
+        "Resource".into()
+    }
+
+    fn get_preset_count(&self) -> i32 {
+        1
+    }
+
+    fn get_preset_name(&self, _preset_index: i32) -> GString {
+        "Default".into()
+    }
+
+    fn get_import_options(&self, _path: GString, _preset_index: i32) -> Array<Dictionary> {
+        ImportOption::to_array([
+            ImportOption::new("name_prefix", "toy_"),
+            ImportOption::new("scale", 1.0)
+                .with_hint(PropertyHint::RANGE, "0.1,10.0,0.1")
+                .with_usage(PropertyUsageFlags::EDITOR),
+        ])
+    }
+
+    fn import(
+        &self,
+        source_file: GString,
+        save_path: GString,
+        options: Dictionary,
+        _platform_variants: Array<GString>,
+        _gen_files: Array<GString>,
+    ) -> Error {
+        let Ok(mut file) = GFile::open(&source_file, ModeFlags::READ) else {
+            return Error::ERR_FILE_CANT_OPEN;
+        };
+        let Ok(content) = file.read_gstring_line() else {
+            return Error::ERR_FILE_CORRUPT;
+        };
+
+        let prefix = options
+            .get("name_prefix")
+            .map(|v| v.to::<GString>())
+            .unwrap_or_default();
+
+        let mut resource = Resource::new_gd();
+        resource.set_name(&format!("{prefix}{content}"));
+
+        let output = format!("{save_path}.{}", self.get_save_extension());
+        match try_save(&resource, &output) {
+            Ok(()) => Error::OK,
+            Err(_) => Error::ERR_FILE_CANT_WRITE,
+        }
+    }
+}
+
+#[itest]
+fn editor_import_options_format() {
+    let importer = ToyImporter::new_gd();
+    let options = importer.bind().get_import_options(GString::new(), 0);
+    assert_eq!(options.len(), 2);
+
+    let prefix = options.at(0);
+    assert_eq!(prefix.at("name"), "name_prefix".to_variant());
+    assert_eq!(prefix.at("default_value"), "toy_".to_variant());
+    assert_eq!(
+        prefix.at("property_hint"),
+        PropertyHint::NONE.ord().to_variant()
+    );
+    assert_eq!(prefix.at("hint_string"), "".to_variant());
+    assert_eq!(
+        prefix.at("usage"),
+        PropertyUsageFlags::DEFAULT.ord().to_variant()
+    );
+
+    let scale = options.at(1);
+    assert_eq!(scale.at("default_value"), 1.0.to_variant());
+    assert_eq!(
+        scale.at("property_hint"),
+        PropertyHint::RANGE.ord().to_variant()
+    );
+    assert_eq!(scale.at("hint_string"), "0.1,10.0,0.1".to_variant());
+    assert_eq!(
+        scale.at("usage"),
+        PropertyUsageFlags::EDITOR.ord().to_variant()
+    );
+}
+
+#[itest]
+fn editor_import_file_end_to_end() {
+    let source_path = format!("res://{SOURCE_FILE}");
+    let mut file = GFile::open(&source_path, ModeFlags::WRITE).unwrap();
+    file.write_gstring_line("crate").unwrap();
+    drop(file);
+
+    let importer = ToyImporter::new_gd();
+    let mut options = Dictionary::new();
+    for option in importer
+        .bind()
+        .get_import_options(GString::new(), 0)
+        .iter_shared()
+    {
+        options.set(option.at("name"), option.at("default_value"));
+    }
+
+    let save_path = format!("res://{SOURCE_FILE}.imported");
+    let err = importer.bind().import(
+        source_path.into(),
+        save_path.into(),
+        options,
+        Array::new(),
+        Array::new(),
+    );
+    assert_eq!(err, Error::OK);
+
+    let imported = try_load::<Resource>(&format!("res://{IMPORTED_FILE}")).expect("imported file");
+    assert_eq!(imported.get_name(), "toy_crate");
+
+    let err = importer.bind().import(
+        "res://no_such_file.toy".into(),
+        GString::new(),
+        Dictionary::new(),
+        Array::new(),
+        Array::new(),
+    );
+    assert_eq!(err, Error::ERR_FILE_CANT_OPEN);
+
+    remove_test_file(SOURCE_FILE);
+    remove_test_file(IMPORTED_FILE);
+}
+
+fn remove_test_file(file_name: &str) {
+    let godot_path = std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/"));
+    let file_path = godot_path.join(file_name);
+    std::fs::remove_file(&file_path)
+        .unwrap_or_else(|_| panic!("couldn't remove test file: {}", file_path.display()));
+}
@@ -0,0 +1,146 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+// The editor itself is not running during integration tests, so the inspector plugin's virtuals are invoked directly, while the
+// `EditorProperty` virtuals are dispatched by the engine.
+
+use godot::builtin::{GString, VariantType};
+use godot::classes::{
+    EditorInspectorPlugin, EditorProperty, IEditorInspectorPlugin, IEditorProperty, Object,
+    RefCounted, Resource,
+};
+use godot::global::{PropertyHint, PropertyUsageFlags};
+use godot::obj::{Base, Gd, NewAlloc, NewGd, WithBaseField};
+use godot::register::{godot_api, GodotClass};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(tool, init, base=Resource)]
+struct InspectedItem {
+    #[export]
+    rarity: i32,
+}
+
+#[derive(GodotClass)]
+#[class(tool, init, base=EditorProperty)]
+struct RarityEditorProperty {
+    update_count: u32,
+    read_only: bool,
+    base: Base<EditorProperty>,
+}
+
+#[godot_api]
+impl IEditorProperty for RarityEditorProperty {
+    fn update_property(&mut self) {
+        self.update_count += 1;
+    }
+
+    fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+}
+
+#[derive(GodotClass)]
+#[class(tool, init, base=EditorInspectorPlugin)]
+struct ItemInspectorPlugin {
+    began: bool,
+    editors: Vec<Gd<RarityEditorProperty>>,
+    base: Base<EditorInspectorPlugin>,
+}
+
+#[godot_api]
+impl IEditorInspectorPlugin for ItemInspectorPlugin {
+    fn can_handle(&self, object: Gd<Object>) -> bool {
+        object.try_cast::<InspectedItem>().is_ok()
+    }
+
+    fn parse_begin(&mut self, _object: Gd<Object>) {
+        self.began = true;
+    }
+
+    fn parse_property(
+        &mut self,
+        _object: Gd<Object>,
+        type_: VariantType,
+        name: GString,
+        _hint_type: PropertyHint,
+        _hint_string: GString,
+        _usage_flags: PropertyUsageFlags,
+        _wide: bool,
+    ) -> bool {
+        if type_ != VariantType::INT || name != "rarity" {
+            return false;
+        }
+
+        let editor = RarityEditorProperty::new_alloc();
+        self.base_mut().add_property_editor(&name, &editor);
+        self.editors.push(editor);
+        true
+    }
+}
+
+#[itest]
+fn editor_inspector_plugin_handles_rust_resource() {
+    let mut plugin = ItemInspectorPlugin::new_gd();
+    let item = InspectedItem::new_gd();
+
+    assert!(plugin.bind().can_handle(item.clone().upcast()));
+    assert!(!plugin.bind().can_handle(RefCounted::new_gd().upcast()));
+
+    let mut guard = plugin.bind_mut();
+    guard.parse_begin(item.clone().upcast());
+    assert!(guard.began);
+
+    let handled = guard.parse_property(
+        item.clone().upcast(),
+        VariantType::INT,
+        "rarity".into(),
+        PropertyHint::NONE,
+        GString::new(),
+        PropertyUsageFlags::DEFAULT,
+        false,
+    );
+    assert!(handled);
+
+    let unhandled = guard.parse_property(
+        item.upcast(),
+        VariantType::STRING,
+        "resource_name".into(),
+        PropertyHint::NONE,
+        GString::new(),
+        PropertyUsageFlags::DEFAULT,
+        false,
+    );
+    assert!(!unhandled);
+
+    let editors = std::mem::take(&mut guard.editors);
+    drop(guard);
+
+    assert_eq!(editors.len(), 1);
+    for editor in editors {
+        editor.free();
+    }
+}
+
+#[itest]
+fn editor_property_virtuals_dispatched() {
+    let mut editor = RarityEditorProperty::new_alloc();
+
+    editor.upcast_mut::<EditorProperty>().update_property();
+    editor.upcast_mut::<EditorProperty>().set_read_only(true);
+
+    {
+        let guard = editor.bind();
+        assert_eq!(guard.update_count, 1);
+        assert!(guard.read_only);
+    }
+
+    editor.free();
+}
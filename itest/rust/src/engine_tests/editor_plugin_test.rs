@@ -0,0 +1,82 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use godot::classes::editor_plugin::DockSlot;
+use godot::classes::{EditorInterface, EditorPlugin, Engine, IEditorPlugin, Label};
+use godot::obj::{Base, Gd, NewAlloc, WithBaseField};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::EditorAdditions;
+
+use crate::framework::{itest, TestContext};
+
+#[derive(GodotClass)]
+#[class(tool, init, base=EditorPlugin)]
+struct DockingEditorPlugin {
+    additions: EditorAdditions,
+    dock: Option<Gd<Label>>,
+    base: Base<EditorPlugin>,
+}
+
+#[godot_api]
+impl IEditorPlugin for DockingEditorPlugin {
+    fn enter_tree(&mut self) {
+        let plugin = self.to_gd();
+        let dock = Label::new_alloc();
+
+        self.additions.add_dock(&plugin, DockSlot::RIGHT_UL, &dock);
+        self.dock = Some(dock);
+    }
+}
+
+#[itest]
+fn editor_additions_removed_on_exit_tree(ctx: &TestContext) {
+    let plugin = DockingEditorPlugin::new_alloc();
+    let mut tree = ctx.scene_tree.clone();
+
+    tree.add_child(&plugin);
+    let dock = plugin
+        .bind()
+        .dock
+        .clone()
+        .expect("dock added in enter_tree");
+    assert_eq!(plugin.bind().additions.len(), 1);
+    assert!(!dock.is_queued_for_deletion());
+
+    tree.remove_child(&plugin);
+    assert!(plugin.bind().additions.is_empty());
+    assert!(dock.is_queued_for_deletion(), "dock is freed on removal");
+
+    plugin.free();
+}
+
+#[itest]
+fn editor_additions_clear_manually() {
+    let mut plugin = DockingEditorPlugin::new_alloc();
+    let dock = Label::new_alloc();
+
+    {
+        let gd = plugin.clone();
+        let mut guard = plugin.bind_mut();
+        guard.additions.add_dock(&gd, DockSlot::LEFT_BR, &dock);
+        assert_eq!(guard.additions.len(), 1);
+
+        guard.additions.clear();
+        assert!(guard.additions.is_empty());
+    }
+
+    assert!(dock.is_queued_for_deletion());
+    plugin.free();
+}
+
+#[itest]
+fn editor_interface_outside_editor() {
+    // Integration tests run headless, without editor.
+    assert!(!Engine::singleton().is_editor_hint());
+    assert!(EditorInterface::try_singleton().is_none());
+}
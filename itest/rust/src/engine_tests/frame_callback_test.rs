@@ -0,0 +1,67 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use godot::builtin::Signal;
+use godot::classes::{Engine, SceneTree};
+use godot::task::{self, TaskHandle};
+use godot::tools::{register_frame_callback, FrameKind};
+
+use crate::framework::itest;
+
+#[itest(async)]
+fn frame_callback_runs_until_dropped() -> TaskHandle {
+    let frames = Rc::new(Cell::new(0));
+    let last_delta = Rc::new(Cell::new(-1.0));
+
+    let handle = register_frame_callback(FrameKind::Process, {
+        let frames = frames.clone();
+        let last_delta = last_delta.clone();
+        move |delta| {
+            frames.set(frames.get() + 1);
+            last_delta.set(delta);
+        }
+    });
+    assert_eq!(handle.kind(), FrameKind::Process);
+
+    let tree = Engine::singleton()
+        .get_main_loop()
+        .unwrap()
+        .cast::<SceneTree>();
+    let process_frame = Signal::from_object_signal(&tree, "process_frame");
+
+    task::spawn(async move {
+        process_frame.to_future::<()>().await;
+        process_frame.to_future::<()>().await;
+
+        assert!(frames.get() >= 1, "callback should run every frame");
+        assert!(last_delta.get() >= 0.0);
+
+        drop(handle);
+        let frames_after_drop = frames.get();
+
+        process_frame.to_future::<()>().await;
+        process_frame.to_future::<()>().await;
+
+        assert_eq!(
+            frames.get(),
+            frames_after_drop,
+            "callback should not run after handle is dropped"
+        );
+    })
+}
+
+#[itest]
+fn frame_callback_physics_unregisters() {
+    let handle = register_frame_callback(FrameKind::Physics, |_delta| {});
+    assert_eq!(handle.kind(), FrameKind::Physics);
+
+    // Only checks that disconnecting works; whether physics frames run within a test is not deterministic.
+    drop(handle);
+}
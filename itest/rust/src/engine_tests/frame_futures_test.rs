@@ -0,0 +1,289 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use godot::classes::{Engine, Node};
+use godot::obj::NewAlloc;
+use godot::task::{self, AwaitFramesError, TaskHandle};
+
+use crate::framework::{itest, TestContext};
+
+#[itest(async)]
+fn await_next_frame() -> TaskHandle {
+    task::spawn(async {
+        let before = Engine::singleton().get_process_frames();
+        task::next_frame().await.unwrap();
+        let after = Engine::singleton().get_process_frames();
+
+        assert!(after > before, "{after} > {before}");
+    })
+}
+
+#[itest(async)]
+fn await_next_physics_frame() -> TaskHandle {
+    task::spawn(async {
+        let before = Engine::singleton().get_physics_frames();
+        task::next_physics_frame().await.unwrap();
+        let after = Engine::singleton().get_physics_frames();
+
+        assert!(after > before, "{after} > {before}");
+    })
+}
+
+#[itest(async)]
+fn await_until_condition(ctx: &TestContext) -> TaskHandle {
+    let parent = Node::new_alloc();
+    ctx.scene_tree.clone().add_child(&parent);
+
+    let polls = Rc::new(Cell::new(0));
+
+    // Child is added a few frames later by another task.
+    let mut adder = parent.clone();
+    task::spawn(async move {
+        for _ in 0..3 {
+            task::next_frame().await.unwrap();
+        }
+        adder.add_child(&Node::new_alloc());
+    });
+
+    let observed = parent.clone();
+    let counter = polls.clone();
+    task::spawn(async move {
+        task::until(move || {
+            counter.set(counter.get() + 1);
+            observed.get_child_count() > 0
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(parent.get_child_count(), 1);
+        assert!(polls.get() > 1, "condition polled once per frame");
+
+        parent.free();
+    })
+}
+
+#[itest(async)]
+fn await_until_immediate() -> TaskHandle {
+    task::spawn(async {
+        let before = Engine::singleton().get_process_frames();
+        task::until(|| true).await.unwrap();
+
+        // Does not wait for a frame if the condition already holds.
+        assert_eq!(Engine::singleton().get_process_frames(), before);
+    })
+}
+
+#[itest(async)]
+fn scene_tree_await_frames(ctx: &TestContext) -> TaskHandle {
+    let tree = ctx.scene_tree.get_tree().unwrap();
+
+    task::spawn(async move {
+        let before = Engine::singleton().get_process_frames();
+        tree.await_frames(3).await.unwrap();
+        assert!(Engine::singleton().get_process_frames() >= before + 3);
+
+        let before = Engine::singleton().get_process_frames();
+        tree.await_idle_frame().await.unwrap();
+        assert!(Engine::singleton().get_process_frames() > before);
+
+        let before = Engine::singleton().get_physics_frames();
+        tree.await_physics_frames(2).await.unwrap();
+        assert!(Engine::singleton().get_physics_frames() >= before + 2);
+
+        tree.await_physics_frame().await.unwrap();
+        tree.await_frames(0).await.unwrap();
+    })
+}
+
+#[itest(async)]
+fn node_await_frames(ctx: &TestContext) -> TaskHandle {
+    let node = Node::new_alloc();
+    ctx.scene_tree.clone().add_child(&node);
+
+    task::spawn(async move {
+        let before = Engine::singleton().get_process_frames();
+        node.await_frames(2).await.unwrap();
+        assert!(Engine::singleton().get_process_frames() >= before + 2);
+
+        node.await_physics_frames(1).await.unwrap();
+        node.free();
+    })
+}
+
+#[itest(async)]
+fn node_await_frames_outside_tree() -> TaskHandle {
+    let node = Node::new_alloc();
+
+    task::spawn(async move {
+        let result = node.await_frames(1).await;
+        assert_eq!(result, Err(AwaitFramesError::NotInTree));
+
+        node.free();
+    })
+}
+
+#[itest(async)]
+fn node_await_frames_leaves_tree(ctx: &TestContext) -> TaskHandle {
+    let mut parent = ctx.scene_tree.clone();
+    let node = Node::new_alloc();
+    parent.add_child(&node);
+
+    // Removed after one frame, while the other task is still waiting.
+    let removed = node.clone();
+    task::spawn(async move {
+        task::next_frame().await.unwrap();
+        parent.remove_child(&removed);
+    });
+
+    task::spawn(async move {
+        let result = node.await_frames(10).await;
+        assert_eq!(result, Err(AwaitFramesError::NotInTree));
+
+        node.free();
+    })
+}
+
+#[itest(async)]
+fn node_await_frames_leaves_tree_during_last_frame(ctx: &TestContext) -> TaskHandle {
+    let mut parent = ctx.scene_tree.clone();
+    let node = Node::new_alloc();
+    parent.add_child(&node);
+
+    // Waiting for a single frame: leaving is detected while waiting, not only before the next frame.
+    let waiting = node.clone();
+    let handle = task::spawn(async move {
+        let result = waiting.await_frames(1).await;
+        assert_eq!(result, Err(AwaitFramesError::NotInTree));
+
+        waiting.free();
+    });
+
+    parent.remove_child(&node);
+    handle
+}
+
+#[itest(async)]
+fn await_cancelled_on_tree_exit(ctx: &TestContext) -> TaskHandle {
+    let mut parent = ctx.scene_tree.clone();
+    let mut node = Node::new_alloc();
+    parent.add_child(&node);
+
+    let resumed = Rc::new(Cell::new(false));
+    let flag = resumed.clone();
+    let waiting = Rc::new(task::spawn(async move {
+        for _ in 0..5 {
+            task::next_frame().await.unwrap();
+        }
+        flag.set(true);
+    }));
+
+    // Like a GDScript coroutine of a removed node, the wait ends when the node leaves the tree.
+    let handle = waiting.clone();
+    node.signals()
+        .tree_exiting()
+        .connect(move || handle.cancel());
+
+    let removed = node.clone();
+    task::spawn(async move {
+        task::next_frame().await.unwrap();
+        parent.remove_child(&removed);
+        assert!(!waiting.is_pending(), "wait cancelled on tree exit");
+
+        for _ in 0..10 {
+            task::next_frame().await.unwrap();
+        }
+        assert!(!resumed.get(), "cancelled task must not resume");
+
+        node.free();
+    })
+}
+
+#[cfg(feature = "codegen-full")]
+mod sleep {
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    use godot::classes::Time;
+    use godot::task::{self, TaskHandle};
+
+    use crate::framework::{itest, TestContext};
+
+    #[itest(async)]
+    fn await_sleep() -> TaskHandle {
+        task::spawn(async {
+            let start = Time::singleton().get_ticks_msec();
+            task::sleep(Duration::from_millis(50)).await.unwrap();
+            let elapsed = Time::singleton().get_ticks_msec() - start;
+
+            assert!(elapsed >= 45, "slept for {elapsed} ms");
+        })
+    }
+
+    #[itest(async)]
+    fn await_sleep_paused(ctx: &TestContext) -> TaskHandle {
+        let mut tree = ctx.scene_tree.get_tree().unwrap();
+
+        let pausable_done = Rc::new(Cell::new(false));
+        let always_done = Rc::new(Cell::new(false));
+
+        let pausable = task::sleep_ex(Duration::from_millis(20))
+            .process_always(false)
+            .done();
+        let always = task::sleep(Duration::from_millis(20));
+
+        tree.set_pause(true);
+
+        let flag = pausable_done.clone();
+        task::spawn(async move {
+            pausable.await.unwrap();
+            flag.set(true);
+        });
+
+        let flag = always_done.clone();
+        task::spawn(async move {
+            always.await.unwrap();
+            flag.set(true);
+        });
+
+        task::spawn(async move {
+            // Timers that process always still time out while paused.
+            let flag = always_done.clone();
+            task::until(move || flag.get()).await.unwrap();
+
+            for _ in 0..5 {
+                task::next_frame().await.unwrap();
+            }
+            assert!(!pausable_done.get(), "timer must not advance while paused");
+
+            tree.set_pause(false);
+            let flag = pausable_done.clone();
+            task::until(move || flag.get()).await.unwrap();
+        })
+    }
+
+    #[itest(async)]
+    fn cancel_sleep() -> TaskHandle {
+        let done = Rc::new(Cell::new(false));
+
+        let flag = done.clone();
+        let sleeping = task::spawn(async move {
+            task::sleep(Duration::from_millis(10)).await.unwrap();
+            flag.set(true);
+        });
+        sleeping.cancel();
+
+        // Timeout of the cancelled task's timer is ignored.
+        task::spawn(async move {
+            task::sleep(Duration::from_millis(50)).await.unwrap();
+            assert!(!done.get());
+        })
+    }
+}
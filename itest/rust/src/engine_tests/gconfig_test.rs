@@ -0,0 +1,151 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use godot::builtin::{Dictionary, GString, Vector2};
+use godot::meta::error::ConvertError;
+use godot::meta::{FromGodot, GodotConvert};
+use godot::tools::GConfig;
+
+use crate::framework::itest;
+
+const TEST_PATH: &str = "user://gconfig_test.cfg";
+const ENCRYPTED_PATH: &str = "user://gconfig_test_encrypted.cfg";
+
+#[derive(Debug, PartialEq)]
+struct AudioSettings {
+    master_volume: f32,
+    muted: bool,
+}
+
+impl GodotConvert for AudioSettings {
+    type Via = Dictionary;
+}
+
+impl FromGodot for AudioSettings {
+    fn try_from_godot(dict: Dictionary) -> Result<Self, ConvertError> {
+        let get = |key: &str| dict.get(key).unwrap_or_default();
+
+        Ok(Self {
+            master_volume: get("master_volume").try_to()?,
+            muted: get("muted").try_to()?,
+        })
+    }
+}
+
+fn sample_config() -> GConfig {
+    let mut config = GConfig::new();
+    config.set("audio", "master_volume", 0.5);
+    config.set("audio", "muted", true);
+    config.set("player", "name", "Alice");
+    config.set("player", "spawn", Vector2::new(3.0, 4.0));
+    config
+}
+
+#[itest]
+fn gconfig_get_set() {
+    let config = sample_config();
+
+    assert_eq!(
+        config.get::<f32>("audio", "master_volume").unwrap(),
+        Some(0.5)
+    );
+    assert_eq!(config.get::<bool>("audio", "muted").unwrap(), Some(true));
+    assert_eq!(
+        config.get::<GString>("player", "name").unwrap(),
+        Some(GString::from("Alice"))
+    );
+
+    // Missing section or key.
+    assert_eq!(config.get::<i32>("audio", "missing").unwrap(), None);
+    assert_eq!(config.get::<i32>("missing", "key").unwrap(), None);
+
+    // Wrong type.
+    config
+        .get::<Vector2>("audio", "muted")
+        .expect_err("bool is not a Vector2");
+}
+
+#[itest]
+fn gconfig_sections_keys() {
+    let mut config = sample_config();
+
+    let sections: Vec<GString> = config.sections().collect();
+    assert_eq!(sections, [GString::from("audio"), GString::from("player")]);
+
+    let keys: Vec<GString> = config.keys("player").collect();
+    assert_eq!(keys, [GString::from("name"), GString::from("spawn")]);
+    assert_eq!(config.keys("missing").count(), 0);
+
+    assert!(config.has_key("audio", "muted"));
+    config.set("audio", "muted", Option::<GString>::None);
+    assert!(!config.has_key("audio", "muted"));
+
+    config.erase_section("player");
+    config.erase_section("missing");
+    assert!(!config.has_section("player"));
+}
+
+#[itest]
+fn gconfig_save_load_roundtrip() {
+    let config = sample_config();
+    config.save(TEST_PATH).expect("save config");
+
+    let loaded = GConfig::load(TEST_PATH).expect("load config");
+    assert_eq!(
+        loaded.get::<f32>("audio", "master_volume").unwrap(),
+        Some(0.5)
+    );
+    assert_eq!(
+        loaded.get::<Vector2>("player", "spawn").unwrap(),
+        Some(Vector2::new(3.0, 4.0))
+    );
+
+    GConfig::load("user://gconfig_test_missing.cfg").expect_err("file does not exist");
+}
+
+#[itest]
+fn gconfig_encrypted_roundtrip() {
+    let key = [7u8; 32];
+
+    let config = sample_config();
+    config
+        .save_encrypted(ENCRYPTED_PATH, &key)
+        .expect("save encrypted config");
+
+    let loaded = GConfig::load_encrypted(ENCRYPTED_PATH, &key).expect("load encrypted config");
+    assert_eq!(loaded.get::<bool>("audio", "muted").unwrap(), Some(true));
+
+    // Plain loading does not understand the encrypted format.
+    GConfig::load(ENCRYPTED_PATH).expect_err("file is encrypted");
+}
+
+#[itest]
+fn gconfig_read_struct() {
+    let mut config = sample_config();
+
+    let audio = config.read_struct::<AudioSettings>("audio").unwrap();
+    assert_eq!(
+        audio,
+        Some(AudioSettings {
+            master_volume: 0.5,
+            muted: true,
+        })
+    );
+
+    assert!(config
+        .read_struct::<AudioSettings>("missing")
+        .unwrap()
+        .is_none());
+
+    // Missing field (nil) and wrong type fail conversion.
+    config.set("broken", "master_volume", "loud");
+    config
+        .read_struct::<AudioSettings>("broken")
+        .expect_err("wrong field types");
+}
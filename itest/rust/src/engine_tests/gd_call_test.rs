@@ -0,0 +1,55 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::node::InternalMode;
+use godot::classes::{Node, Node2D, Node3D};
+use godot::gd_call;
+use godot::obj::{Gd, NewAlloc};
+
+use crate::framework::itest;
+
+#[itest]
+fn gd_call_positional() {
+    let mut parent = Node::new_alloc();
+    let child = Node2D::new_alloc();
+
+    gd_call!(parent.add_child(&child));
+    gd_call!(parent.set_name("parent"));
+
+    assert_eq!(gd_call!(parent.get_child_count()), 1);
+    assert_eq!(gd_call!(child.get_parent()), Some(parent.clone()));
+    assert_eq!(parent.get_name(), "parent");
+
+    parent.free();
+}
+
+#[itest]
+fn gd_call_default_params() {
+    let mut parent = Node::new_alloc();
+    let internal = Node3D::new_alloc();
+    let mut regular = Node2D::new_alloc();
+    regular.set_name("regular");
+
+    gd_call!(parent.add_child(&internal; internal = InternalMode::FRONT));
+    gd_call!(parent.add_child(&regular; force_readable_name = true,));
+
+    assert_eq!(gd_call!(parent.get_child_count()), 1);
+    assert_eq!(
+        gd_call!(parent.get_child_count(; include_internal = true)),
+        2
+    );
+
+    // Complex receiver expression; keyword parameter `type` maps to `type_()`.
+    let found =
+        gd_call!(regular.get_parent().unwrap().find_children("*"; type = "Node2D", owned = false));
+    assert_eq!(found.len(), 1);
+
+    let first: Option<Gd<Node>> = gd_call!(parent.get_child(0; include_internal = true));
+    assert_eq!(first, Some(internal.upcast()));
+
+    parent.free();
+}
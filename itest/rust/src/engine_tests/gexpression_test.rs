@@ -0,0 +1,112 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use godot::builtin::{GString, Variant, Vector2};
+use godot::classes::{Node, Object};
+use godot::meta::error::ExpressionEvalError;
+use godot::meta::ToGodot;
+use godot::obj::NewAlloc;
+use godot::tools::GExpression;
+
+use crate::framework::itest;
+
+#[itest]
+fn gexpression_execute() {
+    let expr = GExpression::parse("a * 2 + b", &["a", "b"]).expect("valid expression");
+
+    assert_eq!(expr.source_text(), &GString::from("a * 2 + b"));
+    assert_eq!(expr.input_names().len(), 2);
+
+    let result: i64 = expr
+        .execute_as(&[3.to_variant(), 4.to_variant()], None)
+        .unwrap();
+    assert_eq!(result, 10);
+
+    // Parsed once, executed again with other inputs.
+    let result: i64 = expr
+        .execute_as(&[10.to_variant(), (-1).to_variant()], None)
+        .unwrap();
+    assert_eq!(result, 19);
+
+    let result = expr
+        .execute(&[1.5.to_variant(), 0.to_variant()], None)
+        .unwrap();
+    assert_eq!(result, 3.0.to_variant());
+}
+
+#[itest]
+fn gexpression_builtin_calls() {
+    let expr = GExpression::parse("Vector2(x, 1).normalized() * 2", &["x"]).unwrap();
+
+    let result: Vector2 = expr.execute_as(&[0.to_variant()], None).unwrap();
+    assert_eq!(result, Vector2::new(0.0, 2.0));
+}
+
+#[itest]
+fn gexpression_base_instance() {
+    let mut node = Node::new_alloc();
+    node.set_name("ExprBase");
+
+    let expr = GExpression::parse("get_name() + suffix", &["suffix"]).unwrap();
+    let base = node.clone().upcast::<Object>();
+
+    let name: GString = expr.execute_as(&["!".to_variant()], Some(&base)).unwrap();
+    assert_eq!(name, GString::from("ExprBase!"));
+
+    node.free();
+}
+
+#[itest]
+fn gexpression_parse_error() {
+    let err = GExpression::parse("1 + * 2", &[]).expect_err("invalid syntax");
+
+    assert_eq!(err.source_text(), &GString::from("1 + * 2"));
+    assert!(!err.error_text().is_empty());
+    assert!(err.to_string().contains("1 + * 2"), "{err}");
+}
+
+#[itest]
+fn gexpression_runtime_error() {
+    let expr = GExpression::parse("value.length()", &["value"]).unwrap();
+
+    // Nil has no length() method.
+    let err = expr
+        .execute(&[Variant::nil()], None)
+        .expect_err("runtime error");
+    let ExpressionEvalError::Execution { error_text } = err else {
+        panic!("expected execution error, got {err:?}");
+    };
+    assert!(!error_text.is_empty());
+
+    // Still usable after a failed execution.
+    let len: i64 = expr.execute_as(&["four".to_variant()], None).unwrap();
+    assert_eq!(len, 4);
+}
+
+#[itest]
+fn gexpression_wrong_inputs_or_type() {
+    let expr = GExpression::parse("a + 1", &["a"]).unwrap();
+
+    let err = expr.execute(&[], None).expect_err("missing input");
+    assert!(matches!(
+        err,
+        ExpressionEvalError::WrongInputCount {
+            expected: 1,
+            actual: 0
+        }
+    ));
+
+    let err = expr
+        .execute_as::<Vector2>(&[1.to_variant()], None)
+        .expect_err("int is not a Vector2");
+    let ExpressionEvalError::WrongType { error } = err else {
+        panic!("expected conversion error, got {err:?}");
+    };
+    assert_eq!(error.value(), Some(&2.to_variant()));
+}
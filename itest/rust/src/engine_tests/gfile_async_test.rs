@@ -0,0 +1,132 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Requires the itest `futures-io` feature.
+#![cfg(feature = "codegen-full")]
+
+use std::io::{Read, SeekFrom, Write};
+use std::pin::Pin;
+use std::task::{Context, Waker};
+
+use futures::{AsyncRead, AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use godot::classes::file_access::ModeFlags;
+use godot::classes::{DirAccess, FileAccess};
+use godot::task::{self, TaskHandle};
+use godot::tools::GFile;
+
+use crate::framework::itest;
+
+const SYNC_PATH: &str = "user://gfile_async_sync.bin";
+const ASYNC_PATH: &str = "user://gfile_async_async.bin";
+
+/// 3 MiB of deterministic pseudo-random bytes.
+fn test_data() -> Vec<u8> {
+    let mut state = 0x2545_f491_u32;
+    (0..3 * 1024 * 1024)
+        .map(|_| {
+            // xorshift32
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect()
+}
+
+#[itest(async)]
+fn gfile_async_stream_matches_sync() -> TaskHandle {
+    let data = test_data();
+
+    let mut sync_file = GFile::open(SYNC_PATH, ModeFlags::WRITE).unwrap();
+    sync_file.write_all(&data).unwrap();
+    drop(sync_file);
+
+    task::spawn(async move {
+        let mut file = GFile::open(ASYNC_PATH, ModeFlags::WRITE)
+            .unwrap()
+            .into_async()
+            .with_chunk_size(256 * 1024);
+
+        file.write_all(&data).await.unwrap();
+        file.close().await.unwrap();
+        drop(file);
+
+        assert_eq!(
+            GFile::sha256(ASYNC_PATH).unwrap(),
+            GFile::sha256(SYNC_PATH).unwrap()
+        );
+
+        let mut file = GFile::open(ASYNC_PATH, ModeFlags::READ)
+            .unwrap()
+            .into_async()
+            .with_chunk_size(100_000);
+
+        let mut streamed = Vec::new();
+        file.read_to_end(&mut streamed).await.unwrap();
+        drop(file);
+
+        let mut expected = Vec::new();
+        GFile::open(SYNC_PATH, ModeFlags::READ)
+            .unwrap()
+            .read_to_end(&mut expected)
+            .unwrap();
+
+        assert_eq!(streamed.len(), data.len());
+        assert!(streamed == expected, "streamed bytes differ from sync read");
+
+        remove_test_files();
+    })
+}
+
+#[itest(async)]
+fn gfile_async_seek() -> TaskHandle {
+    let mut file = GFile::open(SYNC_PATH, ModeFlags::WRITE).unwrap();
+    file.write_all(&test_data()).unwrap();
+    drop(file);
+
+    task::spawn(async move {
+        let mut file = GFile::open(SYNC_PATH, ModeFlags::READ)
+            .unwrap()
+            .into_async()
+            .with_chunk_size(1024 * 1024);
+
+        // Start a read without completing it.
+        let mut buf = [0u8; 16];
+        let mut cx = Context::from_waker(Waker::noop());
+        let poll = Pin::new(&mut file).poll_read(&mut cx, &mut buf);
+        assert!(poll.is_pending());
+        assert!(file.is_busy());
+
+        let err = file.seek(SeekFrom::Start(0)).await.unwrap_err();
+        assert!(err.to_string().contains("in flight"), "{err}");
+
+        // Completing the read makes seeking possible again; the position accounts for buffered bytes.
+        let len = file.read(&mut buf).await.unwrap();
+        assert_eq!(len, buf.len());
+
+        let pos = file.seek(SeekFrom::Current(4)).await.unwrap();
+        assert_eq!(pos, 20);
+
+        let mut byte = [0u8; 1];
+        file.read_exact(&mut byte).await.unwrap();
+        assert_eq!(byte[0], test_data()[20]);
+
+        let sync_file = file.into_sync().unwrap();
+        assert_eq!(sync_file.position(), 21);
+        drop(sync_file);
+
+        remove_test_files();
+    })
+}
+
+fn remove_test_files() {
+    for path in [SYNC_PATH, ASYNC_PATH] {
+        if FileAccess::file_exists(path) {
+            DirAccess::remove_absolute(path);
+        }
+    }
+}
@@ -0,0 +1,107 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{dict, GString};
+use godot::classes::Engine;
+use godot::init::GdextBuild;
+use godot::tools::{is_godot_version_at_least, GodotVersion};
+
+use crate::framework::itest;
+
+fn version(major: u8, minor: u8, patch: u8, status: &str) -> GodotVersion {
+    GodotVersion {
+        major,
+        minor,
+        patch,
+        status: status.to_string(),
+        build: "official".to_string(),
+        hash: String::new(),
+        string: String::new(),
+    }
+}
+
+#[itest]
+fn godot_version_matches_engine() {
+    let engine = Engine::singleton();
+    let version = engine.version();
+    let info = engine.get_version_info();
+
+    assert_eq!(version.triple(), GdextBuild::godot_runtime_version_triple());
+    assert_eq!(version.hex() as i64, info.at("hex").to::<i64>());
+    assert_eq!(
+        GString::from(&version.status),
+        info.at("status").to::<GString>()
+    );
+    assert_eq!(
+        GString::from(&version.build),
+        info.at("build").to::<GString>()
+    );
+    assert_eq!(
+        GString::from(&version.hash),
+        info.at("hash").to::<GString>()
+    );
+    assert_eq!(
+        GString::from(&version.string),
+        info.at("string").to::<GString>()
+    );
+    assert!(!version.string.is_empty());
+
+    // Runtime version is never older than the API level compiled against.
+    let (major, minor, _) = GdextBuild::godot_static_version_triple();
+    assert!(version.is_at_least(major, minor));
+    assert!(is_godot_version_at_least(major, minor));
+    assert!(is_godot_version_at_least(4, 0));
+    assert!(!is_godot_version_at_least(version.major, version.minor + 1));
+    assert!(!is_godot_version_at_least(version.major + 1, 0));
+}
+
+#[itest]
+fn godot_version_ordering() {
+    assert!(version(4, 3, 0, "stable") > version(4, 2, 2, "stable"));
+    assert!(version(4, 2, 1, "stable") < version(4, 2, 2, "stable"));
+    assert!(version(5, 0, 0, "dev") > version(4, 9, 9, "stable"));
+    assert!(version(4, 3, 0, "stable") >= version(4, 3, 0, "stable"));
+
+    // Same numbers, different status: not equal, but still ordered consistently.
+    let dev = version(4, 3, 0, "dev");
+    let stable = version(4, 3, 0, "stable");
+    assert_ne!(dev, stable);
+    assert_eq!(dev.cmp(&stable), stable.cmp(&dev).reverse());
+    assert_ne!(dev.partial_cmp(&stable), Some(std::cmp::Ordering::Equal));
+    assert_eq!(dev.partial_cmp(&stable), Some(dev.cmp(&stable)));
+    assert_eq!(dev.triple(), stable.triple());
+
+    assert!(stable.is_at_least(4, 3));
+    assert!(stable.is_at_least(3, 9));
+    assert!(!stable.is_at_least(4, 4));
+}
+
+#[itest]
+fn godot_version_from_dictionary() {
+    let parsed = GodotVersion::from_dictionary(&dict! {
+        "major": 4,
+        "minor": 2,
+        "patch": 1,
+        "status": "rc1",
+        "build": "custom_build",
+        "hash": "abc123",
+        "string": "4.2.1-rc1 (custom_build)",
+    });
+
+    assert_eq!(parsed.triple(), (4, 2, 1));
+    assert_eq!(parsed.hex(), 0x040201);
+    assert_eq!(parsed.status, "rc1");
+    assert_eq!(parsed.build, "custom_build");
+    assert_eq!(parsed.hash, "abc123");
+    assert_eq!(parsed.to_string(), "4.2.1-rc1");
+
+    // Missing entries fall back to defaults.
+    let empty = GodotVersion::from_dictionary(&dict! {});
+    assert_eq!(empty.triple(), (0, 0, 0));
+    assert_eq!(empty.hash, "");
+    assert_eq!(version(4, 3, 0, "stable").to_string(), "4.3-stable");
+}
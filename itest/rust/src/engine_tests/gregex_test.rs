@@ -0,0 +1,106 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use godot::builtin::GString;
+use godot::global::Error;
+use godot::tools::GRegex;
+
+use crate::framework::{itest, suppress_godot_print};
+
+#[itest]
+fn gregex_search_groups() {
+    let regex = GRegex::compile(r"(?<key>\w+)=(?<value>\d+)").expect("valid pattern");
+    assert_eq!(regex.pattern(), GString::from(r"(?<key>\w+)=(?<value>\d+)"));
+    assert_eq!(regex.group_count(), 2);
+
+    let m = regex.search("set volume=80;").expect("pattern matches");
+    assert_eq!(m.as_gstring(), GString::from("volume=80"));
+    assert_eq!(m.group_count(), 2);
+    assert_eq!(m.subject(), GString::from("set volume=80;"));
+
+    // By index and by name.
+    assert_eq!(m.get_string(1usize), Some(GString::from("volume")));
+    assert_eq!(m.get_string("value"), Some(GString::from("80")));
+    assert_eq!(
+        m.get_string(&GString::from("key")),
+        Some(GString::from("volume"))
+    );
+
+    assert_eq!(m.get_start(0), Some(4));
+    assert_eq!(m.get_end(0), Some(13));
+    assert_eq!(m.get_start("value"), Some(11));
+    assert_eq!(m.get_end("value"), Some(13));
+
+    // Non-existent groups.
+    assert_eq!(m.get_string(3), None);
+    assert_eq!(m.get_string("missing"), None);
+    assert_eq!(m.get_start("missing"), None);
+
+    assert!(regex.search("no match").is_none());
+    assert!(regex.is_match("a=1"));
+    assert!(!regex.is_match("a=b"));
+}
+
+#[itest]
+fn gregex_unmatched_and_empty_groups() {
+    let regex = GRegex::compile(r"(a)|(b)()").unwrap();
+
+    let m = regex.search("b").unwrap();
+    assert_eq!(m.get_string(1), None, "group in other branch");
+    assert_eq!(m.get_start(1), None);
+    assert_eq!(m.get_end(1), None);
+    assert_eq!(m.get_string(2), Some(GString::from("b")));
+
+    // Participating group with empty match is distinct from a non-participating one.
+    assert_eq!(m.get_string(3), Some(GString::new()));
+    assert_eq!(m.get_start(3), Some(1));
+}
+
+#[itest]
+fn gregex_search_all() {
+    let regex = GRegex::compile(r"\d+").unwrap();
+
+    let numbers: Vec<GString> = regex
+        .search_all("1, 22 and 333")
+        .map(|m| m.as_gstring())
+        .collect();
+    assert_eq!(numbers, ["1", "22", "333"].map(GString::from));
+
+    let starts: Vec<usize> = regex
+        .search_all("1, 22 and 333")
+        .filter_map(|m| m.get_start(0))
+        .collect();
+    assert_eq!(starts, [0, 3, 10]);
+
+    assert_eq!(regex.search_all("none").count(), 0);
+
+    let m = regex.search_from("1, 22 and 333", 4).unwrap();
+    assert_eq!(m.as_gstring(), GString::from("2"));
+}
+
+#[itest]
+fn gregex_replace_all() {
+    let regex = GRegex::compile(r"(?<word>\w+)@(\w+)").unwrap();
+
+    let replaced = regex.replace_all("me@home, you@work", "$2:$word");
+    assert_eq!(replaced, GString::from("home:me, work:you"));
+}
+
+#[itest]
+fn gregex_compile_error() {
+    let mut result = None;
+    suppress_godot_print(|| {
+        result = Some(GRegex::compile("(unclosed"));
+    });
+
+    let err = result.unwrap().expect_err("invalid pattern");
+    assert_eq!(err.pattern(), &GString::from("(unclosed"));
+    assert_ne!(err.godot_error(), Error::OK);
+    assert!(err.to_string().contains("(unclosed"), "{err}");
+}
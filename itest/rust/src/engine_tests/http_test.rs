@@ -0,0 +1,179 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use godot::classes::http_client::Method;
+use godot::classes::http_request;
+use godot::meta::error::HttpError;
+use godot::task::{self, TaskHandle};
+use godot::tools::{http_get, HttpRequestBuilder};
+
+use crate::framework::itest;
+
+/// Serves a single connection on a local port, answering with `response` (or not at all if `None`).
+///
+/// Returns the port and a receiver for the raw request text.
+fn serve_once(response: Option<&'static str>) -> (u16, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind local port");
+    let port = listener.local_addr().unwrap().port();
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().expect("accept connection");
+        let request = read_request(&mut stream);
+        let _ = sender.send(request);
+
+        match response {
+            Some(response) => {
+                let _ = stream.write_all(response.as_bytes());
+            }
+            // Keep the connection open without answering, until the client gives up.
+            None => {
+                let _ = stream.read(&mut [0; 1]);
+            }
+        }
+    });
+
+    (port, receiver)
+}
+
+fn read_request(stream: &mut impl Read) -> String {
+    let mut data = Vec::new();
+    let mut buffer = [0; 1024];
+
+    loop {
+        let read = stream.read(&mut buffer).unwrap_or(0);
+        data.extend_from_slice(&buffer[..read]);
+
+        let text = String::from_utf8_lossy(&data);
+        if let Some(header_end) = text.find("\r\n\r\n") {
+            let content_length = text[..header_end]
+                .lines()
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    name.eq_ignore_ascii_case("content-length")
+                        .then(|| value.trim().parse::<usize>().ok())?
+                })
+                .unwrap_or(0);
+
+            if data.len() >= header_end + 4 + content_length {
+                break;
+            }
+        }
+
+        if read == 0 {
+            break;
+        }
+    }
+
+    String::from_utf8_lossy(&data).into_owned()
+}
+
+const HELLO_RESPONSE: &str = "HTTP/1.1 200 OK\r\n\
+    Content-Type: text/plain\r\n\
+    X-Test: yes\r\n\
+    Content-Length: 5\r\n\
+    Connection: close\r\n\
+    \r\n\
+    hello";
+
+#[itest(async)]
+fn http_get_local_server() -> TaskHandle {
+    let (port, requests) = serve_once(Some(HELLO_RESPONSE));
+
+    task::spawn(async move {
+        let response = http_get(format!("http://127.0.0.1:{port}/greeting"))
+            .await
+            .expect("request succeeds");
+
+        assert_eq!(response.status(), 200);
+        assert!(response.is_success());
+        assert_eq!(response.text(), "hello");
+        assert_eq!(response.body().as_slice(), b"hello");
+        assert_eq!(response.header("x-test"), Some("yes"));
+        assert_eq!(response.header("Content-Type"), Some("text/plain"));
+
+        let request = requests.recv().unwrap();
+        assert!(
+            request.starts_with("GET /greeting HTTP/1.1\r\n"),
+            "{request}"
+        );
+    })
+}
+
+#[itest(async)]
+fn http_builder_post() -> TaskHandle {
+    let response = "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    let (port, requests) = serve_once(Some(response));
+
+    task::spawn(async move {
+        let response = HttpRequestBuilder::new(format!("http://127.0.0.1:{port}/scores"))
+            .method(Method::POST)
+            .header("X-Player", "ferris")
+            .body_text("score=42")
+            .send()
+            .await
+            .expect("HTTP error status is not a request error");
+
+        assert_eq!(response.status(), 404);
+        assert!(!response.is_success());
+        assert!(response.text().is_empty());
+
+        let request = requests.recv().unwrap();
+        assert!(
+            request.starts_with("POST /scores HTTP/1.1\r\n"),
+            "{request}"
+        );
+        assert!(request.contains("X-Player: ferris\r\n"), "{request}");
+        assert!(request.ends_with("\r\n\r\nscore=42"), "{request}");
+    })
+}
+
+#[itest(async)]
+fn http_timeout() -> TaskHandle {
+    let (port, _requests) = serve_once(None);
+
+    task::spawn(async move {
+        let result = HttpRequestBuilder::new(format!("http://127.0.0.1:{port}/slow"))
+            .timeout(Duration::from_millis(300))
+            .send()
+            .await;
+
+        assert_eq!(result.unwrap_err(), HttpError::Timeout);
+    })
+}
+
+#[itest(async)]
+fn http_connection_refused() -> TaskHandle {
+    // Bind and release a port, so that nothing listens on it.
+    let port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+
+    task::spawn(async move {
+        let result = http_get(format!("http://127.0.0.1:{port}/")).await;
+
+        assert!(
+            matches!(
+                result,
+                Err(HttpError::Failed(
+                    http_request::Result::CANT_CONNECT | http_request::Result::CONNECTION_ERROR
+                ))
+            ),
+            "{result:?}"
+        );
+    })
+}
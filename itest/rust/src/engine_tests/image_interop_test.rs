@@ -0,0 +1,150 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use godot::builtin::{Color, PackedByteArray};
+use godot::classes::image::Format;
+use godot::classes::Image;
+use godot::obj::Gd;
+use image::{DynamicImage, ImageBuffer, Luma, LumaA, Rgb, Rgb32FImage, Rgba, Rgba32FImage};
+
+use crate::framework::itest;
+
+const WIDTH: u32 = 5;
+const HEIGHT: u32 = 3;
+
+fn channel(x: u32, y: u32, c: u32) -> u8 {
+    (x * 40 + y * 70 + c * 20) as u8
+}
+
+fn channel_f(x: u32, y: u32, c: u32) -> f32 {
+    x as f32 * 0.5 - y as f32 * 1.25 + c as f32 * 3.0
+}
+
+/// Converts `source` to Godot and back, checking the intermediate format.
+fn round_trip(source: DynamicImage, expected_format: Format) -> Gd<Image> {
+    let image = Image::from_dynamic_image(&source);
+    assert_eq!(image.get_format(), expected_format);
+    assert_eq!(image.get_width(), WIDTH as i32);
+    assert_eq!(image.get_height(), HEIGHT as i32);
+
+    let back = image.to_dynamic_image().expect("supported format");
+    assert_eq!(back, source);
+
+    image
+}
+
+#[itest]
+fn image_interop_l8() {
+    let source = ImageBuffer::from_fn(WIDTH, HEIGHT, |x, y| Luma([channel(x, y, 0)]));
+    let image = round_trip(DynamicImage::ImageLuma8(source), Format::L8);
+
+    let v = channel(2, 1, 0);
+    assert_eq!(image.get_pixel(2, 1), Color::from_rgba8(v, v, v, 255));
+}
+
+#[itest]
+fn image_interop_la8() {
+    let source = ImageBuffer::from_fn(WIDTH, HEIGHT, |x, y| {
+        LumaA([channel(x, y, 0), channel(x, y, 1)])
+    });
+    round_trip(DynamicImage::ImageLumaA8(source), Format::LA8);
+}
+
+#[itest]
+fn image_interop_rgb8() {
+    let source = ImageBuffer::from_fn(WIDTH, HEIGHT, |x, y| {
+        Rgb([channel(x, y, 0), channel(x, y, 1), channel(x, y, 2)])
+    });
+    let image = round_trip(DynamicImage::ImageRgb8(source), Format::RGB8);
+
+    let [r, g, b] = [0, 1, 2].map(|c| channel(4, 2, c));
+    assert_eq!(image.get_pixel(4, 2), Color::from_rgba8(r, g, b, 255));
+}
+
+#[itest]
+fn image_interop_rgba8() {
+    let source = ImageBuffer::from_fn(WIDTH, HEIGHT, |x, y| {
+        Rgba([0, 1, 2, 3].map(|c| channel(x, y, c)))
+    });
+    let image = round_trip(DynamicImage::ImageRgba8(source), Format::RGBA8);
+
+    let [r, g, b, a] = [0, 1, 2, 3].map(|c| channel(1, 0, c));
+    assert_eq!(image.get_pixel(1, 0), Color::from_rgba8(r, g, b, a));
+}
+
+#[itest]
+fn image_interop_rgbf() {
+    let source: Rgb32FImage = ImageBuffer::from_fn(WIDTH, HEIGHT, |x, y| {
+        Rgb([0, 1, 2].map(|c| channel_f(x, y, c)))
+    });
+    let image = round_trip(DynamicImage::ImageRgb32F(source), Format::RGBF);
+
+    let pixel = image.get_pixel(3, 2);
+    assert_eq!((pixel.r, pixel.g, pixel.b), (-1.0, 2.0, 5.0));
+}
+
+#[itest]
+fn image_interop_rgbaf() {
+    let source: Rgba32FImage = ImageBuffer::from_fn(WIDTH, HEIGHT, |x, y| {
+        Rgba([0, 1, 2, 3].map(|c| channel_f(x, y, c)))
+    });
+    round_trip(DynamicImage::ImageRgba32F(source), Format::RGBAF);
+}
+
+#[itest]
+fn image_interop_16_bit_as_float() {
+    let source = ImageBuffer::from_fn(WIDTH, HEIGHT, |x, y| {
+        Rgba([0, 1, 2, 3].map(|c| channel(x, y, c) as u16 * 257))
+    });
+    let image = Image::from_dynamic_image(&DynamicImage::ImageRgba16(source.clone()));
+    assert_eq!(image.get_format(), Format::RGBAF);
+
+    // u16 -> f32 -> u16 is lossless.
+    let back = image.to_dynamic_image().unwrap().into_rgba16();
+    assert_eq!(back, source);
+}
+
+#[itest]
+fn image_interop_converts_other_formats() {
+    let mut image = Image::create_empty(WIDTH as i32, HEIGHT as i32, false, Format::RH).unwrap();
+    image.fill(Color::from_rgb(0.5, 0.0, 0.0));
+
+    let back = image.to_dynamic_image().unwrap();
+    let buffer = back.as_rgb32f().expect("RH converts to RGB32F");
+    assert!(buffer.pixels().all(|pixel| pixel.0 == [0.5, 0.0, 0.0]));
+
+    let mut image = Image::create_empty(WIDTH as i32, HEIGHT as i32, false, Format::R8).unwrap();
+    image.fill(Color::from_rgba8(200, 0, 0, 255));
+
+    let back = image.to_dynamic_image().unwrap();
+    assert_eq!(back.as_rgb8().unwrap().get_pixel(4, 2).0, [200, 0, 0]);
+}
+
+#[itest]
+fn image_interop_drops_mipmaps() {
+    let source = ImageBuffer::from_fn(WIDTH, HEIGHT, |x, y| {
+        Rgba([0, 1, 2, 3].map(|c| channel(x, y, c)))
+    });
+    let mut image = Image::from_dynamic_image(&DynamicImage::ImageRgba8(source.clone()));
+    image.generate_mipmaps();
+
+    let back = image.to_dynamic_image().unwrap();
+    assert_eq!(back, DynamicImage::ImageRgba8(source));
+}
+
+#[itest]
+fn image_interop_compressed_unsupported() {
+    // 8x8 DXT1 image: 4 blocks of 8 bytes each.
+    let data = PackedByteArray::from(&[0u8; 32][..]);
+    let image = Image::create_from_data(8, 8, false, Format::DXT1, &data).unwrap();
+    assert!(image.is_compressed());
+
+    let err = image.to_dynamic_image().expect_err("compressed format");
+    assert_eq!(err.format(), Format::DXT1);
+}
@@ -0,0 +1,174 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use godot::builtin::Color;
+use godot::classes::image::Format;
+use godot::classes::Image;
+
+use crate::framework::{expect_panic, itest};
+
+const WIDTH: i32 = 16;
+const HEIGHT: i32 = 8;
+
+/// Horizontal red gradient, vertical green gradient, constant blue and alpha.
+fn gradient_pixel(x: i32, y: i32) -> [u8; 4] {
+    [(x * 16) as u8, (y * 32) as u8, 128, 255]
+}
+
+fn gradient_bytes() -> Vec<u8> {
+    (0..HEIGHT)
+        .flat_map(|y| (0..WIDTH).flat_map(move |x| gradient_pixel(x, y)))
+        .collect()
+}
+
+fn assert_gradient(image: &Image) {
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            let [r, g, b, a] = gradient_pixel(x, y);
+            assert_eq!(
+                image.get_pixel(x, y),
+                Color::from_rgba8(r, g, b, a),
+                "pixel ({x}, {y})"
+            );
+        }
+    }
+}
+
+#[itest]
+fn image_from_rgba8() {
+    let image = Image::from_rgba8(WIDTH, HEIGHT, &gradient_bytes());
+
+    assert_eq!(image.get_width(), WIDTH);
+    assert_eq!(image.get_height(), HEIGHT);
+    assert_eq!(image.get_format(), Format::RGBA8);
+    assert!(!image.has_mipmaps());
+    assert_gradient(&image);
+}
+
+#[itest]
+fn image_from_rgba8_wrong_size() {
+    expect_panic("data too short", || {
+        Image::from_rgba8(WIDTH, HEIGHT, &[0; 4]);
+    });
+}
+
+#[itest]
+fn image_data_view() {
+    let image = Image::from_rgba8(WIDTH, HEIGHT, &gradient_bytes());
+    let view = image.data_view();
+
+    assert_eq!(view.width(), WIDTH);
+    assert_eq!(view.height(), HEIGHT);
+    assert_eq!(view.format(), Format::RGBA8);
+    assert_eq!(view.level_count(), 1);
+    assert_eq!(view.bytes(), gradient_bytes().as_slice());
+
+    let level = view.level(0).expect("level 0 exists");
+    let pixels = level.rgba8().expect("format is RGBA8");
+    assert_eq!(pixels.len(), (WIDTH * HEIGHT) as usize);
+    assert_eq!(pixels[level.pixel_index(3, 5)], gradient_pixel(3, 5));
+
+    // Wrong format or level.
+    assert!(level.rf().is_none());
+    assert!(level.rgbaf().is_none());
+    assert!(view.level(1).is_none());
+}
+
+#[itest]
+fn image_data_view_mut_rgba8() {
+    let mut image = Image::create_empty(WIDTH, HEIGHT, false, Format::RGBA8).unwrap();
+
+    {
+        let mut view = image.data_view_mut();
+        let mut level = view.level_mut(0).unwrap();
+        let width = level.width();
+        let pixels = level.rgba8_mut().expect("format is RGBA8");
+
+        for (i, pixel) in pixels.iter_mut().enumerate() {
+            let (x, y) = (i as i32 % width, i as i32 / width);
+            *pixel = gradient_pixel(x, y);
+        }
+    }
+
+    assert_gradient(&image);
+}
+
+#[itest]
+fn image_data_view_snapshot() {
+    let mut image = Image::from_rgba8(WIDTH, HEIGHT, &gradient_bytes());
+    let before = image.data_view();
+
+    image.data_view_mut().bytes_mut().fill(0);
+
+    // Earlier view is unaffected, image sees the change.
+    assert_eq!(before.bytes(), gradient_bytes().as_slice());
+    assert_eq!(image.get_pixel(1, 1), Color::from_rgba8(0, 0, 0, 0));
+}
+
+#[itest]
+fn image_data_view_float_formats() {
+    let mut image = Image::create_empty(4, 2, false, Format::RF).unwrap();
+    {
+        let mut view = image.data_view_mut();
+        let mut level = view.level_mut(0).unwrap();
+        assert!(level.rgba8_mut().is_none());
+
+        for (i, value) in level.rf_mut().unwrap().iter_mut().enumerate() {
+            *value = i as f32 * 0.25;
+        }
+    }
+    assert_eq!(image.get_pixel(3, 1).r, 7.0 * 0.25);
+    assert_eq!(image.data_view().level(0).unwrap().rf().unwrap()[5], 1.25);
+
+    let mut image = Image::create_empty(2, 2, false, Format::RGBAF).unwrap();
+    {
+        let mut view = image.data_view_mut();
+        let mut level = view.level_mut(0).unwrap();
+        let index = level.pixel_index(1, 1);
+        level.rgbaf_mut().unwrap()[index] = [0.5, -1.0, 2.0, 1.0];
+    }
+    assert_eq!(image.get_pixel(1, 1), Color::from_rgba(0.5, -1.0, 2.0, 1.0));
+    assert_eq!(image.get_pixel(0, 0), Color::from_rgba(0.0, 0.0, 0.0, 0.0));
+}
+
+#[itest]
+fn image_data_view_mipmaps() {
+    let mut image = Image::from_rgba8(WIDTH, HEIGHT, &gradient_bytes());
+    image.generate_mipmaps();
+
+    let view = image.data_view();
+    assert_eq!(view.level_count(), image.get_mipmap_count() as usize + 1);
+    assert_eq!(view.bytes().len(), image.get_data().len());
+
+    let sizes: Vec<(i32, i32)> = (0..view.level_count())
+        .map(|index| {
+            let level = view.level(index).unwrap();
+            assert_eq!(
+                level.bytes().len(),
+                (level.width() * level.height() * 4) as usize
+            );
+            (level.width(), level.height())
+        })
+        .collect();
+    assert_eq!(sizes, [(16, 8), (8, 4), (4, 2), (2, 1), (1, 1)]);
+
+    // Level 0 is the original image.
+    assert_eq!(view.level(0).unwrap().bytes(), gradient_bytes().as_slice());
+
+    // Writing a mipmap level keeps the other levels intact.
+    {
+        let mut view = image.data_view_mut();
+        view.level_mut(4).unwrap().rgba8_mut().unwrap()[0] = [1, 2, 3, 4];
+    }
+    assert!(image.has_mipmaps());
+    assert_gradient(&image);
+
+    let view = image.data_view();
+    assert_eq!(view.level(4).unwrap().rgba8().unwrap(), &[[1, 2, 3, 4]]);
+}
@@ -0,0 +1,97 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Vector2;
+use godot::classes::Input;
+use godot::register::InputActions;
+use godot::tools::InputActions;
+
+use crate::framework::{itest, suppress_godot_print};
+
+// Actions are declared in the itest project's `project.godot`.
+#[derive(InputActions, Copy, Clone, Eq, PartialEq, Debug)]
+enum ItestAction {
+    #[action(name = "itest_jump")]
+    Jump,
+    #[action(name = "itest_move_left")]
+    Left,
+    #[action(name = "itest_move_right")]
+    Right,
+    #[action(name = "itest_move_up")]
+    Up,
+    #[action(name = "itest_move_down")]
+    Down,
+}
+
+#[derive(InputActions, Copy, Clone, Eq, PartialEq, Debug)]
+enum MixedAction {
+    #[action(name = "itest_jump")]
+    Jump,
+    ItestNotInMap,
+    UIAccept,
+}
+
+#[itest]
+fn input_actions_names() {
+    assert_eq!(ItestAction::ALL.len(), 5);
+    assert_eq!(ItestAction::ALL[1], ItestAction::Left);
+    assert_eq!(ItestAction::Left.action_name(), "itest_move_left");
+    assert_eq!(ItestAction::Left.string_name(), "itest_move_left");
+
+    // Default names are snake_case.
+    assert_eq!(MixedAction::ItestNotInMap.action_name(), "itest_not_in_map");
+    assert_eq!(MixedAction::UIAccept.action_name(), "ui_accept");
+}
+
+#[itest]
+fn input_actions_missing() {
+    assert!(ItestAction::missing_actions().is_empty());
+
+    // First access prints a warning about the missing action in debug builds.
+    suppress_godot_print(|| {
+        let missing = MixedAction::missing_actions();
+        assert_eq!(missing, vec![MixedAction::ItestNotInMap]);
+    });
+}
+
+#[itest]
+fn input_actions_pressed() {
+    let mut input = Input::singleton();
+    assert!(!ItestAction::Jump.pressed());
+
+    input.action_press("itest_jump");
+    assert!(ItestAction::Jump.pressed());
+    assert_eq!(ItestAction::Jump.strength(), 1.0);
+
+    input.action_release("itest_jump");
+    assert!(!ItestAction::Jump.pressed());
+    assert_eq!(ItestAction::Jump.strength(), 0.0);
+}
+
+#[itest]
+fn input_actions_axis_vector() {
+    use ItestAction::*;
+
+    let mut input = Input::singleton();
+    assert_eq!(ItestAction::get_axis(Left, Right), 0.0);
+    assert_eq!(
+        ItestAction::get_vector(Left, Right, Up, Down),
+        Vector2::ZERO
+    );
+
+    input.action_press("itest_move_right");
+    input.action_press("itest_move_up");
+    assert_eq!(ItestAction::get_axis(Left, Right), 1.0);
+
+    let direction = ItestAction::get_vector(Left, Right, Up, Down);
+    assert!(direction.x > 0.0 && direction.y < 0.0);
+    assert!((direction.length() - 1.0).abs() < 1e-5);
+
+    input.action_release("itest_move_right");
+    input.action_release("itest_move_up");
+    assert_eq!(ItestAction::get_axis(Left, Right), 0.0);
+}
@@ -0,0 +1,111 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use godot::classes::{
+    InputEvent, InputEventAction, InputEventJoypadButton, InputEventJoypadMotion, InputEventKey,
+    InputEventMagnifyGesture, InputEventMouseButton, InputEventMouseMotion, InputEventScreenTouch,
+};
+use godot::global::{Key, MouseButton};
+use godot::obj::{Gd, Inherits, NewGd};
+use godot::tools::InputEventView;
+
+use crate::framework::itest;
+
+fn event<T>() -> Gd<InputEvent>
+where
+    T: Inherits<InputEvent> + NewGd,
+{
+    T::new_gd().upcast()
+}
+
+#[itest]
+fn input_event_classify() {
+    let view = event::<InputEventKey>().classify();
+    assert!(matches!(view, InputEventView::Key(_)), "{view:?}");
+
+    let view = event::<InputEventMouseButton>().classify();
+    assert!(matches!(view, InputEventView::MouseButton(_)), "{view:?}");
+
+    let view = event::<InputEventMouseMotion>().classify();
+    assert!(matches!(view, InputEventView::MouseMotion(_)), "{view:?}");
+
+    let view = event::<InputEventJoypadButton>().classify();
+    assert!(matches!(view, InputEventView::JoypadButton(_)), "{view:?}");
+
+    let view = event::<InputEventJoypadMotion>().classify();
+    assert!(matches!(view, InputEventView::JoypadMotion(_)), "{view:?}");
+
+    let view = event::<InputEventScreenTouch>().classify();
+    assert!(matches!(view, InputEventView::ScreenTouch(_)), "{view:?}");
+
+    let view = event::<InputEventAction>().classify();
+    assert!(matches!(view, InputEventView::Action(_)), "{view:?}");
+
+    let view = event::<InputEventMagnifyGesture>().classify();
+    assert!(matches!(view, InputEventView::Other(_)), "{view:?}");
+}
+
+#[itest]
+fn input_event_classify_keeps_object() {
+    let mut key = InputEventKey::new_gd();
+    key.set_keycode(Key::A);
+
+    let original = key.clone().upcast::<InputEvent>();
+    let InputEventView::Key(classified) = InputEventView::classify(original.clone()) else {
+        panic!("expected key event");
+    };
+    assert_eq!(classified, key);
+    assert_eq!(classified.get_keycode(), Key::A);
+
+    let roundtrip = InputEventView::Key(classified).into_event();
+    assert_eq!(roundtrip, original);
+}
+
+#[itest]
+fn input_event_key_helpers() {
+    let mut key = InputEventKey::new_gd();
+    key.set_keycode(Key::ESCAPE);
+    key.set_pressed(true);
+
+    assert!(key.is_key_just_pressed(Key::ESCAPE));
+    assert!(!key.is_key_just_pressed(Key::ENTER));
+    assert!(!key.is_key_released(Key::ESCAPE));
+
+    key.set_echo(true);
+    assert!(
+        !key.is_key_just_pressed(Key::ESCAPE),
+        "echo is not a new press"
+    );
+
+    key.set_echo(false);
+    key.set_pressed(false);
+    assert!(key.is_key_released(Key::ESCAPE));
+    assert!(!key.is_key_just_pressed(Key::ESCAPE));
+
+    // Other event types never match.
+    let motion = InputEventMouseMotion::new_gd();
+    assert!(!motion.is_key_just_pressed(Key::ESCAPE));
+    assert!(!motion.is_key_released(Key::ESCAPE));
+}
+
+#[itest]
+fn input_event_mouse_button_helper() {
+    let mut button = InputEventMouseButton::new_gd();
+    button.set_button_index(MouseButton::LEFT);
+    button.set_pressed(true);
+
+    assert!(button.is_mouse_button_pressed(MouseButton::LEFT));
+    assert!(!button.is_mouse_button_pressed(MouseButton::RIGHT));
+
+    button.set_pressed(false);
+    assert!(!button.is_mouse_button_pressed(MouseButton::LEFT));
+
+    let key = InputEventKey::new_gd();
+    assert!(!key.is_mouse_button_pressed(MouseButton::LEFT));
+}
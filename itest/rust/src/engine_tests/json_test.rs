@@ -0,0 +1,79 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use godot::builtin::{array, dict, Dictionary, GString, VariantArray};
+use godot::classes::Json;
+use godot::meta::error::JsonError;
+
+use crate::framework::itest;
+
+#[itest]
+fn json_parse_to_typed() {
+    let config: Dictionary =
+        Json::parse_to(r#"{ "name": "Ferris", "volume": 0.5, "tags": ["a", "b"] }"#).unwrap();
+
+    assert_eq!(config.at("name").to::<GString>(), GString::from("Ferris"));
+    assert_eq!(config.at("volume").to::<f64>(), 0.5);
+    assert_eq!(config.at("tags").to::<VariantArray>().len(), 2);
+
+    let number: f64 = Json::parse_to("42").unwrap();
+    assert_eq!(number, 42.0);
+
+    let flag: bool = Json::parse_to("true").unwrap();
+    assert!(flag);
+}
+
+#[itest]
+fn json_parse_error_line() {
+    let error_line = |text: &str| match Json::parse_to::<Dictionary>(text).unwrap_err() {
+        JsonError::Parse { line, message } => {
+            assert!(!message.is_empty());
+            line
+        }
+        err => panic!("expected parse error, got {err:?}"),
+    };
+
+    let first = error_line("{ \"a\": ] }");
+    let third = error_line("{\n  \"a\": 1,\n  \"b\": ]\n}");
+    assert_eq!(third - first, 2);
+
+    let err = Json::parse_to::<Dictionary>("[1,").unwrap_err();
+    assert!(
+        err.to_string().starts_with("invalid JSON at line "),
+        "{err}"
+    );
+}
+
+#[itest]
+fn json_parse_wrong_type() {
+    let err = Json::parse_to::<Dictionary>("[1, 2]").unwrap_err();
+
+    assert!(matches!(err, JsonError::WrongType { .. }), "{err:?}");
+}
+
+#[itest]
+fn json_stringify_from_typed() {
+    let dict = dict! { "b": "text", "a": true };
+    assert_eq!(
+        Json::stringify_from(&dict),
+        GString::from(r#"{"a":true,"b":"text"}"#)
+    );
+
+    let array = array![1.5, 2.5];
+    assert_eq!(Json::stringify_from(&array), GString::from("[1.5,2.5]"));
+
+    assert_eq!(
+        Json::stringify_from(&GString::from("quoted \"text\"")),
+        GString::from(r#""quoted \"text\"""#)
+    );
+
+    // Roundtrip.
+    let parsed: Dictionary = Json::parse_to(&Json::stringify_from(&dict)).unwrap();
+    assert_eq!(parsed, dict);
+}
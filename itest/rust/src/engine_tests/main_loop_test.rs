@@ -0,0 +1,162 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// The test runner itself runs inside a SceneTree, so a custom main loop is tested by launching a second, headless Godot process
+// on the same project. Needs ProjectSettings, which is only available in full codegen.
+//
+// MainLoop virtuals are dispatched like those of any other class; these tests guard that. The only MainLoop-specific handling is
+// for panics in process callbacks (see `bool_ret_on_panic` in godot-macros).
+#![cfg(feature = "codegen-full")]
+
+#[cfg(since_api = "4.2")]
+use std::thread::JoinHandle;
+
+use godot::classes::{IMainLoop, MainLoop};
+use godot::global::godot_print;
+use godot::register::{godot_api, GodotClass};
+#[cfg(since_api = "4.2")]
+use godot::task::{GateClosed, MainThreadGate};
+
+use crate::framework::{itest, run_headless_main_loop};
+
+const ITERATIONS: u32 = 3;
+
+/// Runs a fixed number of frames, then quits and reports how often each virtual method was called.
+#[derive(GodotClass)]
+#[class(init, base = MainLoop)]
+struct CountingMainLoop {
+    initialized: bool,
+    process_count: u32,
+    physics_count: u32,
+}
+
+#[godot_api]
+impl IMainLoop for CountingMainLoop {
+    fn initialize(&mut self) {
+        self.initialized = true;
+    }
+
+    fn physics_process(&mut self, _delta: f64) -> bool {
+        self.physics_count += 1;
+        false
+    }
+
+    fn process(&mut self, delta: f32) -> bool {
+        assert!(delta >= 0.0);
+
+        self.process_count += 1;
+        self.process_count >= ITERATIONS
+    }
+
+    fn finalize(&mut self) {
+        // Physics frames depend on timing, so they are reported but not checked.
+        godot_print!(
+            "[CountingMainLoop] initialized={} process={} physics={}",
+            self.initialized,
+            self.process_count,
+            self.physics_count,
+        );
+    }
+}
+
+/// Panics in the second frame; the main loop must quit instead of running on.
+#[derive(GodotClass)]
+#[class(init, base = MainLoop)]
+struct PanickingMainLoop {
+    process_count: u32,
+}
+
+#[godot_api]
+impl IMainLoop for PanickingMainLoop {
+    fn process(&mut self, _delta: f64) -> bool {
+        self.process_count += 1;
+        if self.process_count == 2 {
+            panic!("PanickingMainLoop: intentional panic");
+        }
+
+        false
+    }
+
+    fn finalize(&mut self) {
+        godot_print!("[PanickingMainLoop] process={}", self.process_count);
+    }
+}
+
+/// Waits on a `MainThreadGate` result from a background thread. Without a SceneTree, the gate must close instead of blocking forever.
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass)]
+#[class(init, base = MainLoop)]
+struct GateMainLoop {
+    waiter: Option<JoinHandle<Result<(), GateClosed>>>,
+    process_count: u32,
+}
+
+#[cfg(since_api = "4.2")]
+#[godot_api]
+impl IMainLoop for GateMainLoop {
+    fn initialize(&mut self) {
+        let gate = MainThreadGate::new();
+        self.waiter = Some(std::thread::spawn(move || gate.run(|_ctx| ()).wait()));
+    }
+
+    fn process(&mut self, _delta: f64) -> bool {
+        self.process_count += 1;
+
+        let finished = self
+            .waiter
+            .as_ref()
+            .is_some_and(|waiter| waiter.is_finished());
+        finished || self.process_count >= 300
+    }
+
+    fn finalize(&mut self) {
+        let waiter = self.waiter.take().unwrap();
+        if waiter.is_finished() {
+            godot_print!("[GateMainLoop] wait={:?}", waiter.join().unwrap());
+        } else {
+            godot_print!("[GateMainLoop] wait blocked");
+        }
+    }
+}
+
+#[itest]
+fn main_loop_custom_runs_headless() {
+    let (exit_code, output) = run_headless_main_loop("CountingMainLoop", &[]);
+    assert_eq!(exit_code, 0, "child process failed:\n{output}");
+
+    let expected = format!("[CountingMainLoop] initialized=true process={ITERATIONS} ");
+    assert!(
+        output.contains(&expected),
+        "custom main loop did not run as expected:\n{output}"
+    );
+}
+
+#[itest]
+fn main_loop_custom_quits_on_panic() {
+    let (_exit_code, output) = run_headless_main_loop("PanickingMainLoop", &[]);
+
+    assert!(
+        output.contains("intentional panic"),
+        "panic not reported:\n{output}"
+    );
+    assert!(
+        output.contains("[PanickingMainLoop] process=2"),
+        "main loop did not quit right after the panic:\n{output}"
+    );
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn main_loop_custom_closes_main_thread_gate() {
+    let (exit_code, output) = run_headless_main_loop("GateMainLoop", &[]);
+    assert_eq!(exit_code, 0, "child process failed:\n{output}");
+
+    assert!(
+        output.contains("[GateMainLoop] wait=Err(GateClosed)"),
+        "MainThreadGate did not close without SceneTree:\n{output}"
+    );
+}
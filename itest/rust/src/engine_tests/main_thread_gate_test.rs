@@ -0,0 +1,138 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+use godot::classes::Node;
+use godot::obj::{Gd, NewAlloc};
+use godot::task::{self, GateClosed, MainThreadGate, TaskHandle};
+
+use crate::framework::{expect_panic, itest};
+
+#[itest(async)]
+fn main_thread_gate_from_thread() -> TaskHandle {
+    let node = Node::new_alloc();
+    let node_id = node.instance_id();
+    let gate = MainThreadGate::new();
+
+    let worker = thread::spawn(move || {
+        let result = gate.run(move |_ctx| {
+            assert!(godot::init::is_main_thread());
+
+            let mut node = Gd::<Node>::from_instance_id(node_id);
+            node.set_name("renamed_from_thread");
+            node.get_name().to_string()
+        });
+
+        // Blocks the worker until the main thread has run the closure.
+        result.wait()
+    });
+
+    task::spawn(async move {
+        let observed = node.clone();
+        task::until(move || observed.get_name() == "renamed_from_thread")
+            .await
+            .unwrap();
+
+        let returned = worker.join().expect("worker thread panicked");
+        assert_eq!(returned, Ok("renamed_from_thread".to_string()));
+
+        node.free();
+    })
+}
+
+#[itest(async)]
+fn main_thread_gate_await_result() -> TaskHandle {
+    let gate = MainThreadGate::new();
+
+    task::spawn(async move {
+        let first = gate.run(|ctx| ctx.scene_tree().get_frame());
+        let second = gate.clone().run(|_ctx| "second");
+
+        let frame = first.await.unwrap();
+        assert_eq!(second.await, Ok("second"));
+
+        // Context gives access to the running scene tree.
+        assert!(frame > 0);
+    })
+}
+
+#[itest(async)]
+fn main_thread_gate_runs_in_order() -> TaskHandle {
+    let gate = MainThreadGate::new();
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let results: Vec<_> = (0..3)
+        .map(|i| {
+            let log = log.clone();
+            gate.run(move |_ctx| log.lock().unwrap().push(i))
+        })
+        .collect();
+
+    task::spawn(async move {
+        for result in results {
+            result.await.unwrap();
+        }
+
+        assert_eq!(*log.lock().unwrap(), [0, 1, 2]);
+    })
+}
+
+#[itest]
+fn main_thread_gate_wait_on_main_thread_panics() {
+    let result = MainThreadGate::new().run(|_ctx| ());
+
+    expect_panic("wait() on main thread", || {
+        let _ = result.wait();
+    });
+}
+
+#[itest(async)]
+fn main_thread_gate_shutdown_with_queued_closures() -> TaskHandle {
+    let gate = MainThreadGate::new();
+    let captured = Arc::new(());
+
+    // Worker blocks on a closure that is still queued when the gate shuts down.
+    let (queued_tx, queued_rx) = mpsc::channel();
+    let worker_gate = gate.clone();
+    let worker_captured = captured.clone();
+    let worker = thread::spawn(move || {
+        let result = worker_gate.run(move |_ctx| drop(worker_captured));
+        queued_tx.send(()).unwrap();
+        result.wait()
+    });
+    queued_rx.recv().unwrap();
+
+    let task_captured = captured.clone();
+    let awaited = gate.run(move |_ctx| drop(task_captured));
+    assert_eq!(Arc::strong_count(&captured), 3);
+
+    // Simulates unloading the extension, which drops all queued closures.
+    task::restart_main_thread_gate();
+
+    // Neither deadlocks nor leaks: the waiting thread is released, and the closures (with their captures) are dropped without running.
+    assert_eq!(
+        worker.join().expect("worker thread panicked"),
+        Err(GateClosed)
+    );
+    assert_eq!(Arc::strong_count(&captured), 1);
+    assert!(awaited.is_finished());
+
+    // Gates of the closed channel drop new closures immediately.
+    let late = gate.run(|_ctx| ());
+    assert!(late.is_finished());
+
+    task::spawn(async move {
+        assert_eq!(awaited.await, Err(GateClosed));
+        assert_eq!(late.await, Err(GateClosed));
+
+        // The reopened gate works as usual.
+        let result = MainThreadGate::new().run(|_ctx| 42).await;
+        assert_eq!(result, Ok(42));
+    })
+}
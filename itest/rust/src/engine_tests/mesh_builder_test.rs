@@ -0,0 +1,172 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use godot::builtin::{Color, Vector2, Vector3};
+use godot::classes::mesh::{ArrayType, PrimitiveType};
+use godot::classes::ArrayMesh;
+use godot::meta::error::MeshError;
+use godot::obj::NewGd;
+use godot::tools::MeshBuilder;
+
+use crate::framework::itest;
+
+/// Corners of the unit cube centered at the origin; bit 0, 1, 2 of the index select +x, +y, +z.
+fn cube_vertices() -> Vec<Vector3> {
+    (0..8)
+        .map(|i| {
+            let coord = |bit: i32| if i & bit != 0 { 0.5 } else { -0.5 };
+            Vector3::new(coord(1), coord(2), coord(4))
+        })
+        .collect()
+}
+
+/// Two triangles per face, 12 in total.
+#[rustfmt::skip]
+const CUBE_INDICES: [i32; 36] = [
+    0, 1, 3,  0, 3, 2, // -z
+    4, 6, 7,  4, 7, 5, // +z
+    0, 4, 5,  0, 5, 1, // -y
+    2, 3, 7,  2, 7, 6, // +y
+    0, 2, 6,  0, 6, 4, // -x
+    1, 5, 7,  1, 7, 3, // +x
+];
+
+#[itest]
+fn mesh_builder_cube() {
+    let vertices = cube_vertices();
+    let normals: Vec<Vector3> = vertices.iter().map(|v| v.normalized()).collect();
+    let uvs: Vec<Vector2> = vertices
+        .iter()
+        .map(|v| Vector2::new(v.x, v.y) + Vector2::new(0.5, 0.5))
+        .collect();
+    let colors = vec![Color::from_rgb(1.0, 0.5, 0.0); vertices.len()];
+
+    let mesh = MeshBuilder::new(&vertices)
+        .normals(&normals)
+        .uvs(&uvs)
+        .colors(&colors)
+        .indices(&CUBE_INDICES)
+        .build()
+        .expect("valid cube");
+
+    assert_eq!(mesh.get_surface_count(), 1);
+    assert_eq!(mesh.surface_get_primitive_type(0), PrimitiveType::TRIANGLES);
+    assert_eq!(mesh.get_faces().len(), 12 * 3);
+
+    let aabb = mesh.get_aabb();
+    assert_eq!(aabb.position, Vector3::splat(-0.5));
+    assert_eq!(aabb.size, Vector3::ONE);
+}
+
+#[itest]
+fn mesh_builder_read_back() {
+    let vertices = cube_vertices();
+    let normals: Vec<Vector3> = vertices.iter().map(|v| v.normalized()).collect();
+    let uvs: Vec<Vector2> = vertices.iter().map(|v| Vector2::new(v.x, v.z)).collect();
+
+    let mesh = MeshBuilder::new(&vertices)
+        .normals(&normals)
+        .uvs(&uvs)
+        .indices(&CUBE_INDICES)
+        .build()
+        .unwrap();
+
+    let arrays = mesh.surface_mesh_arrays(0).expect("surface 0 exists");
+    assert_eq!(arrays.vertices, vertices);
+    assert_eq!(arrays.uvs.as_deref(), Some(uvs.as_slice()));
+    assert_eq!(arrays.indices.as_deref(), Some(CUBE_INDICES.as_slice()));
+    assert_eq!(arrays.colors, None);
+    assert_eq!(arrays.uv2s, None);
+
+    // Godot stores normals in a compressed form, so they only come back approximately.
+    let read_normals = arrays.normals.as_ref().expect("normals present");
+    assert_eq!(read_normals.len(), normals.len());
+    for (read, original) in read_normals.iter().zip(&normals) {
+        assert!((*read - *original).length() < 1e-3, "{read} != {original}");
+    }
+
+    // Round-trip into another surface of the same mesh.
+    let mut mesh = mesh;
+    let index = arrays.builder().add_to(&mut mesh).unwrap();
+    assert_eq!(index, 1);
+    assert_eq!(mesh.get_faces().len(), 2 * 12 * 3);
+
+    assert!(mesh.surface_mesh_arrays(2).is_none());
+    assert!(mesh.surface_mesh_arrays(-1).is_none());
+}
+
+#[itest]
+fn mesh_builder_without_indices() {
+    let vertices = [Vector3::ZERO, Vector3::RIGHT, Vector3::UP, Vector3::BACK];
+
+    let mut mesh = ArrayMesh::new_gd();
+    let index = MeshBuilder::new(&vertices)
+        .primitive(PrimitiveType::LINES)
+        .add_to(&mut mesh)
+        .unwrap();
+
+    assert_eq!(index, 0);
+    assert_eq!(mesh.surface_get_primitive_type(0), PrimitiveType::LINES);
+
+    let arrays = mesh.surface_mesh_arrays(0).unwrap();
+    assert_eq!(arrays.vertices, vertices);
+    assert_eq!(arrays.indices, None);
+}
+
+#[itest]
+fn mesh_builder_errors() {
+    let vertices = cube_vertices();
+
+    let err = MeshBuilder::new(&[]).build().unwrap_err();
+    assert_eq!(err, MeshError::MissingChannel(ArrayType::VERTEX));
+
+    let err = MeshBuilder::new(&vertices)
+        .normals(&[Vector3::UP; 3])
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        MeshError::LengthMismatch {
+            channel: ArrayType::NORMAL,
+            expected: 8,
+            actual: 3,
+        }
+    );
+
+    let err = MeshBuilder::new(&vertices)
+        .indices(&[0, 1, 8])
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        MeshError::IndexOutOfRange {
+            index: 8,
+            vertex_count: 8,
+        }
+    );
+
+    let err = MeshBuilder::new(&vertices)
+        .indices(&[0, 1, 2, 3])
+        .to_arrays()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        MeshError::IncompletePrimitive {
+            primitive: PrimitiveType::TRIANGLES,
+            count: 4,
+        }
+    );
+
+    // 8 vertices without indices don't form triangles either.
+    let err = MeshBuilder::new(&vertices).build().unwrap_err();
+    assert!(matches!(
+        err,
+        MeshError::IncompletePrimitive { count: 8, .. }
+    ));
+}
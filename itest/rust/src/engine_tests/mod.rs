@@ -7,14 +7,70 @@
 
 #[cfg(since_api = "4.2")]
 mod async_test;
+mod audio_playback_test;
+#[cfg(since_api = "4.2")]
+mod audio_player_test;
+mod class_db_test;
+mod cmdline_test;
 mod codegen_enums_test;
 mod codegen_test;
+mod curve_builder_test;
+mod dir_test;
+mod editor_export_test;
+mod editor_import_test;
+mod editor_inspector_test;
+#[cfg(since_api = "4.2")]
+mod editor_plugin_test;
 mod engine_enum_test;
+#[cfg(since_api = "4.2")]
+mod frame_callback_test;
+#[cfg(since_api = "4.2")]
+mod frame_futures_test;
+mod gconfig_test;
+mod gd_call_test;
+mod gexpression_test;
+#[cfg(all(feature = "futures-io", since_api = "4.2"))]
+mod gfile_async_test;
 mod gfile_test;
+mod godot_version_test;
+mod gregex_test;
+#[cfg(since_api = "4.2")]
+mod http_test;
+#[cfg(feature = "image-interop")]
+mod image_interop_test;
+mod image_view_test;
+mod input_actions_test;
+mod input_event_test;
+mod json_test;
+mod main_loop_test;
+#[cfg(since_api = "4.2")]
+mod main_thread_gate_test;
+mod mesh_builder_test;
+mod multiplayer_peer_test;
 mod native_st_niche_audio_test;
 mod native_st_niche_pointer_test;
 mod native_structures_test;
+mod net_io_test;
 mod node_test;
+mod physics_extension_test;
+mod physics_query_test;
+mod project_settings_test;
+mod rendering_rid_test;
+mod resource_format_test;
 mod save_load_test;
+mod scene_group_test;
+mod shader_params_test;
+#[cfg(since_api = "4.2")]
+mod signal_stream_test;
+#[cfg(since_api = "4.2")]
+mod threadsafe_signal_test;
+#[cfg(since_api = "4.3")]
+mod tile_map_test;
+#[cfg(all(feature = "tokio", since_api = "4.2"))]
+mod tokio_runtime_test;
 mod translate_test;
+#[cfg(since_api = "4.2")]
+mod tween_builder_test;
 mod utilities_test;
+#[cfg(since_api = "4.2")]
+mod worker_pool_test;
@@ -0,0 +1,288 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use std::collections::VecDeque;
+
+use godot::classes::multiplayer_peer::{ConnectionStatus, TransferMode};
+use godot::classes::{
+    Engine, IMultiplayerPeerExtension, MultiplayerApi, MultiplayerPeerExtension, SceneMultiplayer,
+};
+use godot::global::Error;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+struct Packet {
+    data: Vec<u8>,
+    from: i32,
+    channel: i32,
+    mode: TransferMode,
+}
+
+/// Multiplayer peer which delivers packets directly to another in-process peer.
+#[derive(GodotClass)]
+#[class(no_init, base=MultiplayerPeerExtension)]
+struct LoopbackPeer {
+    unique_id: i32,
+    remote: Option<Gd<LoopbackPeer>>,
+    inbox: VecDeque<Packet>,
+    current: Vec<u8>,
+    announced: bool,
+    target_peer: i32,
+    transfer_channel: i32,
+    transfer_mode: TransferMode,
+    base: Base<MultiplayerPeerExtension>,
+}
+
+impl LoopbackPeer {
+    fn new_pair() -> (Gd<Self>, Gd<Self>) {
+        let make = |unique_id| {
+            Gd::from_init_fn(|base| Self {
+                unique_id,
+                remote: None,
+                inbox: VecDeque::new(),
+                current: Vec::new(),
+                announced: false,
+                target_peer: 0,
+                transfer_channel: 0,
+                transfer_mode: TransferMode::RELIABLE,
+                base,
+            })
+        };
+
+        let mut server = make(1);
+        let mut client = make(2);
+        server.bind_mut().remote = Some(client.clone());
+        client.bind_mut().remote = Some(server.clone());
+
+        (server, client)
+    }
+
+    /// Breaks the reference cycle between the two peers.
+    fn unlink(mut a: Gd<Self>, mut b: Gd<Self>) {
+        a.bind_mut().remote = None;
+        b.bind_mut().remote = None;
+    }
+
+    fn remote_id(&self) -> i32 {
+        let remote = self.remote.as_ref().expect("peers are linked");
+        remote.bind().unique_id
+    }
+}
+
+#[godot_api]
+impl IMultiplayerPeerExtension for LoopbackPeer {
+    // Safe signature, rewritten by #[godot_api] from the raw out-pointer version.
+    fn get_packet(&mut self) -> Result<&[u8], Error> {
+        let packet = self.inbox.pop_front().ok_or(Error::ERR_UNAVAILABLE)?;
+        self.current = packet.data;
+
+        Ok(&self.current)
+    }
+
+    // Safe signature, rewritten by #[godot_api] from the raw pointer + size version.
+    fn put_packet(&mut self, buffer: &[u8]) -> Error {
+        let remote_id = self.remote_id();
+        if self.target_peer != 0
+            && self.target_peer != remote_id
+            && self.target_peer != -self.unique_id
+        {
+            // Addressed to a peer that doesn't exist in this 2-peer setup.
+            return Error::OK;
+        }
+
+        let packet = Packet {
+            data: buffer.to_vec(),
+            from: self.unique_id,
+            channel: self.transfer_channel,
+            mode: self.transfer_mode,
+        };
+
+        let mut remote = self.remote.clone().expect("peers are linked");
+        remote.bind_mut().inbox.push_back(packet);
+
+        Error::OK
+    }
+
+    fn get_available_packet_count(&self) -> i32 {
+        self.inbox.len() as i32
+    }
+
+    fn get_max_packet_size(&self) -> i32 {
+        1 << 20
+    }
+
+    fn get_packet_channel(&self) -> i32 {
+        self.inbox.front().map_or(0, |packet| packet.channel)
+    }
+
+    fn get_packet_mode(&self) -> TransferMode {
+        self.inbox
+            .front()
+            .map_or(TransferMode::RELIABLE, |packet| packet.mode)
+    }
+
+    fn set_transfer_channel(&mut self, channel: i32) {
+        self.transfer_channel = channel;
+    }
+
+    fn get_transfer_channel(&self) -> i32 {
+        self.transfer_channel
+    }
+
+    fn set_transfer_mode(&mut self, mode: TransferMode) {
+        self.transfer_mode = mode;
+    }
+
+    fn get_transfer_mode(&self) -> TransferMode {
+        self.transfer_mode
+    }
+
+    fn set_target_peer(&mut self, peer: i32) {
+        self.target_peer = peer;
+    }
+
+    fn get_packet_peer(&self) -> i32 {
+        self.inbox.front().map_or(0, |packet| packet.from)
+    }
+
+    fn is_server(&self) -> bool {
+        self.unique_id == 1
+    }
+
+    fn poll(&mut self) {
+        if !self.announced {
+            self.announced = true;
+
+            let remote_id = self.remote_id();
+            self.base_mut()
+                .emit_signal("peer_connected", &[(remote_id as i64).to_variant()]);
+        }
+    }
+
+    fn close(&mut self) {}
+
+    fn disconnect_peer(&mut self, _peer: i32, _force: bool) {}
+
+    fn get_unique_id(&self) -> i32 {
+        self.unique_id
+    }
+
+    fn get_connection_status(&self) -> ConnectionStatus {
+        ConnectionStatus::CONNECTED
+    }
+
+    fn is_server_relay_supported(&self) -> bool {
+        true
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct RpcReceiver {
+    received: Vec<(i64, i64)>,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl RpcReceiver {
+    #[rpc(any_peer, call_remote, reliable)]
+    fn ping(&mut self, value: i64) {
+        let sender = self
+            .base()
+            .get_multiplayer()
+            .expect("multiplayer is set")
+            .get_remote_sender_id();
+
+        self.received.push((sender as i64, value));
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// One side of the connection: a subtree with its own multiplayer API.
+struct Side {
+    root: Gd<Node>,
+    receiver: Gd<RpcReceiver>,
+    multiplayer: Gd<SceneMultiplayer>,
+}
+
+impl Side {
+    fn new(tree: &mut Gd<SceneTree>, name: &str, peer: &Gd<LoopbackPeer>) -> Self {
+        let mut root = Node::new_alloc();
+        root.set_name(name);
+        tree.get_root().unwrap().add_child(&root);
+
+        let mut receiver = RpcReceiver::new_alloc();
+        receiver.set_name("Receiver");
+        root.add_child(&receiver);
+
+        let mut multiplayer = SceneMultiplayer::new_gd();
+        multiplayer.set_multiplayer_peer(peer);
+        tree.set_multiplayer_ex(&multiplayer)
+            .root_path(&root.get_path())
+            .done();
+
+        Self {
+            root,
+            receiver,
+            multiplayer,
+        }
+    }
+
+    fn free(self, tree: &mut Gd<SceneTree>) {
+        tree.set_multiplayer_ex(Gd::<MultiplayerApi>::null_arg())
+            .root_path(&self.root.get_path())
+            .done();
+
+        self.root.free();
+    }
+}
+
+fn poll_both(a: &mut Side, b: &mut Side) {
+    for _ in 0..10 {
+        a.multiplayer.poll();
+        b.multiplayer.poll();
+    }
+}
+
+#[itest]
+fn multiplayer_peer_extension_rpc() {
+    let mut tree = Engine::singleton()
+        .get_main_loop()
+        .unwrap()
+        .cast::<SceneTree>();
+
+    let (server_peer, client_peer) = LoopbackPeer::new_pair();
+    let mut server = Side::new(&mut tree, "MpServer", &server_peer);
+    let mut client = Side::new(&mut tree, "MpClient", &client_peer);
+
+    poll_both(&mut server, &mut client);
+    assert_eq!(server.multiplayer.get_peers().as_slice(), &[2]);
+    assert_eq!(client.multiplayer.get_peers().as_slice(), &[1]);
+
+    // Client -> server.
+    let err = client.receiver.rpc_id(1, "ping", &[42.to_variant()]);
+    assert_eq!(err, Error::OK);
+    poll_both(&mut server, &mut client);
+    assert_eq!(server.receiver.bind().received, [(2, 42)]);
+
+    // Server -> all clients.
+    let err = server.receiver.rpc("ping", &[7.to_variant()]);
+    assert_eq!(err, Error::OK);
+    poll_both(&mut server, &mut client);
+    assert_eq!(client.receiver.bind().received, [(1, 7)]);
+
+    // No echo back to the sender (call_remote).
+    assert_eq!(server.receiver.bind().received, [(2, 42)]);
+
+    server.free(&mut tree);
+    client.free(&mut tree);
+    LoopbackPeer::unlink(server_peer, client_peer);
+}
@@ -0,0 +1,159 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use std::io::{ErrorKind, Read, Write};
+use std::time::{Duration, Instant};
+
+use godot::builtin::GString;
+use godot::classes::{StreamPeerBuffer, StreamPeerTcp, TcpServer};
+use godot::global::Error;
+use godot::obj::{Gd, NewGd};
+use godot::tools::{GPacketPeer, GStream};
+
+use crate::framework::itest;
+
+/// Repeats `f` until it returns `Some`, failing the test after a few seconds.
+fn poll_until<T>(what: &str, mut f: impl FnMut() -> Option<T>) -> T {
+    let start = Instant::now();
+    loop {
+        if let Some(value) = f() {
+            return value;
+        }
+
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "timed out waiting for {what}"
+        );
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+fn free_tcp_port() -> u16 {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind ephemeral port");
+    listener.local_addr().unwrap().port()
+}
+
+fn free_udp_port() -> u16 {
+    let socket = std::net::UdpSocket::bind("127.0.0.1:0").expect("bind ephemeral port");
+    socket.local_addr().unwrap().port()
+}
+
+/// Reads exactly `len` bytes from a non-blocking stream.
+fn read_exact_polling(stream: &mut GStream, len: usize) -> Vec<u8> {
+    let mut received = Vec::new();
+    let mut buf = [0; 64];
+
+    poll_until("stream data", || {
+        match stream.read(&mut buf) {
+            Ok(0) => panic!("connection closed prematurely"),
+            Ok(n) => received.extend_from_slice(&buf[..n]),
+            Err(e) if e.kind() == ErrorKind::WouldBlock => {}
+            Err(e) => panic!("read failed: {e}"),
+        }
+
+        (received.len() >= len).then_some(())
+    });
+
+    received
+}
+
+fn tcp_pair() -> (Gd<TcpServer>, GStream, GStream) {
+    let port = free_tcp_port();
+
+    let mut server = TcpServer::new_gd();
+    let err = server.listen_ex(port).bind_address("127.0.0.1").done();
+    assert_eq!(err, Error::OK);
+
+    let mut client = GStream::connect_tcp("127.0.0.1", port).expect("connect_tcp");
+
+    let accepted = poll_until("incoming connection", || {
+        // Reading polls the client as well, so it advances from CONNECTING to CONNECTED.
+        let _ = client.read(&mut [0; 1]);
+        server.take_connection()
+    });
+
+    (server, client, GStream::new(accepted))
+}
+
+#[itest]
+fn gstream_tcp_loopback() {
+    let (mut server, mut client, mut accepted) = tcp_pair();
+
+    // Nothing sent yet: non-blocking read must not wait.
+    let err = accepted.read(&mut [0; 8]).expect_err("no data yet");
+    assert_eq!(err.kind(), ErrorKind::WouldBlock);
+
+    poll_until("client write", || match client.write_all(b"ping") {
+        Ok(()) => Some(()),
+        Err(e) if e.kind() == ErrorKind::WouldBlock => None,
+        Err(e) => panic!("write failed: {e}"),
+    });
+    assert_eq!(read_exact_polling(&mut accepted, 4), b"ping");
+
+    accepted.write_all(b"pong").expect("server write");
+    assert_eq!(read_exact_polling(&mut client, 4), b"pong");
+
+    server.stop();
+}
+
+#[itest]
+fn gstream_tcp_eof_after_disconnect() {
+    let (mut server, mut client, accepted) = tcp_pair();
+
+    let mut accepted = accepted.into_inner().cast::<StreamPeerTcp>();
+    accepted.disconnect_from_host();
+
+    let mut buf = [0; 8];
+    poll_until("end of stream", || match client.read(&mut buf) {
+        Ok(0) => Some(()),
+        Ok(n) => panic!("unexpected data: {:?}", &buf[..n]),
+        Err(e) if e.kind() == ErrorKind::WouldBlock => None,
+        // Some platforms report a reset instead of an orderly shutdown.
+        Err(e) if e.kind() == ErrorKind::ConnectionReset => Some(()),
+        Err(e) => panic!("read failed: {e}"),
+    });
+
+    server.stop();
+}
+
+#[itest]
+fn gstream_buffer_peer() {
+    let mut buffer = StreamPeerBuffer::new_gd();
+    buffer.put_data(&b"hello"[..].into());
+    buffer.seek(0);
+
+    let mut stream = GStream::new(buffer);
+    let mut content = String::new();
+    stream.read_to_string(&mut content).expect("read_to_string");
+
+    assert_eq!(content, "hello");
+}
+
+#[itest]
+fn gpacket_peer_udp_loopback() {
+    let port = free_udp_port();
+
+    let mut server = GPacketPeer::bind_udp(port).expect("bind_udp");
+    let mut client = GPacketPeer::connect_udp("127.0.0.1", port).expect("connect_udp");
+
+    assert_eq!(server.recv(), None);
+
+    client.send(b"first").expect("send");
+    client.send(b"second").expect("send");
+
+    let first = poll_until("first packet", || server.recv());
+    let second = poll_until("second packet", || server.recv());
+    assert_eq!(first, b"first");
+    assert_eq!(second, b"second");
+
+    let (ip, _port) = server.last_sender().expect("UDP peer has sender");
+    assert_eq!(ip, GString::from("127.0.0.1"));
+
+    assert_eq!(server.recv(), None);
+}
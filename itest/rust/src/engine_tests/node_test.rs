@@ -7,12 +7,22 @@
 
 use std::str::FromStr;
 
-use godot::builtin::{NodePath, Variant};
-use godot::classes::{Node, Node3D, PackedScene, SceneTree};
+#[cfg(since_api = "4.2")]
+use godot::builtin::Signal;
+use godot::builtin::{NodePath, Variant, Vector3};
+use godot::classes::node::InternalMode;
+use godot::classes::packed_scene::GenEditState;
+use godot::classes::{INode2D, Node, Node2D, Node3D, PackedScene, SceneTree};
 use godot::global;
-use godot::obj::{NewAlloc, NewGd};
+use godot::meta::error::{InstantiateError, UniqueNodeError};
+use godot::meta::ToGodot;
+use godot::obj::{Base, Gd, GodotClass, Inherits, NewAlloc, NewGd};
+use godot::register::{godot_api, GodotClass};
+#[cfg(since_api = "4.2")]
+use godot::task::{self, TaskHandle};
+use godot::tools::NodeProcessing;
 
-use crate::framework::{itest, TestContext};
+use crate::framework::{expect_panic, itest, suppress_godot_print, TestContext};
 
 #[itest]
 fn node_get_node() {
@@ -51,6 +61,389 @@ fn node_get_node_fail() {
     child.free();
 }
 
+#[itest]
+fn node_get_parent_and_owner_as() {
+    let mut root = named::<Node3D>("root");
+    let mut child = named::<Node2D>("child");
+    let mut grandchild = named::<Node>("grandchild");
+
+    root.add_child(&child);
+    child.add_child(&grandchild);
+    child.set_owner(&root);
+    grandchild.set_owner(&root);
+
+    // Parent, with correct and wrong type.
+    let parent = grandchild
+        .get_parent_as::<Node2D>()
+        .expect("parent is Node2D");
+    assert_eq!(parent, child);
+    assert!(grandchild.get_parent_as::<Node3D>().is_none());
+    assert!(root.get_parent_as::<Node>().is_none());
+
+    // Owner, with correct and wrong type.
+    let owner = grandchild
+        .get_owner_as::<Node3D>()
+        .expect("owner is Node3D");
+    assert_eq!(owner, root);
+    assert!(grandchild.get_owner_as::<Node2D>().is_none());
+    assert!(root.get_owner_as::<Node>().is_none());
+    assert_eq!(grandchild.owner(), Some(root.clone().upcast::<Node>()));
+    assert!(root.owner().is_none());
+
+    assert!(root.is_ancestor_of(&grandchild));
+    assert!(!grandchild.is_ancestor_of(&root));
+    assert!(!grandchild.is_inside_tree());
+
+    root.free();
+}
+
+#[itest]
+fn node_get_unique_node() {
+    let mut root = Node::new_alloc();
+
+    let mut container = Node::new_alloc();
+    root.add_child(&container);
+    container.set_owner(&root);
+
+    let mut player = Node2D::new_alloc();
+    player.set_name("Player");
+    container.add_child(&player);
+    player.set_owner(&root);
+    player.set_unique_name_in_owner(true);
+
+    // From the scene root.
+    let found = root.get_unique_node_as::<Node2D>("Player");
+    assert_eq!(found, player);
+
+    // From another node in the same scene, with explicit prefix.
+    let found = container.try_get_unique_node_as::<Node>("%Player");
+    assert_eq!(found, Ok(player.clone().upcast()));
+
+    let err = root
+        .try_get_unique_node_as::<Node3D>("Player")
+        .expect_err("wrong type");
+    assert_eq!(
+        err,
+        UniqueNodeError::WrongType {
+            name: "Player".into(),
+            expected: Node3D::class_name(),
+            actual: "Node2D".into(),
+        }
+    );
+
+    let err = root
+        .try_get_unique_node_as::<Node2D>("Enemy")
+        .expect_err("not found");
+    assert_eq!(
+        err,
+        UniqueNodeError::NotFound {
+            name: "Enemy".into()
+        }
+    );
+
+    root.free();
+}
+
+#[itest]
+fn node_get_unique_node_panics() {
+    let root = Node::new_alloc();
+
+    expect_panic("unique node not found", || {
+        root.get_unique_node_as::<Node>("Missing");
+    });
+
+    root.free();
+}
+
+#[itest]
+fn node_children_of_type() {
+    let root = make_mixed_tree();
+
+    assert_eq!(names(root.children_of_type::<Node3D>(false)), ["a"]);
+    assert_eq!(
+        names(root.children_of_type::<Node3D>(true)),
+        ["internal", "a"]
+    );
+    assert_eq!(names(root.children_of_type::<Node>(false)), ["a", "b"]);
+
+    let b = root
+        .first_child_of_type::<Node2D>(false)
+        .expect("child of type Node2D");
+    assert_eq!(b.get_name(), "b");
+
+    let a2 = root.get_node_as::<Node3D>("a/a2");
+    assert!(a2.first_child_of_type::<Node>(true).is_none());
+    assert_eq!(a2.children_of_type::<Node>(true).count(), 0);
+
+    root.free();
+}
+
+#[itest]
+fn node_descendants_of_type() {
+    let root = make_mixed_tree();
+
+    assert_eq!(
+        names(root.descendants_of_type::<Node3D>(false)),
+        ["a", "a2", "b1"]
+    );
+    assert_eq!(
+        names(root.descendants_of_type::<Node3D>(true)),
+        ["internal", "internal_child", "a", "a2", "b1"]
+    );
+    assert_eq!(
+        names(root.descendants_of_type::<Node2D>(false)),
+        ["a1", "b"]
+    );
+    assert_eq!(
+        names(root.descendants_of_type::<Node>(false)),
+        ["a", "a1", "a2", "b", "b1"]
+    );
+
+    root.free();
+}
+
+#[itest]
+fn node_find_children_as() {
+    let mut root = Node::new_alloc();
+    let enemy1 = named::<Node2D>("Enemy1");
+    let enemy2 = named::<Node3D>("Enemy2");
+    let mut group = named::<Node>("Group");
+    let boss = named::<Node2D>("EnemyBoss");
+    root.add_child(&enemy1);
+    root.add_child(&enemy2);
+    root.add_child(&group);
+    group.add_child(&boss);
+
+    // Not owned yet.
+    assert!(root
+        .find_children_as::<Node2D>("Enemy*", true, true)
+        .is_empty());
+    assert_eq!(
+        names(
+            root.find_children_as::<Node2D>("Enemy*", true, false)
+                .into_iter()
+        ),
+        ["Enemy1", "EnemyBoss"]
+    );
+    assert_eq!(
+        names(
+            root.find_children_as::<Node2D>("Enemy*", false, false)
+                .into_iter()
+        ),
+        ["Enemy1"]
+    );
+    assert_eq!(
+        names(
+            root.find_children_as::<Node>("Enemy?", true, false)
+                .into_iter()
+        ),
+        ["Enemy1", "Enemy2"]
+    );
+
+    // Matching names, but wrong type.
+    assert!(root
+        .find_children_as::<Node3D>("Enemy1", true, false)
+        .is_empty());
+    // No matching names.
+    assert!(root
+        .find_children_as::<Node>("Friend*", true, false)
+        .is_empty());
+
+    for mut node in root.find_children_as::<Node>("*", true, false) {
+        node.set_owner(&root);
+    }
+
+    let found = root
+        .find_child_as::<Node3D>("Enemy*")
+        .expect("Node3D named Enemy*");
+    assert_eq!(found.get_name(), "Enemy2");
+    let found = root
+        .find_child_as::<Node2D>("*Boss")
+        .expect("Node2D named *Boss");
+    assert_eq!(found.get_name(), "EnemyBoss");
+    assert!(root.find_child_as::<Node3D>("Group").is_none());
+    assert!(root.find_child_as::<Node>("Friend").is_none());
+
+    root.free();
+}
+
+#[itest]
+fn node_spawn_owned() {
+    let root = named::<Node>("root");
+
+    let mut prefab = Node3D::new_alloc();
+    prefab.add_child(&named::<Node2D>("inner"));
+
+    let spawned: Gd<Node3D> = root.spawn(prefab).name("Spawned").owned_by(&root).done();
+
+    assert_eq!(spawned.get_name(), "Spawned");
+    assert_eq!(spawned.get_parent(), Some(root.clone()));
+    assert_eq!(spawned.get_owner(), Some(root.clone()));
+    let inner = spawned.get_node_as::<Node2D>("inner");
+    assert_eq!(inner.get_owner(), Some(root.clone()));
+
+    // Owned nodes are what the editor saves for the edited scene; packing is the headless equivalent.
+    let mut scene = PackedScene::new_gd();
+    assert_eq!(scene.pack(&root), global::Error::OK);
+    root.free();
+
+    let instance = scene.instantiate_as::<Node>();
+    assert!(instance.try_get_node_as::<Node3D>("Spawned").is_some());
+    assert!(instance
+        .try_get_node_as::<Node2D>("Spawned/inner")
+        .is_some());
+    instance.free();
+}
+
+#[itest]
+fn node_spawn_keeps_existing_owners() {
+    let root = named::<Node>("root");
+
+    let mut prefab = named::<Node3D>("prefab");
+    let mut inner = named::<Node2D>("inner");
+    prefab.add_child(&inner);
+    inner.set_owner(&prefab);
+
+    let spawned = root
+        .spawn(prefab)
+        .internal(InternalMode::BACK)
+        .owned_by(&root)
+        .done();
+
+    assert_eq!(spawned.get_name(), "prefab");
+    assert_eq!(root.get_child_count(), 0, "child should be internal");
+    assert_eq!(root.get_child_count_ex().include_internal(true).done(), 1);
+    assert_eq!(inner.get_owner(), Some(spawned.upcast()));
+
+    root.free();
+}
+
+#[itest]
+fn node_add_child_editor() {
+    let mut root = named::<Node>("root");
+    let mut parent = named::<Node3D>("parent");
+    root.add_child(&parent);
+    parent.set_owner(&root);
+
+    // Sub-scene whose inner node is owned by the sub-scene root.
+    let mut sub_scene = named::<Node3D>("sub_scene");
+    let mut sub_inner = named::<Node2D>("sub_inner");
+    sub_scene.add_child(&sub_inner);
+    sub_inner.set_owner(&sub_scene);
+
+    let mut child = named::<Node3D>("child");
+    let mut grandchild = named::<Node3D>("grandchild");
+    grandchild.add_child(&named::<Node2D>("leaf"));
+    child.add_child(&grandchild);
+    child.add_child(&sub_scene);
+
+    parent.add_child_editor(&child, &root);
+
+    assert_eq!(child.get_parent(), Some(parent.clone().upcast()));
+    assert_eq!(child.get_owner(), Some(root.clone()));
+    assert_eq!(grandchild.get_owner(), Some(root.clone()));
+    let leaf = child.get_node_as::<Node2D>("grandchild/leaf");
+    assert_eq!(leaf.get_owner(), Some(root.clone()));
+    assert_eq!(sub_scene.get_owner(), Some(root.clone()));
+    assert_eq!(sub_inner.get_owner(), Some(sub_scene.clone().upcast()));
+
+    root.free();
+}
+
+#[itest]
+fn node_add_child_editor_owner_not_ancestor() {
+    let mut parent = named::<Node>("parent");
+    let stranger = named::<Node>("stranger");
+    let child = named::<Node>("child");
+
+    expect_panic("owner must be an ancestor", || {
+        parent.add_child_editor(&child, &stranger);
+    });
+    assert_eq!(child.get_parent(), None);
+
+    child.free();
+    stranger.free();
+    parent.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest(async)]
+fn node_spawn_deferred(ctx: &TestContext) -> TaskHandle {
+    let parent = ctx.scene_tree.clone();
+    let owner = parent.clone();
+
+    let spawned = parent
+        .spawn(Node2D::new_alloc())
+        .name("DeferredSpawn")
+        .owned_by(&owner)
+        .deferred()
+        .done();
+
+    // Returned immediately, but not yet added.
+    assert_eq!(spawned.get_name(), "DeferredSpawn");
+    assert!(spawned.get_parent().is_none());
+
+    let tree = parent.get_tree().unwrap();
+    let process_frame = Signal::from_object_signal(&tree, "process_frame");
+
+    task::spawn(async move {
+        process_frame.to_future::<()>().await;
+
+        assert_eq!(spawned.get_parent(), Some(parent));
+        assert_eq!(spawned.get_owner(), Some(owner));
+        spawned.free();
+    })
+}
+
+/// ```text
+/// root: Node
+/// ├── internal: Node3D (internal)
+/// │   └── internal_child: Node3D
+/// ├── a: Node3D
+/// │   ├── a1: Node2D
+/// │   └── a2: Node3D
+/// └── b: Node2D
+///     └── b1: Node3D
+/// ```
+fn make_mixed_tree() -> Gd<Node> {
+    let mut root = Node::new_alloc();
+
+    let mut internal = named::<Node3D>("internal");
+    internal.add_child(&named::<Node3D>("internal_child"));
+    root.add_child_ex(&internal)
+        .internal(InternalMode::FRONT)
+        .done();
+
+    let mut a = named::<Node3D>("a");
+    a.add_child(&named::<Node2D>("a1"));
+    a.add_child(&named::<Node3D>("a2"));
+    root.add_child(&a);
+
+    let mut b = named::<Node2D>("b");
+    b.add_child(&named::<Node3D>("b1"));
+    root.add_child(&b);
+
+    root
+}
+
+fn named<T>(name: &str) -> Gd<T>
+where
+    T: Inherits<Node> + NewAlloc,
+{
+    let mut node = T::new_alloc();
+    node.upcast_mut::<Node>().set_name(name);
+    node
+}
+
+fn names<T>(nodes: impl Iterator<Item = Gd<T>>) -> Vec<String>
+where
+    T: Inherits<Node>,
+{
+    nodes
+        .map(|node| node.upcast::<Node>().get_name().to_string())
+        .collect()
+}
+
 #[itest]
 fn node_path_from_str(ctx: &TestContext) {
     let child = ctx.scene_tree.clone();
@@ -84,6 +477,220 @@ fn node_scene_tree() {
     child.free();
 }
 
+#[itest]
+fn packed_scene_instantiate_as() {
+    let mut root = Node3D::new_alloc();
+    root.set_name("root");
+
+    let mut child = Node2D::new_alloc();
+    child.set_name("child");
+    root.add_child(&child);
+    child.set_owner(&root);
+
+    let mut scene = PackedScene::new_gd();
+    let err = scene.pack(&root);
+    assert_eq!(err, global::Error::OK);
+    root.free();
+
+    let instance = scene.instantiate_as::<Node3D>();
+    assert_eq!(instance.get_name(), "root");
+    assert!(instance.try_get_node_as::<Node2D>("child").is_some());
+    instance.free();
+
+    let instance = scene
+        .try_instantiate_as::<Node>()
+        .expect("root inherits Node");
+    instance.free();
+
+    assert!(scene.try_instantiate_as::<Node2D>().is_none());
+
+    let instance = scene
+        .try_instantiate_with_state::<Node3D>(GenEditState::INSTANCE)
+        .expect("scene instantiates with edit state");
+    instance.free();
+
+    let err = scene
+        .try_instantiate_with_state::<Node2D>(GenEditState::DISABLED)
+        .expect_err("root is not Node2D");
+    assert_eq!(
+        err,
+        InstantiateError::WrongType {
+            expected: Node2D::class_name(),
+            actual: "Node3D".into(),
+        }
+    );
+}
+
+#[itest]
+fn packed_scene_instantiate_empty() {
+    let scene = PackedScene::new_gd();
+
+    suppress_godot_print(|| {
+        let err = scene
+            .try_instantiate_with_state::<Node>(GenEditState::DISABLED)
+            .expect_err("empty scene cannot be instantiated");
+        assert_eq!(err, InstantiateError::Failed);
+    });
+}
+
+#[itest]
+fn packed_scene_pack_node() {
+    // Built in Rust, without any owners.
+    let root = make_mixed_tree();
+    assert!(root
+        .descendants_of_type::<Node>(false)
+        .all(|node| node.get_owner().is_none()));
+
+    let scene = PackedScene::pack_node(&root).expect("tree can be packed");
+
+    // Owners were assigned, except for the internal subtree.
+    let a1 = root.get_node_as::<Node2D>("a/a1");
+    assert_eq!(a1.get_owner(), Some(root.clone()));
+    assert_eq!(root.get_owner(), None);
+    let internal_child = root.get_node_as::<Node3D>("internal/internal_child");
+    assert_eq!(internal_child.get_owner(), None);
+
+    let instance = scene.instantiate_as::<Node>();
+    assert!(instance.try_get_node_as::<Node2D>("a/a1").is_some());
+    assert!(instance.try_get_node_as::<Node3D>("a/a2").is_some());
+    assert!(instance.try_get_node_as::<Node3D>("b/b1").is_some());
+    assert!(instance.try_get_node_as::<Node3D>("internal").is_none());
+
+    instance.free();
+    root.free();
+}
+
+#[itest]
+fn node_set_descendants_owner() {
+    let root = make_mixed_tree();
+    let a = root.get_node_as::<Node3D>("a");
+    let mut a2 = root.get_node_as::<Node3D>("a/a2");
+
+    // Existing owners are kept.
+    a2.set_owner(&a);
+    a.set_descendants_owner(&root);
+
+    assert_eq!(
+        root.get_node_as::<Node>("a/a1").get_owner(),
+        Some(root.clone())
+    );
+    assert_eq!(a2.get_owner(), Some(a.clone().upcast()));
+
+    // Nodes outside the subtree, and the node itself, are not touched.
+    assert_eq!(a.get_owner(), None);
+    assert_eq!(root.get_node_as::<Node>("b/b1").get_owner(), None);
+
+    root.free();
+}
+
+#[itest]
+fn node_reparent_to(ctx: &TestContext) {
+    let mut root = named::<Node3D>("root");
+    ctx.scene_tree.clone().add_child(&root);
+
+    let mut a = named::<Node3D>("a");
+    let mut b = named::<Node3D>("b");
+    a.set_position(Vector3::new(10.0, 0.0, 0.0));
+    b.set_position(Vector3::new(100.0, 0.0, 0.0));
+    root.add_child(&a);
+    root.add_child(&b);
+
+    let mut child = named::<Node3D>("child");
+    child.set_position(Vector3::new(1.0, 0.0, 0.0));
+
+    // Orphan is added as child, with its local transform (keep_global_transform does not apply).
+    child.reparent_to(&a, true);
+    assert_eq!(child.get_parent(), Some(a.clone().upcast()));
+    assert_eq!(child.get_position(), Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(child.get_global_position(), Vector3::new(11.0, 0.0, 0.0));
+
+    // Global transform kept, local one adjusted.
+    child.reparent_to(&b, true);
+    assert_eq!(child.get_parent(), Some(b.clone().upcast()));
+    assert_eq!(child.get_global_position(), Vector3::new(11.0, 0.0, 0.0));
+    assert_eq!(child.get_position(), Vector3::new(-89.0, 0.0, 0.0));
+
+    // Local transform kept, node moves with the parent.
+    child.reparent_to(&a, false);
+    assert_eq!(child.get_parent(), Some(a.clone().upcast()));
+    assert_eq!(child.get_position(), Vector3::new(-89.0, 0.0, 0.0));
+    assert_eq!(child.get_global_position(), Vector3::new(-79.0, 0.0, 0.0));
+
+    // Same parent: no-op.
+    child.reparent_to(&a, true);
+    assert_eq!(child.get_parent(), Some(a.clone().upcast()));
+    assert_eq!(a.get_child_count(), 1);
+
+    root.free();
+}
+
+#[itest]
+fn node_reparent_to_descendant() {
+    let mut parent = named::<Node>("parent");
+    let child = named::<Node>("child");
+    parent.add_child(&child);
+
+    expect_panic("reparent below own child", || {
+        parent.reparent_to(&child, false);
+    });
+    expect_panic("reparent below itself", || {
+        parent.clone().reparent_to(&parent, false);
+    });
+    assert_eq!(child.get_parent(), Some(parent.clone()));
+
+    parent.free();
+}
+
+#[itest]
+fn node_replace_with() {
+    let mut root = named::<Node>("root");
+    let first = named::<Node>("first");
+    let mut old = named::<Node>("old");
+    let last = named::<Node>("last");
+    root.add_child(&first);
+    root.add_child(&old);
+    root.add_child(&last);
+
+    let grandchild = named::<Node2D>("grandchild");
+    old.add_child(&grandchild);
+    old.add_to_group("replaceable");
+
+    let replacement = named::<Node2D>("replacement");
+    old.replace_with(&replacement, true);
+
+    // Same index, children and groups moved.
+    assert_eq!(replacement.get_parent(), Some(root.clone()));
+    assert_eq!(replacement.get_index(), 1);
+    assert_eq!(grandchild.get_parent(), Some(replacement.clone().upcast()));
+    assert!(replacement.is_in_group("replaceable"));
+
+    // Old node is an orphan, freed by the caller exactly once; freeing the tree doesn't touch it.
+    assert_eq!(old.get_parent(), None);
+    assert_eq!(old.get_child_count(), 0);
+    old.free();
+
+    assert!(grandchild.is_instance_valid());
+    root.free();
+    assert!(!grandchild.is_instance_valid());
+}
+
+#[itest]
+fn node_replace_with_parented() {
+    let mut old = named::<Node>("old");
+    let child = named::<Node>("child");
+    old.add_child(&child);
+
+    expect_panic("replacement has parent", || {
+        old.replace_with(&child, false);
+    });
+    expect_panic("replacement is self", || {
+        old.clone().replace_with(&old, false);
+    });
+    assert_eq!(child.get_parent(), Some(old.clone()));
+
+    old.free();
+}
+
 #[itest]
 fn node_call_group(ctx: &TestContext) {
     let mut node = ctx.scene_tree.clone();
@@ -92,3 +699,72 @@ fn node_call_group(ctx: &TestContext) {
     node.add_to_group("group");
     tree.call_group("group", "set_name", &[Variant::from("name")]);
 }
+
+#[itest]
+fn node_processing_toggles_from_ready(ctx: &TestContext) {
+    let mut node = ProcessToggler::new_alloc();
+    node.set_process(true);
+    node.set_physics_process(true);
+    node.set_process_input(true);
+
+    // ready() disables processing through self, while Godot holds the bind_mut() for the virtual call.
+    let mut root = ctx.scene_tree.clone();
+    root.add_child(&node);
+
+    assert!(!node.is_processing());
+    assert!(!node.is_physics_processing());
+    assert!(!node.is_processing_input());
+    assert!(node.bind().was_processing_in_ready);
+
+    root.remove_child(&node);
+    node.free();
+}
+
+#[itest]
+fn node_processing_toggles_from_func() {
+    let mut node = ProcessToggler::new_alloc();
+
+    node.bind_mut().toggle_all(true);
+    assert!(node.is_processing());
+    assert!(node.is_physics_processing());
+    assert!(node.is_processing_input());
+    assert!(node.bind().is_processing());
+
+    // Via Godot, re-entering the instance.
+    node.call("toggle_all", &[false.to_variant()]);
+    assert!(!node.is_processing());
+    assert!(!node.bind().is_physics_processing());
+
+    node.free();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helper types
+
+#[derive(GodotClass)]
+#[class(init, base=Node2D)]
+struct ProcessToggler {
+    was_processing_in_ready: bool,
+    base: Base<Node2D>,
+}
+
+#[godot_api]
+impl INode2D for ProcessToggler {
+    fn ready(&mut self) {
+        self.was_processing_in_ready = self.is_processing();
+
+        self.set_process(false);
+        self.set_physics_process(false);
+        self.set_process_input(false);
+    }
+}
+
+#[godot_api]
+impl ProcessToggler {
+    #[func]
+    fn toggle_all(&mut self, enable: bool) {
+        self.set_process(enable);
+        self.set_physics_process(enable);
+        self.set_process_input(enable);
+    }
+}
@@ -0,0 +1,269 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// A full IPhysicsServer3DExtension has hundreds of required methods; these tests only implement a space state, which is queried through
+// the public PhysicsDirectSpaceState3D API (and thus through Godot's dispatch to the virtual methods).
+#![cfg(feature = "codegen-full")]
+
+use std::ffi::c_void;
+
+use godot::builtin::{Rid, Transform3D, Vector3};
+use godot::classes::native::{
+    PhysicsServer3DExtensionRayResult, PhysicsServer3DExtensionShapeRestInfo,
+    PhysicsServer3DExtensionShapeResult,
+};
+#[cfg(since_api = "4.2")]
+use godot::classes::PhysicsServer3DExtension;
+use godot::classes::{
+    IPhysicsDirectSpaceState3DExtension, Object, PhysicsDirectSpaceState3D,
+    PhysicsDirectSpaceState3DExtension,
+};
+use godot::obj::{Base, Gd, NewAlloc, WithBaseField};
+use godot::register::{godot_api, GodotClass};
+#[cfg(since_api = "4.2")]
+use godot::tools::{create_test_physics_server_3d_factory, register_physics_server_3d};
+use godot::tools::{ColliderHit, PointQuery3D, RayHit3D, RayQuery3D, ShapeCast, ShapeResultWriter};
+
+use crate::framework::itest;
+
+const GROUND_RID: u64 = 77;
+
+/// Space containing only an infinite ground plane at `y = 0`, filling the half-space below.
+#[derive(GodotClass)]
+#[class(init, base = PhysicsDirectSpaceState3DExtension)]
+struct GroundPlaneSpace {
+    base: Base<PhysicsDirectSpaceState3DExtension>,
+}
+
+impl GroundPlaneSpace {
+    fn ground_hit(&self) -> ColliderHit {
+        let collider = self.to_gd().upcast::<Object>();
+
+        ColliderHit {
+            collider_id: collider.instance_id(),
+            collider,
+            rid: Rid::new(GROUND_RID),
+            shape_idx: 0,
+        }
+    }
+}
+
+#[godot_api]
+impl IPhysicsDirectSpaceState3DExtension for GroundPlaneSpace {
+    unsafe fn intersect_ray(
+        &mut self,
+        from: Vector3,
+        to: Vector3,
+        _collision_mask: u32,
+        _collide_with_bodies: bool,
+        _collide_with_areas: bool,
+        _hit_from_inside: bool,
+        _hit_back_faces: bool,
+        _pick_ray: bool,
+        result: *mut PhysicsServer3DExtensionRayResult,
+    ) -> bool {
+        if from.y <= 0.0 || to.y > 0.0 {
+            return false;
+        }
+
+        let t = from.y / (from.y - to.y);
+        let ground = self.ground_hit();
+        let hit = RayHit3D {
+            position: from + (to - from) * t,
+            normal: Vector3::UP,
+            collider: ground.collider,
+            collider_id: ground.collider_id,
+            rid: ground.rid,
+            shape_idx: ground.shape_idx,
+            face_index: None,
+        };
+
+        hit.write_to(result);
+        true
+    }
+
+    unsafe fn intersect_point(
+        &mut self,
+        position: Vector3,
+        _collision_mask: u32,
+        _collide_with_bodies: bool,
+        _collide_with_areas: bool,
+        results: *mut PhysicsServer3DExtensionShapeResult,
+        max_results: i32,
+    ) -> i32 {
+        let mut writer = ShapeResultWriter::new(results, max_results);
+        if position.y <= 0.0 {
+            writer.push(&self.ground_hit());
+        }
+
+        writer.count()
+    }
+
+    unsafe fn intersect_shape(
+        &mut self,
+        _shape_rid: Rid,
+        _transform: Transform3D,
+        _motion: Vector3,
+        _margin: f32,
+        _collision_mask: u32,
+        _collide_with_bodies: bool,
+        _collide_with_areas: bool,
+        _results: *mut PhysicsServer3DExtensionShapeResult,
+        _max_results: i32,
+    ) -> i32 {
+        0
+    }
+
+    unsafe fn cast_motion(
+        &mut self,
+        _shape_rid: Rid,
+        _transform: Transform3D,
+        _motion: Vector3,
+        _margin: f32,
+        _collision_mask: u32,
+        _collide_with_bodies: bool,
+        _collide_with_areas: bool,
+        closest_safe: *mut f32,
+        closest_unsafe: *mut f32,
+        _info: *mut PhysicsServer3DExtensionShapeRestInfo,
+    ) -> bool {
+        let free = ShapeCast {
+            safe_fraction: 1.0,
+            unsafe_fraction: 1.0,
+        };
+
+        free.write_to(closest_safe, closest_unsafe);
+        true
+    }
+
+    unsafe fn collide_shape(
+        &mut self,
+        _shape_rid: Rid,
+        _transform: Transform3D,
+        _motion: Vector3,
+        _margin: f32,
+        _collision_mask: u32,
+        _collide_with_bodies: bool,
+        _collide_with_areas: bool,
+        _results: *mut c_void,
+        _max_results: i32,
+        _result_count: *mut i32,
+    ) -> bool {
+        false
+    }
+
+    unsafe fn rest_info(
+        &mut self,
+        _shape_rid: Rid,
+        _transform: Transform3D,
+        _motion: Vector3,
+        _margin: f32,
+        _collision_mask: u32,
+        _collide_with_bodies: bool,
+        _collide_with_areas: bool,
+        _rest_info: *mut PhysicsServer3DExtensionShapeRestInfo,
+    ) -> bool {
+        false
+    }
+
+    fn get_closest_point_to_object_volume(&self, _object: Rid, point: Vector3) -> Vector3 {
+        Vector3::new(point.x, point.y.min(0.0), point.z)
+    }
+}
+
+/// Server without any virtual methods implemented. It is registered, but never selected as the active physics engine.
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass)]
+#[class(init, base = PhysicsServer3DExtension)]
+struct NullPhysicsServer {
+    base: Base<PhysicsServer3DExtension>,
+}
+
+fn space_state(space: &Gd<GroundPlaneSpace>) -> Gd<PhysicsDirectSpaceState3D> {
+    space.clone().upcast()
+}
+
+#[itest]
+fn physics_extension_ray_hit() {
+    let space = GroundPlaneSpace::new_alloc();
+
+    let hit = space_state(&space)
+        .cast_ray(&RayQuery3D {
+            from: Vector3::new(1.0, 10.0, 2.0),
+            to: Vector3::new(1.0, -10.0, 2.0),
+            ..Default::default()
+        })
+        .expect("ray through the ground plane should hit");
+
+    assert_eq!(hit.position, Vector3::new(1.0, 0.0, 2.0));
+    assert_eq!(hit.normal, Vector3::UP);
+    assert_eq!(hit.rid, Rid::new(GROUND_RID));
+    assert_eq!(hit.shape_idx, 0);
+    assert_eq!(hit.collider_id, space.instance_id());
+    assert_eq!(hit.face_index, None);
+
+    space.free();
+}
+
+#[itest]
+fn physics_extension_ray_miss() {
+    let space = GroundPlaneSpace::new_alloc();
+
+    let hit = space_state(&space).cast_ray(&RayQuery3D {
+        from: Vector3::new(0.0, 10.0, 0.0),
+        to: Vector3::new(0.0, 5.0, 0.0),
+        ..Default::default()
+    });
+    assert!(hit.is_none());
+
+    space.free();
+}
+
+#[itest]
+fn physics_extension_point() {
+    let space = GroundPlaneSpace::new_alloc();
+    let mut state = space_state(&space);
+
+    let below = state.intersect_point_typed(
+        &PointQuery3D {
+            position: Vector3::new(0.0, -1.0, 0.0),
+            ..Default::default()
+        },
+        8,
+    );
+    assert_eq!(below.len(), 1);
+    assert_eq!(below[0].rid, Rid::new(GROUND_RID));
+    assert_eq!(below[0].collider_id, space.instance_id());
+
+    let above = state.intersect_point_typed(
+        &PointQuery3D {
+            position: Vector3::new(0.0, 1.0, 0.0),
+            ..Default::default()
+        },
+        8,
+    );
+    assert!(above.is_empty());
+
+    space.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn physics_extension_register_server() {
+    const NAME: &str = "GdextTestPhysics3D";
+
+    // Godot only stores the factory; it would call it on startup if the engine was selected with --physics-engine.
+    register_physics_server_3d::<NullPhysicsServer>(NAME);
+
+    let factory = create_test_physics_server_3d_factory::<NullPhysicsServer>(NAME);
+    let server = factory.call(&[]).to::<Gd<PhysicsServer3DExtension>>();
+    let server = server
+        .try_cast::<NullPhysicsServer>()
+        .expect("factory should create the registered class");
+
+    server.free();
+}
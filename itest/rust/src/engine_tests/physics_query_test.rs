@@ -0,0 +1,203 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(all(feature = "codegen-full", since_api = "4.2"))]
+
+use godot::builtin::{Signal, Transform3D, Vector2, Vector3};
+use godot::classes::{
+    BoxShape3D, CollisionShape2D, CollisionShape3D, Node, RectangleShape2D, SphereShape3D,
+    StaticBody2D, StaticBody3D,
+};
+use godot::obj::{Gd, NewAlloc, NewGd};
+use godot::task::{self, TaskHandle};
+use godot::tools::{PointQuery2D, PointQuery3D, RayQuery2D, RayQuery3D, ShapeQuery3D};
+
+use crate::framework::{itest, TestContext};
+
+/// Box of size 2x2x2 around the origin.
+fn add_box_3d(parent: &mut Gd<Node>) -> Gd<StaticBody3D> {
+    let mut shape = BoxShape3D::new_gd();
+    shape.set_size(Vector3::new(2.0, 2.0, 2.0));
+
+    let mut collision = CollisionShape3D::new_alloc();
+    collision.set_shape(&shape);
+
+    let mut body = StaticBody3D::new_alloc();
+    body.add_child(&collision);
+    parent.add_child(&body);
+    body
+}
+
+/// Rectangle of size 20x20 around the origin.
+fn add_rect_2d(parent: &mut Gd<Node>) -> Gd<StaticBody2D> {
+    let mut shape = RectangleShape2D::new_gd();
+    shape.set_size(Vector2::new(20.0, 20.0));
+
+    let mut collision = CollisionShape2D::new_alloc();
+    collision.set_shape(&shape);
+
+    let mut body = StaticBody2D::new_alloc();
+    body.add_child(&collision);
+    parent.add_child(&body);
+    body
+}
+
+#[itest(async)]
+fn physics_query_3d(ctx: &TestContext) -> TaskHandle {
+    let mut parent = ctx.scene_tree.clone();
+    let body = add_box_3d(&mut parent);
+
+    let tree = parent.get_tree().unwrap();
+    let physics_frame = Signal::from_object_signal(&tree, "physics_frame");
+
+    task::spawn(async move {
+        // Bodies are only inserted into the broadphase during a physics step.
+        physics_frame.to_future::<()>().await;
+        physics_frame.to_future::<()>().await;
+
+        let mut space = body
+            .get_world_3d()
+            .and_then(|mut world| world.get_direct_space_state())
+            .expect("3D space state");
+
+        let down = RayQuery3D {
+            from: Vector3::new(0.0, 10.0, 0.0),
+            to: Vector3::new(0.0, -10.0, 0.0),
+            ..Default::default()
+        };
+
+        let hit = space.cast_ray(&down).expect("ray hits box");
+        assert!(hit.position.is_equal_approx(Vector3::new(0.0, 1.0, 0.0)));
+        assert!(hit.normal.is_equal_approx(Vector3::UP));
+        assert_eq!(hit.collider, body.clone().upcast());
+        assert_eq!(hit.collider_id, body.instance_id());
+        assert_eq!(hit.rid, body.get_rid());
+        assert_eq!(hit.shape_idx, 0);
+        assert_eq!(hit.face_index, None);
+
+        let beside = RayQuery3D {
+            from: Vector3::new(10.0, 10.0, 0.0),
+            to: Vector3::new(10.0, -10.0, 0.0),
+            ..Default::default()
+        };
+        assert!(space.cast_ray(&beside).is_none());
+
+        let excluded = RayQuery3D {
+            exclude: vec![body.get_rid()],
+            ..down.clone()
+        };
+        assert!(space.cast_ray(&excluded).is_none());
+
+        let other_layer = RayQuery3D {
+            collision_mask: 1 << 7,
+            ..down
+        };
+        assert!(space.cast_ray(&other_layer).is_none());
+
+        // Shape queries.
+        let mut sphere = SphereShape3D::new_gd();
+        sphere.set_radius(0.5);
+
+        let falling = ShapeQuery3D {
+            shape_rid: sphere.get_rid(),
+            transform: Transform3D::IDENTITY.translated(Vector3::new(0.0, 5.0, 0.0)),
+            motion: Vector3::new(0.0, -10.0, 0.0),
+            ..Default::default()
+        };
+
+        let cast = space.cast_shape(&falling).expect("sphere hits box");
+        assert!(cast.safe_fraction > 0.0 && cast.safe_fraction < 1.0);
+        assert!(cast.unsafe_fraction >= cast.safe_fraction);
+
+        let sideways = ShapeQuery3D {
+            motion: Vector3::new(10.0, 0.0, 0.0),
+            ..falling.clone()
+        };
+        assert!(space.cast_shape(&sideways).is_none());
+
+        let overlapping = ShapeQuery3D {
+            transform: Transform3D::IDENTITY,
+            ..falling
+        };
+        let hits = space.intersect_shape_typed(&overlapping, 8);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].collider, body.clone().upcast());
+
+        // Point queries.
+        let inside = PointQuery3D {
+            position: Vector3::new(0.5, 0.5, 0.5),
+            ..Default::default()
+        };
+        let hits = space.intersect_point_typed(&inside, 8);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].rid, body.get_rid());
+
+        let outside = PointQuery3D {
+            position: Vector3::new(5.0, 0.0, 0.0),
+            ..Default::default()
+        };
+        assert!(space.intersect_point_typed(&outside, 8).is_empty());
+
+        body.free();
+    })
+}
+
+#[itest(async)]
+fn physics_query_2d(ctx: &TestContext) -> TaskHandle {
+    let mut parent = ctx.scene_tree.clone();
+    let body = add_rect_2d(&mut parent);
+
+    let tree = parent.get_tree().unwrap();
+    let physics_frame = Signal::from_object_signal(&tree, "physics_frame");
+
+    task::spawn(async move {
+        physics_frame.to_future::<()>().await;
+        physics_frame.to_future::<()>().await;
+
+        let mut space = body
+            .get_world_2d()
+            .and_then(|mut world| world.get_direct_space_state())
+            .expect("2D space state");
+
+        let down = RayQuery2D {
+            from: Vector2::new(0.0, -50.0),
+            to: Vector2::new(0.0, 50.0),
+            ..Default::default()
+        };
+
+        let hit = space.cast_ray(&down).expect("ray hits rectangle");
+        assert!(hit.position.is_equal_approx(Vector2::new(0.0, -10.0)));
+        assert!(hit.normal.is_equal_approx(Vector2::UP));
+        assert_eq!(hit.collider, body.clone().upcast());
+        assert_eq!(hit.rid, body.get_rid());
+
+        let beside = RayQuery2D {
+            from: Vector2::new(50.0, -50.0),
+            to: Vector2::new(50.0, 50.0),
+            ..Default::default()
+        };
+        assert!(space.cast_ray(&beside).is_none());
+
+        let from_inside = RayQuery2D {
+            from: Vector2::ZERO,
+            hit_from_inside: true,
+            ..down
+        };
+        let hit = space.cast_ray(&from_inside).expect("ray starts inside");
+        assert_eq!(hit.normal, Vector2::ZERO);
+
+        let inside = PointQuery2D {
+            position: Vector2::new(5.0, 5.0),
+            ..Default::default()
+        };
+        let hits = space.intersect_point_typed(&inside, 8);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].collider_id, body.instance_id());
+
+        body.free();
+    })
+}
@@ -0,0 +1,95 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use godot::builtin::{Dictionary, GString, Variant};
+use godot::classes::ProjectSettings;
+use godot::global::PropertyHint;
+use godot::meta::error::SettingError;
+use godot::meta::{PropertyHintInfo, ToGodot};
+use godot::obj::{EngineEnum, Gd};
+use godot::tools::SettingVisibility;
+
+use crate::framework::itest;
+
+fn remove_setting(settings: &mut Gd<ProjectSettings>, path: &str) {
+    // Setting a value to nil erases it.
+    settings.set_setting(path, &Variant::nil());
+    assert!(!settings.has_setting(path));
+}
+
+#[itest]
+fn project_settings_get_as() {
+    let settings = ProjectSettings::singleton();
+
+    let name = settings
+        .get_as::<GString>("application/config/name")
+        .expect("project name exists");
+    assert_eq!(name, settings.get_setting("application/config/name").to());
+
+    let err = settings
+        .get_as::<i64>("gdext_itest/does/not_exist")
+        .expect_err("missing setting");
+    assert!(matches!(err, SettingError::NotFound { .. }));
+
+    let err = settings
+        .get_as::<i64>("application/config/name")
+        .expect_err("string is not an integer");
+    assert!(matches!(err, SettingError::WrongType { .. }));
+
+    assert_eq!(settings.get_or("gdext_itest/does/not_exist", 7), 7);
+    assert_eq!(settings.get_or("application/config/name", 7), 7);
+}
+
+#[itest]
+fn project_settings_register_typed() {
+    let path = "gdext_itest/typed/speed";
+    let mut settings = ProjectSettings::singleton();
+
+    let hint_info = PropertyHintInfo {
+        hint: PropertyHint::RANGE,
+        hint_string: "0,10,0.5".into(),
+    };
+    settings.register_setting(path, 3.5, hint_info, SettingVisibility::Basic);
+
+    assert_eq!(settings.get_as::<f64>(path).ok(), Some(3.5));
+    assert_eq!(settings.property_get_revert(path), 3.5.to_variant());
+
+    let property = settings
+        .get_property_list()
+        .iter_shared()
+        .find(|property: &Dictionary| property.get("name") == Some(path.to_variant()))
+        .expect("setting is listed as property");
+    assert_eq!(
+        property.get("hint"),
+        Some((PropertyHint::RANGE.ord() as i64).to_variant())
+    );
+    assert_eq!(property.get("hint_string"), Some("0,10,0.5".to_variant()));
+
+    remove_setting(&mut settings, path);
+}
+
+#[itest]
+fn project_settings_register_keeps_value() {
+    let path = "gdext_itest/typed/keep";
+    let mut settings = ProjectSettings::singleton();
+
+    // Simulates a value loaded from project.godot before the extension registers the setting.
+    settings.set_setting(path, &"custom".to_variant());
+    settings.register_setting(
+        path,
+        "default",
+        PropertyHintInfo::none(),
+        SettingVisibility::Advanced,
+    );
+
+    assert_eq!(settings.get_as::<GString>(path).ok(), Some("custom".into()));
+    assert_eq!(settings.property_get_revert(path), "default".to_variant());
+
+    remove_setting(&mut settings, path);
+}
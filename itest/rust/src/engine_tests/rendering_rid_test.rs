@@ -0,0 +1,111 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Leaked RIDs are reported by Godot when the test runner exits ("RID allocations of type ... were leaked"), so every test here frees all
+// objects it creates, either explicitly or through OwnedRid.
+
+use godot::builtin::{Aabb, Rid, Vector3};
+use godot::classes::RenderingServer;
+use godot::tools::{
+    AsRid, CanvasItemRid, InstanceRid, MaterialKind, MaterialRid, MeshKind, MeshRid, OwnedRid,
+    ScenarioKind, ScenarioRid,
+};
+
+use crate::framework::itest;
+
+#[itest]
+fn rendering_rid_create_free() {
+    let mesh = MeshRid::create();
+    let material = MaterialRid::create();
+    let canvas_item = CanvasItemRid::create();
+
+    for rid in [mesh.as_rid(), material.as_rid(), canvas_item.as_rid()] {
+        assert!(rid.is_valid());
+    }
+    assert_ne!(mesh.rid(), material.rid());
+
+    let untyped: Rid = mesh.into();
+    assert_eq!(untyped, mesh.rid());
+    assert_eq!(MeshRid::from_rid_unchecked(untyped), mesh);
+    assert_eq!(
+        format!("{mesh:?}"),
+        format!("MeshRid({})", untyped.to_u64())
+    );
+
+    // Typed handles still pass into raw server methods.
+    let surfaces = RenderingServer::singleton().mesh_get_surface_count(mesh.as_rid());
+    assert_eq!(surfaces, 0);
+
+    mesh.free();
+    material.free();
+    canvas_item.free();
+}
+
+#[itest]
+fn rendering_rid_instance_pipeline() {
+    let scenario = ScenarioRid::create();
+    let mesh = MeshRid::create();
+
+    let instance = InstanceRid::create_with(mesh, scenario);
+    assert!(instance.rid().is_valid());
+
+    let other_mesh = MeshRid::create();
+    instance.set_base(other_mesh);
+    instance.set_scenario(scenario);
+
+    // Instances before their bases, bases before the scenario.
+    instance.free();
+    other_mesh.free();
+    mesh.free();
+    scenario.free();
+}
+
+#[itest]
+fn rendering_rid_owned_drop() {
+    let mut server = RenderingServer::singleton();
+    let bounds = Aabb::new(Vector3::ZERO, Vector3::ONE);
+    let scenario = OwnedRid::<ScenarioKind>::create();
+    let mesh = OwnedRid::<MeshKind>::create();
+
+    let handle;
+    {
+        let instance = OwnedRid::new(InstanceRid::create_with(*mesh, *scenario));
+        handle = instance.handle();
+        assert_eq!(instance.as_rid(), handle.rid());
+        assert!(format!("{instance:?}").starts_with("OwnedRid(InstanceRid("));
+
+        // The server only reports instances with an attached object ID; the custom AABB makes the empty mesh cullable.
+        server.instance_attach_object_instance_id(instance.as_rid(), 1234);
+        server.instance_set_custom_aabb(instance.as_rid(), bounds);
+
+        let culled = server
+            .instances_cull_aabb_ex(bounds)
+            .scenario(scenario.as_rid())
+            .done();
+        assert_eq!(culled.as_slice(), &[1234]);
+    }
+
+    // Dropping the guard freed the instance, so the server no longer knows it.
+    let culled = server
+        .instances_cull_aabb_ex(bounds)
+        .scenario(scenario.as_rid())
+        .done();
+    assert!(culled.is_empty(), "instance not freed on drop: {culled:?}");
+    assert!(handle.rid().is_valid());
+
+    // Mesh and scenario are dropped in reverse order.
+}
+
+#[itest]
+fn rendering_rid_owned_into_inner() {
+    let owned = OwnedRid::<MaterialKind>::create();
+    let material = owned.into_inner();
+
+    // No longer owned, so it must be freed manually.
+    assert!(material.rid().is_valid());
+    material.free();
+}
@@ -0,0 +1,95 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{GString, PackedStringArray, StringName, Variant};
+use godot::classes::resource_loader::CacheMode;
+use godot::classes::{IResourceFormatLoader, Resource, ResourceFormatLoader, ResourceLoader};
+use godot::global::Error;
+use godot::obj::{Base, Gd, NewGd};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::{
+    register_resource_format_loader, resource_load_result, try_load,
+    unregister_resource_format_loader,
+};
+
+use crate::framework::itest;
+
+const TOY_EXTENSION: &str = "toyres";
+
+/// Loader for a toy format, which doesn't need files on disk: the resource name is derived from the path.
+#[derive(GodotClass)]
+#[class(init, base=ResourceFormatLoader)]
+struct ToyFormatLoader {
+    base: Base<ResourceFormatLoader>,
+}
+
+#[godot_api]
+impl IResourceFormatLoader for ToyFormatLoader {
+    fn get_recognized_extensions(&self) -> PackedStringArray {
+        PackedStringArray::from(&[GString::from(TOY_EXTENSION)])
+    }
+
+    fn handles_type(&self, type_: StringName) -> bool {
+        type_ == StringName::from("Resource")
+    }
+
+    fn get_resource_type(&self, _path: GString) -> GString {
+        GString::from("Resource")
+    }
+
+    fn exists(&self, _path: GString) -> bool {
+        true
+    }
+
+    fn load(
+        &self,
+        path: GString,
+        _original_path: GString,
+        _use_sub_threads: bool,
+        _cache_mode: i32,
+    ) -> Variant {
+        let path = path.to_string();
+        let result = if path.contains("corrupt") {
+            Err(Error::ERR_FILE_CORRUPT)
+        } else {
+            let mut resource = Resource::new_gd();
+            resource.set_name(path.trim_start_matches("res://"));
+            Ok(resource)
+        };
+
+        resource_load_result(result)
+    }
+}
+
+#[itest]
+fn resource_format_loader_register_load() {
+    let loader = ToyFormatLoader::new_gd();
+    register_resource_format_loader(&loader);
+
+    let resource = try_load::<Resource>("res://atlas.toyres").expect("custom loader is used");
+    assert_eq!(resource.get_name(), GString::from("atlas.toyres"));
+
+    let result = ResourceLoader::singleton()
+        .load_ex("res://data.corrupt.toyres")
+        .cache_mode(CacheMode::IGNORE)
+        .done();
+    assert!(result.is_none(), "error code maps to failed load");
+
+    unregister_resource_format_loader(&loader);
+
+    let extensions = ResourceLoader::singleton().get_recognized_extensions_for_type("Resource");
+    assert!(!extensions.contains(TOY_EXTENSION));
+}
+
+#[itest]
+fn resource_format_loader_result_variant() {
+    let ok = resource_load_result(Ok(Resource::new_gd()));
+    assert!(ok.try_to::<Gd<Resource>>().is_ok());
+
+    let err = resource_load_result::<Resource>(Err(Error::ERR_PARSE_ERROR));
+    assert_eq!(err.to::<Error>(), Error::ERR_PARSE_ERROR);
+}
@@ -11,7 +11,7 @@ use godot::obj::{Base, Gd, NewAlloc, NewGd, OnReady};
 use godot::register::GodotClass;
 use godot::tools::{load, save, try_load, try_save};
 
-use crate::framework::itest;
+use crate::framework::{expect_panic, itest, suppress_panic_log};
 
 fn remove_test_file(file_name: &str) {
     let godot_path = std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/"));
@@ -99,3 +99,26 @@ fn load_with_onready() {
 
     remove_test_file(RESOURCE_NAME);
 }
+
+#[itest]
+fn load_with_onready_fails() {
+    let node = classes::Node::new_alloc();
+
+    expect_panic("OnReady::from_loaded() with faulty path", || {
+        let mut game = OnReady::<Gd<SavedGame>>::from_loaded(FAULTY_PATH);
+        godot::private::auto_init(&mut game, &node);
+    });
+
+    // The message names both the path and the expected class.
+    let panic = suppress_panic_log(|| {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut game = OnReady::<Gd<SavedGame>>::from_loaded(FAULTY_PATH);
+            godot::private::auto_init(&mut game, &node);
+        }))
+    });
+    let message = godot::private::extract_panic_message(&*panic.expect_err("load must fail"));
+    assert!(message.contains(FAULTY_PATH), "message: {message}");
+    assert!(message.contains("SavedGame"), "message: {message}");
+
+    node.free();
+}
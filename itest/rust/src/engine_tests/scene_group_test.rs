@@ -0,0 +1,180 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::StringName;
+use godot::classes::Node;
+use godot::obj::{Base, Gd, NewAlloc};
+use godot::register::GodotClass;
+use godot::tools::GroupMemberPolicy;
+
+use crate::framework::{itest, suppress_godot_print, TestContext};
+
+const GROUP: &str = "itest_enemies";
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct GroupEnemy {
+    #[init(val = 100)]
+    health: i32,
+    base: Base<Node>,
+}
+
+#[itest]
+fn scene_tree_nodes_in_group_as(ctx: &TestContext) {
+    let (enemies, plain) = add_group_members(ctx);
+    let mut tree = ctx.scene_tree.get_tree().unwrap();
+
+    let found = tree
+        .nodes_in_group_as::<GroupEnemy>(GROUP, GroupMemberPolicy::Skip)
+        .unwrap();
+    assert_eq!(found, enemies);
+
+    suppress_godot_print(|| {
+        let found = tree
+            .nodes_in_group_as::<GroupEnemy>(GROUP, GroupMemberPolicy::Warn)
+            .unwrap();
+        assert_eq!(found.len(), 2);
+    });
+
+    let err = tree
+        .nodes_in_group_as::<GroupEnemy>(GROUP, GroupMemberPolicy::Error)
+        .expect_err("plain node is not GroupEnemy");
+    assert_eq!(err.group(), GROUP);
+    assert_eq!(*err.node_path(), plain.get_path());
+
+    let all = tree
+        .nodes_in_group_as::<Node>(GROUP, GroupMemberPolicy::Error)
+        .unwrap();
+    assert_eq!(all.len(), 3);
+
+    let none = tree
+        .nodes_in_group_as::<GroupEnemy>("itest_empty_group", GroupMemberPolicy::Error)
+        .unwrap();
+    assert!(none.is_empty());
+
+    free_group_members(enemies, plain);
+}
+
+#[itest]
+fn scene_tree_get_nodes_in_group_as(ctx: &TestContext) {
+    let (enemies, plain) = add_group_members(ctx);
+    let mut tree = ctx.scene_tree.get_tree().unwrap();
+
+    // Plain node is filtered out.
+    let found = tree.get_nodes_in_group_as::<GroupEnemy>(GROUP);
+    assert_eq!(found, enemies);
+
+    let all = tree.get_nodes_in_group_as::<Node>(GROUP);
+    assert_eq!(all.len(), 3);
+
+    assert!(tree
+        .get_nodes_in_group_as::<GroupEnemy>("itest_empty_group")
+        .is_empty());
+
+    free_group_members(enemies, plain);
+}
+
+#[itest]
+fn node_get_groups() {
+    let mut node = Node::new_alloc();
+    assert!(node.get_groups().is_empty());
+
+    node.add_to_group(GROUP);
+    node.add_to_group(&StringName::from("itest_other"));
+    node.add_to_group("_itest_internal");
+
+    assert!(node.is_in_group(GROUP));
+    assert_eq!(
+        node.get_groups(),
+        [
+            StringName::from(GROUP),
+            StringName::from("itest_other"),
+            StringName::from("_itest_internal"),
+        ]
+    );
+
+    node.remove_from_group(GROUP);
+    assert!(!node.is_in_group(GROUP));
+    assert_eq!(
+        node.get_groups(),
+        [
+            StringName::from("itest_other"),
+            StringName::from("_itest_internal"),
+        ]
+    );
+
+    node.free();
+}
+
+#[itest]
+fn scene_tree_call_group_typed(ctx: &TestContext) {
+    let (enemies, plain) = add_group_members(ctx);
+    let mut tree = ctx.scene_tree.get_tree().unwrap();
+
+    let count = tree
+        .call_group_typed::<GroupEnemy>(GROUP, GroupMemberPolicy::Skip, |enemy| {
+            enemy.health -= 10;
+        })
+        .unwrap();
+    assert_eq!(count, 2);
+    assert_eq!(enemies[0].bind().health, 90);
+    assert_eq!(enemies[1].bind().health, 90);
+
+    tree.call_group_typed::<GroupEnemy>(GROUP, GroupMemberPolicy::Error, |_| {})
+        .expect_err("plain node is not GroupEnemy");
+
+    // A bound member is skipped instead of panicking.
+    {
+        let _guard = enemies[0].bind();
+
+        let count = tree
+            .call_group_typed::<GroupEnemy>(GROUP, GroupMemberPolicy::Skip, |enemy| {
+                enemy.health = 0;
+            })
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+    assert_eq!(enemies[0].bind().health, 90);
+    assert_eq!(enemies[1].bind().health, 0);
+
+    plain.clone().remove_from_group(GROUP);
+    let guard = enemies[1].bind();
+    let err = tree
+        .call_group_typed::<GroupEnemy>(GROUP, GroupMemberPolicy::Error, |_| {})
+        .expect_err("bound member is reported");
+    assert_eq!(
+        *err.node_path(),
+        enemies[1].clone().upcast::<Node>().get_path()
+    );
+    drop(guard);
+
+    free_group_members(enemies, plain);
+}
+
+fn add_group_members(ctx: &TestContext) -> (Vec<Gd<GroupEnemy>>, Gd<Node>) {
+    let mut parent = ctx.scene_tree.clone();
+
+    let enemies = vec![GroupEnemy::new_alloc(), GroupEnemy::new_alloc()];
+    for enemy in &enemies {
+        let mut node = enemy.clone().upcast::<Node>();
+        parent.add_child(&node);
+        node.add_to_group(GROUP);
+    }
+
+    let mut plain = Node::new_alloc();
+    parent.add_child(&plain);
+    plain.add_to_group(GROUP);
+
+    (enemies, plain)
+}
+
+fn free_group_members(enemies: Vec<Gd<GroupEnemy>>, plain: Gd<Node>) {
+    for enemy in enemies {
+        enemy.free();
+    }
+    plain.free();
+}
@@ -0,0 +1,146 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use godot::builtin::{Color, VariantType, Vector2, Vector4};
+use godot::classes::{Shader, ShaderMaterial, Texture2D};
+use godot::meta::error::ShaderParamError;
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewGd};
+use godot::register::ShaderParams;
+use godot::tools::{load, ShaderParams, ShaderUniform};
+
+use crate::framework::itest;
+
+/// Matches `res://shaders/ParamTest.gdshader`.
+#[derive(ShaderParams)]
+struct TestParams {
+    tint: Color,
+    strength: f32,
+    #[param(name = "steps")]
+    step_count: i32,
+    offset: Vector2,
+    noise: Option<Gd<Texture2D>>,
+}
+
+/// Out of sync with the shader: `tint` is declared `source_color`, and there is no `speed` uniform.
+#[derive(ShaderParams)]
+struct OutdatedParams {
+    tint: Vector4,
+    strength: f32,
+    speed: f32,
+}
+
+fn test_material() -> Gd<ShaderMaterial> {
+    let mut material = ShaderMaterial::new_gd();
+    material.set_shader(&load::<Shader>("res://shaders/ParamTest.gdshader"));
+    material
+}
+
+#[itest]
+fn shader_params_set_get() {
+    let mut material = test_material();
+
+    material.set_param("strength", 0.25);
+    material.set_param("steps", 3);
+    material.set_param("tint", Color::from_rgb(1.0, 0.0, 0.5));
+
+    assert_eq!(material.get_param_as::<f32>("strength").unwrap(), 0.25);
+    assert_eq!(material.get_param_as::<i32>("steps").unwrap(), 3);
+    assert_eq!(
+        material.get_param_as::<Color>("tint").unwrap(),
+        Color::from_rgb(1.0, 0.0, 0.5)
+    );
+
+    // Same as the stringly-typed API.
+    assert_eq!(
+        material.get_shader_parameter("strength"),
+        0.25_f64.to_variant()
+    );
+
+    material
+        .get_param_as::<Vector2>("strength")
+        .expect_err("float is not a Vector2");
+}
+
+#[itest]
+fn shader_params_apply() {
+    let mut material = test_material();
+    let params = TestParams {
+        tint: Color::RED,
+        strength: 2.0,
+        step_count: 4,
+        offset: Vector2::new(0.5, -0.5),
+        noise: None,
+    };
+
+    params.apply_to(&mut material);
+
+    assert_eq!(material.get_param_as::<Color>("tint").unwrap(), Color::RED);
+    assert_eq!(material.get_param_as::<f32>("strength").unwrap(), 2.0);
+    assert_eq!(material.get_param_as::<i32>("steps").unwrap(), 4);
+    assert_eq!(
+        material.get_param_as::<Vector2>("offset").unwrap(),
+        Vector2::new(0.5, -0.5)
+    );
+    assert_eq!(
+        material
+            .get_param_as::<Option<Gd<Texture2D>>>("noise")
+            .unwrap(),
+        None
+    );
+}
+
+#[itest]
+fn shader_params_uniforms() {
+    let uniforms = TestParams::uniforms();
+    let names: Vec<&str> = uniforms.iter().map(|uniform| uniform.name).collect();
+
+    assert_eq!(names, ["tint", "strength", "steps", "offset", "noise"]);
+    assert_eq!(
+        uniforms[2],
+        ShaderUniform {
+            name: "steps",
+            variant_type: VariantType::INT,
+        }
+    );
+    assert_eq!(uniforms[4].variant_type, VariantType::OBJECT);
+}
+
+#[itest]
+fn shader_params_validate() {
+    let material = test_material();
+    assert_eq!(material.validate_params::<TestParams>(), Ok(()));
+
+    // Validation is skipped in release builds.
+    if !cfg!(debug_assertions) {
+        return;
+    }
+
+    let errors = material
+        .validate_params::<OutdatedParams>()
+        .expect_err("outdated params");
+    assert_eq!(
+        errors,
+        [
+            ShaderParamError::WrongType {
+                name: "tint".to_string(),
+                expected: VariantType::VECTOR4,
+                actual: VariantType::COLOR,
+            },
+            ShaderParamError::MissingUniform {
+                name: "speed".to_string(),
+            },
+        ]
+    );
+
+    let errors = ShaderMaterial::new_gd()
+        .validate_params::<TestParams>()
+        .expect_err("no shader");
+    assert_eq!(errors, [ShaderParamError::NoShader]);
+}
@@ -0,0 +1,127 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Signal;
+use godot::classes::Object;
+use godot::obj::{Base, NewAlloc};
+use godot::register::{godot_api, GodotClass};
+use godot::task::{self, StreamOverflow, TaskHandle};
+
+use crate::framework::{expect_async_panic, itest};
+
+#[itest(async)]
+fn signal_stream_yields_all_emissions() -> TaskHandle {
+    let mut emitter = StreamEmitter::new_alloc();
+    let mut stream = emitter.signals().counted().to_stream();
+
+    // Emitted before the consumer runs; all are buffered.
+    for value in 0..5 {
+        emitter.signals().counted().emit(value);
+    }
+    assert_eq!(stream.buffered_len(), 5);
+
+    // Freeing the emitter ends the stream, once the buffer is drained.
+    emitter.call_deferred("free", &[]);
+
+    task::spawn(async move {
+        let mut received = Vec::new();
+        while let Some((value,)) = stream.next().await {
+            received.push(value);
+        }
+
+        assert_eq!(received, vec![0, 1, 2, 3, 4]);
+    })
+}
+
+#[itest(async)]
+fn signal_stream_overflow_drop_oldest() -> TaskHandle {
+    let mut emitter = StreamEmitter::new_alloc();
+    let signal = Signal::from_object_signal(&emitter, "counted");
+
+    let mut stream = signal
+        .to_stream_ex::<(i64,)>()
+        .capacity(4)
+        .overflow(StreamOverflow::DropOldest)
+        .done();
+
+    for value in 0..10 {
+        emitter.signals().counted().emit(value);
+    }
+    assert_eq!(stream.buffered_len(), 4);
+
+    task::spawn(async move {
+        let mut received = Vec::new();
+        for _ in 0..4 {
+            let (value,) = stream.next().await.expect("stream ended early");
+            received.push(value);
+        }
+
+        // Only the most recent emissions are kept.
+        assert_eq!(received, vec![6, 7, 8, 9]);
+        assert_eq!(stream.buffered_len(), 0);
+
+        emitter.free();
+        assert_eq!(stream.next().await, None);
+    })
+}
+
+#[itest(async)]
+fn signal_stream_overflow_panic() -> TaskHandle {
+    let mut emitter = StreamEmitter::new_alloc();
+    let signal = Signal::from_object_signal(&emitter, "counted");
+
+    let mut stream = signal
+        .to_stream_ex::<(i64,)>()
+        .capacity(2)
+        .overflow(StreamOverflow::Panic)
+        .done();
+
+    // One more than fits; emitting itself doesn't panic.
+    for value in 0..3 {
+        emitter.signals().counted().emit(value);
+    }
+
+    let handle = task::spawn(expect_async_panic(
+        "stream should panic when polled after overflow",
+        async move {
+            stream.next().await;
+        },
+    ));
+
+    emitter.call_deferred("free", &[]);
+    handle
+}
+
+#[itest]
+fn signal_stream_drop_disconnects() {
+    let mut emitter = StreamEmitter::new_alloc();
+
+    let stream = emitter.signals().counted().to_stream();
+    assert_eq!(emitter.get_signal_connection_list("counted").len(), 1);
+
+    drop(stream);
+    assert!(emitter.get_signal_connection_list("counted").is_empty());
+
+    // Must not reach the dropped stream.
+    emitter.signals().counted().emit(1);
+    emitter.free();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helper types
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct StreamEmitter {
+    _base: Base<Object>,
+}
+
+#[godot_api]
+impl StreamEmitter {
+    #[signal]
+    fn counted(value: i64);
+}
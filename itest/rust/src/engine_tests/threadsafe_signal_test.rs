@@ -0,0 +1,126 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::thread;
+
+use godot::builtin::{GString, Variant};
+use godot::classes::{Object, RefCounted};
+use godot::meta::ToGodot;
+use godot::obj::{Base, Gd, NewAlloc, NewGd};
+use godot::register::{godot_api, GodotClass};
+use godot::task::{self, TaskHandle};
+
+use crate::framework::itest;
+
+const THREADS: i64 = 4;
+const EMITS_PER_THREAD: i64 = 250;
+
+#[itest(async)]
+fn signal_emit_threadsafe_in_order() -> TaskHandle {
+    let mut emitter = ThreadEmitter::new_alloc();
+    let received = Rc::new(RefCell::new(Vec::new()));
+
+    let log = received.clone();
+    emitter.signals().counted().connect(move |thread, seq| {
+        log.borrow_mut()
+            .push((thread, seq, godot::init::is_main_thread()));
+    });
+
+    let workers: Vec<_> = (0..THREADS)
+        .map(|thread| {
+            let signal = emitter.signals().counted().to_threadsafe();
+
+            thread::spawn(move || {
+                for seq in 0..EMITS_PER_THREAD {
+                    signal.emit_threadsafe((thread, seq));
+                }
+            })
+        })
+        .collect();
+
+    task::spawn(async move {
+        for worker in workers {
+            worker.join().expect("emitting thread panicked");
+        }
+
+        let total = (THREADS * EMITS_PER_THREAD) as usize;
+        let observed = received.clone();
+        task::until(move || observed.borrow().len() == total)
+            .await
+            .unwrap();
+
+        let received = received.borrow();
+        assert!(received
+            .iter()
+            .all(|&(_, _, on_main_thread)| on_main_thread));
+
+        // Emissions of different threads interleave, but each thread's emissions arrive in order.
+        for thread in 0..THREADS {
+            let seqs: Vec<i64> = received
+                .iter()
+                .filter(|&&(t, _, _)| t == thread)
+                .map(|&(_, seq, _)| seq)
+                .collect();
+
+            assert_eq!(seqs, (0..EMITS_PER_THREAD).collect::<Vec<_>>());
+        }
+
+        emitter.free();
+    })
+}
+
+#[itest(async)]
+fn signal_emit_signal_threadsafe_untyped() -> TaskHandle {
+    let mut emitter = ThreadEmitter::new_alloc();
+    let payload = RefCounted::new_gd();
+    let received = Rc::new(RefCell::new(None));
+
+    let log = received.clone();
+    emitter.signals().described().connect(move |text, object| {
+        *log.borrow_mut() = Some((text, object));
+    });
+
+    emitter.emit_signal_threadsafe("described", &["hello".to_variant(), payload.to_variant()]);
+
+    // Emission is deferred to the next process frame, even on the main thread.
+    assert!(received.borrow().is_none());
+
+    task::spawn(async move {
+        let observed = received.clone();
+        task::until(move || observed.borrow().is_some())
+            .await
+            .unwrap();
+
+        let (text, object) = received.borrow_mut().take().unwrap();
+        assert_eq!(text, GString::from("hello"));
+
+        // Objects are passed by instance ID, so the same object arrives.
+        assert_eq!(object.to::<Gd<RefCounted>>(), payload);
+
+        emitter.free();
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helper types
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct ThreadEmitter {
+    _base: Base<Object>,
+}
+
+#[godot_api]
+impl ThreadEmitter {
+    #[signal]
+    fn counted(thread: i64, seq: i64);
+
+    #[signal]
+    fn described(text: GString, object: Variant);
+}
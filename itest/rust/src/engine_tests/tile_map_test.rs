@@ -0,0 +1,134 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use godot::builtin::{Vector2, Vector2i};
+use godot::classes::image::Format;
+use godot::classes::{Image, ImageTexture, TileMapLayer, TileSet, TileSetAtlasSource};
+use godot::obj::{Gd, NewAlloc, NewGd};
+use godot::tools::TileCell;
+
+use crate::framework::{itest, TestContext};
+
+/// Layer with 16x16 tiles and one atlas source of 4x2 tiles, each with one alternative.
+fn make_layer() -> (Gd<TileMapLayer>, i32) {
+    let image = Image::create_empty(64, 32, false, Format::RGBA8).unwrap();
+    let texture = ImageTexture::create_from_image(&image).unwrap();
+
+    let mut atlas = TileSetAtlasSource::new_gd();
+    atlas.set_texture(&texture);
+    atlas.set_texture_region_size(Vector2i::new(16, 16));
+    for y in 0..2 {
+        for x in 0..4 {
+            let coords = Vector2i::new(x, y);
+            atlas.create_tile(coords);
+            atlas.create_alternative_tile(coords);
+        }
+    }
+
+    let mut tile_set = TileSet::new_gd();
+    tile_set.set_tile_size(Vector2i::new(16, 16));
+    let source_id = tile_set.add_source(&atlas);
+
+    let mut layer = TileMapLayer::new_alloc();
+    layer.set_tile_set(&tile_set);
+
+    (layer, source_id)
+}
+
+#[itest]
+fn tile_map_layer_get_set_cell() {
+    let (mut layer, source_id) = make_layer();
+    let coords = Vector2i::new(3, -2);
+
+    // Empty cell: Godot's sentinels become None.
+    assert_eq!(layer.get_tile_cell(coords), None);
+    assert_eq!(layer.get_cell_source_id(coords), TileCell::INVALID_SOURCE);
+    assert_eq!(
+        layer.get_cell_atlas_coords(coords),
+        TileCell::INVALID_ATLAS_COORDS
+    );
+    assert_eq!(
+        layer.get_cell_alternative_tile(coords),
+        TileCell::INVALID_ALTERNATIVE
+    );
+
+    let tile = TileCell::atlas(source_id, Vector2i::new(2, 1));
+    layer.set_tile_cell(coords, Some(tile));
+    assert_eq!(layer.get_tile_cell(coords), Some(tile));
+    assert_eq!(layer.get_cell_atlas_coords(coords), Vector2i::new(2, 1));
+
+    let alternative = tile.with_alternative(1);
+    layer.set_tile_cell(coords, Some(alternative));
+    assert_eq!(layer.get_tile_cell(coords), Some(alternative));
+    assert_eq!(layer.get_cell_alternative_tile(coords), 1);
+
+    layer.set_tile_cell(coords, None);
+    assert_eq!(layer.get_tile_cell(coords), None);
+
+    layer.free();
+}
+
+#[itest]
+fn tile_map_layer_used_tile_cells() {
+    let (mut layer, source_id) = make_layer();
+    assert!(layer.used_tile_cells().is_empty());
+
+    let a = TileCell::atlas(source_id, Vector2i::new(0, 0));
+    let b = TileCell::atlas(source_id, Vector2i::new(3, 1)).with_alternative(1);
+    layer.set_tile_cell(Vector2i::new(0, 0), Some(a));
+    layer.set_tile_cell(Vector2i::new(-5, 7), Some(b));
+
+    let mut cells = layer.used_tile_cells();
+    cells.sort_by_key(|(coords, _)| (coords.x, coords.y));
+    assert_eq!(cells, [(Vector2i::new(-5, 7), b), (Vector2i::new(0, 0), a)]);
+
+    layer.free();
+}
+
+#[itest]
+fn tile_map_layer_coordinate_conversion(ctx: &TestContext) {
+    let (mut layer, _) = make_layer();
+
+    // Local conversions work outside the tree.
+    assert_eq!(
+        layer.map_to_local(Vector2i::new(0, 0)),
+        Vector2::new(8.0, 8.0)
+    );
+    assert_eq!(
+        layer.map_to_local(Vector2i::new(-1, 2)),
+        Vector2::new(-8.0, 40.0)
+    );
+    assert_eq!(
+        layer.local_to_map(Vector2::new(20.0, 5.0)),
+        Vector2i::new(1, 0)
+    );
+    assert_eq!(
+        layer.local_to_map(Vector2::new(-0.5, -16.5)),
+        Vector2i::new(-1, -2)
+    );
+
+    // Global conversions take the layer's transform into account.
+    ctx.scene_tree.clone().add_child(&layer);
+    layer.set_position(Vector2::new(100.0, -50.0));
+
+    assert_eq!(
+        layer.map_to_global(Vector2i::new(2, 3)),
+        Vector2::new(140.0, 6.0)
+    );
+    assert_eq!(
+        layer.global_to_map(Vector2::new(140.0, 6.0)),
+        Vector2i::new(2, 3)
+    );
+    assert_eq!(
+        layer.global_to_map(Vector2::new(99.0, -50.0)),
+        Vector2i::new(-1, 0)
+    );
+
+    layer.free();
+}
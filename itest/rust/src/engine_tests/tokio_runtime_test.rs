@@ -0,0 +1,108 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Requires the itest `tokio` feature, which installs `TokioRuntime` in the extension entry point.
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use godot::builtin::Signal;
+use godot::classes::RefCounted;
+use godot::init::is_main_thread;
+use godot::meta::ToGodot;
+use godot::obj::NewGd;
+use godot::task::{self, TaskHandle};
+use godot::tools::{register_frame_callback, FrameKind};
+
+use crate::framework::itest;
+
+#[itest(async)]
+fn tokio_sleep_and_signal_in_same_task() -> TaskHandle {
+    let mut emitter = RefCounted::new_gd();
+    emitter.add_user_signal("ping");
+    let signal = Signal::from_object_signal(&emitter, "ping");
+
+    task::spawn(async move {
+        let start = Instant::now();
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        assert!(
+            start.elapsed() >= Duration::from_millis(25),
+            "{:?}",
+            start.elapsed()
+        );
+        assert!(is_main_thread(), "woken up by tokio on the main thread");
+
+        // Emitted by another Godot task in a later frame.
+        let ping = signal.to_future::<(i32,)>();
+        task::spawn(async move {
+            task::next_frame().await.unwrap();
+            emitter.emit_signal("ping", &[5.to_variant()]);
+        });
+
+        let (value,) = ping.await;
+        assert_eq!(value, 5);
+        assert!(is_main_thread());
+
+        // Tokio is still usable after the signal.
+        tokio::time::sleep(Duration::from_millis(1)).await;
+    })
+}
+
+#[itest(async)]
+fn tokio_spawn_on_runtime() -> TaskHandle {
+    let (sender, receiver) = mpsc::channel();
+
+    task::spawn_on_runtime(async move {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        sender.send(42).unwrap();
+    });
+
+    let received = Rc::new(Cell::new(None));
+    let slot = received.clone();
+
+    task::spawn(async move {
+        task::until(move || {
+            slot.set(receiver.try_recv().ok());
+            slot.get().is_some()
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(received.get(), Some(42));
+    })
+}
+
+#[itest(async)]
+fn tokio_spawn_on_runtime_without_godot_task() -> TaskHandle {
+    let (sender, receiver) = mpsc::channel();
+
+    task::spawn_on_runtime(async move {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        sender.send(7).unwrap();
+    });
+
+    // Received by a frame callback instead of a Godot task: the runtime must make progress without any task being polled.
+    let mut notifier = RefCounted::new_gd();
+    notifier.add_user_signal("received");
+    let received = Signal::from_object_signal(&notifier, "received").to_future::<(i32,)>();
+
+    let callback = register_frame_callback(FrameKind::Process, move |_delta| {
+        if let Ok(value) = receiver.try_recv() {
+            notifier.emit_signal("received", &[value.to_variant()]);
+        }
+    });
+
+    task::spawn(async move {
+        let (value,) = received.await;
+        assert_eq!(value, 7);
+
+        drop(callback);
+    })
+}
@@ -46,3 +46,128 @@ fn tr_n_macro_format_plural() {
     let hello = tr_n!(n; "Hello singular {}!", "Hello plural {}s!", "world");
     assert_eq!(hello.to_string(), "Hello plural worlds!");
 }
+
+#[cfg(feature = "codegen-full")]
+mod translated {
+    use godot::builtin::GString;
+    use godot::classes::file_access::ModeFlags;
+    use godot::classes::{Node, ResourceLoader, Translation, TranslationServer};
+    use godot::obj::{Base, NewAlloc};
+    use godot::register::GodotClass;
+    use godot::tools::{remove_path, tr, tr_n, GFile};
+
+    use crate::framework::itest;
+
+    const PO_PATH: &str = "user://translate_test.po";
+    const LOCALE: &str = "de";
+
+    const PO_CONTENT: &str = r#"msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+"Language: de\n"
+"Plural-Forms: nplurals=2; plural=(n != 1);\n"
+
+msgid "Picked up {count} coins"
+msgstr "{count} Münzen aufgehoben"
+
+msgctxt "menu"
+msgid "Coins: {count}"
+msgstr "Münzen: {count}"
+
+msgid "{count} coin left"
+msgid_plural "{count} coins left"
+msgstr[0] "{count} Münze übrig"
+msgstr[1] "{count} Münzen übrig"
+"#;
+
+    #[derive(GodotClass)]
+    #[class(init, base=Node)]
+    struct Wallet {
+        coins: i32,
+        base: Base<Node>,
+    }
+
+    impl Wallet {
+        fn status(&self) -> GString {
+            tr!(self, "Picked up {count} coins", count = self.coins)
+        }
+    }
+
+    /// Loads the test .po file as translation for the duration of `f`.
+    fn with_translation(f: impl FnOnce()) {
+        let mut file = GFile::open(PO_PATH, ModeFlags::WRITE).unwrap();
+        file.write_gstring(PO_CONTENT).unwrap();
+        drop(file);
+
+        let translation = ResourceLoader::singleton()
+            .load(PO_PATH)
+            .expect("load .po file")
+            .cast::<Translation>();
+
+        let mut server = TranslationServer::singleton();
+        let previous_locale = server.get_locale();
+        server.add_translation(&translation);
+        server.set_locale(LOCALE);
+
+        f();
+
+        server.set_locale(&previous_locale);
+        server.remove_translation(&translation);
+        remove_path(PO_PATH).unwrap();
+    }
+
+    #[itest]
+    fn tr_macro_placeholders() {
+        let mut wallet = Wallet::new_alloc();
+        wallet.bind_mut().coins = 7;
+
+        // Without translation, the source message is formatted.
+        assert_eq!(wallet.bind().status(), "Picked up 7 coins");
+
+        with_translation(|| {
+            assert_eq!(wallet.bind().status(), "7 Münzen aufgehoben");
+
+            let node = wallet.clone().upcast::<Node>();
+            let menu = tr!(node, "menu"; "Coins: {count}", count = 7);
+            assert_eq!(menu, "Münzen: 7");
+
+            // Untranslated messages fall back to the formatted source.
+            let missing = tr!(node, "Lost {count} coins", count = 2);
+            assert_eq!(missing, "Lost 2 coins");
+        });
+
+        wallet.free();
+    }
+
+    #[itest]
+    fn tr_n_macro_placeholders() {
+        let node = Node::new_alloc();
+        let coins_left = |n: i32| -> GString {
+            tr_n!(
+                node,
+                n,
+                "{count} coin left",
+                "{count} coins left",
+                count = n
+            )
+        };
+
+        assert_eq!(coins_left(1), "1 coin left");
+        assert_eq!(coins_left(3), "3 coins left");
+
+        with_translation(|| {
+            assert_eq!(coins_left(1), "1 Münze übrig");
+            assert_eq!(coins_left(3), "3 Münzen übrig");
+        });
+
+        node.free();
+    }
+
+    #[itest]
+    fn tr_macro_without_placeholders() {
+        let node = Node::new_alloc();
+
+        assert_eq!(tr!(node, "Plain text"), "Plain text");
+        node.free();
+    }
+}
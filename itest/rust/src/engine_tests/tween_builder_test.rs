@@ -0,0 +1,147 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use godot::builtin::{NodePath, Signal, Vector2};
+use godot::classes::tween::{EaseType, TransitionType};
+use godot::classes::{Node2D, Node3D};
+use godot::obj::{Gd, NewAlloc};
+use godot::prop;
+use godot::task::{self, TaskHandle};
+use godot::tools::{PropertyPath, TweenBuilder};
+
+use crate::framework::{expect_panic, itest, suppress_godot_print, TestContext};
+
+#[itest]
+fn tween_property_paths() {
+    let position: PropertyPath<Node2D, Vector2> = prop!(Node2D::position);
+    assert_eq!(position.path(), NodePath::from("position"));
+    assert_eq!(
+        prop!(Node2D::position).y().path(),
+        NodePath::from("position:y")
+    );
+    assert_eq!(prop!(Node3D::scale).z().path(), NodePath::from("scale:z"));
+    assert_eq!(
+        prop!(Node2D::modulate).a().path(),
+        NodePath::from("modulate:a")
+    );
+
+    let visible = PropertyPath::<Node2D, bool>::new("visible");
+    assert_eq!(visible.path(), NodePath::from("visible"));
+}
+
+#[itest(async)]
+fn tween_builder_steps(ctx: &TestContext) -> TaskHandle {
+    let node = Node2D::new_alloc();
+    ctx.scene_tree.clone().add_child(&node);
+
+    let method_value = Rc::new(Cell::new(0.0));
+    let callback_node = Rc::new(Cell::new(None));
+
+    let finished = {
+        let method_value = method_value.clone();
+        let callback_node = callback_node.clone();
+
+        TweenBuilder::new(&node)
+            .property(prop!(Node2D::position).x(), 5.0, 0.05)
+            .ease(EaseType::OUT)
+            .trans(TransitionType::CUBIC)
+            .property(prop!(Node2D::modulate).a(), 0.5, 0.05)
+            .method(0.0, 10.0, 0.05, move |_node, value: f64| {
+                method_value.set(value)
+            })
+            .then_call(move |node: Gd<Node2D>| callback_node.set(Some(node.instance_id())))
+            .finished()
+    };
+
+    // Nothing happens before the next frame.
+    assert_eq!(node.get_position(), Vector2::ZERO);
+
+    task::spawn(async move {
+        finished.await;
+
+        assert_eq!(node.get_position(), Vector2::new(5.0, 0.0));
+        assert_eq!(node.get_modulate().a, 0.5);
+        assert_eq!(method_value.get(), 10.0);
+        assert_eq!(callback_node.get(), Some(node.instance_id()));
+
+        node.free();
+    })
+}
+
+#[itest(async)]
+fn tween_property_by_name(ctx: &TestContext) -> TaskHandle {
+    let node = Node2D::new_alloc();
+    ctx.scene_tree.clone().add_child(&node);
+
+    let tween = node
+        .tween_property("position", Vector2::new(3.0, 4.0), 0.05)
+        .ease(EaseType::IN_OUT)
+        .trans(TransitionType::SINE)
+        .property(prop!(Node2D::modulate).a(), 0.25, 0.05)
+        .tween();
+
+    let finished = Signal::from_object_signal(&tween, "finished").to_future::<()>();
+
+    task::spawn(async move {
+        finished.await;
+
+        assert_eq!(node.get_position(), Vector2::new(3.0, 4.0));
+        assert_eq!(node.get_modulate().a, 0.25);
+
+        node.free();
+    })
+}
+
+#[itest]
+fn tween_property_by_name_invalid(ctx: &TestContext) {
+    let node = Node2D::new_alloc();
+    ctx.scene_tree.clone().add_child(&node);
+
+    suppress_godot_print(|| {
+        expect_panic("tween non-existent property", || {
+            node.tween_property("no_such_property", 1.0, 0.1);
+        });
+    });
+
+    node.free();
+}
+
+#[itest]
+fn tween_builder_wrong_class(ctx: &TestContext) {
+    let node = Node3D::new_alloc();
+    ctx.scene_tree.clone().add_child(&node);
+
+    let builder = node.tween();
+    let mut tween = builder.tween();
+
+    expect_panic("tween Node2D property on Node3D", || {
+        builder.property(prop!(Node2D::rotation), 1.0, 0.1);
+    });
+
+    // Empty tweens report an error when started.
+    tween.kill();
+    node.free();
+}
+
+#[itest]
+fn tween_builder_outside_tree() {
+    let node = Node2D::new_alloc();
+
+    // Godot also prints an error.
+    suppress_godot_print(|| {
+        expect_panic("tween node outside tree", || {
+            TweenBuilder::new(&node);
+        });
+    });
+
+    node.free();
+}
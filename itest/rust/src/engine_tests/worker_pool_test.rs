@@ -0,0 +1,81 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+use godot::task;
+
+use crate::framework::itest;
+
+#[itest]
+fn worker_returns_value() {
+    let handle = task::spawn_worker(|| (1..=1000u64).sum::<u64>());
+    let sum = handle.join().expect("worker panicked");
+
+    assert_eq!(sum, 500_500);
+}
+
+#[itest]
+fn worker_group_parallel_sum() {
+    let data: Vec<u64> = (0..1000).collect();
+    let chunks: Vec<Vec<u64>> = data.chunks(100).map(<[u64]>::to_vec).collect();
+    let count = chunks.len() as u32;
+
+    let calls = Arc::new(AtomicU32::new(0));
+    let calls_in_worker = calls.clone();
+
+    let handle = task::spawn_worker_group(count, move |i| {
+        calls_in_worker.fetch_add(1, Ordering::SeqCst);
+        chunks[i as usize].iter().sum::<u64>()
+    });
+    let partial_sums = handle.join().expect("worker panicked");
+
+    assert_eq!(partial_sums.len(), 10);
+    assert_eq!(partial_sums[0], (0..100).sum::<u64>());
+    assert_eq!(partial_sums.iter().sum::<u64>(), 499_500);
+    assert_eq!(calls.load(Ordering::SeqCst), count);
+}
+
+#[itest]
+fn worker_panic_is_returned() {
+    let handle = task::spawn_worker(|| -> i32 { panic!("worker failed") });
+    let payload = handle.join().expect_err("worker should panic");
+
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"worker failed"));
+}
+
+#[itest]
+fn worker_group_panic_is_returned() {
+    let handle = task::spawn_worker_group(8, |i| {
+        if i == 5 {
+            panic!("index {i} failed");
+        }
+        i * 2
+    });
+    let payload = handle.join().expect_err("worker should panic");
+
+    assert_eq!(
+        payload.downcast_ref::<String>().map(String::as_str),
+        Some("index 5 failed")
+    );
+}
+
+#[itest]
+fn worker_dropped_without_join() {
+    let finished = Arc::new(AtomicU32::new(0));
+    let finished_in_worker = finished.clone();
+
+    // Dropping the handle waits for the task.
+    drop(task::spawn_worker(move || {
+        finished_in_worker.fetch_add(1, Ordering::SeqCst);
+    }));
+
+    assert_eq!(finished.load(Ordering::SeqCst), 1);
+}
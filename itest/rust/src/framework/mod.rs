@@ -292,6 +292,49 @@ pub fn runs_release() -> bool {
     !Os::singleton().is_debug_build()
 }
 
+/// Runs the itest project in a second, headless Godot process, with `main_loop` as main loop class; waits until it quits.
+///
+/// Used for tests that cannot run inside the test runner's `SceneTree`. `user_args` are passed after `--`.
+/// Returns the exit code and the combined stdout and stderr output.
+#[cfg(feature = "codegen-full")] // ProjectSettings.
+pub fn run_headless_main_loop(main_loop: &str, user_args: &[&str]) -> (i32, String) {
+    use godot::builtin::{GString, PackedStringArray, VariantArray};
+    use godot::classes::ProjectSettings;
+
+    let mut os = Os::singleton();
+    let executable = os.get_executable_path();
+    let project_dir = ProjectSettings::singleton().globalize_path("res://");
+
+    let mut args = PackedStringArray::from(&[
+        GString::from("--headless"),
+        GString::from("--path"),
+        project_dir,
+        GString::from("--main-loop"),
+        GString::from(main_loop),
+    ]);
+
+    if !user_args.is_empty() {
+        args.push("--");
+        for arg in user_args {
+            args.push(*arg);
+        }
+    }
+
+    let output = VariantArray::new();
+    let exit_code = os
+        .execute_ex(&executable, &args)
+        .output(&output)
+        .read_stderr(true)
+        .done();
+
+    let output = output
+        .iter_shared()
+        .map(|chunk| chunk.to::<GString>().to_string())
+        .collect();
+
+    (exit_code, output)
+}
+
 /// Workaround for tests of the form `assert!(a == a)`.
 ///
 /// We can't always use `assert_eq!(a, a)` because of lacking `Debug` impl.
@@ -13,6 +13,7 @@ use godot::global::godot_error;
 use godot::meta::ToGodot;
 use godot::obj::Gd;
 use godot::register::{godot_api, GodotClass};
+use godot::sys;
 
 use crate::framework::{
     bencher, passes_filter, BenchResult, RustBenchmark, RustTestCase, TestContext,
@@ -177,6 +178,10 @@ impl IntegrationTests {
             rust_file_count
         );
 
+        // Measured once during initialization, so not repeated like the benchmarks. Compare with and without `eager-function-tables`.
+        let table_load_ms = sys::method_table_load_time().as_secs_f64() * 1000.0;
+        println!("  Startup: loaded engine method tables in {table_load_ms:.3}ms.");
+
         let clock = Instant::now();
         self.run_rust_benchmarks(benchmarks, scene_tree);
         let total_time = clock.elapsed();
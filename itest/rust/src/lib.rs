@@ -24,4 +24,9 @@ unsafe impl ExtensionLibrary for framework::IntegrationTests {
         // Testing that we can initialize and use `Object`-derived classes during `Servers` init level. See `object_tests::init_level_test`.
         object_tests::initialize_init_level_test(level);
     }
+
+    #[cfg(all(feature = "tokio", since_api = "4.2"))]
+    fn async_runtime() -> Option<Box<dyn godot::task::AsyncRuntime>> {
+        Some(Box::new(godot::task::TokioRuntime::new()))
+    }
 }
@@ -94,6 +94,49 @@ fn base_with_init() {
     obj.free();
 }
 
+#[itest]
+fn base_init_access() {
+    let mut init_id = None;
+    let obj = Gd::<Based>::from_init_fn(|mut base| {
+        init_id = Some(base.instance_id());
+
+        let mut init = base.init_access();
+        init.set_rotation(11.0);
+        init.set_name("Initialized");
+
+        Based { base, i: 0 }
+    });
+
+    assert_eq!(init_id, Some(obj.instance_id()));
+    assert_eq!(obj.get_rotation(), 11.0);
+    assert_eq!(obj.get_name(), StringName::from("Initialized"));
+    obj.free();
+}
+
+#[itest]
+fn base_init_access_refcounted() {
+    let obj = Gd::<InitRefCounted>::from_init_fn(|mut base| {
+        base.init_access().set_meta("answer", &42.to_variant());
+        InitRefCounted { base }
+    });
+
+    // No Gd was handed out during init(), so the object is not destroyed prematurely and has no extra reference.
+    assert_eq!(obj.get_meta("answer"), 42.to_variant());
+    assert_eq!(obj.get_reference_count(), 1);
+}
+
+#[cfg(debug_assertions)]
+#[itest]
+fn base_init_access_after_init() {
+    let (obj, mut extracted_base) = create_object_with_extracted_base();
+
+    expect_panic("init_access() after init()", || {
+        extracted_base.init_access();
+    });
+
+    obj.free();
+}
+
 #[itest]
 fn base_gd_self() {
     let obj = Based::new_alloc();
@@ -214,6 +257,12 @@ impl Based {
     }
 }
 
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct InitRefCounted {
+    base: Base<RefCounted>,
+}
+
 #[derive(GodotClass)]
 #[class(init, base=Node2D)]
 struct Baseless {
@@ -0,0 +1,115 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::GString;
+use godot::classes::{INode, Node, Node2D};
+use godot::obj::{Base, Gd, WithBaseField};
+use godot::register::{godot_api, GodotClass};
+use godot::test::itest;
+
+#[derive(GodotClass)]
+#[class(init, builder, base = Node2D)]
+struct BuiltEnemy {
+    #[export]
+    #[init(val = 100)]
+    health: i32,
+
+    #[var]
+    #[init(val = 1.5)]
+    speed: f32,
+
+    #[var]
+    name_tag: GString,
+
+    // Not a property: no setter, always initialized by init.
+    #[init(val = 7)]
+    hidden: i32,
+
+    base: Base<Node2D>,
+}
+
+#[derive(GodotClass)]
+#[class(builder, base = Node)]
+struct BuiltWithUserInit {
+    #[var]
+    level: i64,
+
+    #[var]
+    label: GString,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl INode for BuiltWithUserInit {
+    fn init(base: Base<Node>) -> Self {
+        Self {
+            level: 3,
+            label: "default".into(),
+            base,
+        }
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, builder)]
+struct BuiltEmpty {}
+
+#[itest]
+fn class_builder_sets_fields() {
+    let enemy: Gd<BuiltEnemy> = BuiltEnemy::builder()
+        .health(40)
+        .speed(5.0)
+        .name_tag("boss".into())
+        .build();
+
+    {
+        let e = enemy.bind();
+        assert_eq!(e.health, 40);
+        assert_eq!(e.speed, 5.0);
+        assert_eq!(e.name_tag, GString::from("boss"));
+        assert_eq!(e.hidden, 7);
+
+        // Base is wired up properly.
+        assert_eq!(e.base().instance_id(), enemy.instance_id());
+    }
+
+    enemy.free();
+}
+
+#[itest]
+fn class_builder_keeps_init_defaults() {
+    let enemy = BuiltEnemy::builder().speed(2.0).build();
+
+    {
+        let e = enemy.bind();
+        assert_eq!(e.health, 100);
+        assert_eq!(e.speed, 2.0);
+        assert_eq!(e.name_tag, GString::new());
+    }
+
+    enemy.free();
+}
+
+#[itest]
+fn class_builder_user_init() {
+    let obj = BuiltWithUserInit::builder().level(9).build();
+
+    {
+        let b = obj.bind();
+        assert_eq!(b.level, 9);
+        assert_eq!(b.label, GString::from("default"));
+    }
+
+    obj.free();
+}
+
+#[itest]
+fn class_builder_refcounted_no_fields() {
+    let obj = BuiltEmpty::builder().build();
+    assert_eq!(obj.get_class(), GString::from("BuiltEmpty"));
+}
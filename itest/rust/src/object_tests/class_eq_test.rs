@@ -0,0 +1,143 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{GString, Variant};
+use godot::classes::{IRefCounted, RefCounted};
+use godot::meta::ToGodot;
+use godot::obj::{Base, Gd, NewGd};
+use godot::register::{godot_api, GodotClass};
+use godot::test::itest;
+
+#[derive(GodotClass, PartialEq, Debug)]
+#[class(init, eq)]
+struct Money {
+    amount: i64,
+    currency: GString,
+}
+
+#[godot_api]
+impl Money {
+    #[func]
+    fn get_amount(&self) -> i64 {
+        self.amount
+    }
+}
+
+impl Money {
+    fn from_amount(amount: i64, currency: &str) -> Gd<Self> {
+        Gd::from_object(Self {
+            amount,
+            currency: currency.into(),
+        })
+    }
+}
+
+/// Equality ignores the base, and `NaN` is never equal to anything (not even itself).
+#[derive(GodotClass)]
+#[class(eq, base = RefCounted)]
+struct Measurement {
+    value: f64,
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl IRefCounted for Measurement {
+    fn init(base: Base<RefCounted>) -> Self {
+        Self { value: 0.0, base }
+    }
+}
+
+impl PartialEq for Measurement {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+fn gdscript_equals<T>(obj: &Gd<T>, other: Variant) -> bool
+where
+    T: godot::obj::Inherits<godot::classes::Object>,
+{
+    obj.clone()
+        .upcast::<godot::classes::Object>()
+        .call("equals", &[other])
+        .to::<bool>()
+}
+
+#[itest]
+fn class_eq_rust_side() {
+    let a = Money::from_amount(5, "EUR");
+    let b = Money::from_amount(5, "EUR");
+    let c = Money::from_amount(5, "USD");
+
+    // Gd == Gd stays identity comparison.
+    assert_ne!(a, b);
+    assert_eq!(a, a.clone());
+
+    // Gd == Self and Self == Gd compare contents.
+    assert!(a == *b.bind());
+    assert!(*a.bind() == b);
+    assert!(a != *c.bind());
+    assert!(*c.bind() != a);
+
+    // Self-comparison only takes shared binds.
+    assert!(a == *a.bind());
+}
+
+#[itest]
+fn class_eq_registered_with_methods() {
+    let money = Money::from_amount(5, "EUR");
+    let object = money.clone().upcast::<godot::classes::Object>();
+
+    // equals() is registered next to the class's own #[func]s, and for classes without a #[godot_api] impl.
+    assert!(object.has_method("equals"));
+    assert_eq!(object.call("get_amount", &[]), 5.to_variant());
+    assert!(Measurement::new_gd()
+        .upcast::<godot::classes::Object>()
+        .has_method("equals"));
+}
+
+#[itest]
+fn class_eq_gdscript_equals() {
+    let a = Money::from_amount(5, "EUR");
+    let b = Money::from_amount(5, "EUR");
+    let c = Money::from_amount(7, "EUR");
+
+    assert!(gdscript_equals(&a, b.to_variant()));
+    assert!(!gdscript_equals(&a, c.to_variant()));
+    assert!(gdscript_equals(&a, a.to_variant()));
+
+    // Godot's `==` on objects is identity; there is no hook to change that.
+    assert_ne!(a.to_variant(), b.to_variant());
+}
+
+#[itest]
+fn class_eq_cross_type_and_null() {
+    let money = Money::from_amount(0, "");
+    let measurement = Measurement::new_gd();
+
+    assert!(!gdscript_equals(&money, measurement.to_variant()));
+    assert!(!gdscript_equals(&measurement, money.to_variant()));
+    assert!(!gdscript_equals(&money, RefCounted::new_gd().to_variant()));
+    assert!(!gdscript_equals(&money, Variant::nil()));
+    assert!(!gdscript_equals(&money, 0.to_variant()));
+}
+
+#[itest]
+fn class_eq_self_comparison() {
+    let mut nan = Measurement::new_gd();
+    nan.bind_mut().value = f64::NAN;
+
+    let mut other_nan = Measurement::new_gd();
+    other_nan.bind_mut().value = f64::NAN;
+
+    // Distinct instances go through PartialEq; the same instance is always equal to itself.
+    assert!(!gdscript_equals(&nan, other_nan.to_variant()));
+    assert!(gdscript_equals(&nan, nan.to_variant()));
+
+    // On the Rust side, PartialEq is used as-is.
+    assert!(nan != *nan.bind());
+}
@@ -0,0 +1,93 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::StringName;
+use godot::classes::{Engine, GDScript, Object, RefCounted};
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewGd};
+use godot::register::{godot_api, GodotClass};
+use godot::test::itest;
+
+#[derive(GodotClass)]
+#[class(init, singleton, base = Object)]
+struct ScoreService {
+    total: i64,
+}
+
+#[godot_api]
+impl ScoreService {
+    #[func]
+    fn add_points(&mut self, points: i64) -> i64 {
+        self.total += points;
+        self.total
+    }
+
+    #[func]
+    fn get_total(&self) -> i64 {
+        self.total
+    }
+}
+
+// Tests share the same instance, so each test starts from a known state.
+fn reset_score_service() -> Gd<ScoreService> {
+    let mut service = ScoreService::singleton();
+    service.bind_mut().total = 0;
+    service
+}
+
+#[itest]
+fn class_singleton_registered() {
+    let service = reset_score_service();
+
+    let name = StringName::from("ScoreService");
+    let engine = Engine::singleton();
+    assert!(engine.has_singleton(&name));
+
+    let registered: Gd<Object> = engine
+        .get_singleton(&name)
+        .expect("singleton is registered");
+    assert_eq!(registered.instance_id(), service.instance_id());
+    assert_eq!(
+        ScoreService::singleton().instance_id(),
+        service.instance_id()
+    );
+}
+
+#[itest]
+fn class_singleton_rust_access() {
+    let mut service = reset_score_service();
+
+    service.bind_mut().add_points(3);
+    assert_eq!(ScoreService::singleton().bind().get_total(), 3);
+
+    let total = service.call("add_points", &[4.to_variant()]);
+    assert_eq!(total, 7.to_variant());
+}
+
+#[itest]
+fn class_singleton_gdscript_access() {
+    reset_score_service();
+
+    let code = r#"
+extends RefCounted
+
+func award() -> int:
+    ScoreService.add_points(10)
+    return ScoreService.add_points(5)
+"#;
+
+    let mut script = GDScript::new_gd();
+    script.set_source_code(code);
+    script.reload();
+
+    let mut object = RefCounted::new_gd();
+    object.set_script(&script.to_variant());
+
+    let total = object.call("award", &[]);
+    assert_eq!(total, 15.to_variant());
+    assert_eq!(ScoreService::singleton().bind().get_total(), 15);
+}
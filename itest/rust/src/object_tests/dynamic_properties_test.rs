@@ -0,0 +1,119 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{GString, StringName, Variant};
+use godot::classes::Node;
+use godot::meta::{PropertyInfo, ToGodot};
+use godot::obj::{DynamicProperties, NewAlloc};
+use godot::register::GodotClass;
+use godot::test::itest;
+
+#[derive(GodotClass)]
+#[class(init, base = Node, dynamic_properties)]
+struct DataDriven {
+    #[var]
+    level: i64,
+
+    // Dynamic properties, in declaration order.
+    stats: Vec<(StringName, i64)>,
+}
+
+impl DataDriven {
+    fn stat_mut(&mut self, name: &StringName) -> Option<&mut i64> {
+        self.stats
+            .iter_mut()
+            .find(|(stat, _)| stat == name)
+            .map(|(_, value)| value)
+    }
+}
+
+impl DynamicProperties for DataDriven {
+    fn get_property_list(&self) -> Vec<PropertyInfo> {
+        self.stats
+            .iter()
+            .map(|(name, _)| PropertyInfo::new_export::<i64>(&name.to_string()))
+            .collect()
+    }
+
+    fn get_property(&self, property: StringName) -> Option<Variant> {
+        self.stats
+            .iter()
+            .find(|(name, _)| *name == property)
+            .map(|(_, value)| value.to_variant())
+    }
+
+    fn set_property(&mut self, property: StringName, value: Variant) -> bool {
+        match (self.stat_mut(&property), value.try_to::<i64>()) {
+            (Some(stat), Ok(value)) => {
+                *stat = value;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[itest]
+fn dynamic_properties_get_set() {
+    let mut obj = DataDriven::new_alloc();
+    obj.bind_mut().stats = vec![("strength".into(), 10), ("agility".into(), 7)];
+
+    assert_eq!(obj.get("strength"), 10.to_variant());
+    assert_eq!(obj.get("agility"), 7.to_variant());
+    assert_eq!(obj.get("unknown"), Variant::nil());
+
+    obj.set("strength", &12.to_variant());
+    assert_eq!(obj.bind().stats[0], ("strength".into(), 12));
+
+    // Rejected values leave the property unchanged.
+    obj.set("agility", &"fast".to_variant());
+    assert_eq!(obj.bind().stats[1], ("agility".into(), 7));
+
+    obj.free();
+}
+
+#[itest]
+fn dynamic_properties_coexist_with_static() {
+    let mut obj = DataDriven::new_alloc();
+    obj.bind_mut().stats = vec![("strength".into(), 10)];
+
+    // Static #[var] field is reached when the dynamic hooks don't handle the name.
+    obj.set("level", &3.to_variant());
+    assert_eq!(obj.bind().level, 3);
+    assert_eq!(obj.get("level"), 3.to_variant());
+
+    let names: Vec<GString> = obj
+        .get_property_list()
+        .iter_shared()
+        .map(|prop| prop.get("name").unwrap().to::<GString>())
+        .collect();
+
+    let level_index = names.iter().position(|name| name == "level");
+    let strength_index = names.iter().position(|name| name == "strength");
+
+    // Dynamic properties are listed after the static ones.
+    assert!(level_index.is_some(), "static property listed: {names:?}");
+    assert!(
+        strength_index > level_index,
+        "dynamic property listed after static: {names:?}"
+    );
+
+    obj.free();
+}
+
+#[itest]
+fn dynamic_properties_on_base_node() {
+    let mut obj = DataDriven::new_alloc();
+    obj.bind_mut().stats = vec![("strength".into(), 1)];
+
+    // Inherited engine properties are unaffected.
+    obj.set("name", &"Creature".to_variant());
+    assert_eq!(obj.get_name(), StringName::from("Creature"));
+    assert_eq!(obj.upcast_ref::<Node>().get("strength"), 1.to_variant());
+
+    obj.free();
+}
@@ -0,0 +1,79 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{match_class, Node, Node2D, Node3D, Object, RefCounted};
+use godot::obj::{Gd, NewAlloc, NewGd};
+use godot::register::GodotClass;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Node2D)]
+struct MatchClassPlayer {}
+
+fn classify(node: Gd<Node>) -> &'static str {
+    match_class!(node, {
+        MatchClassPlayer(_player) => "player",
+        Node2D(_node2d) => "node2d",
+        Node3D(_node3d) => "node3d",
+        _ => "other",
+    })
+}
+
+#[itest]
+fn match_class_first_matching_arm() {
+    let player = MatchClassPlayer::new_alloc();
+    let node2d = Node2D::new_alloc();
+    let node3d = Node3D::new_alloc();
+    let node = Node::new_alloc();
+
+    // Player is also a Node2D, but its arm comes first.
+    assert_eq!(classify(player.clone().upcast()), "player");
+    assert_eq!(classify(node2d.clone().upcast()), "node2d");
+    assert_eq!(classify(node3d.clone().upcast()), "node3d");
+    assert_eq!(classify(node.clone()), "other");
+
+    player.free();
+    node2d.free();
+    node3d.free();
+    node.free();
+}
+
+#[itest]
+fn match_class_binds_typed_and_original() {
+    let node3d = Node3D::new_alloc();
+    let id = node3d.instance_id();
+
+    let typed_id = match_class!(node3d.clone().upcast::<Node>(), {
+        Node3D(typed) => typed.instance_id(),
+        other => panic!("unexpected class: {other}"),
+    });
+    assert_eq!(typed_id, id);
+
+    let original = match_class!(node3d.clone().upcast::<Node>(), {
+        Node2D(_typed) => None,
+        other => Some(other),
+    });
+    assert_eq!(
+        original.expect("catch-all receives original"),
+        node3d.clone().upcast()
+    );
+
+    node3d.free();
+}
+
+#[itest]
+fn match_class_without_catch_all() {
+    let obj = RefCounted::new_gd().upcast::<Object>();
+
+    let mut hits = 0;
+    match_class!(obj, {
+        Node(_node) => hits += 1,
+    });
+
+    assert_eq!(hits, 0);
+}
@@ -6,20 +6,28 @@
  */
 
 mod base_test;
+mod class_builder_test;
+mod class_eq_test;
 mod class_name_test;
 mod class_rename_test;
+mod class_singleton_test;
 mod dyn_gd_test;
 mod dynamic_call_test;
+// Dynamic properties rely on `get_property_list`, which needs Godot 4.3+.
+#[cfg(since_api = "4.3")]
+mod dynamic_properties_test;
 mod enum_test;
 // `get_property_list` is only supported in Godot 4.3+
 #[cfg(since_api = "4.3")]
 mod get_property_list_test;
 mod init_level_test;
+mod match_class_test;
 mod object_arg_test;
 mod object_swap_test;
 mod object_test;
 mod oneditor_test;
 mod onready_test;
+mod property_access_test;
 mod property_template_test;
 mod property_test;
 mod reentrant_test;
@@ -29,6 +37,7 @@ mod singleton_test;
 mod validate_property_test;
 mod virtual_methods_niche_test;
 mod virtual_methods_test;
+mod weak_gd_test;
 
 // Need to test this in the init level method.
 pub use init_level_test::initialize_init_level_test;
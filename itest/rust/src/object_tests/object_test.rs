@@ -786,6 +786,28 @@ fn object_user_upcast_mut() {
     assert_eq!(object.call("to_string", &[]), "value=17943".to_variant());
 }
 
+#[itest]
+fn object_gd_get_class_is_class() {
+    let obj = user_refc_instance();
+    assert_eq!(obj.get_class(), "RefcPayload");
+    assert!(obj.is_class("RefcPayload"));
+    assert!(obj.is_class("RefCounted"));
+    assert!(obj.is_class("Object"));
+    assert!(!obj.is_class("Node"));
+
+    // Static type is a base class, dynamic class name is still the most-derived one.
+    let object = obj.upcast::<Object>();
+    assert_eq!(object.get_class(), "RefcPayload");
+    assert!(object.is_class("RefcPayload"));
+
+    let node = Node3D::new_alloc();
+    let base = node.clone().upcast::<Node>();
+    assert_eq!(base.get_class(), "Node3D");
+    assert!(base.is_class("Node3D"));
+    assert!(!base.is_class("Node2D"));
+    node.free();
+}
+
 #[itest]
 fn object_user_downcast() {
     let obj = user_refc_instance();
@@ -10,6 +10,7 @@ use godot::classes::notify::NodeNotification;
 use godot::classes::{INode, Node, RefCounted};
 use godot::register::{godot_api, GodotClass};
 
+use godot::meta::ToGodot;
 use godot::obj::{Gd, NewAlloc, OnEditor};
 
 #[itest]
@@ -64,6 +65,30 @@ fn oneditor_no_panic_on_ready() {
     obj.free();
 }
 
+#[itest]
+fn oneditor_property_access() {
+    let mut obj = OnEditorNoDefault::new_alloc();
+
+    // Uninitialized values are readable as null/sentinel, like in the editor.
+    assert!(obj.get("node_field").is_nil());
+    assert_eq!(obj.get("some_primitive"), 0.to_variant());
+
+    let node = Node::new_alloc();
+    obj.set("node_field", &node.to_variant());
+    obj.set("some_primitive", &7.to_variant());
+
+    assert_eq!(obj.get("node_field").to::<Gd<Node>>(), node);
+    assert_eq!(obj.bind().get_node_field(), Some(node.clone()));
+    assert_eq!(*obj.bind().node_field, node);
+    assert_eq!(*obj.bind().some_primitive, 7);
+
+    obj.notify(NodeNotification::READY);
+    assert!(obj.bind().was_ready_run);
+
+    node.free();
+    obj.free();
+}
+
 #[derive(GodotClass)]
 #[class(init, base=Node)]
 struct OnEditorNoDefault {
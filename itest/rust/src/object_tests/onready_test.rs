@@ -152,6 +152,25 @@ fn onready_property_access() {
     obj.free();
 }
 
+#[itest]
+fn onready_property_access_before_ready() {
+    let mut obj = OnReadyWithImpl::create(false);
+
+    expect_panic("#[var] getter before ready()", || {
+        obj.bind().get_auto();
+    });
+
+    obj.notify(NodeNotification::READY);
+    assert_eq!(obj.bind().get_auto(), 11);
+
+    // Manual init skipped in ready().
+    expect_panic("#[var] setter on uninitialized OnReady", || {
+        obj.bind_mut().set_manual(5);
+    });
+
+    obj.free();
+}
+
 #[itest]
 fn init_attribute_node_key_lifecycle() {
     let mut obj = InitWithNodeOrBase::new_alloc();
@@ -165,7 +184,7 @@ fn init_attribute_node_key_lifecycle() {
 
     {
         let obj = obj.bind();
-        assert_eq!(obj.node.get_name(), "child".into());
+        assert_eq!(obj.node.get_name(), "child");
         assert_eq!(obj.self_name.as_str(), "CustomNodeName");
     }
 
@@ -282,7 +301,7 @@ struct InitWithNodeOrBase {
 #[godot_api]
 impl INode for InitWithNodeOrBase {
     fn ready(&mut self) {
-        assert_eq!(self.node.get_name(), "child".into());
+        assert_eq!(self.node.get_name(), "child");
         assert_eq!(self.self_name.as_str(), "CustomNodeName");
     }
 }
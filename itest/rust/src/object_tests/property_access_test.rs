@@ -0,0 +1,178 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{GString, StringName, Variant, Vector2};
+use godot::classes::{Node, Node2D, Object, RefCounted};
+use godot::meta::error::PropertyError;
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewAlloc, NewGd};
+use godot::register::GodotClass;
+use godot::test::itest;
+
+use crate::framework::expect_panic;
+
+#[derive(GodotClass)]
+#[class(init, base = RefCounted)]
+struct PropertyAccessHolder {
+    #[var]
+    speed: f64,
+
+    #[var]
+    target: Option<Gd<Node>>,
+}
+
+#[itest]
+fn property_get_user() {
+    let mut obj = PropertyAccessHolder::new_gd();
+    obj.bind_mut().speed = 2.5;
+
+    assert_eq!(obj.get_property::<f64>("speed"), Some(2.5));
+    assert_eq!(obj.get_property::<f64>("missing"), None);
+
+    // Nil values of existing properties are not confused with missing properties.
+    let target = obj.get_property::<Option<Gd<Node>>>("target");
+    assert_eq!(target, Some(None));
+}
+
+#[itest]
+fn property_get_engine() {
+    let mut node = Node2D::new_alloc();
+    node.set_position(Vector2::new(1.0, 2.0));
+
+    let name = StringName::from("position");
+    assert_eq!(
+        node.get_property::<Vector2>(&name),
+        Some(Vector2::new(1.0, 2.0))
+    );
+
+    let obj = node.clone().upcast::<Object>();
+    assert_eq!(
+        obj.get_property::<Vector2>("position"),
+        Some(Vector2::new(1.0, 2.0))
+    );
+
+    node.free();
+}
+
+#[itest]
+fn property_try_get_errors() {
+    let obj = PropertyAccessHolder::new_gd();
+
+    let err = obj.try_get_property::<f64>("missing").unwrap_err();
+    assert!(
+        matches!(&err, PropertyError::NotFound { class, property } if *class == GString::from("PropertyAccessHolder") && *property == StringName::from("missing")),
+        "unexpected error: {err}"
+    );
+
+    let err = obj.try_get_property::<GString>("speed").unwrap_err();
+    assert!(
+        matches!(&err, PropertyError::WrongType { property, .. } if *property == StringName::from("speed")),
+        "unexpected error: {err}"
+    );
+
+    expect_panic("get_property() with wrong type", || {
+        obj.get_property::<GString>("speed");
+    });
+}
+
+#[itest]
+fn property_set() {
+    let mut obj = PropertyAccessHolder::new_gd();
+
+    obj.set_property("speed", 7.0);
+    assert_eq!(obj.bind().speed, 7.0);
+
+    // Property currently nil.
+    let node = Node::new_alloc();
+    obj.set_property("target", node.clone());
+    assert_eq!(obj.bind().target, Some(node.clone()));
+
+    let err = obj.try_set_property("missing", 1).unwrap_err();
+    assert!(matches!(err, PropertyError::NotFound { .. }), "{err}");
+
+    expect_panic("set_property() on missing property", || {
+        obj.set_property("missing", 1);
+    });
+
+    obj.bind_mut().target = None;
+    node.free();
+}
+
+#[itest]
+fn property_set_refcounted_base() {
+    let obj = PropertyAccessHolder::new_gd();
+    let mut base = obj.clone().upcast::<RefCounted>();
+
+    base.set_property("speed", -1.5);
+    assert_eq!(obj.bind().speed, -1.5);
+}
+
+#[itest]
+fn property_set_many() {
+    let mut node = Node2D::new_alloc();
+
+    node.set_properties(&[
+        ("position", Vector2::new(3.0, 4.0).to_variant()),
+        ("rotation", 1.to_variant()),        // int accepted for float.
+        ("name", "Configured".to_variant()), // GString accepted for StringName.
+    ]);
+
+    assert_eq!(node.get_position(), Vector2::new(3.0, 4.0));
+    assert_eq!(node.get_rotation(), 1.0);
+    assert_eq!(node.get_name(), StringName::from("Configured"));
+
+    node.free();
+}
+
+#[itest]
+fn property_set_many_user() {
+    let mut obj = PropertyAccessHolder::new_gd();
+    let node = Node::new_alloc();
+
+    obj.set_properties(&[("speed", 4.5.to_variant()), ("target", node.to_variant())]);
+    assert_eq!(obj.bind().speed, 4.5);
+    assert_eq!(obj.bind().target, Some(node.clone()));
+
+    // Nil for object properties.
+    obj.set_properties(&[("target", Variant::nil())]);
+    assert_eq!(obj.bind().target, None);
+
+    node.free();
+}
+
+#[itest]
+fn property_set_many_errors() {
+    let mut obj = PropertyAccessHolder::new_gd();
+    obj.bind_mut().speed = 1.0;
+
+    // Wrong type: nothing is written, not even the valid properties before.
+    let err = obj
+        .try_set_properties(&[
+            ("speed", 2.0.to_variant()),
+            ("target", "not a node".to_variant()),
+        ])
+        .unwrap_err();
+    assert!(
+        matches!(&err, PropertyError::WrongType { property, .. } if *property == StringName::from("target")),
+        "unexpected error: {err}"
+    );
+    assert_eq!(obj.bind().speed, 1.0);
+
+    // Missing property is reported by name.
+    let err = obj
+        .try_set_properties(&[("speed", 2.0.to_variant()), ("missing", 1.to_variant())])
+        .unwrap_err();
+    assert!(
+        matches!(&err, PropertyError::NotFound { property, .. } if *property == StringName::from("missing")),
+        "unexpected error: {err}"
+    );
+    assert_eq!(obj.bind().speed, 1.0);
+
+    expect_panic("set_properties() with wrong type", || {
+        obj.set_properties(&[("speed", "fast".to_variant())]);
+    });
+}
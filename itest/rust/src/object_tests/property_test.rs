@@ -302,6 +302,9 @@ struct CheckAllExports {
 
     #[export(color_no_alpha)]
     color_no_alpha: Color,
+
+    #[export(node_path_valid_types = "Node2D, Control")]
+    node_path_valid_types: NodePath,
 }
 
 #[derive(GodotConvert, Var, Export, Eq, PartialEq, Debug)]
@@ -352,14 +355,11 @@ fn derive_property() {
 fn enum_var_hint() {
     let int_prop = <Behavior as Var>::var_hint();
     assert_eq!(int_prop.hint, PropertyHint::ENUM);
-    assert_eq!(
-        int_prop.hint_string,
-        "Peaceful:0,Defend:1,Aggressive:7".into()
-    );
+    assert_eq!(int_prop.hint_string, "Peaceful:0,Defend:1,Aggressive:7");
 
     let str_prop = <StrBehavior as Var>::var_hint();
     assert_eq!(str_prop.hint, PropertyHint::ENUM);
-    assert_eq!(str_prop.hint_string, "Peaceful,Defend,Aggressive".into());
+    assert_eq!(str_prop.hint_string, "Peaceful,Defend,Aggressive");
 }
 
 #[derive(GodotClass)]
@@ -450,6 +450,31 @@ fn export_resource() {
     class.free();
 }
 
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportNodePath {
+    #[export(node_path_valid_types = "Node2D, Control")]
+    target: NodePath,
+}
+
+#[itest]
+fn export_node_path_valid_types() {
+    let class = ExportNodePath::new_alloc();
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "target".to_variant())
+        .unwrap();
+    check_property(&property, "class_name", "");
+    check_property(&property, "type", VariantType::NODE_PATH.ord());
+    check_property(&property, "hint", PropertyHint::NODE_PATH_VALID_TYPES.ord());
+    check_property(&property, "hint_string", "Node2D,Control");
+    check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
+
+    class.free();
+}
+
 #[derive(GodotClass)]
 #[class(init)]
 struct ExportOverride {
@@ -6,6 +6,8 @@
  */
 
 use crate::framework::itest;
+#[cfg(feature = "codegen-full")]
+use godot::classes::{IMainLoop, MainLoop};
 use godot::prelude::*;
 
 #[derive(GodotClass)]
@@ -79,3 +81,105 @@ fn reentrant_emit_succeeds() {
 
     class.free()
 }
+
+#[itest]
+fn reentrant_call_with_borrow_tracing() {
+    // Disables tracing again even if an assertion fails, so that other tests don't flood the output.
+    struct TracingGuard;
+
+    impl Drop for TracingGuard {
+        fn drop(&mut self) {
+            godot::obj::set_borrow_tracing(false);
+        }
+    }
+
+    let mut class = ReentrantClass::new_alloc();
+
+    let trace = {
+        let _guard = TracingGuard;
+        godot::obj::set_borrow_tracing(true);
+
+        godot::obj::capture_borrow_trace(|| {
+            class.call("first_calls", &[]);
+        })
+    };
+
+    {
+        let guard = class.bind();
+        assert!(guard.first_called_post);
+        assert!(guard.second_called);
+    }
+
+    // Release builds don't trace.
+    if cfg!(debug_assertions) {
+        let acquires = trace
+            .iter()
+            .filter(|line| {
+                line.starts_with("[borrow] acquire bind_mut <") && line.contains("ReentrantClass")
+            })
+            .count();
+
+        // One for first_calls(), one for the reentrant second().
+        assert_eq!(acquires, 2, "{trace:#?}");
+    } else {
+        assert!(trace.is_empty(), "{trace:#?}");
+    }
+
+    // No more output once disabled.
+    let trace = godot::obj::capture_borrow_trace(|| {
+        class.bind_mut().second_called = false;
+    });
+    assert!(trace.is_empty(), "{trace:#?}");
+
+    class.free()
+}
+
+/// Binds a user object once, then quits. Used to check the borrow trace printed by a child process.
+#[cfg(feature = "codegen-full")]
+#[derive(GodotClass)]
+#[class(init, base = MainLoop)]
+struct BorrowTraceMainLoop {}
+
+#[cfg(feature = "codegen-full")]
+#[godot_api]
+impl IMainLoop for BorrowTraceMainLoop {
+    fn process(&mut self, _delta: f64) -> bool {
+        let mut class = ReentrantClass::new_alloc();
+        class.bind_mut().second_called = true;
+        class.free();
+
+        true
+    }
+}
+
+// Unlike the test above, this checks the actual Godot output and enabling via environment variable, which is only read on startup.
+#[cfg(feature = "codegen-full")]
+#[itest]
+fn borrow_tracing_prints_output() {
+    use godot::classes::Os;
+
+    let mut os = Os::singleton();
+    os.set_environment("GODOT_RUST_TRACE_BORROWS", "1");
+    let (exit_code, output) = crate::framework::run_headless_main_loop("BorrowTraceMainLoop", &[]);
+    os.unset_environment("GODOT_RUST_TRACE_BORROWS");
+
+    assert_eq!(exit_code, 0, "child process failed:\n{output}");
+
+    let traced = |prefix: &str| {
+        output.lines().any(|line| {
+            line.starts_with(prefix)
+                && line.contains("ReentrantClass>")
+                && line.contains("reentrant_test.rs:")
+        })
+    };
+
+    let acquired = traced("[borrow] acquire bind_mut <");
+    let released = traced("[borrow] release bind_mut <");
+
+    // Release builds don't trace.
+    if cfg!(debug_assertions) {
+        assert!(acquired && released, "borrow not traced:\n{output}");
+    } else {
+        assert!(!output.contains("[borrow] "), "unexpected trace:\n{output}");
+    }
+}
@@ -8,7 +8,7 @@
 use crate::framework::{itest, TestContext};
 
 use godot::builtin::{
-    real, varray, Color, GString, PackedByteArray, PackedColorArray, PackedFloat32Array,
+    real, varray, Callable, Color, GString, PackedByteArray, PackedColorArray, PackedFloat32Array,
     PackedInt32Array, PackedVector2Array, PackedVector3Array, RealConv, StringName, Variant,
     VariantArray, Vector2, Vector3,
 };
@@ -23,6 +23,8 @@ use godot::meta::ToGodot;
 use godot::obj::{Base, Gd, NewAlloc, NewGd};
 use godot::private::class_macros::assert_eq_approx;
 use godot::register::{godot_api, GodotClass};
+use std::cell::Cell;
+use std::rc::Rc;
 
 /// Simple class, that deliberately has no constructor accessible from GDScript
 #[derive(GodotClass, Debug)]
@@ -311,6 +313,28 @@ fn test_ready(test_context: &TestContext) {
     assert_eq!(obj.bind().implementation_value, 1);
 }
 
+// Overriding ready() does not replace the engine's own logic, so there is no "super" implementation to call.
+#[itest]
+fn test_ready_keeps_engine_behavior(test_context: &TestContext) {
+    let mut obj = VirtualReadyTest::new_alloc();
+
+    let signal_emitted = Rc::new(Cell::new(false));
+    let signal_emitted_clone = signal_emitted.clone();
+    obj.connect(
+        "ready",
+        &Callable::from_local_fn("on_ready", move |_args| {
+            signal_emitted_clone.set(true);
+            Ok(Variant::nil())
+        }),
+    );
+
+    let mut test_node = test_context.scene_tree.clone();
+    test_node.add_child(&obj);
+
+    assert_eq!(obj.bind().implementation_value, 1);
+    assert!(signal_emitted.get(), "engine still emits `ready` signal");
+}
+
 #[itest]
 fn test_ready_panic(test_context: &TestContext) {
     let mut obj = VirtualReadyTest::new_alloc();
@@ -0,0 +1,151 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Callable;
+use godot::classes::{Node, Object, RefCounted};
+use godot::meta::ToGodot;
+use godot::obj::{Base, NewAlloc, NewGd, WeakGd, WithBaseField};
+use godot::register::{godot_api, GodotClass};
+
+use crate::framework::itest;
+
+#[itest]
+fn weak_gd_upgrade_manual() {
+    let node = Node::new_alloc();
+    let weak = node.to_weak_gd();
+
+    assert_eq!(weak.instance_id(), node.instance_id());
+    assert_eq!(weak.upgrade(), Some(node.clone()));
+
+    node.free();
+    assert_eq!(weak.upgrade(), None);
+}
+
+#[itest]
+fn weak_gd_upgrade_refcounted() {
+    let obj = RefCounted::new_gd();
+    let weak = obj.to_weak_gd();
+
+    // Weak references don't count.
+    assert_eq!(obj.get_reference_count(), 1);
+
+    let upgraded = weak.upgrade().expect("object is alive");
+    assert_eq!(obj.get_reference_count(), 2);
+    drop(upgraded);
+
+    drop(obj);
+    assert_eq!(weak.upgrade(), None);
+}
+
+#[itest]
+fn weak_gd_eq_hash() {
+    let a = Object::new_alloc();
+    let b = Object::new_alloc();
+
+    let weak_a = a.to_weak_gd();
+    let copy_a = weak_a;
+    assert_eq!(weak_a, copy_a);
+    assert_ne!(weak_a, b.to_weak_gd());
+
+    let set: std::collections::HashSet<_> = [weak_a, copy_a, b.to_weak_gd()].into();
+    assert_eq!(set.len(), 2);
+
+    a.free();
+    b.free();
+}
+
+#[itest]
+fn weak_gd_from_self() {
+    let obj = WeakSelf::new_gd();
+    let weak = obj.bind().to_weak_gd();
+
+    assert_eq!(weak.upgrade(), Some(obj));
+}
+
+#[itest]
+fn weak_gd_from_base() {
+    let mut obj = WeakSelf::new_gd();
+
+    // Typed as the Rust class, not its RefCounted base.
+    let from_base: WeakGd<WeakSelf> = obj.bind().base().to_weak_gd();
+    let from_base_mut: WeakGd<WeakSelf> = obj.bind_mut().base_mut().to_weak_gd();
+
+    assert_eq!(from_base, obj.bind().to_weak_gd());
+    assert_eq!(from_base_mut, from_base);
+    assert_eq!(from_base.upgrade(), Some(obj));
+}
+
+// Closure stored inside the object itself, capturing a weak reference to it. With a strong reference, the object would never be freed.
+#[itest]
+fn weak_gd_no_cycle_callable() {
+    let mut obj = WeakSelf::new_gd();
+
+    let weak = obj.bind().to_weak_gd();
+    let callable = Callable::from_local_fn("increment", move |_args| {
+        if let Some(mut obj) = weak.upgrade() {
+            obj.bind_mut().count += 1;
+        }
+        Ok(().to_variant())
+    });
+    obj.bind_mut().callback = Some(callable);
+
+    // Invoke the copy owned by the object.
+    let stored = obj.bind().callback.clone().expect("callback stored");
+    stored.call(&[]);
+    stored.call(&[]);
+    assert_eq!(obj.bind().count, 2);
+    assert_eq!(obj.get_reference_count(), 1);
+
+    drop(stored);
+    drop(obj);
+    assert_eq!(weak.upgrade(), None);
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn weak_gd_no_cycle_signal() {
+    let mut obj = WeakSelf::new_gd();
+    obj.bind_mut().connect_counting();
+
+    obj.signals().ticked().emit();
+    obj.signals().ticked().emit();
+    assert_eq!(obj.bind().count, 2);
+
+    let weak = obj.to_weak_gd();
+    drop(obj);
+
+    // The connection held by the object didn't keep it alive.
+    assert_eq!(weak.upgrade(), None);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helper types
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct WeakSelf {
+    count: i32,
+    callback: Option<Callable>,
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl WeakSelf {
+    #[signal]
+    fn ticked();
+
+    #[cfg(since_api = "4.2")]
+    fn connect_counting(&mut self) {
+        let weak = self.to_weak_gd();
+
+        self.signals().ticked().connect(move || {
+            if let Some(mut obj) = weak.upgrade() {
+                obj.bind_mut().count += 1;
+            }
+        });
+    }
+}
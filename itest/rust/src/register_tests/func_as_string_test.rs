@@ -0,0 +1,115 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::framework::itest;
+use godot::builtin::VariantType;
+use godot::classes::ClassDb;
+use godot::prelude::*;
+
+#[derive(Debug, PartialEq)]
+struct Coord {
+    x: i32,
+    y: i32,
+}
+
+impl fmt::Display for Coord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct AsStringFuncs {
+    #[init(val = 3)]
+    x: i32,
+}
+
+#[godot_api]
+impl AsStringFuncs {
+    #[func(as_string)]
+    fn coord(&self) -> Coord {
+        Coord { x: self.x, y: 4 }
+    }
+
+    #[func(as_string)]
+    fn shift(&mut self, dx: i32) -> Coord {
+        self.x += dx;
+        self.coord()
+    }
+
+    #[func(as_string)]
+    fn origin() -> impl fmt::Display {
+        Coord { x: 0, y: 0 }
+    }
+
+    #[func(as_string, gd_self)]
+    fn instance_id_text(this: Gd<Self>) -> InstanceId {
+        this.instance_id()
+    }
+
+    #[func(as_string, rename = answer)]
+    fn answer_number(&self) -> i64 {
+        42
+    }
+}
+
+#[itest]
+fn func_as_string_method() {
+    let mut obj = AsStringFuncs::new_gd();
+
+    let result = obj.call("coord", &[]);
+    assert_eq!(result.get_type(), VariantType::STRING);
+    assert_eq!(result, "(3, 4)".to_variant());
+
+    let result = obj.call("shift", &[2.to_variant()]);
+    assert_eq!(result, "(5, 4)".to_variant());
+
+    // Rust callers still get the original type.
+    assert_eq!(obj.bind().coord(), Coord { x: 5, y: 4 });
+}
+
+#[itest]
+fn func_as_string_static_and_gd_self() {
+    let mut obj = AsStringFuncs::new_gd();
+
+    let result = obj.call("origin", &[]);
+    assert_eq!(result, "(0, 0)".to_variant());
+
+    let expected = obj.instance_id().to_string();
+    let result = obj.call("instance_id_text", &[]);
+    assert_eq!(result, expected.to_variant());
+}
+
+#[itest]
+fn func_as_string_renamed() {
+    let mut obj = AsStringFuncs::new_gd();
+
+    // An integer formatted as string, not converted through ToGodot.
+    let result = obj.call("answer", &[]);
+    assert_eq!(result, "42".to_variant());
+}
+
+#[itest]
+fn func_as_string_registered_return_type() {
+    let class_name = AsStringFuncs::class_name().to_string_name();
+    let methods = ClassDb::singleton()
+        .class_get_method_list_ex(&class_name)
+        .no_inheritance(true)
+        .done();
+
+    let coord = methods
+        .iter_shared()
+        .find(|method| method.get("name").map(|name| name.to_string()) == Some("coord".to_string()))
+        .expect("method `coord` is registered");
+
+    let return_info: Dictionary = coord.get("return").unwrap().to();
+    let return_type: i64 = return_info.get("type").unwrap().to();
+    assert_eq!(return_type, VariantType::STRING.ord() as i64);
+}
@@ -0,0 +1,138 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::VariantType;
+use godot::classes::{ClassDb, GDScript};
+use godot::prelude::*;
+use godot::task::{self, TaskHandle};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct AsyncFuncs {
+    #[init(val = GString::from("data"))]
+    prefix: GString,
+    fetch_count: i32,
+}
+
+#[godot_api]
+impl AsyncFuncs {
+    #[func(gd_self)]
+    async fn fetch_data(this: Gd<Self>, id: i32) -> GString {
+        task::next_frame().await.unwrap();
+        format!("{}-{id}", this.bind().prefix).into()
+    }
+
+    #[func(gd_self)]
+    async fn fetch_counted(mut this: Gd<Self>) -> i32 {
+        task::next_frame().await.unwrap();
+
+        let mut guard = this.bind_mut();
+        guard.fetch_count += 1;
+        guard.fetch_count
+    }
+
+    #[func]
+    async fn add_later(a: i64, b: i64) -> i64 {
+        task::next_frame().await.unwrap();
+        a + b
+    }
+
+    // Completes without suspending; the signal must still reach the awaiting caller.
+    #[func]
+    async fn immediate() -> Vector2i {
+        Vector2i::new(3, 4)
+    }
+}
+
+#[itest]
+fn func_async_registered_return_type() {
+    let class_name = AsyncFuncs::class_name().to_string_name();
+    let methods = ClassDb::singleton()
+        .class_get_method_list_ex(&class_name)
+        .no_inheritance(true)
+        .done();
+
+    for name in ["fetch_data", "fetch_counted", "add_later", "immediate"] {
+        let method = methods
+            .iter_shared()
+            .find(|method| method.get("name").map(|n| n.to_string()) == Some(name.to_string()))
+            .unwrap_or_else(|| panic!("method `{name}` is registered"));
+
+        let return_info: Dictionary = method.get("return").unwrap().to();
+        let return_type: i64 = return_info.get("type").unwrap().to();
+        assert_eq!(return_type, VariantType::SIGNAL.ord() as i64, "{name}");
+    }
+}
+
+#[itest(async)]
+fn func_async_await_from_rust() -> TaskHandle {
+    let mut obj = AsyncFuncs::new_gd();
+
+    task::spawn(async move {
+        let signal = obj.call("fetch_data", &[7.to_variant()]).to::<Signal>();
+
+        // The instance is not bound while the method is suspended.
+        obj.bind_mut().prefix = GString::from("changed");
+
+        let (data,) = task::signal_future::<(GString,)>(&signal).await;
+        assert_eq!(data, GString::from("changed-7"));
+
+        let signal = obj.call("fetch_counted", &[]).to::<Signal>();
+        assert_eq!(obj.bind().fetch_count, 0);
+        let (count,) = task::signal_future::<(i32,)>(&signal).await;
+        assert_eq!(count, 1);
+        assert_eq!(obj.bind().fetch_count, 1);
+
+        let signal = obj.call("immediate", &[]).to::<Signal>();
+        let (vector,) = task::signal_future::<(Vector2i,)>(&signal).await;
+        assert_eq!(vector, Vector2i::new(3, 4));
+    })
+}
+
+#[itest(async)]
+fn func_async_await_from_gdscript() -> TaskHandle {
+    let code = r#"
+extends RefCounted
+
+var results = []
+var done = false
+
+func run(obj):
+    results.append(await obj.fetch_data(1))
+    results.append(await obj.fetch_counted())
+    results.append(await obj.add_later(20, 22))
+    results.append(await obj.immediate())
+    done = true
+"#;
+
+    let mut script = GDScript::new_gd();
+    script.set_source_code(code);
+    script.reload();
+
+    let mut caller = RefCounted::new_gd();
+    caller.set_script(&script.to_variant());
+
+    let obj = AsyncFuncs::new_gd();
+    caller.call("run", &[obj.to_variant()]);
+
+    task::spawn(async move {
+        let observed = caller.clone();
+        task::until(move || observed.get("done").to::<bool>())
+            .await
+            .unwrap();
+
+        let results = caller.get("results").to::<VariantArray>();
+        assert_eq!(
+            results,
+            varray!["data-1", 1, 42, Vector2i::new(3, 4)],
+            "awaited results"
+        );
+        assert_eq!(obj.bind().fetch_count, 1);
+    })
+}
@@ -33,6 +33,20 @@ impl FuncObj {
         GString::from("static")
     }
 
+    #[func]
+    fn node_name_by_ref(&self, node: &Gd<Node>) -> StringName {
+        node.get_name()
+    }
+
+    #[func]
+    fn scaled_offset(
+        &self,
+        #[arg(rename = "offset")] offset_in_pixels: Vector2,
+        factor: f32,
+    ) -> Vector2 {
+        offset_in_pixels * factor
+    }
+
     #[cfg(all())]
     fn returns_hello_world(&self) -> GString {
         GString::from("Hello world!")
@@ -280,6 +294,45 @@ fn cfg_doesnt_interfere_with_valid_method_impls() {
     );
 }
 
+#[itest]
+fn func_object_param_by_ref() {
+    let mut object = Gd::from_object(FuncObj);
+    let mut node = Node::new_alloc();
+    node.set_name("by_ref");
+
+    let name = object.call("node_name_by_ref", &[node.to_variant()]);
+    assert_eq!(name.to::<StringName>(), "by_ref");
+
+    // Rust callers pass a reference, too.
+    assert_eq!(object.bind().node_name_by_ref(&node), "by_ref");
+
+    node.free();
+}
+
+#[itest]
+fn func_param_rename() {
+    let methods = ClassDb::singleton().class_method_infos("FuncObj", true);
+    let method = methods
+        .iter()
+        .find(|method| method.name == StringName::from("scaled_offset"))
+        .expect("scaled_offset is registered");
+
+    let param_names: Vec<String> = method
+        .arguments
+        .iter()
+        .map(|arg| arg.name.to_string())
+        .collect();
+    assert_eq!(param_names, ["offset", "factor"]);
+
+    // Calls are unaffected, since arguments are positional.
+    let mut object = Gd::from_object(FuncObj);
+    let result = object.call(
+        "scaled_offset",
+        &[Vector2::new(1.0, 2.0).to_variant(), 3.0.to_variant()],
+    );
+    assert_eq!(result.to::<Vector2>(), Vector2::new(3.0, 6.0));
+}
+
 #[itest]
 fn cfg_removes_or_keeps_methods() {
     assert!(class_has_method::<GdSelfObj>(
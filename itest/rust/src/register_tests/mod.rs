@@ -8,6 +8,9 @@
 mod constant_test;
 mod conversion_test;
 mod derive_godotconvert_test;
+mod func_as_string_test;
+#[cfg(since_api = "4.2")]
+mod func_async_test;
 mod func_test;
 mod gdscript_ffi_test;
 mod multiple_impl_blocks_test;